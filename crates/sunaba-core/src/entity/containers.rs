@@ -0,0 +1,58 @@
+//! Containers for carrying liquids and gases in the inventory
+
+use crate::simulation::MaterialType;
+use serde::{Deserialize, Serialize};
+
+/// Container types, each holding a single fluid at a time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ContainerType {
+    /// Scoops and pours liquids (water, oil, lava...)
+    Bucket,
+    /// Captures and releases gases (steam, smoke, poison gas...)
+    Flask,
+}
+
+impl ContainerType {
+    pub const ALL: [ContainerType; 2] = [ContainerType::Bucket, ContainerType::Flask];
+
+    /// Maximum number of fluid pixels the container holds
+    pub fn capacity(&self) -> u32 {
+        match self {
+            ContainerType::Bucket => 64,
+            ContainerType::Flask => 32,
+        }
+    }
+
+    /// Check if the container can carry materials of this type
+    pub fn holds(&self, material_type: MaterialType) -> bool {
+        match self {
+            ContainerType::Bucket => material_type == MaterialType::Liquid,
+            ContainerType::Flask => material_type == MaterialType::Gas,
+        }
+    }
+
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ContainerType::Bucket => "Bucket",
+            ContainerType::Flask => "Flask",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_containers_hold_one_phase() {
+        assert!(ContainerType::Bucket.holds(MaterialType::Liquid));
+        assert!(!ContainerType::Bucket.holds(MaterialType::Gas));
+        assert!(ContainerType::Flask.holds(MaterialType::Gas));
+        assert!(!ContainerType::Flask.holds(MaterialType::Powder));
+
+        for container in ContainerType::ALL {
+            assert!(container.capacity() > 0, "{}", container.name());
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Crafting system with recipes and material transformation
 
+use crate::entity::containers::ContainerType;
 use crate::entity::inventory::Inventory;
 use crate::simulation::MaterialId;
 use serde::{Deserialize, Serialize};
@@ -12,21 +13,83 @@ pub struct Recipe {
     pub inputs: Vec<(u16, u32)>, // (material_id, count)
     pub output: RecipeOutput,
     pub workstation: Option<WorkstationType>,
+    /// Smelting time in seconds at a furnace (0 = instant)
+    #[serde(default)]
+    pub craft_time: f32,
 }
 
-/// Recipe output (material or tool)
+/// Recipe output (material, tool or empty container)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum RecipeOutput {
     Material { id: u16, count: u32 },
     Tool { tool_id: u16, durability: u32 },
+    Container { container: ContainerType },
 }
 
-/// Workstation types (Phase 8: placed in world, Phase 5: hand crafting only)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Workstation types, placed in the world as multi-pixel structures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum WorkstationType {
-    Furnace, // Smelting (Phase 8)
-    Anvil,   // Tool/weapon crafting (Phase 8)
-    Alchemy, // Potions (future)
+    Furnace, // Smelting (takes fuel and time)
+    Anvil,   // Tool/weapon crafting
+    Alchemy, // Volatile mixtures
+}
+
+impl WorkstationType {
+    pub const ALL: [WorkstationType; 3] = [
+        WorkstationType::Furnace,
+        WorkstationType::Anvil,
+        WorkstationType::Alchemy,
+    ];
+
+    /// Material the structure is built from (also its inventory item)
+    pub fn material_id(&self) -> u16 {
+        match self {
+            WorkstationType::Furnace => MaterialId::FURNACE,
+            WorkstationType::Anvil => MaterialId::ANVIL,
+            WorkstationType::Alchemy => MaterialId::ALCHEMY_TABLE,
+        }
+    }
+
+    /// Workstation built from the given material, if any
+    pub fn from_material(material_id: u16) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.material_id() == material_id)
+    }
+
+    /// Size of the placed structure in pixels (width, height)
+    pub fn footprint(&self) -> (i32, i32) {
+        match self {
+            WorkstationType::Furnace => (6, 6),
+            WorkstationType::Anvil => (6, 3),
+            WorkstationType::Alchemy => (5, 4),
+        }
+    }
+
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            WorkstationType::Furnace => "Furnace",
+            WorkstationType::Anvil => "Anvil",
+            WorkstationType::Alchemy => "Alchemy Table",
+        }
+    }
+}
+
+/// Result of a crafting attempt that respects workstation requirements
+/// `Crafted` output still has to be added to the inventory by the caller
+#[derive(Debug, Clone)]
+pub enum CraftOutcome {
+    /// Finished immediately
+    Crafted(RecipeOutput),
+    /// Queued at a furnace, output is delivered when done
+    Smelting {
+        seconds: f32,
+    },
+    MissingMaterials,
+    MissingWorkstation(WorkstationType),
+    /// Furnace recipe but no fuel in the furnace or inventory
+    MissingFuel,
 }
 
 /// Recipe registry
@@ -57,6 +120,7 @@ impl RecipeRegistry {
                 durability: 50,
             },
             workstation: None, // Hand crafting
+            craft_time: 0.0,
         });
 
         // Stone Pickaxe: 3 stone + 2 wood
@@ -69,6 +133,7 @@ impl RecipeRegistry {
                 durability: 100,
             },
             workstation: None,
+            craft_time: 0.0,
         });
 
         // Iron Pickaxe: 3 iron ingot + 2 wood
@@ -80,7 +145,151 @@ impl RecipeRegistry {
                 tool_id: 1002,
                 durability: 400,
             },
-            workstation: None, // Phase 8: require anvil
+            workstation: Some(WorkstationType::Anvil),
+            craft_time: 0.0,
+        });
+
+        // Wood Axe: 4 wood
+        self.register(Recipe {
+            id: 3,
+            name: "Wood Axe".to_string(),
+            inputs: vec![(MaterialId::WOOD, 4)],
+            output: RecipeOutput::Tool {
+                tool_id: 1010,
+                durability: 50,
+            },
+            workstation: None,
+            craft_time: 0.0,
+        });
+
+        // Stone Axe: 3 stone + 2 wood
+        self.register(Recipe {
+            id: 4,
+            name: "Stone Axe".to_string(),
+            inputs: vec![(MaterialId::STONE, 3), (MaterialId::WOOD, 2)],
+            output: RecipeOutput::Tool {
+                tool_id: 1011,
+                durability: 100,
+            },
+            workstation: None,
+            craft_time: 0.0,
+        });
+
+        // Iron Axe: 3 iron ingot + 2 wood
+        self.register(Recipe {
+            id: 5,
+            name: "Iron Axe".to_string(),
+            inputs: vec![(MaterialId::IRON_INGOT, 3), (MaterialId::WOOD, 2)],
+            output: RecipeOutput::Tool {
+                tool_id: 1012,
+                durability: 400,
+            },
+            workstation: Some(WorkstationType::Anvil),
+            craft_time: 0.0,
+        });
+
+        // Wood Shovel: 3 wood
+        self.register(Recipe {
+            id: 6,
+            name: "Wood Shovel".to_string(),
+            inputs: vec![(MaterialId::WOOD, 3)],
+            output: RecipeOutput::Tool {
+                tool_id: 1020,
+                durability: 50,
+            },
+            workstation: None,
+            craft_time: 0.0,
+        });
+
+        // Stone Shovel: 2 stone + 2 wood
+        self.register(Recipe {
+            id: 7,
+            name: "Stone Shovel".to_string(),
+            inputs: vec![(MaterialId::STONE, 2), (MaterialId::WOOD, 2)],
+            output: RecipeOutput::Tool {
+                tool_id: 1021,
+                durability: 100,
+            },
+            workstation: None,
+            craft_time: 0.0,
+        });
+
+        // Iron Shovel: 2 iron ingot + 2 wood
+        self.register(Recipe {
+            id: 8,
+            name: "Iron Shovel".to_string(),
+            inputs: vec![(MaterialId::IRON_INGOT, 2), (MaterialId::WOOD, 2)],
+            output: RecipeOutput::Tool {
+                tool_id: 1022,
+                durability: 400,
+            },
+            workstation: Some(WorkstationType::Anvil),
+            craft_time: 0.0,
+        });
+
+        // Wood Sword: 4 wood
+        self.register(Recipe {
+            id: 9,
+            name: "Wood Sword".to_string(),
+            inputs: vec![(MaterialId::WOOD, 4)],
+            output: RecipeOutput::Tool {
+                tool_id: 1030,
+                durability: 50,
+            },
+            workstation: None,
+            craft_time: 0.0,
+        });
+
+        // Stone Sword: 4 stone + 1 wood
+        self.register(Recipe {
+            id: 10,
+            name: "Stone Sword".to_string(),
+            inputs: vec![(MaterialId::STONE, 4), (MaterialId::WOOD, 1)],
+            output: RecipeOutput::Tool {
+                tool_id: 1031,
+                durability: 100,
+            },
+            workstation: None,
+            craft_time: 0.0,
+        });
+
+        // Iron Sword: 4 iron ingot + 1 wood
+        self.register(Recipe {
+            id: 11,
+            name: "Iron Sword".to_string(),
+            inputs: vec![(MaterialId::IRON_INGOT, 4), (MaterialId::WOOD, 1)],
+            output: RecipeOutput::Tool {
+                tool_id: 1032,
+                durability: 400,
+            },
+            workstation: Some(WorkstationType::Anvil),
+            craft_time: 0.0,
+        });
+
+        // === CONTAINERS ===
+
+        // Bucket: 3 iron ingot
+        self.register(Recipe {
+            id: 12,
+            name: "Bucket".to_string(),
+            inputs: vec![(MaterialId::IRON_INGOT, 3)],
+            output: RecipeOutput::Container {
+                container: ContainerType::Bucket,
+            },
+            workstation: Some(WorkstationType::Anvil),
+            craft_time: 0.0,
+        });
+
+        // Flask: 2 glass
+        self.register(Recipe {
+            id: 13,
+            name: "Flask".to_string(),
+            inputs: vec![(MaterialId::GLASS, 2)],
+            output: RecipeOutput::Container {
+                container: ContainerType::Flask,
+            },
+            workstation: Some(WorkstationType::Alchemy),
+            craft_time: 0.0,
         });
 
         // === MATERIALS ===
@@ -95,6 +304,7 @@ impl RecipeRegistry {
                 count: 5,
             },
             workstation: None,
+            craft_time: 0.0,
         });
 
         // Gunpowder: 1 coal + 1 fertilizer (simplified chemistry)
@@ -107,6 +317,162 @@ impl RecipeRegistry {
                 count: 2,
             },
             workstation: None,
+            craft_time: 0.0,
+        });
+
+        // === WORKSTATIONS ===
+
+        // Furnace: 20 stone
+        self.register(Recipe {
+            id: 200,
+            name: "Furnace".to_string(),
+            inputs: vec![(MaterialId::STONE, 20)],
+            output: RecipeOutput::Material {
+                id: MaterialId::FURNACE,
+                count: 1,
+            },
+            workstation: None,
+            craft_time: 0.0,
+        });
+
+        // Anvil: 5 iron ingot (smelted at a furnace first)
+        self.register(Recipe {
+            id: 201,
+            name: "Anvil".to_string(),
+            inputs: vec![(MaterialId::IRON_INGOT, 5)],
+            output: RecipeOutput::Material {
+                id: MaterialId::ANVIL,
+                count: 1,
+            },
+            workstation: None,
+            craft_time: 0.0,
+        });
+
+        // Alchemy Table: 4 glass + 4 wood
+        self.register(Recipe {
+            id: 202,
+            name: "Alchemy Table".to_string(),
+            inputs: vec![(MaterialId::GLASS, 4), (MaterialId::WOOD, 4)],
+            output: RecipeOutput::Material {
+                id: MaterialId::ALCHEMY_TABLE,
+                count: 1,
+            },
+            workstation: None,
+            craft_time: 0.0,
+        });
+
+        // === SMELTING (furnace, takes fuel and time) ===
+
+        // Iron Ingot: 2 iron ore
+        self.register(Recipe {
+            id: 300,
+            name: "Smelt Iron".to_string(),
+            inputs: vec![(MaterialId::IRON_ORE, 2)],
+            output: RecipeOutput::Material {
+                id: MaterialId::IRON_INGOT,
+                count: 1,
+            },
+            workstation: Some(WorkstationType::Furnace),
+            craft_time: 4.0,
+        });
+
+        // Copper Ingot: 2 copper ore
+        self.register(Recipe {
+            id: 301,
+            name: "Smelt Copper".to_string(),
+            inputs: vec![(MaterialId::COPPER_ORE, 2)],
+            output: RecipeOutput::Material {
+                id: MaterialId::COPPER_INGOT,
+                count: 1,
+            },
+            workstation: Some(WorkstationType::Furnace),
+            craft_time: 3.0,
+        });
+
+        // Gold Ingot: 2 gold ore
+        self.register(Recipe {
+            id: 302,
+            name: "Smelt Gold".to_string(),
+            inputs: vec![(MaterialId::GOLD_ORE, 2)],
+            output: RecipeOutput::Material {
+                id: MaterialId::GOLD_INGOT,
+                count: 1,
+            },
+            workstation: Some(WorkstationType::Furnace),
+            craft_time: 5.0,
+        });
+
+        // Glass: 4 sand
+        self.register(Recipe {
+            id: 303,
+            name: "Glass".to_string(),
+            inputs: vec![(MaterialId::SAND, 4)],
+            output: RecipeOutput::Material {
+                id: MaterialId::GLASS,
+                count: 2,
+            },
+            workstation: Some(WorkstationType::Furnace),
+            craft_time: 2.0,
+        });
+
+        // Steel Ingot: 2 iron ingot + 1 coal
+        self.register(Recipe {
+            id: 304,
+            name: "Steel Ingot".to_string(),
+            inputs: vec![(MaterialId::IRON_INGOT, 2), (MaterialId::COAL_ORE, 1)],
+            output: RecipeOutput::Material {
+                id: MaterialId::STEEL_INGOT,
+                count: 1,
+            },
+            workstation: Some(WorkstationType::Furnace),
+            craft_time: 8.0,
+        });
+
+        // === ALCHEMY ===
+
+        // Nitro: 2 oil + 1 acid
+        self.register(Recipe {
+            id: 400,
+            name: "Nitro".to_string(),
+            inputs: vec![(MaterialId::OIL, 2), (MaterialId::ACID, 1)],
+            output: RecipeOutput::Material {
+                id: MaterialId::NITRO,
+                count: 2,
+            },
+            workstation: Some(WorkstationType::Alchemy),
+            craft_time: 0.0,
+        });
+
+        // === FLUID HANDLING ===
+
+        // Pipe: 1 copper ingot
+        self.register(Recipe {
+            id: 500,
+            name: "Pipe".to_string(),
+            inputs: vec![(MaterialId::COPPER_INGOT, 1)],
+            output: RecipeOutput::Material {
+                id: MaterialId::PIPE,
+                count: 8,
+            },
+            workstation: Some(WorkstationType::Anvil),
+            craft_time: 0.0,
+        });
+
+        // Pump: 2 iron ingot + 1 copper ingot + 2 wire
+        self.register(Recipe {
+            id: 501,
+            name: "Pump".to_string(),
+            inputs: vec![
+                (MaterialId::IRON_INGOT, 2),
+                (MaterialId::COPPER_INGOT, 1),
+                (MaterialId::WIRE, 2),
+            ],
+            output: RecipeOutput::Material {
+                id: MaterialId::PUMP,
+                count: 1,
+            },
+            workstation: Some(WorkstationType::Anvil),
+            craft_time: 0.0,
         });
     }
 
@@ -119,14 +485,27 @@ impl RecipeRegistry {
         &self.recipes
     }
 
-    /// Get all craftable recipes (player has materials)
-    pub fn get_craftable<'a>(&'a self, inventory: &Inventory) -> Vec<&'a Recipe> {
+    /// Get all craftable recipes (player has materials and required workstation is in reach)
+    pub fn get_craftable<'a>(
+        &'a self,
+        inventory: &Inventory,
+        workstations: &[WorkstationType],
+    ) -> Vec<&'a Recipe> {
         self.recipes
             .iter()
-            .filter(|recipe| self.can_craft(recipe, inventory))
+            .filter(|recipe| {
+                self.has_workstation(recipe, workstations) && self.can_craft(recipe, inventory)
+            })
             .collect()
     }
 
+    /// Check if the recipe's workstation (if any) is among the available ones
+    pub fn has_workstation(&self, recipe: &Recipe, workstations: &[WorkstationType]) -> bool {
+        recipe
+            .workstation
+            .is_none_or(|required| workstations.contains(&required))
+    }
+
     /// Check if player can craft a recipe
     pub fn can_craft(&self, recipe: &Recipe, inventory: &Inventory) -> bool {
         recipe
@@ -179,7 +558,7 @@ mod tests {
     #[test]
     fn test_recipe_registry_creation() {
         let registry = RecipeRegistry::new();
-        assert_eq!(registry.all_recipes().len(), 5); // 3 tools + 2 materials
+        assert_eq!(registry.all_recipes().len(), 27); // 12 tools + 2 containers + 2 materials + 3 workstations + 5 smelting + 1 alchemy + 2 fluid handling
     }
 
     #[test]
@@ -270,14 +649,49 @@ mod tests {
         let mut inv = Inventory::new(50);
 
         // Empty inventory - no recipes craftable
-        assert_eq!(registry.get_craftable(&inv).len(), 0);
+        assert_eq!(registry.get_craftable(&inv, &[]).len(), 0);
 
-        // Add wood - wood pickaxe craftable
+        // Add wood - wood pickaxe, axe, shovel and sword craftable
         inv.add_item(MaterialId::WOOD, 10);
-        assert_eq!(registry.get_craftable(&inv).len(), 1);
+        assert_eq!(registry.get_craftable(&inv, &[]).len(), 4);
 
-        // Add stone - stone pickaxe also craftable
+        // Add stone - stone tier tools also craftable
         inv.add_item(MaterialId::STONE, 5);
-        assert_eq!(registry.get_craftable(&inv).len(), 2);
+        assert_eq!(registry.get_craftable(&inv, &[]).len(), 8);
+
+        // Iron tools and the bucket need an anvil
+        inv.add_item(MaterialId::IRON_INGOT, 4);
+        assert_eq!(registry.get_craftable(&inv, &[]).len(), 8);
+        assert_eq!(
+            registry
+                .get_craftable(&inv, &[WorkstationType::Anvil])
+                .len(),
+            13
+        );
+    }
+
+    #[test]
+    fn test_get_craftable_requires_workstation() {
+        let registry = RecipeRegistry::new();
+        let mut inv = Inventory::new(50);
+        inv.add_item(MaterialId::IRON_ORE, 4);
+
+        // Smelting needs a furnace in reach
+        assert!(registry.get_craftable(&inv, &[]).is_empty());
+        let craftable = registry.get_craftable(&inv, &[WorkstationType::Furnace]);
+        assert_eq!(craftable.len(), 1);
+        assert_eq!(craftable[0].name, "Smelt Iron");
+        assert!(craftable[0].craft_time > 0.0);
+    }
+
+    #[test]
+    fn test_workstation_materials_roundtrip() {
+        for kind in WorkstationType::ALL {
+            assert_eq!(
+                WorkstationType::from_material(kind.material_id()),
+                Some(kind)
+            );
+        }
+        assert_eq!(WorkstationType::from_material(MaterialId::STONE), None);
     }
 }
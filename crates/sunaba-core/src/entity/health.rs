@@ -152,6 +152,72 @@ impl Default for Hunger {
     }
 }
 
+/// Breath component for entities
+/// Air drains while submerged and causes drowning damage when depleted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Breath {
+    pub current: f32,
+    pub max: f32,
+    pub drain_rate: f32,      // Units per second while submerged
+    pub recovery_rate: f32,   // Units per second while breathing
+    pub drowning_damage: f32, // Damage per second when out of air
+}
+
+impl Breath {
+    /// Create a new breath component
+    pub fn new(max: f32, drain_rate: f32, recovery_rate: f32, drowning_damage: f32) -> Self {
+        Breath {
+            current: max,
+            max,
+            drain_rate,
+            recovery_rate,
+            drowning_damage,
+        }
+    }
+
+    /// Update breath (called each frame)
+    /// Returns drowning damage to apply this frame (0.0 if not drowning)
+    pub fn update(&mut self, delta_time: f32, submerged: bool) -> f32 {
+        if !submerged {
+            self.current = (self.current + self.recovery_rate * delta_time).min(self.max);
+            return 0.0;
+        }
+
+        self.current = (self.current - self.drain_rate * delta_time).max(0.0);
+        if self.is_drowning() {
+            self.drowning_damage * delta_time
+        } else {
+            0.0
+        }
+    }
+
+    /// Check if the entity is out of air
+    pub fn is_drowning(&self) -> bool {
+        self.current <= 0.0
+    }
+
+    /// Check if the entity has full air
+    pub fn is_full(&self) -> bool {
+        self.current >= self.max
+    }
+
+    /// Get breath as a percentage (0.0 - 1.0)
+    pub fn percentage(&self) -> f32 {
+        if self.max <= 0.0 {
+            0.0
+        } else {
+            (self.current / self.max).clamp(0.0, 1.0)
+        }
+    }
+}
+
+impl Default for Breath {
+    fn default() -> Self {
+        // Default: 100 max, 10s of air, refills in 2s, 10.0/sec drowning damage
+        Self::new(100.0, 10.0, 50.0, 10.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,4 +326,31 @@ mod tests {
         hunger.update(50.0);
         assert_eq!(hunger.percentage(), 0.0);
     }
+
+    #[test]
+    fn test_breath_drains_and_recovers() {
+        let mut breath = Breath::new(100.0, 10.0, 50.0, 10.0);
+
+        // Breathing at full air does nothing
+        assert_eq!(breath.update(1.0, false), 0.0);
+        assert!(breath.is_full());
+
+        // Submerged for 5 seconds: half the air is gone, no damage yet
+        assert_eq!(breath.update(5.0, true), 0.0);
+        assert_eq!(breath.percentage(), 0.5);
+
+        // Surfacing refills air
+        breath.update(0.5, false);
+        assert_eq!(breath.current, 75.0);
+    }
+
+    #[test]
+    fn test_breath_drowning_damage() {
+        let mut breath = Breath::new(100.0, 10.0, 50.0, 10.0);
+        breath.update(10.0, true);
+        assert!(breath.is_drowning());
+
+        let damage = breath.update(0.5, true);
+        assert_eq!(damage, 5.0);
+    }
 }
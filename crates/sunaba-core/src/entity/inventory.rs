@@ -1,12 +1,19 @@
+use crate::entity::containers::ContainerType;
 use serde::{Deserialize, Serialize};
 
-/// A stack of items in an inventory slot (can be materials or tools)
+/// A stack of items in an inventory slot (can be materials, tools or containers)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ItemStack {
     /// Stackable material with count
     Material { material_id: u16, count: u32 },
     /// Non-stackable tool with durability
     Tool { tool_id: u16, durability: u32 },
+    /// Non-stackable container holding up to `capacity` pixels of one fluid (amount 0 = empty)
+    Container {
+        container: ContainerType,
+        material_id: u16,
+        amount: u32,
+    },
 }
 
 impl ItemStack {
@@ -23,11 +30,20 @@ impl ItemStack {
         }
     }
 
+    /// Create a new, empty container
+    pub fn new_container(container: ContainerType) -> Self {
+        ItemStack::Container {
+            container,
+            material_id: 0,
+            amount: 0,
+        }
+    }
+
     /// Get the material ID if this is a material stack
     pub fn material_id(&self) -> Option<u16> {
         match self {
             ItemStack::Material { material_id, .. } => Some(*material_id),
-            ItemStack::Tool { .. } => None,
+            ItemStack::Tool { .. } | ItemStack::Container { .. } => None,
         }
     }
 
@@ -35,15 +51,77 @@ impl ItemStack {
     pub fn tool_id(&self) -> Option<u16> {
         match self {
             ItemStack::Tool { tool_id, .. } => Some(*tool_id),
-            ItemStack::Material { .. } => None,
+            ItemStack::Material { .. } | ItemStack::Container { .. } => None,
+        }
+    }
+
+    /// Get the container type if this is a container
+    pub fn container(&self) -> Option<ContainerType> {
+        match self {
+            ItemStack::Container { container, .. } => Some(*container),
+            ItemStack::Material { .. } | ItemStack::Tool { .. } => None,
+        }
+    }
+
+    /// Get the fluid held by a container as (material_id, amount), None if empty
+    pub fn contents(&self) -> Option<(u16, u32)> {
+        match self {
+            ItemStack::Container {
+                material_id,
+                amount,
+                ..
+            } if *amount > 0 => Some((*material_id, *amount)),
+            _ => None,
+        }
+    }
+
+    /// Pour fluid into a container, returns the amount accepted
+    /// A container only ever holds one fluid, so other materials are rejected
+    pub fn fill(&mut self, fluid_id: u16, fluid_amount: u32) -> u32 {
+        match self {
+            ItemStack::Container {
+                container,
+                material_id,
+                amount,
+            } => {
+                if *amount > 0 && *material_id != fluid_id {
+                    return 0;
+                }
+                let accepted = fluid_amount.min(container.capacity() - *amount);
+                if accepted > 0 {
+                    *material_id = fluid_id;
+                    *amount += accepted;
+                }
+                accepted
+            }
+            ItemStack::Material { .. } | ItemStack::Tool { .. } => 0,
         }
     }
 
-    /// Get the count (materials) or 1 (tools)
+    /// Take fluid out of a container, returns the amount drained
+    pub fn drain(&mut self, drain_amount: u32) -> u32 {
+        match self {
+            ItemStack::Container {
+                material_id,
+                amount,
+                ..
+            } => {
+                let drained = drain_amount.min(*amount);
+                *amount -= drained;
+                if *amount == 0 {
+                    *material_id = 0;
+                }
+                drained
+            }
+            ItemStack::Material { .. } | ItemStack::Tool { .. } => 0,
+        }
+    }
+
+    /// Get the count (materials) or 1 (tools and containers)
     pub fn count(&self) -> u32 {
         match self {
             ItemStack::Material { count, .. } => *count,
-            ItemStack::Tool { .. } => 1, // Tools don't stack
+            ItemStack::Tool { .. } | ItemStack::Container { .. } => 1, // Don't stack
         }
     }
 
@@ -51,7 +129,7 @@ impl ItemStack {
     pub fn max_stack_size(&self) -> u32 {
         match self {
             ItemStack::Material { .. } => 999, // Materials stack to 999
-            ItemStack::Tool { .. } => 1,       // Tools don't stack
+            ItemStack::Tool { .. } | ItemStack::Container { .. } => 1, // Don't stack
         }
     }
 
@@ -59,7 +137,7 @@ impl ItemStack {
     pub fn can_add(&self, amount: u32) -> bool {
         match self {
             ItemStack::Material { count, .. } => count + amount <= 999,
-            ItemStack::Tool { .. } => false, // Tools never stack
+            ItemStack::Tool { .. } | ItemStack::Container { .. } => false, // Never stack
         }
     }
 
@@ -72,7 +150,7 @@ impl ItemStack {
                 *count += to_add;
                 amount - to_add
             }
-            ItemStack::Tool { .. } => amount, // Can't add to tools
+            ItemStack::Tool { .. } | ItemStack::Container { .. } => amount, // Can't add to these
         }
     }
 
@@ -84,9 +162,9 @@ impl ItemStack {
                 *count -= to_remove;
                 to_remove
             }
-            ItemStack::Tool { .. } => {
+            ItemStack::Tool { .. } | ItemStack::Container { .. } => {
                 if amount > 0 {
-                    1 // Removing a tool removes the whole thing
+                    1 // Removing a tool or container removes the whole thing
                 } else {
                     0
                 }
@@ -99,6 +177,7 @@ impl ItemStack {
         match self {
             ItemStack::Material { count, .. } => *count == 0,
             ItemStack::Tool { durability, .. } => *durability == 0, // Broken tools are "empty"
+            ItemStack::Container { .. } => false, // An empty container is still an item
         }
     }
 
@@ -106,7 +185,7 @@ impl ItemStack {
     pub fn is_full(&self) -> bool {
         match self {
             ItemStack::Material { count, .. } => *count >= 999,
-            ItemStack::Tool { .. } => true, // Tools are always "full" (don't stack)
+            ItemStack::Tool { .. } | ItemStack::Container { .. } => true, // Don't stack
         }
     }
 
@@ -117,7 +196,7 @@ impl ItemStack {
                 *durability = durability.saturating_sub(damage);
                 *durability == 0
             }
-            ItemStack::Material { .. } => false,
+            ItemStack::Material { .. } | ItemStack::Container { .. } => false,
         }
     }
 }
@@ -184,6 +263,18 @@ impl Inventory {
         }
     }
 
+    /// Try to add an empty container to the inventory
+    /// Returns true if successful, false if no empty slot
+    pub fn add_container(&mut self, container: ContainerType) -> bool {
+        match self.find_empty_slot() {
+            Some(index) => {
+                self.slots[index] = Some(ItemStack::new_container(container));
+                true
+            }
+            None => false, // No empty slots
+        }
+    }
+
     /// Try to remove a material from the inventory
     /// Returns the amount actually removed
     pub fn remove_item(&mut self, material_id: u16, mut amount: u32) -> u32 {
@@ -372,6 +463,26 @@ mod tests {
         assert!(tool.is_empty());
     }
 
+    #[test]
+    fn test_container_stack() {
+        let mut bucket = ItemStack::new_container(ContainerType::Bucket);
+        assert_eq!(bucket.container(), Some(ContainerType::Bucket));
+        assert_eq!(bucket.contents(), None);
+        assert!(!bucket.is_empty()); // Empty containers stay in their slot
+
+        // Fills up to capacity with a single fluid
+        let capacity = ContainerType::Bucket.capacity();
+        assert_eq!(bucket.fill(3, 10), 10);
+        assert_eq!(bucket.fill(9, 5), 0); // Different fluid is rejected
+        assert_eq!(bucket.fill(3, capacity), capacity - 10);
+        assert_eq!(bucket.contents(), Some((3, capacity)));
+
+        // Draining everything resets the fluid
+        assert_eq!(bucket.drain(capacity + 5), capacity);
+        assert_eq!(bucket.contents(), None);
+        assert_eq!(bucket.fill(9, 5), 5);
+    }
+
     #[test]
     fn test_inventory_add_single() {
         let mut inv = Inventory::new(10);
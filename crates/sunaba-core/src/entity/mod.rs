@@ -1,8 +1,10 @@
+pub mod containers;
 pub mod crafting;
 pub mod health;
 pub mod input;
 pub mod inventory;
 pub mod player;
+pub mod status_effects;
 pub mod tools;
 
 pub use input::InputState;
@@ -11,7 +13,10 @@ pub use input::InputState;
 pub use sunaba_creature::EntityId;
 
 // Keep Health and Hunger local (player uses them differently than creatures)
-pub use health::{Health, Hunger};
+pub use health::{Breath, Health, Hunger};
+pub use status_effects::{
+    EnvironmentContact, StatusEffect, StatusEffectKind, StatusEffects, Submersion,
+};
 
 #[cfg(test)]
 mod tests {
@@ -3,8 +3,10 @@ use serde::{Deserialize, Serialize};
 
 use super::{
     EntityId,
-    health::{Health, Hunger},
+    containers::ContainerType,
+    health::{Breath, Health, Hunger},
     inventory::Inventory,
+    status_effects::{EnvironmentContact, StatusEffectKind, StatusEffects, Submersion},
 };
 use crate::simulation::mining::MiningProgress;
 use sunaba_simulation::MaterialId;
@@ -43,6 +45,25 @@ pub struct Player {
 
     #[serde(skip)]
     pub air_dash_used: bool, // Has air dash been used this jump?
+
+    /// Environmental state (resets on load)
+    #[serde(skip)]
+    pub breath: Breath,
+
+    #[serde(skip)]
+    pub status_effects: StatusEffects,
+
+    /// Liquid around the player, sampled each frame for swimming physics
+    #[serde(skip)]
+    pub submersion: Submersion,
+
+    #[serde(skip)]
+    pub attack_cooldown: f32, // Cooldown until next melee swing (0.4s → 0.0)
+
+    /// Direction (0-3 = up/right/down/left) given to placed pixels, so pumps
+    /// and logic components can face any way
+    #[serde(skip)]
+    pub placement_direction: u8,
 }
 
 impl Player {
@@ -63,6 +84,27 @@ impl Player {
     pub const DASH_DURATION: f32 = 0.15; // 0.15s = 9 frames at 60fps
     pub const DASH_COOLDOWN: f32 = 0.5; // 0.5s between dashes
 
+    // Swimming
+    pub const DENSITY: f32 = 1.1; // Slightly denser than water - sinks slowly when idle
+    pub const SWIM_THRUST: f32 = 1000.0; // px/s² (W/jump up, S down)
+    pub const SWIM_SPEED_FACTOR: f32 = 0.6; // Horizontal speed multiplier when fully submerged
+    pub const WATER_DRAG: f32 = 3.0; // Velocity damping per second in thin liquids
+    pub const MAX_SWIM_SPEED: f32 = 150.0; // px/s
+
+    // Environmental damage
+    pub const FALL_DAMAGE_SPEED: f32 = 380.0; // Impact speed (px/s) where fall damage starts
+    pub const FALL_DAMAGE_PER_SPEED: f32 = 0.25; // Damage per px/s above threshold
+    pub const HEAT_DAMAGE_TEMPERATURE: f32 = 60.0; // °C
+    pub const HEAT_DAMAGE_PER_DEGREE: f32 = 0.1; // Damage per second per °C above threshold
+    pub const BURN_TEMPERATURE: f32 = 150.0; // °C - catches fire above this
+    pub const BURN_DURATION: f32 = 3.0; // seconds
+    pub const BURN_DAMAGE: f32 = 8.0; // per second
+    pub const POISON_DURATION: f32 = 5.0; // seconds
+    pub const OXYGEN_USE: f32 = 0.05; // Breathing units of oxygen per second
+
+    // Melee combat
+    pub const ATTACK_COOLDOWN: f32 = 0.4; // seconds between swings
+
     /// Create a new player at the specified position
     pub fn new(position: Vec2) -> Self {
         let mut player = Player {
@@ -72,7 +114,7 @@ impl Player {
             grounded: false, // Start in air
             coyote_time: 0.0,
             jump_buffer: 0.0,
-            inventory: Inventory::new(50), // 50 slots
+            inventory: Self::starting_inventory(),
             health: Health::new(100.0),
             hunger: Hunger::new(100.0, 0.1, 1.0), // Drain 0.1/sec, 1.0 dmg/sec when starving
             selected_slot: 0,
@@ -84,17 +126,26 @@ impl Player {
             dash_timer: 0.0,
             dash_cooldown: 0.0,
             air_dash_used: false,
+            breath: Breath::default(),
+            status_effects: StatusEffects::new(),
+            submersion: Submersion::default(),
+            attack_cooldown: 0.0,
+            placement_direction: 0,
         };
 
-        // Give player some starting materials for testing
-        player.inventory.add_item(MaterialId::SAND, 1000);
-        player.inventory.add_item(MaterialId::WATER, 1000);
-        player.inventory.add_item(MaterialId::WOOD, 1000);
-        player.inventory.add_item(MaterialId::FIRE, 1000);
-
         player
     }
 
+    /// Inventory a new player starts with (50 slots, some testing materials)
+    pub fn starting_inventory() -> Inventory {
+        let mut inventory = Inventory::new(50);
+        inventory.add_item(MaterialId::SAND, 1000);
+        inventory.add_item(MaterialId::WATER, 1000);
+        inventory.add_item(MaterialId::WOOD, 1000);
+        inventory.add_item(MaterialId::FIRE, 1000);
+        inventory
+    }
+
     /// Create a player from existing data (for deserialization)
     #[allow(clippy::too_many_arguments)]
     pub fn from_data(
@@ -128,12 +179,19 @@ impl Player {
             dash_timer: 0.0,               // Runtime dash state
             dash_cooldown: 0.0,            // Runtime dash state
             air_dash_used: false,          // Runtime dash state
+            breath: Breath::default(),     // Runtime environmental state
+            status_effects: StatusEffects::new(),
+            submersion: Submersion::default(),
+            attack_cooldown: 0.0,
+            placement_direction: 0,
         }
     }
 
     /// Update player state (hunger, health, etc.)
     /// Returns true if the player died this frame
     pub fn update(&mut self, delta_time: f32) -> bool {
+        self.attack_cooldown = (self.attack_cooldown - delta_time).max(0.0);
+
         // Update hunger and get starvation damage
         let starvation_damage = self.hunger.update(delta_time);
 
@@ -151,6 +209,53 @@ impl Player {
         false
     }
 
+    /// Apply environmental hazards for one frame (breath, heat, status effects)
+    /// Damage is applied to health; death is detected by the next `update`
+    pub fn apply_environment(&mut self, contact: &EnvironmentContact, dt: f32) {
+        self.submersion = contact.submersion;
+
+        let mut damage = self
+            .breath
+            .update(dt, contact.head_submerged || contact.suffocating);
+
+        // Direct heat damage
+        if contact.max_temperature > Self::HEAT_DAMAGE_TEMPERATURE {
+            damage += (contact.max_temperature - Self::HEAT_DAMAGE_TEMPERATURE)
+                * Self::HEAT_DAMAGE_PER_DEGREE
+                * dt;
+        }
+
+        // Catch fire from flames or hot materials, liquids put it out
+        if contact.touching_fire || contact.max_temperature >= Self::BURN_TEMPERATURE {
+            self.status_effects.apply(
+                StatusEffectKind::Burning,
+                Self::BURN_DURATION,
+                Self::BURN_DAMAGE,
+            );
+        } else if contact.submersion.fraction >= 0.5 {
+            self.status_effects.remove(StatusEffectKind::Burning);
+        }
+
+        if contact.toxicity > 0.0 {
+            self.status_effects.apply(
+                StatusEffectKind::Poisoned,
+                Self::POISON_DURATION,
+                contact.toxicity,
+            );
+        }
+
+        damage += self.status_effects.update(dt);
+
+        if damage > 0.0 {
+            self.health.take_damage(damage);
+        }
+    }
+
+    /// Damage from hitting the ground at the given downward speed (px/s)
+    pub fn fall_damage(impact_speed: f32) -> f32 {
+        (impact_speed - Self::FALL_DAMAGE_SPEED).max(0.0) * Self::FALL_DAMAGE_PER_SPEED
+    }
+
     /// Update mining progress
     /// Returns true if mining completed this frame
     pub fn update_mining(&mut self, delta_time: f32) -> bool {
@@ -190,6 +295,14 @@ impl Player {
             .and_then(|stack| stack.material_id())
     }
 
+    /// Get the container in the currently selected hotbar slot
+    pub fn get_selected_container(&self) -> Option<ContainerType> {
+        self.inventory
+            .get_slot(self.selected_slot)
+            .and_then(|slot| slot.as_ref())
+            .and_then(|stack| stack.container())
+    }
+
     /// Select the next inventory slot (cycles through 0-9 for hotbar)
     pub fn select_next_slot(&mut self) {
         self.selected_slot = (self.selected_slot + 1) % 10;
@@ -214,6 +327,11 @@ impl Player {
         self.equipped_tool = Some(tool_id);
     }
 
+    /// Turn the direction of placed pixels a quarter turn clockwise
+    pub fn rotate_placement(&mut self) {
+        self.placement_direction = (self.placement_direction + 1) % 4;
+    }
+
     /// Unequip the currently equipped tool
     pub fn unequip_tool(&mut self) {
         self.equipped_tool = None;
@@ -263,6 +381,8 @@ impl Player {
         self.velocity = Vec2::ZERO;
         self.health = Health::new(100.0);
         self.hunger = Hunger::new(100.0, 0.1, 1.0);
+        self.breath = Breath::default();
+        self.status_effects.clear();
         self.is_dead = false; // Clear death flag
         // Keep inventory on respawn (optional: can clear if you want)
     }
@@ -520,4 +640,72 @@ mod tests {
         player.reset_air_dash();
         assert!(player.can_dash(false));
     }
+
+    #[test]
+    fn test_fall_damage_threshold() {
+        assert_eq!(Player::fall_damage(300.0), 0.0);
+        assert_eq!(Player::fall_damage(Player::FALL_DAMAGE_SPEED), 0.0);
+        assert!(Player::fall_damage(Player::MAX_FALL_SPEED) > 0.0);
+    }
+
+    #[test]
+    fn test_player_drowns_when_submerged() {
+        let mut player = Player::new(Vec2::ZERO);
+        let underwater = EnvironmentContact {
+            head_submerged: true,
+            max_temperature: 20.0,
+            ..Default::default()
+        };
+
+        // Plenty of air at first
+        player.apply_environment(&underwater, 5.0);
+        assert_eq!(player.health.current, 100.0);
+
+        // Out of air: drowning damage
+        player.apply_environment(&underwater, 10.0);
+        player.apply_environment(&underwater, 1.0);
+        assert!(player.health.current < 100.0);
+    }
+
+    #[test]
+    fn test_player_burning_and_extinguish() {
+        let mut player = Player::new(Vec2::ZERO);
+        let fire = EnvironmentContact {
+            touching_fire: true,
+            max_temperature: 20.0,
+            ..Default::default()
+        };
+        player.apply_environment(&fire, 0.1);
+        assert!(player.status_effects.has(StatusEffectKind::Burning));
+        assert!(player.health.current < 100.0);
+
+        let water = EnvironmentContact {
+            submersion: Submersion {
+                fraction: 1.0,
+                density: 1.0,
+                viscosity: 0.1,
+            },
+            max_temperature: 20.0,
+            ..Default::default()
+        };
+        player.apply_environment(&water, 0.1);
+        assert!(!player.status_effects.has(StatusEffectKind::Burning));
+    }
+
+    #[test]
+    fn test_player_poisoned_by_toxic_contact() {
+        let mut player = Player::new(Vec2::ZERO);
+        let gas = EnvironmentContact {
+            toxicity: 5.0,
+            max_temperature: 20.0,
+            ..Default::default()
+        };
+        player.apply_environment(&gas, 0.1);
+        assert!(player.status_effects.has(StatusEffectKind::Poisoned));
+
+        // Poison keeps hurting after leaving the gas
+        let health = player.health.current;
+        player.apply_environment(&EnvironmentContact::default(), 1.0);
+        assert!(player.health.current < health);
+    }
 }
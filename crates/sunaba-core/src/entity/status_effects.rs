@@ -0,0 +1,174 @@
+//! Timed status effects (burning, poisoned) and environmental contact data
+
+use serde::{Deserialize, Serialize};
+
+/// Kind of status effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StatusEffectKind {
+    /// On fire - from flames or very hot materials, put out by liquids
+    Burning,
+    /// Poisoned - from toxic materials such as poison gas or acid
+    Poisoned,
+}
+
+impl StatusEffectKind {
+    /// Display name for the HUD
+    pub fn label(&self) -> &'static str {
+        match self {
+            StatusEffectKind::Burning => "Burning",
+            StatusEffectKind::Poisoned => "Poisoned",
+        }
+    }
+}
+
+/// An active status effect
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StatusEffect {
+    pub kind: StatusEffectKind,
+    /// Seconds until the effect wears off
+    pub remaining: f32,
+    /// Damage dealt per second while active
+    pub damage_per_second: f32,
+}
+
+/// Set of active status effects (at most one per kind)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply an effect, refreshing duration and strength if it's already active
+    pub fn apply(&mut self, kind: StatusEffectKind, duration: f32, damage_per_second: f32) {
+        if let Some(effect) = self.effects.iter_mut().find(|e| e.kind == kind) {
+            effect.remaining = effect.remaining.max(duration);
+            effect.damage_per_second = effect.damage_per_second.max(damage_per_second);
+        } else {
+            self.effects.push(StatusEffect {
+                kind,
+                remaining: duration,
+                damage_per_second,
+            });
+        }
+    }
+
+    /// Remove an effect immediately
+    pub fn remove(&mut self, kind: StatusEffectKind) {
+        self.effects.retain(|e| e.kind != kind);
+    }
+
+    /// Remove all effects
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+
+    /// Check if an effect is active
+    pub fn has(&self, kind: StatusEffectKind) -> bool {
+        self.effects.iter().any(|e| e.kind == kind)
+    }
+
+    /// Get an active effect by kind
+    pub fn get(&self, kind: StatusEffectKind) -> Option<&StatusEffect> {
+        self.effects.iter().find(|e| e.kind == kind)
+    }
+
+    /// Iterate over active effects
+    pub fn iter(&self) -> impl Iterator<Item = &StatusEffect> {
+        self.effects.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Tick durations (called each frame)
+    /// Returns total damage to apply this frame
+    pub fn update(&mut self, delta_time: f32) -> f32 {
+        let mut damage = 0.0;
+        for effect in &mut self.effects {
+            let active = effect.remaining.min(delta_time);
+            damage += effect.damage_per_second * active;
+            effect.remaining -= delta_time;
+        }
+        self.effects.retain(|e| e.remaining > 0.0);
+        damage
+    }
+}
+
+/// How deep an entity is in liquid, used for swimming physics
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Submersion {
+    /// Fraction of the body inside liquid (0.0 - 1.0)
+    pub fraction: f32,
+    /// Average density of the surrounding liquid
+    pub density: f32,
+    /// Average viscosity of the surrounding liquid
+    pub viscosity: f32,
+}
+
+impl Submersion {
+    pub fn is_submerged(&self) -> bool {
+        self.fraction > 0.0
+    }
+}
+
+/// What an entity is touching this frame, sampled from the world
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EnvironmentContact {
+    pub submersion: Submersion,
+    /// Whether the head is inside liquid (can't breathe)
+    pub head_submerged: bool,
+    /// Whether the air at the head is too thin in oxygen to breathe
+    pub suffocating: bool,
+    /// Hottest temperature in contact with the body (°C)
+    pub max_temperature: f32,
+    /// Touching an open flame
+    pub touching_fire: bool,
+    /// Highest toxicity of any touched material (damage per second)
+    pub toxicity: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_refreshes_existing_effect() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffectKind::Burning, 2.0, 5.0);
+        effects.apply(StatusEffectKind::Burning, 1.0, 8.0);
+
+        assert_eq!(effects.iter().count(), 1);
+        let burning = effects.get(StatusEffectKind::Burning).unwrap();
+        assert_eq!(burning.remaining, 2.0);
+        assert_eq!(burning.damage_per_second, 8.0);
+    }
+
+    #[test]
+    fn test_effects_deal_damage_and_expire() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffectKind::Poisoned, 1.0, 4.0);
+
+        assert_eq!(effects.update(0.5), 2.0);
+        assert!(effects.has(StatusEffectKind::Poisoned));
+
+        // Only the remaining half second deals damage
+        assert_eq!(effects.update(2.0), 2.0);
+        assert!(effects.is_empty());
+    }
+
+    #[test]
+    fn test_remove_effect() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffectKind::Burning, 3.0, 5.0);
+        effects.apply(StatusEffectKind::Poisoned, 3.0, 5.0);
+        effects.remove(StatusEffectKind::Burning);
+
+        assert!(!effects.has(StatusEffectKind::Burning));
+        assert!(effects.has(StatusEffectKind::Poisoned));
+    }
+}
@@ -1,6 +1,6 @@
 //! Tool system for mining and crafting
 
-use crate::simulation::{MaterialDef, MaterialTag};
+use crate::simulation::{MaterialDef, MaterialTag, MaterialType};
 use serde::{Deserialize, Serialize};
 
 /// Tool types with different use cases
@@ -8,14 +8,28 @@ use serde::{Deserialize, Serialize};
 pub enum ToolType {
     /// Mining solid blocks and ores
     Pickaxe,
-    /// Harvesting wood/plants (future - Phase 8)
+    /// Felling trees - harvests all connected wood and plants at once
     Axe,
-    /// Combat (future - Phase 8)
+    /// Melee combat against creatures
     Sword,
-    /// Digging powder materials (future - Phase 8)
+    /// Digging powder materials in a wide brush
     Shovel,
 }
 
+impl ToolType {
+    /// Speed factor for a material tag this tool type is meant for
+    /// Returns None if the tag doesn't benefit from this tool type
+    pub fn tag_speed(&self, tag: MaterialTag) -> Option<f32> {
+        match (self, tag) {
+            (ToolType::Pickaxe, MaterialTag::Mineral | MaterialTag::Ore) => Some(1.0),
+            (ToolType::Axe, MaterialTag::Organic) => Some(1.5),
+            (ToolType::Shovel, MaterialTag::Mineral | MaterialTag::Organic) => Some(1.0),
+            (ToolType::Sword, MaterialTag::Organic) => Some(0.5),
+            _ => None,
+        }
+    }
+}
+
 /// Tool tier affects speed and durability
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ToolTier {
@@ -73,18 +87,50 @@ impl ToolDef {
     /// Calculate mining speed for a given material
     /// Returns multiplier (1.0 = base, 2.0 = 2x faster, 0.1 = wrong tool penalty)
     pub fn get_mining_speed(&self, material: &MaterialDef) -> f32 {
-        // Check if tool can harvest this material type
-        let can_harvest = material
+        // Best tag-specific speed among the tags this tool can harvest
+        let tag_speed = material
             .tags
             .iter()
-            .any(|tag| self.can_harvest.contains(tag));
+            .filter(|tag| self.can_harvest.contains(tag))
+            .filter_map(|tag| self.tool_type.tag_speed(*tag))
+            .reduce(f32::max);
 
-        if !can_harvest {
+        let Some(tag_speed) = tag_speed else {
             return 0.1; // 10x slower penalty for wrong tool
-        }
+        };
+
+        // Shovels only dig loose material, solid rock is barely scratched
+        let type_factor = if self.tool_type == ToolType::Shovel
+            && material.material_type != MaterialType::Powder
+        {
+            0.25
+        } else {
+            1.0
+        };
+
+        // Speed = tool_speed * tag_speed / material_hardness_multiplier
+        self.tier.speed_multiplier() * tag_speed * type_factor / material.hardness_multiplier
+    }
+
+    /// Melee damage dealt to creatures per hit
+    pub fn melee_damage(&self) -> f32 {
+        let base = match self.tool_type {
+            ToolType::Sword => 10.0,
+            ToolType::Axe => 6.0,
+            ToolType::Pickaxe => 4.0,
+            ToolType::Shovel => 3.0,
+        };
+        base * self.tier.speed_multiplier()
+    }
+
+    /// Brush radius (pixels) when digging with a shovel
+    pub fn dig_radius(&self) -> i32 {
+        (self.tier.speed_multiplier() * 2.0) as i32 + 1
+    }
 
-        // Speed = tool_speed / material_hardness_multiplier
-        self.tier.speed_multiplier() / material.hardness_multiplier
+    /// Maximum pixels an axe fells in one chop
+    pub fn fell_limit(&self) -> usize {
+        (128.0 * self.tier.speed_multiplier()) as usize
     }
 
     /// Get max durability for this tool
@@ -134,7 +180,74 @@ impl ToolRegistry {
             can_harvest: vec![MaterialTag::Mineral, MaterialTag::Ore],
         });
 
-        // Future (Phase 8): Add axes, shovels, swords for expanded gameplay
+        // Axes - fell whole trees (1010-1012)
+        self.register(ToolDef {
+            id: 1010,
+            name: "Wood Axe".to_string(),
+            tool_type: ToolType::Axe,
+            tier: ToolTier::Wood,
+            can_harvest: vec![MaterialTag::Organic],
+        });
+        self.register(ToolDef {
+            id: 1011,
+            name: "Stone Axe".to_string(),
+            tool_type: ToolType::Axe,
+            tier: ToolTier::Stone,
+            can_harvest: vec![MaterialTag::Organic],
+        });
+        self.register(ToolDef {
+            id: 1012,
+            name: "Iron Axe".to_string(),
+            tool_type: ToolType::Axe,
+            tier: ToolTier::Iron,
+            can_harvest: vec![MaterialTag::Organic],
+        });
+
+        // Shovels - dig powders in a wide brush (1020-1022)
+        self.register(ToolDef {
+            id: 1020,
+            name: "Wood Shovel".to_string(),
+            tool_type: ToolType::Shovel,
+            tier: ToolTier::Wood,
+            can_harvest: vec![MaterialTag::Mineral, MaterialTag::Organic],
+        });
+        self.register(ToolDef {
+            id: 1021,
+            name: "Stone Shovel".to_string(),
+            tool_type: ToolType::Shovel,
+            tier: ToolTier::Stone,
+            can_harvest: vec![MaterialTag::Mineral, MaterialTag::Organic],
+        });
+        self.register(ToolDef {
+            id: 1022,
+            name: "Iron Shovel".to_string(),
+            tool_type: ToolType::Shovel,
+            tier: ToolTier::Iron,
+            can_harvest: vec![MaterialTag::Mineral, MaterialTag::Organic],
+        });
+
+        // Swords - melee damage and knockback against creatures (1030-1032)
+        self.register(ToolDef {
+            id: 1030,
+            name: "Wood Sword".to_string(),
+            tool_type: ToolType::Sword,
+            tier: ToolTier::Wood,
+            can_harvest: vec![MaterialTag::Organic],
+        });
+        self.register(ToolDef {
+            id: 1031,
+            name: "Stone Sword".to_string(),
+            tool_type: ToolType::Sword,
+            tier: ToolTier::Stone,
+            can_harvest: vec![MaterialTag::Organic],
+        });
+        self.register(ToolDef {
+            id: 1032,
+            name: "Iron Sword".to_string(),
+            tool_type: ToolType::Sword,
+            tier: ToolTier::Iron,
+            can_harvest: vec![MaterialTag::Organic],
+        });
     }
 
     fn register(&mut self, tool: ToolDef) {
@@ -170,6 +283,11 @@ impl ToolRegistry {
     pub fn all_tools(&self) -> impl Iterator<Item = &ToolDef> {
         self.tools.iter().filter(|t| t.id != 0)
     }
+
+    /// Get a tool's display name ("Unknown Tool" for unregistered IDs)
+    pub fn name(&self, id: u16) -> &str {
+        self.get(id).map_or("Unknown Tool", |t| t.name.as_str())
+    }
 }
 
 impl Default for ToolRegistry {
@@ -181,7 +299,6 @@ impl Default for ToolRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::simulation::{MaterialDef, MaterialTag, MaterialType};
 
     fn make_test_material(hardness_mult: f32, tags: Vec<MaterialTag>) -> MaterialDef {
         MaterialDef {
@@ -216,6 +333,7 @@ mod tests {
             toxicity: None,
             structural_strength: None,
             fuel_value: None,
+            explosive_yield: None,
             tags,
         }
     }
@@ -273,8 +391,8 @@ mod tests {
     fn test_tool_registry() {
         let registry = ToolRegistry::new();
 
-        // Should have 3 pickaxes registered
-        assert_eq!(registry.all_tools().count(), 3);
+        // 3 tiers each of pickaxe, axe, shovel and sword
+        assert_eq!(registry.all_tools().count(), 12);
 
         // Get wood pickaxe
         let wood_pick = registry.get(1000);
@@ -294,5 +412,60 @@ mod tests {
         // Invalid ID
         assert!(registry.get(999).is_none());
         assert!(registry.get(2000).is_none());
+        assert!(registry.get(1005).is_none());
+        assert_eq!(registry.name(1005), "Unknown Tool");
+
+        // Every tool type has all three tiers
+        for tool_type in [
+            ToolType::Pickaxe,
+            ToolType::Axe,
+            ToolType::Shovel,
+            ToolType::Sword,
+        ] {
+            let tiers: Vec<ToolTier> = registry
+                .all_tools()
+                .filter(|t| t.tool_type == tool_type)
+                .map(|t| t.tier)
+                .collect();
+            assert_eq!(tiers, vec![ToolTier::Wood, ToolTier::Stone, ToolTier::Iron]);
+        }
+    }
+
+    #[test]
+    fn test_tool_type_speeds() {
+        let registry = ToolRegistry::new();
+        let wood = make_test_material(1.0, vec![MaterialTag::Organic]);
+        let stone = make_test_material(1.0, vec![MaterialTag::Mineral]);
+        let mut sand = make_test_material(1.0, vec![MaterialTag::Mineral]);
+        sand.material_type = MaterialType::Powder;
+
+        let axe = registry.get(1010).unwrap();
+        let shovel = registry.get(1020).unwrap();
+        let pickaxe = registry.get(1000).unwrap();
+
+        // Axe beats pickaxe on wood, pickaxe beats axe on stone
+        assert!(axe.get_mining_speed(&wood) > pickaxe.get_mining_speed(&wood));
+        assert!(pickaxe.get_mining_speed(&stone) > axe.get_mining_speed(&stone));
+
+        // Shovel is fast on powders, slow on solid rock
+        assert_eq!(shovel.get_mining_speed(&sand), 1.0);
+        assert!(shovel.get_mining_speed(&stone) < pickaxe.get_mining_speed(&stone));
+    }
+
+    #[test]
+    fn test_combat_and_digging_stats() {
+        let registry = ToolRegistry::new();
+        let wood_sword = registry.get(1030).unwrap();
+        let iron_sword = registry.get(1032).unwrap();
+        let pickaxe = registry.get(1000).unwrap();
+
+        assert!(iron_sword.melee_damage() > wood_sword.melee_damage());
+        assert!(wood_sword.melee_damage() > pickaxe.melee_damage());
+        assert!(
+            registry.get(1022).unwrap().dig_radius() > registry.get(1020).unwrap().dig_radius()
+        );
+        assert!(
+            registry.get(1012).unwrap().fell_limit() > registry.get(1010).unwrap().fell_limit()
+        );
     }
 }
@@ -213,9 +213,10 @@ impl LevelManager {
         );
     }
 
-    /// Load the current level
+    /// Load the current level (levels are calm: weather stays off)
     pub fn load_current_level(&self, world: &mut World) {
         let level = &self.levels[self.current_level];
+        world.set_weather_enabled(false);
         (level.generator)(world);
     }
 }
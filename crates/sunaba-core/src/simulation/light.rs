@@ -37,8 +37,15 @@ impl LightPropagation {
         // Clear all light levels in active chunks only
         self.reset_light_levels(chunks, active_chunks);
 
-        // Add sky light to surface pixels in active chunks
+        // Add sky light to surface pixels in active chunks and spread it on its own,
+        // so sunlight-gated reactions can tell it apart from torches and lava
         self.add_sky_light(chunks, sky_light, active_chunks);
+        self.flood_fill_light(chunks, materials);
+        for &pos in active_chunks {
+            if let Some(chunk) = chunks.get_mut(&pos) {
+                chunk.sky_light_levels = chunk.light_levels;
+            }
+        }
 
         // Add light sources (fire, lava) in active chunks
         self.add_light_sources(chunks, active_chunks);
@@ -415,6 +422,28 @@ mod tests {
         assert!(!chunks.get(&chunk_pos).unwrap().light_dirty);
     }
 
+    #[test]
+    fn test_sky_light_excludes_light_sources() {
+        let (mut chunks, materials) = setup_test_chunks();
+        let mut light = LightPropagation::new();
+
+        // Lava underground (chunk y=-1 is below the surface)
+        let cave = glam::IVec2::new(0, -1);
+        chunks
+            .get_mut(&cave)
+            .unwrap()
+            .set_material(10, 10, MaterialId::LAVA);
+        let active_chunks = vec![cave, glam::IVec2::new(0, 1)];
+        light.propagate_light(&mut chunks, &materials, 15, &active_chunks);
+
+        let cave_chunk = &chunks[&cave];
+        assert!(cave_chunk.get_light(11, 10) > 0, "Lava lights the cave");
+        assert_eq!(cave_chunk.get_sky_light(11, 10), 0);
+
+        let sky_chunk = &chunks[&glam::IVec2::new(0, 1)];
+        assert_eq!(sky_chunk.get_sky_light(5, 5), 15);
+    }
+
     #[test]
     fn test_light_constants() {
         assert_eq!(LIGHT_MAX, 15, "Max light should be 15");
@@ -139,6 +139,7 @@ mod tests {
             toxicity: None,
             structural_strength: None,
             fuel_value: None,
+            explosive_yield: None,
             tags,
         }
     }
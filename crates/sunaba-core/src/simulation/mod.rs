@@ -4,7 +4,6 @@ pub mod falling_chunks;
 pub mod knockback;
 pub mod light;
 pub mod mining;
-pub mod regeneration;
 pub mod state_changes;
 pub mod structural;
 pub mod temperature;
@@ -18,7 +17,6 @@ pub use sunaba_simulation::{
 
 pub use falling_chunks::{ChunkRenderData, FallingChunk, FallingChunkSystem, WorldCollisionQuery};
 pub use light::LightPropagation;
-pub use regeneration::RegenerationSystem;
 pub use state_changes::StateChangeSystem;
 pub use structural::StructuralIntegritySystem;
 pub use temperature::{TemperatureSimulator, add_heat_at_pixel, get_temperature_at_pixel};
@@ -1,234 +0,0 @@
-//! Resource regeneration system
-//!
-//! Handles periodic spawning of renewable resources like fruit from plant matter.
-
-use glam::IVec2;
-use std::collections::HashMap;
-
-#[cfg(feature = "regeneration")]
-use crate::simulation::MaterialId;
-#[cfg(feature = "regeneration")]
-use crate::world::{CHUNK_SIZE, Chunk, Pixel};
-#[cfg(feature = "regeneration")]
-use rand::Rng;
-
-#[cfg(not(feature = "regeneration"))]
-use crate::world::Chunk;
-
-/// Manages resource regeneration (fruit spawning, etc.)
-pub struct RegenerationSystem {
-    /// Time accumulator for throttling (5 second intervals)
-    #[cfg(feature = "regeneration")]
-    time_accumulator: f32,
-}
-
-impl RegenerationSystem {
-    pub fn new() -> Self {
-        Self {
-            #[cfg(feature = "regeneration")]
-            time_accumulator: 0.0,
-        }
-    }
-
-    /// Update regeneration system
-    /// Throttled to run every 5 seconds
-    /// Only processes active chunks
-    #[cfg(feature = "regeneration")]
-    pub fn update(&mut self, chunks: &mut HashMap<IVec2, Chunk>, active_chunks: &[IVec2], dt: f32) {
-        const REGENERATION_INTERVAL: f32 = 5.0; // Check every 5 seconds
-
-        self.time_accumulator += dt;
-
-        if self.time_accumulator < REGENERATION_INTERVAL {
-            return;
-        }
-
-        self.time_accumulator -= REGENERATION_INTERVAL;
-
-        // Process fruit spawning for each active chunk
-        for chunk_pos in active_chunks {
-            if let Some(chunk) = chunks.get_mut(chunk_pos) {
-                self.spawn_fruit_in_chunk(chunk);
-            }
-        }
-    }
-
-    /// Update regeneration system (disabled, no-op)
-    #[cfg(not(feature = "regeneration"))]
-    pub fn update(
-        &mut self,
-        _chunks: &mut HashMap<IVec2, Chunk>,
-        _active_chunks: &[IVec2],
-        _dt: f32,
-    ) {
-        // No-op when regeneration feature is disabled
-    }
-
-    /// Spawn fruit below plant matter pixels
-    #[cfg(feature = "regeneration")]
-    fn spawn_fruit_in_chunk(&self, chunk: &mut Chunk) {
-        const FRUIT_SPAWN_CHANCE: f32 = 0.05; // 5% chance per plant pixel per check
-        let mut rng = rand::thread_rng();
-
-        // Scan all pixels in chunk
-        for y in 0..CHUNK_SIZE {
-            for x in 0..CHUNK_SIZE {
-                let pixel = chunk.get_pixel(x, y);
-
-                // Check if this is plant matter
-                if pixel.material_id != MaterialId::PLANT_MATTER {
-                    continue;
-                }
-
-                // Roll for fruit spawning
-                if rng.r#gen::<f32>() > FRUIT_SPAWN_CHANCE {
-                    continue;
-                }
-
-                // Try to spawn fruit in air space below (check up to 3 pixels down)
-                for dy in 1..=3 {
-                    if y + dy >= CHUNK_SIZE {
-                        break; // Out of chunk bounds
-                    }
-
-                    let below = chunk.get_pixel(x, y + dy);
-
-                    // Found air - spawn fruit here
-                    if below.material_id == MaterialId::AIR {
-                        chunk.set_pixel(x, y + dy, Pixel::new(MaterialId::FRUIT));
-                        log::debug!(
-                            "[REGENERATION] Spawned fruit at chunk ({}, {}) pixel ({}, {})",
-                            chunk.x,
-                            chunk.y,
-                            x,
-                            y + dy
-                        );
-                        break; // Only spawn one fruit per plant pixel
-                    }
-
-                    // If we hit a non-air pixel, stop searching down
-                    if below.material_id != MaterialId::AIR {
-                        break;
-                    }
-                }
-            }
-        }
-    }
-}
-
-impl Default for RegenerationSystem {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::world::Chunk;
-
-    #[test]
-    fn test_regeneration_system_creation() {
-        let _system = RegenerationSystem::new();
-        // System should initialize successfully
-    }
-
-    #[test]
-    fn test_fruit_spawning() {
-        let mut chunk = Chunk::new(0, 0);
-        let system = RegenerationSystem::new();
-
-        // Set up a plant matter pixel with air below
-        chunk.set_pixel(10, 10, Pixel::new(MaterialId::PLANT_MATTER));
-        chunk.set_pixel(10, 11, Pixel::new(MaterialId::AIR));
-        chunk.set_pixel(10, 12, Pixel::new(MaterialId::AIR));
-
-        // Run regeneration multiple times (eventually fruit should spawn due to randomness)
-        let mut fruit_spawned = false;
-        for _ in 0..100 {
-            // 100 attempts should be enough with 5% chance
-            system.spawn_fruit_in_chunk(&mut chunk);
-
-            // Check if fruit spawned in any of the air spaces below
-            if chunk.get_pixel(10, 11).material_id == MaterialId::FRUIT
-                || chunk.get_pixel(10, 12).material_id == MaterialId::FRUIT
-            {
-                fruit_spawned = true;
-                break;
-            }
-        }
-
-        assert!(
-            fruit_spawned,
-            "Fruit should spawn eventually with 5% chance over 100 attempts"
-        );
-    }
-
-    #[test]
-    fn test_no_spawn_without_air() {
-        let mut chunk = Chunk::new(0, 0);
-        let system = RegenerationSystem::new();
-
-        // Set up plant matter with stone below (no air)
-        chunk.set_pixel(10, 10, Pixel::new(MaterialId::PLANT_MATTER));
-        chunk.set_pixel(10, 11, Pixel::new(MaterialId::STONE));
-        chunk.set_pixel(10, 12, Pixel::new(MaterialId::STONE));
-
-        // Run regeneration many times
-        for _ in 0..100 {
-            system.spawn_fruit_in_chunk(&mut chunk);
-        }
-
-        // Verify no fruit spawned
-        assert_eq!(chunk.get_pixel(10, 11).material_id, MaterialId::STONE);
-        assert_eq!(chunk.get_pixel(10, 12).material_id, MaterialId::STONE);
-    }
-
-    #[test]
-    #[cfg(feature = "regeneration")]
-    fn test_throttling() {
-        let mut chunks = HashMap::new();
-        let chunk_pos = IVec2::new(0, 0);
-        let mut chunk = Chunk::new(0, 0);
-        let mut system = RegenerationSystem::new();
-
-        // Set up plant with air
-        chunk.set_pixel(10, 10, Pixel::new(MaterialId::PLANT_MATTER));
-        chunk.set_pixel(10, 11, Pixel::new(MaterialId::AIR));
-        chunks.insert(chunk_pos, chunk);
-
-        // Update with small dt - should not process
-        system.update(&mut chunks, &[chunk_pos], 1.0);
-        assert!(system.time_accumulator > 0.0 && system.time_accumulator < 5.0);
-
-        // Update with large dt - should process and reset accumulator
-        system.update(&mut chunks, &[chunk_pos], 5.0);
-        assert!(system.time_accumulator < 5.0);
-    }
-
-    #[test]
-    fn test_spawn_in_nearest_air() {
-        let mut chunk = Chunk::new(0, 0);
-        let system = RegenerationSystem::new();
-
-        // Set up plant with first space blocked, second space air
-        chunk.set_pixel(10, 10, Pixel::new(MaterialId::PLANT_MATTER));
-        chunk.set_pixel(10, 11, Pixel::new(MaterialId::STONE));
-        chunk.set_pixel(10, 12, Pixel::new(MaterialId::AIR));
-
-        // Try spawning many times
-        for _ in 0..200 {
-            system.spawn_fruit_in_chunk(&mut chunk);
-
-            // If fruit spawned, it should be in the air space (10, 12)
-            if chunk.get_pixel(10, 12).material_id == MaterialId::FRUIT {
-                // Verify stone wasn't overwritten
-                assert_eq!(chunk.get_pixel(10, 11).material_id, MaterialId::STONE);
-                return; // Test passed
-            }
-        }
-
-        // If we get here, fruit didn't spawn in 200 attempts, which is very unlikely
-        // but we won't fail the test since it's probabilistic
-    }
-}
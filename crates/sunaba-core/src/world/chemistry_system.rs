@@ -184,6 +184,14 @@ impl ChemistrySystem {
         };
         let material = world.materials().get(pixel.material_id);
 
+        // Explosives don't burn - they detonate via the explosion system
+        if material.explosive_yield.is_some() {
+            if let Some(chunk) = world.chunks_mut().get_mut(&chunk_pos) {
+                chunk.detonations.push(IVec2::new(world_x, world_y));
+            }
+            return;
+        }
+
         // Probability check - material burns gradually
         if rng.check_probability(material.burn_rate) {
             // Transform to burns_to material with 80/20 probability split
@@ -215,12 +223,15 @@ impl ChemistrySystem {
 
     /// Check for chemical reactions with neighboring pixels
     /// Called during CA update for each pixel that moved
+    #[allow(clippy::too_many_arguments)]
     pub fn check_pixel_reactions<R: WorldRng>(
         chunks: &mut HashMap<IVec2, Chunk>,
         reactions: &ReactionRegistry,
+        materials: &Materials,
         chunk_pos: IVec2,
         x: usize,
         y: usize,
+        sunlight_only: bool,
         stats: &mut dyn SimStats,
         rng: &mut R,
     ) {
@@ -236,7 +247,11 @@ impl ChemistrySystem {
         }
 
         let temp = get_temperature_at_pixel(chunk, x, y);
-        let light_level = chunk.get_light(x, y);
+        let light_level = if sunlight_only {
+            chunk.get_sky_light(x, y)
+        } else {
+            chunk.get_light(x, y)
+        };
         let world_x = chunk_pos.x * CHUNK_SIZE as i32 + x as i32;
         let world_y = chunk_pos.y * CHUNK_SIZE as i32 + y as i32;
 
@@ -297,13 +312,31 @@ impl ChemistrySystem {
                     let (output_a, output_b) =
                         reactions.get_outputs(reaction, pixel.material_id, neighbor.material_id);
 
+                    // Explosives are left in place and queued for detonation.
+                    // Both are recorded on this chunk, the only one this CA pass owns.
+                    let pixel_explosive =
+                        materials.get(pixel.material_id).explosive_yield.is_some();
+                    let neighbor_explosive = materials
+                        .get(neighbor.material_id)
+                        .explosive_yield
+                        .is_some();
+
                     // Set pixel at current position
                     if let Some(chunk) = chunks.get_mut(&chunk_pos) {
-                        chunk.set_pixel(x, y, super::Pixel::new(output_a));
+                        if pixel_explosive {
+                            chunk.detonations.push(IVec2::new(world_x, world_y));
+                        } else {
+                            chunk.set_pixel(x, y, super::Pixel::new(output_a));
+                        }
+                        if neighbor_explosive {
+                            chunk.detonations.push(IVec2::new(neighbor_x, neighbor_y));
+                        }
                     }
 
                     // Set pixel at neighbor position
-                    if let Some(neighbor_chunk) = chunks.get_mut(&neighbor_chunk_pos) {
+                    if !neighbor_explosive
+                        && let Some(neighbor_chunk) = chunks.get_mut(&neighbor_chunk_pos)
+                    {
                         neighbor_chunk.set_pixel(
                             neighbor_local_x,
                             neighbor_local_y,
@@ -38,6 +38,20 @@ pub struct Chunk {
     #[serde(with = "serde_big_array::BigArray")]
     pub light_levels: [u8; CHUNK_AREA],
 
+    /// Oxygen per coarse cell (8x8 grid, 1.0 = fresh air, 0.0 = none)
+    /// Saved since layout version 2, chunks saved before load with fresh air
+    #[serde(default = "fresh_air", with = "serde_big_array::BigArray")]
+    pub oxygen: [f32; 64],
+
+    /// Carbon dioxide per coarse cell (8x8 grid, 0.0 = none), saved since layout version 2
+    #[serde(default = "no_carbon_dioxide", with = "serde_big_array::BigArray")]
+    pub carbon_dioxide: [f32; 64],
+
+    /// Share of the light levels that comes from the sky (not persisted)
+    /// Torches, fire and lava don't count, so it is 0 underground and at night
+    #[serde(skip, default = "dark")]
+    pub sky_light_levels: [u8; CHUNK_AREA],
+
     /// Whether light needs recalculation (not persisted)
     #[serde(skip)]
     pub light_dirty: bool,
@@ -55,6 +69,59 @@ pub struct Chunk {
     /// but we need to keep simulating chunks with active materials until they settle.
     #[serde(skip)]
     pub simulation_active: bool,
+
+    /// World positions of explosive pixels triggered this frame (not persisted)
+    /// Only written by the chunk's own CA pass, drained by the explosion system
+    #[serde(skip)]
+    pub detonations: Vec<glam::IVec2>,
+}
+
+fn fresh_air() -> [f32; 64] {
+    [1.0; 64]
+}
+
+fn no_carbon_dioxide() -> [f32; 64] {
+    [0.0; 64]
+}
+
+fn dark() -> [u8; CHUNK_AREA] {
+    [0; CHUNK_AREA]
+}
+
+/// Chunk layout version 1, before the gas levels were saved
+///
+/// Bincode can't leave out trailing fields, so version 1 blobs are decoded
+/// with this layout when the current one runs out of bytes.
+#[derive(Deserialize)]
+pub(crate) struct ChunkV1 {
+    x: i32,
+    y: i32,
+    #[serde(with = "serde_big_array::BigArray")]
+    pixels: [Pixel; CHUNK_AREA],
+    #[serde(with = "serde_big_array::BigArray")]
+    background: [u16; CHUNK_AREA],
+    #[serde(with = "serde_big_array::BigArray")]
+    temperature: [f32; 64],
+    #[serde(with = "serde_big_array::BigArray")]
+    pressure: [f32; 64],
+    #[serde(with = "serde_big_array::BigArray")]
+    electrical_potential: [f32; 64],
+    #[serde(with = "serde_big_array::BigArray")]
+    light_levels: [u8; CHUNK_AREA],
+}
+
+impl From<ChunkV1> for Chunk {
+    fn from(old: ChunkV1) -> Self {
+        Self {
+            pixels: old.pixels,
+            background: old.background,
+            temperature: old.temperature,
+            pressure: old.pressure,
+            electrical_potential: old.electrical_potential,
+            light_levels: old.light_levels,
+            ..Chunk::new(old.x, old.y)
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -89,15 +156,19 @@ impl Chunk {
             x,
             y,
             pixels: [Pixel::AIR; CHUNK_AREA],
-            background: [0; CHUNK_AREA],     // 0 = no background (air)
-            temperature: [20.0; 64],         // Room temperature (Celsius)
-            pressure: [1.0; 64],             // Atmospheric pressure
+            background: [0; CHUNK_AREA], // 0 = no background (air)
+            temperature: [20.0; 64],     // Room temperature (Celsius)
+            pressure: [1.0; 64],         // Atmospheric pressure
+            oxygen: fresh_air(),
+            carbon_dioxide: no_carbon_dioxide(),
             electrical_potential: [0.0; 64], // No voltage initially
             light_levels: [0; CHUNK_AREA],   // Start dark, will be calculated
-            light_dirty: true,               // Needs initial light calculation
+            sky_light_levels: dark(),
+            light_dirty: true, // Needs initial light calculation
             dirty: false,
             dirty_rect: None,
             simulation_active: false,
+            detonations: Vec::new(),
         }
     }
 
@@ -166,6 +237,13 @@ impl Chunk {
         self.light_levels[y * CHUNK_SIZE + x]
     }
 
+    /// Get the sky light level at local coordinates (0-15)
+    #[inline]
+    pub fn get_sky_light(&self, x: usize, y: usize) -> u8 {
+        debug_assert!(x < CHUNK_SIZE && y < CHUNK_SIZE);
+        self.sky_light_levels[y * CHUNK_SIZE + x]
+    }
+
     /// Set light level at local coordinates (0-15)
     #[inline]
     pub fn set_light(&mut self, x: usize, y: usize, level: u8) {
@@ -256,6 +334,11 @@ impl Chunk {
         self.pressure[cy * 8 + cx]
     }
 
+    /// Get oxygen level at pixel position (using coarse 8x8 grid)
+    pub fn get_oxygen_at(&self, x: usize, y: usize) -> f32 {
+        self.oxygen[self.get_coarse_grid_index(x, y)]
+    }
+
     /// Get the index for the coarse 8x8 grid from pixel coordinates.
     #[inline]
     pub fn get_coarse_grid_index(&self, x: usize, y: usize) -> usize {
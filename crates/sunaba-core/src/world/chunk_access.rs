@@ -0,0 +1,47 @@
+//! Pixel access by world position over a chunk map
+//!
+//! Shared by the world systems that walk across chunk borders (logic, pumps,
+//! containers, liquids, weather and plants). Positions in unloaded chunks read
+//! as None and writes to them are dropped.
+
+use glam::IVec2;
+use std::collections::HashMap;
+
+use super::chunk::{Chunk, Pixel};
+use super::chunk_manager::ChunkManager;
+
+/// Orthogonal neighbor offsets, indexed by direction (0-3 = up/right/down/left)
+pub(super) const ORTHOGONAL: [IVec2; 4] = [
+    IVec2::new(0, 1),
+    IVec2::new(1, 0),
+    IVec2::new(0, -1),
+    IVec2::new(-1, 0),
+];
+
+pub(super) fn pixel_at(chunks: &HashMap<IVec2, Chunk>, pos: IVec2) -> Option<Pixel> {
+    let (chunk_pos, x, y) = ChunkManager::world_to_chunk_coords(pos.x, pos.y);
+    chunks.get(&chunk_pos).map(|chunk| chunk.get_pixel(x, y))
+}
+
+pub(super) fn material_at(chunks: &HashMap<IVec2, Chunk>, pos: IVec2) -> Option<u16> {
+    pixel_at(chunks, pos).map(|pixel| pixel.material_id)
+}
+
+/// Light level (0-15), dark in unloaded chunks
+pub(super) fn light_at(chunks: &HashMap<IVec2, Chunk>, pos: IVec2) -> u8 {
+    let (chunk_pos, x, y) = ChunkManager::world_to_chunk_coords(pos.x, pos.y);
+    chunks
+        .get(&chunk_pos)
+        .map_or(0, |chunk| chunk.get_light(x, y))
+}
+
+pub(super) fn set_pixel_at(chunks: &mut HashMap<IVec2, Chunk>, pos: IVec2, pixel: Pixel) {
+    let (chunk_pos, x, y) = ChunkManager::world_to_chunk_coords(pos.x, pos.y);
+    if let Some(chunk) = chunks.get_mut(&chunk_pos) {
+        chunk.set_pixel(x, y, pixel);
+    }
+}
+
+pub(super) fn set_material_at(chunks: &mut HashMap<IVec2, Chunk>, pos: IVec2, material: u16) {
+    set_pixel_at(chunks, pos, Pixel::new(material));
+}
@@ -44,34 +44,52 @@ impl ChunkStatus {
         player_position: Vec2,
         active_chunk_radius: i32,
     ) -> usize {
-        let player_chunk_x = (player_position.x as i32).div_euclid(CHUNK_SIZE as i32);
-        let player_chunk_y = (player_position.y as i32).div_euclid(CHUNK_SIZE as i32);
+        Self::update_active_chunks_around(chunk_manager, &[player_position], active_chunk_radius)
+    }
+
+    /// Update active chunks around several centers (e.g. every player on a dedicated server)
+    /// A chunk stays active while it is within radius of any center
+    pub fn update_active_chunks_around(
+        chunk_manager: &mut ChunkManager,
+        centers: &[Vec2],
+        active_chunk_radius: i32,
+    ) -> usize {
+        let center_chunks: Vec<IVec2> = centers
+            .iter()
+            .map(|pos| {
+                IVec2::new(
+                    (pos.x as i32).div_euclid(CHUNK_SIZE as i32),
+                    (pos.y as i32).div_euclid(CHUNK_SIZE as i32),
+                )
+            })
+            .collect();
 
         // 1. Remove distant chunks from active list
         chunk_manager.active_chunks.retain(|pos| {
-            let dist_x = (pos.x - player_chunk_x).abs();
-            let dist_y = (pos.y - player_chunk_y).abs();
-            dist_x <= active_chunk_radius && dist_y <= active_chunk_radius
+            center_chunks.iter().any(|center| {
+                (pos.x - center.x).abs() <= active_chunk_radius
+                    && (pos.y - center.y).abs() <= active_chunk_radius
+            })
         });
 
         // 2. Add nearby loaded chunks that aren't currently active
         let mut added_count = 0;
-        for cy in (player_chunk_y - active_chunk_radius)..=(player_chunk_y + active_chunk_radius) {
-            for cx in
-                (player_chunk_x - active_chunk_radius)..=(player_chunk_x + active_chunk_radius)
-            {
-                let pos = IVec2::new(cx, cy);
-
-                // If chunk is loaded but not active, add it to active list
-                if chunk_manager.chunks.contains_key(&pos)
-                    && !chunk_manager.active_chunks.contains(&pos)
-                {
-                    chunk_manager.active_chunks.push(pos);
-                    added_count += 1;
-
-                    // Mark newly activated chunks for simulation so physics/chemistry runs
-                    if let Some(chunk) = chunk_manager.chunks.get_mut(&pos) {
-                        chunk.set_simulation_active(true);
+        for center in &center_chunks {
+            for cy in (center.y - active_chunk_radius)..=(center.y + active_chunk_radius) {
+                for cx in (center.x - active_chunk_radius)..=(center.x + active_chunk_radius) {
+                    let pos = IVec2::new(cx, cy);
+
+                    // If chunk is loaded but not active, add it to active list
+                    if chunk_manager.chunks.contains_key(&pos)
+                        && !chunk_manager.active_chunks.contains(&pos)
+                    {
+                        chunk_manager.active_chunks.push(pos);
+                        added_count += 1;
+
+                        // Mark newly activated chunks for simulation so physics/chemistry runs
+                        if let Some(chunk) = chunk_manager.chunks.get_mut(&pos) {
+                            chunk.set_simulation_active(true);
+                        }
                     }
                 }
             }
@@ -270,6 +288,23 @@ mod tests {
         assert_eq!(first_count, second_count, "Should not add duplicates");
     }
 
+    #[test]
+    fn test_update_active_chunks_around_several_centers() {
+        let mut manager = setup_3x3_chunks();
+
+        // Two players in opposite corners, radius 0 keeps only their own chunks
+        let centers = [Vec2::new(-32.0, -32.0), Vec2::new(96.0, 96.0)];
+        let added = ChunkStatus::update_active_chunks_around(&mut manager, &centers, 0);
+
+        assert_eq!(added, 2);
+        assert!(manager.active_chunks.contains(&IVec2::new(-1, -1)));
+        assert!(manager.active_chunks.contains(&IVec2::new(1, 1)));
+
+        // One player leaves: their chunk is deactivated
+        ChunkStatus::update_active_chunks_around(&mut manager, &centers[1..], 0);
+        assert_eq!(manager.active_chunks, vec![IVec2::new(1, 1)]);
+    }
+
     #[test]
     fn test_ensure_chunks_for_area_single_chunk() {
         let mut manager = ChunkManager::new();
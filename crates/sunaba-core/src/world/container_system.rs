@@ -0,0 +1,228 @@
+//! Container system - scooping fluids into buckets and flasks and pouring them back out
+
+use glam::IVec2;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet, VecDeque};
+
+use super::chunk_access::{ORTHOGONAL, pixel_at};
+use super::chunk_manager::ChunkManager;
+use crate::entity::containers::ContainerType;
+use crate::simulation::{MaterialType, Materials};
+
+/// Max pixels visited while flood filling a fluid body or the air around a pour point
+const FLOOD_SEARCH_LIMIT: usize = 4096;
+
+/// Result of using the selected container this frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContainerUse {
+    /// Nothing happened (out of reach, wrong fluid, nothing to pour, cooldown)
+    None,
+    /// Fluid was taken from the world into the container
+    Scooped { material_id: u16, amount: u32 },
+    /// Fluid was poured from the container into the world
+    Poured { material_id: u16, amount: u32 },
+}
+
+/// Container system - static utility methods for buckets and flasks
+pub struct ContainerSystem;
+
+impl ContainerSystem {
+    /// Check if the container can pick up the fluid at `pos`
+    /// A filled container only accepts more of the fluid it already holds
+    pub fn can_scoop(
+        chunk_manager: &ChunkManager,
+        materials: &Materials,
+        container: ContainerType,
+        held: Option<u16>,
+        pos: IVec2,
+    ) -> bool {
+        Self::material_at(chunk_manager, pos).is_some_and(|material_id| {
+            held.is_none_or(|held| held == material_id)
+                && container.holds(materials.get(material_id).material_type)
+        })
+    }
+
+    /// Flood-fill the fluid body connected to `start` (4-connected)
+    /// Returns at most `limit` positions; liquids are taken from the surface down
+    /// so the body drains like it would in a real bucket
+    pub fn scoop_positions(
+        chunk_manager: &ChunkManager,
+        materials: &Materials,
+        start: IVec2,
+        limit: usize,
+    ) -> Vec<IVec2> {
+        let Some(fluid) = Self::material_at(chunk_manager, start) else {
+            return Vec::new();
+        };
+        let is_fluid = |pos| Self::material_at(chunk_manager, pos) == Some(fluid);
+
+        let mut body = if materials.get(fluid).material_type == MaterialType::Liquid {
+            // Climb to the surface first, so a large body that outgrows the
+            // search still drains from its top rather than around the click
+            let mut body = Self::flood_highest_first(start, is_fluid);
+            body.sort_by_key(|pos| -pos.y);
+            body
+        } else {
+            Self::flood(start, is_fluid)
+        };
+        body.truncate(limit);
+        body
+    }
+
+    /// Empty cells connected to `start` (4-connected) that poured fluid fills
+    /// Returns at most `limit` positions, closest to the pour point first
+    pub fn pour_positions(chunk_manager: &ChunkManager, start: IVec2, limit: usize) -> Vec<IVec2> {
+        let is_empty =
+            |pos: IVec2| pixel_at(&chunk_manager.chunks, pos).is_some_and(|p| p.is_empty());
+        let mut cells = Self::flood(start, is_empty);
+        cells.truncate(limit);
+        cells
+    }
+
+    /// Breadth-first flood fill from `start` over cells matching `matches`
+    fn flood(start: IVec2, matches: impl Fn(IVec2) -> bool) -> Vec<IVec2> {
+        if !matches(start) {
+            return Vec::new();
+        }
+
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+        let mut cells = Vec::new();
+
+        while let Some(pos) = queue.pop_front() {
+            cells.push(pos);
+            if cells.len() >= FLOOD_SEARCH_LIMIT {
+                break;
+            }
+            for offset in ORTHOGONAL {
+                let next = pos + offset;
+                if visited.insert(next) && matches(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        cells
+    }
+
+    /// Flood fill like `flood`, but always expanding the highest cell found so far
+    /// (nearest to `start` among equals)
+    fn flood_highest_first(start: IVec2, matches: impl Fn(IVec2) -> bool) -> Vec<IVec2> {
+        if !matches(start) {
+            return Vec::new();
+        }
+
+        let key = |pos: IVec2| {
+            let distance = (pos.x - start.x).abs() + (pos.y - start.y).abs();
+            (pos.y, Reverse(distance), pos.x)
+        };
+        let mut visited = HashSet::from([start]);
+        let mut queue = BinaryHeap::from([key(start)]);
+        let mut cells = Vec::new();
+
+        while let Some((y, _, x)) = queue.pop() {
+            let pos = IVec2::new(x, y);
+            cells.push(pos);
+            if cells.len() >= FLOOD_SEARCH_LIMIT {
+                break;
+            }
+            for offset in ORTHOGONAL {
+                let next = pos + offset;
+                if visited.insert(next) && matches(next) {
+                    queue.push(key(next));
+                }
+            }
+        }
+
+        cells
+    }
+
+    /// Non-air material at a world position, None for air or unloaded chunks
+    fn material_at(chunk_manager: &ChunkManager, pos: IVec2) -> Option<u16> {
+        pixel_at(&chunk_manager.chunks, pos)
+            .filter(|pixel| !pixel.is_empty())
+            .map(|pixel| pixel.material_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::MaterialId;
+    use crate::world::Chunk;
+    use crate::world::test_fixtures::single_chunk_manager;
+
+    #[test]
+    fn test_scoop_takes_surface_of_connected_body() {
+        // Pool of water (x 10..20, y 5..10) in a stone basin, separate puddle at x=40
+        let manager = single_chunk_manager(|x, y| match (x, y) {
+            (10..=19, 5..=9) => Some(MaterialId::WATER),
+            (40, 5) => Some(MaterialId::WATER),
+            (_, 0..=4) => Some(MaterialId::STONE),
+            _ => None,
+        });
+        let materials = Materials::new();
+
+        let scooped = ContainerSystem::scoop_positions(&manager, &materials, IVec2::new(12, 5), 10);
+        assert_eq!(scooped.len(), 10);
+        assert!(scooped.iter().all(|p| p.y == 9), "Top row drains first");
+
+        let all = ContainerSystem::scoop_positions(&manager, &materials, IVec2::new(12, 5), 1000);
+        assert_eq!(all.len(), 50);
+        assert!(!all.contains(&IVec2::new(40, 5)));
+    }
+
+    #[test]
+    fn test_scoop_drains_surface_of_body_larger_than_search() {
+        // Lake filling 2x2 chunks (16384 pixels), clicked at the bottom
+        let mut manager = ChunkManager::new();
+        for (cx, cy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+            let mut chunk = Chunk::new(cx, cy);
+            for y in 0..64 {
+                for x in 0..64 {
+                    chunk.set_material(x, y, MaterialId::WATER);
+                }
+            }
+            manager.chunks.insert(IVec2::new(cx, cy), chunk);
+        }
+        let materials = Materials::new();
+
+        let scooped = ContainerSystem::scoop_positions(&manager, &materials, IVec2::new(5, 0), 64);
+        assert_eq!(scooped.len(), 64);
+        assert!(scooped.iter().all(|p| p.y == 127), "Surface drains first");
+    }
+
+    #[test]
+    fn test_can_scoop_respects_container_and_contents() {
+        let manager = single_chunk_manager(|x, _| match x {
+            0..=9 => Some(MaterialId::WATER),
+            10..=19 => Some(MaterialId::STEAM),
+            20..=29 => Some(MaterialId::SAND),
+            _ => None,
+        });
+        let materials = Materials::new();
+        let can = |container, held, x| {
+            ContainerSystem::can_scoop(&manager, &materials, container, held, IVec2::new(x, 5))
+        };
+
+        assert!(can(ContainerType::Bucket, None, 5));
+        assert!(!can(ContainerType::Bucket, None, 15)); // Gas needs a flask
+        assert!(can(ContainerType::Flask, None, 15));
+        assert!(!can(ContainerType::Bucket, None, 25)); // Powders are for shovels
+        assert!(!can(ContainerType::Bucket, Some(MaterialId::OIL), 5)); // No mixing
+        assert!(!can(ContainerType::Bucket, None, 40)); // Air
+    }
+
+    #[test]
+    fn test_pour_fills_empty_cells_only() {
+        let manager = single_chunk_manager(|_, y| (y < 5).then_some(MaterialId::STONE));
+
+        let cells = ContainerSystem::pour_positions(&manager, IVec2::new(20, 10), 30);
+        assert_eq!(cells.len(), 30);
+        assert_eq!(cells[0], IVec2::new(20, 10));
+        assert!(cells.iter().all(|p| p.y >= 5));
+
+        // Pouring into solid ground does nothing
+        assert!(ContainerSystem::pour_positions(&manager, IVec2::new(20, 2), 30).is_empty());
+    }
+}
@@ -0,0 +1,484 @@
+//! Day/night system - what the cycle does besides lighting the sky
+//!
+//! - **Surface temperature**: air above the surface drifts towards the biome
+//!   temperature plus a swing that peaks at noon and bottoms out at midnight
+//! - **Night growth**: `GLOWING_MUSHROOM` spreads onto dark ground at night
+//! - **Night spawns**: times the hostile creatures `World` spawns near the
+//!   player after dark (they leave again at sunrise)
+//!
+//! The settings also switch the creature rest drive and sunlight-gated
+//! reactions, which `World` reads when it hands out daylight and light levels.
+//! All of it is off until the system is enabled (the persistent world does
+//! that; levels, scenarios and the Powder sandbox keep it off).
+
+use super::chunk_access::{light_at, material_at, set_material_at};
+use super::rng_trait::WorldRng;
+use super::weather_system::{ColumnClimate, biome_temperature};
+use super::{CHUNK_SIZE, Chunk};
+use crate::world::biome::BiomeType;
+use glam::IVec2;
+use std::collections::HashMap;
+use sunaba_simulation::materials::{MaterialId, MaterialType, Materials};
+
+/// Daylight (0-1) below which it counts as night
+pub const NIGHT_DAYLIGHT: f32 = 0.25;
+
+/// Ticks between surface temperature updates
+const TEMPERATURE_INTERVAL: u32 = 30;
+
+/// Fraction of the gap to the surface temperature closed per update
+const SURFACE_TEMPERATURE_RATE: f32 = 0.05;
+
+/// Ticks between mushroom spreading passes
+const SPREAD_INTERVAL: u32 = 20;
+
+/// Random pixels visited per active chunk per spreading pass
+const RANDOM_TICKS_PER_CHUNK: usize = 32;
+
+/// Brightest light level (0-15) mushrooms spread into
+const MAX_MUSHROOM_LIGHT: u8 = 6;
+
+/// Mushrooms stop spreading once this many others are within `MUSHROOM_RADIUS`
+const MAX_MUSHROOM_NEIGHBORS: usize = 3;
+
+/// Reach of a spreading mushroom (and its crowding check)
+const MUSHROOM_RADIUS: i32 = 2;
+
+/// Horizontal distance range (pixels) from the player hostile creatures spawn at
+const HOSTILE_SPAWN_MIN_DISTANCE: f32 = 80.0;
+const HOSTILE_SPAWN_MAX_DISTANCE: f32 = 160.0;
+
+/// Height (pixels) above the terrain hostile creatures drop in from
+pub const HOSTILE_SPAWN_HEIGHT: i32 = 16;
+
+/// Hostile creatures within this distance (pixels) of the player hurt them
+pub const HOSTILE_ATTACK_RADIUS: f32 = 12.0;
+
+/// Damage per second a hostile creature deals while in reach
+pub const HOSTILE_DAMAGE_PER_SECOND: f32 = 8.0;
+
+/// Day/night effect settings (filled in from the game config)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayNightSettings {
+    /// Surface air temperature (°C) above the biome temperature at noon and below it at midnight
+    pub surface_temperature_swing: f32,
+    /// Glowing mushrooms spread at night
+    pub mushroom_spread: bool,
+    /// Chance per visit that a mushroom spreads
+    pub mushroom_spread_chance: f32,
+    /// Hostile creatures spawn near the player at night
+    pub hostile_spawns: bool,
+    /// Seconds between hostile spawns
+    pub hostile_spawn_interval: f32,
+    /// Most hostile creatures alive at once
+    pub max_hostile_creatures: usize,
+    /// Creatures tire at night and rest
+    pub creature_rest: bool,
+    /// Light-dependent reactions only count sunlight (torches don't make plants grow)
+    pub sunlight_reactions: bool,
+}
+
+impl Default for DayNightSettings {
+    fn default() -> Self {
+        Self {
+            surface_temperature_swing: 6.0,
+            mushroom_spread: true,
+            mushroom_spread_chance: 0.05,
+            hostile_spawns: true,
+            hostile_spawn_interval: 30.0,
+            max_hostile_creatures: 4,
+            creature_rest: true,
+            sunlight_reactions: true,
+        }
+    }
+}
+
+/// Applies the day/night cycle to the active chunks.
+pub struct DayNightSystem {
+    settings: DayNightSettings,
+    /// Ticks since the last surface temperature update
+    temperature_ticks: u32,
+    /// Ticks since the last mushroom spreading pass
+    spread_ticks: u32,
+    /// Seconds until the next hostile spawn
+    spawn_timer: f32,
+    /// Disabled day/night effects leave the world and its creatures alone
+    enabled: bool,
+}
+
+impl DayNightSystem {
+    pub fn new() -> Self {
+        let settings = DayNightSettings::default();
+        Self {
+            spawn_timer: settings.hostile_spawn_interval,
+            settings,
+            temperature_ticks: 0,
+            spread_ticks: 0,
+            enabled: false,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn settings(&self) -> &DayNightSettings {
+        &self.settings
+    }
+
+    pub fn set_settings(&mut self, settings: DayNightSettings) {
+        self.spawn_timer = self.spawn_timer.min(settings.hostile_spawn_interval);
+        self.settings = settings;
+    }
+
+    /// Whether the given daylight (0-1) counts as night
+    pub fn is_night(daylight: f32) -> bool {
+        daylight < NIGHT_DAYLIGHT
+    }
+
+    /// Temperature the air above the surface of `biome` drifts towards
+    pub fn surface_temperature(&self, biome: BiomeType, daylight: f32) -> f32 {
+        biome_temperature(biome) + self.settings.surface_temperature_swing * (daylight * 2.0 - 1.0)
+    }
+
+    /// Runs once per simulation tick with the current daylight (0-1)
+    pub fn update<R: WorldRng>(
+        &mut self,
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        climate: impl Fn(i32) -> ColumnClimate,
+        daylight: f32,
+        rng: &mut R,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        self.temperature_ticks += 1;
+        if self.temperature_ticks >= TEMPERATURE_INTERVAL {
+            self.temperature_ticks = 0;
+            self.update_surface_temperature(chunks, active_chunks, &climate, daylight);
+        }
+
+        self.spread_ticks += 1;
+        if self.spread_ticks >= SPREAD_INTERVAL {
+            self.spread_ticks = 0;
+            if self.settings.mushroom_spread && Self::is_night(daylight) {
+                Self::spread_mushrooms(
+                    chunks,
+                    active_chunks,
+                    self.settings.mushroom_spread_chance,
+                    rng,
+                );
+            }
+        }
+    }
+
+    /// Moves the air above the surface towards the time-of-day temperature
+    fn update_surface_temperature(
+        &self,
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        climate: &impl Fn(i32) -> ColumnClimate,
+        daylight: f32,
+    ) {
+        for &chunk_pos in active_chunks {
+            let base = chunk_pos * CHUNK_SIZE as i32;
+            let Some(chunk) = chunks.get_mut(&chunk_pos) else {
+                continue;
+            };
+            for cx in 0..8 {
+                let column = climate(base.x + cx as i32 * 8 + 4);
+                let target = self.surface_temperature(column.biome, daylight);
+                for cy in 0..8 {
+                    if base.y + cy as i32 * 8 + 4 <= column.surface_y {
+                        continue;
+                    }
+                    let temperature = chunk.get_temperature(cx, cy);
+                    chunk.set_temperature(
+                        cx,
+                        cy,
+                        temperature + (target - temperature) * SURFACE_TEMPERATURE_RATE,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Visits random pixels of every active chunk, spreading the mushrooms found
+    pub fn spread_mushrooms<R: WorldRng>(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        chance: f32,
+        rng: &mut R,
+    ) {
+        for &chunk_pos in active_chunks {
+            if !chunks.contains_key(&chunk_pos) {
+                continue;
+            }
+            for _ in 0..RANDOM_TICKS_PER_CHUNK {
+                let x = Self::random_index(rng, CHUNK_SIZE) as i32;
+                let y = Self::random_index(rng, CHUNK_SIZE) as i32;
+                let pos = chunk_pos * CHUNK_SIZE as i32 + IVec2::new(x, y);
+                if material_at(chunks, pos) == Some(MaterialId::GLOWING_MUSHROOM)
+                    && rng.check_probability(chance)
+                {
+                    Self::spread_mushroom(chunks, pos, rng);
+                }
+            }
+        }
+    }
+
+    /// Grows a new mushroom on dark, uncrowded ground near `pos`
+    fn spread_mushroom<R: WorldRng>(chunks: &mut HashMap<IVec2, Chunk>, pos: IVec2, rng: &mut R) {
+        let span = (MUSHROOM_RADIUS * 2 + 1) as usize;
+        let offset = IVec2::new(
+            Self::random_index(rng, span) as i32 - MUSHROOM_RADIUS,
+            Self::random_index(rng, span) as i32 - MUSHROOM_RADIUS,
+        );
+        let target = pos + offset;
+
+        if material_at(chunks, target) != Some(MaterialId::AIR)
+            || !material_at(chunks, target - IVec2::Y).is_some_and(Self::is_substrate)
+            || light_at(chunks, target) > MAX_MUSHROOM_LIGHT
+        {
+            return;
+        }
+
+        let neighbors = (-MUSHROOM_RADIUS..=MUSHROOM_RADIUS)
+            .flat_map(|dy| {
+                (-MUSHROOM_RADIUS..=MUSHROOM_RADIUS).map(move |dx| target + IVec2::new(dx, dy))
+            })
+            .filter(|&p| material_at(chunks, p) == Some(MaterialId::GLOWING_MUSHROOM))
+            .count();
+        if neighbors > MAX_MUSHROOM_NEIGHBORS {
+            return;
+        }
+
+        set_material_at(chunks, target, MaterialId::GLOWING_MUSHROOM);
+    }
+
+    /// Counts down the spawn timer; true when a hostile creature should spawn now
+    pub fn hostile_spawn_due(&mut self, dt: f32, daylight: f32, hostile_count: usize) -> bool {
+        if !self.enabled
+            || !self.settings.hostile_spawns
+            || !Self::is_night(daylight)
+            || hostile_count >= self.settings.max_hostile_creatures
+        {
+            return false;
+        }
+
+        self.spawn_timer -= dt;
+        if self.spawn_timer > 0.0 {
+            return false;
+        }
+        self.spawn_timer = self.settings.hostile_spawn_interval;
+        true
+    }
+
+    /// Random column to the left or right of the player to spawn a hostile creature in
+    pub fn hostile_spawn_x<R: WorldRng>(player_x: f32, rng: &mut R) -> i32 {
+        let distance = HOSTILE_SPAWN_MIN_DISTANCE
+            + rng.gen_f32() * (HOSTILE_SPAWN_MAX_DISTANCE - HOSTILE_SPAWN_MIN_DISTANCE);
+        let side = if rng.gen_bool() { 1.0 } else { -1.0 };
+        (player_x + side * distance) as i32
+    }
+
+    /// Top of the highest ground (solid or powder) in a loaded world column
+    pub fn ground_height(
+        chunks: &HashMap<IVec2, Chunk>,
+        materials: &Materials,
+        world_x: i32,
+    ) -> Option<i32> {
+        let chunk_x = world_x.div_euclid(CHUNK_SIZE as i32);
+        let local_x = world_x.rem_euclid(CHUNK_SIZE as i32) as usize;
+        let mut column: Vec<(i32, &Chunk)> = chunks
+            .iter()
+            .filter(|(pos, _)| pos.x == chunk_x)
+            .map(|(pos, chunk)| (pos.y, chunk))
+            .collect();
+        column.sort_by_key(|&(chunk_y, _)| std::cmp::Reverse(chunk_y));
+
+        column.into_iter().find_map(|(chunk_y, chunk)| {
+            (0..CHUNK_SIZE).rev().find_map(|y| {
+                let material = materials.get(chunk.get_material(local_x, y));
+                matches!(
+                    material.material_type,
+                    MaterialType::Solid | MaterialType::Powder
+                )
+                .then(|| chunk_y * CHUNK_SIZE as i32 + y as i32)
+            })
+        })
+    }
+
+    fn is_substrate(material: u16) -> bool {
+        matches!(
+            material,
+            MaterialId::DIRT | MaterialId::GRASS | MaterialId::STONE | MaterialId::WOOD
+        )
+    }
+
+    fn random_index<R: WorldRng>(rng: &mut R, len: usize) -> usize {
+        ((rng.gen_f32() * len as f32) as usize).min(len - 1)
+    }
+}
+
+impl Default for DayNightSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::test_fixtures::count;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    /// Stone floor (y 0..10) with a single mushroom, in the dark
+    fn cave() -> HashMap<IVec2, Chunk> {
+        let mut chunk = Chunk::new(0, 0);
+        for y in 0..10 {
+            for x in 0..CHUNK_SIZE {
+                chunk.set_material(x, y, MaterialId::STONE);
+            }
+        }
+        chunk.set_material(32, 10, MaterialId::GLOWING_MUSHROOM);
+        HashMap::from([(IVec2::ZERO, chunk)])
+    }
+
+    #[test]
+    fn test_surface_temperature_follows_the_sun() {
+        let mut system = DayNightSystem::new();
+        system.set_enabled(true);
+        let climate = |_: i32| ColumnClimate {
+            biome: BiomeType::Plains,
+            surface_y: 9,
+        };
+
+        let mut run = |daylight: f32| {
+            let mut chunks = HashMap::from([(IVec2::ZERO, Chunk::new(0, 0))]);
+            let mut rng = StdRng::seed_from_u64(1);
+            for _ in 0..TEMPERATURE_INTERVAL * 200 {
+                system.update(&mut chunks, &[IVec2::ZERO], climate, daylight, &mut rng);
+            }
+            let chunk = &chunks[&IVec2::ZERO];
+            (chunk.get_temperature(0, 7), chunk.get_temperature(0, 0))
+        };
+
+        let (noon_air, noon_ground) = run(1.0);
+        let (midnight_air, midnight_ground) = run(0.0);
+
+        assert!((noon_air - 26.0).abs() < 0.5, "noon air was {noon_air}");
+        assert!(
+            (midnight_air - 14.0).abs() < 0.5,
+            "midnight air was {midnight_air}"
+        );
+        assert_eq!(noon_ground, 20.0, "Underground stays untouched");
+        assert_eq!(midnight_ground, 20.0);
+    }
+
+    #[test]
+    fn test_mushrooms_spread_only_at_night() {
+        let mut system = DayNightSystem::new();
+        system.set_enabled(true);
+        system.set_settings(DayNightSettings {
+            mushroom_spread_chance: 1.0,
+            ..DayNightSettings::default()
+        });
+        let climate = |_: i32| ColumnClimate {
+            biome: BiomeType::Plains,
+            surface_y: 9,
+        };
+        let mut rng = StdRng::seed_from_u64(2);
+
+        let mut chunks = cave();
+        for _ in 0..SPREAD_INTERVAL * 10000 {
+            system.update(&mut chunks, &[IVec2::ZERO], climate, 1.0, &mut rng);
+        }
+        assert_eq!(count(&chunks, MaterialId::GLOWING_MUSHROOM), 1);
+
+        for _ in 0..SPREAD_INTERVAL * 10000 {
+            system.update(&mut chunks, &[IVec2::ZERO], climate, 0.0, &mut rng);
+        }
+        assert!(count(&chunks, MaterialId::GLOWING_MUSHROOM) > 1);
+
+        // New mushrooms only grow on the floor
+        let chunk = &chunks[&IVec2::ZERO];
+        for y in 11..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                assert_ne!(chunk.get_material(x, y), MaterialId::GLOWING_MUSHROOM);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mushrooms_avoid_light() {
+        let mut chunks = cave();
+        let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+        for y in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                chunk.set_light(x, y, 12);
+            }
+        }
+        let mut rng = StdRng::seed_from_u64(3);
+
+        for _ in 0..2000 {
+            DayNightSystem::spread_mushrooms(&mut chunks, &[IVec2::ZERO], 1.0, &mut rng);
+        }
+
+        assert_eq!(count(&chunks, MaterialId::GLOWING_MUSHROOM), 1);
+    }
+
+    #[test]
+    fn test_hostile_spawns_at_night_up_to_limit() {
+        let mut system = DayNightSystem::new();
+        let interval = system.settings().hostile_spawn_interval;
+        let max = system.settings().max_hostile_creatures;
+        assert!(
+            !system.hostile_spawn_due(interval + 1.0, 0.0, 0),
+            "Nothing spawns until the cycle is enabled"
+        );
+        system.set_enabled(true);
+
+        assert!(!system.hostile_spawn_due(interval + 1.0, 1.0, 0));
+        assert!(system.hostile_spawn_due(interval + 1.0, 0.0, 0));
+        assert!(
+            !system.hostile_spawn_due(1.0, 0.0, 1),
+            "Waits for the interval"
+        );
+        assert!(!system.hostile_spawn_due(interval + 1.0, 0.0, max));
+
+        system.set_settings(DayNightSettings {
+            hostile_spawns: false,
+            ..DayNightSettings::default()
+        });
+        assert!(!system.hostile_spawn_due(interval + 1.0, 0.0, 0));
+    }
+
+    #[test]
+    fn test_ground_height_comes_from_loaded_chunks() {
+        let mut chunks = cave();
+        let materials = Materials::new();
+        assert_eq!(
+            DayNightSystem::ground_height(&chunks, &materials, 5),
+            Some(9)
+        );
+
+        // A ledge in the chunk above is the new ground
+        let mut above = Chunk::new(0, 1);
+        above.set_material(5, 20, MaterialId::DIRT);
+        chunks.insert(IVec2::new(0, 1), above);
+        assert_eq!(
+            DayNightSystem::ground_height(&chunks, &materials, 5),
+            Some(CHUNK_SIZE as i32 + 20)
+        );
+
+        // Unloaded columns have no ground to spawn on
+        assert_eq!(DayNightSystem::ground_height(&chunks, &materials, -5), None);
+    }
+}
@@ -1,6 +1,9 @@
 //! Manages the propagation of electricity through conductive materials.
 // Based on the POWDER_PLAN.md
 
+use super::chunk_manager::ChunkManager;
+use super::logic_system::LogicSystem;
+use super::pump_system::PumpSystem;
 use super::{CHUNK_SIZE, Chunk};
 use glam::IVec2;
 use std::collections::VecDeque;
@@ -9,17 +12,59 @@ use sunaba_simulation::pixel::{Pixel, pixel_flags};
 
 const PROPAGATION_QUEUE_MAX: usize = 256;
 
+/// Potential a lightning strike dumps into the conductor it hits (the grid maximum)
+const LIGHTNING_POTENTIAL: f32 = 100.0;
+
 /// Manages the electrical simulation.
 pub struct ElectricalSystem {
     /// Queue of pixels to update for electrical propagation.
     propagation_queue: VecDeque<(IVec2, usize, usize)>,
+    /// Logic components (diodes, gates, relays, plates, delays, sensors)
+    logic: LogicSystem,
+    /// Pumps moving fluids through pipes while powered
+    pumps: PumpSystem,
 }
 
 impl ElectricalSystem {
     pub fn new() -> Self {
         Self {
             propagation_queue: VecDeque::with_capacity(PROPAGATION_QUEUE_MAX),
+            logic: LogicSystem::new(),
+            pumps: PumpSystem::new(),
+        }
+    }
+
+    /// Set the entity positions that weigh down pressure plates this tick.
+    pub fn set_external_loads(&mut self, loads: Vec<IVec2>) {
+        self.logic.set_external_loads(loads);
+    }
+
+    /// Energizes the conductor hit by lightning so the charge spreads through it
+    /// on the next update. Returns false if the pixel doesn't conduct.
+    pub fn strike(
+        &mut self,
+        chunks: &mut std::collections::HashMap<IVec2, Chunk>,
+        materials: &Materials,
+        pos: IVec2,
+    ) -> bool {
+        let (chunk_pos, x, y) = ChunkManager::world_to_chunk_coords(pos.x, pos.y);
+        let Some(chunk) = chunks.get_mut(&chunk_pos) else {
+            return false;
+        };
+        let mut pixel = chunk.get_pixel(x, y);
+        if !materials.get(pixel.material_id).conducts_electricity {
+            return false;
+        }
+
+        let coarse_idx = chunk.get_coarse_grid_index(x, y);
+        chunk.electrical_potential[coarse_idx] = LIGHTNING_POTENTIAL;
+        pixel.flags |= pixel_flags::POWERED;
+        chunk.set_pixel(x, y, pixel);
+
+        if self.propagation_queue.len() < PROPAGATION_QUEUE_MAX {
+            self.propagation_queue.push_back((chunk_pos, x, y));
         }
+        true
     }
 
     /// Updates the electrical state for all active chunks.
@@ -35,9 +80,19 @@ impl ElectricalSystem {
         // 2. Add power from active sources to the grid
         self.add_power_from_sources(chunks, active_chunks, materials);
 
+        // 2.5. Evaluate logic components and queue the conductors they drive
+        for driven in self.logic.update(chunks, active_chunks, materials) {
+            if self.propagation_queue.len() < PROPAGATION_QUEUE_MAX {
+                self.propagation_queue.push_back(driven);
+            }
+        }
+
         // 3. Propagate power through conductors
         self.propagate_power(chunks, materials);
 
+        // 3.5. Run pumps next to powered conductors
+        self.pumps.update(chunks, active_chunks, materials);
+
         // 4. Handle special behaviors (sparks, thunder) and generate heat
         self.handle_effects(chunks, active_chunks, materials);
     }
@@ -176,9 +231,16 @@ impl ElectricalSystem {
                                 * neighbor_material.electrical_conductivity
                                 * 0.5;
 
-                            if transfer_amount > 0.01 {
-                                neighbor_chunk.electrical_potential[neighbor_coarse_idx] +=
-                                    transfer_amount;
+                            // Conductors sharing an already charged coarse cell get no
+                            // transfer, but still need the POWERED flag (logic inputs read it)
+                            let unflagged_in_charged_cell = neighbor_potential > 0.0
+                                && neighbor_pixel.flags & pixel_flags::POWERED == 0;
+
+                            if transfer_amount > 0.01 || unflagged_in_charged_cell {
+                                if transfer_amount > 0.01 {
+                                    neighbor_chunk.electrical_potential[neighbor_coarse_idx] +=
+                                        transfer_amount;
+                                }
                                 let mut updated_pixel = neighbor_pixel;
                                 updated_pixel.flags |= pixel_flags::POWERED;
                                 neighbor_chunk.set_pixel(next_x, next_y, updated_pixel);
@@ -542,6 +604,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_propagation_flags_conductors_in_same_cell() {
+        let mut system = ElectricalSystem::new();
+        let materials = Materials::new();
+        let mut chunks = HashMap::new();
+        let chunk_pos = IVec2::new(0, 0);
+
+        // Battery and wire run share coarse cell 0
+        let mut chunk = Chunk::new(0, 0);
+        let mut battery_pixel = Pixel::new(MaterialId::BATTERY);
+        battery_pixel.flags |= pixel_flags::SPARK_SOURCE;
+        chunk.set_pixel(1, 1, battery_pixel);
+        chunk.set_pixel(2, 1, Pixel::new(MaterialId::WIRE));
+        chunk.set_pixel(3, 1, Pixel::new(MaterialId::WIRE));
+        chunks.insert(chunk_pos, chunk);
+        let active_chunks = vec![chunk_pos];
+
+        for _ in 0..3 {
+            system.update(&mut chunks, &active_chunks, &materials);
+        }
+
+        let chunk = chunks.get(&chunk_pos).unwrap();
+        assert!(chunk.get_pixel(3, 1).flags & pixel_flags::POWERED != 0);
+    }
+
+    #[test]
+    fn test_logic_gate_drives_wire() {
+        let mut system = ElectricalSystem::new();
+        let materials = Materials::new();
+        let mut chunks = HashMap::new();
+        let chunk_pos = IVec2::new(0, 0);
+
+        // Unpowered NOT gate facing right drives the wire run behind it
+        let mut chunk = Chunk::new(0, 0);
+        chunk.set_pixel(6, 0, Pixel::new(MaterialId::NOT_GATE).with_direction(1));
+        chunk.set_pixel(7, 0, Pixel::new(MaterialId::WIRE));
+        chunk.set_pixel(8, 0, Pixel::new(MaterialId::WIRE));
+        chunks.insert(chunk_pos, chunk);
+        let active_chunks = vec![chunk_pos];
+
+        for _ in 0..3 {
+            system.update(&mut chunks, &active_chunks, &materials);
+        }
+
+        let chunk = chunks.get(&chunk_pos).unwrap();
+        assert!(chunk.electrical_potential[chunk.get_coarse_grid_index(8, 0)] > 0.0);
+        assert!(chunk.get_pixel(8, 0).flags & pixel_flags::POWERED != 0);
+    }
+
+    #[test]
+    fn test_lightning_strike_energizes_conductors() {
+        let mut system = ElectricalSystem::new();
+        let materials = Materials::new();
+        let mut chunks = HashMap::new();
+        let mut chunk = Chunk::new(0, 0);
+        chunk.set_material(10, 10, MaterialId::METAL);
+        chunk.set_material(20, 10, MaterialId::DIRT);
+        chunks.insert(IVec2::ZERO, chunk);
+
+        assert!(system.strike(&mut chunks, &materials, IVec2::new(10, 10)));
+        assert!(!system.strike(&mut chunks, &materials, IVec2::new(20, 10)));
+
+        let chunk = &chunks[&IVec2::ZERO];
+        assert_ne!(chunk.get_pixel(10, 10).flags & pixel_flags::POWERED, 0);
+        assert_eq!(chunk.get_pixel(20, 10).flags & pixel_flags::POWERED, 0);
+        assert_eq!(system.propagation_queue.len(), 1);
+    }
+
     #[test]
     fn test_discharge_reduces_potential() {
         let system = ElectricalSystem::new();
@@ -0,0 +1,442 @@
+//! Explosion system - blast destruction, debris, shockwaves and chain reactions
+//!
+//! Explosive pixels (materials with an `explosive_yield`) never burn normally.
+//! When ignited or triggered by a reaction, the CA pass records them in their
+//! chunk's `detonations` list. After the CA pass this system consumes them,
+//! merges nearby charges into a single blast and applies its effects:
+//!
+//! - Pixels inside the radius break if the blast outweighs their hardness
+//! - The inner core is vaporized and filled with a sparse fireball
+//! - Loosened powders and liquids are flung outward, loosened solids fall as debris
+//! - A pressure spike and heat are added to the coarse fields
+//! - A short light flash is spawned
+//! - Player and creatures nearby take damage and knockback
+//! - Explosives caught in the blast detonate on the next frame (chain reaction)
+
+use glam::{IVec2, Vec2};
+use std::collections::HashSet;
+
+use super::World;
+use crate::simulation::{MaterialDef, MaterialId, MaterialType, add_heat_at_pixel};
+use crate::world::WorldRng;
+
+/// Charges closer than this are merged into one blast (pixels)
+const MERGE_DISTANCE: f32 = 6.0;
+/// Upper bound on blast radius so huge stockpiles stay affordable (pixels)
+const MAX_RADIUS: f32 = 48.0;
+/// Blasts applied per frame, the rest carry over to the next frame
+const MAX_EXPLOSIONS_PER_FRAME: usize = 8;
+/// Fraction of the radius where broken pixels are vaporized instead of loosened
+const CORE_FRACTION: f32 = 0.5;
+/// Chance for an empty core pixel to become fire
+const FIREBALL_DENSITY: f32 = 0.25;
+/// Number of angular sectors loosened solids are grouped into as debris
+const DEBRIS_SECTORS: usize = 8;
+/// Extra distance loosened powders and liquids are thrown past the edge (pixels)
+const FLING_DISTANCE: f32 = 6.0;
+/// Pressure added at the center per unit of blast power
+const PRESSURE_PER_POWER: f32 = 5.0;
+/// Heat added at the center per unit of blast power (°C)
+const HEAT_PER_POWER: f32 = 30.0;
+/// Entities are affected up to this multiple of the blast radius
+const ENTITY_RANGE: f32 = 1.5;
+/// Damage at the center per unit of blast power
+const DAMAGE_PER_POWER: f32 = 6.0;
+/// Knockback velocity at the center per unit of blast power (pixels/s)
+const KNOCKBACK_PER_POWER: f32 = 40.0;
+
+/// A pending blast, possibly merged from several charges
+#[derive(Debug, Clone, Copy)]
+pub struct Explosion {
+    /// Energy-weighted center in world coordinates
+    pub center: Vec2,
+    /// Total explosive yield
+    pub energy: f32,
+}
+
+impl Explosion {
+    /// Radius of destruction in pixels
+    pub fn radius(&self) -> f32 {
+        (2.0 * self.energy.sqrt()).min(MAX_RADIUS)
+    }
+
+    /// Blast strength at the center, compared against pixel resistance
+    pub fn power(&self) -> f32 {
+        2.0 * self.energy.sqrt()
+    }
+}
+
+/// Resistance of a material to blasts, or None if it can't be destroyed
+///
+/// Gases are always blown away. Solids without a hardness (bedrock) are unbreakable.
+pub fn blast_resistance(material: &MaterialDef) -> Option<f32> {
+    if material.material_type == MaterialType::Gas {
+        return Some(0.0);
+    }
+    let base = match material.hardness {
+        Some(h) => h as f32 * material.hardness_multiplier,
+        None if material.material_type == MaterialType::Solid => return None,
+        None => 0.5,
+    };
+    Some(base + material.structural_strength.unwrap_or(0.0) / 50.0)
+}
+
+/// Tracks pending explosions and applies them to the world
+#[derive(Default)]
+pub struct ExplosionSystem {
+    pending: Vec<Explosion>,
+}
+
+impl ExplosionSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of blasts waiting to be applied
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Queue a charge, merging it into a nearby pending blast if there is one
+    pub fn add_charge(&mut self, position: Vec2, energy: f32) {
+        if let Some(existing) = self
+            .pending
+            .iter_mut()
+            .find(|e| e.center.distance(position) <= MERGE_DISTANCE)
+        {
+            let total = existing.energy + energy;
+            existing.center = (existing.center * existing.energy + position * energy) / total;
+            existing.energy = total;
+        } else {
+            self.pending.push(Explosion {
+                center: position,
+                energy,
+            });
+        }
+    }
+
+    /// Consume detonated pixels, then apply up to `MAX_EXPLOSIONS_PER_FRAME` blasts
+    /// Returns the number of blasts applied
+    pub fn update<R: WorldRng>(&mut self, world: &mut World, rng: &mut R) -> usize {
+        self.collect_detonations(world);
+
+        let count = self.pending.len().min(MAX_EXPLOSIONS_PER_FRAME);
+        let batch: Vec<Explosion> = self.pending.drain(..count).collect();
+        for explosion in &batch {
+            self.detonate(world, explosion, rng);
+        }
+        count
+    }
+
+    /// Drain the detonation lists recorded during the CA pass
+    fn collect_detonations(&mut self, world: &mut World) {
+        let mut positions = Vec::new();
+        for chunk in world.chunks_mut().values_mut() {
+            positions.append(&mut chunk.detonations);
+        }
+
+        for pos in positions {
+            // Duplicates and pixels that moved away are skipped here
+            if let Some(energy) = Self::explosive_yield_at(world, pos) {
+                world.set_pixel(pos.x, pos.y, MaterialId::AIR);
+                self.add_charge(pos.as_vec2(), energy);
+            }
+        }
+    }
+
+    fn explosive_yield_at(world: &World, pos: IVec2) -> Option<f32> {
+        let pixel = world.get_pixel(pos.x, pos.y)?;
+        if pixel.is_empty() {
+            return None;
+        }
+        world.materials().get(pixel.material_id).explosive_yield
+    }
+
+    fn detonate<R: WorldRng>(&mut self, world: &mut World, explosion: &Explosion, rng: &mut R) {
+        let radius = explosion.radius();
+        let power = explosion.power();
+        let center = explosion.center;
+        let cx = center.x.round() as i32;
+        let cy = center.y.round() as i32;
+        let r = radius.ceil() as i32;
+
+        log::debug!(
+            "Explosion at ({}, {}) energy={:.1} radius={:.1}",
+            cx,
+            cy,
+            explosion.energy,
+            radius
+        );
+
+        let mut sectors: Vec<HashSet<IVec2>> = vec![HashSet::new(); DEBRIS_SECTORS];
+        let mut flung: Vec<(IVec2, u16)> = Vec::new();
+
+        for dy in -r..=r {
+            for dx in -r..=r {
+                let distance = ((dx * dx + dy * dy) as f32).sqrt();
+                if distance > radius {
+                    continue;
+                }
+                let (x, y) = (cx + dx, cy + dy);
+                let Some(pixel) = world.get_pixel(x, y) else {
+                    continue;
+                };
+                let in_core = distance <= radius * CORE_FRACTION;
+
+                if pixel.is_empty() {
+                    if in_core && rng.check_probability(FIREBALL_DENSITY) {
+                        world.set_pixel(x, y, MaterialId::FIRE);
+                    }
+                    continue;
+                }
+
+                let material = world.materials().get(pixel.material_id);
+
+                // Caught explosives go off next frame
+                if let Some(energy) = material.explosive_yield {
+                    world.set_pixel(x, y, MaterialId::AIR);
+                    self.add_charge(IVec2::new(x, y).as_vec2(), energy);
+                    continue;
+                }
+
+                let Some(resistance) = blast_resistance(material) else {
+                    continue;
+                };
+                if power * (1.0 - distance / radius) < resistance {
+                    continue;
+                }
+
+                let material_type = material.material_type;
+                if in_core || material_type == MaterialType::Gas {
+                    let vaporized = if rng.check_probability(FIREBALL_DENSITY) {
+                        MaterialId::FIRE
+                    } else {
+                        MaterialId::AIR
+                    };
+                    world.set_pixel(x, y, vaporized);
+                } else if material_type == MaterialType::Solid {
+                    let angle = (dy as f32).atan2(dx as f32) + std::f32::consts::PI;
+                    let sector = (angle / std::f32::consts::TAU * DEBRIS_SECTORS as f32) as usize;
+                    sectors[sector % DEBRIS_SECTORS].insert(IVec2::new(x, y));
+                } else {
+                    flung.push((IVec2::new(x, y), pixel.material_id));
+                }
+            }
+        }
+
+        for (pos, material_id) in flung {
+            let direction = (pos.as_vec2() - center).try_normalize().unwrap_or(Vec2::Y);
+            let throw = radius + FLING_DISTANCE * (0.5 + rng.gen_f32());
+            let target = (center + direction * throw).round().as_ivec2();
+            // Pixels that can't land stay where they are
+            if world
+                .get_pixel(target.x, target.y)
+                .is_some_and(|p| p.is_empty())
+            {
+                world.set_pixel(target.x, target.y, material_id);
+                world.set_pixel(pos.x, pos.y, MaterialId::AIR);
+            }
+        }
+
+        for region in sectors.into_iter().filter(|s| !s.is_empty()) {
+            world.create_debris(region);
+        }
+
+        Self::apply_shockwave(world, center, radius, power);
+        world.add_light_flash(cx, cy, 15, 0.2 + radius / 100.0);
+        Self::apply_entity_damage(world, center, radius, power);
+    }
+
+    /// Add pressure and heat on the coarse 8x8 grids inside the blast
+    fn apply_shockwave(world: &mut World, center: Vec2, radius: f32, power: f32) {
+        let r = radius.ceil() as i32;
+        let (cx, cy) = (center.x.round() as i32, center.y.round() as i32);
+
+        for dy in (-r..=r).step_by(8) {
+            for dx in (-r..=r).step_by(8) {
+                let distance = ((dx * dx + dy * dy) as f32).sqrt();
+                if distance > radius {
+                    continue;
+                }
+                let falloff = 1.0 - distance / radius;
+                let (x, y) = (cx + dx, cy + dy);
+
+                if let Some(pressure) = world.get_pressure_at(x, y) {
+                    world.set_pressure_at(x, y, pressure + PRESSURE_PER_POWER * power * falloff);
+                }
+
+                let (chunk_pos, local_x, local_y) =
+                    super::ChunkManager::world_to_chunk_coords(x, y);
+                if let Some(chunk) = world.chunks_mut().get_mut(&chunk_pos) {
+                    add_heat_at_pixel(chunk, local_x, local_y, HEAT_PER_POWER * power * falloff);
+                }
+            }
+        }
+    }
+
+    /// Damage and push the player and creatures within range
+    fn apply_entity_damage(world: &mut World, center: Vec2, radius: f32, power: f32) {
+        let range = radius * ENTITY_RANGE;
+        let impact = |position: Vec2| -> Option<(f32, Vec2)> {
+            let distance = position.distance(center);
+            if distance > range {
+                return None;
+            }
+            let falloff = 1.0 - distance / range;
+            let direction = (position - center).try_normalize().unwrap_or(Vec2::Y);
+            Some((
+                DAMAGE_PER_POWER * power * falloff,
+                direction * KNOCKBACK_PER_POWER * power * falloff,
+            ))
+        };
+
+        if let Some((damage, knockback)) = impact(world.player.position) {
+            world.player.health.take_damage(damage);
+            world.player.pending_knockback += knockback;
+        }
+
+        for creature in world.creature_manager.iter_mut() {
+            if let Some((damage, knockback)) = impact(creature.position) {
+                creature.health.take_damage(damage);
+                creature.velocity += knockback;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::Materials;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn create_test_world() -> World {
+        let mut world = World::new(true);
+        world.disable_persistence();
+        world.ensure_chunks_for_area(-128, -128, 128, 128);
+        world.player.position = Vec2::new(1000.0, 1000.0);
+        world
+    }
+
+    fn fill(world: &mut World, min: IVec2, max: IVec2, material: u16) {
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                world.set_pixel(x, y, material);
+            }
+        }
+    }
+
+    #[test]
+    fn test_explosive_yields() {
+        let materials = Materials::new();
+        let yield_of = |id| materials.get(id).explosive_yield.unwrap();
+        assert!(yield_of(MaterialId::GUNPOWDER) < yield_of(MaterialId::NITRO));
+        assert!(yield_of(MaterialId::NITRO) < yield_of(MaterialId::BOMB));
+        assert!(yield_of(MaterialId::BOMB) < yield_of(MaterialId::C_4));
+        assert!(materials.get(MaterialId::STONE).explosive_yield.is_none());
+    }
+
+    #[test]
+    fn test_blast_resistance() {
+        let materials = Materials::new();
+        let dirt = blast_resistance(materials.get(MaterialId::DIRT)).unwrap();
+        let stone = blast_resistance(materials.get(MaterialId::STONE)).unwrap();
+        assert!(dirt < stone);
+        assert!(blast_resistance(materials.get(MaterialId::BEDROCK)).is_none());
+        assert_eq!(
+            blast_resistance(materials.get(MaterialId::SMOKE)),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn test_nearby_charges_merge() {
+        let mut system = ExplosionSystem::new();
+        system.add_charge(Vec2::new(0.0, 0.0), 4.0);
+        system.add_charge(Vec2::new(2.0, 0.0), 4.0);
+        system.add_charge(Vec2::new(50.0, 0.0), 4.0);
+
+        assert_eq!(system.pending_count(), 2);
+        assert_eq!(system.pending[0].energy, 8.0);
+        assert_eq!(system.pending[0].center, Vec2::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_blast_destroys_weak_material_not_bedrock() {
+        let mut world = create_test_world();
+        let mut rng = StdRng::seed_from_u64(1);
+        fill(
+            &mut world,
+            IVec2::new(-20, -20),
+            IVec2::new(20, 20),
+            MaterialId::DIRT,
+        );
+        world.set_pixel(2, 0, MaterialId::BEDROCK);
+
+        let mut system = ExplosionSystem::new();
+        system.add_charge(Vec2::ZERO, 25.0);
+        assert_eq!(system.update(&mut world, &mut rng), 1);
+
+        let center = world.get_pixel(0, 0).unwrap().material_id;
+        assert!(center == MaterialId::AIR || center == MaterialId::FIRE);
+        assert_eq!(
+            world.get_pixel(2, 0).unwrap().material_id,
+            MaterialId::BEDROCK
+        );
+        // Far outside the radius is untouched
+        assert_eq!(
+            world.get_pixel(20, 20).unwrap().material_id,
+            MaterialId::DIRT
+        );
+    }
+
+    #[test]
+    fn test_detonation_recorded_by_chunk_is_consumed() {
+        let mut world = create_test_world();
+        let mut rng = StdRng::seed_from_u64(2);
+        world.set_pixel(5, 5, MaterialId::C_4);
+        world
+            .chunks_mut()
+            .get_mut(&IVec2::ZERO)
+            .unwrap()
+            .detonations
+            .push(IVec2::new(5, 5));
+
+        let mut system = ExplosionSystem::new();
+        assert_eq!(system.update(&mut world, &mut rng), 1);
+        assert_ne!(world.get_pixel(5, 5).unwrap().material_id, MaterialId::C_4);
+    }
+
+    #[test]
+    fn test_chain_detonation() {
+        let mut world = create_test_world();
+        let mut rng = StdRng::seed_from_u64(3);
+        world.set_pixel(8, 0, MaterialId::BOMB);
+
+        let mut system = ExplosionSystem::new();
+        system.add_charge(Vec2::ZERO, 25.0);
+        system.update(&mut world, &mut rng);
+
+        // The bomb was caught in the blast and queued for the next frame
+        assert_ne!(world.get_pixel(8, 0).unwrap().material_id, MaterialId::BOMB);
+        assert_eq!(system.pending_count(), 1);
+        assert_eq!(system.update(&mut world, &mut rng), 1);
+        assert_eq!(system.pending_count(), 0);
+    }
+
+    #[test]
+    fn test_player_damage_and_knockback() {
+        let mut world = create_test_world();
+        let mut rng = StdRng::seed_from_u64(4);
+        world.player.position = Vec2::new(5.0, 0.0);
+        let health_before = world.player.health.current;
+
+        let mut system = ExplosionSystem::new();
+        system.add_charge(Vec2::ZERO, 25.0);
+        system.update(&mut world, &mut rng);
+
+        assert!(world.player.health.current < health_before);
+        assert!(world.player.pending_knockback.x > 0.0);
+    }
+}
@@ -0,0 +1,486 @@
+//! Gas system - oxygen and carbon dioxide in the air
+//!
+//! Every coarse cell of a chunk tracks the oxygen (1.0 = fresh air) and carbon
+//! dioxide its air holds:
+//! - **Fire** burns oxygen into carbon dioxide and goes out (to `SMOKE`) once
+//!   the oxygen runs low
+//! - **Plants** in light turn carbon dioxide back into oxygen
+//! - **Diffusion** mixes neighboring cells through their open (gas-filled)
+//!   pixels, so a sealed cave keeps its own air and can be breathed empty
+//! - **Venting**: cells with a clear gas column up to the open sky exchange
+//!   with the atmosphere
+//!
+//! Players and creatures breathe from the cell at their head. Gas levels are
+//! saved with their chunk (chunks saved before that load with fresh air).
+
+use super::chunk_manager::ChunkManager;
+use super::weather_system::ColumnClimate;
+use super::{CHUNK_SIZE, Chunk};
+use glam::IVec2;
+use std::collections::HashMap;
+use sunaba_simulation::materials::{MaterialId, MaterialType, Materials};
+use sunaba_simulation::pixel::Pixel;
+
+/// Oxygen level of fresh air
+pub const FRESH_OXYGEN: f32 = 1.0;
+
+/// Below this oxygen level air can't be breathed
+pub const SUFFOCATION_OXYGEN: f32 = 0.3;
+
+/// Below this oxygen level fire goes out
+pub const MIN_COMBUSTION_OXYGEN: f32 = 0.15;
+
+/// Breathing units (see `MetabolicParams::oxygen_requirement`) held by a cell of fresh air
+pub const AIR_UNITS_PER_CELL: f32 = 10.0;
+
+/// Ticks between gas exchange passes
+const GAS_INTERVAL: u32 = 6;
+
+/// Oxygen burned into carbon dioxide per fire pixel per pass
+const FIRE_OXYGEN_USE: f32 = 0.01;
+
+/// Plants need at least this light level (0-15) to photosynthesize
+const PHOTOSYNTHESIS_LIGHT: u8 = 8;
+
+/// Oxygen produced per lit plant pixel per pass
+const PLANT_OXYGEN_OUTPUT: f32 = 0.002;
+
+/// Fraction of the difference between two open cells exchanged per pass
+const DIFFUSION_RATE: f32 = 0.2;
+
+/// Fraction of the gap to fresh air closed per pass above the surface
+const VENT_RATE: f32 = 0.2;
+
+/// Burns, grows, mixes and vents the air of the active chunks.
+pub struct GasSystem {
+    /// Ticks since the last gas exchange pass
+    ticks: u32,
+}
+
+impl GasSystem {
+    pub fn new() -> Self {
+        Self { ticks: 0 }
+    }
+
+    /// Runs a gas exchange pass every `GAS_INTERVAL` ticks.
+    pub fn update(
+        &mut self,
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        materials: &Materials,
+        climate: impl Fn(i32) -> ColumnClimate,
+    ) {
+        self.ticks += 1;
+        if self.ticks < GAS_INTERVAL {
+            return;
+        }
+        self.ticks = 0;
+        Self::exchange(chunks, active_chunks, materials, &climate);
+    }
+
+    /// One gas exchange pass over the active chunks
+    pub fn exchange(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        materials: &Materials,
+        climate: &impl Fn(i32) -> ColumnClimate,
+    ) {
+        let openness: HashMap<IVec2, [f32; 64]> = active_chunks
+            .iter()
+            .filter_map(|&pos| {
+                let chunk = chunks.get_mut(&pos)?;
+                Some((pos, Self::react(chunk, materials)))
+            })
+            .collect();
+
+        Self::diffuse(chunks, &openness);
+        Self::vent(chunks, &openness, materials, climate);
+    }
+
+    /// Burns and photosynthesizes within each cell; returns the open fraction of every cell
+    fn react(chunk: &mut Chunk, materials: &Materials) -> [f32; 64] {
+        let mut open = [0u32; 64];
+        let mut fires = [0u32; 64];
+        let mut plants = [0u32; 64];
+
+        for y in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                let idx = chunk.get_coarse_grid_index(x, y);
+                let material_id = chunk.get_material(x, y);
+                if materials.get(material_id).material_type == MaterialType::Gas {
+                    open[idx] += 1;
+                }
+                if material_id == MaterialId::FIRE {
+                    fires[idx] += 1;
+                } else if Self::is_plant(material_id)
+                    && chunk.get_light(x, y) >= PHOTOSYNTHESIS_LIGHT
+                {
+                    plants[idx] += 1;
+                }
+            }
+        }
+
+        let mut smothered = [false; 64];
+        for idx in 0..64 {
+            let burned = (fires[idx] as f32 * FIRE_OXYGEN_USE).min(chunk.oxygen[idx]);
+            chunk.oxygen[idx] -= burned;
+            chunk.carbon_dioxide[idx] += burned;
+
+            let produced = (plants[idx] as f32 * PLANT_OXYGEN_OUTPUT)
+                .min(FRESH_OXYGEN - chunk.oxygen[idx])
+                .max(0.0);
+            chunk.oxygen[idx] += produced;
+            chunk.carbon_dioxide[idx] = (chunk.carbon_dioxide[idx] - produced).max(0.0);
+
+            smothered[idx] = fires[idx] > 0 && chunk.oxygen[idx] < MIN_COMBUSTION_OXYGEN;
+        }
+
+        if smothered.contains(&true) {
+            for y in 0..CHUNK_SIZE {
+                for x in 0..CHUNK_SIZE {
+                    if smothered[chunk.get_coarse_grid_index(x, y)]
+                        && chunk.get_material(x, y) == MaterialId::FIRE
+                    {
+                        chunk.set_pixel(x, y, Pixel::new(MaterialId::SMOKE));
+                    }
+                }
+            }
+        }
+
+        open.map(|count| count as f32 / 64.0)
+    }
+
+    /// Mixes every cell with its right and upper neighbor through their open pixels
+    fn diffuse(chunks: &mut HashMap<IVec2, Chunk>, openness: &HashMap<IVec2, [f32; 64]>) {
+        let mut transfers = Vec::new();
+
+        for (&chunk_pos, open) in openness {
+            let Some(chunk) = chunks.get(&chunk_pos) else {
+                continue;
+            };
+            for cy in 0..8 {
+                for cx in 0..8 {
+                    let idx = cy * 8 + cx;
+                    if open[idx] <= 0.0 {
+                        continue;
+                    }
+                    let right = if cx < 7 {
+                        (chunk_pos, idx + 1)
+                    } else {
+                        (chunk_pos + IVec2::X, cy * 8)
+                    };
+                    let up = if cy < 7 {
+                        (chunk_pos, idx + 8)
+                    } else {
+                        (chunk_pos + IVec2::Y, cx)
+                    };
+
+                    for (neighbor_pos, neighbor_idx) in [right, up] {
+                        let (Some(neighbor_open), Some(neighbor)) =
+                            (openness.get(&neighbor_pos), chunks.get(&neighbor_pos))
+                        else {
+                            continue;
+                        };
+                        let rate = DIFFUSION_RATE * open[idx].min(neighbor_open[neighbor_idx]);
+                        if rate <= 0.0 {
+                            continue;
+                        }
+                        let oxygen =
+                            (chunk.oxygen[idx] - neighbor.oxygen[neighbor_idx]) * rate / 2.0;
+                        let carbon_dioxide = (chunk.carbon_dioxide[idx]
+                            - neighbor.carbon_dioxide[neighbor_idx])
+                            * rate
+                            / 2.0;
+                        transfers.push((chunk_pos, idx, -oxygen, -carbon_dioxide));
+                        transfers.push((neighbor_pos, neighbor_idx, oxygen, carbon_dioxide));
+                    }
+                }
+            }
+        }
+
+        for (chunk_pos, idx, oxygen, carbon_dioxide) in transfers {
+            if let Some(chunk) = chunks.get_mut(&chunk_pos) {
+                chunk.oxygen[idx] = (chunk.oxygen[idx] + oxygen).clamp(0.0, FRESH_OXYGEN);
+                chunk.carbon_dioxide[idx] = (chunk.carbon_dioxide[idx] + carbon_dioxide).max(0.0);
+            }
+        }
+    }
+
+    /// Open cells under the open sky drift back to fresh air
+    fn vent(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        openness: &HashMap<IVec2, [f32; 64]>,
+        materials: &Materials,
+        climate: &impl Fn(i32) -> ColumnClimate,
+    ) {
+        let mut loaded_rows: HashMap<i32, Vec<i32>> = HashMap::new();
+        for pos in chunks.keys() {
+            loaded_rows.entry(pos.x).or_default().push(pos.y);
+        }
+        for rows in loaded_rows.values_mut() {
+            rows.sort_unstable_by(|a, b| b.cmp(a));
+        }
+
+        let mut floors: HashMap<i32, Option<i32>> = HashMap::new();
+        for (&chunk_pos, open) in openness {
+            let base = chunk_pos * CHUNK_SIZE as i32;
+            let rows = &loaded_rows[&chunk_pos.x];
+            // Lowest cell row reached by the sky in each coarse column
+            let mut sky_from = [i32::MAX; 8];
+            for x in 0..CHUNK_SIZE as i32 {
+                let world_x = base.x + x;
+                let floor = *floors.entry(world_x).or_insert_with(|| {
+                    Self::sky_floor(chunks, materials, rows, world_x, climate(world_x).surface_y)
+                });
+                if let Some(floor) = floor {
+                    let cell = ((floor + 1 - base.y).max(0) / 8) as usize;
+                    let column = x as usize / 8;
+                    sky_from[column] = sky_from[column].min(cell as i32);
+                }
+            }
+
+            let Some(chunk) = chunks.get_mut(&chunk_pos) else {
+                continue;
+            };
+            for cx in 0..8 {
+                for cy in 0..8 {
+                    let idx = cy * 8 + cx;
+                    if open[idx] <= 0.0 || (cy as i32) < sky_from[cx] {
+                        continue;
+                    }
+                    chunk.oxygen[idx] += (FRESH_OXYGEN - chunk.oxygen[idx]) * VENT_RATE;
+                    chunk.carbon_dioxide[idx] *= 1.0 - VENT_RATE;
+                }
+            }
+        }
+    }
+
+    /// Height of the highest non-gas pixel of a world column, if the column is
+    /// loaded up into the sky above the generated surface
+    ///
+    /// `rows` are the loaded chunk rows of the column, topmost first. A gap in
+    /// them is unknown ground and blocks the sky like a solid pixel.
+    fn sky_floor(
+        chunks: &HashMap<IVec2, Chunk>,
+        materials: &Materials,
+        rows: &[i32],
+        world_x: i32,
+        surface_y: i32,
+    ) -> Option<i32> {
+        let size = CHUNK_SIZE as i32;
+        let top = *rows.first()?;
+        if (top + 1) * size - 1 <= surface_y {
+            return None;
+        }
+
+        let chunk_x = world_x.div_euclid(size);
+        let local_x = world_x.rem_euclid(size) as usize;
+        let mut expected = top;
+        for &chunk_y in rows {
+            if chunk_y != expected {
+                return Some((expected + 1) * size - 1);
+            }
+            let chunk = &chunks[&IVec2::new(chunk_x, chunk_y)];
+            if let Some(y) = (0..CHUNK_SIZE).rev().find(|&y| {
+                materials.get(chunk.get_material(local_x, y)).material_type != MaterialType::Gas
+            }) {
+                return Some(chunk_y * size + y as i32);
+            }
+            expected -= 1;
+        }
+        Some((expected + 1) * size - 1)
+    }
+
+    /// Oxygen level at world coordinates (None if the chunk isn't loaded)
+    pub fn oxygen_at(chunks: &HashMap<IVec2, Chunk>, pos: IVec2) -> Option<f32> {
+        let (chunk_pos, x, y) = ChunkManager::world_to_chunk_coords(pos.x, pos.y);
+        chunks
+            .get(&chunk_pos)
+            .map(|chunk| chunk.get_oxygen_at(x, y))
+    }
+
+    /// Breathes `amount` units of oxygen at world coordinates, exhaling carbon dioxide
+    pub fn breathe(chunks: &mut HashMap<IVec2, Chunk>, pos: IVec2, amount: f32) {
+        let (chunk_pos, x, y) = ChunkManager::world_to_chunk_coords(pos.x, pos.y);
+        if let Some(chunk) = chunks.get_mut(&chunk_pos) {
+            let idx = chunk.get_coarse_grid_index(x, y);
+            let used = (amount / AIR_UNITS_PER_CELL).min(chunk.oxygen[idx]);
+            chunk.oxygen[idx] -= used;
+            chunk.carbon_dioxide[idx] += used;
+        }
+    }
+
+    fn is_plant(material: u16) -> bool {
+        matches!(
+            material,
+            MaterialId::PLANT_MATTER | MaterialId::GRASS | MaterialId::SAPLING
+        )
+    }
+}
+
+impl Default for GasSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::biome::BiomeType;
+
+    /// Everything underground unless `surface_y` is below the chunk
+    fn climate(surface_y: i32) -> impl Fn(i32) -> ColumnClimate {
+        move |_| ColumnClimate {
+            biome: BiomeType::Plains,
+            surface_y,
+        }
+    }
+
+    /// Stone chunk with an open 8x8 cell at `(cx, cy)`
+    fn sealed_cell(cx: usize, cy: usize) -> Chunk {
+        let mut chunk = Chunk::new(0, 0);
+        for y in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                if x / 8 != cx || y / 8 != cy {
+                    chunk.set_material(x, y, MaterialId::STONE);
+                }
+            }
+        }
+        chunk
+    }
+
+    #[test]
+    fn test_fire_uses_oxygen_and_goes_out() {
+        let mut chunk = sealed_cell(2, 2);
+        for x in 16..24 {
+            chunk.set_material(x, 16, MaterialId::FIRE);
+        }
+        let mut chunks = HashMap::from([(IVec2::ZERO, chunk)]);
+        let materials = Materials::new();
+
+        for _ in 0..20 {
+            GasSystem::exchange(&mut chunks, &[IVec2::ZERO], &materials, &climate(1000));
+        }
+
+        let chunk = &chunks[&IVec2::ZERO];
+        let idx = 2 * 8 + 2;
+        assert!(chunk.oxygen[idx] < MIN_COMBUSTION_OXYGEN);
+        assert!(chunk.carbon_dioxide[idx] > 0.8);
+        assert!((16..24).all(|x| chunk.get_material(x, 16) == MaterialId::SMOKE));
+        assert_eq!(chunk.oxygen[0], FRESH_OXYGEN, "Solid cells are untouched");
+    }
+
+    #[test]
+    fn test_lit_plants_produce_oxygen() {
+        let materials = Materials::new();
+        let mut run = |light: u8| {
+            let mut chunk = sealed_cell(0, 0);
+            chunk.oxygen[0] = 0.5;
+            chunk.carbon_dioxide[0] = 0.5;
+            for x in 0..8 {
+                chunk.set_material(x, 0, MaterialId::PLANT_MATTER);
+                chunk.set_light(x, 0, light);
+            }
+            let mut chunks = HashMap::from([(IVec2::ZERO, chunk)]);
+            for _ in 0..10 {
+                GasSystem::exchange(&mut chunks, &[IVec2::ZERO], &materials, &climate(1000));
+            }
+            let chunk = &chunks[&IVec2::ZERO];
+            (chunk.oxygen[0], chunk.carbon_dioxide[0])
+        };
+
+        let (lit_oxygen, lit_carbon_dioxide) = run(15);
+        assert!(lit_oxygen > 0.6);
+        assert!(lit_carbon_dioxide < 0.4);
+        assert_eq!(
+            run(0),
+            (0.5, 0.5),
+            "Plants in the dark don't photosynthesize"
+        );
+    }
+
+    #[test]
+    fn test_air_mixes_through_open_cells_only() {
+        let mut chunk = Chunk::new(0, 0);
+        // Stone wall filling coarse column 4
+        for y in 0..CHUNK_SIZE {
+            for x in 32..40 {
+                chunk.set_material(x, y, MaterialId::STONE);
+            }
+        }
+        chunk.oxygen[0] = 0.0;
+        chunk.oxygen[5] = 0.0;
+        let mut chunks = HashMap::from([(IVec2::ZERO, chunk)]);
+        let materials = Materials::new();
+
+        for _ in 0..600 {
+            GasSystem::exchange(&mut chunks, &[IVec2::ZERO], &materials, &climate(1000));
+        }
+
+        // Each side evens out on its own without leaking through the wall
+        let chunk = &chunks[&IVec2::ZERO];
+        let side_total = |columns: std::ops::Range<usize>| -> f32 {
+            (0..8)
+                .flat_map(|cy| columns.clone().map(move |cx| cy * 8 + cx))
+                .map(|idx| chunk.oxygen[idx])
+                .sum()
+        };
+        assert!((side_total(0..4) - 31.0).abs() < 0.01);
+        assert!((side_total(5..8) - 23.0).abs() < 0.01);
+        assert!((chunk.oxygen[0] - 31.0 / 32.0).abs() < 0.01);
+        assert!((chunk.oxygen[5] - 23.0 / 24.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_surface_air_vents_to_fresh() {
+        let mut chunk = Chunk::new(0, 0);
+        chunk.oxygen = [0.2; 64];
+        chunk.carbon_dioxide = [0.8; 64];
+        let mut chunks = HashMap::from([(IVec2::ZERO, chunk)]);
+
+        for _ in 0..50 {
+            GasSystem::exchange(&mut chunks, &[IVec2::ZERO], &Materials::new(), &climate(-1));
+        }
+
+        let chunk = &chunks[&IVec2::ZERO];
+        assert!(chunk.oxygen.iter().all(|&o| o > 0.99));
+        assert!(chunk.carbon_dioxide.iter().all(|&c| c < 0.01));
+    }
+
+    #[test]
+    fn test_only_air_under_open_sky_vents() {
+        // Roof over the top of the chunk, with a hole above coarse column 0
+        let mut chunk = Chunk::new(0, 0);
+        for x in 8..CHUNK_SIZE {
+            chunk.set_material(x, 60, MaterialId::STONE);
+        }
+        chunk.oxygen = [0.2; 64];
+        let mut chunks = HashMap::from([(IVec2::ZERO, chunk)]);
+
+        // The whole chunk is above the generated surface
+        for _ in 0..50 {
+            GasSystem::exchange(&mut chunks, &[IVec2::ZERO], &Materials::new(), &climate(-1));
+        }
+
+        let chunk = &chunks[&IVec2::ZERO];
+        assert!(chunk.oxygen[0] > 0.99, "Air under the hole vents");
+        assert!(chunk.oxygen[7 * 8 + 4] > 0.99, "Air above the roof vents");
+        // Covered cells only get fresh air by mixing in from the open column
+        assert!(chunk.oxygen[4] < chunk.oxygen[0]);
+    }
+
+    #[test]
+    fn test_breathing_uses_oxygen() {
+        let mut chunks = HashMap::from([(IVec2::ZERO, Chunk::new(0, 0))]);
+
+        GasSystem::breathe(&mut chunks, IVec2::new(3, 3), AIR_UNITS_PER_CELL * 0.25);
+
+        assert_eq!(GasSystem::oxygen_at(&chunks, IVec2::new(3, 3)), Some(0.75));
+        assert_eq!(chunks[&IVec2::ZERO].carbon_dioxide[0], 0.25);
+        assert_eq!(
+            GasSystem::oxygen_at(&chunks, IVec2::new(20, 3)),
+            Some(FRESH_OXYGEN)
+        );
+    }
+}
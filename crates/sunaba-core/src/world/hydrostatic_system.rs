@@ -0,0 +1,415 @@
+//! Hydrostatic equalization for liquid bodies.
+//!
+//! The CA liquid rule only moves liquids down and sideways, so a liquid never
+//! climbs back up. This system levels connected liquid bodies instead: every
+//! few ticks each body (same liquid, 4-connected) is flood filled and its free
+//! surfaces are compared. Pixels are taken from the highest surface and placed
+//! on top of the lowest open surface, which gives:
+//! - **Communicating vessels**: both arms of a U-tube settle at the same height
+//! - **Fountains**: a nozzle below the reservoir level pushes liquid up and out
+//! - **Leaks under pressure**: a hole low in a tank keeps spilling until the
+//!   level outside matches
+//!
+//! Surface height is measured as pressure head: lighter liquids resting on a
+//! surface add their column weighted by `density`, so oil floating in one arm
+//! of a U-tube pushes the water on that side down.
+//!
+//! Moves conserve mass (one pixel removed per pixel placed). The system runs on
+//! the main thread once both checkerboard CA phases have finished, so it can
+//! write to any chunk without racing the parallel workers.
+
+use super::chunk_access::{self, ORTHOGONAL, pixel_at};
+use super::chunk_manager::ChunkManager;
+use super::{CHUNK_SIZE, Chunk};
+use glam::IVec2;
+use std::collections::{HashMap, HashSet, VecDeque};
+use sunaba_simulation::materials::{MaterialId, MaterialType, Materials};
+use sunaba_simulation::pixel::Pixel;
+
+/// Ticks between equalization passes
+pub const EQUALIZE_INTERVAL: u32 = 4;
+
+/// Min surface height difference (pixels of head) before liquid is moved;
+/// one move changes the difference by 2, so smaller gaps would oscillate
+const LEVEL_THRESHOLD: f32 = 2.0;
+
+/// Max pixels moved per body per pass
+const MAX_TRANSFERS_PER_BODY: usize = 8;
+
+/// Bodies larger than this are skipped (their surfaces can't be compared safely)
+const BODY_SEARCH_LIMIT: usize = 16384;
+
+/// A free surface of a liquid body
+#[derive(Debug, Clone, Copy)]
+struct Surface {
+    /// Top pixel of the liquid column
+    pos: IVec2,
+    /// Height of the surface plus the weighted head of lighter liquids above it
+    level: f32,
+    /// Air directly above, so liquid can be placed on top
+    open: bool,
+    /// First cell above the lighter liquids resting on this surface
+    top: IVec2,
+}
+
+/// Levels connected liquid bodies.
+pub struct HydrostaticSystem {
+    /// Ticks since the last equalization pass
+    ticks: u32,
+}
+
+impl HydrostaticSystem {
+    pub fn new() -> Self {
+        Self { ticks: 0 }
+    }
+
+    /// Runs an equalization pass every `EQUALIZE_INTERVAL` ticks.
+    ///
+    /// Returns the number of liquid pixels moved.
+    pub fn update(
+        &mut self,
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        materials: &Materials,
+    ) -> usize {
+        self.ticks += 1;
+        if self.ticks < EQUALIZE_INTERVAL {
+            return 0;
+        }
+        self.ticks = 0;
+        Self::equalize(chunks, active_chunks, materials)
+    }
+
+    /// Levels every liquid body with a surface in the given chunks.
+    pub fn equalize(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        materials: &Materials,
+    ) -> usize {
+        // Seed bodies from surface pixels, so fully submerged chunks cost nothing
+        let mut seeds: Vec<IVec2> = Vec::new();
+        for &chunk_pos in active_chunks {
+            if let Some(chunk) = chunks.get(&chunk_pos) {
+                for y in 0..CHUNK_SIZE {
+                    for x in 0..CHUNK_SIZE {
+                        let pixel = chunk.get_pixel(x, y);
+                        if !Self::is_liquid(materials, pixel) {
+                            continue;
+                        }
+                        let world_pos = IVec2::new(
+                            chunk_pos.x * CHUNK_SIZE as i32 + x as i32,
+                            chunk_pos.y * CHUNK_SIZE as i32 + y as i32,
+                        );
+                        let above = pixel_at(chunks, world_pos + IVec2::Y);
+                        if above.is_some_and(|p| p.material_id != pixel.material_id) {
+                            seeds.push(world_pos);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut visited: HashSet<IVec2> = HashSet::new();
+        let mut moved = 0;
+        for seed in seeds {
+            if visited.contains(&seed) {
+                continue;
+            }
+            // Earlier moves may have emptied this seed
+            let Some(pixel) = pixel_at(chunks, seed).filter(|&p| Self::is_liquid(materials, p))
+            else {
+                continue;
+            };
+            let Some(surfaces) = Self::body_surfaces(chunks, materials, seed, &mut visited) else {
+                continue;
+            };
+            moved += Self::level_body(chunks, pixel, surfaces);
+        }
+
+        moved
+    }
+
+    /// Flood fills the body containing `start` and returns its free surfaces,
+    /// or None if the body is too large or runs into unloaded chunks
+    ///
+    /// `visited` collects every pixel flooded this pass. A complete body is
+    /// closed off, so reaching a pixel an earlier flood visited means this is
+    /// the rest of a body that was too large, which is skipped as a whole.
+    fn body_surfaces(
+        chunks: &HashMap<IVec2, Chunk>,
+        materials: &Materials,
+        start: IVec2,
+        visited: &mut HashSet<IVec2>,
+    ) -> Option<Vec<Surface>> {
+        let fluid = pixel_at(chunks, start)?.material_id;
+        let density = materials.get(fluid).density;
+
+        let mut body = HashSet::from([start]);
+        visited.insert(start);
+        let mut queue = VecDeque::from([start]);
+        let mut complete = true;
+        let mut surfaces = Vec::new();
+
+        while let Some(pos) = queue.pop_front() {
+            if body.len() > BODY_SEARCH_LIMIT {
+                complete = false;
+                break;
+            }
+
+            if let Some(surface) = Self::surface_at(chunks, materials, pos, fluid, density) {
+                surfaces.push(surface);
+            }
+
+            for offset in ORTHOGONAL {
+                let next = pos + offset;
+                match pixel_at(chunks, next) {
+                    Some(p) if p.material_id == fluid => {
+                        if body.insert(next) {
+                            if !visited.insert(next) {
+                                complete = false;
+                            }
+                            queue.push_back(next);
+                        }
+                    }
+                    Some(_) => {}
+                    None => complete = false,
+                }
+            }
+        }
+
+        complete.then_some(surfaces)
+    }
+
+    /// Describes the surface at `pos` if it is the top of a column open to the air
+    ///
+    /// Lighter liquids stacked on top add their weighted head; a column capped by
+    /// a solid or a powder is confined and doesn't count as a free surface.
+    fn surface_at(
+        chunks: &HashMap<IVec2, Chunk>,
+        materials: &Materials,
+        pos: IVec2,
+        fluid: u16,
+        density: f32,
+    ) -> Option<Surface> {
+        let above = pixel_at(chunks, pos + IVec2::Y)?;
+        if above.material_id == fluid {
+            return None;
+        }
+
+        let mut head = 0.0;
+        let mut cursor = pos + IVec2::Y;
+        loop {
+            let pixel = pixel_at(chunks, cursor)?;
+            if pixel.is_empty() {
+                break;
+            }
+            let material = materials.get(pixel.material_id);
+            match material.material_type {
+                MaterialType::Liquid => head += material.density / density,
+                MaterialType::Gas => break,
+                MaterialType::Solid | MaterialType::Powder => return None,
+            }
+            cursor += IVec2::Y;
+        }
+
+        Some(Surface {
+            pos,
+            level: pos.y as f32 + head,
+            open: above.is_empty(),
+            top: cursor,
+        })
+    }
+
+    /// Moves pixels from the highest surfaces onto the lowest open ones
+    fn level_body(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        pixel: Pixel,
+        mut surfaces: Vec<Surface>,
+    ) -> usize {
+        let mut receivers: Vec<Surface> = surfaces.iter().filter(|s| s.open).copied().collect();
+        if receivers.is_empty() {
+            return 0;
+        }
+        surfaces.sort_by(|a, b| b.level.total_cmp(&a.level));
+        receivers.sort_by(|a, b| a.level.total_cmp(&b.level));
+
+        let mut moved = 0;
+        for (donor, receiver) in surfaces.iter().zip(&receivers).take(MAX_TRANSFERS_PER_BODY) {
+            if donor.level - receiver.level < LEVEL_THRESHOLD {
+                break;
+            }
+            // Lighter liquids on the donor sink into the gap instead of leaving a hole
+            for y in donor.pos.y..donor.top.y - 1 {
+                let above = IVec2::new(donor.pos.x, y + 1);
+                if let Some(lighter) = pixel_at(chunks, above) {
+                    Self::set_pixel_at(chunks, IVec2::new(donor.pos.x, y), lighter);
+                }
+            }
+            Self::set_pixel_at(chunks, donor.top - IVec2::Y, Pixel::new(MaterialId::AIR));
+            Self::set_pixel_at(chunks, receiver.pos + IVec2::Y, pixel);
+            moved += 1;
+        }
+        moved
+    }
+
+    fn is_liquid(materials: &Materials, pixel: Pixel) -> bool {
+        !pixel.is_empty() && materials.get(pixel.material_id).material_type == MaterialType::Liquid
+    }
+
+    /// Writes a pixel and wakes its chunk so the CA settles the moved liquid
+    fn set_pixel_at(chunks: &mut HashMap<IVec2, Chunk>, pos: IVec2, pixel: Pixel) {
+        chunk_access::set_pixel_at(chunks, pos, pixel);
+        let (chunk_pos, _, _) = ChunkManager::world_to_chunk_coords(pos.x, pos.y);
+        if let Some(chunk) = chunks.get_mut(&chunk_pos) {
+            chunk.set_simulation_active(true);
+        }
+    }
+}
+
+impl Default for HydrostaticSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::test_fixtures::count;
+
+    /// U-tube: stone walls, left arm x 10..=12, right arm x 20..=22, joined at y 5..=7
+    fn u_tube(left_level: i32, right_level: i32) -> HashMap<IVec2, Chunk> {
+        let mut chunk = Chunk::new(0, 0);
+        for y in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                let (xi, yi) = (x as i32, y as i32);
+                let in_left = (10..=12).contains(&xi) && yi >= 5;
+                let in_right = (20..=22).contains(&xi) && yi >= 5;
+                let in_bottom = (10..=22).contains(&xi) && (5..=7).contains(&yi);
+                if !(in_left || in_right || in_bottom) {
+                    chunk.set_material(x, y, MaterialId::STONE);
+                } else if (in_left && yi <= left_level)
+                    || (in_right && yi <= right_level)
+                    || in_bottom
+                {
+                    chunk.set_material(x, y, MaterialId::WATER);
+                }
+            }
+        }
+        HashMap::from([(IVec2::ZERO, chunk)])
+    }
+
+    fn column_height(chunks: &HashMap<IVec2, Chunk>, x: usize) -> usize {
+        (0..CHUNK_SIZE)
+            .filter(|&y| chunks[&IVec2::ZERO].get_material(x, y) == MaterialId::WATER)
+            .count()
+    }
+
+    #[test]
+    fn test_u_tube_levels_out() {
+        let mut chunks = u_tube(40, 10);
+        let materials = Materials::new();
+        let water_before = count(&chunks, MaterialId::WATER);
+
+        for _ in 0..50 {
+            HydrostaticSystem::equalize(&mut chunks, &[IVec2::ZERO], &materials);
+        }
+
+        assert_eq!(count(&chunks, MaterialId::WATER), water_before);
+        let left = column_height(&chunks, 11) as i32;
+        let right = column_height(&chunks, 21) as i32;
+        assert!((left - right).abs() <= 2, "left {} right {}", left, right);
+    }
+
+    #[test]
+    fn test_level_body_is_stable() {
+        let mut chunks = u_tube(20, 20);
+        let materials = Materials::new();
+        assert_eq!(
+            HydrostaticSystem::equalize(&mut chunks, &[IVec2::ZERO], &materials),
+            0
+        );
+    }
+
+    #[test]
+    fn test_lighter_liquid_pushes_surface_down() {
+        // Equal water levels, but oil floats on the left arm
+        let mut chunks = u_tube(20, 20);
+        {
+            let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+            for y in 21..=30 {
+                for x in 10..=12 {
+                    chunk.set_material(x, y, MaterialId::OIL);
+                }
+            }
+        }
+        let materials = Materials::new();
+
+        for _ in 0..50 {
+            HydrostaticSystem::equalize(&mut chunks, &[IVec2::ZERO], &materials);
+        }
+
+        // Oil's weight pushes water from the left arm into the right one
+        let left = column_height(&chunks, 11);
+        let right = column_height(&chunks, 21);
+        assert!(right >= left + 6, "left {} right {}", left, right);
+        // Oil sank with the water instead of leaving a gap
+        assert_eq!(count(&chunks, MaterialId::OIL), 30);
+        assert_eq!(
+            chunks[&IVec2::ZERO].get_material(11, 5 + left),
+            MaterialId::OIL
+        );
+    }
+
+    #[test]
+    fn test_oversized_body_is_left_alone() {
+        // Stone box 5 chunks wide and 2 high: the bottom row full of water
+        // (over BODY_SEARCH_LIMIT pixels) with a raised column at the left end
+        let mut chunks = HashMap::new();
+        let mut active = Vec::new();
+        for cy in 0..2 {
+            for cx in 0..5 {
+                let mut chunk = Chunk::new(cx, cy);
+                for y in 0..CHUNK_SIZE {
+                    for x in 0..CHUNK_SIZE {
+                        let wx = cx * CHUNK_SIZE as i32 + x as i32;
+                        let wy = cy * CHUNK_SIZE as i32 + y as i32;
+                        let material = if wx == 0 || wx == 319 || wy == 0 {
+                            MaterialId::STONE
+                        } else if wy < 64 || ((1..=3).contains(&wx) && wy <= 84) {
+                            MaterialId::WATER
+                        } else {
+                            MaterialId::AIR
+                        };
+                        chunk.set_material(x, y, material);
+                    }
+                }
+                chunks.insert(IVec2::new(cx, cy), chunk);
+                active.push(IVec2::new(cx, cy));
+            }
+        }
+        let materials = Materials::new();
+
+        // Every seed belongs to the same body, so no part of it is levelled
+        assert_eq!(
+            HydrostaticSystem::equalize(&mut chunks, &active, &materials),
+            0
+        );
+        assert_eq!(
+            chunks[&IVec2::new(0, 1)].get_material(2, 20),
+            MaterialId::WATER
+        );
+    }
+
+    #[test]
+    fn test_update_is_throttled() {
+        let mut chunks = u_tube(40, 10);
+        let materials = Materials::new();
+        let mut system = HydrostaticSystem::new();
+
+        for _ in 0..EQUALIZE_INTERVAL - 1 {
+            assert_eq!(system.update(&mut chunks, &[IVec2::ZERO], &materials), 0);
+        }
+        assert!(system.update(&mut chunks, &[IVec2::ZERO], &materials) > 0);
+    }
+}
@@ -6,7 +6,7 @@ use super::chunk_manager::ChunkManager;
 use crate::simulation::{LightPropagation, Materials};
 
 /// Day/night cycle duration in seconds (1200s = 20 minutes)
-const DAY_NIGHT_CYCLE_DURATION: f32 = 1200.0;
+pub const DAY_NIGHT_CYCLE_DURATION: f32 = 1200.0;
 
 /// Growth cycle duration in seconds (resources regenerate every 10s)
 const GROWTH_CYCLE_DURATION: f32 = 10.0;
@@ -70,6 +70,12 @@ impl LightSystem {
 
     /// Calculate current sky light level based on day/night cycle (0-15)
     pub fn calculate_sky_light(&self) -> u8 {
+        // -1 (midnight) → 0, 0 (dawn/dusk) → 7.5, 1 (noon) → 15
+        (self.daylight() * 15.0) as u8
+    }
+
+    /// Sun strength over the day/night cycle (0.0 = midnight, 1.0 = noon)
+    pub fn daylight(&self) -> f32 {
         // Convert time to angle (0-2π)
         let angle = (self.day_night_time / DAY_NIGHT_CYCLE_DURATION) * 2.0 * std::f32::consts::PI;
 
@@ -77,10 +83,8 @@ impl LightSystem {
         // Shift so 0s = midnight (cos(0) = 1, we want -1)
         let cosine = -(angle.cos());
 
-        // Map -1..1 to 0..15
-        // -1 (midnight) → 0, 0 (dawn/dusk) → 7.5, 1 (noon) → 15
-        let normalized = (cosine + 1.0) / 2.0; // 0..1
-        (normalized * 15.0) as u8
+        // Map -1..1 to 0..1
+        (cosine + 1.0) / 2.0
     }
 
     /// Initialize light levels before first CA update
@@ -0,0 +1,566 @@
+//! Logic components for the electrical system.
+//!
+//! Logic components are non-conductive pixels that read the POWERED flag of the
+//! conductors around them and drive power into the conductors they output to.
+//! Orientation comes from the DIRECTION bits (0 = up, 1 = right, 2 = down, 3 = left):
+//! - **Diode**: output = input behind
+//! - **NOT gate**: output = !input behind
+//! - **AND gate**: output = left input && right input
+//! - **Delay**: output = input behind as it was `DELAY_TICKS` ticks ago
+//! - **Pressure plate**: drives all orthogonal conductors while something rests on it
+//! - **Water sensor**: drives all orthogonal conductors while touching water
+//! - **Relay**: a connected group of relays opens (RELAY_OPEN) while any member touches power
+//!
+//! The current output of a component is stored in its BEHAVIOR_ACTIVE flag, so it is
+//! persisted with the chunk and visible to renderers.
+
+use super::chunk_access::{ORTHOGONAL, pixel_at, set_pixel_at};
+use super::chunk_manager::ChunkManager;
+use super::{CHUNK_SIZE, Chunk};
+use glam::IVec2;
+use std::collections::{HashMap, HashSet};
+use sunaba_simulation::materials::{MaterialId, MaterialType, Materials};
+use sunaba_simulation::pixel::{Pixel, pixel_flags};
+
+/// Ticks a delay holds each input before repeating it (0.5s at 60fps)
+pub const DELAY_TICKS: u8 = 30;
+
+/// Potential driven into an output conductor by an active component
+const OUTPUT_POTENTIAL: f32 = 50.0;
+
+/// Inputs a delay has seen over the last `DELAY_TICKS` ticks (ring buffer)
+struct DelayLine {
+    inputs: [bool; DELAY_TICKS as usize],
+    next: usize,
+}
+
+impl DelayLine {
+    /// A line that has been holding `output` for as long as it remembers
+    fn new(output: bool) -> Self {
+        Self {
+            inputs: [output; DELAY_TICKS as usize],
+            next: 0,
+        }
+    }
+
+    /// Record this tick's input and return the one from `DELAY_TICKS` ago
+    fn shift(&mut self, input: bool) -> bool {
+        let output = std::mem::replace(&mut self.inputs[self.next], input);
+        self.next = (self.next + 1) % self.inputs.len();
+        output
+    }
+
+    /// Whether an input edge is still travelling through the line
+    fn is_pending(&self, output: bool) -> bool {
+        self.inputs.iter().any(|&input| input != output)
+    }
+}
+
+/// Evaluates logic components once per electrical update.
+pub struct LogicSystem {
+    /// Delay components with an input edge still in flight: world pos -> recent inputs
+    delay_lines: HashMap<IVec2, DelayLine>,
+    /// World positions weighing down pressure plates (player/creature feet)
+    external_loads: Vec<IVec2>,
+}
+
+impl LogicSystem {
+    pub fn new() -> Self {
+        Self {
+            delay_lines: HashMap::new(),
+            external_loads: Vec::new(),
+        }
+    }
+
+    /// Set the entity positions that count as weight on pressure plates.
+    /// Replaces the loads from the previous tick.
+    pub fn set_external_loads(&mut self, loads: Vec<IVec2>) {
+        self.external_loads = loads;
+    }
+
+    /// Returns true if the material is a logic component handled by this system
+    pub fn is_logic_component(material_id: u16) -> bool {
+        matches!(
+            material_id,
+            MaterialId::DIODE
+                | MaterialId::NOT_GATE
+                | MaterialId::AND_GATE
+                | MaterialId::RELAY
+                | MaterialId::RELAY_OPEN
+                | MaterialId::PRESSURE_PLATE
+                | MaterialId::DELAY
+                | MaterialId::WATER_SENSOR
+        )
+    }
+
+    /// Evaluates all logic components in the active chunks.
+    ///
+    /// Returns the conductor pixels that were driven this tick, so the electrical
+    /// system can propagate power from them.
+    pub fn update(
+        &mut self,
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        materials: &Materials,
+    ) -> Vec<(IVec2, usize, usize)> {
+        // Collect components first to avoid borrow issues
+        let mut gates: Vec<(IVec2, Pixel)> = Vec::new();
+        let mut relays: Vec<IVec2> = Vec::new();
+
+        for &chunk_pos in active_chunks {
+            if let Some(chunk) = chunks.get(&chunk_pos) {
+                for y in 0..CHUNK_SIZE {
+                    for x in 0..CHUNK_SIZE {
+                        let pixel = chunk.get_pixel(x, y);
+                        if !Self::is_logic_component(pixel.material_id) {
+                            continue;
+                        }
+                        let world_pos = IVec2::new(
+                            chunk_pos.x * CHUNK_SIZE as i32 + x as i32,
+                            chunk_pos.y * CHUNK_SIZE as i32 + y as i32,
+                        );
+                        match pixel.material_id {
+                            MaterialId::RELAY | MaterialId::RELAY_OPEN => relays.push(world_pos),
+                            _ => gates.push((world_pos, pixel)),
+                        }
+                    }
+                }
+            }
+        }
+
+        // Evaluate every gate against the same snapshot so evaluation order doesn't matter
+        let outputs: Vec<(IVec2, Pixel, bool)> = gates
+            .iter()
+            .map(|&(pos, pixel)| (pos, pixel, self.evaluate(chunks, materials, pos, pixel)))
+            .collect();
+
+        // Forget lines of delays that are gone
+        let seen: HashSet<IVec2> = gates.iter().map(|(pos, _)| *pos).collect();
+        self.delay_lines.retain(|pos, _| seen.contains(pos));
+
+        let mut driven = Vec::new();
+        for (pos, pixel, active) in outputs {
+            let was_active = pixel.flags & pixel_flags::BEHAVIOR_ACTIVE != 0;
+            if active != was_active || self.delay_lines.contains_key(&pos) {
+                // Rewriting a delay with edges in flight keeps its chunk awake until they're out
+                let mut updated = pixel;
+                if active {
+                    updated.flags |= pixel_flags::BEHAVIOR_ACTIVE;
+                } else {
+                    updated.flags &= !pixel_flags::BEHAVIOR_ACTIVE;
+                }
+                set_pixel_at(chunks, pos, updated);
+            }
+
+            if active {
+                for target in Self::output_targets(pos, pixel) {
+                    if let Some(driven_pixel) = Self::drive(chunks, materials, target) {
+                        driven.push(driven_pixel);
+                    }
+                }
+            }
+        }
+
+        self.update_relays(chunks, materials, &relays);
+
+        driven
+    }
+
+    /// Computes the desired output of a (non-relay) component.
+    fn evaluate(
+        &mut self,
+        chunks: &HashMap<IVec2, Chunk>,
+        materials: &Materials,
+        pos: IVec2,
+        pixel: Pixel,
+    ) -> bool {
+        let forward = Self::direction_offset(pixel.direction());
+        let behind = pos - forward;
+
+        match pixel.material_id {
+            MaterialId::DIODE => Self::is_input_high(chunks, materials, behind),
+            MaterialId::NOT_GATE => !Self::is_input_high(chunks, materials, behind),
+            MaterialId::AND_GATE => {
+                let left = pos + IVec2::new(-forward.y, forward.x);
+                let right = pos + IVec2::new(forward.y, -forward.x);
+                Self::is_input_high(chunks, materials, left)
+                    && Self::is_input_high(chunks, materials, right)
+            }
+            MaterialId::DELAY => {
+                let input = Self::is_input_high(chunks, materials, behind);
+                let output = pixel.flags & pixel_flags::BEHAVIOR_ACTIVE != 0;
+                if input == output && !self.delay_lines.contains_key(&pos) {
+                    return output;
+                }
+                let line = self
+                    .delay_lines
+                    .entry(pos)
+                    .or_insert_with(|| DelayLine::new(output));
+                let delayed = line.shift(input);
+                if !line.is_pending(delayed) {
+                    self.delay_lines.remove(&pos);
+                }
+                delayed
+            }
+            MaterialId::PRESSURE_PLATE => self.is_plate_loaded(chunks, materials, pos),
+            MaterialId::WATER_SENSOR => (-1..=1)
+                .flat_map(|dy| (-1..=1).map(move |dx| IVec2::new(dx, dy)))
+                .filter(|offset| *offset != IVec2::ZERO)
+                .any(|offset| {
+                    pixel_at(chunks, pos + offset).is_some_and(|p| {
+                        matches!(
+                            p.material_id,
+                            MaterialId::WATER | MaterialId::SEAWATER | MaterialId::SOAPY_WATER
+                        )
+                    })
+                }),
+            _ => false,
+        }
+    }
+
+    /// A plate is loaded by a non-gas pixel resting on it or by an entity standing on it
+    fn is_plate_loaded(
+        &self,
+        chunks: &HashMap<IVec2, Chunk>,
+        materials: &Materials,
+        pos: IVec2,
+    ) -> bool {
+        let above = pos + IVec2::new(0, 1);
+        let pixel_load = pixel_at(chunks, above).is_some_and(|p| {
+            !p.is_empty()
+                && p.material_id != MaterialId::PRESSURE_PLATE
+                && materials.get(p.material_id).material_type != MaterialType::Gas
+        });
+
+        pixel_load
+            || self
+                .external_loads
+                .iter()
+                .any(|load| load.x == pos.x && (0..=2).contains(&(load.y - pos.y)))
+    }
+
+    /// Opens or closes connected relay groups depending on whether any member touches power
+    fn update_relays(
+        &self,
+        chunks: &mut HashMap<IVec2, Chunk>,
+        materials: &Materials,
+        relays: &[IVec2],
+    ) {
+        let relay_set: HashSet<IVec2> = relays.iter().copied().collect();
+        let mut visited: HashSet<IVec2> = HashSet::new();
+
+        for &start in relays {
+            if visited.contains(&start) {
+                continue;
+            }
+
+            // Flood fill the connected relay group
+            let mut group = Vec::new();
+            let mut stack = vec![start];
+            visited.insert(start);
+            while let Some(pos) = stack.pop() {
+                group.push(pos);
+                for offset in ORTHOGONAL {
+                    let next = pos + offset;
+                    if relay_set.contains(&next) && visited.insert(next) {
+                        stack.push(next);
+                    }
+                }
+            }
+
+            let powered = group.iter().any(|&pos| {
+                ORTHOGONAL
+                    .iter()
+                    .any(|&offset| Self::is_input_high(chunks, materials, pos + offset))
+            });
+            let target_material = if powered {
+                MaterialId::RELAY_OPEN
+            } else {
+                MaterialId::RELAY
+            };
+
+            for pos in group {
+                if let Some(pixel) = pixel_at(chunks, pos)
+                    && pixel.material_id != target_material
+                {
+                    let mut updated = pixel;
+                    updated.material_id = target_material;
+                    set_pixel_at(chunks, pos, updated);
+                }
+            }
+        }
+    }
+
+    /// Pixels a component drives while active
+    fn output_targets(pos: IVec2, pixel: Pixel) -> Vec<IVec2> {
+        match pixel.material_id {
+            MaterialId::PRESSURE_PLATE | MaterialId::WATER_SENSOR => {
+                ORTHOGONAL.iter().map(|&offset| pos + offset).collect()
+            }
+            _ => vec![pos + Self::direction_offset(pixel.direction())],
+        }
+    }
+
+    /// Pushes power into a conductor; returns its chunk-local position if it was driven
+    fn drive(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        materials: &Materials,
+        target: IVec2,
+    ) -> Option<(IVec2, usize, usize)> {
+        let (chunk_pos, x, y) = ChunkManager::world_to_chunk_coords(target.x, target.y);
+        let chunk = chunks.get_mut(&chunk_pos)?;
+        let mut pixel = chunk.get_pixel(x, y);
+        if !materials.get(pixel.material_id).conducts_electricity {
+            return None;
+        }
+
+        let coarse_idx = chunk.get_coarse_grid_index(x, y);
+        chunk.electrical_potential[coarse_idx] =
+            chunk.electrical_potential[coarse_idx].max(OUTPUT_POTENTIAL);
+        pixel.flags |= pixel_flags::POWERED;
+        chunk.set_pixel(x, y, pixel);
+        Some((chunk_pos, x, y))
+    }
+
+    /// An input is high when it is a powered conductor
+    pub(super) fn is_input_high(
+        chunks: &HashMap<IVec2, Chunk>,
+        materials: &Materials,
+        pos: IVec2,
+    ) -> bool {
+        pixel_at(chunks, pos).is_some_and(|p| {
+            p.flags & pixel_flags::POWERED != 0 && materials.get(p.material_id).conducts_electricity
+        })
+    }
+
+    /// Converts direction (0-3) to a world offset (y up)
+    pub(super) fn direction_offset(dir: u8) -> IVec2 {
+        ORTHOGONAL[(dir & 3) as usize]
+    }
+}
+
+impl Default for LogicSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::test_fixtures::empty_chunk;
+
+    fn powered_wire() -> Pixel {
+        let mut wire = Pixel::new(MaterialId::WIRE);
+        wire.flags |= pixel_flags::POWERED;
+        wire
+    }
+
+    fn is_powered(chunks: &HashMap<IVec2, Chunk>, x: usize, y: usize) -> bool {
+        chunks[&IVec2::ZERO].get_pixel(x, y).flags & pixel_flags::POWERED != 0
+    }
+
+    #[test]
+    fn test_diode_forwards_power() {
+        let (mut chunks, active, materials) = empty_chunk();
+        let mut system = LogicSystem::new();
+        let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+        // Facing right: input at x=9, output at x=11
+        chunk.set_pixel(9, 10, powered_wire());
+        chunk.set_pixel(10, 10, Pixel::new(MaterialId::DIODE).with_direction(1));
+        chunk.set_pixel(11, 10, Pixel::new(MaterialId::WIRE));
+
+        let driven = system.update(&mut chunks, &active, &materials);
+
+        assert_eq!(driven, vec![(IVec2::ZERO, 11, 10)]);
+        assert!(is_powered(&chunks, 11, 10));
+    }
+
+    #[test]
+    fn test_diode_blocks_reverse_power() {
+        let (mut chunks, active, materials) = empty_chunk();
+        let mut system = LogicSystem::new();
+        let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+        // Facing right, but power arrives from the output side
+        chunk.set_pixel(9, 10, Pixel::new(MaterialId::WIRE));
+        chunk.set_pixel(10, 10, Pixel::new(MaterialId::DIODE).with_direction(1));
+        chunk.set_pixel(11, 10, powered_wire());
+
+        let driven = system.update(&mut chunks, &active, &materials);
+
+        assert!(driven.is_empty());
+        assert!(!is_powered(&chunks, 9, 10));
+    }
+
+    #[test]
+    fn test_not_gate_inverts() {
+        let (mut chunks, active, materials) = empty_chunk();
+        let mut system = LogicSystem::new();
+        let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+        // Facing up: input below at y=9, output above at y=11
+        chunk.set_pixel(10, 9, Pixel::new(MaterialId::WIRE));
+        chunk.set_pixel(10, 10, Pixel::new(MaterialId::NOT_GATE));
+        chunk.set_pixel(10, 11, Pixel::new(MaterialId::WIRE));
+
+        system.update(&mut chunks, &active, &materials);
+        assert!(is_powered(&chunks, 10, 11), "Unpowered input drives output");
+
+        // Power the input and clear the output
+        let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+        chunk.set_pixel(10, 9, powered_wire());
+        chunk.set_pixel(10, 11, Pixel::new(MaterialId::WIRE));
+
+        let driven = system.update(&mut chunks, &active, &materials);
+        assert!(driven.is_empty(), "Powered input silences output");
+        let gate = chunks[&IVec2::ZERO].get_pixel(10, 10);
+        assert_eq!(gate.flags & pixel_flags::BEHAVIOR_ACTIVE, 0);
+    }
+
+    #[test]
+    fn test_and_gate_requires_both_inputs() {
+        let (mut chunks, active, materials) = empty_chunk();
+        let mut system = LogicSystem::new();
+        let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+        // Facing up: sides at x=9 and x=11, output at y=11
+        chunk.set_pixel(9, 10, powered_wire());
+        chunk.set_pixel(10, 10, Pixel::new(MaterialId::AND_GATE));
+        chunk.set_pixel(11, 10, Pixel::new(MaterialId::WIRE));
+        chunk.set_pixel(10, 11, Pixel::new(MaterialId::WIRE));
+
+        let driven = system.update(&mut chunks, &active, &materials);
+        assert!(driven.is_empty(), "One input is not enough");
+
+        chunks
+            .get_mut(&IVec2::ZERO)
+            .unwrap()
+            .set_pixel(11, 10, powered_wire());
+        let driven = system.update(&mut chunks, &active, &materials);
+        assert_eq!(driven, vec![(IVec2::ZERO, 10, 11)]);
+    }
+
+    #[test]
+    fn test_delay_waits_before_switching() {
+        let (mut chunks, active, materials) = empty_chunk();
+        let mut system = LogicSystem::new();
+        let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+        chunk.set_pixel(10, 9, powered_wire());
+        chunk.set_pixel(10, 10, Pixel::new(MaterialId::DELAY));
+        chunk.set_pixel(10, 11, Pixel::new(MaterialId::WIRE));
+
+        for _ in 0..DELAY_TICKS {
+            let driven = system.update(&mut chunks, &active, &materials);
+            assert!(driven.is_empty(), "Output must wait for the delay");
+        }
+
+        let driven = system.update(&mut chunks, &active, &materials);
+        assert_eq!(driven, vec![(IVec2::ZERO, 10, 11)]);
+    }
+
+    #[test]
+    fn test_delay_replays_short_pulse() {
+        let (mut chunks, active, materials) = empty_chunk();
+        let mut system = LogicSystem::new();
+        let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+        chunk.set_pixel(10, 9, powered_wire());
+        chunk.set_pixel(10, 10, Pixel::new(MaterialId::DELAY));
+        chunk.set_pixel(10, 11, Pixel::new(MaterialId::WIRE));
+
+        // One-tick pulse
+        system.update(&mut chunks, &active, &materials);
+        chunks
+            .get_mut(&IVec2::ZERO)
+            .unwrap()
+            .set_pixel(10, 9, Pixel::new(MaterialId::WIRE));
+
+        for _ in 1..DELAY_TICKS {
+            let driven = system.update(&mut chunks, &active, &materials);
+            assert!(driven.is_empty(), "Pulse must wait for the delay");
+        }
+        let driven = system.update(&mut chunks, &active, &materials);
+        assert_eq!(driven, vec![(IVec2::ZERO, 10, 11)]);
+
+        // The pulse comes out one tick long
+        chunks
+            .get_mut(&IVec2::ZERO)
+            .unwrap()
+            .set_pixel(10, 11, Pixel::new(MaterialId::WIRE));
+        assert!(system.update(&mut chunks, &active, &materials).is_empty());
+        assert!(system.delay_lines.is_empty());
+    }
+
+    #[test]
+    fn test_pressure_plate_triggered_by_weight() {
+        let (mut chunks, active, materials) = empty_chunk();
+        let mut system = LogicSystem::new();
+        let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+        chunk.set_pixel(10, 10, Pixel::new(MaterialId::PRESSURE_PLATE));
+        chunk.set_pixel(11, 10, Pixel::new(MaterialId::WIRE));
+
+        assert!(system.update(&mut chunks, &active, &materials).is_empty());
+
+        // Sand resting on the plate
+        chunks
+            .get_mut(&IVec2::ZERO)
+            .unwrap()
+            .set_material(10, 11, MaterialId::SAND);
+        assert_eq!(system.update(&mut chunks, &active, &materials).len(), 1);
+
+        // Smoke is too light, but a player standing on it counts
+        chunks
+            .get_mut(&IVec2::ZERO)
+            .unwrap()
+            .set_material(10, 11, MaterialId::SMOKE);
+        assert!(system.update(&mut chunks, &active, &materials).is_empty());
+        system.set_external_loads(vec![IVec2::new(10, 11)]);
+        assert_eq!(system.update(&mut chunks, &active, &materials).len(), 1);
+    }
+
+    #[test]
+    fn test_water_sensor() {
+        let (mut chunks, active, materials) = empty_chunk();
+        let mut system = LogicSystem::new();
+        let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+        chunk.set_pixel(10, 10, Pixel::new(MaterialId::WATER_SENSOR));
+        chunk.set_pixel(10, 9, Pixel::new(MaterialId::WIRE));
+
+        assert!(system.update(&mut chunks, &active, &materials).is_empty());
+
+        chunks
+            .get_mut(&IVec2::ZERO)
+            .unwrap()
+            .set_material(11, 11, MaterialId::WATER);
+        assert_eq!(
+            system.update(&mut chunks, &active, &materials),
+            vec![(IVec2::ZERO, 10, 9)]
+        );
+    }
+
+    #[test]
+    fn test_relay_group_opens_and_closes() {
+        let (mut chunks, active, materials) = empty_chunk();
+        let mut system = LogicSystem::new();
+        let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+        // Vertical door of three relays, powered at the bottom only
+        chunk.set_pixel(10, 10, Pixel::new(MaterialId::RELAY));
+        chunk.set_pixel(10, 11, Pixel::new(MaterialId::RELAY));
+        chunk.set_pixel(10, 12, Pixel::new(MaterialId::RELAY));
+        chunk.set_pixel(10, 9, powered_wire());
+
+        system.update(&mut chunks, &active, &materials);
+        for y in 10..=12 {
+            assert_eq!(
+                chunks[&IVec2::ZERO].get_material(10, y),
+                MaterialId::RELAY_OPEN
+            );
+        }
+
+        chunks
+            .get_mut(&IVec2::ZERO)
+            .unwrap()
+            .set_material(10, 9, MaterialId::WIRE);
+        system.update(&mut chunks, &active, &materials);
+        for y in 10..=12 {
+            assert_eq!(chunks[&IVec2::ZERO].get_material(10, y), MaterialId::RELAY);
+        }
+    }
+}
@@ -6,15 +6,22 @@ pub mod biome_zones;
 mod ca_update;
 mod chemistry_system;
 mod chunk;
+mod chunk_access;
 mod chunk_manager;
 mod chunk_status;
 mod collision;
+mod container_system;
 pub mod context_scanner;
+mod day_night_system;
 mod debris_system;
 pub mod electrical_system;
+mod explosion_system;
 pub mod features;
+mod gas_system;
 pub mod generation;
+mod hydrostatic_system;
 mod light_system;
+mod logic_system;
 mod mining_system;
 mod neighbor_queries;
 pub mod persistence;
@@ -22,8 +29,10 @@ mod persistence_system;
 #[cfg(feature = "regeneration")]
 pub mod pixel_entity_system;
 mod pixel_queries;
+mod player_environment;
 mod player_physics;
 pub mod pressure_system;
+mod pump_system;
 mod raycasting;
 pub mod rng_trait;
 #[cfg(feature = "regeneration")]
@@ -32,6 +41,12 @@ pub mod stats;
 pub mod structure_placement;
 pub mod structure_templates;
 pub mod structures;
+#[cfg(test)]
+mod test_fixtures;
+mod tool_system;
+mod vegetation_system;
+mod weather_system;
+mod workstation_system;
 #[allow(clippy::module_inception)]
 mod world;
 pub mod worldgen_config;
@@ -41,30 +56,48 @@ pub use biome_transition::{
     BiomeTransition, BlendMode, MaterialStability, classify_material_stability, find_biome_boundary,
 };
 pub use biome_zones::{BiomeZoneRegistry, UndergroundZone, ZoneDefinition, ZoneTransition};
-pub use chunk::{CHUNK_SIZE, Chunk, Pixel, pixel_flags};
+pub use chunk::{CHUNK_SIZE, Chunk, DirtyRect, Pixel, pixel_flags};
 pub use chunk_manager::ChunkManager;
 pub use chunk_status::ChunkStatus;
+pub use container_system::{ContainerSystem, ContainerUse};
 pub use context_scanner::{
     ContextScanner, MAX_SCAN_DISTANCE, PlacementContext, PlacementPredicate,
 };
+pub use day_night_system::{DayNightSettings, DayNightSystem, NIGHT_DAYLIGHT};
 pub use debris_system::DebrisSystem;
 pub use electrical_system::ElectricalSystem;
+pub use explosion_system::{Explosion, ExplosionSystem, blast_resistance};
+pub use gas_system::{
+    AIR_UNITS_PER_CELL, FRESH_OXYGEN, GasSystem, MIN_COMBUSTION_OXYGEN, SUFFOCATION_OXYGEN,
+};
 pub use generation::WorldGenerator;
-pub use light_system::LightSystem;
+pub use hydrostatic_system::{EQUALIZE_INTERVAL, HydrostaticSystem};
+pub use light_system::{DAY_NIGHT_CYCLE_DURATION, LightSystem};
+pub use logic_system::LogicSystem;
 pub use mining_system::MiningSystem;
 pub use neighbor_queries::NeighborQueries;
-pub use persistence::{ChunkPersistence, WorldMetadata};
+pub use persistence::{ChunkPersistence, WorldMetadata, decode_chunk_blob, encode_chunk_blob};
 pub use persistence_system::PersistenceSystem;
 #[cfg(feature = "regeneration")]
 pub use pixel_entity_system::PixelEntitySystem;
 pub use pixel_queries::PixelQueries;
+pub use player_environment::PlayerEnvironmentSystem;
 pub use player_physics::PlayerPhysicsSystem;
+pub use pump_system::{PUMP_FLOW_PER_TICK, PumpSystem};
 pub use raycasting::Raycasting;
 pub use rng_trait::WorldRng;
 #[cfg(feature = "regeneration")]
 pub use special_behaviors_system::SpecialBehaviorsSystem;
 pub use stats::{NoopStats, SimStats};
 pub use structures::{AnchorType, StructureTemplate, StructureVariants};
+pub use tool_system::{TOOL_REACH, ToolSystem, ToolUse};
+pub use vegetation_system::VegetationSystem;
+pub use weather_system::{
+    ColumnClimate, MAX_WIND_SPEED, WeatherKind, WeatherState, WeatherSystem, biome_temperature,
+};
+pub use workstation_system::{
+    Furnace, PlacedWorkstation, SmeltingJob, WORKSTATION_REACH, WorkstationSystem,
+};
 pub use world::World;
 pub use worldgen_config::{
     BiomeBlendModeConfig, BiomeConfig, BiomeParams, BiomeTransitionConfig, BridgeConfig,
@@ -1,6 +1,7 @@
 use crate::entity::player::Player;
-use crate::world::chunk::Chunk;
+use crate::world::chunk::{Chunk, ChunkV1};
 use crate::world::generation::WorldGenerator;
+use crate::world::workstation_system::PlacedWorkstation;
 #[allow(unused_imports)]
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
@@ -33,6 +34,10 @@ pub struct WorldMetadata {
     /// Player save data (inventory, health, hunger)
     #[serde(default)]
     pub player_data: Option<Player>,
+
+    /// Placed workstations (furnace fuel and smelting queues included)
+    #[serde(default)]
+    pub workstations: Vec<PlacedWorkstation>,
 }
 
 impl Default for WorldMetadata {
@@ -66,10 +71,32 @@ impl Default for WorldMetadata {
             last_played,
             play_time_seconds: 0,
             player_data: None, // Will be populated on first save
+            workstations: Vec::new(),
         }
     }
 }
 
+/// Encode a chunk as a server blob (the `chunk_data.pixel_data` format)
+///
+/// Chunk files hold the same bincode bytes, lz4-compressed.
+pub fn encode_chunk_blob(chunk: &Chunk) -> Result<Vec<u8>> {
+    bincode_next::serde::encode_to_vec(chunk, bincode_next::config::standard())
+        .context("Failed to serialize chunk")
+}
+
+/// Decode a server blob (or a decompressed chunk file)
+///
+/// Blobs saved before the gas levels (layout version 1) load with fresh air.
+pub fn decode_chunk_blob(blob: &[u8]) -> Result<Chunk> {
+    let config = bincode_next::config::standard();
+    match bincode_next::serde::decode_from_slice::<Chunk, _>(blob, config) {
+        Ok((chunk, _)) => Ok(chunk),
+        Err(e) => bincode_next::serde::decode_from_slice::<ChunkV1, _>(blob, config)
+            .map(|(old, _)| old.into())
+            .map_err(|_| anyhow::anyhow!("Failed to deserialize chunk: {:?}", e)),
+    }
+}
+
 /// Manages chunk save/load operations with compression
 pub struct ChunkPersistence {
     #[cfg(not(target_arch = "wasm32"))]
@@ -119,8 +146,7 @@ fn save_worker_thread(rx: Receiver<SaveRequest>, world_dir: PathBuf) {
 #[cfg(not(target_arch = "wasm32"))]
 fn save_chunk_sync(chunk: &Chunk, path: &Path) -> Result<usize> {
     // Serialize with bincode
-    let serialized = bincode_next::serde::encode_to_vec(chunk, bincode_next::config::standard())
-        .context("Failed to serialize chunk")?;
+    let serialized = encode_chunk_blob(chunk)?;
 
     // Compress with lz4
     let compressed = lz4_flex::compress_prepend_size(&serialized);
@@ -190,8 +216,7 @@ impl ChunkPersistence {
         Ok(())
     }
 
-    /// Save chunk synchronously (blocking, for tests)
-    #[cfg(test)]
+    /// Save chunk synchronously (blocking, for tests and server shutdown)
     pub fn save_chunk_blocking(&self, chunk: &Chunk) -> Result<()> {
         let path = self.chunk_path(chunk.x, chunk.y);
         save_chunk_sync(chunk, &path).context("Failed to save chunk synchronously")?;
@@ -260,16 +285,58 @@ impl ChunkPersistence {
             .context("Failed to decompress chunk")?;
         log::debug!("Decompressed to {} bytes", serialized.len());
 
-        let (chunk, _): (Chunk, _) =
-            bincode_next::serde::decode_from_slice(&serialized, bincode_next::config::standard())
-                .map_err(|e| {
-                log::error!("Bincode deserialization error: {:?}", e);
-                anyhow::anyhow!("Failed to deserialize chunk: {:?}", e)
-            })?;
+        let chunk = decode_chunk_blob(&serialized).inspect_err(|e| {
+            log::error!("Bincode deserialization error: {:?}", e);
+        })?;
         log::debug!("Successfully deserialized chunk");
         Ok(chunk)
     }
 
+    /// Coordinates of every chunk saved on disk
+    pub fn saved_chunk_positions(&self) -> Result<Vec<(i32, i32)>> {
+        let entries = std::fs::read_dir(self.world_dir.join("chunks"))
+            .context("Failed to read chunks directory")?;
+
+        let mut positions = Vec::new();
+        for entry in entries {
+            let name = entry.context("Failed to read chunk entry")?.file_name();
+            let Some(coords) = name
+                .to_str()
+                .and_then(|n| n.strip_prefix("chunk_"))
+                .and_then(|n| n.strip_suffix(".bin"))
+            else {
+                continue;
+            };
+            if let Some((x, y)) = coords.split_once('_')
+                && let (Ok(x), Ok(y)) = (x.parse(), y.parse())
+            {
+                positions.push((x, y));
+            }
+        }
+        positions.sort_unstable();
+        Ok(positions)
+    }
+
+    /// Saved chunk as a server blob (for uploading to a multiplayer server),
+    /// or `None` if the chunk was never saved
+    pub fn export_chunk_blob(&self, chunk_x: i32, chunk_y: i32) -> Result<Option<Vec<u8>>> {
+        let path = self.chunk_path(chunk_x, chunk_y);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let compressed = std::fs::read(&path).context("Failed to read chunk file")?;
+        let blob = lz4_flex::decompress_size_prepended(&compressed)
+            .context("Failed to decompress chunk")?;
+        Ok(Some(blob))
+    }
+
+    /// Save a server blob (downloaded from a multiplayer server) as a chunk file
+    pub fn import_chunk_blob(&self, blob: &[u8]) -> Result<Chunk> {
+        let chunk = decode_chunk_blob(blob)?;
+        self.save_chunk_blocking(&chunk)?;
+        Ok(chunk)
+    }
+
     fn chunk_path(&self, x: i32, y: i32) -> PathBuf {
         self.world_dir
             .join("chunks")
@@ -285,6 +352,11 @@ impl ChunkPersistence {
         Ok(())
     }
 
+    /// Whether this world has saved metadata (false for a brand-new world)
+    pub fn has_metadata(&self) -> bool {
+        self.world_dir.join("world.meta").exists()
+    }
+
     /// Load world metadata from disk, or create default
     pub fn load_metadata(&self) -> WorldMetadata {
         let path = self.world_dir.join("world.meta");
@@ -312,6 +384,20 @@ impl ChunkPersistence {
         }
     }
 
+    /// Move a world aside to `worlds/<name>_backup_<timestamp>` (no-op if it
+    /// doesn't exist); returns the backup directory
+    pub fn archive_world(world_name: &str) -> Result<Option<PathBuf>> {
+        let world_dir = PathBuf::from("worlds").join(world_name);
+        if !world_dir.exists() {
+            return Ok(None);
+        }
+        let stamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+        let backup_dir = PathBuf::from("worlds").join(format!("{}_backup_{}", world_name, stamp));
+        std::fs::rename(&world_dir, &backup_dir).context("Failed to archive world directory")?;
+        log::info!("Archived world {} to {:?}", world_name, backup_dir);
+        Ok(Some(backup_dir))
+    }
+
     /// Delete all chunks and metadata (used by --regenerate)
     pub fn delete_world(world_name: &str) -> Result<()> {
         let world_dir = PathBuf::from("worlds").join(world_name);
@@ -389,6 +475,94 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_chunk_blob_export_import() -> Result<()> {
+        let test_world = "test_chunk_blobs";
+        let persistence = ChunkPersistence::new(test_world)?;
+
+        let mut chunk = Chunk::new(-2, 7);
+        chunk.set_material(1, 2, 42);
+        persistence.save_chunk_blocking(&chunk)?;
+
+        // Exported blobs are plain bincode, like the server's chunk rows
+        let blob = persistence.export_chunk_blob(-2, 7)?.expect("saved chunk");
+        assert_eq!(blob, encode_chunk_blob(&chunk)?);
+        assert!(persistence.export_chunk_blob(0, 0)?.is_none());
+
+        let mut other = Chunk::new(3, -4);
+        other.set_material(5, 6, 99);
+        let imported = persistence.import_chunk_blob(&encode_chunk_blob(&other)?)?;
+        assert_eq!((imported.x, imported.y), (3, -4));
+        assert!(persistence.import_chunk_blob(&[1, 2, 3]).is_err());
+
+        assert_eq!(persistence.saved_chunk_positions()?, vec![(-2, 7), (3, -4)]);
+        let generator = WorldGenerator::new(0);
+        assert_eq!(
+            persistence.load_chunk(3, -4, &generator).get_material(5, 6),
+            99
+        );
+
+        ChunkPersistence::delete_world(test_world)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_chunk_blob_from_before_gas_fields() -> Result<()> {
+        use crate::world::chunk::{CHUNK_AREA, Pixel};
+
+        /// Chunk layout before oxygen and carbon dioxide were added
+        #[derive(serde::Serialize)]
+        struct OldChunk {
+            x: i32,
+            y: i32,
+            #[serde(with = "serde_big_array::BigArray")]
+            pixels: [Pixel; CHUNK_AREA],
+            #[serde(with = "serde_big_array::BigArray")]
+            background: [u16; CHUNK_AREA],
+            #[serde(with = "serde_big_array::BigArray")]
+            temperature: [f32; 64],
+            #[serde(with = "serde_big_array::BigArray")]
+            pressure: [f32; 64],
+            #[serde(with = "serde_big_array::BigArray")]
+            electrical_potential: [f32; 64],
+            #[serde(with = "serde_big_array::BigArray")]
+            light_levels: [u8; CHUNK_AREA],
+        }
+
+        let mut old = OldChunk {
+            x: 4,
+            y: -1,
+            pixels: [Pixel::AIR; CHUNK_AREA],
+            background: [0; CHUNK_AREA],
+            temperature: [20.0; 64],
+            pressure: [1.0; 64],
+            electrical_potential: [0.0; 64],
+            light_levels: [15; CHUNK_AREA],
+        };
+        old.pixels[3 * 64 + 2] = Pixel::new(42);
+        old.electrical_potential[9] = 5.0;
+        let blob = bincode_next::serde::encode_to_vec(&old, bincode_next::config::standard())
+            .map_err(|e| anyhow::anyhow!("{:?}", e))?;
+
+        let chunk = decode_chunk_blob(&blob)?;
+        assert_eq!((chunk.x, chunk.y), (4, -1));
+        assert_eq!(chunk.get_material(2, 3), 42);
+        assert_eq!(chunk.electrical_potential[9], 5.0);
+        assert_eq!(chunk.light_levels[0], 15);
+        assert_eq!(chunk.oxygen, [1.0; 64]);
+        assert_eq!(chunk.carbon_dioxide, [0.0; 64]);
+
+        // The current layout round-trips, gas levels included
+        let mut chunk = chunk;
+        chunk.oxygen[7] = 0.25;
+        chunk.carbon_dioxide[7] = 0.75;
+        let decoded = decode_chunk_blob(&encode_chunk_blob(&chunk)?)?;
+        assert_eq!(decoded.get_material(2, 3), 42);
+        assert_eq!(decoded.oxygen[7], 0.25);
+        assert_eq!(decoded.carbon_dioxide[7], 0.75);
+        Ok(())
+    }
+
     #[test]
     fn test_metadata_save_load() -> Result<()> {
         let test_world = "test_metadata";
@@ -402,6 +576,7 @@ mod tests {
             last_played: "2024-01-02T00:00:00Z".to_string(),
             play_time_seconds: 3600,
             player_data: None,
+            workstations: Vec::new(),
         };
 
         // Save and load
@@ -7,6 +7,7 @@ use std::collections::VecDeque;
 use super::chunk_manager::ChunkManager;
 use super::generation::WorldGenerator;
 use super::persistence::{ChunkPersistence, WorldMetadata};
+use super::workstation_system::PlacedWorkstation;
 use super::{CHUNK_SIZE, Chunk};
 use crate::entity::player::Player;
 
@@ -364,6 +365,7 @@ impl PersistenceSystem {
         &mut self,
         chunk_manager: &mut ChunkManager,
         player: &Player,
+        workstations: &[PlacedWorkstation],
         play_time_seconds: u64,
     ) {
         self.save_dirty_chunks(chunk_manager);
@@ -383,6 +385,7 @@ impl PersistenceSystem {
                 last_played,
                 play_time_seconds, // Accumulated play time from World
                 player_data: Some(player.clone()), // Save player inventory, health, hunger
+                workstations: workstations.to_vec(),
             };
 
             if let Err(e) = persistence.save_metadata(&metadata) {
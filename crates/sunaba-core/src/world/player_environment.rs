@@ -0,0 +1,170 @@
+//! Player environment sampling - liquids, heat and hazards touching the player
+
+use glam::{IVec2, Vec2};
+use std::collections::HashMap;
+
+use super::Chunk;
+use super::chunk_manager::ChunkManager;
+use super::gas_system::{GasSystem, SUFFOCATION_OXYGEN};
+use crate::entity::player::Player;
+use crate::entity::status_effects::{EnvironmentContact, Submersion};
+use crate::simulation::{MaterialId, MaterialType, Materials, get_temperature_at_pixel};
+
+/// Rows at the top of the hitbox that count as the head (for breathing)
+const HEAD_ROWS: i32 = 3;
+
+/// Samples the world around the player for swimming and environmental damage
+pub struct PlayerEnvironmentSystem;
+
+impl PlayerEnvironmentSystem {
+    /// Sample everything overlapping the player's hitbox (plus a 1px contact margin)
+    pub fn sample(
+        chunks: &HashMap<IVec2, Chunk>,
+        materials: &Materials,
+        position: Vec2,
+    ) -> EnvironmentContact {
+        let half_w = (Player::WIDTH / 2.0) as i32;
+        let half_h = (Player::HEIGHT / 2.0) as i32;
+        let (cx, cy) = (position.x.floor() as i32, position.y.floor() as i32);
+        let (min_x, max_x) = (cx - half_w, cx + half_w - 1);
+        let (min_y, max_y) = (cy - half_h, cy + half_h - 1);
+
+        let mut contact = EnvironmentContact {
+            max_temperature: f32::MIN,
+            ..Default::default()
+        };
+        let mut body_pixels = 0;
+        let mut liquid_pixels = 0;
+        let mut density_sum = 0.0;
+        let mut viscosity_sum = 0.0;
+
+        for y in (min_y - 1)..=(max_y + 1) {
+            for x in (min_x - 1)..=(max_x + 1) {
+                let (chunk_pos, local_x, local_y) = ChunkManager::world_to_chunk_coords(x, y);
+                let Some(chunk) = chunks.get(&chunk_pos) else {
+                    continue;
+                };
+
+                contact.max_temperature = contact
+                    .max_temperature
+                    .max(get_temperature_at_pixel(chunk, local_x, local_y));
+
+                // Only pixels inside the hitbox count towards submersion
+                let inside = (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y);
+                if inside {
+                    body_pixels += 1;
+                }
+
+                let pixel = chunk.get_pixel(local_x, local_y);
+                if pixel.is_empty() {
+                    continue;
+                }
+                let material = materials.get(pixel.material_id);
+
+                if pixel.material_id == MaterialId::FIRE {
+                    contact.touching_fire = true;
+                }
+                if let Some(toxicity) = material.toxicity {
+                    contact.toxicity = contact.toxicity.max(toxicity);
+                }
+
+                if inside && material.material_type == MaterialType::Liquid {
+                    liquid_pixels += 1;
+                    density_sum += material.density;
+                    viscosity_sum += material.viscosity;
+                    if y > max_y - HEAD_ROWS {
+                        contact.head_submerged = true;
+                    }
+                }
+            }
+        }
+
+        contact.suffocating = GasSystem::oxygen_at(chunks, Self::head_position(position))
+            .is_some_and(|oxygen| oxygen < SUFFOCATION_OXYGEN);
+
+        if contact.max_temperature == f32::MIN {
+            contact.max_temperature = 20.0;
+        }
+        if liquid_pixels > 0 {
+            contact.submersion = Submersion {
+                fraction: liquid_pixels as f32 / body_pixels.max(1) as f32,
+                density: density_sum / liquid_pixels as f32,
+                viscosity: viscosity_sum / liquid_pixels as f32,
+            };
+        }
+        contact
+    }
+
+    /// Pixel the player breathes from (top of the hitbox)
+    pub fn head_position(position: Vec2) -> IVec2 {
+        let half_h = (Player::HEIGHT / 2.0) as i32;
+        IVec2::new(
+            position.x.floor() as i32,
+            position.y.floor() as i32 + half_h - 1,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::test_fixtures::single_chunk;
+
+    #[test]
+    fn test_dry_air() {
+        let chunks = single_chunk(|_, _| None);
+        let contact =
+            PlayerEnvironmentSystem::sample(&chunks, &Materials::new(), Vec2::new(32.0, 32.0));
+        assert!(!contact.submersion.is_submerged());
+        assert!(!contact.head_submerged);
+        assert!(!contact.touching_fire);
+        assert_eq!(contact.toxicity, 0.0);
+        assert_eq!(contact.max_temperature, 20.0);
+        assert!(!contact.suffocating);
+    }
+
+    #[test]
+    fn test_fully_underwater() {
+        let chunks = single_chunk(|_, _| Some(MaterialId::WATER));
+        let contact =
+            PlayerEnvironmentSystem::sample(&chunks, &Materials::new(), Vec2::new(32.0, 32.0));
+        assert_eq!(contact.submersion.fraction, 1.0);
+        assert_eq!(contact.submersion.density, 1.0);
+        assert!(contact.head_submerged);
+    }
+
+    #[test]
+    fn test_wading_keeps_head_dry() {
+        // Water up to y < 30, player spans y 26..=37
+        let chunks = single_chunk(|_, y| (y < 30).then_some(MaterialId::WATER));
+        let contact =
+            PlayerEnvironmentSystem::sample(&chunks, &Materials::new(), Vec2::new(32.0, 32.0));
+        assert!(contact.submersion.is_submerged());
+        assert!(contact.submersion.fraction < 0.5);
+        assert!(!contact.head_submerged);
+    }
+
+    #[test]
+    fn test_fire_and_poison_contact() {
+        let chunks = single_chunk(|x, y| match (x, y) {
+            (36, 32) => Some(MaterialId::FIRE),
+            (32, 38) => Some(MaterialId::POISON_GAS),
+            _ => None,
+        });
+        let contact =
+            PlayerEnvironmentSystem::sample(&chunks, &Materials::new(), Vec2::new(32.0, 32.0));
+        assert!(contact.touching_fire);
+        assert!(contact.toxicity > 0.0);
+    }
+
+    #[test]
+    fn test_thin_air_suffocates() {
+        let mut chunks = single_chunk(|_, _| None);
+        // Head at y 37 breathes from coarse cell (4, 4)
+        chunks.get_mut(&IVec2::ZERO).unwrap().oxygen[4 * 8 + 4] = 0.1;
+        let contact =
+            PlayerEnvironmentSystem::sample(&chunks, &Materials::new(), Vec2::new(32.0, 32.0));
+        assert!(contact.suffocating);
+        assert!(!contact.head_submerged);
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::entity::input::InputState;
 use crate::entity::player::Player;
+use crate::entity::status_effects::Submersion;
 use glam::Vec2;
 
 /// Player physics system - handles movement, jumping, gravity, collision
@@ -26,6 +27,13 @@ where
     0.0
 }
 
+/// Horizontal speed multiplier in liquid (deeper and thicker liquids are slower)
+fn swim_speed_factor(submersion: &Submersion) -> f32 {
+    let depth_penalty = submersion.fraction * (1.0 - Player::SWIM_SPEED_FACTOR);
+    let viscosity_penalty = submersion.fraction * submersion.viscosity * 0.5;
+    (1.0 - depth_penalty - viscosity_penalty).max(0.1)
+}
+
 impl PlayerPhysicsSystem {
     /// Update player physics for one frame
     ///
@@ -108,7 +116,8 @@ impl PlayerPhysicsSystem {
 
             if horizontal_input != 0.0 {
                 // Apply movement input
-                player.velocity.x = horizontal_input * player_speed;
+                player.velocity.x =
+                    horizontal_input * player_speed * swim_speed_factor(&player.submersion);
             } else if player.grounded {
                 // Apply friction when grounded and no input
                 let friction = PLAYER_DECELERATION * dt;
@@ -128,6 +137,24 @@ impl PlayerPhysicsSystem {
             player.jump_buffer = 0.0;
             player.coyote_time = 0.0;
             log::debug!("Player jumped!");
+        } else if player.submersion.is_submerged() && !player.grounded {
+            // Swimming: buoyancy from liquid density, drag from viscosity
+            let submersion = player.submersion;
+            let buoyancy =
+                Player::GRAVITY * submersion.fraction * (submersion.density / Player::DENSITY);
+            player.velocity.y += (buoyancy - Player::GRAVITY) * dt;
+            if input.w_pressed || input.jump_pressed {
+                player.velocity.y += Player::SWIM_THRUST * dt;
+            }
+            if input.s_pressed {
+                player.velocity.y -= Player::SWIM_THRUST * dt;
+            }
+            let drag = (Player::WATER_DRAG + submersion.viscosity * 10.0) * submersion.fraction;
+            player.velocity *= 1.0 - (drag * dt).min(1.0);
+            player.velocity.y = player
+                .velocity
+                .y
+                .clamp(-Player::MAX_SWIM_SPEED, Player::MAX_SWIM_SPEED);
         } else if !player.grounded {
             // Apply flight thrust if W pressed (Noita-style levitation)
             if input.w_pressed {
@@ -180,6 +207,14 @@ impl PlayerPhysicsSystem {
 
         // Stop vertical velocity if hit ceiling/floor
         if !can_move_y {
+            // Hard landings hurt (liquids slow the fall before impact)
+            if movement.y < 0.0 && !player.is_dashing() {
+                let damage = Player::fall_damage(-player.velocity.y);
+                if damage > 0.0 {
+                    log::debug!("Fall damage: {:.1}", damage);
+                    player.health.take_damage(damage);
+                }
+            }
             player.velocity.y = 0.0;
         }
 
@@ -618,4 +653,104 @@ mod tests {
             "Player should be blocked by tall wall"
         );
     }
+
+    #[test]
+    fn test_fall_damage_on_hard_landing() {
+        let mut player = make_test_player();
+        let input = make_test_input();
+        player.velocity.y = -Player::MAX_FALL_SPEED;
+
+        // Floor blocks downward movement
+        PlayerPhysicsSystem::update(
+            &mut player,
+            &input,
+            1.0 / 60.0,
+            200.0,
+            || false,
+            |_, y, _, _| y < 100.0,
+        );
+
+        assert!(player.health.current < player.health.max);
+        assert_eq!(player.velocity.y, 0.0);
+    }
+
+    #[test]
+    fn test_soft_landing_no_damage() {
+        let mut player = make_test_player();
+        let input = make_test_input();
+        player.velocity.y = -100.0;
+
+        PlayerPhysicsSystem::update(
+            &mut player,
+            &input,
+            1.0 / 60.0,
+            200.0,
+            || false,
+            |_, y, _, _| y < 100.0,
+        );
+
+        assert_eq!(player.health.current, player.health.max);
+    }
+
+    #[test]
+    fn test_buoyancy_floats_in_dense_liquid() {
+        let mut player = make_test_player();
+        let input = make_test_input();
+        player.submersion = Submersion {
+            fraction: 1.0,
+            density: 3.0,
+            viscosity: 0.1,
+        };
+
+        PlayerPhysicsSystem::update(
+            &mut player,
+            &input,
+            0.1,
+            200.0,
+            || false,
+            |_, _, _, _| false,
+        );
+        assert!(player.velocity.y > 0.0, "Should rise in lava-dense liquid");
+
+        // In water the player sinks slowly instead of free-falling
+        let mut player = make_test_player();
+        player.submersion = Submersion {
+            fraction: 1.0,
+            density: 1.0,
+            viscosity: 0.1,
+        };
+        PlayerPhysicsSystem::update(
+            &mut player,
+            &input,
+            0.1,
+            200.0,
+            || false,
+            |_, _, _, _| false,
+        );
+        assert!(player.velocity.y < 0.0);
+        assert!(player.velocity.y > -Player::GRAVITY * 0.1);
+    }
+
+    #[test]
+    fn test_swim_up() {
+        let mut player = make_test_player();
+        let mut input = make_test_input();
+        input.w_pressed = true;
+        player.submersion = Submersion {
+            fraction: 1.0,
+            density: 1.0,
+            viscosity: 0.1,
+        };
+
+        PlayerPhysicsSystem::update(
+            &mut player,
+            &input,
+            0.1,
+            200.0,
+            || false,
+            |_, _, _, _| false,
+        );
+        assert!(player.velocity.y > 0.0);
+        assert!(player.velocity.y <= Player::MAX_SWIM_SPEED);
+    }
 }
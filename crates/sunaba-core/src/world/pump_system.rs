@@ -0,0 +1,305 @@
+//! Pumps and pipes for moving fluids between reservoirs.
+//!
+//! A pump runs while any orthogonal conductor is powered. Each tick it takes one
+//! pixel of liquid or gas from behind it (or from its sides when the back is dry)
+//! and pushes it out of the front, following the DIRECTION bits like the logic
+//! components (0 = up, 1 = right, 2 = down, 3 = left):
+//! - **Open front**: the fluid is released right in front of the pump
+//! - **Pipe in front**: the fluid travels through the connected pipe network and
+//!   leaves straight out of the open end farthest from the pump; gaps along the
+//!   side of a pipe don't leak
+//!
+//! Thick fluids pump slower: the flow rate scales with `1 - viscosity`, so water
+//! moves almost a pixel per tick while lava crawls. A running pump keeps its
+//! BEHAVIOR_ACTIVE flag set so renderers can show it working.
+
+use super::chunk_access::{ORTHOGONAL, pixel_at, set_pixel_at};
+use super::logic_system::LogicSystem;
+use super::{CHUNK_SIZE, Chunk};
+use glam::IVec2;
+use std::collections::{HashMap, HashSet, VecDeque};
+use sunaba_simulation::materials::{MaterialId, MaterialType, Materials};
+use sunaba_simulation::pixel::{Pixel, pixel_flags};
+
+/// Pixels per tick moved by a pump for a fluid without viscosity (60 px/s at 60fps)
+pub const PUMP_FLOW_PER_TICK: f32 = 1.0;
+
+/// Slowest flow as a fraction of `PUMP_FLOW_PER_TICK`, so very thick fluids still move
+const MIN_FLOW_FACTOR: f32 = 0.1;
+
+/// Max pipe pixels searched for an outlet
+const PIPE_SEARCH_LIMIT: usize = 1024;
+
+/// Runs powered pumps once per electrical update.
+pub struct PumpSystem {
+    /// Fractional pixels each running pump has accumulated: world pos -> flow
+    flow: HashMap<IVec2, f32>,
+}
+
+impl PumpSystem {
+    pub fn new() -> Self {
+        Self {
+            flow: HashMap::new(),
+        }
+    }
+
+    /// Pumping speed for a fluid as a fraction of `PUMP_FLOW_PER_TICK`
+    pub fn flow_factor(materials: &Materials, material_id: u16) -> f32 {
+        (1.0 - materials.get(material_id).viscosity).clamp(MIN_FLOW_FACTOR, 1.0)
+    }
+
+    /// Runs all pumps in the active chunks.
+    ///
+    /// Must run after power propagation, since pumps read the POWERED flag of
+    /// the conductors around them. Returns the number of fluid pixels moved.
+    pub fn update(
+        &mut self,
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        materials: &Materials,
+    ) -> usize {
+        let mut pumps: Vec<(IVec2, Pixel)> = Vec::new();
+        for &chunk_pos in active_chunks {
+            if let Some(chunk) = chunks.get(&chunk_pos) {
+                for y in 0..CHUNK_SIZE {
+                    for x in 0..CHUNK_SIZE {
+                        let pixel = chunk.get_pixel(x, y);
+                        if pixel.material_id == MaterialId::PUMP {
+                            let world_pos = IVec2::new(
+                                chunk_pos.x * CHUNK_SIZE as i32 + x as i32,
+                                chunk_pos.y * CHUNK_SIZE as i32 + y as i32,
+                            );
+                            pumps.push((world_pos, pixel));
+                        }
+                    }
+                }
+            }
+        }
+
+        // Forget flow of pumps that are gone
+        let seen: HashSet<IVec2> = pumps.iter().map(|(pos, _)| *pos).collect();
+        self.flow.retain(|pos, _| seen.contains(pos));
+
+        let mut moved = 0;
+        for (pos, pixel) in pumps {
+            let powered = ORTHOGONAL
+                .iter()
+                .any(|&offset| LogicSystem::is_input_high(chunks, materials, pos + offset));
+
+            let was_active = pixel.flags & pixel_flags::BEHAVIOR_ACTIVE != 0;
+            if powered != was_active {
+                let mut updated = pixel;
+                if powered {
+                    updated.flags |= pixel_flags::BEHAVIOR_ACTIVE;
+                } else {
+                    updated.flags &= !pixel_flags::BEHAVIOR_ACTIVE;
+                }
+                set_pixel_at(chunks, pos, updated);
+            }
+
+            if !powered {
+                self.flow.remove(&pos);
+                continue;
+            }
+
+            let forward = LogicSystem::direction_offset(pixel.direction());
+            let Some(intake) = Self::find_intake(chunks, materials, pos, forward) else {
+                continue;
+            };
+            let Some(outlet) = Self::find_outlet(chunks, pos, forward, intake) else {
+                continue;
+            };
+
+            let Some(fluid) = pixel_at(chunks, intake) else {
+                continue;
+            };
+            let flow = self.flow.entry(pos).or_insert(0.0);
+            *flow += PUMP_FLOW_PER_TICK * Self::flow_factor(materials, fluid.material_id);
+            if *flow < 1.0 {
+                continue;
+            }
+            *flow -= 1.0;
+
+            set_pixel_at(chunks, intake, Pixel::new(MaterialId::AIR));
+            set_pixel_at(chunks, outlet, fluid);
+            moved += 1;
+        }
+
+        moved
+    }
+
+    /// Fluid pixel the pump draws from: behind it first, then its sides
+    fn find_intake(
+        chunks: &HashMap<IVec2, Chunk>,
+        materials: &Materials,
+        pos: IVec2,
+        forward: IVec2,
+    ) -> Option<IVec2> {
+        let left = IVec2::new(-forward.y, forward.x);
+        [pos - forward, pos + left, pos - left]
+            .into_iter()
+            .find(|&candidate| {
+                pixel_at(chunks, candidate).is_some_and(|p| {
+                    !p.is_empty()
+                        && matches!(
+                            materials.get(p.material_id).material_type,
+                            MaterialType::Liquid | MaterialType::Gas
+                        )
+                })
+            })
+    }
+
+    /// Empty cell the pumped fluid is released into
+    ///
+    /// Directly in front of the pump, or straight past the open end of the
+    /// connected pipe network that is farthest from the pump. An end is a pipe
+    /// pixel joined to only one other pipe (the first pipe counts the pump).
+    fn find_outlet(
+        chunks: &HashMap<IVec2, Chunk>,
+        pos: IVec2,
+        forward: IVec2,
+        intake: IVec2,
+    ) -> Option<IVec2> {
+        let front = pos + forward;
+        let front_pixel = pixel_at(chunks, front)?;
+        if front_pixel.is_empty() {
+            return Some(front);
+        }
+        if front_pixel.material_id != MaterialId::PIPE {
+            return None;
+        }
+
+        let is_pipe =
+            |p: IVec2| pixel_at(chunks, p).is_some_and(|px| px.material_id == MaterialId::PIPE);
+        let is_open = |p: IVec2| {
+            p != pos && p != intake && pixel_at(chunks, p).is_some_and(|px| px.is_empty())
+        };
+
+        let mut visited = HashSet::from([front]);
+        let mut queue = VecDeque::from([(front, pos)]);
+        let mut outlet = None;
+        while let Some((pipe, previous)) = queue.pop_front() {
+            if visited.len() > PIPE_SEARCH_LIMIT {
+                break;
+            }
+            let links: Vec<IVec2> = ORTHOGONAL
+                .iter()
+                .map(|&o| pipe + o)
+                .filter(|&p| p != previous && is_pipe(p))
+                .collect();
+
+            // Later (farther) ends override the outlet of nearer ones; the
+            // fluid leaves straight out of an open end, a capped end is shut
+            let straight = pipe + (pipe - previous);
+            if links.is_empty() && is_open(straight) {
+                outlet = Some(straight);
+            }
+            for next in links {
+                if visited.insert(next) {
+                    queue.push_back((next, pipe));
+                }
+            }
+        }
+
+        outlet
+    }
+}
+
+impl Default for PumpSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::test_fixtures::empty_chunk;
+
+    fn powered_wire() -> Pixel {
+        let mut wire = Pixel::new(MaterialId::WIRE);
+        wire.flags |= pixel_flags::POWERED;
+        wire
+    }
+
+    /// Pump at (10, 10) facing right, water behind it, pipe running right then up
+    fn build_pipeline(chunks: &mut HashMap<IVec2, Chunk>, wire: Pixel) {
+        let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+        chunk.set_pixel(10, 10, Pixel::new(MaterialId::PUMP).with_direction(1));
+        chunk.set_pixel(10, 11, wire);
+        chunk.set_material(9, 10, MaterialId::WATER);
+        for x in 11..=20 {
+            chunk.set_material(x, 10, MaterialId::PIPE);
+        }
+        for y in 11..=15 {
+            chunk.set_material(20, y, MaterialId::PIPE);
+        }
+    }
+
+    #[test]
+    fn test_unpowered_pump_does_nothing() {
+        let (mut chunks, active, materials) = empty_chunk();
+        build_pipeline(&mut chunks, Pixel::new(MaterialId::WIRE));
+        let mut system = PumpSystem::new();
+
+        assert_eq!(system.update(&mut chunks, &active, &materials), 0);
+        assert_eq!(chunks[&IVec2::ZERO].get_material(9, 10), MaterialId::WATER);
+    }
+
+    #[test]
+    fn test_powered_pump_moves_fluid_to_pipe_end() {
+        let (mut chunks, active, materials) = empty_chunk();
+        build_pipeline(&mut chunks, powered_wire());
+        let mut system = PumpSystem::new();
+
+        let mut moved = 0;
+        for _ in 0..5 {
+            moved += system.update(&mut chunks, &active, &materials);
+        }
+
+        let chunk = &chunks[&IVec2::ZERO];
+        assert_eq!(moved, 1);
+        assert_eq!(chunk.get_material(9, 10), MaterialId::AIR);
+        assert_eq!(chunk.get_material(20, 16), MaterialId::WATER);
+        assert_ne!(
+            chunk.get_pixel(10, 10).flags & pixel_flags::BEHAVIOR_ACTIVE,
+            0
+        );
+    }
+
+    #[test]
+    fn test_side_gap_in_pipe_doesnt_leak() {
+        let (mut chunks, active, materials) = empty_chunk();
+        build_pipeline(&mut chunks, powered_wire());
+        // Cap the end: the only open cells left are beside the pipe
+        chunks
+            .get_mut(&IVec2::ZERO)
+            .unwrap()
+            .set_material(20, 16, MaterialId::STONE);
+        let mut system = PumpSystem::new();
+
+        for _ in 0..5 {
+            assert_eq!(system.update(&mut chunks, &active, &materials), 0);
+        }
+        assert_eq!(chunks[&IVec2::ZERO].get_material(9, 10), MaterialId::WATER);
+    }
+
+    #[test]
+    fn test_viscous_fluids_pump_slower() {
+        let materials = Materials::new();
+        let water = PumpSystem::flow_factor(&materials, MaterialId::WATER);
+        let lava = PumpSystem::flow_factor(&materials, MaterialId::LAVA);
+        assert!(water > lava);
+        assert!(lava >= MIN_FLOW_FACTOR);
+
+        // Lava needs several ticks per pixel
+        let (mut chunks, active, materials) = empty_chunk();
+        build_pipeline(&mut chunks, powered_wire());
+        chunks
+            .get_mut(&IVec2::ZERO)
+            .unwrap()
+            .set_material(9, 10, MaterialId::LAVA);
+        let mut system = PumpSystem::new();
+        assert_eq!(system.update(&mut chunks, &active, &materials), 0);
+    }
+}
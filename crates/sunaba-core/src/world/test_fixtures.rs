@@ -0,0 +1,46 @@
+//! Chunk fixtures shared by the world system tests
+
+use glam::IVec2;
+use std::collections::HashMap;
+
+use super::{CHUNK_SIZE, Chunk, ChunkManager};
+use crate::simulation::Materials;
+
+/// Empty chunk (0, 0), the active chunk list and the material table
+pub(crate) fn empty_chunk() -> (HashMap<IVec2, Chunk>, Vec<IVec2>, Materials) {
+    let chunks = HashMap::from([(IVec2::ZERO, Chunk::new(0, 0))]);
+    (chunks, vec![IVec2::ZERO], Materials::new())
+}
+
+/// Chunk (0, 0) with `fill` choosing the material of each local pixel (air for None)
+pub(crate) fn single_chunk(fill: impl Fn(i32, i32) -> Option<u16>) -> HashMap<IVec2, Chunk> {
+    let mut chunk = Chunk::new(0, 0);
+    for y in 0..64 {
+        for x in 0..64 {
+            if let Some(material) = fill(x as i32, y as i32) {
+                chunk.set_material(x, y, material);
+            }
+        }
+    }
+    HashMap::from([(IVec2::ZERO, chunk)])
+}
+
+/// Chunk manager holding `single_chunk(fill)`
+pub(crate) fn single_chunk_manager(fill: impl Fn(i32, i32) -> Option<u16>) -> ChunkManager {
+    let mut manager = ChunkManager::new();
+    manager.chunks = single_chunk(fill);
+    manager
+}
+
+/// Number of `material` pixels across all chunks
+pub(crate) fn count(chunks: &HashMap<IVec2, Chunk>, material: u16) -> usize {
+    chunks
+        .values()
+        .map(|chunk| {
+            (0..CHUNK_SIZE)
+                .flat_map(|y| (0..CHUNK_SIZE).map(move |x| (x, y)))
+                .filter(|&(x, y)| chunk.get_material(x, y) == material)
+                .count()
+        })
+        .sum()
+}
@@ -0,0 +1,236 @@
+//! Tool system - axe felling, shovel digging and sword strikes
+
+use glam::{IVec2, Vec2};
+use std::collections::{HashSet, VecDeque};
+
+use super::chunk::{Pixel, pixel_flags};
+use super::chunk_manager::ChunkManager;
+use crate::creature::spawning::CreatureManager;
+use crate::entity::player::Player;
+use crate::entity::tools::ToolDef;
+use crate::simulation::{MaterialId, MaterialType, Materials};
+
+/// Max distance (pixels) from the player at which tools can be used
+pub const TOOL_REACH: f32 = 64.0;
+
+/// Radius (pixels) around the swing target in which creatures are hit
+const STRIKE_RADIUS: f32 = 12.0;
+
+/// Knockback velocity per point of melee damage
+const KNOCKBACK_PER_DAMAGE: f32 = 8.0;
+
+/// Result of using the equipped tool this frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ToolUse {
+    /// Nothing happened (out of reach, wrong target, cooldown)
+    None,
+    /// Pickaxe is still working on a pixel
+    Mining,
+    /// Pickaxe finished a pixel
+    Mined(u16),
+    /// Axe felled a tree (pixels collected, pixels dropped as debris)
+    Felled { collected: u32, debris: u32 },
+    /// Shovel dug up powder pixels
+    Dug(u32),
+    /// Sword swing hit this many creatures
+    Struck(usize),
+}
+
+/// Tool system - static utility methods for non-pickaxe tools
+pub struct ToolSystem;
+
+impl ToolSystem {
+    /// Check if a pixel is part of a grown tree (trunk wood, leaves, saplings)
+    /// Player-placed wood is a build, not a tree, and is never felled
+    pub fn is_tree_pixel(pixel: Pixel) -> bool {
+        pixel.flags & pixel_flags::PLAYER_PLACED == 0
+            && matches!(
+                pixel.material_id,
+                MaterialId::WOOD | MaterialId::PLANT_MATTER | MaterialId::SAPLING
+            )
+    }
+
+    /// Flood-fill the tree connected to `start` (8-connected)
+    /// Returns at most `limit` positions, closest to the chop point first
+    pub fn fell_tree(chunk_manager: &ChunkManager, start: IVec2, limit: usize) -> Vec<IVec2> {
+        let is_tree = |pos: IVec2| {
+            let (chunk_pos, local_x, local_y) = ChunkManager::world_to_chunk_coords(pos.x, pos.y);
+            chunk_manager
+                .chunks
+                .get(&chunk_pos)
+                .is_some_and(|chunk| Self::is_tree_pixel(chunk.get_pixel(local_x, local_y)))
+        };
+
+        if !is_tree(start) {
+            return Vec::new();
+        }
+
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+        let mut tree = Vec::new();
+
+        while let Some(pos) = queue.pop_front() {
+            tree.push(pos);
+            if tree.len() >= limit {
+                break;
+            }
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let next = pos + IVec2::new(dx, dy);
+                    if visited.insert(next) && is_tree(next) {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        tree
+    }
+
+    /// Powder pixels inside a circular brush (what a shovel digs up)
+    pub fn dig_positions(
+        chunk_manager: &ChunkManager,
+        materials: &Materials,
+        center: IVec2,
+        radius: i32,
+    ) -> Vec<IVec2> {
+        let mut positions = Vec::new();
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                if dx * dx + dy * dy > radius * radius {
+                    continue;
+                }
+                let pos = center + IVec2::new(dx, dy);
+                let (chunk_pos, local_x, local_y) =
+                    ChunkManager::world_to_chunk_coords(pos.x, pos.y);
+                if let Some(chunk) = chunk_manager.chunks.get(&chunk_pos) {
+                    let material = materials.get(chunk.get_pixel(local_x, local_y).material_id);
+                    if material.material_type == MaterialType::Powder && material.hardness.is_some()
+                    {
+                        positions.push(pos);
+                    }
+                }
+            }
+        }
+        positions
+    }
+
+    /// Swing a melee weapon at `target`, damaging and knocking back nearby creatures
+    /// Returns the number of creatures hit
+    pub fn strike(
+        player: &Player,
+        creatures: &mut CreatureManager,
+        tool: &ToolDef,
+        target: Vec2,
+    ) -> usize {
+        let damage = tool.melee_damage();
+        let mut hits = 0;
+
+        for creature in creatures.iter_mut() {
+            if creature.position.distance(target) > STRIKE_RADIUS
+                || creature.position.distance(player.position) > TOOL_REACH
+            {
+                continue;
+            }
+
+            let direction = (creature.position - player.position)
+                .try_normalize()
+                .unwrap_or(Vec2::X);
+            creature.health.take_damage(damage);
+            creature.velocity += (direction + Vec2::Y * 0.5) * damage * KNOCKBACK_PER_DAMAGE;
+            hits += 1;
+        }
+
+        if hits > 0 {
+            log::debug!(
+                "[COMBAT] {} hit {} creature(s) for {:.1}",
+                tool.name,
+                hits,
+                damage
+            );
+        }
+        hits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::tools::ToolRegistry;
+    use crate::world::test_fixtures::single_chunk_manager;
+
+    #[test]
+    fn test_fell_tree_takes_connected_wood_and_leaves() {
+        // Trunk at x=10 (y 5..15), canopy at y=15, separate tree at x=30
+        let mut manager = single_chunk_manager(|x, y| match (x, y) {
+            (10, 5..=14) => Some(MaterialId::WOOD),
+            (8..=12, 15) => Some(MaterialId::PLANT_MATTER),
+            (30, 5..=14) => Some(MaterialId::WOOD),
+            (11, 10) => Some(MaterialId::ALCHEMY_TABLE),
+            (9, 10) => Some(MaterialId::BONE),
+            (_, 4) => Some(MaterialId::GRASS),
+            (_, 0..=3) => Some(MaterialId::DIRT),
+            _ => None,
+        });
+        // Wooden wall built against the trunk
+        let chunk = manager.chunks.get_mut(&IVec2::ZERO).unwrap();
+        for y in 5..10 {
+            chunk.set_material_player_placed(11, y, MaterialId::WOOD);
+        }
+
+        let tree = ToolSystem::fell_tree(&manager, IVec2::new(10, 5), 1000);
+        assert_eq!(tree.len(), 15);
+        assert!(!tree.contains(&IVec2::new(30, 5)));
+        // Grass, bone and the player's wall aren't part of the tree
+        assert!(!tree.iter().any(|p| p.y < 5 || p.x == 9 && p.y == 10));
+        assert!(!tree.iter().any(|p| p.x == 11 && p.y < 10));
+
+        // Limit caps the felled pixels
+        let capped = ToolSystem::fell_tree(&manager, IVec2::new(10, 5), 4);
+        assert_eq!(capped.len(), 4);
+
+        // Chopping air, grass or a wooden build does nothing
+        assert!(ToolSystem::fell_tree(&manager, IVec2::new(20, 20), 1000).is_empty());
+        assert!(ToolSystem::fell_tree(&manager, IVec2::new(20, 4), 1000).is_empty());
+        assert!(ToolSystem::fell_tree(&manager, IVec2::new(11, 6), 1000).is_empty());
+    }
+
+    #[test]
+    fn test_dig_positions_only_powders() {
+        let manager = single_chunk_manager(|x, _| {
+            if x < 20 {
+                Some(MaterialId::SAND)
+            } else {
+                Some(MaterialId::STONE)
+            }
+        });
+        let materials = Materials::new();
+
+        let dug = ToolSystem::dig_positions(&manager, &materials, IVec2::new(20, 20), 3);
+        assert!(!dug.is_empty());
+        assert!(dug.iter().all(|p| p.x < 20));
+    }
+
+    #[test]
+    fn test_strike_damages_and_knocks_back() {
+        let registry = ToolRegistry::new();
+        let sword = registry.get(1030).unwrap();
+        let player = Player::new(Vec2::new(0.0, 0.0));
+        let mut creatures = CreatureManager::new(10);
+        let near = creatures.spawn_creature(
+            crate::creature::genome::CreatureGenome::test_biped(),
+            Vec2::new(20.0, 0.0),
+        );
+        creatures.spawn_creature(
+            crate::creature::genome::CreatureGenome::test_biped(),
+            Vec2::new(200.0, 0.0),
+        );
+
+        let hits = ToolSystem::strike(&player, &mut creatures, sword, Vec2::new(20.0, 0.0));
+        assert_eq!(hits, 1);
+
+        let creature = creatures.iter().find(|c| c.id == near).unwrap();
+        assert!(creature.health.current < creature.health.max);
+        assert!(creature.velocity.x > 0.0);
+    }
+}
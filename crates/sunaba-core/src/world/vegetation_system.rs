@@ -0,0 +1,589 @@
+//! Vegetation system - plant ecology driven by light, water and temperature
+//!
+//! A few random pixels of every active chunk are visited each growth pass
+//! (like random ticks), so growth is spread out instead of happening in waves:
+//! - **Soil**: lit `DIRT` next to `GRASS` is grown over, and bare soil sprouts
+//!   `PLANT_MATTER`
+//! - **Plants**: rooted plants grow upward to a small height and bear `FRUIT`,
+//!   so the food supply follows the conditions instead of a timer
+//! - **Trees**: a `SAPLING` on soil pushes a `WOOD` trunk upward and finishes
+//!   with a canopy; canopies drop new saplings now and then
+//! - **Death**: plants in hot biomes wither away without water nearby, and
+//!   heat scorches them (flammable vegetation also burns through the fire
+//!   reactions)
+//!
+//! Growth chances scale with a growth factor: the light level, water within a
+//! few pixels and the local temperature. `FERTILIZER` near the soil triples it
+//! and is slowly used up.
+
+use super::chunk_access::{light_at, material_at, set_material_at};
+use super::chunk_manager::ChunkManager;
+use super::rng_trait::WorldRng;
+use super::weather_system::{ColumnClimate, biome_temperature};
+use super::{CHUNK_SIZE, Chunk};
+use glam::IVec2;
+use std::collections::HashMap;
+use sunaba_simulation::materials::MaterialId;
+
+/// Ticks between growth passes
+const GROWTH_INTERVAL: u32 = 10;
+
+/// Random pixels visited per active chunk per growth pass
+const RANDOM_TICKS_PER_CHUNK: usize = 64;
+
+/// Below this light level (0-15) nothing grows
+const MIN_GROWTH_LIGHT: u8 = 6;
+
+/// Temperature range (°C) plants grow in
+const MIN_GROWTH_TEMPERATURE: f32 = 2.0;
+const MAX_GROWTH_TEMPERATURE: f32 = 40.0;
+
+/// Plants wither from heat at this temperature (°C), before they catch fire
+const SCORCH_TEMPERATURE: f32 = 60.0;
+
+/// Plants in biomes at least this hot (°C) need water nearby to survive
+const DROUGHT_TEMPERATURE: f32 = 30.0;
+
+/// Search radius for water around a plant
+const WATER_RADIUS: i32 = 6;
+
+/// Search radius for fertilizer around soil
+const FERTILIZER_RADIUS: i32 = 2;
+
+/// Growth factor multiplier for dry soil
+const DRY_FACTOR: f32 = 0.25;
+
+/// Growth factor multiplier next to fertilizer
+const FERTILIZER_BOOST: f32 = 3.0;
+
+/// Chance a boosting fertilizer pixel is used up
+const FERTILIZER_USE_CHANCE: f32 = 0.2;
+
+/// Per-visit chances at a growth factor of 1.0
+const GRASS_SPREAD_CHANCE: f32 = 0.5;
+const SPROUT_CHANCE: f32 = 0.02;
+const PLANT_GROWTH_CHANCE: f32 = 0.2;
+const FRUIT_CHANCE: f32 = 0.1;
+const SEED_CHANCE: f32 = 0.002;
+const SAPLING_GROWTH_CHANCE: f32 = 0.3;
+const WITHER_CHANCE: f32 = 0.2;
+
+/// Tallest plant stem grown from soil
+const MAX_PLANT_HEIGHT: i32 = 4;
+
+/// Stems at least this tall bear fruit
+const FRUITING_HEIGHT: i32 = 2;
+
+/// Trunk height a sapling grows before spreading its canopy
+const TREE_HEIGHT: i32 = 6;
+
+/// Radius of a grown canopy
+const CANOPY_RADIUS: i32 = 3;
+
+/// Grows, spreads and kills vegetation in the active chunks.
+pub struct VegetationSystem {
+    /// Ticks since the last growth pass
+    ticks: u32,
+    /// Disabled vegetation never grows, spreads or withers
+    enabled: bool,
+}
+
+impl VegetationSystem {
+    pub fn new() -> Self {
+        Self {
+            ticks: 0,
+            enabled: true,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Runs a growth pass every `GROWTH_INTERVAL` ticks.
+    pub fn update<R: WorldRng>(
+        &mut self,
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        climate: impl Fn(i32) -> ColumnClimate,
+        rng: &mut R,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        self.ticks += 1;
+        if self.ticks < GROWTH_INTERVAL {
+            return;
+        }
+        self.ticks = 0;
+        Self::grow(chunks, active_chunks, &climate, rng);
+    }
+
+    /// Visits random pixels of every active chunk once.
+    pub fn grow<R: WorldRng>(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        climate: &impl Fn(i32) -> ColumnClimate,
+        rng: &mut R,
+    ) {
+        for &chunk_pos in active_chunks {
+            if !chunks.contains_key(&chunk_pos) {
+                continue;
+            }
+            for _ in 0..RANDOM_TICKS_PER_CHUNK {
+                let x = Self::random_index(rng, CHUNK_SIZE) as i32;
+                let y = Self::random_index(rng, CHUNK_SIZE) as i32;
+                let pos = chunk_pos * CHUNK_SIZE as i32 + IVec2::new(x, y);
+                Self::visit(chunks, pos, climate, rng);
+            }
+        }
+    }
+
+    /// Applies the growth rules of the material at `pos`
+    fn visit<R: WorldRng>(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        pos: IVec2,
+        climate: &impl Fn(i32) -> ColumnClimate,
+        rng: &mut R,
+    ) {
+        let Some(material) = material_at(chunks, pos) else {
+            return;
+        };
+        match material {
+            MaterialId::DIRT | MaterialId::GRASS => Self::visit_soil(chunks, pos, material, rng),
+            MaterialId::PLANT_MATTER => Self::visit_plant(chunks, pos, climate, rng),
+            MaterialId::SAPLING => Self::visit_sapling(chunks, pos, climate, rng),
+            _ => {}
+        }
+    }
+
+    /// Grass spreading and sprouting on bare soil
+    fn visit_soil<R: WorldRng>(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        pos: IVec2,
+        material: u16,
+        rng: &mut R,
+    ) {
+        let above = pos + IVec2::Y;
+        let above_material = material_at(chunks, above);
+        if above_material != Some(MaterialId::AIR) {
+            // Buried grass dies back to dirt
+            if material == MaterialId::GRASS
+                && above_material.is_some_and(|m| !Self::is_vegetation(m) && m != MaterialId::GRASS)
+            {
+                set_material_at(chunks, pos, MaterialId::DIRT);
+            }
+            return;
+        }
+
+        if Self::temperature_at(chunks, above) >= SCORCH_TEMPERATURE {
+            if material == MaterialId::GRASS {
+                set_material_at(chunks, pos, MaterialId::DIRT);
+            }
+            return;
+        }
+
+        let factor = Self::soil_growth_factor(chunks, pos, rng);
+        if factor <= 0.0 {
+            return;
+        }
+
+        if material == MaterialId::DIRT
+            && Self::has_nearby(chunks, pos, 1, MaterialId::GRASS)
+            && rng.check_probability(GRASS_SPREAD_CHANCE * factor)
+        {
+            set_material_at(chunks, pos, MaterialId::GRASS);
+        } else if rng.check_probability(SPROUT_CHANCE * factor) {
+            set_material_at(chunks, above, MaterialId::PLANT_MATTER);
+        }
+    }
+
+    /// Stem growth, fruiting, seeding and dying plants
+    fn visit_plant<R: WorldRng>(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        pos: IVec2,
+        climate: &impl Fn(i32) -> ColumnClimate,
+        rng: &mut R,
+    ) {
+        if Self::dies(chunks, pos, climate, rng) {
+            return;
+        }
+
+        let factor = Self::growth_factor(chunks, pos);
+        if factor <= 0.0 {
+            return;
+        }
+
+        match Self::stem_height(chunks, pos) {
+            Some(height) => {
+                let above = pos + IVec2::Y;
+                if height < MAX_PLANT_HEIGHT
+                    && material_at(chunks, above) == Some(MaterialId::AIR)
+                    && rng.check_probability(PLANT_GROWTH_CHANCE * factor)
+                {
+                    set_material_at(chunks, above, MaterialId::PLANT_MATTER);
+                } else if height >= FRUITING_HEIGHT && rng.check_probability(FRUIT_CHANCE * factor)
+                {
+                    let side = if rng.gen_bool() { IVec2::X } else { -IVec2::X };
+                    if material_at(chunks, pos + side) == Some(MaterialId::AIR) {
+                        set_material_at(chunks, pos + side, MaterialId::FRUIT);
+                    }
+                }
+            }
+            // Canopies (not rooted in soil) drop saplings
+            None => {
+                let below = pos - IVec2::Y;
+                if material_at(chunks, below) == Some(MaterialId::AIR)
+                    && rng.check_probability(SEED_CHANCE * factor)
+                {
+                    set_material_at(chunks, below, MaterialId::SAPLING);
+                }
+            }
+        }
+    }
+
+    /// Trunk growth and canopy of a sapling standing on soil or its own trunk
+    fn visit_sapling<R: WorldRng>(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        pos: IVec2,
+        climate: &impl Fn(i32) -> ColumnClimate,
+        rng: &mut R,
+    ) {
+        if Self::dies(chunks, pos, climate, rng) {
+            return;
+        }
+
+        let mut trunk = 0;
+        let mut below = pos - IVec2::Y;
+        while material_at(chunks, below) == Some(MaterialId::WOOD) && trunk < TREE_HEIGHT {
+            trunk += 1;
+            below -= IVec2::Y;
+        }
+        if !material_at(chunks, below).is_some_and(Self::is_soil) {
+            return; // Still falling or landed on rock
+        }
+
+        let factor = Self::growth_factor(chunks, pos);
+        if factor <= 0.0 || !rng.check_probability(SAPLING_GROWTH_CHANCE * factor) {
+            return;
+        }
+
+        let above = pos + IVec2::Y;
+        if trunk < TREE_HEIGHT {
+            if material_at(chunks, above) == Some(MaterialId::AIR) {
+                set_material_at(chunks, pos, MaterialId::WOOD);
+                set_material_at(chunks, above, MaterialId::SAPLING);
+            }
+            return;
+        }
+
+        // Fully grown: spread the canopy around the top of the trunk
+        set_material_at(chunks, pos, MaterialId::PLANT_MATTER);
+        for dy in -CANOPY_RADIUS..=CANOPY_RADIUS {
+            for dx in -CANOPY_RADIUS..=CANOPY_RADIUS {
+                let leaf = pos + IVec2::new(dx, dy + 1);
+                if dx * dx + dy * dy <= CANOPY_RADIUS * CANOPY_RADIUS
+                    && material_at(chunks, leaf) == Some(MaterialId::AIR)
+                {
+                    set_material_at(chunks, leaf, MaterialId::PLANT_MATTER);
+                }
+            }
+        }
+    }
+
+    /// Kills scorched plants and withers plants in drought; returns true if it died
+    fn dies<R: WorldRng>(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        pos: IVec2,
+        climate: &impl Fn(i32) -> ColumnClimate,
+        rng: &mut R,
+    ) -> bool {
+        if Self::temperature_at(chunks, pos) >= SCORCH_TEMPERATURE {
+            set_material_at(chunks, pos, MaterialId::ASH);
+            return true;
+        }
+
+        let hot = biome_temperature(climate(pos.x).biome) >= DROUGHT_TEMPERATURE;
+        if hot
+            && !Self::has_nearby(chunks, pos, WATER_RADIUS, MaterialId::WATER)
+            && rng.check_probability(WITHER_CHANCE)
+        {
+            // Withered plants dry up to nothing rather than leaving new soil behind
+            set_material_at(chunks, pos, MaterialId::AIR);
+            return true;
+        }
+        false
+    }
+
+    /// Growth factor of a plant pixel: light, water and temperature (0.0 = no growth)
+    fn growth_factor(chunks: &HashMap<IVec2, Chunk>, pos: IVec2) -> f32 {
+        let light = light_at(chunks, pos);
+        let temperature = Self::temperature_at(chunks, pos);
+        if light < MIN_GROWTH_LIGHT
+            || !(MIN_GROWTH_TEMPERATURE..=MAX_GROWTH_TEMPERATURE).contains(&temperature)
+        {
+            return 0.0;
+        }
+
+        let water = if Self::has_nearby(chunks, pos, WATER_RADIUS, MaterialId::WATER) {
+            1.0
+        } else {
+            DRY_FACTOR
+        };
+        light as f32 / 15.0 * water
+    }
+
+    /// Growth factor of the soil at `pos`, measured in the air above it and
+    /// boosted (and slowly used up) by fertilizer nearby
+    fn soil_growth_factor<R: WorldRng>(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        pos: IVec2,
+        rng: &mut R,
+    ) -> f32 {
+        let factor = Self::growth_factor(chunks, pos + IVec2::Y);
+        if factor <= 0.0 {
+            return 0.0;
+        }
+        let Some(fertilizer) =
+            Self::find_nearby(chunks, pos, FERTILIZER_RADIUS, MaterialId::FERTILIZER)
+        else {
+            return factor;
+        };
+        if rng.check_probability(FERTILIZER_USE_CHANCE) {
+            set_material_at(chunks, fertilizer, MaterialId::DIRT);
+        }
+        factor * FERTILIZER_BOOST
+    }
+
+    /// Height of the plant stem ending at `pos`, or None if it isn't rooted in soil
+    fn stem_height(chunks: &HashMap<IVec2, Chunk>, pos: IVec2) -> Option<i32> {
+        let mut height = 1;
+        let mut below = pos - IVec2::Y;
+        while material_at(chunks, below) == Some(MaterialId::PLANT_MATTER) {
+            height += 1;
+            if height > MAX_PLANT_HEIGHT {
+                return None; // Too tall to be a stem, part of a canopy
+            }
+            below -= IVec2::Y;
+        }
+        material_at(chunks, below)
+            .is_some_and(Self::is_soil)
+            .then_some(height)
+    }
+
+    fn is_soil(material: u16) -> bool {
+        matches!(material, MaterialId::DIRT | MaterialId::GRASS)
+    }
+
+    fn is_vegetation(material: u16) -> bool {
+        matches!(material, MaterialId::PLANT_MATTER | MaterialId::SAPLING)
+    }
+
+    fn has_nearby(chunks: &HashMap<IVec2, Chunk>, pos: IVec2, radius: i32, material: u16) -> bool {
+        Self::find_nearby(chunks, pos, radius, material).is_some()
+    }
+
+    /// First pixel of `material` within a square of `radius` around `pos`
+    fn find_nearby(
+        chunks: &HashMap<IVec2, Chunk>,
+        pos: IVec2,
+        radius: i32,
+        material: u16,
+    ) -> Option<IVec2> {
+        (-radius..=radius)
+            .flat_map(|dy| (-radius..=radius).map(move |dx| pos + IVec2::new(dx, dy)))
+            .find(|&p| p != pos && material_at(chunks, p) == Some(material))
+    }
+
+    fn random_index<R: WorldRng>(rng: &mut R, len: usize) -> usize {
+        ((rng.gen_f32() * len as f32) as usize).min(len - 1)
+    }
+
+    fn temperature_at(chunks: &HashMap<IVec2, Chunk>, pos: IVec2) -> f32 {
+        let (chunk_pos, x, y) = ChunkManager::world_to_chunk_coords(pos.x, pos.y);
+        chunks
+            .get(&chunk_pos)
+            .map_or(20.0, |chunk| chunk.get_temperature(x / 8, y / 8))
+    }
+}
+
+impl Default for VegetationSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::biome::BiomeType;
+    use crate::world::test_fixtures::count;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    /// Dirt floor (y 0..10) under open, fully lit sky
+    fn meadow() -> HashMap<IVec2, Chunk> {
+        let mut chunk = Chunk::new(0, 0);
+        for y in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                chunk.set_light(x, y, 15);
+                if y < 10 {
+                    chunk.set_material(x, y, MaterialId::DIRT);
+                }
+            }
+        }
+        HashMap::from([(IVec2::ZERO, chunk)])
+    }
+
+    fn climate(biome: BiomeType) -> impl Fn(i32) -> ColumnClimate {
+        move |_| ColumnClimate {
+            biome,
+            surface_y: 9,
+        }
+    }
+
+    fn run(chunks: &mut HashMap<IVec2, Chunk>, biome: BiomeType, passes: usize, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        for _ in 0..passes {
+            VegetationSystem::grow(chunks, &[IVec2::ZERO], &climate(biome), &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_grass_spreads_and_plants_sprout_in_light() {
+        let mut chunks = meadow();
+        chunks
+            .get_mut(&IVec2::ZERO)
+            .unwrap()
+            .set_material(32, 9, MaterialId::GRASS);
+
+        run(&mut chunks, BiomeType::Plains, 2000, 1);
+
+        assert!(count(&chunks, MaterialId::GRASS) > 5, "Grass should spread");
+        assert!(
+            count(&chunks, MaterialId::PLANT_MATTER) > 0,
+            "Plants should sprout"
+        );
+    }
+
+    #[test]
+    fn test_nothing_grows_in_the_dark() {
+        let mut chunks = meadow();
+        {
+            let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+            chunk.set_material(32, 9, MaterialId::GRASS);
+            for y in 0..CHUNK_SIZE {
+                for x in 0..CHUNK_SIZE {
+                    chunk.set_light(x, y, 0);
+                }
+            }
+        }
+
+        run(&mut chunks, BiomeType::Plains, 2000, 2);
+
+        assert_eq!(count(&chunks, MaterialId::GRASS), 1);
+        assert_eq!(count(&chunks, MaterialId::PLANT_MATTER), 0);
+    }
+
+    #[test]
+    fn test_rooted_plants_bear_fruit() {
+        let mut chunks = meadow();
+        {
+            let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+            chunk.set_material(20, 5, MaterialId::WATER);
+            chunk.set_material(20, 10, MaterialId::PLANT_MATTER);
+        }
+
+        run(&mut chunks, BiomeType::Plains, 3000, 3);
+
+        assert!(count(&chunks, MaterialId::FRUIT) > 0);
+    }
+
+    #[test]
+    fn test_sapling_grows_into_tree() {
+        let mut chunks = meadow();
+        {
+            let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+            chunk.set_material(32, 10, MaterialId::SAPLING);
+            chunk.set_material(34, 9, MaterialId::WATER);
+        }
+
+        run(&mut chunks, BiomeType::Forest, 8000, 4);
+
+        let chunk = &chunks[&IVec2::ZERO];
+        let top = 10 + TREE_HEIGHT as usize;
+        for y in 10..top {
+            assert_eq!(
+                chunk.get_material(32, y),
+                MaterialId::WOOD,
+                "Trunk at y={}",
+                y
+            );
+        }
+        assert_eq!(chunk.get_material(32, top), MaterialId::PLANT_MATTER);
+        assert_eq!(
+            chunk.get_material(30, top + 1),
+            MaterialId::PLANT_MATTER,
+            "Canopy"
+        );
+    }
+
+    #[test]
+    fn test_drought_and_heat_kill_plants() {
+        // Desert plants without water wither away (on stone, so nothing sprouts back)
+        let mut chunks = meadow();
+        {
+            let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+            chunk.set_material(32, 9, MaterialId::STONE);
+            chunk.set_material(32, 10, MaterialId::PLANT_MATTER);
+        }
+        run(&mut chunks, BiomeType::Desert, 2000, 5);
+        assert_eq!(chunks[&IVec2::ZERO].get_material(32, 10), MaterialId::AIR);
+        assert_eq!(count(&chunks, MaterialId::DIRT), CHUNK_SIZE * 10 - 1);
+
+        // ...but survive next to water
+        let mut chunks = meadow();
+        {
+            let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+            chunk.set_material(32, 10, MaterialId::PLANT_MATTER);
+            chunk.set_material(35, 10, MaterialId::WATER);
+        }
+        run(&mut chunks, BiomeType::Desert, 2000, 6);
+        assert_eq!(
+            chunks[&IVec2::ZERO].get_material(32, 10),
+            MaterialId::PLANT_MATTER
+        );
+
+        // Heat scorches plants
+        let mut chunks = meadow();
+        {
+            let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+            chunk.set_material(32, 10, MaterialId::PLANT_MATTER);
+            chunk.set_temperature(4, 1, 80.0);
+        }
+        run(&mut chunks, BiomeType::Plains, 2000, 7);
+        assert_eq!(chunks[&IVec2::ZERO].get_material(32, 10), MaterialId::ASH);
+    }
+
+    #[test]
+    fn test_fertilizer_boosts_growth_and_is_used_up() {
+        let mut chunks = meadow();
+        chunks
+            .get_mut(&IVec2::ZERO)
+            .unwrap()
+            .set_material(31, 9, MaterialId::FERTILIZER);
+        let mut rng = StdRng::seed_from_u64(8);
+
+        let plain = VegetationSystem::growth_factor(&chunks, IVec2::new(10, 10));
+        let mut boosted = 0.0;
+        while count(&chunks, MaterialId::FERTILIZER) > 0 {
+            boosted =
+                VegetationSystem::soil_growth_factor(&mut chunks, IVec2::new(32, 9), &mut rng);
+        }
+        assert_eq!(boosted, plain * FERTILIZER_BOOST);
+    }
+}
@@ -0,0 +1,723 @@
+//! Weather system - wind, rain, snow and lightning
+//!
+//! The sky cycles between clear spells, rain and thunderstorms every few
+//! minutes. Each biome turns the global weather into local conditions:
+//! - **Wind**: a global gusting wind, scaled per biome, pushes gases and light
+//!   powders sideways above the terrain surface
+//! - **Precipitation**: the biome temperature decides what falls from the
+//!   topmost loaded chunks: rain (`WATER`), snow in cold biomes, nothing where
+//!   it's hot enough for rain to evaporate. While it snows the air above the
+//!   surface is chilled towards the biome temperature, so snow settles and
+//!   exposed water freezes
+//! - **Lightning**: storms drop powered `THUNDER` bolts from the sky; the
+//!   strike point is reported so the electrical system can energize it
+
+use super::biome::BiomeType;
+use super::chunk_access::{pixel_at, set_pixel_at};
+use super::generation::WorldGenerator;
+use super::rng_trait::WorldRng;
+use super::{CHUNK_SIZE, Chunk};
+use glam::{IVec2, Vec2};
+use std::collections::HashMap;
+use sunaba_simulation::materials::{MaterialId, MaterialType, Materials};
+use sunaba_simulation::pixel::{Pixel, pixel_flags};
+
+/// Fixed simulation timestep the weather advances by each tick
+const TICK: f32 = 1.0 / 60.0;
+
+/// Seconds of calm, clear weather before the first change
+const FIRST_CHANGE_DELAY: f32 = 120.0;
+
+/// Shortest and longest weather spell (seconds)
+const SPELL_MIN_DURATION: f32 = 60.0;
+const SPELL_MAX_DURATION: f32 = 240.0;
+
+/// Strongest global wind (pixels per second)
+pub const MAX_WIND_SPEED: f32 = 40.0;
+
+/// Ticks between wind passes over the loaded pixels
+const WIND_INTERVAL: u32 = 4;
+
+/// Chance that a pixel in full-strength wind is pushed during a wind pass
+const WIND_PUSH_CHANCE: f32 = 0.5;
+
+/// Powders at or below this density are light enough to be blown around
+const LIGHT_POWDER_DENSITY: f32 = 1.0;
+
+/// Gases and powders the wind leaves alone (beams, bolts and pixel creatures)
+const WIND_IMMUNE: [u16; 4] = [
+    MaterialId::LASER,
+    MaterialId::THUNDER,
+    MaterialId::ANT,
+    MaterialId::BIRD,
+];
+
+/// Drops spawned per sky chunk per tick at full intensity
+const DROPS_PER_CHUNK: f32 = 0.6;
+
+/// Biomes at or above this temperature (°C) evaporate rain before it lands
+const EVAPORATION_TEMPERATURE: f32 = 30.0;
+
+/// Biomes at or below this temperature (°C) get snow instead of rain
+const FREEZING_TEMPERATURE: f32 = 0.0;
+
+/// Fraction of the gap to the biome temperature closed per wind pass while snowing
+const CHILL_RATE: f32 = 0.05;
+
+/// Chance of a lightning strike per tick during a full-intensity storm
+const LIGHTNING_CHANCE: f32 = 1.0 / 300.0;
+
+/// Longest lightning bolt (pixels)
+const BOLT_MAX_LENGTH: usize = 256;
+
+/// Kind of weather currently over the world
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeatherKind {
+    Clear,
+    Rain,
+    Storm,
+}
+
+impl WeatherKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            WeatherKind::Clear => "Clear",
+            WeatherKind::Rain => "Rain",
+            WeatherKind::Storm => "Storm",
+        }
+    }
+}
+
+/// Global weather conditions
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeatherState {
+    pub kind: WeatherKind,
+    /// Precipitation strength (0.0 = none, 1.0 = downpour)
+    pub intensity: f32,
+    /// Global horizontal wind (pixels per second, positive = right)
+    pub wind: f32,
+}
+
+impl WeatherState {
+    /// Calm, clear skies
+    pub fn clear() -> Self {
+        Self {
+            kind: WeatherKind::Clear,
+            intensity: 0.0,
+            wind: 0.0,
+        }
+    }
+}
+
+/// Terrain and biome of a world column, as seen by the weather
+#[derive(Debug, Clone, Copy)]
+pub struct ColumnClimate {
+    pub biome: BiomeType,
+    /// Height of the terrain surface; weather only reaches pixels above it
+    pub surface_y: i32,
+}
+
+impl ColumnClimate {
+    /// Climate of a world column according to the terrain generator
+    pub fn sample(generator: &WorldGenerator, world_x: i32) -> Self {
+        Self {
+            biome: generator.get_biome_at_internal(world_x),
+            surface_y: generator.get_terrain_height(world_x),
+        }
+    }
+}
+
+/// Average air temperature of a biome (°C)
+pub fn biome_temperature(biome: BiomeType) -> f32 {
+    match biome {
+        BiomeType::Desert => 35.0,
+        BiomeType::Plains => 20.0,
+        BiomeType::Forest => 15.0,
+        BiomeType::Ocean => 12.0,
+        BiomeType::Mountains => -8.0,
+    }
+}
+
+/// How exposed a biome is to the global wind
+fn biome_wind_factor(biome: BiomeType) -> f32 {
+    match biome {
+        BiomeType::Desert => 1.2,
+        BiomeType::Plains => 1.0,
+        BiomeType::Forest => 0.5, // Sheltered by trees
+        BiomeType::Ocean => 1.4,
+        BiomeType::Mountains => 1.6,
+    }
+}
+
+/// Drives the weather and applies it to the loaded chunks.
+pub struct WeatherSystem {
+    state: WeatherState,
+    /// Seconds until the next weather change
+    time_until_change: f32,
+    /// Seconds since the system started, drives wind gusts
+    time: f32,
+    /// Ticks since the last wind pass
+    wind_ticks: u32,
+    /// Disabled weather stays clear and calm and never touches the world
+    enabled: bool,
+}
+
+impl WeatherSystem {
+    pub fn new() -> Self {
+        Self {
+            state: WeatherState::clear(),
+            time_until_change: FIRST_CHANGE_DELAY,
+            time: 0.0,
+            wind_ticks: 0,
+            enabled: true,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Turns weather on or off; turning it off clears the sky at once
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if enabled == self.enabled {
+            return;
+        }
+        self.enabled = enabled;
+        self.state = WeatherState::clear();
+        self.time_until_change = FIRST_CHANGE_DELAY;
+    }
+
+    /// Current global weather
+    pub fn state(&self) -> WeatherState {
+        self.state
+    }
+
+    /// Forces the weather for `duration` seconds
+    pub fn set_state(&mut self, state: WeatherState, duration: f32) {
+        self.state = state;
+        self.time_until_change = duration;
+    }
+
+    /// Local wind at a position (pixels per second), zero below the surface
+    pub fn wind_at(&self, x: i32, y: i32, climate: ColumnClimate) -> Vec2 {
+        if y <= climate.surface_y {
+            return Vec2::ZERO;
+        }
+        let gust = 1.0 + 0.3 * (self.time * 0.7 + x as f32 * 0.02).sin();
+        Vec2::new(
+            self.state.wind * biome_wind_factor(climate.biome) * gust,
+            0.0,
+        )
+    }
+
+    /// Material falling at a position (rain or snow), None when dry or sheltered
+    pub fn precipitation_at(&self, y: i32, climate: ColumnClimate) -> Option<u16> {
+        if self.state.kind == WeatherKind::Clear || y <= climate.surface_y {
+            return None;
+        }
+        Self::precipitation_for(climate.biome)
+    }
+
+    /// What falls from the sky over a biome when it isn't clear
+    pub fn precipitation_for(biome: BiomeType) -> Option<u16> {
+        let temperature = biome_temperature(biome);
+        if temperature >= EVAPORATION_TEMPERATURE {
+            None
+        } else if temperature <= FREEZING_TEMPERATURE {
+            Some(MaterialId::SNOW)
+        } else {
+            Some(MaterialId::WATER)
+        }
+    }
+
+    /// Advances the weather by one tick and applies it to the active chunks.
+    ///
+    /// Returns the points hit by lightning this tick, for the electrical system.
+    pub fn update<R: WorldRng>(
+        &mut self,
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        materials: &Materials,
+        climate: impl Fn(i32) -> ColumnClimate,
+        rng: &mut R,
+    ) -> Vec<IVec2> {
+        if !self.enabled {
+            return Vec::new();
+        }
+
+        self.time += TICK;
+        self.time_until_change -= TICK;
+        if self.time_until_change <= 0.0 {
+            self.change_weather(rng);
+        }
+
+        self.wind_ticks += 1;
+        if self.wind_ticks >= WIND_INTERVAL {
+            self.wind_ticks = 0;
+            self.blow_wind(chunks, active_chunks, materials, &climate, rng);
+            if self.state.kind != WeatherKind::Clear {
+                Self::chill_snowing_air(chunks, active_chunks, &climate);
+            }
+        }
+
+        if self.state.kind == WeatherKind::Clear {
+            return Vec::new();
+        }
+
+        // Precipitation and lightning start in the topmost loaded chunks
+        let sky_chunks: Vec<IVec2> = active_chunks
+            .iter()
+            .copied()
+            .filter(|pos| !chunks.contains_key(&(*pos + IVec2::Y)))
+            .collect();
+
+        self.precipitate(chunks, &sky_chunks, &climate, rng);
+
+        let mut strikes = Vec::new();
+        if self.state.kind == WeatherKind::Storm
+            && !sky_chunks.is_empty()
+            && rng.check_probability(LIGHTNING_CHANCE * self.state.intensity)
+        {
+            let chunk_pos = sky_chunks[Self::random_index(rng, sky_chunks.len())];
+            let x = chunk_pos.x * CHUNK_SIZE as i32 + Self::random_index(rng, CHUNK_SIZE) as i32;
+            let y = chunk_pos.y * CHUNK_SIZE as i32 + CHUNK_SIZE as i32 - 1;
+            if let Some(strike) = Self::strike_lightning(chunks, IVec2::new(x, y), rng) {
+                strikes.push(strike);
+            }
+        }
+        strikes
+    }
+
+    /// Rolls the next weather spell
+    fn change_weather<R: WorldRng>(&mut self, rng: &mut R) {
+        let roll = rng.gen_f32();
+        let kind = if roll < 0.5 {
+            WeatherKind::Clear
+        } else if roll < 0.85 {
+            WeatherKind::Rain
+        } else {
+            WeatherKind::Storm
+        };
+
+        let intensity = match kind {
+            WeatherKind::Clear => 0.0,
+            _ => 0.3 + 0.7 * rng.gen_f32(),
+        };
+        let mut wind = (rng.gen_f32() * 2.0 - 1.0) * MAX_WIND_SPEED;
+        if kind != WeatherKind::Storm {
+            wind *= 0.5;
+        }
+
+        self.state = WeatherState {
+            kind,
+            intensity,
+            wind,
+        };
+        self.time_until_change =
+            SPELL_MIN_DURATION + rng.gen_f32() * (SPELL_MAX_DURATION - SPELL_MIN_DURATION);
+        log::info!(
+            "Weather changed to {} (intensity {:.2}, wind {:.1})",
+            kind.name(),
+            intensity,
+            wind
+        );
+    }
+
+    /// Pushes gases and light powders above the surface one pixel downwind
+    fn blow_wind<R: WorldRng>(
+        &self,
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        materials: &Materials,
+        climate: &impl Fn(i32) -> ColumnClimate,
+        rng: &mut R,
+    ) {
+        if self.state.wind == 0.0 {
+            return;
+        }
+        let step = if self.state.wind > 0.0 { 1 } else { -1 };
+
+        for &chunk_pos in active_chunks {
+            let base = chunk_pos * CHUNK_SIZE as i32;
+            let columns: Vec<ColumnClimate> = (0..CHUNK_SIZE as i32)
+                .map(|x| climate(base.x + x))
+                .collect();
+            if columns
+                .iter()
+                .all(|c| c.surface_y >= base.y + CHUNK_SIZE as i32 - 1)
+            {
+                continue; // Fully underground
+            }
+
+            // Visit downwind pixels first so nothing is pushed twice in one pass
+            for y in 0..CHUNK_SIZE {
+                for i in 0..CHUNK_SIZE {
+                    let x = if step > 0 { CHUNK_SIZE - 1 - i } else { i };
+                    let pos = base + IVec2::new(x as i32, y as i32);
+                    let Some(pixel) = pixel_at(chunks, pos) else {
+                        continue;
+                    };
+                    // Pixels already pushed from a neighboring chunk stay put
+                    if pixel.flags & pixel_flags::UPDATED != 0 || !Self::is_blown(materials, pixel)
+                    {
+                        continue;
+                    }
+
+                    let wind = self.wind_at(pos.x, pos.y, columns[x]).x.abs();
+                    if !rng.check_probability(wind / MAX_WIND_SPEED * WIND_PUSH_CHANCE) {
+                        continue;
+                    }
+
+                    let target = pos + IVec2::new(step, 0);
+                    if pixel_at(chunks, target).is_some_and(|p| p.is_empty()) {
+                        let mut moved = pixel;
+                        moved.flags |= pixel_flags::UPDATED;
+                        set_pixel_at(chunks, target, moved);
+                        set_pixel_at(chunks, pos, Pixel::new(MaterialId::AIR));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Gases and light powders ride the wind
+    fn is_blown(materials: &Materials, pixel: Pixel) -> bool {
+        if pixel.is_empty() || WIND_IMMUNE.contains(&pixel.material_id) {
+            return false;
+        }
+        let material = materials.get(pixel.material_id);
+        match material.material_type {
+            MaterialType::Gas => material.density < LIGHT_POWDER_DENSITY,
+            MaterialType::Powder => material.density <= LIGHT_POWDER_DENSITY,
+            _ => false,
+        }
+    }
+
+    /// Cools the air above the surface of snowing biomes towards the biome temperature
+    fn chill_snowing_air(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        active_chunks: &[IVec2],
+        climate: &impl Fn(i32) -> ColumnClimate,
+    ) {
+        for &chunk_pos in active_chunks {
+            let base = chunk_pos * CHUNK_SIZE as i32;
+            let Some(chunk) = chunks.get_mut(&chunk_pos) else {
+                continue;
+            };
+            for cx in 0..8 {
+                let column = climate(base.x + cx as i32 * 8 + 4);
+                if Self::precipitation_for(column.biome) != Some(MaterialId::SNOW) {
+                    continue;
+                }
+                let target = biome_temperature(column.biome);
+                for cy in 0..8 {
+                    if base.y + cy as i32 * 8 + 4 <= column.surface_y {
+                        continue;
+                    }
+                    let temperature = chunk.get_temperature(cx, cy);
+                    if temperature > target {
+                        chunk.set_temperature(
+                            cx,
+                            cy,
+                            temperature + (target - temperature) * CHILL_RATE,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Spawns rain or snow along the top row of the sky chunks
+    fn precipitate<R: WorldRng>(
+        &self,
+        chunks: &mut HashMap<IVec2, Chunk>,
+        sky_chunks: &[IVec2],
+        climate: &impl Fn(i32) -> ColumnClimate,
+        rng: &mut R,
+    ) {
+        for &chunk_pos in sky_chunks {
+            if !rng.check_probability(DROPS_PER_CHUNK * self.state.intensity) {
+                continue;
+            }
+            let x = chunk_pos.x * CHUNK_SIZE as i32 + Self::random_index(rng, CHUNK_SIZE) as i32;
+            let y = chunk_pos.y * CHUNK_SIZE as i32 + CHUNK_SIZE as i32 - 1;
+            let Some(material_id) = self.precipitation_at(y, climate(x)) else {
+                continue;
+            };
+            let pos = IVec2::new(x, y);
+            if pixel_at(chunks, pos).is_some_and(|p| p.is_empty()) {
+                set_pixel_at(chunks, pos, Pixel::new(material_id));
+            }
+        }
+    }
+
+    /// Drops a jagged bolt of powered thunder from `start` to the ground
+    ///
+    /// Returns the first non-air pixel the bolt hit, if any.
+    fn strike_lightning<R: WorldRng>(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        start: IVec2,
+        rng: &mut R,
+    ) -> Option<IVec2> {
+        let mut bolt = Pixel::new(MaterialId::THUNDER);
+        bolt.flags |= pixel_flags::POWERED;
+
+        let mut pos = start;
+        for _ in 0..BOLT_MAX_LENGTH {
+            let pixel = pixel_at(chunks, pos)?;
+            if !pixel.is_empty() {
+                return Some(pos);
+            }
+            set_pixel_at(chunks, pos, bolt);
+
+            pos.y -= 1;
+            if rng.check_probability(0.3) {
+                pos.x += if rng.gen_bool() { 1 } else { -1 };
+            }
+        }
+        None
+    }
+
+    fn random_index<R: WorldRng>(rng: &mut R, len: usize) -> usize {
+        ((rng.gen_f32() * len as f32) as usize).min(len - 1)
+    }
+}
+
+impl Default for WeatherSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::test_fixtures::{count, empty_chunk};
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    /// Open sky over the whole test chunk
+    fn sky(biome: BiomeType) -> impl Fn(i32) -> ColumnClimate {
+        move |_| ColumnClimate {
+            biome,
+            surface_y: -1000,
+        }
+    }
+
+    fn weather(kind: WeatherKind, wind: f32) -> WeatherState {
+        WeatherState {
+            kind,
+            intensity: 1.0,
+            wind,
+        }
+    }
+
+    #[test]
+    fn test_starts_clear_and_calm() {
+        let system = WeatherSystem::new();
+        assert_eq!(system.state(), WeatherState::clear());
+
+        let climate = sky(BiomeType::Plains)(0);
+        assert_eq!(system.wind_at(0, 0, climate), Vec2::ZERO);
+        assert_eq!(system.precipitation_at(0, climate), None);
+    }
+
+    #[test]
+    fn test_disabled_weather_stays_clear() {
+        let (mut chunks, active, materials) = empty_chunk();
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut system = WeatherSystem::new();
+        system.set_state(weather(WeatherKind::Storm, MAX_WIND_SPEED), 1000.0);
+        system.set_enabled(false);
+
+        for _ in 0..600 {
+            system.update(
+                &mut chunks,
+                &active,
+                &materials,
+                sky(BiomeType::Plains),
+                &mut rng,
+            );
+        }
+        assert_eq!(system.state(), WeatherState::clear());
+        assert_eq!(count(&chunks, MaterialId::WATER), 0);
+        assert_eq!(system.wind_at(0, 0, sky(BiomeType::Plains)(0)), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_biome_temperature_decides_precipitation() {
+        use BiomeType::*;
+        assert_eq!(
+            WeatherSystem::precipitation_for(Plains),
+            Some(MaterialId::WATER)
+        );
+        assert_eq!(
+            WeatherSystem::precipitation_for(Mountains),
+            Some(MaterialId::SNOW)
+        );
+        assert_eq!(WeatherSystem::precipitation_for(Desert), None);
+    }
+
+    #[test]
+    fn test_rain_falls_only_under_open_sky() {
+        let (mut chunks, active, materials) = empty_chunk();
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut system = WeatherSystem::new();
+        system.set_state(weather(WeatherKind::Rain, 0.0), 100.0);
+
+        for _ in 0..60 {
+            system.update(
+                &mut chunks,
+                &active,
+                &materials,
+                sky(BiomeType::Plains),
+                &mut rng,
+            );
+        }
+        let drops = count(&chunks, MaterialId::WATER);
+        assert!(drops > 0, "Rain should fall in the sky chunk");
+        assert!(
+            (0..CHUNK_SIZE - 1).all(|y| (0..CHUNK_SIZE)
+                .all(|x| chunks[&IVec2::ZERO].get_material(x, y) != MaterialId::WATER)),
+            "Drops spawn in the top row"
+        );
+
+        // Same chunk buried under the terrain stays dry
+        let (mut chunks, active, materials) = empty_chunk();
+        let buried = |_| ColumnClimate {
+            biome: BiomeType::Plains,
+            surface_y: 1000,
+        };
+        for _ in 0..60 {
+            system.update(&mut chunks, &active, &materials, buried, &mut rng);
+        }
+        assert_eq!(count(&chunks, MaterialId::WATER), 0);
+    }
+
+    #[test]
+    fn test_snow_chills_cold_biomes() {
+        let (mut chunks, active, materials) = empty_chunk();
+        let mut rng = StdRng::seed_from_u64(2);
+        let mut system = WeatherSystem::new();
+        system.set_state(weather(WeatherKind::Rain, 0.0), 100.0);
+
+        for _ in 0..600 {
+            system.update(
+                &mut chunks,
+                &active,
+                &materials,
+                sky(BiomeType::Mountains),
+                &mut rng,
+            );
+        }
+        assert!(count(&chunks, MaterialId::SNOW) > 0);
+        assert_eq!(count(&chunks, MaterialId::WATER), 0);
+        assert!(chunks[&IVec2::ZERO].get_temperature(4, 7) < FREEZING_TEMPERATURE);
+    }
+
+    #[test]
+    fn test_wind_moves_pixels_once_across_chunk_borders() {
+        let (mut chunks, _, materials) = empty_chunk();
+        chunks.insert(IVec2::new(1, 0), Chunk::new(1, 0));
+        for y in 0..CHUNK_SIZE {
+            chunks.get_mut(&IVec2::ZERO).unwrap().set_material(
+                CHUNK_SIZE - 1,
+                y,
+                MaterialId::SMOKE,
+            );
+        }
+        let mut rng = StdRng::seed_from_u64(4);
+        let mut system = WeatherSystem::new();
+        system.set_state(weather(WeatherKind::Clear, MAX_WIND_SPEED), 100.0);
+
+        // The upwind chunk is visited first, pushing smoke into the next one
+        system.update(
+            &mut chunks,
+            &[IVec2::ZERO, IVec2::new(1, 0)],
+            &materials,
+            sky(BiomeType::Plains),
+            &mut rng,
+        );
+
+        let next = &chunks[&IVec2::new(1, 0)];
+        let crossed = (0..CHUNK_SIZE)
+            .filter(|&y| next.get_material(0, y) == MaterialId::SMOKE)
+            .count();
+        assert!(crossed > 0, "Smoke should cross the border");
+        assert!(
+            (0..CHUNK_SIZE)
+                .all(|y| (1..CHUNK_SIZE).all(|x| next.get_material(x, y) != MaterialId::SMOKE)),
+            "Pushed smoke isn't pushed again in the next chunk"
+        );
+        assert_eq!(count(&chunks, MaterialId::SMOKE), CHUNK_SIZE);
+    }
+
+    #[test]
+    fn test_wind_pushes_light_materials_downwind() {
+        let (mut chunks, active, materials) = empty_chunk();
+        {
+            let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+            chunk.set_material(10, 30, MaterialId::SMOKE);
+            chunk.set_material(10, 31, MaterialId::ASH);
+            chunk.set_material(10, 32, MaterialId::STONE);
+            chunk.set_material(10, 33, MaterialId::SAND);
+        }
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut system = WeatherSystem::new();
+        system.set_state(weather(WeatherKind::Clear, MAX_WIND_SPEED), 100.0);
+
+        for _ in 0..40 {
+            chunks.get_mut(&IVec2::ZERO).unwrap().clear_update_flags();
+            system.update(
+                &mut chunks,
+                &active,
+                &materials,
+                sky(BiomeType::Plains),
+                &mut rng,
+            );
+        }
+
+        let chunk = &chunks[&IVec2::ZERO];
+        let find = |material, y| (0..CHUNK_SIZE).find(|&x| chunk.get_material(x, y) == material);
+        assert!(find(MaterialId::SMOKE, 30).unwrap() > 10);
+        assert!(find(MaterialId::ASH, 31).unwrap() > 10);
+        assert_eq!(find(MaterialId::STONE, 32), Some(10));
+        assert_eq!(
+            find(MaterialId::SAND, 33),
+            Some(10),
+            "Heavy powders resist the wind"
+        );
+    }
+
+    #[test]
+    fn test_storm_strikes_ground_with_thunder() {
+        let (mut chunks, active, materials) = empty_chunk();
+        {
+            let chunk = chunks.get_mut(&IVec2::ZERO).unwrap();
+            for x in 0..CHUNK_SIZE {
+                chunk.set_material(x, 5, MaterialId::METAL);
+            }
+        }
+        let mut rng = StdRng::seed_from_u64(4);
+        let mut system = WeatherSystem::new();
+        system.set_state(weather(WeatherKind::Storm, 0.0), 1000.0);
+
+        let mut strikes = Vec::new();
+        for _ in 0..5000 {
+            strikes = system.update(
+                &mut chunks,
+                &active,
+                &materials,
+                sky(BiomeType::Desert),
+                &mut rng,
+            );
+            if !strikes.is_empty() {
+                break;
+            }
+        }
+
+        assert_eq!(strikes.len(), 1, "A storm should eventually strike");
+        assert_eq!(strikes[0].y, 5, "Lightning stops at the ground");
+        assert!(count(&chunks, MaterialId::THUNDER) >= CHUNK_SIZE - 6);
+    }
+}
@@ -0,0 +1,545 @@
+//! Workstation system - placed furnaces, anvils and alchemy tables
+//!
+//! Workstations are multi-pixel structures built from their own material.
+//! Recipes that name a workstation can only be crafted while one is in reach.
+//! Furnaces burn fuel from the player's inventory, smelt over time and heat
+//! the surrounding temperature field while lit.
+
+use glam::{IVec2, Vec2};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+use super::Chunk;
+use super::chunk_manager::ChunkManager;
+use crate::entity::crafting::{
+    CraftOutcome, Recipe, RecipeOutput, RecipeRegistry, WorkstationType,
+};
+use crate::entity::player::Player;
+use crate::simulation::{Materials, add_heat_at_pixel, get_temperature_at_pixel};
+
+/// Distance (pixels) from the player to a workstation's center to use it
+pub const WORKSTATION_REACH: f32 = 48.0;
+
+/// Seconds of burn time per point of a material's `fuel_value`
+const FUEL_SECONDS_PER_VALUE: f32 = 0.2;
+
+/// Heat added per second to each temperature cell under a lit furnace
+const FURNACE_HEAT_PER_SECOND: f32 = 150.0;
+
+/// Furnaces stop adding heat once their cells reach this temperature (°C)
+const FURNACE_MAX_TEMPERATURE: f32 = 900.0;
+
+/// Maximum queued smelting jobs per furnace
+const MAX_FURNACE_JOBS: usize = 8;
+
+/// A queued smelting job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmeltingJob {
+    pub output: RecipeOutput,
+    /// Seconds of burning left until the output is ready
+    pub remaining: f32,
+}
+
+/// Furnace state (fuel and smelting queue)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Furnace {
+    /// Seconds of burn time left
+    pub fuel: f32,
+    pub jobs: VecDeque<SmeltingJob>,
+    /// Finished items waiting for the player to come back in reach
+    pub output: Vec<RecipeOutput>,
+}
+
+impl Furnace {
+    pub fn is_lit(&self) -> bool {
+        self.fuel > 0.0 && !self.jobs.is_empty()
+    }
+}
+
+/// A workstation placed in the world
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlacedWorkstation {
+    pub kind: WorkstationType,
+    /// Bottom-left pixel of the footprint
+    pub origin: IVec2,
+    /// Only set for furnaces
+    #[serde(default)]
+    pub furnace: Option<Furnace>,
+}
+
+impl PlacedWorkstation {
+    pub fn new(kind: WorkstationType, origin: IVec2) -> Self {
+        Self {
+            kind,
+            origin,
+            furnace: (kind == WorkstationType::Furnace).then(Furnace::default),
+        }
+    }
+
+    /// Center of the footprint in world coordinates
+    pub fn center(&self) -> Vec2 {
+        let (w, h) = self.kind.footprint();
+        self.origin.as_vec2() + Vec2::new(w as f32, h as f32) / 2.0
+    }
+
+    /// Check if a position is close enough to use this workstation
+    pub fn in_reach(&self, position: Vec2) -> bool {
+        self.center().distance(position) <= WORKSTATION_REACH
+    }
+
+    /// All world pixels covered by this workstation
+    pub fn pixels(&self) -> impl Iterator<Item = IVec2> + '_ {
+        let (w, h) = self.kind.footprint();
+        (0..h).flat_map(move |dy| (0..w).map(move |dx| self.origin + IVec2::new(dx, dy)))
+    }
+
+    /// Check if the given pixel belongs to this workstation
+    pub fn contains(&self, pos: IVec2) -> bool {
+        let (w, h) = self.kind.footprint();
+        let rel = pos - self.origin;
+        rel.x >= 0 && rel.y >= 0 && rel.x < w && rel.y < h
+    }
+}
+
+/// Tracks placed workstations and runs furnaces
+#[derive(Debug, Clone, Default)]
+pub struct WorkstationSystem {
+    stations: Vec<PlacedWorkstation>,
+}
+
+impl WorkstationSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stations(&self) -> &[PlacedWorkstation] {
+        &self.stations
+    }
+
+    /// Replace all stations (used when loading a world)
+    pub fn set_stations(&mut self, stations: Vec<PlacedWorkstation>) {
+        self.stations = stations;
+    }
+
+    /// Check if a workstation footprint fits at `origin` (loaded, empty pixels only)
+    pub fn can_place(
+        &self,
+        chunks: &HashMap<IVec2, Chunk>,
+        kind: WorkstationType,
+        origin: IVec2,
+    ) -> bool {
+        PlacedWorkstation::new(kind, origin).pixels().all(|pos| {
+            let (chunk_pos, local_x, local_y) = ChunkManager::world_to_chunk_coords(pos.x, pos.y);
+            chunks
+                .get(&chunk_pos)
+                .is_some_and(|chunk| chunk.get_pixel(local_x, local_y).is_empty())
+        })
+    }
+
+    /// Register a newly built workstation
+    /// The caller is responsible for writing its pixels into the world
+    pub fn add(&mut self, kind: WorkstationType, origin: IVec2) -> &PlacedWorkstation {
+        self.stations.push(PlacedWorkstation::new(kind, origin));
+        self.stations.last().unwrap()
+    }
+
+    /// Pick up the workstation covering `pos`
+    ///
+    /// The player gets the station back as a single item plus a furnace's
+    /// finished output; fuel and unfinished jobs are lost. Returns the removed
+    /// station so the caller can clear its footprint, or `None` if there is no
+    /// station there or the inventory has no room for it.
+    pub fn pick_up(&mut self, pos: IVec2, player: &mut Player) -> Option<PlacedWorkstation> {
+        let index = self.stations.iter().position(|s| s.contains(pos))?;
+        if !player.mine_material(self.stations[index].kind.material_id()) {
+            return None;
+        }
+
+        let station = self.stations.swap_remove(index);
+        if let Some(furnace) = &station.furnace {
+            for output in furnace.output.iter().cloned() {
+                if let Some(lost) = deliver(player, output) {
+                    log::warn!("Inventory full, dropped furnace output {:?}", lost);
+                }
+            }
+        }
+        Some(station)
+    }
+
+    /// Workstation kinds within reach of a position (deduplicated)
+    pub fn in_reach(&self, position: Vec2) -> Vec<WorkstationType> {
+        let mut kinds = Vec::new();
+        for station in self.stations.iter().filter(|s| s.in_reach(position)) {
+            if !kinds.contains(&station.kind) {
+                kinds.push(station.kind);
+            }
+        }
+        kinds
+    }
+
+    /// Craft a recipe, respecting its workstation requirement
+    ///
+    /// Furnace recipes consume their inputs immediately and are queued at the
+    /// nearest furnace; fuel is pulled from the inventory if the furnace is cold.
+    pub fn craft(
+        &mut self,
+        recipe: &Recipe,
+        registry: &RecipeRegistry,
+        player: &mut Player,
+        materials: &Materials,
+    ) -> CraftOutcome {
+        if let Some(required) = recipe.workstation
+            && !self.in_reach(player.position).contains(&required)
+        {
+            return CraftOutcome::MissingWorkstation(required);
+        }
+
+        if !registry.can_craft(recipe, &player.inventory) {
+            return CraftOutcome::MissingMaterials;
+        }
+
+        if recipe.workstation != Some(WorkstationType::Furnace) || recipe.craft_time <= 0.0 {
+            return match registry.try_craft(recipe, &mut player.inventory) {
+                Some(output) => CraftOutcome::Crafted(output),
+                None => CraftOutcome::MissingMaterials,
+            };
+        }
+
+        let position = player.position;
+        let Some(furnace) = self
+            .stations
+            .iter_mut()
+            .filter(|s| s.in_reach(position))
+            .filter_map(|s| {
+                let distance = s.center().distance(position);
+                s.furnace.as_mut().map(|f| (distance, f))
+            })
+            .filter(|(_, f)| f.jobs.len() < MAX_FURNACE_JOBS)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, f)| f)
+        else {
+            return CraftOutcome::MissingWorkstation(WorkstationType::Furnace);
+        };
+
+        // Make sure the queue (including this job) can be fully fueled
+        let queued: f32 = furnace.jobs.iter().map(|j| j.remaining).sum();
+        let needed = queued + recipe.craft_time;
+        while furnace.fuel < needed {
+            let Some(seconds) = take_fuel(player, materials, &recipe.inputs) else {
+                return CraftOutcome::MissingFuel;
+            };
+            furnace.fuel += seconds;
+        }
+
+        let Some(output) = registry.try_craft(recipe, &mut player.inventory) else {
+            return CraftOutcome::MissingMaterials;
+        };
+        furnace.jobs.push_back(SmeltingJob {
+            output,
+            remaining: recipe.craft_time,
+        });
+
+        CraftOutcome::Smelting { seconds: needed }
+    }
+
+    /// Burn fuel, advance smelting and heat the world around lit furnaces
+    ///
+    /// Finished output goes straight to the player when in reach, otherwise it
+    /// waits in the furnace. Returns stations whose pixels were destroyed
+    /// (mined or blown up) so the caller can clear what's left of them.
+    pub fn update(
+        &mut self,
+        chunks: &mut HashMap<IVec2, Chunk>,
+        player: &mut Player,
+        delta_time: f32,
+    ) -> Vec<PlacedWorkstation> {
+        let material_intact = |chunks: &HashMap<IVec2, Chunk>, station: &PlacedWorkstation| {
+            station.pixels().all(|pos| {
+                let (chunk_pos, local_x, local_y) =
+                    ChunkManager::world_to_chunk_coords(pos.x, pos.y);
+                // Unloaded chunks keep their stations
+                chunks.get(&chunk_pos).is_none_or(|chunk| {
+                    chunk.get_pixel(local_x, local_y).material_id == station.kind.material_id()
+                })
+            })
+        };
+
+        let mut broken = Vec::new();
+        let mut i = 0;
+        while i < self.stations.len() {
+            if material_intact(chunks, &self.stations[i]) {
+                i += 1;
+            } else {
+                broken.push(self.stations.swap_remove(i));
+            }
+        }
+
+        for station in &mut self.stations {
+            let in_reach = station.in_reach(player.position);
+            let pixels: Vec<IVec2> = station.pixels().collect();
+            let Some(furnace) = station.furnace.as_mut() else {
+                continue;
+            };
+
+            if furnace.is_lit() {
+                let burn = delta_time.min(furnace.fuel);
+                furnace.fuel -= burn;
+                if let Some(job) = furnace.jobs.front_mut() {
+                    job.remaining -= burn;
+                    if job.remaining <= 0.0 {
+                        let job = furnace.jobs.pop_front().unwrap();
+                        furnace.output.push(job.output);
+                    }
+                }
+                heat_footprint(chunks, &pixels, FURNACE_HEAT_PER_SECOND * burn);
+            }
+
+            if in_reach && !furnace.output.is_empty() {
+                furnace.output = std::mem::take(&mut furnace.output)
+                    .into_iter()
+                    .filter_map(|output| deliver(player, output))
+                    .collect();
+            }
+        }
+
+        broken
+    }
+}
+
+/// Remove one fuel item from the inventory, returning its burn time in seconds
+/// Materials the recipe itself needs are never burned
+fn take_fuel(player: &mut Player, materials: &Materials, reserved: &[(u16, u32)]) -> Option<f32> {
+    let (material_id, fuel_value) = player
+        .inventory
+        .slots
+        .iter()
+        .flatten()
+        .filter_map(|stack| stack.material_id())
+        .filter(|id| !reserved.iter().any(|(reserved_id, _)| reserved_id == id))
+        .find_map(|id| materials.get(id).fuel_value.map(|value| (id, value)))?;
+    (player.inventory.remove_item(material_id, 1) == 1)
+        .then_some(fuel_value * FUEL_SECONDS_PER_VALUE)
+}
+
+/// Give finished output to the player, returns whatever didn't fit
+fn deliver(player: &mut Player, output: RecipeOutput) -> Option<RecipeOutput> {
+    match output {
+        RecipeOutput::Material { id, count } => {
+            let remaining = player.inventory.add_item(id, count);
+            (remaining > 0).then_some(RecipeOutput::Material {
+                id,
+                count: remaining,
+            })
+        }
+        RecipeOutput::Tool {
+            tool_id,
+            durability,
+        } => (!player.inventory.add_tool(tool_id, durability)).then_some(output),
+        RecipeOutput::Container { container } => {
+            (!player.inventory.add_container(container)).then_some(output)
+        }
+    }
+}
+
+/// Add heat to every temperature cell covered by the footprint (once per cell)
+fn heat_footprint(chunks: &mut HashMap<IVec2, Chunk>, pixels: &[IVec2], heat: f32) {
+    let mut heated: Vec<(IVec2, usize, usize)> = Vec::new();
+    for pos in pixels {
+        let (chunk_pos, local_x, local_y) = ChunkManager::world_to_chunk_coords(pos.x, pos.y);
+        let cell = (chunk_pos, local_x / 8, local_y / 8);
+        if heated.contains(&cell) {
+            continue;
+        }
+        heated.push(cell);
+        if let Some(chunk) = chunks.get_mut(&chunk_pos)
+            && get_temperature_at_pixel(chunk, local_x, local_y) < FURNACE_MAX_TEMPERATURE
+        {
+            add_heat_at_pixel(chunk, local_x, local_y, heat);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::inventory::Inventory;
+    use crate::simulation::MaterialId;
+
+    fn setup() -> (HashMap<IVec2, Chunk>, Player) {
+        let mut chunks = HashMap::new();
+        chunks.insert(IVec2::ZERO, Chunk::new(0, 0));
+        let mut player = Player::new(Vec2::new(20.0, 20.0));
+        player.inventory = Inventory::new(50);
+        (chunks, player)
+    }
+
+    fn build(
+        chunks: &mut HashMap<IVec2, Chunk>,
+        system: &mut WorkstationSystem,
+        kind: WorkstationType,
+        origin: IVec2,
+    ) {
+        assert!(system.can_place(chunks, kind, origin));
+        let station = system.add(kind, origin);
+        for pos in station.pixels() {
+            chunks.get_mut(&IVec2::ZERO).unwrap().set_material(
+                pos.x as usize,
+                pos.y as usize,
+                kind.material_id(),
+            );
+        }
+    }
+
+    fn recipe(registry: &RecipeRegistry, name: &str) -> Recipe {
+        registry
+            .all_recipes()
+            .iter()
+            .find(|r| r.name == name)
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn test_placement_requires_empty_space() {
+        let (mut chunks, _) = setup();
+        let mut system = WorkstationSystem::new();
+        build(
+            &mut chunks,
+            &mut system,
+            WorkstationType::Anvil,
+            IVec2::new(10, 10),
+        );
+
+        // Overlapping footprint is blocked, unloaded chunks are blocked
+        assert!(!system.can_place(&chunks, WorkstationType::Furnace, IVec2::new(12, 8)));
+        assert!(!system.can_place(&chunks, WorkstationType::Furnace, IVec2::new(-3, 10)));
+        assert!(system.can_place(&chunks, WorkstationType::Furnace, IVec2::new(30, 10)));
+    }
+
+    #[test]
+    fn test_reach_gates_recipes() {
+        let (mut chunks, mut player) = setup();
+        let registry = RecipeRegistry::new();
+        let materials = Materials::new();
+        let mut system = WorkstationSystem::new();
+        player.inventory.add_item(MaterialId::OIL, 2);
+        player.inventory.add_item(MaterialId::ACID, 1);
+        let nitro = recipe(&registry, "Nitro");
+
+        assert!(matches!(
+            system.craft(&nitro, &registry, &mut player, &materials),
+            CraftOutcome::MissingWorkstation(WorkstationType::Alchemy)
+        ));
+
+        build(
+            &mut chunks,
+            &mut system,
+            WorkstationType::Alchemy,
+            IVec2::new(20, 10),
+        );
+        assert_eq!(
+            system.in_reach(player.position),
+            vec![WorkstationType::Alchemy]
+        );
+        assert!(system.in_reach(Vec2::new(500.0, 500.0)).is_empty());
+        assert!(matches!(
+            system.craft(&nitro, &registry, &mut player, &materials),
+            CraftOutcome::Crafted(RecipeOutput::Material {
+                id: MaterialId::NITRO,
+                count: 2
+            })
+        ));
+        assert_eq!(player.inventory.count_item(MaterialId::OIL), 0);
+    }
+
+    #[test]
+    fn test_furnace_needs_fuel_and_time() {
+        let (mut chunks, mut player) = setup();
+        let registry = RecipeRegistry::new();
+        let materials = Materials::new();
+        let mut system = WorkstationSystem::new();
+        build(
+            &mut chunks,
+            &mut system,
+            WorkstationType::Furnace,
+            IVec2::new(20, 10),
+        );
+        player.inventory.add_item(MaterialId::IRON_ORE, 2);
+        let smelt = recipe(&registry, "Smelt Iron");
+
+        assert!(matches!(
+            system.craft(&smelt, &registry, &mut player, &materials),
+            CraftOutcome::MissingFuel
+        ));
+        assert_eq!(player.inventory.count_item(MaterialId::IRON_ORE), 2);
+
+        player.inventory.add_item(MaterialId::WOOD, 5);
+        assert!(matches!(
+            system.craft(&smelt, &registry, &mut player, &materials),
+            CraftOutcome::Smelting { .. }
+        ));
+        assert_eq!(player.inventory.count_item(MaterialId::IRON_ORE), 0);
+        assert!(player.inventory.count_item(MaterialId::WOOD) < 5);
+
+        // Not done yet after one second, but the furnace heats its surroundings
+        system.update(&mut chunks, &mut player, 1.0);
+        assert_eq!(player.inventory.count_item(MaterialId::IRON_INGOT), 0);
+        let chunk = &chunks[&IVec2::ZERO];
+        assert!(get_temperature_at_pixel(chunk, 22, 12) > 100.0);
+
+        for _ in 0..4 {
+            system.update(&mut chunks, &mut player, 1.0);
+        }
+        assert_eq!(player.inventory.count_item(MaterialId::IRON_INGOT), 1);
+    }
+
+    #[test]
+    fn test_destroyed_station_is_removed() {
+        let (mut chunks, mut player) = setup();
+        let mut system = WorkstationSystem::new();
+        build(
+            &mut chunks,
+            &mut system,
+            WorkstationType::Anvil,
+            IVec2::new(10, 10),
+        );
+
+        assert!(system.update(&mut chunks, &mut player, 0.1).is_empty());
+        chunks
+            .get_mut(&IVec2::ZERO)
+            .unwrap()
+            .set_material(12, 11, MaterialId::AIR);
+
+        let broken = system.update(&mut chunks, &mut player, 0.1);
+        assert_eq!(broken.len(), 1);
+        assert!(system.stations().is_empty());
+    }
+
+    #[test]
+    fn test_pick_up_returns_one_item_and_output() {
+        let (mut chunks, mut player) = setup();
+        let mut system = WorkstationSystem::new();
+        build(
+            &mut chunks,
+            &mut system,
+            WorkstationType::Furnace,
+            IVec2::new(20, 10),
+        );
+        system.stations[0]
+            .furnace
+            .as_mut()
+            .unwrap()
+            .output
+            .push(RecipeOutput::Material {
+                id: MaterialId::IRON_INGOT,
+                count: 3,
+            });
+
+        assert!(system.pick_up(IVec2::new(0, 0), &mut player).is_none());
+        let station = system.pick_up(IVec2::new(23, 14), &mut player).unwrap();
+        assert_eq!(station.kind, WorkstationType::Furnace);
+        assert!(system.stations().is_empty());
+        assert_eq!(player.inventory.count_item(MaterialId::FURNACE), 1);
+        assert_eq!(player.inventory.count_item(MaterialId::IRON_INGOT), 3);
+    }
+}
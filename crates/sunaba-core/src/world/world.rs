@@ -15,28 +15,41 @@ use super::chemistry_system::ChemistrySystem;
 use super::chunk_manager::ChunkManager;
 use super::chunk_status::ChunkStatus;
 use super::collision::CollisionDetector;
+use super::container_system::{ContainerSystem, ContainerUse};
+use super::day_night_system::{
+    DayNightSettings, DayNightSystem, HOSTILE_ATTACK_RADIUS, HOSTILE_DAMAGE_PER_SECOND,
+    HOSTILE_SPAWN_HEIGHT,
+};
 use super::debris_system::DebrisSystem;
 use super::electrical_system::ElectricalSystem;
-use super::light_system::LightSystem;
+use super::explosion_system::ExplosionSystem;
+use super::gas_system::{GasSystem, SUFFOCATION_OXYGEN};
+use super::hydrostatic_system::HydrostaticSystem;
+use super::light_system::{DAY_NIGHT_CYCLE_DURATION, LightSystem};
 use super::mining_system::MiningSystem;
 use super::persistence_system::PersistenceSystem;
 #[cfg(feature = "regeneration")]
 use super::pixel_entity_system::PixelEntitySystem;
 use super::pixel_queries::PixelQueries;
+use super::player_environment::PlayerEnvironmentSystem;
 use super::player_physics::PlayerPhysicsSystem;
 use super::pressure_system::PressureSystem;
 use super::raycasting::Raycasting;
 #[cfg(feature = "regeneration")]
 use super::special_behaviors_system::SpecialBehaviorsSystem;
 use super::stats::NoopStats;
+use super::tool_system::{TOOL_REACH, ToolSystem, ToolUse};
+use super::vegetation_system::VegetationSystem;
+use super::weather_system::{ColumnClimate, WeatherState, WeatherSystem};
+use super::workstation_system::WorkstationSystem;
 use super::{CHUNK_SIZE, Chunk, Pixel, pixel_flags};
 
-use crate::entity::crafting::RecipeRegistry;
+use crate::entity::crafting::{CraftOutcome, Recipe, RecipeRegistry, WorkstationType};
 use crate::entity::player::Player;
-use crate::entity::tools::ToolRegistry;
+use crate::entity::tools::{ToolRegistry, ToolType};
 use crate::simulation::{
     ChunkRenderData, FallingChunk, MaterialId, MaterialType, Materials, ReactionRegistry,
-    RegenerationSystem, StructuralIntegritySystem, TemperatureSimulator, WorldCollisionQuery,
+    StructuralIntegritySystem, TemperatureSimulator, WorldCollisionQuery,
 };
 
 /// The game world, composed of chunks
@@ -68,18 +81,36 @@ pub struct World {
     /// Temporary light manager (mining flashes, explosions, etc.)
     temporary_lights: crate::simulation::temporary_light_manager::TemporaryLightManager,
 
-    /// Resource regeneration system
-    regeneration_system: RegenerationSystem,
+    /// Vegetation system (plant growth, grass, trees, fruit)
+    vegetation_system: VegetationSystem,
 
     /// Debris system (kinematic falling chunks, simple debris physics, WASM-compatible)
     debris_system: DebrisSystem,
 
+    /// Explosion system (blast destruction, shockwaves, chain detonation)
+    explosion_system: ExplosionSystem,
+
+    /// Placed workstations (furnaces, anvils, alchemy tables)
+    workstation_system: WorkstationSystem,
+
     /// Electrical system (power propagation for Powder Game)
     electrical_system: ElectricalSystem,
 
     /// Pressure system (gas accumulation and propagation for Powder Game)
     pressure_system: PressureSystem,
 
+    /// Hydrostatic system (levels connected liquid bodies)
+    hydrostatic_system: HydrostaticSystem,
+
+    /// Weather system (wind, rain, snow, lightning)
+    weather_system: WeatherSystem,
+
+    /// Day/night effects (surface temperature, night growth, night spawns)
+    day_night_system: DayNightSystem,
+
+    /// Gas system (oxygen and carbon dioxide per coarse cell)
+    gas_system: GasSystem,
+
     /// Special behaviors system (fuse, vine, virus, clone for Powder Game)
     #[cfg(feature = "regeneration")]
     special_behaviors_system: SpecialBehaviorsSystem,
@@ -110,6 +141,9 @@ pub struct World {
 
     /// Active chunk simulation radius (chunks from center)
     active_chunk_radius: i32,
+
+    /// Extra simulation centers (dedicated server players); empty = around `player`
+    simulation_centers: Vec<Vec2>,
 }
 
 impl World {
@@ -128,10 +162,16 @@ impl World {
             light_system: LightSystem::new(),
             temporary_lights:
                 crate::simulation::temporary_light_manager::TemporaryLightManager::new(),
-            regeneration_system: RegenerationSystem::new(),
+            vegetation_system: VegetationSystem::new(),
             debris_system: DebrisSystem::new(),
+            explosion_system: ExplosionSystem::new(),
+            workstation_system: WorkstationSystem::new(),
             electrical_system: ElectricalSystem::new(),
             pressure_system: PressureSystem::new(),
+            hydrostatic_system: HydrostaticSystem::new(),
+            weather_system: WeatherSystem::new(),
+            day_night_system: DayNightSystem::new(),
+            gas_system: GasSystem::new(),
             #[cfg(feature = "regeneration")]
             special_behaviors_system: SpecialBehaviorsSystem::new(),
             #[cfg(feature = "regeneration")]
@@ -144,6 +184,7 @@ impl World {
             session_start: Instant::now(),
             total_play_time_seconds: 0,
             active_chunk_radius: 3, // Default: 7×7 grid (matches ACTIVE_CHUNK_RADIUS)
+            simulation_centers: Vec::new(),
         };
 
         // Don't pre-generate - let chunks generate on-demand as player explores
@@ -186,6 +227,11 @@ impl World {
         self.persistence_system.set_seed(seed);
     }
 
+    /// Get the world generator seed
+    pub fn seed(&self) -> u64 {
+        self.persistence_system.seed()
+    }
+
     /// Update the world generator config and regenerate all chunks
     pub fn update_generator_config(&mut self, config: super::worldgen_config::WorldGenConfig) {
         // Update the generator config
@@ -405,6 +451,16 @@ impl World {
         );
     }
 
+    /// Pixel positions directly below the player's hitbox (for pressure plates)
+    fn player_foot_positions(&self) -> Vec<IVec2> {
+        let half_width = crate::entity::player::Player::WIDTH / 2.0;
+        let feet_y = (self.player.position.y - crate::entity::player::Player::HEIGHT / 2.0 - 1.0)
+            .floor() as i32;
+        let min_x = (self.player.position.x - half_width).floor() as i32;
+        let max_x = (self.player.position.x + half_width).floor() as i32;
+        (min_x..=max_x).map(|x| IVec2::new(x, feet_y)).collect()
+    }
+
     /// Check if player is dead
     pub fn is_player_dead(&self) -> bool {
         self.player.is_dead
@@ -419,11 +475,25 @@ impl World {
 
     /// Update active chunks: remove distant chunks and re-activate nearby loaded chunks
     pub fn update_active_chunks(&mut self) {
-        ChunkStatus::update_active_chunks(
-            &mut self.chunk_manager,
-            self.player.position,
-            self.active_chunk_radius,
-        );
+        if self.simulation_centers.is_empty() {
+            ChunkStatus::update_active_chunks(
+                &mut self.chunk_manager,
+                self.player.position,
+                self.active_chunk_radius,
+            );
+        } else {
+            ChunkStatus::update_active_chunks_around(
+                &mut self.chunk_manager,
+                &self.simulation_centers,
+                self.active_chunk_radius,
+            );
+        }
+    }
+
+    /// Simulate around these positions instead of the local player (for dedicated servers)
+    /// An empty list restores simulating around `player`
+    pub fn set_simulation_centers(&mut self, centers: Vec<Vec2>) {
+        self.simulation_centers = centers;
     }
 
     /// Set the active chunk simulation radius
@@ -493,9 +563,75 @@ impl World {
             .spawn_creature(genome, self.player.position)
     }
 
+    /// Spawn hostile creatures at night, let them hurt the player, and remove them at sunrise
+    fn update_hostile_creatures<R: crate::world::WorldRng>(&mut self, dt: f32, rng: &mut R) {
+        use crate::creature::genome::CreatureGenome;
+        use crate::creature::{CreatureArchetype, MorphologyConfig};
+
+        let daylight = self.light_system.daylight();
+        if !self.day_night_system.is_enabled() || !DayNightSystem::is_night(daylight) {
+            let hostile: Vec<_> = self
+                .creature_manager
+                .iter()
+                .filter(|c| c.hostile)
+                .map(|c| c.id)
+                .collect();
+            for id in hostile {
+                self.creature_manager.remove_creature(id);
+            }
+            return;
+        }
+
+        let hostile_count = self.creature_manager.iter().filter(|c| c.hostile).count();
+        if self
+            .day_night_system
+            .hostile_spawn_due(dt, daylight, hostile_count)
+            && self.creature_manager.can_spawn()
+        {
+            let x = DayNightSystem::hostile_spawn_x(self.player.position.x, rng);
+            // Columns that aren't loaded have no ground to drop onto
+            if let Some(ground) =
+                DayNightSystem::ground_height(&self.chunk_manager.chunks, &self.materials, x)
+            {
+                let y = ground + HOSTILE_SPAWN_HEIGHT;
+                let id = self
+                    .creature_manager
+                    .spawn_creature_with_archetype_and_hunger(
+                        CreatureGenome::test_quadruped(),
+                        Vec2::new(x as f32, y as f32),
+                        1.0,
+                        &MorphologyConfig::default(),
+                        CreatureArchetype::Spider,
+                    );
+                if let Some(creature) = self.creature_manager.get_mut(id) {
+                    creature.hostile = true;
+                }
+            }
+        }
+
+        if self.player.is_dead {
+            return;
+        }
+        let attackers = self
+            .creature_manager
+            .iter()
+            .filter(|c| {
+                c.hostile && c.position.distance(self.player.position) <= HOSTILE_ATTACK_RADIUS
+            })
+            .count();
+        if attackers > 0 {
+            self.player
+                .health
+                .take_damage(attackers as f32 * HOSTILE_DAMAGE_PER_SECOND * dt);
+        }
+    }
+
     /// Mine a single pixel and add it to player's inventory
     /// Returns true if successfully mined
     pub fn mine_pixel(&mut self, world_x: i32, world_y: i32) -> bool {
+        if self.is_workstation_pixel(world_x, world_y) {
+            return self.mine_workstation_pixel(IVec2::new(world_x, world_y));
+        }
         MiningSystem::mine_pixel(
             &mut self.player,
             &mut self.chunk_manager,
@@ -514,6 +650,17 @@ impl World {
         material_id: u16,
         brush_size: u32,
     ) -> u32 {
+        // Workstations are placed as a whole structure, not brushed
+        if let Some(kind) = WorkstationType::from_material(material_id) {
+            let (w, _) = kind.footprint();
+            let origin = IVec2::new(world_x - w / 2, world_y);
+            return if self.place_workstation(kind, origin) {
+                1
+            } else {
+                0
+            };
+        }
+
         let positions = MiningSystem::place_material_from_inventory(
             &mut self.player,
             &self.chunk_manager,
@@ -526,7 +673,7 @@ impl World {
 
         let count = positions.len() as u32;
         for (x, y) in positions {
-            let mut pixel = Pixel::new(material_id);
+            let mut pixel = Pixel::new(material_id).with_direction(self.player.placement_direction);
             pixel.flags |= pixel_flags::PLAYER_PLACED;
             self.set_pixel_full(x, y, pixel);
         }
@@ -534,6 +681,80 @@ impl World {
         count
     }
 
+    /// Build a workstation from the player's inventory with its bottom-left corner at `origin`
+    /// Returns false if the player has none or the footprint isn't clear
+    pub fn place_workstation(&mut self, kind: WorkstationType, origin: IVec2) -> bool {
+        if !self.player.inventory.has_item(kind.material_id(), 1)
+            || !self
+                .workstation_system
+                .can_place(&self.chunk_manager.chunks, kind, origin)
+        {
+            return false;
+        }
+
+        self.player.inventory.remove_item(kind.material_id(), 1);
+        let pixels: Vec<IVec2> = self.workstation_system.add(kind, origin).pixels().collect();
+        for pos in pixels {
+            let mut pixel = Pixel::new(kind.material_id());
+            pixel.flags |= pixel_flags::PLAYER_PLACED;
+            self.set_pixel_full(pos.x, pos.y, pixel);
+        }
+        log::info!("Placed {} at {:?}", kind.name(), origin);
+        true
+    }
+
+    fn is_workstation_pixel(&self, world_x: i32, world_y: i32) -> bool {
+        self.get_pixel(world_x, world_y)
+            .is_some_and(|p| WorkstationType::from_material(p.material_id).is_some())
+    }
+
+    /// Mine a pixel of a workstation: the whole station comes back as one item
+    ///
+    /// Stray workstation pixels without a station are cleared without a drop.
+    /// Returns true if a station was picked up.
+    fn mine_workstation_pixel(&mut self, pos: IVec2) -> bool {
+        let Some(station) = self.workstation_system.pick_up(pos, &mut self.player) else {
+            if !self.workstations().iter().any(|s| s.contains(pos)) {
+                self.set_pixel(pos.x, pos.y, MaterialId::AIR);
+            }
+            return false;
+        };
+        self.clear_workstation(&station);
+        log::info!("Picked up {} at {:?}", station.kind.name(), station.origin);
+        true
+    }
+
+    /// Clear whatever is left of a removed workstation's footprint
+    fn clear_workstation(&mut self, station: &super::PlacedWorkstation) {
+        for pos in station.pixels() {
+            if self.get_pixel(pos.x, pos.y).map(|p| p.material_id)
+                == Some(station.kind.material_id())
+            {
+                self.set_pixel(pos.x, pos.y, MaterialId::AIR);
+            }
+        }
+    }
+
+    /// Workstations placed in the world
+    pub fn workstations(&self) -> &[super::PlacedWorkstation] {
+        self.workstation_system.stations()
+    }
+
+    /// Workstation kinds within reach of the player (for recipe filtering)
+    pub fn workstations_in_reach(&self) -> Vec<WorkstationType> {
+        self.workstation_system.in_reach(self.player.position)
+    }
+
+    /// Craft a recipe from the player's inventory at nearby workstations
+    pub fn craft_recipe(&mut self, recipe: &Recipe) -> CraftOutcome {
+        self.workstation_system.craft(
+            recipe,
+            &self.recipe_registry,
+            &mut self.player,
+            &self.materials,
+        )
+    }
+
     /// Place material at world coordinates without consuming from inventory (debug mode)
     pub fn place_material_debug(
         &mut self,
@@ -552,7 +773,7 @@ impl World {
 
         let count = positions.len() as u32;
         for (x, y) in positions {
-            let mut pixel = Pixel::new(material_id);
+            let mut pixel = Pixel::new(material_id).with_direction(self.player.placement_direction);
             pixel.flags |= pixel_flags::PLAYER_PLACED;
             self.set_pixel_full(x, y, pixel);
         }
@@ -585,6 +806,13 @@ impl World {
     /// Complete mining at the specified position
     /// Returns Some(material_id) if successfully mined, None otherwise
     fn complete_mining(&mut self, world_x: i32, world_y: i32) -> Option<u16> {
+        if self.is_workstation_pixel(world_x, world_y) {
+            let material_id = self.get_pixel(world_x, world_y)?.material_id;
+            return self
+                .mine_workstation_pixel(IVec2::new(world_x, world_y))
+                .then_some(material_id);
+        }
+
         let mined_material = MiningSystem::complete_mining(
             &mut self.player,
             &self.chunk_manager,
@@ -602,6 +830,210 @@ impl World {
         mined_material
     }
 
+    /// Use the equipped tool at world coordinates (called every frame while held)
+    ///
+    /// Pickaxes mine over time, axes fell the whole tree, shovels dig powders in a
+    /// wide brush and swords strike creatures. Axe chops, shovel digs and sword
+    /// swings share the player's attack cooldown, so a held button works at the
+    /// same pace whatever the frame rate.
+    pub fn use_equipped_tool(&mut self, world_x: i32, world_y: i32, delta_time: f32) -> ToolUse {
+        let Some(tool) = self.player.get_equipped_tool(&self.tool_registry).cloned() else {
+            return ToolUse::None;
+        };
+        let target = Vec2::new(world_x as f32, world_y as f32);
+        if target.distance(self.player.position) > TOOL_REACH {
+            return ToolUse::None;
+        }
+
+        match tool.tool_type {
+            ToolType::Pickaxe => {
+                if self.player.mining_progress.target_pixel != Some((world_x, world_y)) {
+                    self.start_mining(world_x, world_y);
+                }
+                match self.update_mining(delta_time) {
+                    Some(material_id) => ToolUse::Mined(material_id),
+                    None if self.player.mining_progress.is_mining() => ToolUse::Mining,
+                    None => ToolUse::None,
+                }
+            }
+            ToolType::Axe => {
+                if self.player.attack_cooldown > 0.0 {
+                    return ToolUse::None;
+                }
+                let tree = ToolSystem::fell_tree(
+                    &self.chunk_manager,
+                    IVec2::new(world_x, world_y),
+                    tool.fell_limit(),
+                );
+                if tree.is_empty() {
+                    return ToolUse::None;
+                }
+                self.player.attack_cooldown = Player::ATTACK_COOLDOWN;
+
+                // Collect into the inventory, whatever doesn't fit falls as debris
+                let mut collected = 0;
+                let mut overflow = std::collections::HashSet::new();
+                for pos in tree {
+                    let Some(pixel) = self.get_pixel(pos.x, pos.y) else {
+                        continue;
+                    };
+                    if self.player.mine_material(pixel.material_id) {
+                        self.set_pixel(pos.x, pos.y, MaterialId::AIR);
+                        collected += 1;
+                    } else {
+                        overflow.insert(pos);
+                    }
+                }
+                let debris = overflow.len() as u32;
+                if !overflow.is_empty() {
+                    self.create_debris(overflow);
+                }
+                log::debug!(
+                    "[TOOLS] Felled tree: {} collected, {} debris",
+                    collected,
+                    debris
+                );
+                ToolUse::Felled { collected, debris }
+            }
+            ToolType::Shovel => {
+                if self.player.attack_cooldown > 0.0 {
+                    return ToolUse::None;
+                }
+                let positions = ToolSystem::dig_positions(
+                    &self.chunk_manager,
+                    &self.materials,
+                    IVec2::new(world_x, world_y),
+                    tool.dig_radius(),
+                );
+                let mut dug = 0;
+                for pos in positions {
+                    let Some(pixel) = self.get_pixel(pos.x, pos.y) else {
+                        continue;
+                    };
+                    if !self.player.mine_material(pixel.material_id) {
+                        break; // Inventory full
+                    }
+                    self.set_pixel(pos.x, pos.y, MaterialId::AIR);
+                    dug += 1;
+                }
+                if dug > 0 {
+                    self.player.attack_cooldown = Player::ATTACK_COOLDOWN;
+                    ToolUse::Dug(dug)
+                } else {
+                    ToolUse::None
+                }
+            }
+            ToolType::Sword => {
+                if self.player.attack_cooldown > 0.0 {
+                    return ToolUse::None;
+                }
+                self.player.attack_cooldown = Player::ATTACK_COOLDOWN;
+                ToolUse::Struck(ToolSystem::strike(
+                    &self.player,
+                    &mut self.creature_manager,
+                    &tool,
+                    target,
+                ))
+            }
+        }
+    }
+
+    /// Use the container in the selected hotbar slot at world coordinates
+    ///
+    /// Clicking a fluid the container can carry scoops up the connected body (up to
+    /// the remaining capacity); clicking anywhere else pours the contents into the
+    /// empty cells around the target. Shares the player's attack cooldown so a held
+    /// button doesn't scoop and pour on alternate frames.
+    pub fn use_selected_container(&mut self, world_x: i32, world_y: i32) -> ContainerUse {
+        let Some(stack) = self
+            .player
+            .inventory
+            .get_slot(self.player.selected_slot)
+            .and_then(|slot| slot.as_ref())
+        else {
+            return ContainerUse::None;
+        };
+        let Some(container) = stack.container() else {
+            return ContainerUse::None;
+        };
+        let contents = stack.contents();
+        let target = IVec2::new(world_x, world_y);
+        if self.player.attack_cooldown > 0.0
+            || target.as_vec2().distance(self.player.position) > TOOL_REACH
+        {
+            return ContainerUse::None;
+        }
+
+        let held = contents.map(|(material_id, _)| material_id);
+        let stored = contents.map_or(0, |(_, amount)| amount);
+        let result = if stored < container.capacity()
+            && ContainerSystem::can_scoop(
+                &self.chunk_manager,
+                &self.materials,
+                container,
+                held,
+                target,
+            ) {
+            let positions = ContainerSystem::scoop_positions(
+                &self.chunk_manager,
+                &self.materials,
+                target,
+                (container.capacity() - stored) as usize,
+            );
+            let Some(material_id) = self.get_pixel(target.x, target.y).map(|p| p.material_id)
+            else {
+                return ContainerUse::None;
+            };
+            for pos in &positions {
+                self.set_pixel(pos.x, pos.y, MaterialId::AIR);
+            }
+            let amount = positions.len() as u32;
+            if let Some(Some(stack)) = self
+                .player
+                .inventory
+                .get_slot_mut(self.player.selected_slot)
+            {
+                stack.fill(material_id, amount);
+            }
+            ContainerUse::Scooped {
+                material_id,
+                amount,
+            }
+        } else if let Some((material_id, amount)) = contents {
+            let positions =
+                ContainerSystem::pour_positions(&self.chunk_manager, target, amount as usize);
+            if positions.is_empty() {
+                return ContainerUse::None;
+            }
+            for pos in &positions {
+                self.set_pixel(pos.x, pos.y, material_id);
+            }
+            let amount = positions.len() as u32;
+            if let Some(Some(stack)) = self
+                .player
+                .inventory
+                .get_slot_mut(self.player.selected_slot)
+            {
+                stack.drain(amount);
+            }
+            ContainerUse::Poured {
+                material_id,
+                amount,
+            }
+        } else {
+            return ContainerUse::None;
+        };
+
+        self.player.attack_cooldown = Player::ATTACK_COOLDOWN;
+        log::debug!(
+            "[CONTAINERS] {} at {:?}: {:?}",
+            container.name(),
+            target,
+            result
+        );
+        result
+    }
+
     /// DEBUG: Instantly mine all materials in a circle around position
     /// Used for quick world exploration during testing
     pub fn debug_mine_circle(&mut self, center_x: i32, center_y: i32, radius: i32) {
@@ -659,6 +1091,21 @@ impl World {
     ) {
         const FIXED_TIMESTEP: f32 = 1.0 / 60.0;
 
+        // Environmental hazards (drowning, heat, burning, poison) and swimming state
+        if !self.player.is_dead {
+            let contact = PlayerEnvironmentSystem::sample(
+                &self.chunk_manager.chunks,
+                &self.materials,
+                self.player.position,
+            );
+            self.player.apply_environment(&contact, dt);
+            GasSystem::breathe(
+                &mut self.chunk_manager.chunks,
+                PlayerEnvironmentSystem::head_position(self.player.position),
+                Player::OXYGEN_USE * dt,
+            );
+        }
+
         // Update player (hunger, health, starvation damage)
         if self.player.update(dt) {
             log::info!("Player died!");
@@ -666,6 +1113,15 @@ impl World {
             // Respawn will be triggered by player input (game over screen)
         }
 
+        // Furnaces (fuel, smelting, heat) and removal of destroyed workstations
+        let broken =
+            self.workstation_system
+                .update(&mut self.chunk_manager.chunks, &mut self.player, dt);
+        for station in broken {
+            self.clear_workstation(&station);
+            log::info!("{} at {:?} destroyed", station.kind.name(), station.origin);
+        }
+
         // Update light system (day/night cycle, growth timer)
         self.light_system.update(dt);
 
@@ -694,10 +1150,6 @@ impl World {
     fn step_simulation<R: crate::world::WorldRng>(
         &mut self,
         stats: &mut dyn crate::world::SimStats,
-        #[cfg_attr(
-            all(not(target_arch = "wasm32"), feature = "regeneration"),
-            allow(unused_variables)
-        )]
         rng: &mut R,
         is_multiplayer_connected: bool,
     ) {
@@ -752,11 +1204,36 @@ impl World {
                 .filter(|&pos| ChunkStatus::needs_ca_update(chunk_manager, pos))
                 .collect()
         };
+        // 2.4. Weather (before electrical, so lightning strikes spread this tick)
+        {
+            #[cfg(feature = "profiling")]
+            puffin::profile_scope!("weather");
+
+            let generator = &self.persistence_system.generator;
+            let strikes = self.weather_system.update(
+                &mut self.chunk_manager.chunks,
+                &self.chunk_manager.active_chunks,
+                &self.materials,
+                |x| ColumnClimate::sample(generator, x),
+                rng,
+            );
+            for strike in strikes {
+                self.electrical_system.strike(
+                    &mut self.chunk_manager.chunks,
+                    &self.materials,
+                    strike,
+                );
+            }
+        }
+
         // 2.5. Electrical system update (before CA movement)
         {
             #[cfg(feature = "profiling")]
             puffin::profile_scope!("electrical");
 
+            // The player's feet weigh down pressure plates
+            self.electrical_system
+                .set_external_loads(self.player_foot_positions());
             self.electrical_system.update(
                 &mut self.chunk_manager.chunks,
                 &chunks_to_update,
@@ -888,6 +1365,33 @@ impl World {
             }
         }
 
+        // 2.85. Hydrostatic equalization (after both checkerboard phases, on this
+        // thread, so moves may cross chunk borders without racing the CA workers)
+        {
+            #[cfg(feature = "profiling")]
+            puffin::profile_scope!("hydrostatic");
+
+            self.hydrostatic_system.update(
+                &mut self.chunk_manager.chunks,
+                &self.chunk_manager.active_chunks,
+                &self.materials,
+            );
+        }
+
+        // 2.9. Explosions (detonations recorded during the CA pass)
+        // Temporarily take explosion_system so it can modify the world
+        let mut explosion_system = std::mem::take(&mut self.explosion_system);
+        {
+            #[cfg(feature = "detailed_profiling")]
+            let _span = tracing::info_span!("explosions").entered();
+
+            let explosions = explosion_system.update(self, rng);
+            if explosions > 0 {
+                log::debug!("Applied {} explosions", explosions);
+            }
+        }
+        self.explosion_system = explosion_system;
+
         // 3. Temperature diffusion (30fps throttled) - active chunks only
         {
             #[cfg(feature = "profiling")]
@@ -898,6 +1402,20 @@ impl World {
             );
         }
 
+        // 3.5. Gas exchange (fire burns oxygen, plants make it, air mixes and vents)
+        {
+            #[cfg(feature = "detailed_profiling")]
+            let _span = tracing::info_span!("gas_exchange").entered();
+
+            let generator = &self.persistence_system.generator;
+            self.gas_system.update(
+                &mut self.chunk_manager.chunks,
+                &self.chunk_manager.active_chunks,
+                &self.materials,
+                |x| ColumnClimate::sample(generator, x),
+            );
+        }
+
         // 4. Light propagation (15fps throttled) - active chunks only
         {
             #[cfg(feature = "detailed_profiling")]
@@ -957,21 +1475,41 @@ impl World {
         }
         self.debris_system = debris_system;
 
-        // 9. Resource regeneration (fruit spawning)
+        // 9. Vegetation (growth, spreading, fruit, withering)
+        {
+            #[cfg(feature = "detailed_profiling")]
+            let _span = tracing::info_span!("vegetation").entered();
+
+            let generator = &self.persistence_system.generator;
+            self.vegetation_system.update(
+                &mut self.chunk_manager.chunks,
+                &self.chunk_manager.active_chunks,
+                |x| ColumnClimate::sample(generator, x),
+                rng,
+            );
+        }
+
+        // 9.5. Day/night effects (surface temperature, glowing mushrooms at night)
         {
             #[cfg(feature = "detailed_profiling")]
-            let _span = tracing::info_span!("regeneration").entered();
+            let _span = tracing::info_span!("day_night").entered();
 
-            self.regeneration_system.update(
+            let generator = &self.persistence_system.generator;
+            self.day_night_system.update(
                 &mut self.chunk_manager.chunks,
                 &self.chunk_manager.active_chunks,
-                1.0 / 60.0,
+                |x| ColumnClimate::sample(generator, x),
+                self.light_system.daylight(),
+                rng,
             );
         }
 
         // 10. Update creatures (sensing, planning, neural control)
         // Skip creature updates when connected to multiplayer (server is authoritative)
         if !is_multiplayer_connected {
+            // Hostile creatures come out at night and leave at sunrise
+            self.update_hostile_creatures(1.0 / 60.0, rng);
+
             // Temporarily take creature_manager to avoid borrow checker issues
             let mut creature_manager = std::mem::replace(
                 &mut self.creature_manager,
@@ -1039,6 +1577,11 @@ impl World {
             return;
         }
 
+        // Open relays are passable but hold their position (no gas movement)
+        if pixel.material_id == MaterialId::RELAY_OPEN {
+            return;
+        }
+
         // Special handling for fire
         if pixel.material_id == MaterialId::FIRE {
             self.update_fire(chunk_pos, x, y, stats, rng);
@@ -1190,6 +1733,108 @@ impl World {
         PixelQueries::get_light(&self.light_system, &self.chunk_manager, world_x, world_y)
     }
 
+    /// Current global weather
+    pub fn weather(&self) -> WeatherState {
+        self.weather_system.state()
+    }
+
+    /// Force the weather for `duration` seconds
+    pub fn set_weather(&mut self, state: WeatherState, duration: f32) {
+        self.weather_system.set_state(state, duration);
+    }
+
+    /// Whether the weather system runs (the Powder sandbox turns it off)
+    pub fn weather_enabled(&self) -> bool {
+        self.weather_system.is_enabled()
+    }
+
+    /// Turn weather on or off; disabled weather is clear and calm
+    pub fn set_weather_enabled(&mut self, enabled: bool) {
+        self.weather_system.set_enabled(enabled);
+    }
+
+    /// Whether plants grow, spread and wither (the Powder sandbox turns it off)
+    pub fn vegetation_enabled(&self) -> bool {
+        self.vegetation_system.is_enabled()
+    }
+
+    /// Turn vegetation growth on or off
+    pub fn set_vegetation_enabled(&mut self, enabled: bool) {
+        self.vegetation_system.set_enabled(enabled);
+    }
+
+    /// Get wind velocity at world coordinates (pixels/second, zero underground)
+    pub fn get_wind_at(&self, world_x: i32, world_y: i32) -> Vec2 {
+        self.weather_system
+            .wind_at(world_x, world_y, self.column_climate(world_x))
+    }
+
+    /// Get the material falling from the sky at world coordinates (rain or snow)
+    pub fn get_precipitation_at(&self, world_x: i32, world_y: i32) -> Option<u16> {
+        self.weather_system
+            .precipitation_at(world_y, self.column_climate(world_x))
+    }
+
+    /// Get oxygen level at world coordinates (1.0 = fresh air, coarse 8x8 grid)
+    pub fn get_oxygen_at(&self, world_x: i32, world_y: i32) -> Option<f32> {
+        GasSystem::oxygen_at(&self.chunk_manager.chunks, IVec2::new(world_x, world_y))
+    }
+
+    /// Get carbon dioxide level at world coordinates (coarse 8x8 grid)
+    pub fn get_carbon_dioxide_at(&self, world_x: i32, world_y: i32) -> Option<f32> {
+        let (chunk_pos, local_x, local_y) = ChunkManager::world_to_chunk_coords(world_x, world_y);
+        self.chunk_manager
+            .chunks
+            .get(&chunk_pos)
+            .map(|c| c.carbon_dioxide[c.get_coarse_grid_index(local_x, local_y)])
+    }
+
+    /// Whether the day/night cycle drives temperature, night spawns, creature
+    /// rest and sunlight reactions (off until the persistent world loads)
+    pub fn day_night_enabled(&self) -> bool {
+        self.day_night_system.is_enabled()
+    }
+
+    /// Turn the day/night effects on or off (the sky keeps cycling either way)
+    pub fn set_day_night_enabled(&mut self, enabled: bool) {
+        self.day_night_system.set_enabled(enabled);
+    }
+
+    /// Current day/night effect settings
+    pub fn day_night_settings(&self) -> &DayNightSettings {
+        self.day_night_system.settings()
+    }
+
+    /// Replace the day/night effect settings (from the game config)
+    pub fn set_day_night_settings(&mut self, settings: DayNightSettings) {
+        self.day_night_system.set_settings(settings);
+    }
+
+    /// Sun strength over the day/night cycle (0.0 = midnight, 1.0 = noon)
+    pub fn daylight(&self) -> f32 {
+        self.light_system.daylight()
+    }
+
+    /// Seconds into the day/night cycle (0 = midnight, half the cycle = noon)
+    pub fn time_of_day(&self) -> f32 {
+        self.light_system.day_night_time
+    }
+
+    /// Jump to a point in the day/night cycle (wrapped to the cycle length)
+    pub fn set_time_of_day(&mut self, seconds: f32) {
+        self.light_system.day_night_time = seconds.rem_euclid(DAY_NIGHT_CYCLE_DURATION);
+    }
+
+    /// Whether it is currently night
+    pub fn is_night(&self) -> bool {
+        DayNightSystem::is_night(self.daylight())
+    }
+
+    /// Biome and terrain height of a world column
+    fn column_climate(&self, world_x: i32) -> ColumnClimate {
+        ColumnClimate::sample(&self.persistence_system.generator, world_x)
+    }
+
     /// Get pressure at world coordinates (coarse 8x8 grid)
     pub fn get_pressure_at(&self, world_x: i32, world_y: i32) -> Option<f32> {
         let (chunk_pos, local_x, local_y) = ChunkManager::world_to_chunk_coords(world_x, world_y);
@@ -1408,6 +2053,7 @@ impl World {
     pub fn clear_all_chunks(&mut self) {
         self.persistence_system
             .clear_all_chunks(&mut self.chunk_manager);
+        self.workstation_system.set_stations(Vec::new());
     }
 
     /// Add a chunk to the world
@@ -1418,6 +2064,10 @@ impl World {
 
     /// Initialize persistent world (load or generate)
     pub fn load_persistent_world(&mut self) {
+        // Levels switch weather and the day/night effects off; the persistent world has them
+        self.set_weather_enabled(true);
+        self.set_day_night_enabled(true);
+
         // Load world data (this also loads metadata with play_time_seconds)
         let _ = self
             .persistence_system
@@ -1428,6 +2078,7 @@ impl World {
         if let Ok(persistence) = ChunkPersistence::new("default") {
             let metadata = persistence.load_metadata();
             self.total_play_time_seconds = metadata.play_time_seconds;
+            self.workstation_system.set_stations(metadata.workstations);
             #[cfg(any(feature = "client", not(target_arch = "wasm32")))]
             {
                 self.session_start = Instant::now(); // Reset session start
@@ -1469,6 +2120,7 @@ impl World {
         self.persistence_system.save_all_dirty_chunks(
             &mut self.chunk_manager,
             &self.player,
+            self.workstation_system.stations(),
             total_play_time,
         );
     }
@@ -1556,12 +2208,17 @@ impl World {
         stats: &mut dyn crate::world::SimStats,
         rng: &mut R,
     ) {
+        // Light-dependent reactions only count sunlight reaching the pixel
+        let sunlight_only = self.day_night_system.is_enabled()
+            && self.day_night_system.settings().sunlight_reactions;
         ChemistrySystem::check_pixel_reactions(
             &mut self.chunk_manager.chunks,
             &self.reactions,
+            &self.materials,
             chunk_pos,
             x,
             y,
+            sunlight_only,
             stats,
             rng,
         );
@@ -1635,6 +2292,23 @@ impl sunaba_creature::WorldAccess for World {
         PixelQueries::get_pressure(&self.chunk_manager, x, y)
     }
 
+    fn get_wind_at(&self, x: i32, y: i32) -> glam::Vec2 {
+        World::get_wind_at(self, x, y)
+    }
+
+    fn get_precipitation_at(&self, x: i32, y: i32) -> Option<u16> {
+        World::get_precipitation_at(self, x, y)
+    }
+
+    fn can_breathe_at(&self, x: i32, y: i32) -> bool {
+        World::get_oxygen_at(self, x, y).is_none_or(|oxygen| oxygen >= SUFFOCATION_OXYGEN)
+    }
+
+    fn get_daylight(&self) -> Option<f32> {
+        (self.day_night_system.is_enabled() && self.day_night_system.settings().creature_rest)
+            .then(|| self.daylight())
+    }
+
     fn is_creature_grounded(&self, positions: &[(glam::Vec2, f32)]) -> bool {
         World::is_creature_grounded(self, positions)
     }
@@ -1658,6 +2332,10 @@ impl sunaba_creature::WorldMutAccess for World {
     fn set_pixel_full(&mut self, x: i32, y: i32, pixel: sunaba_simulation::Pixel) {
         World::set_pixel_full(self, x, y, pixel)
     }
+
+    fn consume_oxygen_at(&mut self, x: i32, y: i32, amount: f32) {
+        GasSystem::breathe(&mut self.chunk_manager.chunks, IVec2::new(x, y), amount);
+    }
 }
 
 #[cfg(test)]
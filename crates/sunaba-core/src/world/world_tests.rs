@@ -476,3 +476,159 @@ fn test_update_chunk_settle() {
     // Should still have the chunk
     assert!(world.has_chunk(IVec2::new(0, -1)));
 }
+
+#[test]
+fn test_bucket_scoops_and_pours() {
+    use crate::entity::containers::ContainerType;
+    use crate::entity::inventory::Inventory;
+
+    let mut world = create_test_world();
+    for y in 70..130 {
+        for x in -30..30 {
+            let material = match y {
+                80..=84 if (-10..=10).contains(&x) => MaterialId::WATER,
+                ..=79 => MaterialId::STONE,
+                _ => MaterialId::AIR,
+            };
+            world.set_pixel(x, y, material);
+        }
+    }
+    world.player.inventory = Inventory::new(50);
+    assert!(world.player.inventory.add_container(ContainerType::Bucket));
+    world.player.select_slot(0);
+
+    let capacity = ContainerType::Bucket.capacity();
+    assert_eq!(
+        world.use_selected_container(0, 82),
+        ContainerUse::Scooped {
+            material_id: MaterialId::WATER,
+            amount: capacity
+        }
+    );
+    // The surface row drained first
+    assert_eq!(world.get_pixel(0, 84).unwrap().material_id, MaterialId::AIR);
+    assert_eq!(
+        world.get_pixel(0, 80).unwrap().material_id,
+        MaterialId::WATER
+    );
+
+    // Cooldown blocks the next use, then the bucket pours into the air above
+    assert_eq!(world.use_selected_container(0, 110), ContainerUse::None);
+    world.player.attack_cooldown = 0.0;
+    assert_eq!(
+        world.use_selected_container(0, 110),
+        ContainerUse::Poured {
+            material_id: MaterialId::WATER,
+            amount: capacity
+        }
+    );
+    assert_eq!(
+        world.get_pixel(0, 110).unwrap().material_id,
+        MaterialId::WATER
+    );
+    let bucket = world
+        .player
+        .inventory
+        .get_slot(0)
+        .unwrap()
+        .as_ref()
+        .unwrap();
+    assert_eq!(bucket.contents(), None);
+}
+
+#[test]
+fn test_placed_pixels_take_player_direction() {
+    let mut world = create_test_world();
+    world.set_pixel(5, 100, MaterialId::AIR);
+    world.player.rotate_placement();
+
+    assert_eq!(world.place_material_debug(5, 100, MaterialId::PUMP, 0), 1);
+    let pump = world.get_pixel(5, 100).unwrap();
+    assert_eq!(pump.material_id, MaterialId::PUMP);
+    assert_eq!(pump.direction(), 1);
+}
+
+#[test]
+fn test_shovel_digs_once_per_cooldown() {
+    use crate::entity::inventory::Inventory;
+
+    let mut world = create_test_world();
+    for y in 70..130 {
+        for x in -30..30 {
+            let material = if y < 100 {
+                MaterialId::SAND
+            } else {
+                MaterialId::AIR
+            };
+            world.set_pixel(x, y, material);
+        }
+    }
+    world.player.inventory = Inventory::new(50);
+    world.player.equip_tool(1020); // Wood shovel
+
+    assert!(matches!(
+        world.use_equipped_tool(0, 90, 1.0 / 60.0),
+        ToolUse::Dug(_)
+    ));
+    // Held on the next frames, nothing more is dug until the cooldown runs out
+    assert_eq!(world.use_equipped_tool(0, 80, 1.0 / 60.0), ToolUse::None);
+    assert_eq!(
+        world.get_pixel(0, 80).unwrap().material_id,
+        MaterialId::SAND
+    );
+    world.player.attack_cooldown = 0.0;
+    assert!(matches!(
+        world.use_equipped_tool(0, 80, 1.0 / 60.0),
+        ToolUse::Dug(_)
+    ));
+}
+
+#[test]
+fn test_mining_workstation_removes_whole_footprint() {
+    use crate::entity::inventory::Inventory;
+
+    let mut world = create_test_world();
+    for y in 100..110 {
+        for x in 0..10 {
+            world.set_pixel(x, y, MaterialId::AIR);
+        }
+    }
+    world.player.inventory = Inventory::new(50);
+    world.player.inventory.add_item(MaterialId::FURNACE, 1);
+    assert!(world.place_workstation(WorkstationType::Furnace, IVec2::new(2, 100)));
+    assert_eq!(world.player.inventory.count_item(MaterialId::FURNACE), 0);
+
+    assert!(world.mine_pixel(4, 103));
+    assert!(world.workstations().is_empty());
+    assert_eq!(world.player.inventory.count_item(MaterialId::FURNACE), 1);
+    for y in 100..106 {
+        for x in 2..8 {
+            assert_eq!(world.get_pixel(x, y).unwrap().material_id, MaterialId::AIR);
+        }
+    }
+}
+
+#[test]
+fn test_hostiles_need_day_night_enabled() {
+    use rand::SeedableRng;
+    let mut rng = rand_xoshiro::Xoshiro256StarStar::seed_from_u64(7);
+    let mut world = create_test_world();
+    world.ensure_chunks_for_area(-256, -64, 256, 128);
+    for x in -256..256 {
+        world.set_pixel(x, 0, MaterialId::STONE);
+    }
+    world.set_time_of_day(0.0); // Midnight
+    let interval = world.day_night_settings().hostile_spawn_interval;
+    let hostiles = |world: &World| world.creature_manager.iter().filter(|c| c.hostile).count();
+
+    // Off by default (levels and the Powder sandbox never see spiders)
+    world.update_hostile_creatures(interval + 1.0, &mut rng);
+    assert_eq!(hostiles(&world), 0);
+
+    // Enabled, a spider drops in above the loaded ground
+    world.set_day_night_enabled(true);
+    world.update_hostile_creatures(interval + 1.0, &mut rng);
+    assert_eq!(hostiles(&world), 1);
+    let spider = world.creature_manager.iter().find(|c| c.hostile).unwrap();
+    assert_eq!(spider.position.y, HOSTILE_SPAWN_HEIGHT as f32);
+}
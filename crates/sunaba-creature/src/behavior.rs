@@ -8,6 +8,22 @@ use std::collections::VecDeque;
 
 use super::sensors::SensoryInput;
 
+/// Energy lost per second while awake in daylight
+const DAY_FATIGUE_RATE: f32 = 0.002;
+
+/// Extra energy lost per second while awake in full darkness
+const NIGHT_FATIGUE_RATE: f32 = 0.02;
+
+/// Energy regained per second while resting at noon (a quarter of it at midnight,
+/// so creatures that tire at night keep resting until morning)
+const REST_RECOVERY_RATE: f32 = 0.04;
+
+/// How long a tired creature rests before re-planning (seconds)
+const REST_DURATION: f32 = 10.0;
+
+/// Wind speed (pixels/second) strong enough to keep creatures from wandering
+const SHELTER_WIND_SPEED: f32 = 20.0;
+
 /// High-level needs
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreatureNeeds {
@@ -42,6 +58,17 @@ impl CreatureNeeds {
         };
     }
 
+    /// Update energy from the circadian rhythm (daylight: 0.0 = midnight, 1.0 = noon)
+    pub fn update_energy(&mut self, delta_time: f32, daylight: f32, resting: bool) {
+        let daylight = daylight.clamp(0.0, 1.0);
+        let change = if resting {
+            REST_RECOVERY_RATE * (0.25 + 0.75 * daylight)
+        } else {
+            -(DAY_FATIGUE_RATE + NIGHT_FATIGUE_RATE * (1.0 - daylight))
+        };
+        self.energy = (self.energy + change * delta_time).clamp(0.0, 1.0);
+    }
+
     /// Get most urgent need
     pub fn most_urgent(&self) -> NeedType {
         if self.threat_level > 0.5 {
@@ -205,6 +232,13 @@ impl GoalPlanner {
                 self.action_plan
                     .push_back(CreatureAction::Wander { duration: 5.0 });
             }
+        } else if self.current_goal.contains(&WorldProperty::HasEnergy)
+            || Self::is_bad_weather(sensory)
+        {
+            // Tired creatures rest, and so do idle ones caught in rain, snow or gales
+            self.action_plan.push_back(CreatureAction::Rest {
+                duration: REST_DURATION,
+            });
         } else {
             // Default: wander
             self.action_plan
@@ -212,6 +246,11 @@ impl GoalPlanner {
         }
     }
 
+    /// Whether the weather is bad enough to wait out instead of wandering
+    fn is_bad_weather(sensory: &SensoryInput) -> bool {
+        sensory.precipitation.is_some() || sensory.wind.length() >= SHELTER_WIND_SPEED
+    }
+
     /// Get next action to execute
     pub fn next_action(&mut self) -> Option<CreatureAction> {
         self.action_plan.pop_front()
@@ -279,6 +318,8 @@ mod tests {
             nearest_threat: Some(Vec2::new(5.0, 5.0)),
             food_direction: Some(Vec2::new(1.0, 0.0)),
             food_distance: 0.2,
+            wind: Vec2::ZERO,
+            precipitation: None,
         };
 
         needs.update(&sensory, 0.6);
@@ -344,6 +385,8 @@ mod tests {
             nearest_threat: None,
             food_direction: Some(Vec2::new(1.0, 0.0)),
             food_distance: 0.2,
+            wind: Vec2::ZERO,
+            precipitation: None,
         };
 
         planner.evaluate_world_state(&sensory, 0.2);
@@ -370,6 +413,8 @@ mod tests {
             nearest_threat: Some(Vec2::new(5.0, 5.0)),
             food_direction: None,
             food_distance: 1.0,
+            wind: Vec2::ZERO,
+            precipitation: None,
         };
 
         planner.plan(&sensory, Vec2::ZERO);
@@ -395,6 +440,8 @@ mod tests {
             nearest_threat: None,
             food_direction: Some(Vec2::new(1.0, 0.0)),
             food_distance: 0.2,
+            wind: Vec2::ZERO,
+            precipitation: None,
         };
 
         planner.plan(&sensory, Vec2::ZERO);
@@ -424,6 +471,8 @@ mod tests {
             nearest_threat: None,
             food_direction: None,
             food_distance: 1.0,
+            wind: Vec2::ZERO,
+            precipitation: None,
         };
 
         planner.plan(&sensory, Vec2::ZERO);
@@ -432,6 +481,105 @@ mod tests {
         assert!(matches!(action, Some(CreatureAction::Wander { .. })));
     }
 
+    #[test]
+    fn test_energy_follows_day_night_cycle() {
+        let mut needs = CreatureNeeds::new();
+
+        // Awake through a night: tired within a minute
+        for _ in 0..60 {
+            needs.update_energy(1.0, 0.0, false);
+        }
+        assert_eq!(needs.most_urgent(), NeedType::Energy);
+
+        // Awake during the day: barely tires
+        let mut day_needs = CreatureNeeds::new();
+        for _ in 0..60 {
+            day_needs.update_energy(1.0, 1.0, false);
+        }
+        assert!(day_needs.energy > 0.8);
+
+        // Resting recovers, faster once the sun is up
+        let tired = needs.energy;
+        needs.update_energy(1.0, 0.0, true);
+        let night_gain = needs.energy - tired;
+        needs.update_energy(1.0, 1.0, true);
+        let day_gain = needs.energy - tired - night_gain;
+        assert!(night_gain > 0.0);
+        assert!(day_gain > night_gain);
+    }
+
+    #[test]
+    fn test_planner_rests_when_tired() {
+        let mut planner = GoalPlanner::new();
+        let mut needs = CreatureNeeds::new();
+        needs.energy = 0.1;
+        planner.update_goal(&needs);
+        assert_eq!(planner.current_goal, vec![WorldProperty::HasEnergy]);
+
+        let sensory = SensoryInput {
+            raycasts: vec![],
+            contact_materials: vec![],
+            gradients: ChemicalGradient {
+                food: 0.0,
+                danger: 0.0,
+                mate: 0.0,
+            },
+            nearest_food: None,
+            nearest_threat: None,
+            food_direction: None,
+            food_distance: 1.0,
+            wind: Vec2::ZERO,
+            precipitation: None,
+        };
+
+        planner.plan(&sensory, Vec2::ZERO);
+
+        let action = planner.next_action();
+        assert!(matches!(action, Some(CreatureAction::Rest { .. })));
+    }
+
+    #[test]
+    fn test_planner_shelters_from_weather() {
+        let mut planner = GoalPlanner::new();
+        planner.update_goal(&CreatureNeeds::new());
+        let mut sensory = SensoryInput {
+            raycasts: vec![],
+            contact_materials: vec![],
+            gradients: ChemicalGradient {
+                food: 0.0,
+                danger: 0.0,
+                mate: 0.0,
+            },
+            nearest_food: None,
+            nearest_threat: None,
+            food_direction: None,
+            food_distance: 1.0,
+            wind: Vec2::new(5.0, 0.0),
+            precipitation: None,
+        };
+
+        planner.plan(&sensory, Vec2::ZERO);
+        assert!(matches!(
+            planner.next_action(),
+            Some(CreatureAction::Wander { .. })
+        ));
+
+        sensory.precipitation = Some(sunaba_simulation::MaterialId::WATER);
+        planner.plan(&sensory, Vec2::ZERO);
+        assert!(matches!(
+            planner.next_action(),
+            Some(CreatureAction::Rest { .. })
+        ));
+
+        sensory.precipitation = None;
+        sensory.wind = Vec2::new(-SHELTER_WIND_SPEED, 0.0);
+        planner.plan(&sensory, Vec2::ZERO);
+        assert!(matches!(
+            planner.next_action(),
+            Some(CreatureAction::Rest { .. })
+        ));
+    }
+
     #[test]
     fn test_plan_validation() {
         let mut planner = GoalPlanner::new();
@@ -452,6 +600,8 @@ mod tests {
             nearest_threat: None,
             food_direction: None,
             food_distance: 1.0,
+            wind: Vec2::ZERO,
+            precipitation: None,
         };
         assert!(planner.is_plan_valid(&sensory_safe));
 
@@ -468,6 +618,8 @@ mod tests {
             nearest_threat: Some(Vec2::new(5.0, 5.0)),
             food_direction: None,
             food_distance: 1.0,
+            wind: Vec2::ZERO,
+            precipitation: None,
         };
         assert!(!planner.is_plan_valid(&sensory_danger));
     }
@@ -14,6 +14,9 @@ use super::neural::DeepNeuralController;
 use super::sensors::{SensorConfig, SensoryInput};
 use super::simple_physics::CreaturePhysicsState;
 
+/// Health lost per second in air without oxygen
+const SUFFOCATION_DAMAGE: f32 = 10.0;
+
 /// Main creature entity
 #[derive(Serialize, Deserialize)]
 pub struct Creature {
@@ -48,6 +51,10 @@ pub struct Creature {
     /// Counter for blocks mined (for fitness evaluation)
     pub blocks_mined: u32,
 
+    /// Night creature that hurts the player on contact and leaves at sunrise
+    #[serde(default)]
+    pub hostile: bool,
+
     // Movement state (not serialized - runtime only)
     #[serde(skip)]
     pub velocity: Vec2,
@@ -107,6 +114,7 @@ impl Creature {
             generation: 0,
             food_eaten: 0,
             blocks_mined: 0,
+            hostile: false,
             velocity: Vec2::ZERO,
             wander_target: None,
             wander_timer: 0.0,
@@ -168,6 +176,7 @@ impl Creature {
             generation: 0,
             food_eaten: 0,
             blocks_mined: 0,
+            hostile: false,
             velocity: Vec2::ZERO,
             wander_target: None,
             wander_timer: 0.0,
@@ -194,8 +203,27 @@ impl Creature {
             self.health.take_damage(5.0 * delta_time);
         }
 
-        // 2. Update needs from sensory input
+        // Breathe (suffocates in air without oxygen)
+        let (breath_x, breath_y) = (
+            self.position.x.round() as i32,
+            self.position.y.round() as i32,
+        );
+        if world.can_breathe_at(breath_x, breath_y) {
+            world.consume_oxygen_at(
+                breath_x,
+                breath_y,
+                self.genome.metabolic.oxygen_requirement * delta_time,
+            );
+        } else {
+            self.health.take_damage(SUFFOCATION_DAMAGE * delta_time);
+        }
+
+        // 2. Update needs from sensory input (and tire with the day/night cycle)
         self.needs.update(sensory_input, self.hunger.percentage());
+        if let Some(daylight) = world.get_daylight() {
+            self.needs
+                .update_energy(delta_time, daylight, self.is_resting());
+        }
 
         // 3. Update behavior planning
         if let Some(ref mut planner) = self.planner {
@@ -220,8 +248,10 @@ impl Creature {
             self.action_timer -= delta_time;
         }
 
-        // 4. Neural control - run brain and get motor commands
-        self.run_neural_control(delta_time, sensory_input, world);
+        // 4. Neural control - run brain and get motor commands (resting creatures lie still)
+        if !self.is_resting() {
+            self.run_neural_control(delta_time, sensory_input, world);
+        }
 
         // 5. Auto-eating - proximity-based food consumption
         const AUTO_EAT_RADIUS: f32 = 8.0;
@@ -263,6 +293,11 @@ impl Creature {
         self.health.is_dead()
     }
 
+    /// Whether the creature is currently resting
+    pub fn is_resting(&self) -> bool {
+        matches!(self.current_action, Some(CreatureAction::Rest { .. }))
+    }
+
     /// Run neural controller and return motor commands
     fn run_neural_control(
         &mut self,
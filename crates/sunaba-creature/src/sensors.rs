@@ -50,6 +50,10 @@ pub struct SensoryInput {
     pub food_direction: Option<Vec2>,
     /// Distance to nearest food (normalized 0-1 based on compass_radius)
     pub food_distance: f32,
+    /// Wind at the creature (pixels/second, zero when sheltered)
+    pub wind: Vec2,
+    /// Rain or snow falling on the creature
+    pub precipitation: Option<u16>,
 }
 
 impl SensoryInput {
@@ -83,6 +87,8 @@ impl SensoryInput {
         // For now, contact materials is empty (would need physics integration)
         let contact_materials = Vec::new();
 
+        let (wind, precipitation) = sense_weather(world, position);
+
         Self {
             raycasts,
             contact_materials,
@@ -91,6 +97,8 @@ impl SensoryInput {
             nearest_threat,
             food_direction,
             food_distance,
+            wind,
+            precipitation,
         }
     }
 
@@ -137,6 +145,8 @@ impl SensoryInput {
 
         let contact_materials = Vec::new();
 
+        let (wind, precipitation) = sense_weather(world, position);
+
         Self {
             raycasts,
             contact_materials,
@@ -145,6 +155,8 @@ impl SensoryInput {
             nearest_threat,
             food_direction,
             food_distance,
+            wind,
+            precipitation,
         }
     }
 }
@@ -534,6 +546,14 @@ pub fn calculate_gradients(
     }
 }
 
+/// Sense the weather at a position
+/// Returns (wind velocity, falling material); worlds without weather are calm and dry
+pub fn sense_weather(world: &impl crate::WorldAccess, position: Vec2) -> (Vec2, Option<u16>) {
+    let x = position.x.round() as i32;
+    let y = position.y.round() as i32;
+    (world.get_wind_at(x, y), world.get_precipitation_at(x, y))
+}
+
 /// Helper function to find ground below a position
 /// Returns the Y coordinate of the first solid pixel, or None if no ground found
 fn find_ground_below(
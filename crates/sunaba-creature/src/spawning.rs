@@ -323,6 +323,16 @@ impl CreatureManager {
     pub fn get_mut(&mut self, id: EntityId) -> Option<&mut Creature> {
         self.creatures.get_mut(&id)
     }
+
+    /// Iterate over all living creatures (unordered)
+    pub fn iter(&self) -> impl Iterator<Item = &Creature> {
+        self.creatures.values()
+    }
+
+    /// Iterate mutably over all living creatures (unordered)
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Creature> {
+        self.creatures.values_mut()
+    }
 }
 
 #[cfg(test)]
@@ -33,6 +33,30 @@ pub trait WorldAccess {
     /// Get pressure at world coordinates
     fn get_pressure_at(&self, x: i32, y: i32) -> f32;
 
+    /// Get wind velocity at world coordinates (pixels/second)
+    /// Worlds without weather are calm
+    fn get_wind_at(&self, _x: i32, _y: i32) -> Vec2 {
+        Vec2::ZERO
+    }
+
+    /// Get the material falling from the sky at world coordinates (rain or snow)
+    /// Worlds without weather stay dry
+    fn get_precipitation_at(&self, _x: i32, _y: i32) -> Option<u16> {
+        None
+    }
+
+    /// Check if the air at world coordinates holds enough oxygen to breathe
+    /// Worlds without gas simulation are always breathable
+    fn can_breathe_at(&self, _x: i32, _y: i32) -> bool {
+        true
+    }
+
+    /// Get the sun strength (0.0 = midnight, 1.0 = noon) creatures keep their rhythm by
+    /// Worlds without a day/night cycle return None and creatures never tire
+    fn get_daylight(&self) -> Option<f32> {
+        None
+    }
+
     /// Check if creature is grounded at given body part positions
     /// positions contains (center, radius) for each body part
     fn is_creature_grounded(&self, positions: &[(Vec2, f32)]) -> bool;
@@ -55,4 +79,8 @@ pub trait WorldMutAccess: WorldAccess {
 
     /// Set full pixel at world coordinates (with flags)
     fn set_pixel_full(&mut self, x: i32, y: i32, pixel: Pixel);
+
+    /// Breathe `amount` units of oxygen from the air at world coordinates
+    /// Worlds without gas simulation ignore breathing
+    fn consume_oxygen_at(&mut self, _x: i32, _y: i32, _amount: f32) {}
 }
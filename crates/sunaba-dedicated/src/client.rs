@@ -0,0 +1,231 @@
+//! Blocking client for the dedicated server
+//!
+//! Messages from the server are read on a background thread and queued, so
+//! a game loop can `poll` once per frame without blocking.
+
+use anyhow::{Context, Result, anyhow, bail};
+use glam::IVec2;
+use std::io::BufReader;
+use std::net::{Shutdown, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+use sunaba_core::world::{CHUNK_SIZE, World};
+
+use crate::protocol::{self, ClientMessage, PROTOCOL_VERSION, PlayerId, ServerMessage};
+
+/// How long `connect` waits for the server to accept the join
+const JOIN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Connection to a dedicated server
+pub struct DedicatedClient {
+    stream: TcpStream,
+    incoming: Receiver<ServerMessage>,
+    player_id: PlayerId,
+    seed: u64,
+    spawn: (f32, f32),
+    view_radius: i32,
+}
+
+impl DedicatedClient {
+    /// Connect and join under `name`; fails if the server rejects the join
+    pub fn connect(addr: impl ToSocketAddrs, name: &str) -> Result<Self> {
+        let mut stream = TcpStream::connect(addr).context("Failed to connect to server")?;
+        stream.set_nodelay(true)?;
+
+        let reader = stream.try_clone()?;
+        let (tx, incoming) = mpsc::channel();
+        thread::Builder::new()
+            .name("dedicated-client-read".to_string())
+            .spawn(move || {
+                let mut reader = BufReader::new(reader);
+                while let Ok(message) = protocol::read_message(&mut reader) {
+                    if tx.send(message).is_err() {
+                        break;
+                    }
+                }
+            })
+            .context("Failed to spawn client reader thread")?;
+
+        protocol::write_message(
+            &mut stream,
+            &ClientMessage::Join {
+                name: name.to_string(),
+                protocol_version: PROTOCOL_VERSION,
+            },
+        )?;
+
+        match incoming.recv_timeout(JOIN_TIMEOUT) {
+            Ok(ServerMessage::Welcome {
+                player_id,
+                seed,
+                spawn_x,
+                spawn_y,
+                view_radius,
+                ..
+            }) => Ok(Self {
+                stream,
+                incoming,
+                player_id,
+                seed,
+                spawn: (spawn_x, spawn_y),
+                view_radius,
+            }),
+            Ok(ServerMessage::Rejected { reason }) => bail!("Join rejected: {}", reason),
+            Ok(other) => Err(anyhow!("Unexpected message before welcome: {:?}", other)),
+            Err(RecvTimeoutError::Timeout) => bail!("Timeout waiting for server to accept join"),
+            Err(RecvTimeoutError::Disconnected) => bail!("Server closed the connection"),
+        }
+    }
+
+    /// Id the server assigned to this player
+    pub fn player_id(&self) -> PlayerId {
+        self.player_id
+    }
+
+    /// World generation seed of the server world
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Spawn point of the server world
+    pub fn spawn(&self) -> (f32, f32) {
+        self.spawn
+    }
+
+    /// Chunks the server streams around this player
+    pub fn view_radius(&self) -> i32 {
+        self.view_radius
+    }
+
+    /// Send a raw protocol message
+    pub fn send(&mut self, message: &ClientMessage) -> Result<()> {
+        protocol::write_message(&mut self.stream, message).context("Failed to send message")
+    }
+
+    /// Report the local player's position and velocity
+    pub fn update_position(&mut self, x: f32, y: f32, vel_x: f32, vel_y: f32) -> Result<()> {
+        self.send(&ClientMessage::UpdatePosition { x, y, vel_x, vel_y })
+    }
+
+    /// Place a material at world coordinates
+    pub fn place_material(&mut self, x: i32, y: i32, material_id: u16) -> Result<()> {
+        self.send(&ClientMessage::PlaceMaterial { x, y, material_id })
+    }
+
+    /// Mine the pixel at world coordinates
+    pub fn mine(&mut self, x: i32, y: i32) -> Result<()> {
+        self.send(&ClientMessage::Mine { x, y })
+    }
+
+    /// Change display name
+    pub fn set_name(&mut self, name: &str) -> Result<()> {
+        self.send(&ClientMessage::SetName {
+            name: name.to_string(),
+        })
+    }
+
+    /// Return to the spawn point
+    pub fn respawn(&mut self) -> Result<()> {
+        self.send(&ClientMessage::Respawn)
+    }
+
+    /// Send a latency probe (answered with `ServerMessage::Pong`)
+    pub fn ping(&mut self, timestamp_ms: u64) -> Result<()> {
+        self.send(&ClientMessage::Ping { timestamp_ms })
+    }
+
+    /// Leave gracefully and close the connection
+    pub fn leave(mut self) -> Result<()> {
+        self.send(&ClientMessage::Leave)
+    }
+
+    /// All messages received since the last poll (non-blocking)
+    pub fn poll(&self) -> Vec<ServerMessage> {
+        self.incoming.try_iter().collect()
+    }
+
+    /// Wait for the next message
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<ServerMessage> {
+        self.incoming.recv_timeout(timeout).ok()
+    }
+
+    /// Wait until a message matching `predicate` arrives, discarding others
+    pub fn wait_for(
+        &self,
+        timeout: Duration,
+        mut predicate: impl FnMut(&ServerMessage) -> bool,
+    ) -> Option<ServerMessage> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.checked_duration_since(Instant::now())?;
+            let message = self.incoming.recv_timeout(remaining).ok()?;
+            if predicate(&message) {
+                return Some(message);
+            }
+        }
+    }
+}
+
+impl Drop for DedicatedClient {
+    fn drop(&mut self) {
+        // Closes the socket for the reader thread's clone too, so both sides see EOF
+        let _ = self.stream.shutdown(Shutdown::Both);
+    }
+}
+
+/// Mirror a server message into a local world (chunk data, changed regions and unloads)
+/// Returns true if the world changed
+pub fn apply_to_world(world: &mut World, message: &ServerMessage) -> bool {
+    match message {
+        ServerMessage::Chunk { x, y, data } => match protocol::decode_chunk(data) {
+            Ok(chunk) => {
+                world.insert_chunk(IVec2::new(*x, *y), chunk);
+                true
+            }
+            Err(e) => {
+                log::warn!("Failed to decode chunk ({}, {}): {}", x, y, e);
+                false
+            }
+        },
+        ServerMessage::ChunkRegion {
+            x,
+            y,
+            min_x,
+            min_y,
+            width,
+            height,
+            data,
+        } => {
+            let (min_x, min_y) = (*min_x as usize, *min_y as usize);
+            let (width, height) = (*width as usize, *height as usize);
+            if min_x + width > CHUNK_SIZE || min_y + height > CHUNK_SIZE {
+                log::warn!("Chunk region outside chunk ({}, {})", x, y);
+                return false;
+            }
+            let Some(chunk) = world.chunks_mut().get_mut(&IVec2::new(*x, *y)) else {
+                return false;
+            };
+            match protocol::decode_region(data) {
+                Ok(pixels) if pixels.len() == width * height => {
+                    for (i, pixel) in pixels.into_iter().enumerate() {
+                        chunk.set_pixel(min_x + i % width, min_y + i / width, pixel);
+                    }
+                    true
+                }
+                Ok(_) => {
+                    log::warn!("Chunk region size mismatch in ({}, {})", x, y);
+                    false
+                }
+                Err(e) => {
+                    log::warn!("Failed to decode chunk region ({}, {}): {}", x, y, e);
+                    false
+                }
+            }
+        }
+        ServerMessage::ChunkUnloaded { x, y } => {
+            world.chunks_mut().remove(&IVec2::new(*x, *y)).is_some()
+        }
+        _ => false,
+    }
+}
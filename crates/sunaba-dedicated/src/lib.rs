@@ -0,0 +1,13 @@
+//! # Sunaba Dedicated Server
+//!
+//! Standalone multiplayer host over TCP (native clients) or WebSocket (browser
+//! clients), for running a shared world without SpacetimeDB. The server owns the authoritative `World`, streams
+//! chunks around each player and applies their edits; clients report their
+//! own movement.
+
+pub mod client;
+pub mod protocol;
+pub mod server;
+
+pub use client::{DedicatedClient, apply_to_world};
+pub use server::{DedicatedServer, ServerConfig};
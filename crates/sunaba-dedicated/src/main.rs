@@ -0,0 +1,83 @@
+//! Sunaba dedicated server - hosts a shared world over TCP or WebSocket
+
+use clap::Parser;
+use std::io::BufRead;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use sunaba_dedicated::{DedicatedServer, ServerConfig, protocol};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Address to listen on
+    #[arg(long, default_value_t = format!("0.0.0.0:{}", protocol::DEFAULT_PORT))]
+    bind: String,
+
+    /// Also accept WebSocket clients (browsers) on this address
+    #[arg(long)]
+    ws_bind: Option<String>,
+
+    /// World name (saved under worlds/<name>)
+    #[arg(long, default_value = "server")]
+    world: String,
+
+    /// Keep the world in memory only (nothing is saved)
+    #[arg(long)]
+    in_memory: bool,
+
+    /// Seed for a new world (ignored for existing worlds)
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Chunks streamed around each player
+    #[arg(long, default_value = "4")]
+    view_radius: i32,
+
+    /// Simulation ticks per second
+    #[arg(long, default_value = "60")]
+    tick_rate: u32,
+
+    /// Maximum number of players
+    #[arg(long, default_value = "16")]
+    max_players: usize,
+}
+
+fn main() -> anyhow::Result<()> {
+    // Initialize logging
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    let args = Args::parse();
+    let config = ServerConfig {
+        bind_addr: args.bind,
+        ws_bind_addr: args.ws_bind,
+        world_name: (!args.in_memory).then_some(args.world),
+        seed: args.seed,
+        view_radius: args.view_radius.max(1),
+        tick_rate: args.tick_rate.max(1),
+        max_players: args.max_players,
+        ..Default::default()
+    };
+    let mut server = DedicatedServer::bind(config)?;
+
+    // Console: "stop" saves the world and exits
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let console_shutdown = shutdown.clone();
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lock().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            match line.trim() {
+                "stop" | "quit" | "exit" => {
+                    console_shutdown.store(true, Ordering::Relaxed);
+                    break;
+                }
+                "" => {}
+                other => log::warn!("Unknown command '{}' (try 'stop')", other),
+            }
+        }
+    });
+
+    log::info!("Type 'stop' to save and shut down");
+    server.run(&shutdown)
+}
@@ -0,0 +1,260 @@
+//! Wire protocol between dedicated server and clients
+//!
+//! Every message is the bincode encoding of a `ClientMessage` or
+//! `ServerMessage`. Over TCP each one is framed by a little-endian `u32`
+//! payload length; over WebSocket each one is a single binary message. Chunk
+//! payloads are additionally lz4-compressed.
+
+use bincode_next as bincode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Read, Write};
+use sunaba_core::world::{Chunk, DirtyRect, Pixel};
+
+/// Bumped whenever a message changes shape; mismatched clients are rejected
+pub const PROTOCOL_VERSION: u32 = 2;
+
+/// Default TCP port (the remote-control server uses 7453)
+pub const DEFAULT_PORT: u16 = 7454;
+
+/// Largest accepted frame (a full chunk compresses far below this)
+pub const MAX_FRAME_BYTES: usize = 4 * 1024 * 1024;
+
+/// Longest accepted player name (in characters)
+pub const MAX_NAME_LEN: usize = 32;
+
+/// Server-assigned id of a connected player
+pub type PlayerId = u64;
+
+/// Message sent from client to server
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ClientMessage {
+    /// First message on every connection
+    Join { name: String, protocol_version: u32 },
+    /// Client-authoritative movement (same model as the SpacetimeDB server)
+    UpdatePosition {
+        x: f32,
+        y: f32,
+        vel_x: f32,
+        vel_y: f32,
+    },
+    /// Place a material at world coordinates
+    PlaceMaterial { x: i32, y: i32, material_id: u16 },
+    /// Mine the pixel at world coordinates
+    Mine { x: i32, y: i32 },
+    /// Change display name
+    SetName { name: String },
+    /// Return to the spawn point
+    Respawn,
+    /// Latency probe, answered with `Pong`
+    Ping { timestamp_ms: u64 },
+    /// Graceful disconnect
+    Leave,
+}
+
+/// Message sent from server to client
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ServerMessage {
+    /// Join accepted
+    Welcome {
+        player_id: PlayerId,
+        seed: u64,
+        spawn_x: f32,
+        spawn_y: f32,
+        tick: u64,
+        /// Chunks streamed around the player (in chunks from the player's chunk)
+        view_radius: i32,
+    },
+    /// Join refused (version mismatch, server full); the connection is closed
+    Rejected { reason: String },
+    /// Full chunk state when the chunk comes into view, see `encode_chunk`
+    Chunk { x: i32, y: i32, data: Vec<u8> },
+    /// Pixels changed inside a chunk the client already holds: the rectangle
+    /// at local (`min_x`, `min_y`), row by row, see `encode_region`
+    ChunkRegion {
+        x: i32,
+        y: i32,
+        min_x: u16,
+        min_y: u16,
+        width: u16,
+        height: u16,
+        data: Vec<u8>,
+    },
+    /// Chunk left the player's view radius
+    ChunkUnloaded { x: i32, y: i32 },
+    /// Another player joined
+    PlayerJoined(PlayerState),
+    /// Another player left
+    PlayerLeft { player_id: PlayerId },
+    /// Positions of all players
+    Players {
+        tick: u64,
+        players: Vec<PlayerState>,
+    },
+    /// Answer to `Ping`
+    Pong { timestamp_ms: u64, tick: u64 },
+    /// An action was refused (not joined, out of reach, invalid material)
+    ActionRejected { reason: String },
+}
+
+/// Public state of one player
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerState {
+    pub player_id: PlayerId,
+    pub name: String,
+    pub x: f32,
+    pub y: f32,
+    pub vel_x: f32,
+    pub vel_y: f32,
+}
+
+/// Encode one message without framing (a WebSocket message carries exactly one)
+pub fn encode_message<M: Serialize>(message: &M) -> io::Result<Vec<u8>> {
+    let payload = bincode::serde::encode_to_vec(message, bincode::config::standard())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    if payload.len() > MAX_FRAME_BYTES {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Frame too large: {} bytes", payload.len()),
+        ));
+    }
+    Ok(payload)
+}
+
+/// Decode one unframed message
+pub fn decode_message<M: DeserializeOwned>(payload: &[u8]) -> io::Result<M> {
+    let (message, _) = bincode::serde::decode_from_slice(payload, bincode::config::standard())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    Ok(message)
+}
+
+/// Write one length-prefixed frame
+pub fn write_message<W: Write, M: Serialize>(writer: &mut W, message: &M) -> io::Result<()> {
+    let payload = encode_message(message)?;
+    writer.write_all(&(payload.len() as u32).to_le_bytes())?;
+    writer.write_all(&payload)?;
+    writer.flush()
+}
+
+/// Read one length-prefixed frame (blocks until complete)
+pub fn read_message<R: Read, M: DeserializeOwned>(reader: &mut R) -> io::Result<M> {
+    let mut len = [0u8; 4];
+    reader.read_exact(&mut len)?;
+    let len = u32::from_le_bytes(len) as usize;
+    if len > MAX_FRAME_BYTES {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Frame too large: {} bytes", len),
+        ));
+    }
+
+    let mut payload = vec![0u8; len];
+    reader.read_exact(&mut payload)?;
+    decode_message(&payload)
+}
+
+/// Encode full chunk (bincode + lz4) for `ServerMessage::Chunk`
+pub fn encode_chunk(chunk: &Chunk) -> Result<Vec<u8>, String> {
+    let serialized = bincode::serde::encode_to_vec(chunk, bincode::config::standard())
+        .map_err(|e| format!("Failed to encode chunk: {}", e))?;
+    Ok(lz4_flex::compress_prepend_size(&serialized))
+}
+
+/// Decode a chunk payload from `ServerMessage::Chunk`
+pub fn decode_chunk(data: &[u8]) -> Result<Chunk, String> {
+    let serialized = lz4_flex::decompress_size_prepended(data)
+        .map_err(|e| format!("Failed to decompress chunk: {}", e))?;
+    let (chunk, _): (Chunk, _) =
+        bincode::serde::decode_from_slice(&serialized, bincode::config::standard())
+            .map_err(|e| format!("Failed to decode chunk: {}", e))?;
+    Ok(chunk)
+}
+
+/// Encode the pixels of `rect` (bincode + lz4) for `ServerMessage::ChunkRegion`
+pub fn encode_region(chunk: &Chunk, rect: &DirtyRect) -> Result<Vec<u8>, String> {
+    let pixels: Vec<Pixel> = (rect.min_y..=rect.max_y)
+        .flat_map(|y| (rect.min_x..=rect.max_x).map(move |x| chunk.get_pixel(x, y)))
+        .collect();
+    let serialized = bincode::serde::encode_to_vec(&pixels, bincode::config::standard())
+        .map_err(|e| format!("Failed to encode chunk region: {}", e))?;
+    Ok(lz4_flex::compress_prepend_size(&serialized))
+}
+
+/// Decode the pixels of a `ServerMessage::ChunkRegion` payload
+pub fn decode_region(data: &[u8]) -> Result<Vec<Pixel>, String> {
+    let serialized = lz4_flex::decompress_size_prepended(data)
+        .map_err(|e| format!("Failed to decompress chunk region: {}", e))?;
+    let (pixels, _): (Vec<Pixel>, _) =
+        bincode::serde::decode_from_slice(&serialized, bincode::config::standard())
+            .map_err(|e| format!("Failed to decode chunk region: {}", e))?;
+    Ok(pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sunaba_simulation::MaterialId;
+
+    #[test]
+    fn test_frames_roundtrip() {
+        let mut buffer = Vec::new();
+        let join = ClientMessage::Join {
+            name: "alice".to_string(),
+            protocol_version: PROTOCOL_VERSION,
+        };
+        write_message(&mut buffer, &join).unwrap();
+        write_message(&mut buffer, &ClientMessage::Mine { x: -3, y: 7 }).unwrap();
+
+        let mut reader = buffer.as_slice();
+        assert_eq!(read_message::<_, ClientMessage>(&mut reader).unwrap(), join);
+        assert_eq!(
+            read_message::<_, ClientMessage>(&mut reader).unwrap(),
+            ClientMessage::Mine { x: -3, y: 7 }
+        );
+        assert!(read_message::<_, ClientMessage>(&mut reader).is_err());
+    }
+
+    #[test]
+    fn test_oversized_frame_rejected() {
+        let mut buffer = ((MAX_FRAME_BYTES + 1) as u32).to_le_bytes().to_vec();
+        buffer.extend_from_slice(&[0; 16]);
+        let err = read_message::<_, ServerMessage>(&mut buffer.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_chunk_roundtrip() {
+        let mut chunk = Chunk::new(2, -1);
+        chunk.set_material(5, 9, MaterialId::STONE);
+
+        let data = encode_chunk(&chunk).unwrap();
+        let decoded = decode_chunk(&data).unwrap();
+
+        assert_eq!((decoded.x, decoded.y), (2, -1));
+        assert_eq!(decoded.get_material(5, 9), MaterialId::STONE);
+        assert!(decode_chunk(&data[..data.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn test_region_roundtrip() {
+        let mut chunk = Chunk::new(0, 0);
+        chunk.set_material(3, 4, MaterialId::STONE);
+        chunk.set_material(5, 6, MaterialId::WATER);
+        let rect = chunk.dirty_rect.unwrap();
+
+        let pixels = decode_region(&encode_region(&chunk, &rect).unwrap()).unwrap();
+
+        // 3x3 rectangle from (3, 4) to (5, 6), row by row
+        assert_eq!(pixels.len(), 9);
+        assert_eq!(pixels[0].material_id, MaterialId::STONE);
+        assert_eq!(pixels[8].material_id, MaterialId::WATER);
+        assert_eq!(pixels[4].material_id, MaterialId::AIR);
+    }
+
+    #[test]
+    fn test_unframed_message_roundtrip() {
+        let message = ServerMessage::ChunkUnloaded { x: 1, y: -2 };
+        let payload = encode_message(&message).unwrap();
+        assert_eq!(decode_message::<ServerMessage>(&payload).unwrap(), message);
+    }
+}
@@ -0,0 +1,950 @@
+//! Authoritative world host
+//!
+//! The server owns one `World` and steps it at a fixed rate. Network I/O runs
+//! on background threads (an accept thread per listener, a reader and a writer
+//! thread per TCP connection, one thread per WebSocket connection) that talk to
+//! the tick loop through channels, so all world access stays on the thread
+//! calling `tick`.
+
+use anyhow::{Context, Result};
+use glam::{IVec2, Vec2};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufReader};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender, TryRecvError, TrySendError};
+use std::thread;
+use std::time::{Duration, Instant};
+use sunaba_core::world::{
+    CHUNK_SIZE, ChunkPersistence, DirtyRect, NoopStats, TOOL_REACH, World, WorldGenerator,
+    WorldMetadata,
+};
+use sunaba_simulation::MaterialId;
+use tungstenite::Message;
+use tungstenite::protocol::WebSocketConfig;
+
+use crate::protocol::{
+    self, ClientMessage, MAX_NAME_LEN, PROTOCOL_VERSION, PlayerId, PlayerState, ServerMessage,
+};
+
+/// Chunks beyond the view radius (plus this margin) of every player are unloaded
+const EVICT_MARGIN: i32 = 2;
+
+/// Ticks between chunk eviction passes
+const EVICT_INTERVAL: u64 = 60;
+
+/// Ticks between player position broadcasts (20Hz at 60 ticks/s)
+const PLAYER_BROADCAST_INTERVAL: u64 = 3;
+
+/// Ticks between sending the changed regions of streamed chunks (20Hz at 60 ticks/s)
+const CHUNK_UPDATE_INTERVAL: u64 = 3;
+
+/// How long a WebSocket connection thread waits for client data before
+/// flushing its outbox again
+const WS_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Time a WebSocket client gets to complete the HTTP upgrade
+const WS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Messages queued for a connection before it counts as too slow and is dropped
+/// (streaming a whole view at the default radius queues 81 chunks)
+const OUTBOX_CAPACITY: usize = 4096;
+
+/// Dedicated server settings
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    /// Address to listen on (port 0 picks a free port)
+    pub bind_addr: String,
+    /// Address for WebSocket clients (browsers); `None` serves TCP only
+    pub ws_bind_addr: Option<String>,
+    /// World directory name under `worlds/`; `None` keeps the world in memory only
+    pub world_name: Option<String>,
+    /// Seed for a brand-new world (existing worlds keep their saved seed)
+    pub seed: Option<u64>,
+    /// Chunks streamed to each player around their chunk
+    pub view_radius: i32,
+    /// Simulation ticks per second
+    pub tick_rate: u32,
+    /// Ticks between autosaves (0 disables autosave)
+    pub autosave_interval: u64,
+    /// Maximum number of joined players
+    pub max_players: usize,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind_addr: format!("0.0.0.0:{}", protocol::DEFAULT_PORT),
+            ws_bind_addr: None,
+            world_name: Some("server".to_string()),
+            seed: None,
+            view_radius: 4,
+            tick_rate: 60,
+            autosave_interval: 60 * 60, // Once a minute
+            max_players: 16,
+        }
+    }
+}
+
+/// Connection lifecycle reported by the network threads
+enum NetEvent {
+    Connected {
+        id: PlayerId,
+        outbox: SyncSender<ServerMessage>,
+        stream: TcpStream,
+    },
+    Message {
+        id: PlayerId,
+        message: ClientMessage,
+    },
+    Disconnected {
+        id: PlayerId,
+    },
+}
+
+/// One connection (a player once it has joined)
+struct Session {
+    /// Set by `Join`; actions are refused until then
+    name: Option<String>,
+    position: Vec2,
+    velocity: Vec2,
+    /// Chunks this client currently holds
+    streamed: HashSet<IVec2>,
+    /// Messages are written to the socket by the connection's writer thread
+    outbox: SyncSender<ServerMessage>,
+    /// Set when the outbox filled up because the client stopped reading
+    overflowed: Cell<bool>,
+    /// Handle for closing the socket under a writer stuck on a slow client
+    stream: TcpStream,
+}
+
+impl Session {
+    fn state(&self, player_id: PlayerId) -> PlayerState {
+        PlayerState {
+            player_id,
+            name: self.name.clone().unwrap_or_default(),
+            x: self.position.x,
+            y: self.position.y,
+            vel_x: self.velocity.x,
+            vel_y: self.velocity.y,
+        }
+    }
+
+    fn send(&self, message: ServerMessage) {
+        match self.outbox.try_send(message) {
+            Ok(()) => {}
+            // The tick loop disconnects the client
+            Err(TrySendError::Full(_)) => self.overflowed.set(true),
+            // The connection is going away; its reader reports that
+            Err(TrySendError::Disconnected(_)) => {}
+        }
+    }
+}
+
+/// Standalone multiplayer server hosting one authoritative `World`
+pub struct DedicatedServer {
+    config: ServerConfig,
+    world: World,
+    generator: WorldGenerator,
+    persistence: Option<ChunkPersistence>,
+    metadata: WorldMetadata,
+    sessions: HashMap<PlayerId, Session>,
+    events: Receiver<NetEvent>,
+    local_addr: SocketAddr,
+    ws_local_addr: Option<SocketAddr>,
+    /// Area changed in each chunk since changes were last sent
+    pending_regions: HashMap<IVec2, DirtyRect>,
+    tick: u64,
+    rng: StdRng,
+}
+
+impl DedicatedServer {
+    /// Open (or create) the world and start accepting connections
+    pub fn bind(config: ServerConfig) -> Result<Self> {
+        let persistence = match &config.world_name {
+            Some(name) => Some(ChunkPersistence::new(name)?),
+            None => None,
+        };
+
+        let mut metadata = persistence
+            .as_ref()
+            .map(|p| p.load_metadata())
+            .unwrap_or_default();
+        let is_new_world = persistence.as_ref().is_none_or(|p| !p.has_metadata());
+        if is_new_world && let Some(seed) = config.seed {
+            metadata.seed = seed;
+        }
+        if let Some(persistence) = &persistence {
+            persistence.save_metadata(&metadata)?;
+        }
+
+        // The server loads and saves chunks itself, so the world never auto-loads
+        let mut world = World::new(true);
+        world.disable_persistence();
+        world.set_generator(metadata.seed);
+
+        let listener = TcpListener::bind(&config.bind_addr)
+            .with_context(|| format!("Failed to bind {}", config.bind_addr))?;
+        let local_addr = listener.local_addr()?;
+
+        let ws_listener = match &config.ws_bind_addr {
+            Some(addr) => {
+                Some(TcpListener::bind(addr).with_context(|| format!("Failed to bind {}", addr))?)
+            }
+            None => None,
+        };
+        let ws_local_addr = match &ws_listener {
+            Some(listener) => Some(listener.local_addr()?),
+            None => None,
+        };
+
+        // Both listeners draw player ids from one counter
+        let next_id = Arc::new(AtomicU64::new(1));
+        let (event_tx, events) = mpsc::channel();
+        if let Some(ws_listener) = ws_listener {
+            let ws_events = event_tx.clone();
+            let ws_next_id = next_id.clone();
+            thread::Builder::new()
+                .name("dedicated-accept-ws".to_string())
+                .spawn(move || accept_loop(ws_listener, ws_events, ws_next_id, spawn_websocket))
+                .context("Failed to spawn WebSocket accept thread")?;
+        }
+        thread::Builder::new()
+            .name("dedicated-accept".to_string())
+            .spawn(move || accept_loop(listener, event_tx, next_id, spawn_connection))
+            .context("Failed to spawn accept thread")?;
+
+        log::info!(
+            "Dedicated server listening on {} (world: {}, seed: {})",
+            local_addr,
+            config.world_name.as_deref().unwrap_or("<in-memory>"),
+            metadata.seed
+        );
+        if let Some(addr) = ws_local_addr {
+            log::info!("WebSocket clients can connect on ws://{}", addr);
+        }
+
+        Ok(Self {
+            generator: WorldGenerator::new(metadata.seed),
+            rng: StdRng::seed_from_u64(metadata.seed),
+            config,
+            world,
+            persistence,
+            metadata,
+            sessions: HashMap::new(),
+            events,
+            local_addr,
+            ws_local_addr,
+            pending_regions: HashMap::new(),
+            tick: 0,
+        })
+    }
+
+    /// Address the server is listening on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Address WebSocket clients connect to, if enabled
+    pub fn ws_local_addr(&self) -> Option<SocketAddr> {
+        self.ws_local_addr
+    }
+
+    /// The authoritative world
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    /// Ticks simulated since start
+    pub fn tick_count(&self) -> u64 {
+        self.tick
+    }
+
+    /// Number of joined players
+    pub fn player_count(&self) -> usize {
+        self.sessions.values().filter(|s| s.name.is_some()).count()
+    }
+
+    /// Tick at the configured rate until `shutdown` is set, then save
+    pub fn run(&mut self, shutdown: &AtomicBool) -> Result<()> {
+        let tick_duration = Duration::from_secs_f64(1.0 / self.config.tick_rate.max(1) as f64);
+        let mut next_tick = Instant::now();
+
+        while !shutdown.load(Ordering::Relaxed) {
+            self.tick();
+
+            next_tick += tick_duration;
+            let now = Instant::now();
+            if next_tick > now {
+                thread::sleep(next_tick - now);
+            } else {
+                // Running behind: don't try to catch up with a burst of ticks
+                next_tick = now;
+            }
+        }
+
+        log::info!("Shutting down dedicated server");
+        self.save()
+    }
+
+    /// Process network events and advance the world by one tick
+    pub fn tick(&mut self) {
+        self.tick += 1;
+
+        while let Ok(event) = self.events.try_recv() {
+            self.handle_event(event);
+        }
+
+        let centers: Vec<Vec2> = self
+            .sessions
+            .values()
+            .filter(|s| s.name.is_some())
+            .map(|s| s.position)
+            .collect();
+
+        // Skip simulation while nobody is online (same as the SpacetimeDB server)
+        if !centers.is_empty() {
+            for center in &centers {
+                self.load_chunks_around(*center);
+            }
+            self.world.set_simulation_centers(centers);
+
+            let dt = 1.0 / self.config.tick_rate.max(1) as f32;
+            self.world.update(dt, &mut NoopStats, &mut self.rng, true);
+
+            self.stream_chunks();
+
+            if self.tick.is_multiple_of(PLAYER_BROADCAST_INTERVAL) {
+                self.broadcast_players();
+            }
+        }
+
+        self.drop_overflowed();
+
+        if self.tick.is_multiple_of(EVICT_INTERVAL) {
+            self.evict_chunks();
+        }
+
+        if self.config.autosave_interval > 0
+            && self.tick.is_multiple_of(self.config.autosave_interval)
+            && let Err(e) = self.save()
+        {
+            log::error!("Autosave failed: {}", e);
+        }
+    }
+
+    /// Write all modified chunks and the world metadata to disk (blocking)
+    pub fn save(&mut self) -> Result<()> {
+        let Some(persistence) = &self.persistence else {
+            return Ok(());
+        };
+
+        let mut saved = 0;
+        for chunk in self.world.chunks_mut().values_mut() {
+            if chunk.dirty {
+                persistence.save_chunk_blocking(chunk)?;
+                chunk.dirty = false;
+                saved += 1;
+            }
+        }
+        persistence.save_metadata(&self.metadata)?;
+
+        log::info!("Saved {} chunks", saved);
+        Ok(())
+    }
+
+    fn handle_event(&mut self, event: NetEvent) {
+        match event {
+            NetEvent::Connected { id, outbox, stream } => {
+                self.sessions.insert(
+                    id,
+                    Session {
+                        name: None,
+                        position: self.spawn_point(),
+                        velocity: Vec2::ZERO,
+                        streamed: HashSet::new(),
+                        outbox,
+                        overflowed: Cell::new(false),
+                        stream,
+                    },
+                );
+            }
+            NetEvent::Message { id, message } => self.handle_message(id, message),
+            NetEvent::Disconnected { id } => self.remove_session(id),
+        }
+    }
+
+    fn handle_message(&mut self, id: PlayerId, message: ClientMessage) {
+        let Some(session) = self.sessions.get(&id) else {
+            return;
+        };
+
+        if session.name.is_none() {
+            match message {
+                ClientMessage::Join {
+                    name,
+                    protocol_version,
+                } => self.join(id, name, protocol_version),
+                ClientMessage::Leave => self.remove_session(id),
+                _ => session.send(ServerMessage::ActionRejected {
+                    reason: "Join first".to_string(),
+                }),
+            }
+            return;
+        }
+
+        match message {
+            ClientMessage::Join { .. } => {}
+            ClientMessage::UpdatePosition { x, y, vel_x, vel_y } => {
+                if [x, y, vel_x, vel_y].iter().all(|v| v.is_finite())
+                    && let Some(session) = self.sessions.get_mut(&id)
+                {
+                    session.position = Vec2::new(x, y);
+                    session.velocity = Vec2::new(vel_x, vel_y);
+                }
+            }
+            ClientMessage::PlaceMaterial { x, y, material_id } => {
+                let valid = self.world.materials().get(material_id).id == material_id;
+                if !valid {
+                    self.reject(id, format!("Unknown material {}", material_id));
+                } else if self.check_edit(id, x, y) {
+                    self.world.set_pixel(x, y, material_id);
+                }
+            }
+            ClientMessage::Mine { x, y } => {
+                if !self.check_edit(id, x, y) {
+                    return;
+                }
+                // Air and bedrock have no hardness
+                let material = self
+                    .world
+                    .get_pixel_material(x, y)
+                    .unwrap_or(MaterialId::AIR);
+                if self.world.materials().get(material).hardness.is_none() {
+                    self.reject(id, format!("({}, {}) cannot be mined", x, y));
+                } else {
+                    self.world.set_pixel(x, y, MaterialId::AIR);
+                }
+            }
+            ClientMessage::SetName { name } => match sanitize_name(&name) {
+                Some(name) => {
+                    if let Some(session) = self.sessions.get_mut(&id) {
+                        session.name = Some(name);
+                    }
+                }
+                None => self.reject(id, "Invalid name".to_string()),
+            },
+            ClientMessage::Respawn => {
+                let spawn = self.spawn_point();
+                if let Some(session) = self.sessions.get_mut(&id) {
+                    session.position = spawn;
+                    session.velocity = Vec2::ZERO;
+                }
+            }
+            ClientMessage::Ping { timestamp_ms } => session.send(ServerMessage::Pong {
+                timestamp_ms,
+                tick: self.tick,
+            }),
+            ClientMessage::Leave => self.remove_session(id),
+        }
+    }
+
+    fn join(&mut self, id: PlayerId, name: String, protocol_version: u32) {
+        let rejection = if protocol_version != PROTOCOL_VERSION {
+            Some(format!(
+                "Protocol version {} does not match server version {}",
+                protocol_version, PROTOCOL_VERSION
+            ))
+        } else if self.player_count() >= self.config.max_players {
+            Some("Server is full".to_string())
+        } else {
+            None
+        };
+        if let Some(reason) = rejection {
+            if let Some(session) = self.sessions.remove(&id) {
+                session.send(ServerMessage::Rejected { reason });
+            }
+            return;
+        }
+
+        let name = sanitize_name(&name).unwrap_or_else(|| format!("Player {}", id));
+        let spawn = self.spawn_point();
+        let Some(session) = self.sessions.get_mut(&id) else {
+            return;
+        };
+        session.name = Some(name.clone());
+        session.send(ServerMessage::Welcome {
+            player_id: id,
+            seed: self.metadata.seed,
+            spawn_x: spawn.x,
+            spawn_y: spawn.y,
+            tick: self.tick,
+            view_radius: self.config.view_radius,
+        });
+
+        let joined = session.state(id);
+        for (other_id, other) in &self.sessions {
+            if *other_id != id && other.name.is_some() {
+                other.send(ServerMessage::PlayerJoined(joined.clone()));
+            }
+        }
+
+        log::info!("{} joined ({} online)", name, self.player_count());
+    }
+
+    /// Drop a connection; its writer thread then closes the socket
+    fn remove_session(&mut self, id: PlayerId) {
+        let Some(session) = self.sessions.remove(&id) else {
+            return;
+        };
+        let Some(name) = session.name else {
+            return;
+        };
+
+        for other in self.sessions.values() {
+            if other.name.is_some() {
+                other.send(ServerMessage::PlayerLeft { player_id: id });
+            }
+        }
+        log::info!("{} left ({} online)", name, self.player_count());
+    }
+
+    /// Disconnect clients whose outbox filled up instead of queueing without bound
+    fn drop_overflowed(&mut self) {
+        let overflowed: Vec<PlayerId> = self
+            .sessions
+            .iter()
+            .filter(|(_, s)| s.overflowed.get())
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in overflowed {
+            if let Some(session) = self.sessions.get(&id) {
+                log::warn!("Connection {} is not keeping up, disconnecting", id);
+                // Unblocks a writer stuck on the socket; the reader then reports the disconnect
+                let _ = session.stream.shutdown(Shutdown::Both);
+            }
+            self.remove_session(id);
+        }
+    }
+
+    /// Edits must be within reach of the player and inside a loaded chunk
+    fn check_edit(&self, id: PlayerId, x: i32, y: i32) -> bool {
+        let Some(session) = self.sessions.get(&id) else {
+            return false;
+        };
+        let target = Vec2::new(x as f32, y as f32);
+        if target.distance(session.position) > TOOL_REACH {
+            session.send(ServerMessage::ActionRejected {
+                reason: format!("({}, {}) is out of reach", x, y),
+            });
+            return false;
+        }
+        if self.world.get_pixel(x, y).is_none() {
+            session.send(ServerMessage::ActionRejected {
+                reason: format!("({}, {}) is not loaded", x, y),
+            });
+            return false;
+        }
+        true
+    }
+
+    fn reject(&self, id: PlayerId, reason: String) {
+        if let Some(session) = self.sessions.get(&id) {
+            session.send(ServerMessage::ActionRejected { reason });
+        }
+    }
+
+    fn spawn_point(&self) -> Vec2 {
+        Vec2::new(self.metadata.spawn_point.0, self.metadata.spawn_point.1)
+    }
+
+    /// Load (from disk) or generate every chunk within view of a player
+    fn load_chunks_around(&mut self, center: Vec2) {
+        let center = chunk_of(center);
+        let radius = self.config.view_radius;
+
+        for cy in (center.y - radius)..=(center.y + radius) {
+            for cx in (center.x - radius)..=(center.x + radius) {
+                let pos = IVec2::new(cx, cy);
+                if self.world.has_chunk(pos) {
+                    continue;
+                }
+                let chunk = match &self.persistence {
+                    Some(persistence) => persistence.load_chunk(cx, cy, &self.generator),
+                    None => self.generator.generate_chunk(cx, cy),
+                };
+                self.world.insert_chunk(pos, chunk);
+            }
+        }
+    }
+
+    /// Send the changed regions of chunks to the players holding them (every
+    /// `CHUNK_UPDATE_INTERVAL` ticks), whole chunks to players they came into
+    /// view of, and unload chunks that left a player's view
+    fn stream_chunks(&mut self) {
+        // Changes are collected here; the server has no renderer to clear them
+        for (pos, chunk) in self.world.chunks_mut().iter_mut() {
+            if let Some(rect) = chunk.dirty_rect.take() {
+                self.pending_regions
+                    .entry(*pos)
+                    .and_modify(|pending| {
+                        pending.expand(rect.min_x, rect.min_y);
+                        pending.expand(rect.max_x, rect.max_y);
+                    })
+                    .or_insert(rect);
+            }
+        }
+
+        let mut regions: Vec<(IVec2, ServerMessage)> = Vec::new();
+        if self.tick.is_multiple_of(CHUNK_UPDATE_INTERVAL) {
+            for (pos, rect) in self.pending_regions.drain() {
+                let Some(chunk) = self.world.get_chunk(pos.x, pos.y) else {
+                    continue;
+                };
+                match protocol::encode_region(chunk, &rect) {
+                    Ok(data) => regions.push((
+                        pos,
+                        ServerMessage::ChunkRegion {
+                            x: pos.x,
+                            y: pos.y,
+                            min_x: rect.min_x as u16,
+                            min_y: rect.min_y as u16,
+                            width: (rect.max_x - rect.min_x + 1) as u16,
+                            height: (rect.max_y - rect.min_y + 1) as u16,
+                            data,
+                        },
+                    )),
+                    Err(e) => log::error!("{}", e),
+                }
+            }
+        }
+
+        // Whole chunks are encoded at most once per tick, however many players need them
+        let mut encoded: HashMap<IVec2, Vec<u8>> = HashMap::new();
+        let radius = self.config.view_radius;
+        for session in self.sessions.values_mut() {
+            if session.name.is_none() {
+                continue;
+            }
+
+            for (pos, region) in &regions {
+                if session.streamed.contains(pos) {
+                    session.send(region.clone());
+                }
+            }
+
+            let center = chunk_of(session.position);
+            let in_view = |pos: &IVec2| (*pos - center).abs().max_element() <= radius;
+
+            let left_view: Vec<IVec2> = session
+                .streamed
+                .iter()
+                .filter(|pos| !in_view(*pos))
+                .copied()
+                .collect();
+            for pos in left_view {
+                session.streamed.remove(&pos);
+                session.send(ServerMessage::ChunkUnloaded { x: pos.x, y: pos.y });
+            }
+
+            for cy in (center.y - radius)..=(center.y + radius) {
+                for cx in (center.x - radius)..=(center.x + radius) {
+                    let pos = IVec2::new(cx, cy);
+                    if session.streamed.contains(&pos) {
+                        continue;
+                    }
+                    let Some(chunk) = self.world.get_chunk(cx, cy) else {
+                        continue;
+                    };
+                    let data = match encoded.get(&pos) {
+                        Some(data) => data.clone(),
+                        None => match protocol::encode_chunk(chunk) {
+                            Ok(data) => {
+                                encoded.insert(pos, data.clone());
+                                data
+                            }
+                            Err(e) => {
+                                log::error!("{}", e);
+                                continue;
+                            }
+                        },
+                    };
+                    session.send(ServerMessage::Chunk { x: cx, y: cy, data });
+                    session.streamed.insert(pos);
+                }
+            }
+        }
+    }
+
+    fn broadcast_players(&self) {
+        let players: Vec<PlayerState> = self
+            .sessions
+            .iter()
+            .filter(|(_, s)| s.name.is_some())
+            .map(|(id, s)| s.state(*id))
+            .collect();
+
+        for session in self.sessions.values() {
+            if session.name.is_some() {
+                session.send(ServerMessage::Players {
+                    tick: self.tick,
+                    players: players.clone(),
+                });
+            }
+        }
+    }
+
+    /// Unload chunks no player can see, saving modified ones first
+    fn evict_chunks(&mut self) {
+        let centers: Vec<IVec2> = self
+            .sessions
+            .values()
+            .filter(|s| s.name.is_some())
+            .map(|s| chunk_of(s.position))
+            .collect();
+        let keep_radius = self.config.view_radius + EVICT_MARGIN;
+
+        let to_evict: Vec<IVec2> = self
+            .world
+            .chunks()
+            .keys()
+            .filter(|pos| {
+                !centers
+                    .iter()
+                    .any(|center| (**pos - *center).abs().max_element() <= keep_radius)
+            })
+            .copied()
+            .collect();
+
+        for pos in to_evict {
+            self.pending_regions.remove(&pos);
+            let Some(chunk) = self.world.chunks_mut().remove(&pos) else {
+                continue;
+            };
+            // Blocking, so a chunk loaded again right away reads the saved state
+            if chunk.dirty
+                && let Some(persistence) = &self.persistence
+                && let Err(e) = persistence.save_chunk_blocking(&chunk)
+            {
+                log::error!("Failed to save chunk ({}, {}): {}", pos.x, pos.y, e);
+            }
+        }
+    }
+}
+
+/// Chunk containing a world position
+fn chunk_of(position: Vec2) -> IVec2 {
+    IVec2::new(
+        (position.x as i32).div_euclid(CHUNK_SIZE as i32),
+        (position.y as i32).div_euclid(CHUNK_SIZE as i32),
+    )
+}
+
+/// Trimmed name, or `None` if empty or too long
+fn sanitize_name(name: &str) -> Option<String> {
+    let name = name.trim();
+    (!name.is_empty() && name.chars().count() <= MAX_NAME_LEN).then(|| name.to_string())
+}
+
+/// Sets up the threads serving one accepted connection
+type SpawnConnection =
+    fn(PlayerId, TcpStream, Receiver<ServerMessage>, &Sender<NetEvent>) -> Result<()>;
+
+/// Accept connections and hand each one to `spawn`
+fn accept_loop(
+    listener: TcpListener,
+    events: Sender<NetEvent>,
+    next_id: Arc<AtomicU64>,
+    spawn: SpawnConnection,
+) {
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                log::warn!("Connection error: {}", e);
+                continue;
+            }
+        };
+        let id = next_id.fetch_add(1, Ordering::Relaxed);
+
+        let handle = match stream.try_clone() {
+            Ok(handle) => handle,
+            Err(e) => {
+                log::warn!("Failed to set up connection {}: {}", id, e);
+                continue;
+            }
+        };
+        let (outbox, outbox_rx) = mpsc::sync_channel(OUTBOX_CAPACITY);
+        if events
+            .send(NetEvent::Connected {
+                id,
+                outbox,
+                stream: handle,
+            })
+            .is_err()
+        {
+            // The server was dropped: stop accepting
+            break;
+        }
+        if let Err(e) = spawn(id, stream, outbox_rx, &events) {
+            log::warn!("Failed to set up connection {}: {}", id, e);
+            let _ = events.send(NetEvent::Disconnected { id });
+        }
+    }
+}
+
+/// Plain TCP: a writer thread drains the outbox, a reader thread reports messages
+fn spawn_connection(
+    id: PlayerId,
+    stream: TcpStream,
+    outbox: Receiver<ServerMessage>,
+    events: &Sender<NetEvent>,
+) -> Result<()> {
+    stream.set_nodelay(true)?;
+    log::debug!("Connection {} from {}", id, stream.peer_addr()?);
+
+    let mut writer = stream.try_clone()?;
+    thread::Builder::new()
+        .name(format!("dedicated-write-{}", id))
+        .spawn(move || {
+            for message in outbox {
+                if protocol::write_message(&mut writer, &message).is_err() {
+                    break;
+                }
+            }
+            // Session removed (or write failed): close so the reader stops too
+            let _ = writer.shutdown(Shutdown::Both);
+        })?;
+
+    let events = events.clone();
+    thread::Builder::new()
+        .name(format!("dedicated-read-{}", id))
+        .spawn(move || {
+            let mut reader = BufReader::new(stream);
+            while let Ok(message) = protocol::read_message(&mut reader) {
+                if events.send(NetEvent::Message { id, message }).is_err() {
+                    return;
+                }
+            }
+            let _ = events.send(NetEvent::Disconnected { id });
+        })?;
+
+    Ok(())
+}
+
+/// WebSocket: one thread does the HTTP upgrade, then alternates between
+/// reading with a short timeout and flushing the outbox
+fn spawn_websocket(
+    id: PlayerId,
+    stream: TcpStream,
+    outbox: Receiver<ServerMessage>,
+    events: &Sender<NetEvent>,
+) -> Result<()> {
+    stream.set_nodelay(true)?;
+    stream.set_read_timeout(Some(WS_HANDSHAKE_TIMEOUT))?;
+    log::debug!("WebSocket connection {} from {}", id, stream.peer_addr()?);
+
+    let events = events.clone();
+    thread::Builder::new()
+        .name(format!("dedicated-ws-{}", id))
+        .spawn(move || {
+            serve_websocket(id, stream, &outbox, &events);
+            let _ = events.send(NetEvent::Disconnected { id });
+        })?;
+
+    Ok(())
+}
+
+/// Runs until the client disconnects or the session is removed
+fn serve_websocket(
+    id: PlayerId,
+    stream: TcpStream,
+    outbox: &Receiver<ServerMessage>,
+    events: &Sender<NetEvent>,
+) {
+    let config = WebSocketConfig::default().max_message_size(Some(protocol::MAX_FRAME_BYTES));
+    let mut socket = match tungstenite::accept_with_config(stream, Some(config)) {
+        Ok(socket) => socket,
+        Err(e) => {
+            log::debug!("WebSocket handshake with connection {} failed: {}", id, e);
+            return;
+        }
+    };
+    if socket
+        .get_ref()
+        .set_read_timeout(Some(WS_POLL_INTERVAL))
+        .is_err()
+    {
+        return;
+    }
+
+    loop {
+        loop {
+            match outbox.try_recv() {
+                Ok(message) => {
+                    let Ok(payload) = protocol::encode_message(&message) else {
+                        return;
+                    };
+                    if socket.write(Message::Binary(payload.into())).is_err() {
+                        return;
+                    }
+                }
+                Err(TryRecvError::Empty) => break,
+                // Session removed: say goodbye and close
+                Err(TryRecvError::Disconnected) => {
+                    let _ = socket.close(None);
+                    let _ = socket.flush();
+                    return;
+                }
+            }
+        }
+        if socket.flush().is_err() {
+            return;
+        }
+
+        match socket.read() {
+            Ok(Message::Binary(payload)) => match protocol::decode_message(&payload) {
+                Ok(message) => {
+                    if events.send(NetEvent::Message { id, message }).is_err() {
+                        return;
+                    }
+                }
+                Err(e) => {
+                    log::debug!("Bad message from connection {}: {}", id, e);
+                    return;
+                }
+            },
+            // Pings are answered by tungstenite; text is not part of the protocol
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(e))
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) => {}
+            Err(_) => return,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_name() {
+        assert_eq!(sanitize_name("  alice "), Some("alice".to_string()));
+        assert_eq!(sanitize_name("   "), None);
+        assert_eq!(sanitize_name(&"x".repeat(MAX_NAME_LEN + 1)), None);
+    }
+
+    #[test]
+    fn test_chunk_of_negative_positions() {
+        assert_eq!(chunk_of(Vec2::new(0.0, 100.0)), IVec2::new(0, 1));
+        assert_eq!(chunk_of(Vec2::new(-1.0, -65.0)), IVec2::new(-1, -2));
+    }
+}
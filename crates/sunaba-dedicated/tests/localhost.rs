@@ -0,0 +1,285 @@
+//! End-to-end tests: a dedicated server and clients on localhost
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use sunaba_core::world::ChunkPersistence;
+use sunaba_dedicated::protocol::{self, ClientMessage, PROTOCOL_VERSION, ServerMessage};
+use sunaba_dedicated::{DedicatedClient, DedicatedServer, ServerConfig};
+use sunaba_simulation::MaterialId;
+use tungstenite::Message;
+use tungstenite::stream::MaybeTlsStream;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Spawn point (0, 100) lies in chunk (0, 1) at local (0, 36)
+const SPAWN_X: i32 = 0;
+const SPAWN_Y: i32 = 100;
+
+struct TestServer {
+    addr: SocketAddr,
+    ws_addr: SocketAddr,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl TestServer {
+    fn start(world_name: Option<String>) -> Self {
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let (addr_tx, addr_rx) = mpsc::channel();
+        let server_shutdown = shutdown.clone();
+
+        let handle = thread::spawn(move || {
+            let mut server = DedicatedServer::bind(ServerConfig {
+                bind_addr: "127.0.0.1:0".to_string(),
+                ws_bind_addr: Some("127.0.0.1:0".to_string()),
+                world_name,
+                seed: Some(42),
+                view_radius: 1,
+                autosave_interval: 0,
+                ..Default::default()
+            })
+            .expect("server should bind");
+            addr_tx
+                .send((server.local_addr(), server.ws_local_addr().unwrap()))
+                .unwrap();
+            server.run(&server_shutdown).expect("server should save");
+        });
+
+        let (addr, ws_addr) = addr_rx.recv_timeout(TIMEOUT).expect("server should start");
+        Self {
+            addr,
+            ws_addr,
+            shutdown,
+            handle: Some(handle),
+        }
+    }
+
+    fn stop(mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            handle.join().unwrap();
+        }
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Wait for a chunk or region update where the spawn pixel holds `material`
+fn wait_for_spawn_material(client: &DedicatedClient, material: u16) -> bool {
+    client
+        .wait_for(TIMEOUT, |message| match message {
+            ServerMessage::Chunk { x: 0, y: 1, data } => protocol::decode_chunk(data)
+                .is_ok_and(|chunk| chunk.get_material(0, 36) == material),
+            ServerMessage::ChunkRegion {
+                x: 0,
+                y: 1,
+                min_x,
+                min_y,
+                width,
+                height,
+                data,
+            } => {
+                let (local_x, local_y) = (-(*min_x as i32), 36 - *min_y as i32);
+                let inside =
+                    (0..*width as i32).contains(&local_x) && (0..*height as i32).contains(&local_y);
+                inside
+                    && protocol::decode_region(data).is_ok_and(|pixels| {
+                        pixels[(local_y * *width as i32 + local_x) as usize].material_id == material
+                    })
+            }
+            _ => false,
+        })
+        .is_some()
+}
+
+#[test]
+fn test_two_clients_share_world() {
+    let server = TestServer::start(None);
+
+    let mut alice = DedicatedClient::connect(server.addr, "alice").unwrap();
+    assert_eq!(alice.seed(), 42);
+    assert_eq!(alice.spawn(), (SPAWN_X as f32, SPAWN_Y as f32));
+
+    // Initial stream covers the spawn chunk
+    assert!(
+        alice
+            .wait_for(TIMEOUT, |m| matches!(
+                m,
+                ServerMessage::Chunk { x: 0, y: 1, .. }
+            ))
+            .is_some()
+    );
+
+    let bob = DedicatedClient::connect(server.addr, "bob").unwrap();
+    assert_ne!(alice.player_id(), bob.player_id());
+    let bob_id = bob.player_id();
+    assert!(
+        alice
+            .wait_for(TIMEOUT, |m| matches!(m, ServerMessage::PlayerJoined(p) if p.player_id == bob_id && p.name == "bob"))
+            .is_some()
+    );
+    assert!(
+        bob.wait_for(
+            TIMEOUT,
+            |m| matches!(m, ServerMessage::Players { players, .. } if players.len() == 2)
+        )
+        .is_some()
+    );
+
+    // Alice's edit reaches Bob
+    alice
+        .place_material(SPAWN_X, SPAWN_Y, MaterialId::GLASS)
+        .unwrap();
+    assert!(wait_for_spawn_material(&bob, MaterialId::GLASS));
+
+    alice.mine(SPAWN_X, SPAWN_Y).unwrap();
+    assert!(wait_for_spawn_material(&bob, MaterialId::AIR));
+
+    // Edits out of reach are refused
+    alice
+        .place_material(SPAWN_X + 500, SPAWN_Y, MaterialId::GLASS)
+        .unwrap();
+    assert!(
+        alice
+            .wait_for(TIMEOUT, |m| matches!(
+                m,
+                ServerMessage::ActionRejected { .. }
+            ))
+            .is_some()
+    );
+
+    let alice_id = alice.player_id();
+    alice.leave().unwrap();
+    assert!(
+        bob.wait_for(
+            TIMEOUT,
+            |m| matches!(m, ServerMessage::PlayerLeft { player_id } if *player_id == alice_id)
+        )
+        .is_some()
+    );
+
+    server.stop();
+}
+
+#[test]
+fn test_bedrock_cannot_be_mined() {
+    let server = TestServer::start(None);
+    let mut client = DedicatedClient::connect(server.addr, "miner").unwrap();
+    assert!(
+        client
+            .wait_for(TIMEOUT, |m| matches!(
+                m,
+                ServerMessage::Chunk { x: 0, y: 1, .. }
+            ))
+            .is_some()
+    );
+
+    client
+        .place_material(SPAWN_X, SPAWN_Y, MaterialId::BEDROCK)
+        .unwrap();
+    assert!(wait_for_spawn_material(&client, MaterialId::BEDROCK));
+
+    client.mine(SPAWN_X, SPAWN_Y).unwrap();
+    assert!(
+        client
+            .wait_for(TIMEOUT, |m| matches!(
+                m,
+                ServerMessage::ActionRejected { reason } if reason.contains("cannot be mined")
+            ))
+            .is_some()
+    );
+
+    server.stop();
+}
+
+#[test]
+fn test_websocket_client_joins() {
+    let server = TestServer::start(None);
+
+    let (mut socket, _) = tungstenite::connect(format!("ws://{}", server.ws_addr)).unwrap();
+    if let MaybeTlsStream::Plain(stream) = socket.get_ref() {
+        stream.set_read_timeout(Some(TIMEOUT)).unwrap();
+    }
+    let join = ClientMessage::Join {
+        name: "browser".to_string(),
+        protocol_version: PROTOCOL_VERSION,
+    };
+    socket
+        .send(Message::Binary(
+            protocol::encode_message(&join).unwrap().into(),
+        ))
+        .unwrap();
+
+    let mut next_message = || loop {
+        if let Message::Binary(payload) = socket.read().unwrap() {
+            return protocol::decode_message::<ServerMessage>(&payload).unwrap();
+        }
+    };
+    assert!(matches!(
+        next_message(),
+        ServerMessage::Welcome { seed: 42, .. }
+    ));
+    // Chunks stream over WebSocket like over TCP
+    while !matches!(next_message(), ServerMessage::Chunk { x: 0, y: 1, .. }) {}
+
+    server.stop();
+}
+
+#[test]
+fn test_version_mismatch_rejected() {
+    let server = TestServer::start(None);
+
+    let mut stream = std::net::TcpStream::connect(server.addr).unwrap();
+    protocol::write_message(
+        &mut stream,
+        &protocol::ClientMessage::Join {
+            name: "old".to_string(),
+            protocol_version: protocol::PROTOCOL_VERSION + 1,
+        },
+    )
+    .unwrap();
+    let reply: ServerMessage = protocol::read_message(&mut stream).unwrap();
+    assert!(matches!(reply, ServerMessage::Rejected { .. }));
+
+    server.stop();
+}
+
+#[test]
+fn test_edits_survive_restart() {
+    let world_name = format!("dedicated_test_{}", std::process::id());
+    let _ = ChunkPersistence::delete_world(&world_name);
+
+    let server = TestServer::start(Some(world_name.clone()));
+    let mut client = DedicatedClient::connect(server.addr, "builder").unwrap();
+    // Edits are only accepted once the target chunk is loaded
+    assert!(
+        client
+            .wait_for(TIMEOUT, |m| matches!(
+                m,
+                ServerMessage::Chunk { x: 0, y: 1, .. }
+            ))
+            .is_some()
+    );
+    client
+        .place_material(SPAWN_X, SPAWN_Y, MaterialId::GLASS)
+        .unwrap();
+    assert!(wait_for_spawn_material(&client, MaterialId::GLASS));
+    drop(client);
+    server.stop();
+
+    let server = TestServer::start(Some(world_name.clone()));
+    let client = DedicatedClient::connect(server.addr, "builder").unwrap();
+    assert!(wait_for_spawn_material(&client, MaterialId::GLASS));
+    drop(client);
+    server.stop();
+
+    ChunkPersistence::delete_world(&world_name).unwrap();
+}
@@ -1,6 +1,7 @@
 //! Application state and event loop for Powder Game demo
 
 use anyhow::Result;
+use std::path::PathBuf;
 use std::sync::Arc;
 use web_time::Instant;
 use winit::{
@@ -8,17 +9,19 @@ use winit::{
     dpi::LogicalSize,
     event::{ElementState, MouseButton, WindowEvent},
     event_loop::{ActiveEventLoop, EventLoop},
-    keyboard::{KeyCode, PhysicalKey},
+    keyboard::{KeyCode, ModifiersState, PhysicalKey},
     window::{Window, WindowAttributes, WindowId},
 };
 
 use sunaba_core::simulation::Materials;
-use sunaba_core::world::{NoopStats, World};
+use sunaba_core::world::{NoopStats, Pixel, World};
 
 use crate::config::PowderConfig;
+use crate::history::{Canvas, History};
 use crate::render::Renderer;
-use crate::tools::{DragTool, EraseTool, PenTool, Tool, WindTool};
-use crate::ui::{ActiveTool, MaterialToolbar, PowderStats, ToolbarState, show_hud};
+use crate::scene::{SCENES_DIR, Scene};
+use crate::tools::{ToolBox, ToolPreview};
+use crate::ui::{ActiveTool, MaterialToolbar, PowderStats, SceneGallery, ToolbarState, show_hud};
 
 /// Main application state
 pub struct App {
@@ -37,19 +40,20 @@ pub struct App {
     // UI state
     toolbar: MaterialToolbar,
     toolbar_state: ToolbarState,
+    gallery: SceneGallery,
 
     // Tools
-    left_pen: PenTool,
-    right_pen: PenTool,
-    eraser: EraseTool,
-    wind_tool: WindTool,
-    drag_tool: DragTool,
+    tools: ToolBox,
+    history: History,
+    /// Tool and button of the stroke in progress
+    stroke: Option<(ActiveTool, MouseButton)>,
 
     // Input state
     mouse_pos: Option<(f32, f32)>,
     left_pressed: bool,
     right_pressed: bool,
     last_draw_pos: Option<(i32, i32)>,
+    modifiers: ModifiersState,
 
     // Timing
     last_update: Instant,
@@ -88,6 +92,10 @@ impl App {
         // Set active chunk radius to cover entire 1024px world (17×17 chunks)
         world.set_active_chunk_radius(8);
 
+        // No rain, snow, lightning or growing plants in the sandbox
+        world.set_weather_enabled(false);
+        world.set_vegetation_enabled(false);
+
         // Ensure chunks around origin are loaded
         let half_size = (config.world_size as i32) / 2;
         world.ensure_chunks_for_area(-half_size, -half_size, half_size, half_size);
@@ -95,13 +103,10 @@ impl App {
         // Create UI
         let toolbar = MaterialToolbar::new(&materials);
         let toolbar_state = ToolbarState::default();
+        let gallery = SceneGallery::new(SCENES_DIR);
 
         // Create tools
-        let left_pen = PenTool::new(toolbar_state.left_material);
-        let right_pen = PenTool::new(toolbar_state.right_material);
-        let eraser = EraseTool;
-        let wind_tool = WindTool::new();
-        let drag_tool = DragTool::new();
+        let tools = ToolBox::new(&toolbar_state);
 
         // Setup egui
         let egui_ctx = egui::Context::default();
@@ -131,15 +136,15 @@ impl App {
                 config,
                 toolbar,
                 toolbar_state,
-                left_pen,
-                right_pen,
-                eraser,
-                wind_tool,
-                drag_tool,
+                gallery,
+                tools,
+                history: History::new(),
+                stroke: None,
                 mouse_pos: None,
                 left_pressed: false,
                 right_pressed: false,
                 last_draw_pos: None,
+                modifiers: ModifiersState::empty(),
                 last_update: Instant::now(),
                 frame_count: 0,
                 fps_update_time: Instant::now(),
@@ -168,47 +173,57 @@ impl App {
             self.fps_update_time = now;
         }
 
-        // Handle drawing based on active tool
-        if let Some((screen_x, screen_y)) = self.mouse_pos {
-            let (world_x, world_y) = self.renderer.screen_to_world(screen_x, screen_y);
-            let brush_size = self.toolbar_state.brush_size;
-
-            if self.left_pressed || self.right_pressed {
-                match self.toolbar_state.active_tool {
-                    ActiveTool::Pen => {
-                        if self.left_pressed {
-                            self.left_pen
-                                .apply(&mut self.world, world_x, world_y, brush_size);
-                        }
-                        if self.right_pressed {
-                            self.right_pen
-                                .apply(&mut self.world, world_x, world_y, brush_size);
-                        }
-                    }
-                    ActiveTool::Eraser => {
-                        self.eraser
-                            .apply(&mut self.world, world_x, world_y, brush_size);
-                    }
-                    ActiveTool::Wind => {
-                        self.wind_tool
-                            .apply(&mut self.world, world_x, world_y, brush_size);
-                    }
-                    ActiveTool::Drag => {
-                        self.drag_tool
-                            .apply_drag(&mut self.world, world_x, world_y, brush_size);
-                    }
-                }
-            } else {
-                // End drag when mouse released
-                if self.toolbar_state.active_tool == ActiveTool::Drag {
-                    self.drag_tool.end_drag();
+        // Drive the stroke of the active tool
+        let cursor = self
+            .mouse_pos
+            .map(|(screen_x, screen_y)| self.renderer.screen_to_world(screen_x, screen_y));
+        let brush_size = self.toolbar_state.brush_size;
+
+        match (cursor, self.stroke) {
+            (Some((world_x, world_y)), None) if self.left_pressed || self.right_pressed => {
+                let button = if self.left_pressed {
+                    MouseButton::Left
+                } else {
+                    MouseButton::Right
+                };
+                let active_tool = self.toolbar_state.active_tool;
+                let mut canvas = Canvas::new(&mut self.world, &mut self.history);
+                self.tools
+                    .get_mut(active_tool, button == MouseButton::Right)
+                    .begin(&mut canvas, world_x, world_y, brush_size);
+                self.stroke = Some((active_tool, button));
+            }
+            (Some((world_x, world_y)), Some((tool, button))) if self.is_pressed(button) => {
+                let mut canvas = Canvas::new(&mut self.world, &mut self.history);
+                self.tools.get_mut(tool, button == MouseButton::Right).drag(
+                    &mut canvas,
+                    world_x,
+                    world_y,
+                    brush_size,
+                );
+            }
+            (Some(_), Some((tool, button))) => {
+                // Button released
+                let mut canvas = Canvas::new(&mut self.world, &mut self.history);
+                self.tools
+                    .get_mut(tool, button == MouseButton::Right)
+                    .commit(&mut canvas, brush_size);
+                self.end_stroke();
+
+                // Switch to pasting what was just copied
+                if let Some(region) = self.tools.copy.take_copied() {
+                    self.tools.paste.set_region(region);
+                    self.toolbar_state.active_tool = ActiveTool::Paste;
                 }
             }
-        } else {
-            // End drag when mouse leaves window
-            if self.toolbar_state.active_tool == ActiveTool::Drag {
-                self.drag_tool.end_drag();
+            (None, Some((tool, button))) => {
+                // Cursor left the window
+                self.tools
+                    .get_mut(tool, button == MouseButton::Right)
+                    .cancel();
+                self.end_stroke();
             }
+            _ => {}
         }
 
         // Update simulation (unless paused)
@@ -229,9 +244,8 @@ impl App {
     /// Render frame
     fn render(&mut self) -> Result<()> {
         // Update tool materials from toolbar state
-        self.left_pen.set_material(self.toolbar_state.left_material);
-        self.right_pen
-            .set_material(self.toolbar_state.right_material);
+        self.tools.sync(&self.toolbar_state);
+        self.handle_toolbar_requests();
 
         // Update visualization mode
         self.renderer
@@ -246,6 +260,7 @@ impl App {
         let fps = self.fps;
         let brush_size = self.toolbar_state.brush_size;
         let paused = self.toolbar_state.paused;
+        let preview = self.tool_preview();
 
         // Begin frame
         let output = self.renderer.begin_frame()?;
@@ -267,7 +282,8 @@ impl App {
         let raw_input = self.egui_state.take_egui_input(&self.window);
         let full_output = self.egui_ctx.run(raw_input, |ctx| {
             // Show toolbar
-            self.toolbar.show(ctx, &mut self.toolbar_state);
+            self.toolbar
+                .show(ctx, &mut self.toolbar_state, &mut self.gallery);
 
             // Show HUD
             let stats = PowderStats {
@@ -277,8 +293,14 @@ impl App {
                 paused,
             };
             show_hud(ctx, &stats);
+
+            if let Some(preview) = preview {
+                draw_tool_preview(ctx, &self.renderer, preview);
+            }
         });
 
+        self.handle_gallery_requests();
+
         // Handle egui platform output
         self.egui_state
             .handle_platform_output(&self.window, full_output.platform_output);
@@ -352,6 +374,136 @@ impl App {
         Ok(())
     }
 
+    fn is_pressed(&self, button: MouseButton) -> bool {
+        match button {
+            MouseButton::Left => self.left_pressed,
+            MouseButton::Right => self.right_pressed,
+            _ => false,
+        }
+    }
+
+    /// Record the finished stroke for undo
+    fn end_stroke(&mut self) {
+        self.history.commit(&self.world);
+        self.stroke = None;
+    }
+
+    /// Outline for the tool under the cursor (the stroke's tool while drawing)
+    fn tool_preview(&mut self) -> Option<ToolPreview> {
+        let (screen_x, screen_y) = self.mouse_pos?;
+        let (world_x, world_y) = self.renderer.screen_to_world(screen_x, screen_y);
+        let (tool, button) = self
+            .stroke
+            .unwrap_or((self.toolbar_state.active_tool, MouseButton::Left));
+        self.tools
+            .get_mut(tool, button == MouseButton::Right)
+            .preview(world_x, world_y, self.toolbar_state.brush_size)
+    }
+
+    /// Perform the undo/redo/rotate actions requested in the toolbar
+    fn handle_toolbar_requests(&mut self) {
+        // Undo/redo requested mid-stroke wait until the stroke is recorded
+        if self.stroke.is_none() {
+            if self.toolbar_state.undo_requested {
+                self.history.undo(&mut self.world);
+            }
+            if self.toolbar_state.redo_requested {
+                self.history.redo(&mut self.world);
+            }
+            self.toolbar_state.undo_requested = false;
+            self.toolbar_state.redo_requested = false;
+        }
+        if self.toolbar_state.rotate_requested {
+            self.tools.paste.rotate();
+        }
+
+        self.toolbar_state.rotate_requested = false;
+    }
+
+    /// Perform the save/load/share actions requested in the scene gallery
+    fn handle_gallery_requests(&mut self) {
+        if self.gallery.save_requested {
+            self.gallery.status = Some(match self.save_scene() {
+                Ok(path) => format!("Saved {}", path.display()),
+                Err(e) => format!("Save failed: {}", e),
+            });
+            self.gallery.refresh();
+        }
+
+        if self.gallery.copy_share_requested {
+            match Scene::capture(&self.world, self.config.world_size).to_share_string() {
+                Ok(text) => {
+                    self.egui_ctx.copy_text(text.clone());
+                    self.gallery.status =
+                        Some(format!("Copied share string ({} characters)", text.len()));
+                    self.gallery.share_text = text;
+                }
+                Err(e) => self.gallery.status = Some(format!("Copy failed: {}", e)),
+            }
+        }
+
+        if self.gallery.load_share_requested {
+            self.gallery.status = Some(
+                match Scene::from_share_string(&self.gallery.share_text, self.config.world_size) {
+                    Ok(scene) => {
+                        scene.apply(&mut self.world);
+                        self.history.clear();
+                        "Loaded scene from share string".to_string()
+                    }
+                    Err(e) => format!("Load failed: {}", e),
+                },
+            );
+        }
+
+        if let Some(path) = self.gallery.load_requested.clone() {
+            let scene = std::fs::read(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|data| Scene::from_png(&data, self.config.world_size));
+            self.gallery.status = Some(match scene {
+                Ok(scene) => {
+                    scene.apply(&mut self.world);
+                    self.history.clear();
+                    format!("Loaded {}", path.display())
+                }
+                Err(e) => format!("Load failed: {}", e),
+            });
+        }
+
+        if let Some(path) = self.gallery.delete_requested.clone() {
+            if let Err(e) = std::fs::remove_file(&path) {
+                self.gallery.status = Some(format!("Delete failed: {}", e));
+            }
+            self.gallery.refresh();
+        }
+
+        self.gallery.reset_flags();
+    }
+
+    /// Write the sandbox to the gallery as a PNG with the scene embedded
+    fn save_scene(&self) -> Result<PathBuf> {
+        let name: String = self
+            .gallery
+            .save_name
+            .trim()
+            .chars()
+            .filter(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | ' '))
+            .collect();
+        let name = if name.is_empty() {
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs());
+            format!("scene_{}", timestamp)
+        } else {
+            name
+        };
+
+        std::fs::create_dir_all(self.gallery.dir())?;
+        let path = self.gallery.dir().join(format!("{}.png", name));
+        let scene = Scene::capture(&self.world, self.config.world_size);
+        std::fs::write(&path, scene.to_png(&self.materials)?)?;
+        Ok(path)
+    }
+
     /// Count non-air particles in the world
     fn count_particles(&self) -> usize {
         let mut count = 0;
@@ -375,6 +527,51 @@ impl App {
     }
 }
 
+/// Outline a tool preview over the world
+fn draw_tool_preview(ctx: &egui::Context, renderer: &Renderer, preview: ToolPreview) {
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Background,
+        egui::Id::new("tool_preview"),
+    ));
+    let points_per_pixel = 1.0 / ctx.pixels_per_point();
+    let to_screen = |x: f32, y: f32| {
+        let (screen_x, screen_y) = renderer.world_to_screen(x, y);
+        egui::pos2(screen_x * points_per_pixel, screen_y * points_per_pixel)
+    };
+    // Screen rect covering the world pixels between two inclusive corners
+    let pixel_rect = |min: glam::IVec2, max: glam::IVec2| {
+        egui::Rect::from_two_pos(
+            to_screen(min.x as f32, min.y as f32),
+            to_screen(max.x as f32 + 1.0, max.y as f32 + 1.0),
+        )
+    };
+    let stroke = egui::Stroke::new(1.0, egui::Color32::WHITE);
+
+    match preview {
+        ToolPreview::Line { from, to, radius } => {
+            let width = to_screen(radius as f32 * 2.0 + 1.0, 0.0).x - to_screen(0.0, 0.0).x;
+            painter.line_segment(
+                [
+                    to_screen(from.x as f32 + 0.5, from.y as f32 + 0.5),
+                    to_screen(to.x as f32 + 0.5, to.y as f32 + 0.5),
+                ],
+                egui::Stroke::new(width.max(1.0), egui::Color32::from_white_alpha(96)),
+            );
+        }
+        ToolPreview::Rect { min, max } => {
+            painter.rect_stroke(pixel_rect(min, max), 0.0, stroke, egui::StrokeKind::Middle);
+        }
+        ToolPreview::Ellipse { min, max } => {
+            let rect = pixel_rect(min, max);
+            painter.add(egui::Shape::ellipse_stroke(
+                rect.center(),
+                rect.size() / 2.0,
+                stroke,
+            ));
+        }
+    }
+}
+
 impl ApplicationHandler for App {
     fn resumed(&mut self, _event_loop: &ActiveEventLoop) {
         // Nothing to do on resume for now
@@ -425,6 +622,9 @@ impl ApplicationHandler for App {
                     self.last_draw_pos = None;
                 }
             }
+            WindowEvent::ModifiersChanged(modifiers) => {
+                self.modifiers = modifiers.state();
+            }
             WindowEvent::MouseWheel { delta, .. } => {
                 // Zoom with scroll wheel
                 let scroll = match delta {
@@ -446,20 +646,35 @@ impl ApplicationHandler for App {
                                 self.should_step = true;
                             }
                         }
-                        PhysicalKey::Code(KeyCode::KeyC) => {
-                            // Clear world
+                        PhysicalKey::Code(KeyCode::KeyC) if !self.modifiers.control_key() => {
+                            // Clear world (undoable)
                             let half_size = (self.config.world_size / 64) as i32 / 2;
+                            let mut canvas = Canvas::new(&mut self.world, &mut self.history);
                             for cy in -half_size..half_size {
                                 for cx in -half_size..half_size {
                                     for y in 0..64 {
                                         for x in 0..64 {
                                             let world_x = cx * 64 + x;
                                             let world_y = cy * 64 + y;
-                                            self.world.set_pixel(world_x, world_y, 0);
+                                            canvas.set_pixel(world_x, world_y, Pixel::AIR);
                                         }
                                     }
                                 }
                             }
+                            self.history.commit(&self.world);
+                        }
+                        PhysicalKey::Code(KeyCode::KeyZ) if self.modifiers.control_key() => {
+                            if self.modifiers.shift_key() {
+                                self.toolbar_state.redo_requested = true;
+                            } else {
+                                self.toolbar_state.undo_requested = true;
+                            }
+                        }
+                        PhysicalKey::Code(KeyCode::KeyY) if self.modifiers.control_key() => {
+                            self.toolbar_state.redo_requested = true;
+                        }
+                        PhysicalKey::Code(KeyCode::KeyR) => {
+                            self.toolbar_state.rotate_requested = true;
                         }
                         PhysicalKey::Code(KeyCode::BracketLeft) => {
                             if self.toolbar_state.brush_size > 1 {
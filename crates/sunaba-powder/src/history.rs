@@ -0,0 +1,248 @@
+//! Undo/redo for editor strokes
+//!
+//! Tools write through a `Canvas`, which remembers each pixel's value the
+//! first time a stroke writes it. When the stroke ends, the current value of
+//! those pixels is read back and unchanged ones are dropped, so an edit holds
+//! exactly the pixels the tools wrote (before and after) and never pixels the
+//! simulation moved nearby.
+
+use glam::IVec2;
+use std::collections::HashMap;
+use sunaba_core::world::{CHUNK_SIZE, Pixel, World};
+
+use crate::scene::TRANSIENT_FLAGS;
+
+/// Edits kept for undo (oldest are dropped)
+pub const MAX_UNDO_STEPS: usize = 100;
+
+#[derive(Debug, Clone, Copy)]
+struct PixelChange {
+    pos: IVec2,
+    before: Pixel,
+    after: Pixel,
+}
+
+/// Changes made by one stroke
+#[derive(Debug, Clone)]
+struct Edit {
+    changes: Vec<PixelChange>,
+}
+
+fn same_pixel(a: Pixel, b: Pixel) -> bool {
+    a.material_id == b.material_id && (a.flags ^ b.flags) & !TRANSIENT_FLAGS == 0
+}
+
+fn chunk_pos(pos: IVec2) -> IVec2 {
+    pos.div_euclid(IVec2::splat(CHUNK_SIZE as i32))
+}
+
+/// Undo and redo stacks plus the stroke being recorded
+#[derive(Debug, Default)]
+pub struct History {
+    undo: Vec<Edit>,
+    redo: Vec<Edit>,
+    /// Pixels written by the current stroke, with their value before the first write
+    pending: HashMap<IVec2, Pixel>,
+}
+
+impl History {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember a pixel's value before the stroke first writes it
+    pub fn touch(&mut self, world: &World, x: i32, y: i32) {
+        let pos = IVec2::new(x, y);
+        if self.pending.contains_key(&pos) {
+            return;
+        }
+        if let Some(pixel) = world.get_pixel(x, y) {
+            self.pending.insert(pos, pixel);
+        }
+    }
+
+    /// Finish the current stroke, recording what it changed
+    ///
+    /// Returns false if nothing changed (no edit is recorded).
+    pub fn commit(&mut self, world: &World) -> bool {
+        let changes: Vec<_> = self
+            .pending
+            .drain()
+            .filter_map(|(pos, before)| {
+                let after = world.get_pixel(pos.x, pos.y)?;
+                (!same_pixel(before, after)).then_some(PixelChange { pos, before, after })
+            })
+            .collect();
+
+        if changes.is_empty() {
+            return false;
+        }
+        self.undo.push(Edit { changes });
+        if self.undo.len() > MAX_UNDO_STEPS {
+            self.undo.remove(0);
+        }
+        self.redo.clear();
+        true
+    }
+
+    /// Revert the newest edit; returns false if there is nothing to undo
+    pub fn undo(&mut self, world: &mut World) -> bool {
+        let Some(edit) = self.undo.pop() else {
+            return false;
+        };
+        apply_edit(world, &edit, |change| change.before);
+        self.redo.push(edit);
+        true
+    }
+
+    /// Reapply the newest undone edit; returns false if there is none
+    pub fn redo(&mut self, world: &mut World) -> bool {
+        let Some(edit) = self.redo.pop() else {
+            return false;
+        };
+        apply_edit(world, &edit, |change| change.after);
+        self.undo.push(edit);
+        true
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forget all edits (e.g. after loading a scene)
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.pending.clear();
+    }
+}
+
+fn apply_edit(world: &mut World, edit: &Edit, pixel: impl Fn(&PixelChange) -> Pixel) {
+    for change in &edit.changes {
+        world.set_pixel_full(change.pos.x, change.pos.y, pixel(change));
+        if let Some(chunk) = world.chunks_mut().get_mut(&chunk_pos(change.pos)) {
+            chunk.set_simulation_active(true);
+        }
+    }
+}
+
+/// World access for tools that records written pixels for undo
+pub struct Canvas<'a> {
+    world: &'a mut World,
+    history: &'a mut History,
+}
+
+impl<'a> Canvas<'a> {
+    pub fn new(world: &'a mut World, history: &'a mut History) -> Self {
+        Self { world, history }
+    }
+
+    pub fn world(&self) -> &World {
+        self.world
+    }
+
+    pub fn get_pixel(&self, x: i32, y: i32) -> Option<Pixel> {
+        self.world.get_pixel(x, y)
+    }
+
+    pub fn set_pixel(&mut self, x: i32, y: i32, pixel: Pixel) {
+        self.history.touch(self.world, x, y);
+        self.world.set_pixel_full(x, y, pixel);
+    }
+
+    /// Pressure isn't part of undo history
+    pub fn add_pressure_at(&mut self, x: i32, y: i32, delta: f32) {
+        self.world.add_pressure_at(x, y, delta);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sunaba_core::simulation::MaterialId;
+
+    fn sandbox() -> World {
+        let mut world = World::new(false);
+        world.ensure_chunks_for_area(-128, -128, 128, 128);
+        world
+    }
+
+    fn material_at(world: &World, x: i32, y: i32) -> u16 {
+        world.get_pixel(x, y).unwrap().material_id
+    }
+
+    #[test]
+    fn test_undo_redo_restores_pixels_across_chunks() {
+        let mut world = sandbox();
+        let mut history = History::new();
+
+        let mut canvas = Canvas::new(&mut world, &mut history);
+        canvas.set_pixel(-1, 0, Pixel::new(MaterialId::SAND));
+        canvas.set_pixel(70, 5, Pixel::new(MaterialId::STONE));
+        assert!(history.commit(&world));
+        assert_eq!(history.undo[0].changes.len(), 2);
+
+        assert!(history.undo(&mut world));
+        assert_eq!(material_at(&world, -1, 0), MaterialId::AIR);
+        assert_eq!(material_at(&world, 70, 5), MaterialId::AIR);
+        assert!(!history.can_undo());
+
+        assert!(history.redo(&mut world));
+        assert_eq!(material_at(&world, -1, 0), MaterialId::SAND);
+        assert_eq!(material_at(&world, 70, 5), MaterialId::STONE);
+    }
+
+    #[test]
+    fn test_diff_keeps_only_changed_pixels() {
+        let mut world = sandbox();
+        let mut history = History::new();
+
+        let mut canvas = Canvas::new(&mut world, &mut history);
+        canvas.set_pixel(3, 3, Pixel::new(MaterialId::SAND));
+        canvas.set_pixel(4, 3, Pixel::AIR);
+        history.commit(&world);
+        assert_eq!(history.undo[0].changes.len(), 1);
+
+        // Rewriting the same pixels changes nothing
+        let mut canvas = Canvas::new(&mut world, &mut history);
+        canvas.set_pixel(3, 3, Pixel::new(MaterialId::SAND));
+        assert!(!history.commit(&world));
+        assert_eq!(history.undo.len(), 1);
+    }
+
+    #[test]
+    fn test_new_edit_clears_redo() {
+        let mut world = sandbox();
+        let mut history = History::new();
+
+        Canvas::new(&mut world, &mut history).set_pixel(0, 0, Pixel::new(MaterialId::SAND));
+        history.commit(&world);
+        history.undo(&mut world);
+        assert!(history.can_redo());
+
+        Canvas::new(&mut world, &mut history).set_pixel(1, 0, Pixel::new(MaterialId::WATER));
+        history.commit(&world);
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn test_only_written_pixels_are_recorded() {
+        let mut world = sandbox();
+        let mut history = History::new();
+
+        let mut canvas = Canvas::new(&mut world, &mut history);
+        canvas.set_pixel(3, 3, Pixel::new(MaterialId::SAND));
+        // Something else (e.g. the simulation) changes a pixel in the same chunk
+        world.set_pixel_full(10, 10, Pixel::new(MaterialId::WATER));
+        history.commit(&world);
+        assert_eq!(history.undo[0].changes.len(), 1);
+
+        history.undo(&mut world);
+        assert_eq!(material_at(&world, 3, 3), MaterialId::AIR);
+        assert_eq!(material_at(&world, 10, 10), MaterialId::WATER);
+    }
+}
@@ -5,7 +5,9 @@
 
 pub mod app;
 pub mod config;
+pub mod history;
 pub mod render;
+pub mod scene;
 pub mod tools;
 pub mod ui;
 
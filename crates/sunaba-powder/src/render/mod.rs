@@ -5,5 +5,6 @@ mod visualization;
 
 pub use renderer::Renderer;
 pub use visualization::{
-    get_visualization_overlay, light_to_color, pressure_to_color, temperature_to_color,
+    get_visualization_overlay, light_to_color, oxygen_to_color, pressure_to_color,
+    temperature_to_color,
 };
@@ -424,6 +424,16 @@ impl Renderer {
         (world_x as i32, world_y as i32)
     }
 
+    /// Convert world coordinates to screen coordinates (physical pixels)
+    pub fn world_to_screen(&self, world_x: f32, world_y: f32) -> (f32, f32) {
+        let ndc_x = (world_x - self.camera.position[0]) * self.camera.zoom / self.camera.aspect;
+        let ndc_y = (world_y - self.camera.position[1]) * self.camera.zoom;
+
+        let screen_x = (ndc_x + 1.0) / 2.0 * self.size.width as f32;
+        let screen_y = (1.0 - ndc_y) / 2.0 * self.size.height as f32;
+        (screen_x, screen_y)
+    }
+
     /// Update world texture from World state
     pub fn update_world_texture(&mut self, world: &World, materials: &Materials) {
         let world_size = self.world_texture_size as i32;
@@ -502,6 +512,7 @@ impl Renderer {
                         chunk.temperature[(local_y as usize / 8) * 8 + (local_x as usize / 8)];
                     let light_level =
                         chunk.light_levels[(local_y as usize) * CHUNK_SIZE + (local_x as usize)];
+                    let oxygen = chunk.get_oxygen_at(local_x as usize, local_y as usize);
 
                     if let Some(overlay) = get_visualization_overlay(
                         vis_mode,
@@ -509,6 +520,7 @@ impl Renderer {
                         pressure,
                         temperature,
                         light_level,
+                        oxygen,
                     ) {
                         // Blend overlay with material color using alpha
                         let alpha = overlay[3] as f32 / 255.0;
@@ -1,6 +1,6 @@
 //! Visualization modes for Powder Game demo
 //!
-//! Provides color conversion functions for pressure, temperature, and light visualization.
+//! Provides color conversion functions for pressure, temperature, light, and oxygen visualization.
 
 use crate::ui::VisualizationMode;
 
@@ -71,6 +71,13 @@ pub fn light_to_color(level: u8) -> [u8; 4] {
     [brightness, brightness, warm, 128]
 }
 
+/// Convert oxygen level (0.0-1.0) to RGBA color
+/// Fresh air = green, no oxygen = red
+pub fn oxygen_to_color(oxygen: f32) -> [u8; 4] {
+    let t = oxygen.clamp(0.0, 1.0);
+    [(255.0 * (1.0 - t)) as u8, (255.0 * t) as u8, 0, 128]
+}
+
 /// Get visualization overlay color for a pixel based on mode
 /// Returns Some(color) if overlay should be applied, None otherwise
 pub fn get_visualization_overlay(
@@ -79,6 +86,7 @@ pub fn get_visualization_overlay(
     pressure: f32,
     temperature: f32,
     light_level: u8,
+    oxygen: f32,
 ) -> Option<[u8; 4]> {
     use sunaba_core::simulation::MaterialId;
 
@@ -100,6 +108,14 @@ pub fn get_visualization_overlay(
             // Show light level for all materials
             Some(light_to_color(light_level))
         }
+        VisualizationMode::Oxygen => {
+            // Show oxygen for air, like pressure
+            if material_id == MaterialId::AIR {
+                Some(oxygen_to_color(oxygen))
+            } else {
+                None
+            }
+        }
     }
 }
 
@@ -145,4 +161,16 @@ mod tests {
         assert_eq!(bright[0], 255);
         assert_eq!(bright[1], 255);
     }
+
+    #[test]
+    fn test_oxygen_colors() {
+        let fresh = oxygen_to_color(1.0);
+        let stale = oxygen_to_color(0.0);
+
+        // Fresh air should be green, no oxygen red
+        assert_eq!(fresh[1], 255);
+        assert_eq!(fresh[0], 0);
+        assert_eq!(stale[0], 255);
+        assert_eq!(stale[1], 0);
+    }
 }
@@ -0,0 +1,440 @@
+//! Scene files for saving, loading and sharing sandbox drawings
+//!
+//! A scene holds the pixels (material and persistent flags such as powered
+//! wires and directions) and the coarse temperature and pressure fields of
+//! every chunk covering the sandbox. Untouched chunks (all air at default
+//! temperature and pressure) are left out.
+//!
+//! Encoding is bincode + lz4. The share string is that blob as URL-safe
+//! base64 behind `sunaba-scene:`, and a scene PNG is a thumbnail carrying the
+//! share string in a `tEXt` chunk, so the files in the gallery are the scenes.
+
+use anyhow::{Context, Result, anyhow, bail, ensure};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use bincode_next as bincode;
+use glam::IVec2;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use sunaba_core::simulation::Materials;
+use sunaba_core::world::{CHUNK_SIZE, Chunk, Pixel, World, pixel_flags};
+
+/// Current scene format version
+pub const SCENE_VERSION: u32 = 1;
+
+/// Directory the gallery saves scene PNGs to
+pub const SCENES_DIR: &str = "scenes";
+
+/// Side of a scene thumbnail in pixels
+pub const THUMBNAIL_SIZE: u32 = 128;
+
+const SHARE_PREFIX: &str = "sunaba-scene:";
+const PNG_TEXT_KEYWORD: &str = "sunaba-scene";
+
+/// Simulation bookkeeping, not part of a drawing
+pub(crate) const TRANSIENT_FLAGS: u16 = pixel_flags::UPDATED;
+
+/// Cells in a chunk's coarse temperature/pressure grid
+const COARSE_CELLS: usize = 64;
+
+/// Saved sandbox
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Scene {
+    pub version: u32,
+    /// Side of the square sandbox (pixels, centered on the origin)
+    pub world_size: u32,
+    /// Chunks that differ from a fresh chunk
+    pub chunks: Vec<SceneChunk>,
+}
+
+/// One chunk of a scene (row-major, like `Chunk`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SceneChunk {
+    pub x: i32,
+    pub y: i32,
+    pub materials: Vec<u16>,
+    pub flags: Vec<u16>,
+    /// Coarse 8x8 grid
+    pub temperature: Vec<f32>,
+    /// Coarse 8x8 grid
+    pub pressure: Vec<f32>,
+}
+
+impl SceneChunk {
+    fn capture(chunk: &Chunk, x: i32, y: i32) -> Option<Self> {
+        let blank = Chunk::new(x, y);
+        let untouched = chunk.pixels().iter().all(|p| p.is_empty())
+            && chunk.temperature == blank.temperature
+            && chunk.pressure == blank.pressure;
+        if untouched {
+            return None;
+        }
+
+        Some(Self {
+            x,
+            y,
+            materials: chunk.pixels().iter().map(|p| p.material_id).collect(),
+            flags: chunk
+                .pixels()
+                .iter()
+                .map(|p| p.flags & !TRANSIENT_FLAGS)
+                .collect(),
+            temperature: chunk.temperature.to_vec(),
+            pressure: chunk.pressure.to_vec(),
+        })
+    }
+
+    fn validate(&self) -> Result<()> {
+        let area = CHUNK_SIZE * CHUNK_SIZE;
+        ensure!(
+            self.materials.len() == area
+                && self.flags.len() == area
+                && self.temperature.len() == COARSE_CELLS
+                && self.pressure.len() == COARSE_CELLS,
+            "Malformed scene chunk ({}, {})",
+            self.x,
+            self.y
+        );
+        Ok(())
+    }
+}
+
+/// Chunk coordinates covering a sandbox of `world_size` pixels
+fn chunk_range(world_size: u32) -> std::ops::Range<i32> {
+    let half = (world_size / CHUNK_SIZE as u32) as i32 / 2;
+    -half..half
+}
+
+/// Largest bincode encoding of a scene with every chunk of the sandbox saved
+///
+/// Counts varint integers at their widest, so no genuine scene exceeds it.
+fn max_encoded_size(world_size: u32) -> usize {
+    let side = chunk_range(world_size).len();
+    let area = CHUNK_SIZE * CHUNK_SIZE;
+    // Coordinates, four vector lengths, u16 materials and flags, f32 fields
+    let chunk = 2 * 5 + 4 * 9 + area * 2 * 3 + COARSE_CELLS * 2 * 4;
+    // Version, world size and chunk count
+    2 * 5 + 9 + side * side * chunk
+}
+
+impl Scene {
+    /// Capture the sandbox from a world
+    pub fn capture(world: &World, world_size: u32) -> Self {
+        let range = chunk_range(world_size);
+        let mut chunks = Vec::new();
+        for y in range.clone() {
+            for x in range.clone() {
+                if let Some(chunk) = world.get_chunk(x, y)
+                    && let Some(scene_chunk) = SceneChunk::capture(chunk, x, y)
+                {
+                    chunks.push(scene_chunk);
+                }
+            }
+        }
+
+        Self {
+            version: SCENE_VERSION,
+            world_size,
+            chunks,
+        }
+    }
+
+    /// Replace the sandbox in `world` with this scene
+    ///
+    /// Chunks the scene leaves out are reset to air at default temperature
+    /// and pressure.
+    pub fn apply(&self, world: &mut World) {
+        let half_size = self.world_size as i32 / 2;
+        world.ensure_chunks_for_area(-half_size, -half_size, half_size, half_size);
+
+        let saved: HashMap<_, _> = self.chunks.iter().map(|c| ((c.x, c.y), c)).collect();
+        let range = chunk_range(self.world_size);
+        for y in range.clone() {
+            for x in range.clone() {
+                let Some(chunk) = world.chunks_mut().get_mut(&IVec2::new(x, y)) else {
+                    continue;
+                };
+                let blank = Chunk::new(x, y);
+
+                match saved.get(&(x, y)) {
+                    Some(saved) => {
+                        for (i, (&material_id, &flags)) in
+                            saved.materials.iter().zip(&saved.flags).enumerate()
+                        {
+                            let pixel = Pixel { material_id, flags };
+                            chunk.set_pixel(i % CHUNK_SIZE, i / CHUNK_SIZE, pixel);
+                        }
+                        chunk.temperature.copy_from_slice(&saved.temperature);
+                        chunk.pressure.copy_from_slice(&saved.pressure);
+                    }
+                    None => {
+                        for i in 0..CHUNK_SIZE * CHUNK_SIZE {
+                            chunk.set_pixel(i % CHUNK_SIZE, i / CHUNK_SIZE, Pixel::AIR);
+                        }
+                        chunk.temperature = blank.temperature;
+                        chunk.pressure = blank.pressure;
+                    }
+                }
+                chunk.set_simulation_active(true);
+            }
+        }
+    }
+
+    /// Compressed binary form
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let raw = bincode::serde::encode_to_vec(self, bincode::config::standard())
+            .map_err(|e| anyhow!("Failed to encode scene: {}", e))?;
+        Ok(lz4_flex::compress_prepend_size(&raw))
+    }
+
+    /// Decode the compressed binary form of a scene for a sandbox of
+    /// `world_size` pixels
+    pub fn decode(data: &[u8], world_size: u32) -> Result<Self> {
+        // Refuse to allocate whatever size a crafted blob declares
+        let (size, _) = lz4_flex::block::uncompressed_size(data).context("Corrupt scene data")?;
+        ensure!(
+            size <= max_encoded_size(world_size),
+            "Scene data is too large ({} bytes)",
+            size
+        );
+        let raw = lz4_flex::decompress_size_prepended(data).context("Corrupt scene data")?;
+        let (scene, _): (Scene, _) =
+            bincode::serde::decode_from_slice(&raw, bincode::config::standard())
+                .map_err(|e| anyhow!("Failed to decode scene: {}", e))?;
+
+        if scene.version > SCENE_VERSION {
+            bail!("Scene was saved by a newer version (v{})", scene.version);
+        }
+        ensure!(
+            scene.world_size == world_size,
+            "Scene is for a {}px sandbox, not {}px",
+            scene.world_size,
+            world_size
+        );
+        let range = chunk_range(world_size);
+        for chunk in &scene.chunks {
+            ensure!(
+                range.contains(&chunk.x) && range.contains(&chunk.y),
+                "Scene chunk ({}, {}) is outside the sandbox",
+                chunk.x,
+                chunk.y
+            );
+            chunk.validate()?;
+        }
+        Ok(scene)
+    }
+
+    /// Text form for pasting into chat or a bug report
+    pub fn to_share_string(&self) -> Result<String> {
+        Ok(format!(
+            "{}{}",
+            SHARE_PREFIX,
+            URL_SAFE_NO_PAD.encode(self.encode()?)
+        ))
+    }
+
+    /// Parse a share string for a sandbox of `world_size` pixels
+    /// (surrounding whitespace is ignored)
+    pub fn from_share_string(text: &str, world_size: u32) -> Result<Self> {
+        let Some(encoded) = text.trim().strip_prefix(SHARE_PREFIX) else {
+            bail!("Not a scene string (expected '{}...')", SHARE_PREFIX);
+        };
+        let data = URL_SAFE_NO_PAD
+            .decode(encoded)
+            .context("Invalid scene string")?;
+        Self::decode(&data, world_size)
+    }
+
+    /// RGBA thumbnail of `size`x`size` pixels (nearest-pixel, top row first)
+    pub fn thumbnail(&self, materials: &Materials, size: u32) -> Vec<u8> {
+        let saved: HashMap<_, _> = self.chunks.iter().map(|c| ((c.x, c.y), c)).collect();
+        let half_size = self.world_size as i32 / 2;
+        let chunk_size = CHUNK_SIZE as i32;
+        let step = self.world_size as f32 / size as f32;
+
+        let mut rgba = Vec::with_capacity((size * size * 4) as usize);
+        for row in 0..size {
+            // World Y points up, image rows go down
+            let world_y = half_size - 1 - (row as f32 * step) as i32;
+            for column in 0..size {
+                let world_x = -half_size + (column as f32 * step) as i32;
+                let chunk = saved.get(&(
+                    world_x.div_euclid(chunk_size),
+                    world_y.div_euclid(chunk_size),
+                ));
+                let material_id = chunk.map_or(0, |chunk| {
+                    let local_x = world_x.rem_euclid(chunk_size) as usize;
+                    let local_y = world_y.rem_euclid(chunk_size) as usize;
+                    chunk.materials[local_y * CHUNK_SIZE + local_x]
+                });
+                rgba.extend_from_slice(&materials.get(material_id).color);
+            }
+        }
+        rgba
+    }
+
+    /// Thumbnail PNG with the scene embedded
+    pub fn to_png(&self, materials: &Materials) -> Result<Vec<u8>> {
+        let mut png_data = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png_data, THUMBNAIL_SIZE, THUMBNAIL_SIZE);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.add_text_chunk(PNG_TEXT_KEYWORD.to_string(), self.to_share_string()?)?;
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.thumbnail(materials, THUMBNAIL_SIZE))?;
+        writer.finish()?;
+        Ok(png_data)
+    }
+
+    /// Read the scene embedded in a PNG written by `to_png`, for a sandbox
+    /// of `world_size` pixels
+    pub fn from_png(data: &[u8], world_size: u32) -> Result<Self> {
+        let reader = png::Decoder::new(Cursor::new(data)).read_info()?;
+        let Some(text) = reader
+            .info()
+            .uncompressed_latin1_text
+            .iter()
+            .find(|chunk| chunk.keyword == PNG_TEXT_KEYWORD)
+        else {
+            bail!("PNG has no embedded scene");
+        };
+        Self::from_share_string(&text.text, world_size)
+    }
+}
+
+/// Decoded RGBA pixels of a scene PNG: `(width, height, rgba)`
+pub fn read_png_thumbnail(data: &[u8]) -> Result<(u32, u32, Vec<u8>)> {
+    let mut reader = png::Decoder::new(Cursor::new(data)).read_info()?;
+    let mut rgba = vec![0; reader.output_buffer_size().context("PNG too large")?];
+    let info = reader.next_frame(&mut rgba)?;
+    ensure!(
+        info.color_type == png::ColorType::Rgba && info.bit_depth == png::BitDepth::Eight,
+        "Unsupported thumbnail format"
+    );
+    rgba.truncate(info.buffer_size());
+    Ok((info.width, info.height, rgba))
+}
+
+/// Scene PNGs in `dir`, newest first
+pub fn list_scene_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "png"))
+        .map(|entry| {
+            let modified = entry.metadata().and_then(|m| m.modified()).ok();
+            (modified, entry.path())
+        })
+        .collect();
+    files.sort_by(|a, b| b.0.cmp(&a.0));
+    files.into_iter().map(|(_, path)| path).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sunaba_core::simulation::MaterialId;
+
+    const WORLD_SIZE: u32 = 256;
+
+    fn sandbox() -> World {
+        let mut world = World::new(false);
+        let half_size = WORLD_SIZE as i32 / 2;
+        world.ensure_chunks_for_area(-half_size, -half_size, half_size, half_size);
+        world
+    }
+
+    #[test]
+    fn test_capture_skips_untouched_chunks() {
+        let mut world = sandbox();
+        assert!(Scene::capture(&world, WORLD_SIZE).chunks.is_empty());
+
+        world.set_pixel(5, 5, MaterialId::SAND);
+        let scene = Scene::capture(&world, WORLD_SIZE);
+        assert_eq!(scene.chunks.len(), 1);
+        assert_eq!((scene.chunks[0].x, scene.chunks[0].y), (0, 0));
+    }
+
+    #[test]
+    fn test_round_trip_keeps_pixels_flags_and_fields() {
+        let mut world = sandbox();
+        let wire = Pixel::new(MaterialId::DIODE).with_direction(2);
+        world.set_pixel_full(-10, 20, wire);
+        world.set_pixel(30, -40, MaterialId::WATER);
+        world
+            .chunks_mut()
+            .get_mut(&IVec2::new(0, -1))
+            .unwrap()
+            .set_temperature(1, 2, 300.0);
+
+        let scene = Scene::capture(&world, WORLD_SIZE);
+        let shared =
+            Scene::from_share_string(&scene.to_share_string().unwrap(), WORLD_SIZE).unwrap();
+        assert_eq!(shared, scene);
+
+        // Applying replaces what's there, including pixels outside the scene
+        let mut other = sandbox();
+        other.set_pixel(100, 100, MaterialId::STONE);
+        shared.apply(&mut other);
+        assert_eq!(other.get_pixel(-10, 20).unwrap().direction(), 2);
+        assert_eq!(
+            other.get_pixel(-10, 20).unwrap().material_id,
+            MaterialId::DIODE
+        );
+        assert_eq!(
+            other.get_pixel(30, -40).unwrap().material_id,
+            MaterialId::WATER
+        );
+        assert!(other.get_pixel(100, 100).unwrap().is_empty());
+        assert_eq!(other.get_chunk(0, -1).unwrap().get_temperature(1, 2), 300.0);
+    }
+
+    #[test]
+    fn test_png_embeds_scene() {
+        let mut world = sandbox();
+        world.set_pixel(0, 0, MaterialId::SAND);
+        let scene = Scene::capture(&world, WORLD_SIZE);
+        let materials = Materials::new();
+
+        let png_data = scene.to_png(&materials).unwrap();
+        assert_eq!(Scene::from_png(&png_data, WORLD_SIZE).unwrap(), scene);
+
+        let (width, height, rgba) = read_png_thumbnail(&png_data).unwrap();
+        assert_eq!((width, height), (THUMBNAIL_SIZE, THUMBNAIL_SIZE));
+        assert_eq!(rgba.len(), (THUMBNAIL_SIZE * THUMBNAIL_SIZE * 4) as usize);
+    }
+
+    #[test]
+    fn test_rejects_foreign_strings() {
+        assert!(Scene::from_share_string("hello", WORLD_SIZE).is_err());
+        assert!(Scene::from_share_string("sunaba-scene:!!!", WORLD_SIZE).is_err());
+    }
+
+    #[test]
+    fn test_rejects_scenes_that_dont_fit_the_sandbox() {
+        let mut world = sandbox();
+        world.set_pixel(0, 0, MaterialId::SAND);
+        let scene = Scene::capture(&world, WORLD_SIZE);
+        let data = scene.encode().unwrap();
+        assert!(Scene::decode(&data, WORLD_SIZE).is_ok());
+
+        // Saved for a different sandbox size
+        assert!(Scene::decode(&data, WORLD_SIZE * 2).is_err());
+
+        // Chunk outside the sandbox
+        let mut outside = scene.clone();
+        outside.chunks[0].x = 100;
+        assert!(Scene::decode(&outside.encode().unwrap(), WORLD_SIZE).is_err());
+
+        // Declared size far beyond a full scene, rejected before decompressing
+        let mut huge = data.clone();
+        huge[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        let error = Scene::decode(&huge, WORLD_SIZE).unwrap_err();
+        assert!(error.to_string().contains("too large"));
+    }
+}
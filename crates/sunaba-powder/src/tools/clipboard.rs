@@ -0,0 +1,253 @@
+//! Copy and paste of rectangular regions
+
+use glam::IVec2;
+
+use super::{Tool, ToolPreview};
+use crate::history::Canvas;
+use sunaba_core::world::{Pixel, World};
+
+/// Copied rectangle of pixels (row-major, row 0 at the bottom like world Y)
+#[derive(Clone, Debug)]
+pub struct Region {
+    width: u32,
+    height: u32,
+    pixels: Vec<Pixel>,
+}
+
+impl Region {
+    /// Copy the pixels between two inclusive corners (unloaded pixels are air)
+    pub fn capture(world: &World, min: IVec2, max: IVec2) -> Self {
+        let mut pixels = Vec::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                pixels.push(world.get_pixel(x, y).unwrap_or(Pixel::AIR));
+            }
+        }
+        Self {
+            width: (max.x - min.x + 1) as u32,
+            height: (max.y - min.y + 1) as u32,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Pixel at a position relative to the bottom-left corner
+    pub fn get(&self, x: u32, y: u32) -> Pixel {
+        self.pixels[(y * self.width + x) as usize]
+    }
+
+    /// Copy turned a quarter turn clockwise; directional pixels turn with it
+    pub fn rotated_cw(&self) -> Self {
+        let mut pixels = vec![Pixel::AIR; self.pixels.len()];
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let pixel = self.get(x, y);
+                // (x, y) -> (y, width - 1 - x), with the new width being our height
+                let index = (self.width - 1 - x) * self.height + y;
+                pixels[index as usize] = pixel.with_direction((pixel.direction() + 1) % 4);
+            }
+        }
+        Self {
+            width: self.height,
+            height: self.width,
+            pixels,
+        }
+    }
+
+    /// Bottom-left corner when centered on a position
+    fn origin(&self, x: i32, y: i32) -> IVec2 {
+        IVec2::new(x - self.width as i32 / 2, y - self.height as i32 / 2)
+    }
+}
+
+/// Tool that copies the dragged-out rectangle
+pub struct CopyTool {
+    start: Option<IVec2>,
+    end: IVec2,
+    copied: Option<Region>,
+}
+
+impl CopyTool {
+    /// Create a new copy tool
+    pub fn new() -> Self {
+        Self {
+            start: None,
+            end: IVec2::ZERO,
+            copied: None,
+        }
+    }
+
+    /// Region copied by the last stroke, if not taken yet
+    pub fn take_copied(&mut self) -> Option<Region> {
+        self.copied.take()
+    }
+}
+
+impl Default for CopyTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tool for CopyTool {
+    fn name(&self) -> &str {
+        "Copy"
+    }
+
+    fn begin(&mut self, _canvas: &mut Canvas, x: i32, y: i32, _brush_size: u32) {
+        self.start = Some(IVec2::new(x, y));
+        self.end = IVec2::new(x, y);
+    }
+
+    fn drag(&mut self, _canvas: &mut Canvas, x: i32, y: i32, _brush_size: u32) {
+        self.end = IVec2::new(x, y);
+    }
+
+    fn preview(&self, x: i32, y: i32, _brush_size: u32) -> Option<ToolPreview> {
+        let start = self.start?;
+        let end = IVec2::new(x, y);
+        Some(ToolPreview::Rect {
+            min: start.min(end),
+            max: start.max(end),
+        })
+    }
+
+    fn commit(&mut self, canvas: &mut Canvas, _brush_size: u32) {
+        if let Some(start) = self.start.take() {
+            let (min, max) = (start.min(self.end), start.max(self.end));
+            self.copied = Some(Region::capture(canvas.world(), min, max));
+        }
+    }
+
+    fn cancel(&mut self) {
+        self.start = None;
+    }
+}
+
+/// Tool that stamps the copied region centered on the cursor
+///
+/// Air in the region is transparent.
+pub struct PasteTool {
+    region: Option<Region>,
+}
+
+impl PasteTool {
+    /// Create a new paste tool with nothing to paste
+    pub fn new() -> Self {
+        Self { region: None }
+    }
+
+    /// Set the region to paste
+    pub fn set_region(&mut self, region: Region) {
+        self.region = Some(region);
+    }
+
+    /// Whether there is anything to paste
+    pub fn has_region(&self) -> bool {
+        self.region.is_some()
+    }
+
+    /// Turn the region a quarter turn clockwise
+    pub fn rotate(&mut self) {
+        if let Some(region) = &mut self.region {
+            *region = region.rotated_cw();
+        }
+    }
+}
+
+impl Default for PasteTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tool for PasteTool {
+    fn name(&self) -> &str {
+        "Paste"
+    }
+
+    fn begin(&mut self, canvas: &mut Canvas, x: i32, y: i32, _brush_size: u32) {
+        let Some(region) = &self.region else {
+            return;
+        };
+        let origin = region.origin(x, y);
+        for ry in 0..region.height {
+            for rx in 0..region.width {
+                let pixel = region.get(rx, ry);
+                if !pixel.is_empty() {
+                    canvas.set_pixel(origin.x + rx as i32, origin.y + ry as i32, pixel);
+                }
+            }
+        }
+    }
+
+    // One stamp per click
+    fn drag(&mut self, _canvas: &mut Canvas, _x: i32, _y: i32, _brush_size: u32) {}
+
+    fn preview(&self, x: i32, y: i32, _brush_size: u32) -> Option<ToolPreview> {
+        let region = self.region.as_ref()?;
+        let min = region.origin(x, y);
+        Some(ToolPreview::Rect {
+            min,
+            max: min + IVec2::new(region.width as i32 - 1, region.height as i32 - 1),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::History;
+    use sunaba_core::simulation::MaterialId;
+
+    #[test]
+    fn test_rotation_turns_pixels_and_directions() {
+        let mut world = World::new(false);
+        world.ensure_chunks_for_area(-64, -64, 64, 64);
+        // 3x2 region: diode pointing up in the bottom-right corner
+        world.set_pixel_full(2, 0, Pixel::new(MaterialId::DIODE).with_direction(0));
+        world.set_pixel(0, 1, MaterialId::STONE);
+
+        let region = Region::capture(&world, IVec2::new(0, 0), IVec2::new(2, 1));
+        let rotated = region.rotated_cw();
+        assert_eq!((rotated.width(), rotated.height()), (2, 3));
+        // Bottom-right goes to bottom-left, top-left goes to top-right
+        assert_eq!(rotated.get(0, 0).material_id, MaterialId::DIODE);
+        assert_eq!(rotated.get(0, 0).direction(), 1);
+        assert_eq!(rotated.get(1, 2).material_id, MaterialId::STONE);
+
+        let full_turn = rotated.rotated_cw().rotated_cw().rotated_cw();
+        assert_eq!(full_turn.get(2, 0).direction(), 0);
+        assert_eq!(full_turn.get(0, 1).material_id, MaterialId::STONE);
+    }
+
+    #[test]
+    fn test_paste_skips_air() {
+        let mut world = World::new(false);
+        world.ensure_chunks_for_area(-64, -64, 64, 64);
+        world.set_pixel(0, 0, MaterialId::SAND);
+        world.set_pixel(11, 10, MaterialId::WATER);
+
+        let mut paste = PasteTool::new();
+        paste.set_region(Region::capture(&world, IVec2::new(0, 0), IVec2::new(1, 0)));
+        let mut history = History::new();
+        paste.begin(&mut Canvas::new(&mut world, &mut history), 11, 10, 1);
+
+        // 2x1 region centered on (11, 10) starts at (10, 10)
+        assert_eq!(
+            world.get_pixel(10, 10).unwrap().material_id,
+            MaterialId::SAND
+        );
+        assert_eq!(
+            world.get_pixel(11, 10).unwrap().material_id,
+            MaterialId::WATER
+        );
+    }
+}
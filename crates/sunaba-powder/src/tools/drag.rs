@@ -1,7 +1,9 @@
 //! Drag tool for moving pixels around
 
+use super::Tool;
+use crate::history::Canvas;
 use sunaba_core::simulation::MaterialId;
-use sunaba_core::world::World;
+use sunaba_core::world::Pixel;
 
 /// Drag tool that moves pixels by dragging
 pub struct DragTool {
@@ -31,7 +33,7 @@ impl DragTool {
     }
 
     /// Apply drag movement to pixels
-    pub fn apply_drag(&mut self, world: &mut World, x: i32, y: i32, brush_size: u32) {
+    pub fn apply_drag(&mut self, canvas: &mut Canvas, x: i32, y: i32, brush_size: u32) {
         if let Some((last_x, last_y)) = self.last_pos {
             let dx = x - last_x;
             let dy = y - last_y;
@@ -53,11 +55,11 @@ impl DragTool {
                             let dst_x = src_x + dx;
                             let dst_y = src_y + dy;
 
-                            if let Some(pixel) = world.get_pixel(src_x, src_y)
+                            if let Some(pixel) = canvas.get_pixel(src_x, src_y)
                                 && pixel.material_id != MaterialId::AIR
                             {
                                 // Check destination is air
-                                if let Some(dst_pixel) = world.get_pixel(dst_x, dst_y)
+                                if let Some(dst_pixel) = canvas.get_pixel(dst_x, dst_y)
                                     && dst_pixel.material_id == MaterialId::AIR
                                 {
                                     moves.push((src_x, src_y, dst_x, dst_y, pixel.material_id));
@@ -69,8 +71,8 @@ impl DragTool {
 
                 // Apply moves
                 for (src_x, src_y, dst_x, dst_y, material_id) in moves {
-                    world.set_pixel(dst_x, dst_y, material_id);
-                    world.set_pixel(src_x, src_y, MaterialId::AIR);
+                    canvas.set_pixel(dst_x, dst_y, Pixel::new(material_id));
+                    canvas.set_pixel(src_x, src_y, Pixel::AIR);
                 }
             }
         }
@@ -80,6 +82,28 @@ impl DragTool {
     }
 }
 
+impl Tool for DragTool {
+    fn name(&self) -> &str {
+        "Drag"
+    }
+
+    fn begin(&mut self, _canvas: &mut Canvas, x: i32, y: i32, _brush_size: u32) {
+        self.start_drag(x, y);
+    }
+
+    fn drag(&mut self, canvas: &mut Canvas, x: i32, y: i32, brush_size: u32) {
+        self.apply_drag(canvas, x, y, brush_size);
+    }
+
+    fn commit(&mut self, _canvas: &mut Canvas, _brush_size: u32) {
+        self.end_drag();
+    }
+
+    fn cancel(&mut self) {
+        self.end_drag();
+    }
+}
+
 impl Default for DragTool {
     fn default() -> Self {
         Self::new()
@@ -1,8 +1,8 @@
 //! Eraser tool
 
 use super::{Tool, draw_circle};
+use crate::history::Canvas;
 use sunaba_core::simulation::MaterialId;
-use sunaba_core::world::World;
 
 /// Eraser tool that places AIR
 pub struct EraseTool;
@@ -12,7 +12,7 @@ impl Tool for EraseTool {
         "Eraser"
     }
 
-    fn apply(&self, world: &mut World, x: i32, y: i32, brush_size: u32) {
-        draw_circle(world, x, y, brush_size, MaterialId::AIR);
+    fn apply(&self, canvas: &mut Canvas, x: i32, y: i32, brush_size: u32) {
+        draw_circle(canvas, x, y, brush_size, MaterialId::AIR);
     }
 }
@@ -0,0 +1,104 @@
+//! Flood fill tool
+
+use glam::IVec2;
+use std::collections::{HashSet, VecDeque};
+
+use super::Tool;
+use crate::history::Canvas;
+use sunaba_core::world::Pixel;
+
+/// Most pixels one fill changes (stops runaway fills past the sandbox)
+pub const MAX_FILL_PIXELS: usize = 1 << 20;
+
+/// Tool that replaces the clicked area of one material with another
+pub struct FillTool {
+    pixel: Pixel,
+}
+
+impl FillTool {
+    /// Create a new fill tool
+    pub fn new() -> Self {
+        Self { pixel: Pixel::AIR }
+    }
+
+    /// Set the pixel (material and direction) to fill with
+    pub fn set_pixel(&mut self, pixel: Pixel) {
+        self.pixel = pixel;
+    }
+}
+
+impl Default for FillTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tool for FillTool {
+    fn name(&self) -> &str {
+        "Fill"
+    }
+
+    fn begin(&mut self, canvas: &mut Canvas, x: i32, y: i32, _brush_size: u32) {
+        let Some(target) = canvas.get_pixel(x, y) else {
+            return;
+        };
+        if target.material_id == self.pixel.material_id {
+            return;
+        }
+
+        let region = flood_region(IVec2::new(x, y), |point| {
+            canvas
+                .get_pixel(point.x, point.y)
+                .is_some_and(|pixel| pixel.material_id == target.material_id)
+        });
+        for point in region {
+            canvas.set_pixel(point.x, point.y, self.pixel);
+        }
+    }
+
+    // One fill per click
+    fn drag(&mut self, _canvas: &mut Canvas, _x: i32, _y: i32, _brush_size: u32) {}
+}
+
+/// Pixels 4-connected to `start` for which `matches` holds
+///
+/// Stops after `MAX_FILL_PIXELS`.
+pub fn flood_region(start: IVec2, matches: impl Fn(IVec2) -> bool) -> Vec<IVec2> {
+    let mut region = Vec::new();
+    if !matches(start) {
+        return region;
+    }
+
+    let mut visited = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(point) = queue.pop_front() {
+        region.push(point);
+        if region.len() >= MAX_FILL_PIXELS {
+            break;
+        }
+        for offset in [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y] {
+            let next = point + offset;
+            if !visited.contains(&next) && matches(next) {
+                visited.insert(next);
+                queue.push_back(next);
+            }
+        }
+    }
+    region
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flood_region_stays_inside_walls() {
+        // 5x5 box with walls on its border
+        let inside = |p: IVec2| p.x > 0 && p.x < 4 && p.y > 0 && p.y < 4;
+        let region = flood_region(IVec2::new(2, 2), inside);
+        assert_eq!(region.len(), 9);
+        assert!(region.iter().all(|&p| inside(p)));
+
+        assert!(flood_region(IVec2::new(0, 0), inside).is_empty());
+    }
+}
@@ -1,37 +1,212 @@
 //! Tools module for Powder Game demo
 
+mod clipboard;
 mod drag;
 mod erase;
+mod fill;
 mod pen;
+mod replace;
+mod shapes;
 mod wind;
 
+pub use clipboard::{CopyTool, PasteTool, Region};
 pub use drag::DragTool;
 pub use erase::EraseTool;
+pub use fill::{FillTool, MAX_FILL_PIXELS, flood_region};
 pub use pen::PenTool;
+pub use replace::ReplaceTool;
+pub use shapes::{Shape, ShapeTool, ellipse_points, line_points, rect_points};
 pub use wind::WindTool;
 
-use sunaba_core::world::World;
+use glam::IVec2;
+use sunaba_core::world::Pixel;
+
+use crate::history::Canvas;
+use crate::ui::{ActiveTool, ToolbarState};
+
+/// Outline drawn over the world while a tool is in use
+///
+/// Coordinates are world pixels; `min`/`max` corners are inclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToolPreview {
+    /// Line stroked with a round brush of `radius`
+    Line {
+        from: IVec2,
+        to: IVec2,
+        radius: u32,
+    },
+    Rect {
+        min: IVec2,
+        max: IVec2,
+    },
+    Ellipse {
+        min: IVec2,
+        max: IVec2,
+    },
+}
 
 /// Trait for drawing tools
+///
+/// A stroke calls `begin` when a mouse button is pressed, `drag` every frame
+/// while it is held and `commit` when it is released (`cancel` instead if the
+/// cursor leaves the window). Brushes paint with `apply` as they go; shape
+/// tools follow the cursor with a `preview` and write everything in `commit`.
 pub trait Tool {
     /// Tool display name
     fn name(&self) -> &str;
 
     /// Apply tool at position with given brush size
-    fn apply(&self, world: &mut World, x: i32, y: i32, brush_size: u32);
+    fn apply(&self, _canvas: &mut Canvas, _x: i32, _y: i32, _brush_size: u32) {}
+
+    /// Start a stroke at position
+    fn begin(&mut self, canvas: &mut Canvas, x: i32, y: i32, brush_size: u32) {
+        self.apply(canvas, x, y, brush_size);
+    }
+
+    /// Continue the stroke to position
+    fn drag(&mut self, canvas: &mut Canvas, x: i32, y: i32, brush_size: u32) {
+        self.apply(canvas, x, y, brush_size);
+    }
+
+    /// Outline of what the tool would write with the cursor at position
+    fn preview(&self, _x: i32, _y: i32, _brush_size: u32) -> Option<ToolPreview> {
+        None
+    }
+
+    /// Finish the stroke
+    fn commit(&mut self, _canvas: &mut Canvas, _brush_size: u32) {}
+
+    /// Abandon the stroke without writing anything further
+    fn cancel(&mut self) {}
 }
 
-/// Draw a filled circle of pixels
-pub fn draw_circle(world: &mut World, center_x: i32, center_y: i32, radius: u32, material_id: u16) {
-    let r = radius as i32;
+/// One of each tool, kept in sync with the toolbar's materials
+pub struct ToolBox {
+    pub left_pen: PenTool,
+    pub right_pen: PenTool,
+    pub eraser: EraseTool,
+    pub wind: WindTool,
+    pub drag: DragTool,
+    pub line: ShapeTool,
+    pub rect: ShapeTool,
+    pub ellipse: ShapeTool,
+    pub fill: FillTool,
+    pub replace: ReplaceTool,
+    pub copy: CopyTool,
+    pub paste: PasteTool,
+    left: Pixel,
+    right: Pixel,
+}
+
+impl ToolBox {
+    /// Create the tools for the toolbar's initial state
+    pub fn new(state: &ToolbarState) -> Self {
+        let mut tools = Self {
+            left_pen: PenTool::new(state.left_material),
+            right_pen: PenTool::new(state.right_material),
+            eraser: EraseTool,
+            wind: WindTool::new(),
+            drag: DragTool::new(),
+            line: ShapeTool::new(Shape::Line),
+            rect: ShapeTool::new(Shape::Rect),
+            ellipse: ShapeTool::new(Shape::Ellipse),
+            fill: FillTool::new(),
+            replace: ReplaceTool::new(),
+            copy: CopyTool::new(),
+            paste: PasteTool::new(),
+            left: Pixel::AIR,
+            right: Pixel::AIR,
+        };
+        tools.sync(state);
+        tools
+    }
+
+    /// Update materials, direction and shape fill from the toolbar
+    pub fn sync(&mut self, state: &ToolbarState) {
+        self.left_pen.set_material(state.left_material);
+        self.right_pen.set_material(state.right_material);
+        self.left_pen.set_direction(state.direction);
+        self.right_pen.set_direction(state.direction);
 
-    for dy in -r..=r {
-        for dx in -r..=r {
-            if dx * dx + dy * dy <= r * r {
-                let x = center_x + dx;
-                let y = center_y + dy;
-                world.set_pixel(x, y, material_id);
+        self.left = Pixel::new(state.left_material).with_direction(state.direction);
+        self.right = Pixel::new(state.right_material).with_direction(state.direction);
+        self.rect.set_filled(state.shape_filled);
+        self.ellipse.set_filled(state.shape_filled);
+    }
+
+    /// Tool for a stroke of the left button (or the right, if `secondary`)
+    ///
+    /// Material tools draw that button's material; the replace brush turns
+    /// the other button's material into it.
+    pub fn get_mut(&mut self, tool: ActiveTool, secondary: bool) -> &mut dyn Tool {
+        let (pixel, other) = if secondary {
+            (self.right, self.left)
+        } else {
+            (self.left, self.right)
+        };
+
+        match tool {
+            ActiveTool::Pen if secondary => &mut self.right_pen,
+            ActiveTool::Pen => &mut self.left_pen,
+            ActiveTool::Eraser => &mut self.eraser,
+            ActiveTool::Wind => &mut self.wind,
+            ActiveTool::Drag => &mut self.drag,
+            ActiveTool::Line => {
+                self.line.set_pixel(pixel);
+                &mut self.line
+            }
+            ActiveTool::Rect => {
+                self.rect.set_pixel(pixel);
+                &mut self.rect
             }
+            ActiveTool::Ellipse => {
+                self.ellipse.set_pixel(pixel);
+                &mut self.ellipse
+            }
+            ActiveTool::Fill => {
+                self.fill.set_pixel(pixel);
+                &mut self.fill
+            }
+            ActiveTool::Replace => {
+                self.replace.set_materials(other.material_id, pixel);
+                &mut self.replace
+            }
+            ActiveTool::Copy => &mut self.copy,
+            ActiveTool::Paste => &mut self.paste,
         }
     }
 }
+
+/// Pixels in a filled circle
+pub fn brush_points(center_x: i32, center_y: i32, radius: u32) -> impl Iterator<Item = (i32, i32)> {
+    let r = radius as i32;
+    (-r..=r).flat_map(move |dy| {
+        (-r..=r)
+            .filter(move |dx| dx * dx + dy * dy <= r * r)
+            .map(move |dx| (center_x + dx, center_y + dy))
+    })
+}
+
+/// Draw a filled circle of pixels
+pub fn draw_circle(
+    canvas: &mut Canvas,
+    center_x: i32,
+    center_y: i32,
+    radius: u32,
+    material_id: u16,
+) {
+    draw_circle_pixel(canvas, center_x, center_y, radius, Pixel::new(material_id));
+}
+
+/// Draw a filled circle of a full pixel (material and flags, e.g. direction bits)
+pub fn draw_circle_pixel(
+    canvas: &mut Canvas,
+    center_x: i32,
+    center_y: i32,
+    radius: u32,
+    pixel: Pixel,
+) {
+    for (x, y) in brush_points(center_x, center_y, radius) {
+        canvas.set_pixel(x, y, pixel);
+    }
+}
@@ -1,17 +1,28 @@
 //! Pen tool for drawing materials
 
-use super::{Tool, draw_circle};
-use sunaba_core::world::World;
+use super::{Tool, draw_circle_pixel};
+use crate::history::Canvas;
+use sunaba_core::world::Pixel;
 
 /// Pen tool that draws a specific material
 pub struct PenTool {
     material_id: u16,
+    /// Orientation for directional materials (0 = up, 1 = right, 2 = down, 3 = left)
+    direction: u8,
 }
 
 impl PenTool {
     /// Create a new pen tool for the given material
     pub fn new(material_id: u16) -> Self {
-        Self { material_id }
+        Self {
+            material_id,
+            direction: 0,
+        }
+    }
+
+    /// Set the orientation written into the DIRECTION bits
+    pub fn set_direction(&mut self, direction: u8) {
+        self.direction = direction;
     }
 
     /// Set the material this pen draws
@@ -30,7 +41,8 @@ impl Tool for PenTool {
         "Pen"
     }
 
-    fn apply(&self, world: &mut World, x: i32, y: i32, brush_size: u32) {
-        draw_circle(world, x, y, brush_size, self.material_id);
+    fn apply(&self, canvas: &mut Canvas, x: i32, y: i32, brush_size: u32) {
+        let pixel = Pixel::new(self.material_id).with_direction(self.direction);
+        draw_circle_pixel(canvas, x, y, brush_size, pixel);
     }
 }
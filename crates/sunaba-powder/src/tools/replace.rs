@@ -0,0 +1,53 @@
+//! Replace brush
+
+use super::{Tool, brush_points};
+use crate::history::Canvas;
+use sunaba_core::world::Pixel;
+
+/// Brush that turns one material into another, leaving everything else alone
+pub struct ReplaceTool {
+    from: u16,
+    to: Pixel,
+}
+
+impl ReplaceTool {
+    /// Create a new replace brush
+    pub fn new() -> Self {
+        Self {
+            from: 0,
+            to: Pixel::AIR,
+        }
+    }
+
+    /// Replace `from` with `to`
+    pub fn set_materials(&mut self, from: u16, to: Pixel) {
+        self.from = from;
+        self.to = to;
+    }
+}
+
+impl Default for ReplaceTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tool for ReplaceTool {
+    fn name(&self) -> &str {
+        "Replace"
+    }
+
+    fn apply(&self, canvas: &mut Canvas, x: i32, y: i32, brush_size: u32) {
+        if self.from == self.to.material_id {
+            return;
+        }
+        for (x, y) in brush_points(x, y, brush_size) {
+            if canvas
+                .get_pixel(x, y)
+                .is_some_and(|pixel| pixel.material_id == self.from)
+            {
+                canvas.set_pixel(x, y, self.to);
+            }
+        }
+    }
+}
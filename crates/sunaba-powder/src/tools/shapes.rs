@@ -0,0 +1,228 @@
+//! Line, rectangle and ellipse tools
+//!
+//! A shape is dragged out from where the button was pressed to the cursor
+//! and only written when the button is released.
+
+use glam::{IVec2, Vec2};
+
+use super::{Tool, ToolPreview, draw_circle_pixel};
+use crate::history::Canvas;
+use sunaba_core::world::Pixel;
+
+/// Shape drawn by a `ShapeTool`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shape {
+    Line,
+    Rect,
+    Ellipse,
+}
+
+/// Tool that drags out a shape of one material
+pub struct ShapeTool {
+    shape: Shape,
+    pixel: Pixel,
+    /// Rectangles and ellipses are filled instead of outlined
+    filled: bool,
+    /// Where the stroke started
+    start: Option<IVec2>,
+    /// Latest cursor position of the stroke
+    end: IVec2,
+}
+
+impl ShapeTool {
+    /// Create a new tool for the given shape
+    pub fn new(shape: Shape) -> Self {
+        Self {
+            shape,
+            pixel: Pixel::AIR,
+            filled: false,
+            start: None,
+            end: IVec2::ZERO,
+        }
+    }
+
+    /// Set the pixel (material and direction) the shape is drawn with
+    pub fn set_pixel(&mut self, pixel: Pixel) {
+        self.pixel = pixel;
+    }
+
+    /// Fill rectangles and ellipses instead of outlining them
+    pub fn set_filled(&mut self, filled: bool) {
+        self.filled = filled;
+    }
+
+    /// Get the shape
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+}
+
+impl Tool for ShapeTool {
+    fn name(&self) -> &str {
+        match self.shape {
+            Shape::Line => "Line",
+            Shape::Rect => "Rectangle",
+            Shape::Ellipse => "Ellipse",
+        }
+    }
+
+    fn begin(&mut self, _canvas: &mut Canvas, x: i32, y: i32, _brush_size: u32) {
+        self.start = Some(IVec2::new(x, y));
+        self.end = IVec2::new(x, y);
+    }
+
+    fn drag(&mut self, _canvas: &mut Canvas, x: i32, y: i32, _brush_size: u32) {
+        self.end = IVec2::new(x, y);
+    }
+
+    fn preview(&self, x: i32, y: i32, brush_size: u32) -> Option<ToolPreview> {
+        let start = self.start?;
+        let end = IVec2::new(x, y);
+        Some(match self.shape {
+            Shape::Line => ToolPreview::Line {
+                from: start,
+                to: end,
+                radius: brush_size,
+            },
+            Shape::Rect => ToolPreview::Rect {
+                min: start.min(end),
+                max: start.max(end),
+            },
+            Shape::Ellipse => ToolPreview::Ellipse {
+                min: start.min(end),
+                max: start.max(end),
+            },
+        })
+    }
+
+    fn commit(&mut self, canvas: &mut Canvas, brush_size: u32) {
+        let Some(start) = self.start.take() else {
+            return;
+        };
+        let (min, max) = (start.min(self.end), start.max(self.end));
+
+        let points = match self.shape {
+            Shape::Line => {
+                for point in line_points(start, self.end) {
+                    draw_circle_pixel(canvas, point.x, point.y, brush_size, self.pixel);
+                }
+                return;
+            }
+            Shape::Rect => rect_points(min, max, brush_size, self.filled),
+            Shape::Ellipse => ellipse_points(min, max, brush_size, self.filled),
+        };
+        for point in points {
+            canvas.set_pixel(point.x, point.y, self.pixel);
+        }
+    }
+
+    fn cancel(&mut self) {
+        self.start = None;
+    }
+}
+
+/// Pixels on the line from `from` to `to` (Bresenham, both ends included)
+pub fn line_points(from: IVec2, to: IVec2) -> Vec<IVec2> {
+    let delta = (to - from).abs();
+    let step = (to - from).signum();
+    let mut error = delta.x - delta.y;
+    let mut point = from;
+    let mut points = Vec::with_capacity(delta.max_element() as usize + 1);
+
+    loop {
+        points.push(point);
+        if point == to {
+            return points;
+        }
+        let doubled = error * 2;
+        if doubled > -delta.y {
+            error -= delta.y;
+            point.x += step.x;
+        }
+        if doubled < delta.x {
+            error += delta.x;
+            point.y += step.y;
+        }
+    }
+}
+
+/// Pixels of the rectangle between two inclusive corners, either filled or
+/// outlined `thickness` pixels deep
+pub fn rect_points(min: IVec2, max: IVec2, thickness: u32, filled: bool) -> Vec<IVec2> {
+    let thickness = thickness.max(1) as i32;
+    let mut points = Vec::new();
+    for y in min.y..=max.y {
+        for x in min.x..=max.x {
+            let depth = (x - min.x).min(max.x - x).min(y - min.y).min(max.y - y);
+            if filled || depth < thickness {
+                points.push(IVec2::new(x, y));
+            }
+        }
+    }
+    points
+}
+
+/// Pixels of the ellipse inscribed in the rectangle between two inclusive
+/// corners, either filled or outlined `thickness` pixels deep
+pub fn ellipse_points(min: IVec2, max: IVec2, thickness: u32, filled: bool) -> Vec<IVec2> {
+    let center = (min + max).as_vec2() / 2.0;
+    let radii = (max - min).as_vec2() / 2.0 + 0.5;
+    let inner = radii - thickness.max(1) as f32;
+    let inside = |offset: Vec2, radii: Vec2| {
+        radii.x > 0.0 && radii.y > 0.0 && (offset / radii).length_squared() <= 1.0
+    };
+
+    let mut points = Vec::new();
+    for y in min.y..=max.y {
+        for x in min.x..=max.x {
+            let offset = IVec2::new(x, y).as_vec2() - center;
+            if inside(offset, radii) && (filled || !inside(offset, inner)) {
+                points.push(IVec2::new(x, y));
+            }
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_includes_both_ends() {
+        let points = line_points(IVec2::new(0, 0), IVec2::new(5, -2));
+        assert_eq!(points.first(), Some(&IVec2::new(0, 0)));
+        assert_eq!(points.last(), Some(&IVec2::new(5, -2)));
+        assert_eq!(points.len(), 6);
+
+        assert_eq!(
+            line_points(IVec2::new(3, 3), IVec2::new(3, 3)),
+            vec![IVec2::new(3, 3)]
+        );
+    }
+
+    #[test]
+    fn test_rect_outline_and_fill() {
+        let (min, max) = (IVec2::new(0, 0), IVec2::new(4, 3));
+        assert_eq!(rect_points(min, max, 1, true).len(), 20);
+
+        let outline = rect_points(min, max, 1, false);
+        assert_eq!(outline.len(), 14);
+        assert!(!outline.contains(&IVec2::new(2, 1)));
+    }
+
+    #[test]
+    fn test_ellipse_fits_its_rectangle() {
+        let (min, max) = (IVec2::new(-10, -5), IVec2::new(10, 5));
+        let filled = ellipse_points(min, max, 1, true);
+        assert!(filled.contains(&IVec2::new(0, 0)));
+        assert!(filled.contains(&IVec2::new(-10, 0)));
+        assert!(filled.contains(&IVec2::new(0, 5)));
+        assert!(!filled.contains(&IVec2::new(-10, -5)));
+
+        let outline = ellipse_points(min, max, 2, false);
+        assert!(outline.contains(&IVec2::new(10, 0)));
+        assert!(!outline.contains(&IVec2::new(0, 0)));
+        assert!(outline.len() < filled.len());
+    }
+}
@@ -1,7 +1,7 @@
 //! Wind tool for creating pressure areas
 
 use super::Tool;
-use sunaba_core::world::World;
+use crate::history::Canvas;
 
 /// Wind tool that adds pressure to an area
 pub struct WindTool {
@@ -42,7 +42,7 @@ impl Tool for WindTool {
         "Wind"
     }
 
-    fn apply(&self, world: &mut World, x: i32, y: i32, brush_size: u32) {
+    fn apply(&self, canvas: &mut Canvas, x: i32, y: i32, brush_size: u32) {
         // Apply pressure in brush area
         // The coarse grid mapping (8x8) happens inside add_pressure_at()
         let r = brush_size as i32;
@@ -50,7 +50,7 @@ impl Tool for WindTool {
         for dy in -r..=r {
             for dx in -r..=r {
                 if dx * dx + dy * dy <= r * r {
-                    world.add_pressure_at(x + dx, y + dy, self.strength);
+                    canvas.add_pressure_at(x + dx, y + dy, self.strength);
                 }
             }
         }
@@ -0,0 +1,189 @@
+//! Scene gallery for the toolbar
+//!
+//! Lists the scene PNGs saved in `scenes/` as clickable thumbnails, plus
+//! controls to save the current sandbox and to copy or paste share strings.
+//! Buttons only record requests; the app performs them after the frame.
+
+use egui::{Color32, TextureHandle, TextureOptions, Vec2};
+use std::path::{Path, PathBuf};
+
+use crate::scene::{list_scene_files, read_png_thumbnail};
+
+/// Thumbnail edge length in the toolbar (points)
+const THUMBNAIL_DISPLAY_SIZE: f32 = 88.0;
+
+struct GalleryEntry {
+    path: PathBuf,
+    name: String,
+    thumbnail: Option<TextureHandle>,
+}
+
+/// Saved scenes and the requests made through them this frame
+pub struct SceneGallery {
+    dir: PathBuf,
+    entries: Vec<GalleryEntry>,
+    needs_refresh: bool,
+
+    /// Name for the next save (empty = timestamp)
+    pub save_name: String,
+    /// Share string being pasted, or the one just copied
+    pub share_text: String,
+    /// Result of the last gallery action
+    pub status: Option<String>,
+
+    pub save_requested: bool,
+    pub copy_share_requested: bool,
+    pub load_share_requested: bool,
+    pub load_requested: Option<PathBuf>,
+    pub delete_requested: Option<PathBuf>,
+}
+
+impl SceneGallery {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            entries: Vec::new(),
+            needs_refresh: true,
+            save_name: String::new(),
+            share_text: String::new(),
+            status: None,
+            save_requested: false,
+            copy_share_requested: false,
+            load_share_requested: false,
+            load_requested: None,
+            delete_requested: None,
+        }
+    }
+
+    /// Directory scenes are saved to
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Re-read the scene directory on the next frame
+    pub fn refresh(&mut self) {
+        self.needs_refresh = true;
+    }
+
+    /// Clear the requests handled by the app
+    pub fn reset_flags(&mut self) {
+        self.save_requested = false;
+        self.copy_share_requested = false;
+        self.load_share_requested = false;
+        self.load_requested = None;
+        self.delete_requested = None;
+    }
+
+    fn reload(&mut self, ctx: &egui::Context) {
+        self.entries = list_scene_files(&self.dir)
+            .into_iter()
+            .map(|path| {
+                let name = path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let thumbnail = std::fs::read(&path)
+                    .ok()
+                    .and_then(|data| read_png_thumbnail(&data).ok())
+                    .map(|(width, height, rgba)| {
+                        let image = egui::ColorImage::from_rgba_unmultiplied(
+                            [width as usize, height as usize],
+                            &rgba,
+                        );
+                        ctx.load_texture(format!("scene_{}", name), image, TextureOptions::NEAREST)
+                    });
+                GalleryEntry {
+                    path,
+                    name,
+                    thumbnail,
+                }
+            })
+            .collect();
+        self.needs_refresh = false;
+    }
+
+    /// Show the gallery controls and thumbnails
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        if self.needs_refresh {
+            self.reload(ui.ctx());
+        }
+
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.save_name)
+                    .hint_text("Scene name")
+                    .desired_width(140.0),
+            );
+            if ui.button("Save").clicked() {
+                self.save_requested = true;
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.share_text)
+                    .hint_text("sunaba-scene:...")
+                    .desired_width(140.0),
+            );
+            if ui
+                .button("Copy")
+                .on_hover_text("Copy the current sandbox as a share string")
+                .clicked()
+            {
+                self.copy_share_requested = true;
+            }
+            if ui
+                .button("Paste")
+                .on_hover_text("Load the share string in the text box")
+                .clicked()
+            {
+                self.load_share_requested = true;
+            }
+        });
+
+        if let Some(status) = &self.status {
+            ui.label(status);
+        }
+
+        if self.entries.is_empty() {
+            ui.weak(format!("No saved scenes in {}/", self.dir.display()));
+            return;
+        }
+
+        let columns = 3;
+        egui::Grid::new("scene_gallery")
+            .num_columns(columns)
+            .spacing([4.0, 4.0])
+            .show(ui, |ui| {
+                for (i, entry) in self.entries.iter().enumerate() {
+                    ui.vertical(|ui| {
+                        let size = Vec2::splat(THUMBNAIL_DISPLAY_SIZE);
+                        let response = match &entry.thumbnail {
+                            Some(texture) => {
+                                ui.add(egui::Button::image(egui::Image::new((texture.id(), size))))
+                            }
+                            None => ui.add_sized(size, egui::Button::new("?")),
+                        };
+                        if response.clicked() {
+                            self.load_requested = Some(entry.path.clone());
+                        }
+                        response.context_menu(|ui| {
+                            if ui.button("Delete").clicked() {
+                                self.delete_requested = Some(entry.path.clone());
+                                ui.close();
+                            }
+                        });
+                        ui.label(
+                            egui::RichText::new(&entry.name)
+                                .small()
+                                .color(Color32::GRAY),
+                        );
+                    });
+
+                    if (i + 1) % columns == 0 {
+                        ui.end_row();
+                    }
+                }
+            });
+    }
+}
@@ -1,7 +1,9 @@
 //! UI module for Powder Game demo
 
+mod gallery;
 mod hud;
 mod toolbar;
 
+pub use gallery::SceneGallery;
 pub use hud::{PowderStats, show_hud};
 pub use toolbar::{ActiveTool, MaterialToolbar, ToolbarState, VisualizationMode};
@@ -5,6 +5,8 @@
 use egui::{Color32, CornerRadius, Stroke, StrokeKind, Vec2};
 use sunaba_core::simulation::{MaterialId, Materials};
 
+use super::SceneGallery;
+
 /// Cached material info for toolbar display
 #[derive(Clone)]
 pub struct MaterialInfo {
@@ -21,6 +23,14 @@ pub enum ActiveTool {
     Eraser,
     Wind,
     Drag,
+    Line,
+    Rect,
+    Ellipse,
+    Fill,
+    /// Brush turning the other button's material into this button's
+    Replace,
+    Copy,
+    Paste,
 }
 
 /// Visualization mode for background overlays
@@ -34,6 +44,8 @@ pub enum VisualizationMode {
     Temperature,
     /// Light level visualization
     Light,
+    /// Oxygen level of the air
+    Oxygen,
 }
 
 /// State for the material toolbar
@@ -52,6 +64,15 @@ pub struct ToolbarState {
     pub active_tool: ActiveTool,
     /// Visualization mode
     pub visualization_mode: VisualizationMode,
+    /// Orientation for directional materials (0 = up, 1 = right, 2 = down, 3 = left)
+    pub direction: u8,
+    /// Fill rectangles and ellipses instead of outlining them
+    pub shape_filled: bool,
+
+    // Requests handled by the app after the frame
+    pub undo_requested: bool,
+    pub redo_requested: bool,
+    pub rotate_requested: bool,
 }
 
 impl Default for ToolbarState {
@@ -64,6 +85,11 @@ impl Default for ToolbarState {
             paused: false,
             active_tool: ActiveTool::default(),
             visualization_mode: VisualizationMode::default(),
+            direction: 0,
+            shape_filled: false,
+            undo_requested: false,
+            redo_requested: false,
+            rotate_requested: false,
         }
     }
 }
@@ -76,8 +102,15 @@ pub struct MaterialToolbar {
 impl MaterialToolbar {
     /// Create a new toolbar with materials from the registry
     pub fn new(materials: &Materials) -> Self {
-        // Collect all non-AIR materials
-        let material_infos: Vec<MaterialInfo> = (1..=MaterialId::BUBBLE)
+        // Collect all non-AIR materials (workstations are survival-only)
+        let material_infos: Vec<MaterialInfo> = (1..=MaterialId::WATER_SENSOR)
+            .chain([
+                MaterialId::PIPE,
+                MaterialId::PUMP,
+                MaterialId::SNOW,
+                MaterialId::GRASS,
+                MaterialId::SAPLING,
+            ])
             .map(|id| {
                 let mat = materials.get(id);
                 let color = mat.color;
@@ -95,7 +128,7 @@ impl MaterialToolbar {
     }
 
     /// Show the toolbar panel
-    pub fn show(&self, ctx: &egui::Context, state: &mut ToolbarState) {
+    pub fn show(&self, ctx: &egui::Context, state: &mut ToolbarState, gallery: &mut SceneGallery) {
         egui::SidePanel::left("material_toolbar")
             .default_width(320.0)
             .resizable(true)
@@ -138,6 +171,46 @@ impl MaterialToolbar {
                     ui.selectable_value(&mut state.active_tool, ActiveTool::Wind, "Wind");
                     ui.selectable_value(&mut state.active_tool, ActiveTool::Drag, "Drag");
                 });
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut state.active_tool, ActiveTool::Line, "Line");
+                    ui.selectable_value(&mut state.active_tool, ActiveTool::Rect, "Rect");
+                    ui.selectable_value(&mut state.active_tool, ActiveTool::Ellipse, "Ellipse");
+                });
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut state.active_tool, ActiveTool::Fill, "Fill");
+                    ui.selectable_value(&mut state.active_tool, ActiveTool::Replace, "Replace")
+                        .on_hover_text("Paint over the other button's material only");
+                });
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut state.active_tool, ActiveTool::Copy, "Copy");
+                    ui.selectable_value(&mut state.active_tool, ActiveTool::Paste, "Paste");
+                    if state.active_tool == ActiveTool::Paste
+                        && ui.button("Rotate").on_hover_text("R").clicked()
+                    {
+                        state.rotate_requested = true;
+                    }
+                });
+                if matches!(state.active_tool, ActiveTool::Rect | ActiveTool::Ellipse) {
+                    ui.checkbox(&mut state.shape_filled, "Filled");
+                }
+
+                ui.horizontal(|ui| {
+                    if ui.button("Undo").on_hover_text("Ctrl+Z").clicked() {
+                        state.undo_requested = true;
+                    }
+                    if ui.button("Redo").on_hover_text("Ctrl+Y").clicked() {
+                        state.redo_requested = true;
+                    }
+                });
+
+                // Orientation for diodes, gates and delays
+                ui.horizontal(|ui| {
+                    ui.label("Direction:");
+                    ui.selectable_value(&mut state.direction, 0, "Up");
+                    ui.selectable_value(&mut state.direction, 1, "Right");
+                    ui.selectable_value(&mut state.direction, 2, "Down");
+                    ui.selectable_value(&mut state.direction, 3, "Left");
+                });
 
                 ui.separator();
 
@@ -166,6 +239,11 @@ impl MaterialToolbar {
                         VisualizationMode::Light,
                         "Light",
                     );
+                    ui.selectable_value(
+                        &mut state.visualization_mode,
+                        VisualizationMode::Oxygen,
+                        "O2",
+                    );
                 });
 
                 ui.separator();
@@ -187,6 +265,13 @@ impl MaterialToolbar {
                     }
                 });
 
+                ui.separator();
+
+                // Saved scenes and share strings
+                egui::CollapsingHeader::new("Scenes").show(ui, |ui| {
+                    gallery.show(ui);
+                });
+
                 ui.separator();
                 ui.label("Materials (click = left, right-click = right):");
                 ui.separator();
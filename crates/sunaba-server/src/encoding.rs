@@ -3,6 +3,9 @@
 //! Uses bincode for efficient serialization of game state.
 
 use bincode_next as bincode;
+use sunaba_core::entity::inventory::Inventory;
+use sunaba_core::entity::player::Player;
+use sunaba_core::simulation::mining::MiningProgress;
 use sunaba_creature::{CreatureGenome, CreatureMorphology, CreaturePhysicsState};
 use sunaba_simulation::{CHUNK_SIZE, Pixel};
 
@@ -85,3 +88,43 @@ pub fn decode_chunk(data: &[u8]) -> Result<sunaba_core::world::Chunk, String> {
 
     Ok(chunk)
 }
+
+/// Encode player inventory to bytes
+pub fn encode_inventory(inventory: &Inventory) -> Result<Vec<u8>, String> {
+    bincode::serde::encode_to_vec(inventory, bincode::config::standard())
+        .map_err(|e| format!("Failed to encode inventory: {}", e))
+}
+
+/// Decode player inventory from bytes
+pub fn decode_inventory(data: &[u8]) -> Result<Inventory, String> {
+    if data.is_empty() {
+        // Player row without an inventory yet
+        return Ok(Player::starting_inventory());
+    }
+
+    let (inventory, _): (Inventory, _) =
+        bincode::serde::decode_from_slice(data, bincode::config::standard())
+            .map_err(|e| format!("Failed to decode inventory: {}", e))?;
+
+    Ok(inventory)
+}
+
+/// Encode player mining progress to bytes
+pub fn encode_mining_progress(progress: &MiningProgress) -> Result<Vec<u8>, String> {
+    bincode::serde::encode_to_vec(progress, bincode::config::standard())
+        .map_err(|e| format!("Failed to encode mining progress: {}", e))
+}
+
+/// Decode player mining progress from bytes
+pub fn decode_mining_progress(data: &[u8]) -> Result<MiningProgress, String> {
+    if data.is_empty() {
+        // Not mining
+        return Ok(MiningProgress::new());
+    }
+
+    let (progress, _): (MiningProgress, _) =
+        bincode::serde::decode_from_slice(data, bincode::config::standard())
+            .map_err(|e| format!("Failed to decode mining progress: {}", e))?;
+
+    Ok(progress)
+}
@@ -0,0 +1,1127 @@
+//! Test harness driving server logic against `InMemoryStorage`
+//!
+//! Mirrors what the scheduled reducers do (same `logic` functions, same
+//! defaults) without SpacetimeDB, so tests can run ticks and then assert on
+//! table rows.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use spacetimedb::Identity;
+use std::time::Duration;
+use sunaba_core::entity::inventory::Inventory;
+use sunaba_core::world::World;
+use sunaba_simulation::CHUNK_SIZE;
+
+use crate::encoding;
+use crate::logic;
+use crate::storage::{InMemoryStorage, ServerStorage};
+use crate::tables::{Player, WorldConfig};
+
+/// In-memory server: tables, the simulated world and a seeded RNG
+pub struct TestServer {
+    pub storage: InMemoryStorage,
+    pub world: Option<World>,
+    rng: StdRng,
+}
+
+impl TestServer {
+    /// Server after `init`, with a small settlement radius to keep tests fast
+    /// and spawn protection off (tests edit next to spawn)
+    pub fn new() -> Self {
+        Self::with_config(|config| {
+            config.settlement_radius = 1;
+            config.spawn_protection_radius = 0;
+        })
+    }
+
+    /// Server after `init`, with the default world config adjusted by `configure`
+    pub fn with_config(configure: impl FnOnce(&mut WorldConfig)) -> Self {
+        let mut config = logic::default_world_config();
+        configure(&mut config);
+
+        let mut storage = InMemoryStorage::new();
+        storage.set_config(config);
+
+        Self {
+            storage,
+            world: None,
+            rng: StdRng::seed_from_u64(7),
+        }
+    }
+
+    /// Deterministic identity for test player `n`
+    pub fn identity(n: u8) -> Identity {
+        Identity::from_byte_array([n; 32])
+    }
+
+    pub fn connect(&mut self, identity: Identity) {
+        logic::player_connected(&mut self.storage, &mut self.world, identity, &mut self.rng);
+    }
+
+    pub fn disconnect(&mut self, identity: Identity) {
+        logic::player_disconnected(&mut self.storage, identity);
+    }
+
+    /// Run one world tick; returns the delay the reducer would schedule
+    pub fn world_tick(&mut self) -> Option<Duration> {
+        logic::run_world_tick(&mut self.storage, &mut self.world, &mut self.rng)
+    }
+
+    /// Run `count` world ticks
+    pub fn world_ticks(&mut self, count: usize) {
+        for _ in 0..count {
+            self.world_tick();
+        }
+    }
+
+    pub fn creature_tick(&mut self) -> Duration {
+        logic::run_creature_tick(&mut self.storage)
+    }
+
+    pub fn settle_tick(&mut self) -> Option<Duration> {
+        logic::run_settle_tick(&mut self.storage, self.world.as_mut(), &mut self.rng)
+    }
+
+    pub fn place_material(&mut self, x: i32, y: i32, material_id: u16) -> bool {
+        logic::place_material(&mut self.storage, self.world.as_mut(), x, y, material_id)
+    }
+
+    pub fn mine(&mut self, x: i32, y: i32) -> bool {
+        logic::mine(&mut self.storage, self.world.as_mut(), x, y)
+    }
+
+    /// Free placement as the `player_place_material` reducer does it
+    pub fn debug_place_material(
+        &mut self,
+        identity: Identity,
+        x: i32,
+        y: i32,
+        material_id: u16,
+    ) -> Result<(), String> {
+        logic::debug_place_material(
+            &mut self.storage,
+            self.world.as_mut(),
+            identity,
+            x,
+            y,
+            material_id,
+        )
+    }
+
+    /// Instant mining as the `player_mine` reducer does it
+    pub fn debug_mine(&mut self, identity: Identity, x: i32, y: i32) -> Result<(), String> {
+        logic::debug_mine(&mut self.storage, self.world.as_mut(), identity, x, y)
+    }
+
+    pub fn start_mining(&mut self, identity: Identity, x: i32, y: i32) -> Result<(), String> {
+        logic::start_mining(&mut self.storage, self.world.as_ref(), identity, x, y)
+    }
+
+    pub fn place_from_inventory(
+        &mut self,
+        identity: Identity,
+        x: i32,
+        y: i32,
+        material_id: u16,
+    ) -> Result<(), String> {
+        logic::place_from_inventory(
+            &mut self.storage,
+            self.world.as_mut(),
+            identity,
+            x,
+            y,
+            material_id,
+        )
+    }
+
+    /// Send a chat message or command at `now_ms`
+    pub fn chat(
+        &mut self,
+        identity: Identity,
+        text: &str,
+        now_ms: u64,
+    ) -> Result<logic::ChatEffect, String> {
+        logic::send_chat(
+            &mut self.storage,
+            self.world.as_mut(),
+            identity,
+            text.to_string(),
+            now_ms,
+        )
+    }
+
+    /// Advance hunger and mining for a player by `delta_time` seconds
+    pub fn update_survival(&mut self, identity: Identity, delta_time: f32) {
+        logic::update_player_survival(&mut self.storage, self.world.as_mut(), identity, delta_time);
+    }
+
+    pub fn inventory(&self, identity: Identity) -> Inventory {
+        encoding::decode_inventory(&self.player(identity).inventory_data).expect("inventory")
+    }
+
+    /// Overwrite a player's inventory
+    pub fn set_inventory(&mut self, identity: Identity, inventory: &Inventory) {
+        let player = self.player(identity);
+        self.storage.update_player(Player {
+            inventory_data: encoding::encode_inventory(inventory).unwrap(),
+            ..player
+        });
+    }
+
+    pub fn config(&self) -> WorldConfig {
+        self.storage.config().expect("world config row")
+    }
+
+    pub fn player(&self, identity: Identity) -> Player {
+        self.storage.find_player(identity).expect("player row")
+    }
+
+    /// Material stored in the chunk table at world coordinates
+    pub fn stored_material(&self, x: i32, y: i32) -> Option<u16> {
+        let size = CHUNK_SIZE as i32;
+        let row = self
+            .storage
+            .find_chunk(x.div_euclid(size), y.div_euclid(size))?;
+        let chunk = encoding::decode_chunk(&row.pixel_data).ok()?;
+        Some(chunk.get_material(x.rem_euclid(size) as usize, y.rem_euclid(size) as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::IVec2;
+    use sunaba_simulation::MaterialId;
+
+    #[test]
+    fn test_first_connect_creates_player_and_settles_spawn() {
+        let mut server = TestServer::new();
+        let alice = TestServer::identity(1);
+
+        server.connect(alice);
+
+        let player = server.player(alice);
+        assert!(player.online);
+        assert_eq!((player.x, player.y), logic::SPAWN_POINT);
+        assert!(server.config().settlement_complete);
+        assert_eq!(server.storage.chunk_count(), 9); // radius 1 around spawn
+
+        // Reconnecting keeps the row
+        server.disconnect(alice);
+        assert!(!server.player(alice).online);
+        server.connect(alice);
+        assert!(server.player(alice).online);
+        assert_eq!(server.storage.players().len(), 1);
+    }
+
+    #[test]
+    fn test_world_tick_without_players_only_counts() {
+        let mut server = TestServer::new();
+
+        assert_eq!(server.world_tick(), Some(Duration::from_millis(100)));
+        assert_eq!(server.config().tick_count, 1);
+        assert_eq!(server.storage.chunk_count(), 0);
+    }
+
+    #[test]
+    fn test_paused_world_stops_ticking() {
+        let mut server = TestServer::with_config(|config| config.simulation_paused = true);
+
+        assert_eq!(server.world_tick(), None);
+        assert_eq!(server.config().tick_count, 0);
+    }
+
+    #[test]
+    fn test_world_tick_loads_chunks_around_players_and_records_metrics() {
+        let mut server = TestServer::new();
+        let alice = TestServer::identity(1);
+        server.connect(alice);
+
+        assert_eq!(server.world_tick(), Some(Duration::from_millis(16)));
+
+        // 7x7 chunks around the player's chunk (0, 1) are stored
+        for cy in -2..=4 {
+            for cx in -3..=3 {
+                assert!(server.storage.find_chunk(cx, cy).is_some());
+            }
+        }
+        assert_eq!(server.storage.chunk_count(), 49);
+
+        // Gravity pulls the player
+        assert!(server.player(alice).vel_y > 0.0);
+
+        server.world_ticks(9);
+        let metrics = server.storage.metrics();
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics[0].tick, 10);
+        assert_eq!(metrics[0].online_players, 1);
+    }
+
+    #[test]
+    fn test_placed_material_survives_world_ticks() {
+        let mut server = TestServer::new();
+        server.connect(TestServer::identity(1));
+        server.world_tick();
+
+        assert!(server.place_material(5, 90, MaterialId::GLASS));
+        assert_eq!(server.stored_material(5, 90), Some(MaterialId::GLASS));
+
+        server.world_ticks(5);
+        assert_eq!(server.stored_material(5, 90), Some(MaterialId::GLASS));
+
+        assert!(server.mine(5, 90));
+        assert_eq!(server.stored_material(5, 90), Some(MaterialId::AIR));
+    }
+
+    #[test]
+    fn test_edit_without_world_updates_chunk_row() {
+        let mut server = TestServer::new();
+        server.connect(TestServer::identity(1));
+        server.world = None;
+
+        assert!(server.place_material(3, 3, MaterialId::STONE));
+        assert_eq!(server.stored_material(3, 3), Some(MaterialId::STONE));
+        assert!(server.storage.find_chunk(0, 0).unwrap().dirty);
+
+        // Unknown chunk: nothing to edit
+        assert!(!server.place_material(10_000, 10_000, MaterialId::STONE));
+    }
+
+    #[test]
+    fn test_creature_limit_and_hunger() {
+        let mut server = TestServer::with_config(|config| {
+            config.settlement_radius = 1;
+            config.max_creatures = 1;
+        });
+        server.connect(TestServer::identity(1));
+
+        assert!(logic::spawn_creature(
+            &mut server.storage,
+            "worm".to_string(),
+            0.0,
+            80.0
+        ));
+        assert!(!logic::spawn_creature(
+            &mut server.storage,
+            "worm".to_string(),
+            8.0,
+            80.0
+        ));
+        assert_eq!(server.storage.creatures().len(), 1);
+
+        assert_eq!(server.creature_tick(), Duration::from_millis(33));
+        let creature = &server.storage.creatures()[0];
+        assert!(creature.hunger < creature.max_hunger);
+        assert!(creature.alive);
+
+        // Nobody online: creatures are frozen
+        server.disconnect(TestServer::identity(1));
+        let hunger = server.storage.creatures()[0].hunger;
+        assert_eq!(server.creature_tick(), Duration::from_millis(200));
+        assert_eq!(server.storage.creatures()[0].hunger, hunger);
+    }
+
+    #[test]
+    fn test_creature_poses_follow_loaded_radius() {
+        let mut server = TestServer::with_config(|config| {
+            config.settlement_radius = 1;
+            config.max_creatures = 2;
+        });
+        let player = TestServer::identity(1);
+        server.connect(player);
+
+        let far_x = 10.0 * CHUNK_SIZE as f32;
+        assert!(logic::spawn_creature(
+            &mut server.storage,
+            "worm".to_string(),
+            0.0,
+            80.0
+        ));
+        assert!(logic::spawn_creature(
+            &mut server.storage,
+            "worm".to_string(),
+            far_x,
+            80.0
+        ));
+        let near = server.storage.creatures()[0].id;
+        let far = server.storage.creatures()[1].id;
+
+        // Only the creature inside the default radius is published
+        server.creature_tick();
+        let poses = server.storage.creature_poses();
+        assert_eq!(poses.len(), 1);
+        assert_eq!(poses[0].creature_id, near);
+        assert!(!poses[0].parts.is_empty());
+        let infos = server.storage.creature_infos();
+        assert_eq!(infos.len(), 1);
+        assert_eq!(infos[0].archetype, "worm");
+
+        // A larger loaded radius brings the far creature in
+        logic::set_loaded_radius(&mut server.storage, player, 100);
+        assert_eq!(
+            server.player(player).loaded_radius,
+            logic::MAX_LOADED_RADIUS
+        );
+        server.creature_tick();
+        assert_eq!(server.storage.creature_poses().len(), 2);
+        assert_eq!(server.storage.creature_infos().len(), 2);
+
+        // Shrinking it drops the pose but keeps the fetched-once info
+        logic::set_loaded_radius(&mut server.storage, player, 1);
+        server.creature_tick();
+        let poses = server.storage.creature_poses();
+        assert_eq!(poses.len(), 1);
+        assert_eq!(poses[0].creature_id, near);
+        assert_eq!(server.storage.creature_infos().len(), 2);
+
+        // Removed creatures disappear from both tables
+        server.storage.delete_creature(far);
+        server.storage.delete_creature(near);
+        server.creature_tick();
+        assert!(server.storage.creature_poses().is_empty());
+        assert!(server.storage.creature_infos().is_empty());
+    }
+
+    #[test]
+    fn test_creature_poses_advance_while_paused() {
+        let mut server = TestServer::with_config(|config| config.simulation_paused = true);
+        server.connect(TestServer::identity(1));
+        assert!(logic::spawn_creature(
+            &mut server.storage,
+            "worm".to_string(),
+            0.0,
+            80.0
+        ));
+
+        // The world tick stands still, but every creature tick publishes a
+        // newer pose for clients to interpolate towards
+        assert_eq!(server.world_tick(), None);
+        server.creature_tick();
+        assert_eq!(server.storage.creature_poses()[0].seq, 1);
+        server.creature_tick();
+        assert_eq!(server.storage.creature_poses()[0].seq, 2);
+        assert_eq!(server.config().tick_count, 0);
+    }
+
+    #[test]
+    fn test_settle_tick_progresses_rings() {
+        let mut server = TestServer::new();
+
+        // No players: idle reschedule, no work
+        assert_eq!(server.settle_tick(), Some(Duration::from_millis(1000)));
+        assert_eq!(server.config().settlement_progress, 0);
+
+        // Player row without the connect-time burst settlement
+        server.connect(TestServer::identity(1));
+        server.storage.set_config(WorldConfig {
+            settlement_progress: 0,
+            settlement_complete: false,
+            ..server.config()
+        });
+
+        assert_eq!(server.settle_tick(), Some(Duration::from_millis(100)));
+        assert_eq!(server.config().settlement_progress, 1);
+        assert_eq!(server.settle_tick(), Some(Duration::from_millis(100)));
+        assert!(server.config().settlement_complete);
+        assert_eq!(server.settle_tick(), None);
+    }
+
+    #[test]
+    fn test_place_from_inventory_consumes_items() {
+        let mut server = TestServer::new();
+        let alice = TestServer::identity(1);
+        server.connect(alice);
+        server.world_tick();
+
+        let sand = server.inventory(alice).count_item(MaterialId::SAND);
+        assert!(sand > 0);
+
+        server
+            .place_from_inventory(alice, 5, 90, MaterialId::SAND)
+            .unwrap();
+        assert_eq!(server.stored_material(5, 90), Some(MaterialId::SAND));
+        assert_eq!(
+            server.inventory(alice).count_item(MaterialId::SAND),
+            sand - 1
+        );
+
+        // Occupied target and missing material are rejected without side effects
+        assert!(
+            server
+                .place_from_inventory(alice, 5, 90, MaterialId::SAND)
+                .is_err()
+        );
+        assert!(
+            server
+                .place_from_inventory(alice, 6, 90, MaterialId::STONE)
+                .is_err()
+        );
+        assert_eq!(
+            server.inventory(alice).count_item(MaterialId::SAND),
+            sand - 1
+        );
+
+        // Nothing is placed or mined beyond tool reach
+        logic::update_player_position(&mut server.storage, alice, 200.0, 100.0, 0.0, 0.0);
+        assert!(
+            server
+                .place_from_inventory(alice, 6, 90, MaterialId::SAND)
+                .is_err()
+        );
+        assert!(server.start_mining(alice, 5, 90).is_err());
+        assert_eq!(
+            server.inventory(alice).count_item(MaterialId::SAND),
+            sand - 1
+        );
+    }
+
+    #[test]
+    fn test_mining_completes_into_inventory() {
+        let mut server = TestServer::new();
+        let alice = TestServer::identity(1);
+        server.connect(alice);
+        server.world_tick();
+        assert!(server.place_material(5, 90, MaterialId::STONE));
+
+        server.start_mining(alice, 5, 90).unwrap();
+        let progress = encoding::decode_mining_progress(&server.player(alice).mining_data).unwrap();
+        assert_eq!(progress.target_pixel, Some((5, 90)));
+        assert!(progress.required_time > 0.0);
+
+        // Not done yet
+        server.update_survival(alice, progress.required_time * 0.5);
+        assert_eq!(server.stored_material(5, 90), Some(MaterialId::STONE));
+        assert_eq!(server.inventory(alice).count_item(MaterialId::STONE), 0);
+
+        server.update_survival(alice, progress.required_time);
+        assert_eq!(server.stored_material(5, 90), Some(MaterialId::AIR));
+        assert_eq!(server.inventory(alice).count_item(MaterialId::STONE), 1);
+        assert!(server.player(alice).mining_data.is_empty());
+
+        // Air can't be mined
+        assert!(server.start_mining(alice, 5, 90).is_err());
+    }
+
+    #[test]
+    fn test_craft_and_equip_tool() {
+        let mut server = TestServer::new();
+        let alice = TestServer::identity(1);
+        server.connect(alice);
+
+        // Wood pickaxe (recipe 0, tool 1000) isn't owned yet
+        assert!(logic::equip_tool(&mut server.storage, alice, Some(1000)).is_err());
+
+        let wood = server.inventory(alice).count_item(MaterialId::WOOD);
+        logic::craft(&mut server.storage, alice, 0).unwrap();
+        let inventory = server.inventory(alice);
+        assert_eq!(inventory.count_item(MaterialId::WOOD), wood - 5);
+        assert!(inventory.get_tool_durability(1000).is_some());
+
+        logic::equip_tool(&mut server.storage, alice, Some(1000)).unwrap();
+        assert_eq!(server.player(alice).equipped_tool, Some(1000));
+        logic::equip_tool(&mut server.storage, alice, None).unwrap();
+        assert_eq!(server.player(alice).equipped_tool, None);
+
+        // Missing materials (stone) and workstation recipes (iron pickaxe) fail
+        assert!(logic::craft(&mut server.storage, alice, 1).is_err());
+        assert!(logic::craft(&mut server.storage, alice, 2).is_err());
+        assert!(logic::craft(&mut server.storage, alice, u16::MAX).is_err());
+        assert_eq!(
+            server.inventory(alice).count_item(MaterialId::WOOD),
+            wood - 5
+        );
+    }
+
+    #[test]
+    fn test_hunger_starvation_and_eating() {
+        let mut server = TestServer::new();
+        let alice = TestServer::identity(1);
+        server.connect(alice);
+
+        // Hunger drains at 0.1/s
+        server.update_survival(alice, 10.0);
+        assert!((server.player(alice).hunger - 99.0).abs() < 1e-3);
+
+        // Starving costs 1 health/s
+        let player = server.player(alice);
+        server.storage.update_player(Player {
+            hunger: 0.0,
+            ..player
+        });
+        server.update_survival(alice, 10.0);
+        assert!((server.player(alice).health - 90.0).abs() < 1e-3);
+
+        // Only food in the inventory can be eaten
+        assert!(logic::eat(&mut server.storage, alice, MaterialId::FRUIT).is_err());
+        assert!(logic::eat(&mut server.storage, alice, MaterialId::SAND).is_err());
+
+        let mut inventory = server.inventory(alice);
+        inventory.add_item(MaterialId::FRUIT, 2);
+        server.set_inventory(alice, &inventory);
+        logic::eat(&mut server.storage, alice, MaterialId::FRUIT).unwrap();
+        assert!(server.player(alice).hunger > 0.0);
+        assert_eq!(server.inventory(alice).count_item(MaterialId::FRUIT), 1);
+
+        // Dead players can't act
+        let player = server.player(alice);
+        server.storage.update_player(Player {
+            health: 0.0,
+            ..player
+        });
+        assert!(logic::eat(&mut server.storage, alice, MaterialId::FRUIT).is_err());
+    }
+
+    #[test]
+    fn test_edit_acknowledgements() {
+        let mut server = TestServer::new();
+        let alice = TestServer::identity(1);
+        server.connect(alice);
+
+        logic::acknowledge_edit(&mut server.storage, alice, 1, true);
+        logic::acknowledge_edit(&mut server.storage, alice, 2, false);
+        logic::acknowledge_edit(&mut server.storage, alice, 0, false); // untracked
+        let player = server.player(alice);
+        assert_eq!(player.last_edit_seq, 2);
+        assert_eq!(player.rejected_edits, vec![2]);
+
+        // Rejections stay until the client confirms them, however many pile up
+        for seq in 3..40 {
+            logic::acknowledge_edit(&mut server.storage, alice, seq, false);
+        }
+        let player = server.player(alice);
+        assert_eq!(player.last_edit_seq, 39);
+        assert_eq!(player.rejected_edits.len(), 38);
+
+        logic::confirm_rejected_edits(&mut server.storage, alice, 30);
+        let player = server.player(alice);
+        assert_eq!(player.rejected_edits, (31..40).collect::<Vec<u64>>());
+
+        // A new session starts counting again
+        server.disconnect(alice);
+        server.connect(alice);
+        let player = server.player(alice);
+        assert_eq!(player.last_edit_seq, 0);
+        assert!(player.rejected_edits.is_empty());
+    }
+
+    #[test]
+    fn test_claims_gate_edits_by_permission() {
+        let mut server = TestServer::new();
+        let (alice, bob) = (TestServer::identity(1), TestServer::identity(2));
+        server.connect(alice);
+        server.connect(bob);
+        server.world_tick();
+
+        logic::claim_land(&mut server.storage, alice, "Base".into(), 10, 95, 0, 85).unwrap();
+        let claim = server.storage.claims()[0].clone();
+        assert_eq!(
+            (claim.min_x, claim.min_y, claim.max_x, claim.max_y),
+            (0, 85, 10, 95)
+        );
+
+        // Overlaps and other players' claims are refused
+        assert!(
+            logic::claim_land(&mut server.storage, bob, "Mine".into(), 10, 95, 20, 99).is_err()
+        );
+        assert!(logic::unclaim_land(&mut server.storage, bob, claim.id).is_err());
+
+        // Bob is locked out of Alice's land, but not outside it
+        assert!(
+            server
+                .place_from_inventory(bob, 5, 90, MaterialId::SAND)
+                .is_err()
+        );
+        assert!(
+            server
+                .place_from_inventory(bob, 20, 90, MaterialId::SAND)
+                .is_ok()
+        );
+        assert!(
+            server
+                .place_from_inventory(alice, 5, 90, MaterialId::SAND)
+                .is_ok()
+        );
+        assert!(server.start_mining(bob, 5, 90).is_err());
+
+        // Crafting and eating inside the claim need the interact permission
+        logic::update_player_position(&mut server.storage, bob, 5.0, 90.0, 0.0, 0.0);
+        let mut inventory = server.inventory(bob);
+        inventory.add_item(MaterialId::FRUIT, 1);
+        server.set_inventory(bob, &inventory);
+        assert!(logic::eat(&mut server.storage, bob, MaterialId::FRUIT).is_err());
+        assert!(logic::craft(&mut server.storage, bob, 0).is_err());
+        logic::set_claim_permission(
+            &mut server.storage,
+            alice,
+            claim.id,
+            bob,
+            false,
+            false,
+            true,
+        )
+        .unwrap();
+        logic::eat(&mut server.storage, bob, MaterialId::FRUIT).unwrap();
+        logic::craft(&mut server.storage, bob, 0).unwrap();
+        assert!(server.start_mining(bob, 5, 90).is_err());
+
+        // Mining rights only
+        logic::set_claim_permission(
+            &mut server.storage,
+            alice,
+            claim.id,
+            bob,
+            false,
+            true,
+            false,
+        )
+        .unwrap();
+        assert!(server.start_mining(bob, 5, 90).is_ok());
+        assert!(
+            server
+                .place_from_inventory(bob, 6, 90, MaterialId::SAND)
+                .is_err()
+        );
+
+        // Clearing every permission removes the member
+        logic::set_claim_permission(
+            &mut server.storage,
+            alice,
+            claim.id,
+            bob,
+            false,
+            false,
+            false,
+        )
+        .unwrap();
+        assert!(
+            server
+                .storage
+                .find_claim(claim.id)
+                .unwrap()
+                .members
+                .is_empty()
+        );
+
+        logic::unclaim_land(&mut server.storage, alice, claim.id).unwrap();
+        assert!(
+            server
+                .place_from_inventory(bob, 6, 90, MaterialId::SAND)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_claim_limits() {
+        let mut server = TestServer::new();
+        let alice = TestServer::identity(1);
+        server.connect(alice);
+
+        let size = logic::MAX_CLAIM_SIZE;
+        assert!(
+            logic::claim_land(
+                &mut server.storage,
+                alice,
+                "Big".into(),
+                1000,
+                0,
+                1000 + size,
+                0
+            )
+            .is_err()
+        );
+
+        // Corners at the ends of the coordinate range must not wrap into a small claim
+        assert!(
+            logic::claim_land(
+                &mut server.storage,
+                alice,
+                "Everything".into(),
+                i32::MIN,
+                i32::MIN,
+                i32::MAX,
+                i32::MAX
+            )
+            .is_err()
+        );
+        assert!(
+            logic::claim_land(
+                &mut server.storage,
+                alice,
+                "Wide".into(),
+                i32::MAX,
+                0,
+                i32::MIN,
+                5
+            )
+            .is_err()
+        );
+        assert!(server.storage.claims().is_empty());
+
+        // Small claims at the edge of the range are fine
+        logic::claim_land(
+            &mut server.storage,
+            alice,
+            "Edge".into(),
+            i32::MAX - 5,
+            i32::MIN,
+            i32::MAX,
+            i32::MIN + 5,
+        )
+        .unwrap();
+        let edge = server.storage.claims()[0].id;
+        logic::unclaim_land(&mut server.storage, alice, edge).unwrap();
+
+        for i in 0..logic::MAX_CLAIMS_PER_PLAYER as i32 {
+            let x = 1000 + i * 10;
+            logic::claim_land(&mut server.storage, alice, String::new(), x, 0, x + 5, 5).unwrap();
+        }
+        assert_eq!(server.storage.claims()[0].name, "Claim");
+        assert!(
+            logic::claim_land(
+                &mut server.storage,
+                alice,
+                "One more".into(),
+                2000,
+                0,
+                2005,
+                5
+            )
+            .is_err()
+        );
+
+        // Unknown players can't claim
+        assert!(
+            logic::claim_land(
+                &mut server.storage,
+                TestServer::identity(9),
+                "X".into(),
+                3000,
+                0,
+                3005,
+                5
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_debug_edits_need_admin() {
+        let mut server = TestServer::new();
+        let (alice, admin) = (TestServer::identity(1), TestServer::identity(2));
+        server.connect(alice);
+        server.connect(admin);
+        server.storage.add_admin(admin);
+        server.world_tick();
+        assert!(server.place_material(5, 90, MaterialId::STONE));
+
+        // Players can't place for free or mine instantly
+        assert!(
+            server
+                .debug_place_material(alice, 6, 90, MaterialId::GOLD_INGOT)
+                .is_err()
+        );
+        assert!(server.debug_mine(alice, 5, 90).is_err());
+        assert_eq!(server.stored_material(5, 90), Some(MaterialId::STONE));
+
+        server
+            .debug_place_material(admin, 6, 90, MaterialId::GOLD_INGOT)
+            .unwrap();
+        server.debug_mine(admin, 5, 90).unwrap();
+        assert_eq!(server.stored_material(6, 90), Some(MaterialId::GOLD_INGOT));
+        assert_eq!(server.stored_material(5, 90), Some(MaterialId::AIR));
+    }
+
+    #[test]
+    fn test_spawn_protection_and_admin_override() {
+        let mut server = TestServer::with_config(|config| {
+            config.settlement_radius = 1;
+            config.spawn_protection_radius = 16;
+        });
+        let (alice, admin) = (TestServer::identity(1), TestServer::identity(2));
+        server.connect(alice);
+        server.connect(admin);
+        server.storage.add_admin(admin);
+        server.world_tick();
+
+        // (5, 90) is within 16 pixels of spawn
+        assert!(
+            server
+                .place_from_inventory(alice, 5, 90, MaterialId::SAND)
+                .is_err()
+        );
+        assert!(server.start_mining(alice, 5, 90).is_err());
+        assert!(
+            logic::claim_land(&mut server.storage, alice, "Spawn".into(), 0, 80, 20, 90).is_err()
+        );
+        assert!(
+            server
+                .place_from_inventory(admin, 5, 90, MaterialId::SAND)
+                .is_ok()
+        );
+
+        // Admins bypass claims and can remove them
+        logic::claim_land(&mut server.storage, alice, "Base".into(), 30, 85, 40, 95).unwrap();
+        let claim_id = server.storage.claims()[0].id;
+        assert!(
+            server
+                .place_from_inventory(admin, 35, 90, MaterialId::SAND)
+                .is_ok()
+        );
+        logic::unclaim_land(&mut server.storage, admin, claim_id).unwrap();
+
+        // Only admins can resize the zone
+        assert!(logic::set_spawn_protection(&mut server.storage, alice, 0).is_err());
+        logic::set_spawn_protection(&mut server.storage, admin, 0).unwrap();
+        assert!(
+            server
+                .place_from_inventory(alice, 6, 90, MaterialId::SAND)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_chat_limits() {
+        let mut server = TestServer::new();
+        let alice = TestServer::identity(1);
+        server.connect(alice);
+        logic::set_player_name(&mut server.storage, alice, "Alice".into());
+
+        server.chat(alice, "  hello  ", 1_000).unwrap();
+        let messages = server.storage.chat_messages();
+        assert_eq!(messages[0].text, "hello");
+        assert_eq!(messages[0].sender_name, "Alice");
+        assert!(!messages[0].system && messages[0].recipient.is_none());
+
+        assert!(server.chat(alice, "   ", 1_000).is_err());
+        let long = "x".repeat(logic::MAX_CHAT_LENGTH + 1);
+        assert!(server.chat(alice, &long, 1_000).is_err());
+
+        // Rate limited within the window, fine again after it
+        for _ in 1..logic::CHAT_RATE_LIMIT {
+            server.chat(alice, "spam", 2_000).unwrap();
+        }
+        assert!(server.chat(alice, "spam", 3_000).is_err());
+        server
+            .chat(alice, "later", 2_000 + logic::CHAT_RATE_WINDOW_MS + 1)
+            .unwrap();
+
+        // History is capped, oldest first out
+        for i in 0..logic::MAX_CHAT_HISTORY as u64 {
+            server
+                .chat(alice, "filler", 100_000 + i * logic::CHAT_RATE_WINDOW_MS)
+                .unwrap();
+        }
+        let messages = server.storage.chat_messages();
+        assert_eq!(messages.len(), logic::MAX_CHAT_HISTORY);
+        assert!(messages.iter().all(|m| m.text == "filler"));
+
+        // The limit still holds once the history has dropped the messages
+        let now = 10_000_000;
+        for _ in 0..logic::CHAT_RATE_LIMIT {
+            server.chat(alice, "burst", now).unwrap();
+        }
+        for message in server.storage.chat_messages() {
+            server.storage.delete_chat_message(message.id);
+        }
+        assert!(server.chat(alice, "burst", now).is_err());
+    }
+
+    #[test]
+    fn test_chat_commands_need_admin() {
+        let mut server = TestServer::new();
+        let (alice, admin) = (TestServer::identity(1), TestServer::identity(2));
+        server.connect(alice);
+        server.connect(admin);
+        server.storage.add_admin(admin);
+        logic::set_player_name(&mut server.storage, alice, "Alice".into());
+
+        // Non-admins get a private reply and nothing changes
+        assert_eq!(server.chat(alice, "/pause", 0), Ok(logic::ChatEffect::None));
+        assert!(!server.config().simulation_paused);
+        let reply = server.storage.chat_messages().pop().unwrap();
+        assert!(reply.system);
+        assert_eq!(reply.recipient, Some(alice));
+
+        // Pause toggles; resuming asks the reducer to restart the world tick
+        assert_eq!(server.chat(admin, "/pause", 0), Ok(logic::ChatEffect::None));
+        assert!(server.config().simulation_paused);
+        assert_eq!(server.world_tick(), None);
+        assert_eq!(
+            server.chat(admin, "/pause", 0),
+            Ok(logic::ChatEffect::ResumeSimulation)
+        );
+        assert!(!server.config().simulation_paused);
+
+        server.chat(admin, "/time dusk", 0).unwrap();
+        assert_eq!(server.config().time_of_day, 900.0);
+
+        server.chat(admin, "/tp alice 40 -20", 20_000).unwrap();
+        let player = server.player(alice);
+        assert_eq!((player.x, player.y), (40.0, -20.0));
+        assert_eq!((player.teleport_seq, player.teleport_x), (1, 40.0));
+
+        server.chat(admin, "/spawn worm 10 100", 20_000).unwrap();
+        assert_eq!(server.storage.alive_creatures().len(), 1);
+
+        server.chat(admin, "/kick Alice", 20_000).unwrap();
+        assert!(!server.player(alice).online);
+        let announcement = server.storage.chat_messages().pop().unwrap();
+        assert_eq!(announcement.recipient, None);
+
+        // A kicked client is refused until it reconnects
+        assert!(server.chat(alice, "still here", 20_000).is_err());
+        assert!(
+            server
+                .place_from_inventory(alice, 5, 90, MaterialId::SAND)
+                .is_err()
+        );
+        logic::update_player_position(&mut server.storage, alice, 0.0, 0.0, 0.0, 0.0);
+        assert_eq!(server.player(alice).x, 40.0);
+        server.connect(alice);
+        assert!(server.chat(alice, "back", 20_000).is_ok());
+
+        // Unknown commands and bad arguments are replied, not errors
+        assert!(server.chat(admin, "/fly", 40_000).is_ok());
+        assert!(server.chat(admin, "/tp nobody", 40_000).is_ok());
+        assert!(server.chat(admin, "/time later", 40_000).is_ok());
+        assert_eq!(server.config().time_of_day, 900.0);
+    }
+
+    #[test]
+    fn test_snapshot_rollback_region_and_world() {
+        let mut server = TestServer::new();
+        let (alice, admin) = (TestServer::identity(1), TestServer::identity(2));
+        server.connect(alice);
+        server.connect(admin);
+        server.storage.add_admin(admin);
+        server.world_tick();
+
+        assert!(server.place_material(5, 90, MaterialId::GLASS));
+        assert!(server.place_material(150, 90, MaterialId::GLASS));
+        assert!(logic::create_snapshot(&mut server.storage, alice, "x".into(), 0).is_err());
+        let snapshot =
+            logic::create_snapshot(&mut server.storage, admin, "Before".into(), 0).unwrap();
+
+        assert!(server.mine(5, 90));
+        assert!(server.mine(150, 90));
+
+        // Region rollback only touches the chunks it covers
+        let region = logic::ChunkRegion::from_pixels(0, 85, 10, 95);
+        assert!(
+            logic::rollback(&mut server.storage, None, alice, snapshot, Some(region), 0).is_err()
+        );
+        logic::rollback(
+            &mut server.storage,
+            server.world.as_mut(),
+            admin,
+            snapshot,
+            Some(region),
+            0,
+        )
+        .unwrap();
+        assert_eq!(server.stored_material(5, 90), Some(MaterialId::GLASS));
+        assert_eq!(server.stored_material(150, 90), Some(MaterialId::AIR));
+
+        // The server world reloads the restored chunk from the table
+        assert!(!server.world.as_ref().unwrap().has_chunk(IVec2::new(0, 1)));
+        server.world_tick();
+        assert_eq!(server.stored_material(5, 90), Some(MaterialId::GLASS));
+
+        logic::rollback(
+            &mut server.storage,
+            server.world.as_mut(),
+            admin,
+            snapshot,
+            None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(server.stored_material(150, 90), Some(MaterialId::GLASS));
+
+        // Each rollback kept the replaced state as its own snapshot
+        let snapshots = server.storage.snapshots();
+        assert_eq!(snapshots.len(), 3);
+        assert!(snapshots.iter().all(|s| !s.automatic));
+
+        logic::delete_snapshot(&mut server.storage, admin, snapshot).unwrap();
+        assert!(server.storage.snapshot_chunks(snapshot).is_empty());
+    }
+
+    #[test]
+    fn test_periodic_snapshots_rotate() {
+        let mut server = TestServer::new();
+        let admin = TestServer::identity(1);
+        server.storage.add_admin(admin);
+
+        // Nothing to back up while nobody is online
+        logic::take_periodic_snapshot(&mut server.storage, 0);
+        assert!(server.storage.snapshots().is_empty());
+
+        server.connect(admin);
+        logic::create_snapshot(&mut server.storage, admin, "Keep".into(), 0).unwrap();
+        for _ in 0..logic::MAX_AUTOMATIC_SNAPSHOTS + 2 {
+            logic::take_periodic_snapshot(&mut server.storage, 0);
+            // Not due again until the interval has passed
+            logic::take_periodic_snapshot(&mut server.storage, 0);
+
+            let config = server.config();
+            server.storage.set_config(WorldConfig {
+                tick_count: config.tick_count + logic::SNAPSHOT_INTERVAL_TICKS,
+                ..config
+            });
+        }
+
+        let snapshots = server.storage.snapshots();
+        let automatic = snapshots.iter().filter(|s| s.automatic).count();
+        assert_eq!(automatic, logic::MAX_AUTOMATIC_SNAPSHOTS);
+        assert!(snapshots.iter().any(|s| s.label == "Keep"));
+        assert!(
+            server
+                .storage
+                .snapshot_chunks(snapshots[0].id)
+                .iter()
+                .any(|c| (c.x, c.y) == (0, 1))
+        );
+    }
+
+    #[test]
+    fn test_import_chunk_checks_coordinates() {
+        let mut server = TestServer::new();
+        let admin = TestServer::identity(1);
+        server.connect(admin);
+        server.storage.add_admin(admin);
+
+        let mut chunk = sunaba_core::world::Chunk::new(2, -1);
+        chunk.set_material(7, 7, MaterialId::STONE);
+        let data = encoding::encode_chunk(&chunk).unwrap();
+
+        assert!(logic::import_chunk(&mut server.storage, None, admin, 0, 0, data.clone()).is_err());
+        assert!(logic::import_chunk(&mut server.storage, None, admin, 2, -1, vec![1, 2]).is_err());
+        logic::import_chunk(&mut server.storage, None, admin, 2, -1, data).unwrap();
+        assert_eq!(server.stored_material(135, -57), Some(MaterialId::STONE));
+    }
+
+    #[test]
+    fn test_old_metrics_cleaned_up() {
+        let mut server = TestServer::new();
+        for tick in 0..3700 {
+            server.storage.insert_metrics(crate::tables::ServerMetrics {
+                id: 0,
+                tick,
+                timestamp_ms: tick * 16,
+                world_tick_time_ms: 0.0,
+                creature_tick_time_ms: 0.0,
+                active_chunks: 0,
+                dirty_chunks_synced: 0,
+                online_players: 0,
+                creatures_alive: 0,
+            });
+        }
+
+        logic::cleanup_old_metrics(&mut server.storage);
+
+        let metrics = server.storage.metrics();
+        assert_eq!(metrics.len(), 3600);
+        assert!(metrics.iter().all(|m| m.tick >= 100));
+    }
+}
@@ -1,19 +1,38 @@
 //! Helper functions for SpacetimeDB reducers
 
 use glam::IVec2;
-use spacetimedb::{ReducerContext, Table};
+use spacetimedb::ReducerContext;
+use sunaba_core::world::World;
 
 use crate::encoding;
-use crate::tables::{ChunkData, Player, chunk_data, player};
+use crate::storage::ServerStorage;
+use crate::tables::{ChunkData, Player};
 
 // ============================================================================
 // Helper Functions for World Simulation
 // ============================================================================
 
+/// Create the server World: empty if the database already holds chunks,
+/// otherwise with terrain generation from the configured seed
+pub fn create_server_world(storage: &impl ServerStorage, seed: u64) -> World {
+    if storage.chunk_count() > 0 {
+        log::info!("Initializing server World (loading existing chunks from database)");
+        World::new(false) // No terrain gen needed
+    } else {
+        log::info!(
+            "Initializing server World with seed {} (fresh database)",
+            seed
+        );
+        let mut world = World::new(true);
+        world.set_generator(seed);
+        world
+    }
+}
+
 /// Load chunk from DB or generate new one
 pub fn load_or_create_chunk(
-    ctx: &ReducerContext,
-    world: &mut sunaba_core::world::World,
+    storage: &mut impl ServerStorage,
+    world: &mut World,
     chunk_x: i32,
     chunk_y: i32,
 ) {
@@ -24,11 +43,7 @@ pub fn load_or_create_chunk(
     }
 
     // Try load from DB
-    if let Some(data) = ctx
-        .db
-        .chunk_data()
-        .iter()
-        .find(|c| c.x == chunk_x && c.y == chunk_y)
+    if let Some(data) = storage.find_chunk(chunk_x, chunk_y)
         && let Ok(chunk) = encoding::decode_chunk(&data.pixel_data)
     {
         world.insert_chunk(pos, chunk);
@@ -44,54 +59,26 @@ pub fn load_or_create_chunk(
         let pixel_data =
             encoding::encode_chunk(chunk).expect("Failed to encode newly generated chunk");
 
-        upsert_chunk(ctx, chunk_x, chunk_y, pixel_data, 0);
+        upsert_chunk(storage, chunk_x, chunk_y, pixel_data, 0);
     }
 }
 
 /// Insert or update a chunk in the database (upsert by x,y coordinates)
 /// This prevents duplicate rows when the same chunk is inserted multiple times
 pub fn upsert_chunk(
-    ctx: &ReducerContext,
+    storage: &mut impl ServerStorage,
     chunk_x: i32,
     chunk_y: i32,
     pixel_data: Vec<u8>,
     tick: u64,
 ) {
-    // Check if chunk already exists
-    if let Some(existing) = ctx
-        .db
-        .chunk_data()
-        .iter()
-        .find(|c| c.x == chunk_x && c.y == chunk_y)
-    {
-        // Update existing row
-        ctx.db.chunk_data().id().update(ChunkData {
-            pixel_data,
-            dirty: false,
-            last_modified_tick: tick,
-            ..existing
-        });
-    } else {
-        // Insert new row
-        ctx.db.chunk_data().insert(ChunkData {
-            id: 0, // auto_inc for new chunks only
-            x: chunk_x,
-            y: chunk_y,
-            pixel_data,
-            dirty: false,
-            last_modified_tick: tick,
-        });
-    }
+    write_chunk_row(storage, chunk_x, chunk_y, pixel_data, false, tick);
 }
 
 /// Sync dirty chunks from World to database
 /// CRITICAL OPTIMIZATION: Only syncs chunks marked dirty by simulation
 /// Returns the number of chunks synced
-pub fn sync_dirty_chunks_to_db(
-    ctx: &ReducerContext,
-    world: &sunaba_core::world::World,
-    tick: u64,
-) -> u32 {
+pub fn sync_dirty_chunks_to_db(storage: &mut impl ServerStorage, world: &World, tick: u64) -> u32 {
     let mut synced_count = 0;
 
     for (pos, chunk) in world.chunks_iter() {
@@ -105,36 +92,41 @@ pub fn sync_dirty_chunks_to_db(
             continue;
         };
 
-        // Update or insert
-        if let Some(existing) = ctx
-            .db
-            .chunk_data()
-            .iter()
-            .find(|c| c.x == pos.x && c.y == pos.y)
-        {
-            ctx.db.chunk_data().id().update(ChunkData {
-                pixel_data,
-                dirty: true,
-                last_modified_tick: tick,
-                ..existing
-            });
-        } else {
-            ctx.db.chunk_data().insert(ChunkData {
-                id: 0,
-                x: pos.x,
-                y: pos.y,
-                pixel_data,
-                dirty: true,
-                last_modified_tick: tick,
-            });
-        }
-
+        write_chunk_row(storage, pos.x, pos.y, pixel_data, true, tick);
         synced_count += 1;
     }
 
     synced_count
 }
 
+/// Update the chunk row at (x, y), inserting it if missing
+pub fn write_chunk_row(
+    storage: &mut impl ServerStorage,
+    chunk_x: i32,
+    chunk_y: i32,
+    pixel_data: Vec<u8>,
+    dirty: bool,
+    tick: u64,
+) {
+    if let Some(existing) = storage.find_chunk(chunk_x, chunk_y) {
+        storage.update_chunk(ChunkData {
+            pixel_data,
+            dirty,
+            last_modified_tick: tick,
+            ..existing
+        });
+    } else {
+        storage.insert_chunk(ChunkData {
+            id: 0, // auto_inc for new chunks only
+            x: chunk_x,
+            y: chunk_y,
+            pixel_data,
+            dirty,
+            last_modified_tick: tick,
+        });
+    }
+}
+
 /// Get chunks at radius r from center (for settlement system)
 pub fn get_chunks_at_radius(center_x: i32, center_y: i32, r: i32) -> Vec<(i32, i32)> {
     let mut chunks = Vec::new();
@@ -163,22 +155,23 @@ pub fn get_chunks_at_radius(center_x: i32, center_y: i32, r: i32) -> Vec<(i32, i
 // ============================================================================
 
 /// Find chunk at given chunk coordinates
-pub fn find_chunk_at(ctx: &ReducerContext, chunk_x: i32, chunk_y: i32) -> Option<ChunkData> {
-    ctx.db
-        .chunk_data()
-        .iter()
-        .find(|c| c.x == chunk_x && c.y == chunk_y)
+pub fn find_chunk_at(
+    storage: &impl ServerStorage,
+    chunk_x: i32,
+    chunk_y: i32,
+) -> Option<ChunkData> {
+    storage.find_chunk(chunk_x, chunk_y)
 }
 
 /// Update player physics based on velocity
-pub fn update_player_physics(ctx: &ReducerContext, player: Player, delta_time: f32) {
+pub fn update_player_physics(storage: &mut impl ServerStorage, player: Player, delta_time: f32) {
     const GRAVITY: f32 = 300.0;
 
     let vel_y = player.vel_y + GRAVITY * delta_time;
     let new_x = player.x + player.vel_x * delta_time;
     let new_y = player.y + vel_y * delta_time;
 
-    ctx.db.player().identity().update(Player {
+    storage.update_player(Player {
         x: new_x,
         y: new_y,
         vel_y,
@@ -186,6 +179,15 @@ pub fn update_player_physics(ctx: &ReducerContext, player: Player, delta_time: f
     });
 }
 
+// ============================================================================
+// Time Helpers
+// ============================================================================
+
+/// Reducer timestamp in milliseconds since the Unix epoch
+pub fn now_ms(ctx: &ReducerContext) -> u64 {
+    (ctx.timestamp.to_micros_since_unix_epoch() / 1000).max(0) as u64
+}
+
 // ============================================================================
 // Admin Authentication Helpers
 // ============================================================================
@@ -9,9 +9,13 @@
 //! Full functionality will be added incrementally.
 
 mod encoding;
+#[cfg(test)]
+mod harness;
 mod helpers;
+pub mod logic;
 mod reducers;
 mod state;
+pub mod storage;
 mod tables;
 mod world_access;
 
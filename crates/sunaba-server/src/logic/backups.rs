@@ -0,0 +1,334 @@
+//! World snapshots, rollback and chunk import
+//!
+//! A snapshot copies every chunk blob, every living creature and the world
+//! config at a tick. Periodic snapshots rotate; labelled ones taken by admins
+//! stay until deleted. Rolling back replaces whole chunks (the entire world or
+//! the chunks touching a region) and first takes a snapshot of the current
+//! state, so a rollback can itself be undone.
+
+use glam::IVec2;
+use spacetimedb::Identity;
+use std::time::Duration;
+use sunaba_core::world::World;
+use sunaba_simulation::CHUNK_SIZE;
+
+use super::chat::reply;
+use super::claims::require_admin;
+use crate::encoding;
+use crate::helpers::write_chunk_row;
+use crate::storage::ServerStorage;
+use crate::tables::{CreatureData, SnapshotChunk, SnapshotCreature, WorldConfig, WorldSnapshot};
+
+/// Ticks between periodic snapshots (10 minutes at 60fps)
+pub const SNAPSHOT_INTERVAL_TICKS: u64 = 60 * 60 * 10;
+
+/// How often the snapshot tick checks whether a periodic snapshot is due
+pub const SNAPSHOT_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Periodic snapshots kept (oldest are deleted)
+pub const MAX_AUTOMATIC_SNAPSHOTS: usize = 6;
+
+/// Longest snapshot label (characters)
+const MAX_SNAPSHOT_LABEL_LEN: usize = 64;
+
+/// Inclusive chunk-space bounds of a rollback
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChunkRegion {
+    pub min: IVec2,
+    pub max: IVec2,
+}
+
+impl ChunkRegion {
+    /// Chunks touching an inclusive world pixel rectangle (corners in any order)
+    pub fn from_pixels(x1: i32, y1: i32, x2: i32, y2: i32) -> Self {
+        let size = CHUNK_SIZE as i32;
+        Self {
+            min: IVec2::new(x1.min(x2).div_euclid(size), y1.min(y2).div_euclid(size)),
+            max: IVec2::new(x1.max(x2).div_euclid(size), y1.max(y2).div_euclid(size)),
+        }
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.min.x && x <= self.max.x && y >= self.min.y && y <= self.max.y
+    }
+}
+
+/// Copy chunks, living creatures and the config into a new snapshot
+///
+/// Returns the snapshot id. Periodic snapshots beyond
+/// `MAX_AUTOMATIC_SNAPSHOTS` are deleted oldest first.
+pub fn take_snapshot(
+    storage: &mut impl ServerStorage,
+    label: String,
+    automatic: bool,
+    now_ms: u64,
+) -> Result<u64, String> {
+    let config = storage
+        .config()
+        .ok_or_else(|| "World config not found".to_string())?;
+    let chunks = storage.chunks();
+    let creatures = storage.alive_creatures();
+
+    let snapshot_id = storage.insert_snapshot(WorldSnapshot {
+        id: 0,
+        tick: config.tick_count,
+        taken_at_ms: now_ms,
+        label: label.trim().chars().take(MAX_SNAPSHOT_LABEL_LEN).collect(),
+        automatic,
+        config,
+        chunk_count: chunks.len() as u32,
+        creature_count: creatures.len() as u32,
+    });
+
+    for chunk in chunks {
+        storage.insert_snapshot_chunk(SnapshotChunk {
+            id: 0,
+            snapshot_id,
+            x: chunk.x,
+            y: chunk.y,
+            pixel_data: chunk.pixel_data,
+        });
+    }
+    for creature in creatures {
+        storage.insert_snapshot_creature(SnapshotCreature {
+            id: 0,
+            snapshot_id,
+            creature,
+        });
+    }
+
+    if automatic {
+        let automatic: Vec<_> = storage
+            .snapshots()
+            .into_iter()
+            .filter(|s| s.automatic)
+            .collect();
+        let excess = automatic.len().saturating_sub(MAX_AUTOMATIC_SNAPSHOTS);
+        for old in &automatic[..excess] {
+            storage.delete_snapshot(old.id);
+        }
+    }
+
+    log::info!("Snapshot {} taken", snapshot_id);
+    Ok(snapshot_id)
+}
+
+/// Take a periodic snapshot if one is due
+///
+/// Runs from its own scheduled reducer rather than the world tick, so the
+/// copy never lands in the middle of a simulation frame. Nothing changes
+/// while nobody is online, so empty servers skip it.
+pub fn take_periodic_snapshot(storage: &mut impl ServerStorage, now_ms: u64) {
+    let Some(config) = storage.config() else {
+        return;
+    };
+    if !storage.has_online_players() {
+        return;
+    }
+
+    let last_tick = storage
+        .snapshots()
+        .iter()
+        .filter(|s| s.automatic)
+        .map(|s| s.tick)
+        .max();
+    let due = last_tick.is_none_or(|tick| config.tick_count >= tick + SNAPSHOT_INTERVAL_TICKS);
+    if !due {
+        return;
+    }
+
+    if let Err(e) = take_snapshot(storage, String::new(), true, now_ms) {
+        log::error!("Periodic snapshot failed: {}", e);
+    }
+}
+
+/// Take a labelled snapshot (admin only); it is never rotated out
+pub fn create_snapshot(
+    storage: &mut impl ServerStorage,
+    identity: Identity,
+    label: String,
+    now_ms: u64,
+) -> Result<u64, String> {
+    require_admin(storage, identity)?;
+    take_snapshot(storage, label, false, now_ms)
+}
+
+/// Delete a snapshot and its rows (admin only)
+pub fn delete_snapshot(
+    storage: &mut impl ServerStorage,
+    identity: Identity,
+    snapshot_id: u64,
+) -> Result<(), String> {
+    require_admin(storage, identity)?;
+    if storage.find_snapshot(snapshot_id).is_none() {
+        return Err(format!("Snapshot {} not found", snapshot_id));
+    }
+    storage.delete_snapshot(snapshot_id);
+    Ok(())
+}
+
+/// Reply to an admin with the available snapshots (newest first)
+pub fn list_snapshots(
+    storage: &mut impl ServerStorage,
+    identity: Identity,
+    now_ms: u64,
+) -> Result<(), String> {
+    require_admin(storage, identity)?;
+    let player = storage
+        .find_player(identity)
+        .ok_or_else(|| "Player not found".to_string())?;
+
+    let snapshots = storage.snapshots();
+    if snapshots.is_empty() {
+        reply(storage, &player, now_ms, "No snapshots".to_string());
+        return Ok(());
+    }
+
+    let lines: Vec<_> = snapshots
+        .iter()
+        .rev()
+        .map(|s| {
+            let label = if s.automatic {
+                "periodic"
+            } else {
+                s.label.as_str()
+            };
+            format!(
+                "#{} tick {} ({}, {} chunks, {} creatures)",
+                s.id, s.tick, label, s.chunk_count, s.creature_count
+            )
+        })
+        .collect();
+    reply(storage, &player, now_ms, lines.join("\n"));
+    Ok(())
+}
+
+/// Drop a chunk from the server `World` so it reloads from the database
+fn evict_chunk(world: Option<&mut World>, chunk_x: i32, chunk_y: i32) {
+    if let Some(world) = world {
+        world.chunks_mut().remove(&IVec2::new(chunk_x, chunk_y));
+    }
+}
+
+/// Roll the world (or the chunks in `region`) back to a snapshot (admin only)
+///
+/// Chunks the snapshot doesn't have are deleted and regenerate from the seed.
+/// Creatures in the affected chunks are replaced by the snapshot's, and a
+/// whole-world rollback also restores the clock and world settings (the tick
+/// counter keeps running). Restored chunks are evicted from the server's
+/// `World` so they reload from the database.
+pub fn rollback(
+    storage: &mut impl ServerStorage,
+    mut world: Option<&mut World>,
+    identity: Identity,
+    snapshot_id: u64,
+    region: Option<ChunkRegion>,
+    now_ms: u64,
+) -> Result<(), String> {
+    require_admin(storage, identity)?;
+    let snapshot = storage
+        .find_snapshot(snapshot_id)
+        .ok_or_else(|| format!("Snapshot {} not found", snapshot_id))?;
+    let config = storage
+        .config()
+        .ok_or_else(|| "World config not found".to_string())?;
+
+    // Keep the state being replaced so the rollback can be undone
+    take_snapshot(
+        storage,
+        format!("Before rollback to #{}", snapshot_id),
+        false,
+        now_ms,
+    )?;
+
+    let in_region = |x: i32, y: i32| region.is_none_or(|r| r.contains(x, y));
+    let tick = config.tick_count;
+
+    let saved = storage.snapshot_chunks(snapshot_id);
+    for chunk in storage.chunks() {
+        if in_region(chunk.x, chunk.y) && !saved.iter().any(|s| s.x == chunk.x && s.y == chunk.y) {
+            storage.delete_chunk(chunk.id);
+            evict_chunk(world.as_deref_mut(), chunk.x, chunk.y);
+        }
+    }
+    let mut restored_chunks = 0;
+    for chunk in saved {
+        if in_region(chunk.x, chunk.y) {
+            write_chunk_row(storage, chunk.x, chunk.y, chunk.pixel_data, true, tick);
+            evict_chunk(world.as_deref_mut(), chunk.x, chunk.y);
+            restored_chunks += 1;
+        }
+    }
+
+    for creature in storage.creatures() {
+        if in_region(creature.chunk_x, creature.chunk_y) {
+            storage.delete_creature(creature.id);
+        }
+    }
+    for saved in storage.snapshot_creatures(snapshot_id) {
+        if in_region(saved.creature.chunk_x, saved.creature.chunk_y) {
+            // Fresh id: the original row may still exist outside the region
+            storage.insert_creature(CreatureData {
+                id: 0,
+                ..saved.creature
+            });
+        }
+    }
+
+    let config = if region.is_none() {
+        if let Some(world) = world.as_deref_mut() {
+            world.set_time_of_day(snapshot.config.time_of_day);
+        }
+        WorldConfig {
+            max_creatures: snapshot.config.max_creatures,
+            spawn_protection_radius: snapshot.config.spawn_protection_radius,
+            time_of_day: snapshot.config.time_of_day,
+            ..config
+        }
+    } else {
+        config
+    };
+    // Wake the world so the restored chunks get simulated and synced
+    storage.set_config(WorldConfig {
+        is_idle: false,
+        last_activity_tick: tick,
+        ..config
+    });
+
+    log::info!(
+        "Rolled back {} chunks to snapshot {} (tick {})",
+        restored_chunks,
+        snapshot_id,
+        snapshot.tick
+    );
+    Ok(())
+}
+
+/// Replace one chunk with an uploaded blob (admin only)
+///
+/// `pixel_data` uses the `chunk_data` encoding, which is also what
+/// singleplayer chunk files contain once decompressed.
+pub fn import_chunk(
+    storage: &mut impl ServerStorage,
+    world: Option<&mut World>,
+    identity: Identity,
+    chunk_x: i32,
+    chunk_y: i32,
+    pixel_data: Vec<u8>,
+) -> Result<(), String> {
+    require_admin(storage, identity)?;
+    let chunk = encoding::decode_chunk(&pixel_data)?;
+    if (chunk.x, chunk.y) != (chunk_x, chunk_y) {
+        return Err(format!(
+            "Chunk data is for ({}, {}), not ({}, {})",
+            chunk.x, chunk.y, chunk_x, chunk_y
+        ));
+    }
+
+    let tick = storage.config().map_or(0, |c| c.tick_count);
+    write_chunk_row(storage, chunk_x, chunk_y, pixel_data, true, tick);
+    if let Some(world) = world {
+        world.insert_chunk(IVec2::new(chunk_x, chunk_y), chunk);
+    }
+    Ok(())
+}
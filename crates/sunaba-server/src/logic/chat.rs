@@ -0,0 +1,366 @@
+//! Chat messages and slash commands
+//!
+//! Plain text is broadcast to everyone. Text starting with `/` is a command:
+//! replies go back to the sender only (`recipient`), and the world-changing
+//! commands require an `AdminUser` row. Every message or command a player
+//! sends counts against the same rate limit, tracked on their player row
+//! (the chat table is trimmed, so it can't be counted from there).
+
+use spacetimedb::Identity;
+use sunaba_core::world::{DAY_NIGHT_CYCLE_DURATION, World};
+
+use super::creatures::spawn_creature;
+use super::players::session_player;
+use crate::storage::ServerStorage;
+use crate::tables::{ChatMessage, Player, WorldConfig};
+
+/// Longest chat message (characters)
+pub const MAX_CHAT_LENGTH: usize = 256;
+
+/// Messages kept in the table (oldest are deleted)
+pub const MAX_CHAT_HISTORY: usize = 100;
+
+/// Messages and commands a player may send per window
+pub const CHAT_RATE_LIMIT: usize = 5;
+
+/// Rate limit window (milliseconds)
+pub const CHAT_RATE_WINDOW_MS: u64 = 10_000;
+
+const HELP_TEXT: &str = "Commands: /help, /who, /pause, /time <seconds|dawn|noon|dusk|midnight>, \
+/tp [player] <x> <y>, /tp <player>, /kick <player>, /spawn <archetype> [x y]";
+
+/// Follow-up work for the reducer (things `ServerStorage` can't do)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChatEffect {
+    None,
+    /// Simulation was unpaused; the world tick timer must be rescheduled
+    ResumeSimulation,
+}
+
+fn display_name(player: &Player) -> String {
+    player.name.clone().unwrap_or_else(|| "Player".to_string())
+}
+
+/// Insert a message and trim the history to `MAX_CHAT_HISTORY`
+fn post(storage: &mut impl ServerStorage, message: ChatMessage) {
+    storage.insert_chat_message(message);
+
+    let messages = storage.chat_messages();
+    let excess = messages.len().saturating_sub(MAX_CHAT_HISTORY);
+    for old in &messages[..excess] {
+        storage.delete_chat_message(old.id);
+    }
+}
+
+/// Server message; `recipient: None` announces to everyone
+fn post_system(
+    storage: &mut impl ServerStorage,
+    sender: &Player,
+    recipient: Option<Identity>,
+    text: String,
+    now_ms: u64,
+) {
+    post(
+        storage,
+        ChatMessage {
+            id: 0,
+            sender: sender.identity,
+            sender_name: display_name(sender),
+            text,
+            sent_at_ms: now_ms,
+            system: true,
+            recipient,
+        },
+    );
+}
+
+/// Server message to `player` only
+pub(crate) fn reply(storage: &mut impl ServerStorage, player: &Player, now_ms: u64, text: String) {
+    post_system(storage, player, Some(player.identity), text, now_ms);
+}
+
+/// Find a player by display name (case-insensitive)
+fn find_player_by_name(storage: &impl ServerStorage, name: &str) -> Result<Player, String> {
+    storage
+        .players()
+        .into_iter()
+        .find(|p| {
+            p.name
+                .as_deref()
+                .is_some_and(|n| n.eq_ignore_ascii_case(name))
+        })
+        .ok_or_else(|| format!("No player named {}", name))
+}
+
+fn parse_coord(arg: &str) -> Result<f32, String> {
+    arg.parse::<f32>()
+        .ok()
+        .filter(|v| v.is_finite())
+        .ok_or_else(|| format!("Invalid coordinate: {}", arg))
+}
+
+/// Parse a `/time` argument into seconds of the day/night cycle
+fn parse_time_of_day(arg: &str) -> Result<f32, String> {
+    let quarter = DAY_NIGHT_CYCLE_DURATION / 4.0;
+    match arg.to_lowercase().as_str() {
+        "midnight" => Ok(0.0),
+        "dawn" => Ok(quarter),
+        "noon" | "day" => Ok(quarter * 2.0),
+        "dusk" | "night" => Ok(quarter * 3.0),
+        other => other
+            .parse::<f32>()
+            .ok()
+            .filter(|v| v.is_finite())
+            .map(|v| v.rem_euclid(DAY_NIGHT_CYCLE_DURATION))
+            .ok_or_else(|| format!("Invalid time: {}", arg)),
+    }
+}
+
+/// Send a chat message or run a slash command
+///
+/// Errors (rate limit, empty or overlong text, unknown player) are returned
+/// to the caller; command failures after that are replied in chat.
+pub fn send_chat(
+    storage: &mut impl ServerStorage,
+    world: Option<&mut World>,
+    identity: Identity,
+    text: String,
+    now_ms: u64,
+) -> Result<ChatEffect, String> {
+    let player = session_player(storage, identity)?;
+
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Empty message".to_string());
+    }
+    if text.chars().count() > MAX_CHAT_LENGTH {
+        return Err(format!(
+            "Message too long (max {} characters)",
+            MAX_CHAT_LENGTH
+        ));
+    }
+
+    let window_start = now_ms.saturating_sub(CHAT_RATE_WINDOW_MS);
+    let mut chat_sent_ms: Vec<u64> = player
+        .chat_sent_ms
+        .iter()
+        .copied()
+        .filter(|&sent| sent >= window_start)
+        .collect();
+    if chat_sent_ms.len() >= CHAT_RATE_LIMIT {
+        return Err("Sending messages too fast".to_string());
+    }
+    chat_sent_ms.push(now_ms);
+    storage.update_player(Player {
+        chat_sent_ms,
+        ..player.clone()
+    });
+
+    let Some(command) = text.strip_prefix('/') else {
+        post(
+            storage,
+            ChatMessage {
+                id: 0,
+                sender: identity,
+                sender_name: display_name(&player),
+                text: text.to_string(),
+                sent_at_ms: now_ms,
+                system: false,
+                recipient: None,
+            },
+        );
+        return Ok(ChatEffect::None);
+    };
+
+    match run_command(storage, world, &player, command, now_ms) {
+        Ok(effect) => Ok(effect),
+        Err(e) => {
+            post_system(storage, &player, Some(identity), e, now_ms);
+            Ok(ChatEffect::None)
+        }
+    }
+}
+
+/// Run a slash command (without the `/`); errors are replied to the sender
+fn run_command(
+    storage: &mut impl ServerStorage,
+    world: Option<&mut World>,
+    player: &Player,
+    command: &str,
+    now_ms: u64,
+) -> Result<ChatEffect, String> {
+    let mut args = command.split_whitespace();
+    let name = args.next().unwrap_or_default().to_lowercase();
+    let args: Vec<&str> = args.collect();
+
+    // Commands anyone can use
+    match name.as_str() {
+        "help" => {
+            reply(storage, player, now_ms, HELP_TEXT.to_string());
+            return Ok(ChatEffect::None);
+        }
+        "who" => {
+            let names: Vec<_> = storage.online_players().iter().map(display_name).collect();
+            reply(
+                storage,
+                player,
+                now_ms,
+                format!("{} online: {}", names.len(), names.join(", ")),
+            );
+            return Ok(ChatEffect::None);
+        }
+        _ => {}
+    }
+
+    if !matches!(name.as_str(), "pause" | "time" | "tp" | "kick" | "spawn") {
+        return Err(format!("Unknown command /{} (try /help)", name));
+    }
+    if !storage.is_admin(player.identity) {
+        log::warn!("Admin command /{} denied for {:?}", name, player.identity);
+        return Err(format!("/{} requires admin", name));
+    }
+
+    let config = storage
+        .config()
+        .ok_or_else(|| "World config not found".to_string())?;
+
+    match (name.as_str(), args.as_slice()) {
+        ("pause", []) => {
+            let paused = !config.simulation_paused;
+            storage.set_config(WorldConfig {
+                simulation_paused: paused,
+                ..config
+            });
+            let state = if paused { "paused" } else { "resumed" };
+            post_system(
+                storage,
+                player,
+                None,
+                format!("{} {} the simulation", display_name(player), state),
+                now_ms,
+            );
+            Ok(if paused {
+                ChatEffect::None
+            } else {
+                ChatEffect::ResumeSimulation
+            })
+        }
+        ("time", [arg]) => {
+            let time_of_day = parse_time_of_day(arg)?;
+            if let Some(world) = world {
+                world.set_time_of_day(time_of_day);
+            }
+            storage.set_config(WorldConfig {
+                time_of_day,
+                ..config
+            });
+            reply(
+                storage,
+                player,
+                now_ms,
+                format!("Time set to {:.0}s", time_of_day),
+            );
+            Ok(ChatEffect::None)
+        }
+        ("tp", [target]) => {
+            let target = find_player_by_name(storage, target)?;
+            teleport(storage, player.identity, target.x, target.y)?;
+            reply(
+                storage,
+                player,
+                now_ms,
+                format!("Teleported to {}", display_name(&target)),
+            );
+            Ok(ChatEffect::None)
+        }
+        ("tp", [x, y]) => {
+            let (x, y) = (parse_coord(x)?, parse_coord(y)?);
+            teleport(storage, player.identity, x, y)?;
+            reply(
+                storage,
+                player,
+                now_ms,
+                format!("Teleported to ({:.0}, {:.0})", x, y),
+            );
+            Ok(ChatEffect::None)
+        }
+        ("tp", [target, x, y]) => {
+            let target = find_player_by_name(storage, target)?;
+            let (x, y) = (parse_coord(x)?, parse_coord(y)?);
+            teleport(storage, target.identity, x, y)?;
+            reply(
+                storage,
+                player,
+                now_ms,
+                format!(
+                    "Teleported {} to ({:.0}, {:.0})",
+                    display_name(&target),
+                    x,
+                    y
+                ),
+            );
+            Ok(ChatEffect::None)
+        }
+        ("kick", [target]) => {
+            let target = find_player_by_name(storage, target)?;
+            if !target.online {
+                return Err(format!("{} is not online", display_name(&target)));
+            }
+            // Reducers can't close a connection; clients disconnect when
+            // their row goes offline, and `session_player` refuses anything
+            // they send until they reconnect
+            storage.update_player(Player {
+                online: false,
+                ..target.clone()
+            });
+            post_system(
+                storage,
+                player,
+                None,
+                format!("{} was kicked", display_name(&target)),
+                now_ms,
+            );
+            Ok(ChatEffect::None)
+        }
+        ("spawn", [archetype, rest @ ..]) if rest.is_empty() || rest.len() == 2 => {
+            let (x, y) = match rest {
+                [x, y] => (parse_coord(x)?, parse_coord(y)?),
+                _ => (player.x, player.y),
+            };
+            if !spawn_creature(storage, archetype.to_string(), x, y) {
+                return Err("Creature limit reached".to_string());
+            }
+            reply(
+                storage,
+                player,
+                now_ms,
+                format!("Spawned {} at ({:.0}, {:.0})", archetype, x, y),
+            );
+            Ok(ChatEffect::None)
+        }
+        _ => Err(format!("Invalid arguments for /{} (try /help)", name)),
+    }
+}
+
+/// Move a player; their client picks up the new `teleport_seq`
+pub fn teleport(
+    storage: &mut impl ServerStorage,
+    identity: Identity,
+    x: f32,
+    y: f32,
+) -> Result<(), String> {
+    let player = storage
+        .find_player(identity)
+        .ok_or_else(|| "Player not found".to_string())?;
+    storage.update_player(Player {
+        x,
+        y,
+        vel_x: 0.0,
+        vel_y: 0.0,
+        teleport_seq: player.teleport_seq.wrapping_add(1),
+        teleport_x: x,
+        teleport_y: y,
+        ..player
+    });
+    Ok(())
+}
@@ -0,0 +1,277 @@
+//! Land claims, build permissions and spawn protection
+//!
+//! Players claim rectangular regions; inside a claim only the owner and
+//! members granted the matching permission may build, mine or interact
+//! (crafting, eating). The square around the spawn point is reserved for
+//! admins' building and mining; admins bypass every check.
+
+use spacetimedb::Identity;
+
+use super::players::{SPAWN_POINT, session_player};
+use crate::storage::ServerStorage;
+use crate::tables::{ClaimMember, LandClaim, WorldConfig};
+
+/// Largest claim side length (pixels)
+pub const MAX_CLAIM_SIZE: i32 = 256;
+
+/// Claims a non-admin player may own at once
+pub const MAX_CLAIMS_PER_PLAYER: usize = 4;
+
+/// Longest claim name (characters)
+const MAX_CLAIM_NAME_LEN: usize = 32;
+
+/// What a player is trying to do at a position
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClaimAction {
+    Build,
+    Mine,
+    Interact,
+}
+
+impl LandClaim {
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.min_x && x <= self.max_x && y >= self.min_y && y <= self.max_y
+    }
+
+    /// Whether the claim intersects the inclusive rectangle
+    pub fn overlaps(&self, min_x: i32, min_y: i32, max_x: i32, max_y: i32) -> bool {
+        self.min_x <= max_x && min_x <= self.max_x && self.min_y <= max_y && min_y <= self.max_y
+    }
+
+    /// Whether `identity` may perform `action` inside this claim
+    pub fn allows(&self, identity: Identity, action: ClaimAction) -> bool {
+        if self.owner == identity {
+            return true;
+        }
+        self.members
+            .iter()
+            .find(|m| m.identity == identity)
+            .is_some_and(|m| match action {
+                ClaimAction::Build => m.build,
+                ClaimAction::Mine => m.mine,
+                ClaimAction::Interact => m.interact,
+            })
+    }
+}
+
+/// Inclusive bounds of the protected spawn zone, if enabled
+pub fn spawn_zone(config: &WorldConfig) -> Option<(i32, i32, i32, i32)> {
+    let radius = config.spawn_protection_radius;
+    if radius <= 0 {
+        return None;
+    }
+    let (x, y) = (SPAWN_POINT.0 as i32, SPAWN_POINT.1 as i32);
+    Some((
+        x.saturating_sub(radius),
+        y.saturating_sub(radius),
+        x.saturating_add(radius),
+        y.saturating_add(radius),
+    ))
+}
+
+/// Fail unless `identity` is an admin
+pub fn require_admin(storage: &impl ServerStorage, identity: Identity) -> Result<(), String> {
+    if storage.is_admin(identity) {
+        Ok(())
+    } else {
+        Err("Admin only".to_string())
+    }
+}
+
+/// Check that `identity` may perform `action` at a world position
+pub fn check_permission(
+    storage: &impl ServerStorage,
+    identity: Identity,
+    world_x: i32,
+    world_y: i32,
+    action: ClaimAction,
+) -> Result<(), String> {
+    if storage.is_admin(identity) {
+        return Ok(());
+    }
+
+    // Spawn protection only guards the terrain
+    if action != ClaimAction::Interact
+        && let Some((min_x, min_y, max_x, max_y)) = storage.config().as_ref().and_then(spawn_zone)
+        && (min_x..=max_x).contains(&world_x)
+        && (min_y..=max_y).contains(&world_y)
+    {
+        return Err("Spawn area is protected".to_string());
+    }
+
+    match storage
+        .claims()
+        .into_iter()
+        .find(|c| c.contains(world_x, world_y))
+    {
+        Some(claim) if !claim.allows(identity, action) => {
+            Err(format!("Land is claimed ({})", claim.name))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Claim a rectangle of land (inclusive world pixel corners, any order)
+///
+/// Claims can't overlap each other or the spawn zone. Admins skip the size,
+/// count and spawn checks.
+pub fn claim_land(
+    storage: &mut impl ServerStorage,
+    identity: Identity,
+    name: String,
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+) -> Result<(), String> {
+    session_player(storage, identity)?;
+
+    let (min_x, max_x) = (x1.min(x2), x1.max(x2));
+    let (min_y, max_y) = (y1.min(y2), y1.max(y2));
+    let admin = storage.is_admin(identity);
+
+    if !admin {
+        // i64 so corners at opposite ends of the i32 range can't wrap around
+        let width = i64::from(max_x) - i64::from(min_x) + 1;
+        let height = i64::from(max_y) - i64::from(min_y) + 1;
+        if width > i64::from(MAX_CLAIM_SIZE) || height > i64::from(MAX_CLAIM_SIZE) {
+            return Err(format!(
+                "Claims can be at most {}x{} pixels",
+                MAX_CLAIM_SIZE, MAX_CLAIM_SIZE
+            ));
+        }
+
+        let owned = storage
+            .claims()
+            .iter()
+            .filter(|c| c.owner == identity)
+            .count();
+        if owned >= MAX_CLAIMS_PER_PLAYER {
+            return Err(format!(
+                "Claim limit reached ({} per player)",
+                MAX_CLAIMS_PER_PLAYER
+            ));
+        }
+
+        if let Some((sx1, sy1, sx2, sy2)) = storage.config().as_ref().and_then(spawn_zone)
+            && min_x <= sx2
+            && sx1 <= max_x
+            && min_y <= sy2
+            && sy1 <= max_y
+        {
+            return Err("Can't claim the spawn area".to_string());
+        }
+    }
+
+    if let Some(other) = storage
+        .claims()
+        .into_iter()
+        .find(|c| c.overlaps(min_x, min_y, max_x, max_y))
+    {
+        return Err(format!("Overlaps existing claim ({})", other.name));
+    }
+
+    let name = name.trim();
+    let name = if name.is_empty() {
+        "Claim".to_string()
+    } else {
+        name.chars().take(MAX_CLAIM_NAME_LEN).collect()
+    };
+
+    log::info!(
+        "Land claimed: {} ({}, {})..({}, {})",
+        name,
+        min_x,
+        min_y,
+        max_x,
+        max_y
+    );
+    storage.insert_claim(LandClaim {
+        id: 0,
+        owner: identity,
+        name,
+        min_x,
+        min_y,
+        max_x,
+        max_y,
+        members: Vec::new(),
+    });
+    Ok(())
+}
+
+/// Claim owned by `identity`, or any claim for admins
+fn managed_claim(
+    storage: &impl ServerStorage,
+    identity: Identity,
+    claim_id: u64,
+) -> Result<LandClaim, String> {
+    session_player(storage, identity)?;
+    let claim = storage
+        .find_claim(claim_id)
+        .ok_or_else(|| format!("Claim {} not found", claim_id))?;
+    if claim.owner != identity && !storage.is_admin(identity) {
+        return Err("Not the owner of this claim".to_string());
+    }
+    Ok(claim)
+}
+
+/// Remove a claim (owner or admin)
+pub fn unclaim_land(
+    storage: &mut impl ServerStorage,
+    identity: Identity,
+    claim_id: u64,
+) -> Result<(), String> {
+    let claim = managed_claim(storage, identity, claim_id)?;
+    log::info!("Land unclaimed: {}", claim.name);
+    storage.delete_claim(claim.id);
+    Ok(())
+}
+
+/// Grant or change another player's permissions in a claim (owner or admin)
+///
+/// Clearing all three permissions removes the member.
+pub fn set_claim_permission(
+    storage: &mut impl ServerStorage,
+    identity: Identity,
+    claim_id: u64,
+    member: Identity,
+    build: bool,
+    mine: bool,
+    interact: bool,
+) -> Result<(), String> {
+    let mut claim = managed_claim(storage, identity, claim_id)?;
+    if member == claim.owner {
+        return Err("The owner always has full access".to_string());
+    }
+
+    claim.members.retain(|m| m.identity != member);
+    if build || mine || interact {
+        claim.members.push(ClaimMember {
+            identity: member,
+            build,
+            mine,
+            interact,
+        });
+    }
+    storage.update_claim(claim);
+    Ok(())
+}
+
+/// Change the spawn protection radius (admin only; 0 disables it)
+pub fn set_spawn_protection(
+    storage: &mut impl ServerStorage,
+    identity: Identity,
+    radius: i32,
+) -> Result<(), String> {
+    if !storage.is_admin(identity) {
+        return Err("Admin only".to_string());
+    }
+    let config = storage
+        .config()
+        .ok_or_else(|| "World config not found".to_string())?;
+    storage.set_config(WorldConfig {
+        spawn_protection_radius: radius.max(0),
+        ..config
+    });
+    Ok(())
+}
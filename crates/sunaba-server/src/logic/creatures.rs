@@ -0,0 +1,348 @@
+//! Creature spawning and AI logic
+//!
+//! `creature_data` stays server-side. Clients get each creature's genome and
+//! morphology once through `creature_info`, and a small `creature_pose` row
+//! per tick while the creature is inside some online player's loaded radius.
+
+use glam::{IVec2, Vec2};
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+use sunaba_creature::{
+    CreatureArchetype, CreatureGenome, CreatureMorphology, CreaturePhysicsState,
+    DeepNeuralController, MorphologyConfig, SensorConfig, SensoryInput,
+};
+use sunaba_simulation::CHUNK_SIZE;
+
+use crate::encoding;
+use crate::storage::ServerStorage;
+use crate::tables::{CreatureData, CreatureInfo, CreaturePose, PartPose};
+use crate::world_access::StorageWorldAccess;
+
+/// Fixed creature AI step (~30fps)
+const CREATURE_DELTA_TIME: f32 = 0.033;
+
+/// Spawn a creature from archetype
+/// Returns false if the creature limit is reached or encoding fails
+pub fn spawn_creature(storage: &mut impl ServerStorage, archetype: String, x: f32, y: f32) -> bool {
+    // Check creature limit
+    let Some(config) = storage.config() else {
+        log::error!("World config not found");
+        return false;
+    };
+
+    let current_count = storage.alive_creatures().len() as u32;
+    if current_count >= config.max_creatures {
+        log::warn!("Maximum creature limit reached");
+        return false;
+    }
+
+    // Parse archetype
+    let archetype_enum = match archetype.to_lowercase().as_str() {
+        "spider" => CreatureArchetype::Spider,
+        "snake" => CreatureArchetype::Snake,
+        "worm" => CreatureArchetype::Worm,
+        "flyer" => CreatureArchetype::Flyer,
+        _ => CreatureArchetype::Evolved,
+    };
+
+    // Create genome based on archetype
+    let genome = match archetype_enum {
+        CreatureArchetype::Spider => CreatureGenome::archetype_spider(),
+        CreatureArchetype::Snake => CreatureGenome::archetype_snake(),
+        CreatureArchetype::Worm => CreatureGenome::archetype_worm(),
+        CreatureArchetype::Flyer => CreatureGenome::archetype_flyer(),
+        CreatureArchetype::Evolved => CreatureGenome::archetype_spider(), // Default to spider for evolved
+    };
+    let morph_config = MorphologyConfig::default();
+    let morphology = archetype_enum.create_morphology(&genome, &morph_config);
+    let physics_state = CreaturePhysicsState::new(&morphology, Vec2::new(x, y));
+
+    // Serialize
+    let Ok(genome_data) = encoding::encode_genome(&genome) else {
+        log::error!("Failed to serialize genome");
+        return false;
+    };
+    let Ok(morphology_data) = encoding::encode_morphology(&morphology) else {
+        log::error!("Failed to serialize morphology");
+        return false;
+    };
+    let Ok(physics_state_data) = encoding::encode_physics_state(&physics_state) else {
+        log::error!("Failed to serialize physics state");
+        return false;
+    };
+
+    // Insert creature
+    storage.insert_creature(CreatureData {
+        id: 0,                        // auto_inc
+        entity_id: config.tick_count, // Use tick as unique ID
+        x,
+        y,
+        chunk_x: (x / CHUNK_SIZE as f32).floor() as i32,
+        chunk_y: (y / CHUNK_SIZE as f32).floor() as i32,
+        vel_x: 0.0,
+        vel_y: 0.0,
+        archetype,
+        genome_data,
+        morphology_data,
+        physics_state_data,
+        health: 100.0,
+        max_health: 100.0,
+        hunger: 100.0,
+        max_hunger: 100.0,
+        generation: genome.generation,
+        food_eaten: 0,
+        blocks_mined: 0,
+        alive: true,
+    });
+
+    log::info!("Spawned creature at ({}, {})", x, y);
+    true
+}
+
+/// One creature AI tick: sense, think, move, metabolize
+/// Returns the delay until the next tick
+pub fn run_creature_tick(storage: &mut impl ServerStorage) -> Duration {
+    // Skip creature tick if no players are online (reduces CPU usage when idle)
+    if !storage.has_online_players() {
+        return Duration::from_millis(200); // Slower tick when idle
+    }
+
+    let interest = interest_areas(storage);
+    let creatures = storage.alive_creatures();
+
+    // Drop published rows of creatures that died or were removed (e.g. by a
+    // rollback); the loop below publishes the rest
+    let alive: BTreeSet<u64> = creatures.iter().map(|c| c.id).collect();
+    let mut has_info = BTreeSet::new();
+    for info in storage.creature_infos() {
+        if alive.contains(&info.creature_id) {
+            has_info.insert(info.creature_id);
+        } else {
+            storage.delete_creature_info(info.creature_id);
+        }
+    }
+    // Last published sequence number of each pose still in the table
+    let mut pose_seq = BTreeMap::new();
+    for pose in storage.creature_poses() {
+        if alive.contains(&pose.creature_id) {
+            pose_seq.insert(pose.creature_id, pose.seq);
+        } else {
+            storage.delete_creature_pose(pose.creature_id);
+        }
+    }
+
+    for creature_row in creatures {
+        // Deserialize creature state
+        let Ok(genome) = encoding::decode_genome(&creature_row.genome_data) else {
+            log::error!(
+                "Failed to deserialize genome for creature {}",
+                creature_row.id
+            );
+            continue;
+        };
+        let Ok(morphology) = encoding::decode_morphology(&creature_row.morphology_data) else {
+            log::error!(
+                "Failed to deserialize morphology for creature {}",
+                creature_row.id
+            );
+            continue;
+        };
+        let Ok(mut physics_state) =
+            encoding::decode_physics_state(&creature_row.physics_state_data)
+        else {
+            log::error!(
+                "Failed to deserialize physics state for creature {}",
+                creature_row.id
+            );
+            continue;
+        };
+
+        // Rebuild brain from genome (deterministic)
+        let num_raycasts = 8;
+        let num_materials = 5;
+        let body_part_features = morphology.body_parts.len() * (9 + num_raycasts + num_materials);
+        let output_dim = morphology.joints.len() + 1;
+
+        let mut brain =
+            DeepNeuralController::from_genome(&genome.controller, body_part_features, output_dim);
+
+        // Gather sensory input through a read-only view of the chunk table
+        let position = Vec2::new(creature_row.x, creature_row.y);
+        let sensory_input = {
+            let world_access = StorageWorldAccess::new(&*storage);
+            SensoryInput::gather(&world_access, position, &SensorConfig::default())
+        };
+
+        // Extract body part features and run neural network
+        let features = extract_creature_features(&morphology, &physics_state, &sensory_input);
+
+        if features.len() == brain.input_dim() {
+            let outputs = brain.forward(&features);
+
+            // Apply motor commands
+            let num_joints = morphology.joints.len();
+            if outputs.len() > num_joints {
+                let joint_commands: Vec<f32> = outputs[..num_joints].to_vec();
+                physics_state.apply_all_motor_commands(
+                    &joint_commands,
+                    &morphology,
+                    CREATURE_DELTA_TIME,
+                );
+            }
+        }
+
+        // Apply physics
+        physics_state.apply_motor_rotations(&morphology, position);
+
+        // Update hunger
+        let mut new_hunger =
+            creature_row.hunger - (genome.metabolic.hunger_rate * CREATURE_DELTA_TIME);
+        let mut new_health = creature_row.health;
+
+        // Starvation damage
+        if new_hunger <= 0.0 {
+            new_hunger = 0.0;
+            new_health -= 5.0 * CREATURE_DELTA_TIME;
+        }
+
+        let alive = new_health > 0.0;
+
+        // Serialize updated state
+        let Ok(physics_state_data) = encoding::encode_physics_state(&physics_state) else {
+            log::error!(
+                "Failed to serialize physics state for creature {}",
+                creature_row.id
+            );
+            continue;
+        };
+
+        let id = creature_row.id;
+        let watched = is_watched(&interest, creature_row.chunk_x, creature_row.chunk_y);
+        if !alive {
+            if has_info.contains(&id) {
+                storage.delete_creature_info(id);
+            }
+            if pose_seq.contains_key(&id) {
+                storage.delete_creature_pose(id);
+            }
+        } else if watched {
+            if !has_info.contains(&id) {
+                storage.insert_creature_info(CreatureInfo {
+                    creature_id: id,
+                    archetype: creature_row.archetype.clone(),
+                    genome_data: creature_row.genome_data.clone(),
+                    morphology_data: creature_row.morphology_data.clone(),
+                    generation: creature_row.generation,
+                });
+            }
+            storage.set_creature_pose(CreaturePose {
+                creature_id: id,
+                seq: pose_seq.get(&id).map_or(1, |seq| seq.wrapping_add(1)),
+                x: creature_row.x,
+                y: creature_row.y,
+                chunk_x: creature_row.chunk_x,
+                chunk_y: creature_row.chunk_y,
+                health: new_health,
+                max_health: creature_row.max_health,
+                parts: physics_state
+                    .get_body_positions()
+                    .into_iter()
+                    .map(|(pos, rotation)| PartPose {
+                        x: pos.x,
+                        y: pos.y,
+                        rotation,
+                    })
+                    .collect(),
+            });
+        } else if pose_seq.contains_key(&id) {
+            storage.delete_creature_pose(id);
+        }
+
+        // Update creature in database
+        storage.update_creature(CreatureData {
+            physics_state_data,
+            health: new_health,
+            hunger: new_hunger,
+            alive,
+            ..creature_row
+        });
+    }
+
+    // Note: std::time::Instant is not available in WASM, so timing metrics are not collected
+    // The creature_tick_time_ms field in ServerMetrics will remain 0.0
+
+    // 33ms = 30fps
+    Duration::from_millis(33)
+}
+
+/// Chunk each online player stands in, with the radius their client keeps loaded
+fn interest_areas(storage: &impl ServerStorage) -> Vec<(IVec2, i32)> {
+    let size = CHUNK_SIZE as f32;
+    storage
+        .online_players()
+        .iter()
+        .map(|p| {
+            let chunk = IVec2::new((p.x / size).floor() as i32, (p.y / size).floor() as i32);
+            (chunk, p.loaded_radius)
+        })
+        .collect()
+}
+
+/// Whether a chunk is inside any player's loaded square
+fn is_watched(areas: &[(IVec2, i32)], chunk_x: i32, chunk_y: i32) -> bool {
+    areas.iter().any(|(center, radius)| {
+        (chunk_x - center.x).abs() <= *radius && (chunk_y - center.y).abs() <= *radius
+    })
+}
+
+/// Extract features from creature for neural network input
+fn extract_creature_features(
+    morphology: &CreatureMorphology,
+    physics_state: &CreaturePhysicsState,
+    sensory_input: &SensoryInput,
+) -> Vec<f32> {
+    let mut features = Vec::new();
+
+    for (i, _part) in morphology.body_parts.iter().enumerate() {
+        // Joint angle and velocity
+        features.push(physics_state.get_motor_angle(i).unwrap_or(0.0));
+        features.push(physics_state.get_motor_velocity(i).unwrap_or(0.0));
+
+        // Position relative to root
+        if let (Some(pos), Some(root_pos)) = (
+            physics_state.part_positions.get(i),
+            physics_state.part_positions.first(),
+        ) {
+            features.push((pos.x - root_pos.x) / 50.0);
+            features.push((pos.y - root_pos.y) / 50.0);
+        } else {
+            features.push(0.0);
+            features.push(0.0);
+        }
+
+        // Ground contact, food direction, etc.
+        features.push(0.0); // ground contact
+        // Food direction from food_direction field
+        if let Some(dir) = sensory_input.food_direction {
+            features.push(dir.x);
+            features.push(dir.y);
+        } else {
+            features.push(0.0);
+            features.push(0.0);
+        }
+        features.push(sensory_input.food_distance);
+        features.push(sensory_input.gradients.food); // food gradient intensity
+
+        // Raycast distances (8 rays)
+        for ray in &sensory_input.raycasts {
+            features.push(ray.distance);
+        }
+        let padding_count = 8_usize.saturating_sub(sensory_input.raycasts.len());
+        features.extend(std::iter::repeat_n(1.0, padding_count));
+
+        // Contact materials (5 slots)
+        features.extend(std::iter::repeat_n(0.0, 5));
+    }
+
+    features
+}
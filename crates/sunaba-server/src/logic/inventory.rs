@@ -0,0 +1,335 @@
+//! Server-side inventory, mining, crafting and survival logic
+//!
+//! Inventories and mining progress live on the `Player` row as bincode blobs
+//! and are only changed here, through the same sunaba-core types the
+//! singleplayer game uses (`Inventory`, `RecipeRegistry::try_craft`,
+//! `calculate_mining_time`). Clients mirror the row from their subscription.
+
+use glam::Vec2;
+use spacetimedb::Identity;
+use sunaba_core::entity::Hunger;
+use sunaba_core::entity::crafting::{RecipeOutput, WorkstationType};
+use sunaba_core::entity::inventory::Inventory;
+use sunaba_core::simulation::mining::{MiningProgress, calculate_mining_time};
+use sunaba_core::world::{TOOL_REACH, World};
+use sunaba_simulation::MaterialId;
+
+use super::claims::{ClaimAction, check_permission};
+use super::players::{get_world_material, session_player, set_world_pixel};
+use crate::encoding;
+use crate::state::{MATERIALS, RECIPES, TOOLS};
+use crate::storage::ServerStorage;
+use crate::tables::Player;
+
+/// Hunger settings (match `sunaba_core::entity::player::Player::new`)
+const HUNGER_MAX: f32 = 100.0;
+const HUNGER_DRAIN_RATE: f32 = 0.1;
+const STARVATION_DAMAGE: f32 = 1.0;
+
+/// Find a player who is alive (dead players can't act until they respawn)
+fn living_player(storage: &impl ServerStorage, identity: Identity) -> Result<Player, String> {
+    let player = session_player(storage, identity)?;
+    if player.health <= 0.0 {
+        return Err("Player is dead".to_string());
+    }
+    Ok(player)
+}
+
+/// Pixels beyond tool reach of the player can't be mined or built on (same
+/// limit as singleplayer)
+fn check_reach(player: &Player, world_x: i32, world_y: i32) -> Result<(), String> {
+    let target = Vec2::new(world_x as f32, world_y as f32);
+    if target.distance(Vec2::new(player.x, player.y)) > TOOL_REACH {
+        return Err(format!("({}, {}) is out of reach", world_x, world_y));
+    }
+    Ok(())
+}
+
+/// Check the interact permission where the player stands
+fn check_interact(storage: &impl ServerStorage, player: &Player) -> Result<(), String> {
+    check_permission(
+        storage,
+        player.identity,
+        player.x.floor() as i32,
+        player.y.floor() as i32,
+        ClaimAction::Interact,
+    )
+}
+
+/// Core hunger component for a player row
+fn hunger_of(player: &Player) -> Hunger {
+    let mut hunger = Hunger::new(HUNGER_MAX, HUNGER_DRAIN_RATE, STARVATION_DAMAGE);
+    hunger.set(player.hunger);
+    hunger
+}
+
+/// Write an updated inventory back to the player row
+fn save_inventory(
+    storage: &mut impl ServerStorage,
+    player: Player,
+    inventory: &Inventory,
+) -> Result<(), String> {
+    let inventory_data = encoding::encode_inventory(inventory)?;
+    storage.update_player(Player {
+        inventory_data,
+        ..player
+    });
+    Ok(())
+}
+
+/// Start mining a pixel; progress advances in the world tick
+///
+/// Mining time comes from the material hardness and the equipped tool, exactly
+/// as in singleplayer.
+pub fn start_mining(
+    storage: &mut impl ServerStorage,
+    world: Option<&World>,
+    identity: Identity,
+    world_x: i32,
+    world_y: i32,
+) -> Result<(), String> {
+    let player = living_player(storage, identity)?;
+    check_reach(&player, world_x, world_y)?;
+    check_permission(storage, identity, world_x, world_y, ClaimAction::Mine)?;
+
+    let material_id = get_world_material(storage, world, world_x, world_y)
+        .ok_or_else(|| format!("Chunk at ({}, {}) not loaded", world_x, world_y))?;
+    let material = MATERIALS.get(material_id);
+    if material.hardness.is_none() {
+        return Err(format!("{} can't be mined", material.name));
+    }
+
+    let tool = player.equipped_tool.and_then(|id| TOOLS.get(id));
+    let required_time = calculate_mining_time(1.0, material, tool);
+
+    let mut progress = MiningProgress::new();
+    progress.start((world_x, world_y), required_time);
+
+    let mining_data = encoding::encode_mining_progress(&progress)?;
+    storage.update_player(Player {
+        mining_data,
+        ..player
+    });
+    Ok(())
+}
+
+/// Stop mining without collecting anything
+pub fn cancel_mining(storage: &mut impl ServerStorage, identity: Identity) {
+    let player = match session_player(storage, identity) {
+        Ok(player) => player,
+        Err(e) => {
+            log::warn!("cancel_mining from {:?} refused: {}", identity, e);
+            return;
+        }
+    };
+
+    storage.update_player(Player {
+        mining_data: Vec::new(),
+        ..player
+    });
+}
+
+/// Place one pixel of a material taken from the player's inventory
+pub fn place_from_inventory(
+    storage: &mut impl ServerStorage,
+    world: Option<&mut World>,
+    identity: Identity,
+    world_x: i32,
+    world_y: i32,
+    material_id: u16,
+) -> Result<(), String> {
+    let player = living_player(storage, identity)?;
+    check_reach(&player, world_x, world_y)?;
+    check_permission(storage, identity, world_x, world_y, ClaimAction::Build)?;
+
+    // Workstations are multi-pixel structures tracked by the world's
+    // workstation system, which the server doesn't sync yet
+    if let Some(kind) = WorkstationType::from_material(material_id) {
+        return Err(format!("{} can't be placed in multiplayer", kind.name()));
+    }
+
+    let current = get_world_material(storage, world.as_deref(), world_x, world_y)
+        .ok_or_else(|| format!("Chunk at ({}, {}) not loaded", world_x, world_y))?;
+    if current != MaterialId::AIR {
+        return Err("Target pixel is not empty".to_string());
+    }
+
+    let mut inventory = encoding::decode_inventory(&player.inventory_data)?;
+    if inventory.remove_item(material_id, 1) == 0 {
+        return Err(format!(
+            "No {} in inventory",
+            MATERIALS.get(material_id).name
+        ));
+    }
+
+    if !set_world_pixel(storage, world, world_x, world_y, material_id) {
+        return Err(format!("Chunk at ({}, {}) not found", world_x, world_y));
+    }
+    save_inventory(storage, player, &inventory)
+}
+
+/// Craft a recipe from the player's inventory
+///
+/// Nothing is consumed unless the output fits into the inventory. Recipes that
+/// need a workstation are rejected, as workstations aren't synced yet. Inside
+/// someone else's claim the player needs the interact permission.
+pub fn craft(
+    storage: &mut impl ServerStorage,
+    identity: Identity,
+    recipe_id: u16,
+) -> Result<(), String> {
+    let player = living_player(storage, identity)?;
+    check_interact(storage, &player)?;
+
+    let recipe = RECIPES
+        .get(recipe_id)
+        .ok_or_else(|| format!("Unknown recipe {}", recipe_id))?;
+    if let Some(workstation) = recipe.workstation {
+        return Err(format!("{} requires a {}", recipe.name, workstation.name()));
+    }
+
+    let mut inventory = encoding::decode_inventory(&player.inventory_data)?;
+    let output = RECIPES
+        .try_craft(recipe, &mut inventory)
+        .ok_or_else(|| format!("Missing materials for {}", recipe.name))?;
+
+    let added = match output {
+        RecipeOutput::Material { id, count } => inventory.add_item(id, count) == 0,
+        RecipeOutput::Tool {
+            tool_id,
+            durability,
+        } => inventory.add_tool(tool_id, durability),
+        RecipeOutput::Container { container } => inventory.add_container(container),
+    };
+    if !added {
+        return Err("Inventory full".to_string());
+    }
+
+    save_inventory(storage, player, &inventory)
+}
+
+/// Eat one unit of a food material from the player's inventory
+///
+/// Inside someone else's claim the player needs the interact permission.
+pub fn eat(
+    storage: &mut impl ServerStorage,
+    identity: Identity,
+    material_id: u16,
+) -> Result<(), String> {
+    let player = living_player(storage, identity)?;
+    check_interact(storage, &player)?;
+
+    let material = MATERIALS.get(material_id);
+    let nutrition = material
+        .nutritional_value
+        .ok_or_else(|| format!("{} is not edible", material.name))?;
+
+    let mut inventory = encoding::decode_inventory(&player.inventory_data)?;
+    if inventory.remove_item(material_id, 1) == 0 {
+        return Err(format!("No {} in inventory", material.name));
+    }
+
+    let mut hunger = hunger_of(&player);
+    hunger.eat(nutrition);
+
+    let inventory_data = encoding::encode_inventory(&inventory)?;
+    storage.update_player(Player {
+        inventory_data,
+        hunger: hunger.current,
+        ..player
+    });
+    Ok(())
+}
+
+/// Equip a tool from the inventory, or unequip with `None`
+pub fn equip_tool(
+    storage: &mut impl ServerStorage,
+    identity: Identity,
+    tool_id: Option<u16>,
+) -> Result<(), String> {
+    let player = living_player(storage, identity)?;
+
+    if let Some(id) = tool_id {
+        if TOOLS.get(id).is_none() {
+            return Err(format!("Unknown tool {}", id));
+        }
+        let inventory = encoding::decode_inventory(&player.inventory_data)?;
+        if inventory.get_tool_durability(id).is_none() {
+            return Err(format!("{} not in inventory", TOOLS.name(id)));
+        }
+    }
+
+    storage.update_player(Player {
+        equipped_tool: tool_id,
+        // Mining time depends on the tool, so restart
+        mining_data: Vec::new(),
+        ..player
+    });
+    Ok(())
+}
+
+/// Advance hunger, starvation and mining for one player
+///
+/// Completed mining adds the pixel's material to the inventory and clears the
+/// pixel; if the inventory is full or the player lost mining rights there
+/// (e.g. the land was claimed meanwhile) the pixel stays. Tool durability isn't
+/// worn down, matching singleplayer mining.
+pub fn update_player_survival(
+    storage: &mut impl ServerStorage,
+    world: Option<&mut World>,
+    identity: Identity,
+    delta_time: f32,
+) {
+    let Some(player) = storage.find_player(identity) else {
+        return;
+    };
+    if player.health <= 0.0 {
+        return;
+    }
+
+    let mut hunger = hunger_of(&player);
+    let damage = hunger.update(delta_time);
+    let health = (player.health - damage).max(0.0);
+    if health <= 0.0 {
+        log::info!(
+            "Player {} starved",
+            player.name.as_deref().unwrap_or("Unknown")
+        );
+    }
+
+    let mut progress = encoding::decode_mining_progress(&player.mining_data).unwrap_or_default();
+    let target = progress.target_pixel;
+    let completed = health > 0.0 && progress.update(delta_time);
+    let mining_data = if progress.is_mining() {
+        encoding::encode_mining_progress(&progress).unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    let mut inventory_data = player.inventory_data.clone();
+    if completed
+        && let Some((x, y)) = target
+        && check_permission(storage, identity, x, y, ClaimAction::Mine).is_ok()
+    {
+        let world_ref = world.as_deref();
+        if let Some(material_id) = get_world_material(storage, world_ref, x, y)
+            && MATERIALS.get(material_id).hardness.is_some()
+            && let Ok(mut inventory) = encoding::decode_inventory(&player.inventory_data)
+        {
+            if inventory.add_item(material_id, 1) == 0 {
+                set_world_pixel(storage, world, x, y, MaterialId::AIR);
+                inventory_data = encoding::encode_inventory(&inventory).unwrap_or(inventory_data);
+            } else {
+                log::debug!("Inventory full, can't collect mined material");
+            }
+        }
+    }
+
+    storage.update_player(Player {
+        health,
+        hunger: hunger.current,
+        mining_data,
+        inventory_data,
+        ..player
+    });
+}
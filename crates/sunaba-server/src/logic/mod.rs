@@ -0,0 +1,22 @@
+//! Backend-agnostic gameplay logic
+//!
+//! Reducer bodies live here as plain functions over `ServerStorage`, an
+//! explicit `World` and an RNG. The reducers in `crate::reducers` only wrap
+//! them with `SpacetimeStorage`, the global `SERVER_WORLD` and timer
+//! scheduling; tests drive the same functions through `InMemoryStorage`.
+
+mod backups;
+mod chat;
+mod claims;
+mod creatures;
+mod inventory;
+mod players;
+mod world;
+
+pub use backups::*;
+pub use chat::*;
+pub use claims::*;
+pub use creatures::*;
+pub use inventory::*;
+pub use players::*;
+pub use world::*;
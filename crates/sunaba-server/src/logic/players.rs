@@ -0,0 +1,390 @@
+//! Player connection and action logic
+
+use glam::IVec2;
+use spacetimedb::Identity;
+use sunaba_core::entity::player::Player as CorePlayer;
+use sunaba_core::world::{World, WorldRng};
+use sunaba_simulation::{CHUNK_SIZE, MaterialId, Pixel};
+
+use super::claims::require_admin;
+use super::world::settle_spawn_chunks;
+use crate::encoding;
+use crate::helpers::{find_chunk_at, write_chunk_row};
+use crate::storage::ServerStorage;
+use crate::tables::{ChunkData, Player};
+
+/// Server-determined spawn point (matches client spawn chunks)
+pub const SPAWN_POINT: (f32, f32) = (0.0, 100.0);
+
+/// Rejected edit sequence numbers kept on the player row until the client
+/// confirms them (bounds the row for clients that never do)
+const MAX_REJECTED_EDITS: usize = 4096;
+
+/// Loaded chunk radius assumed until the client reports its own
+pub const DEFAULT_LOADED_RADIUS: i32 = 3;
+
+/// Largest loaded radius a client may report (bounds creature pose fan-out)
+pub const MAX_LOADED_RADIUS: i32 = 16;
+
+/// Mark a player online, creating them at the spawn point on first connect
+///
+/// The first connection also burst-settles the spawn chunks, so the spawn
+/// area is ready immediately instead of waiting on the settle timer (the 60fps
+/// world tick would otherwise starve it). Returns true if it did.
+pub fn player_connected<S: ServerStorage, R: WorldRng>(
+    storage: &mut S,
+    world_slot: &mut Option<World>,
+    identity: Identity,
+    rng: &mut R,
+) -> bool {
+    if let Some(player) = storage.find_player(identity) {
+        // Mark existing player as online; edit sequence numbers restart with
+        // the new client session
+        storage.update_player(Player {
+            online: true,
+            last_edit_seq: 0,
+            rejected_edits: Vec::new(),
+            ..player
+        });
+        log::info!("Returning player reconnected");
+    } else {
+        storage.insert_player(Player {
+            identity,
+            name: None,
+            online: true,
+            x: SPAWN_POINT.0,
+            y: SPAWN_POINT.1,
+            vel_x: 0.0,
+            vel_y: 0.0,
+            selected_material: MaterialId::SAND,
+            health: 100.0,
+            hunger: 100.0,
+            inventory_data: encoding::encode_inventory(&CorePlayer::starting_inventory())
+                .unwrap_or_default(),
+            equipped_tool: None,
+            mining_data: Vec::new(),
+            last_edit_seq: 0,
+            rejected_edits: Vec::new(),
+            teleport_seq: 0,
+            teleport_x: SPAWN_POINT.0,
+            teleport_y: SPAWN_POINT.1,
+            loaded_radius: DEFAULT_LOADED_RADIUS,
+            chat_sent_ms: Vec::new(),
+        });
+        log::info!("New player created");
+    }
+
+    if let Some(config) = storage.config()
+        && !config.settlement_complete
+    {
+        settle_spawn_chunks(storage, world_slot, config.settlement_radius, rng);
+        return true;
+    }
+    false
+}
+
+/// The sender's row, refused unless their session is live
+///
+/// `/kick` marks a player offline, but reducers can't close the connection,
+/// so every player action goes through this until the client reconnects.
+pub fn session_player(storage: &impl ServerStorage, identity: Identity) -> Result<Player, String> {
+    let player = storage
+        .find_player(identity)
+        .ok_or_else(|| "Player not found".to_string())?;
+    if !player.online {
+        return Err("Not connected".to_string());
+    }
+    Ok(player)
+}
+
+/// Mark a player offline (their row is kept for reconnects)
+pub fn player_disconnected(storage: &mut impl ServerStorage, identity: Identity) {
+    if let Some(player) = storage.find_player(identity) {
+        storage.update_player(Player {
+            online: false,
+            ..player
+        });
+    }
+}
+
+/// Update player position directly (client-authoritative for now)
+pub fn update_player_position(
+    storage: &mut impl ServerStorage,
+    identity: Identity,
+    x: f32,
+    y: f32,
+    vel_x: f32,
+    vel_y: f32,
+) {
+    let player = match session_player(storage, identity) {
+        Ok(player) => player,
+        Err(e) => {
+            log::warn!("Position update from {:?} refused: {}", identity, e);
+            return;
+        }
+    };
+
+    storage.update_player(Player {
+        x,
+        y,
+        vel_x,
+        vel_y,
+        ..player
+    });
+}
+
+/// Material at world coordinates, from the simulated world if the chunk is
+/// loaded, otherwise from the chunk row
+pub(super) fn get_world_material(
+    storage: &impl ServerStorage,
+    world: Option<&World>,
+    world_x: i32,
+    world_y: i32,
+) -> Option<u16> {
+    let chunk_x = world_x.div_euclid(CHUNK_SIZE as i32);
+    let chunk_y = world_y.div_euclid(CHUNK_SIZE as i32);
+
+    if let Some(world) = world
+        && world.has_chunk(IVec2::new(chunk_x, chunk_y))
+    {
+        return world.get_pixel_material(world_x, world_y);
+    }
+
+    let row = find_chunk_at(storage, chunk_x, chunk_y)?;
+    let chunk = encoding::decode_chunk(&row.pixel_data).ok()?;
+    let local_x = world_x.rem_euclid(CHUNK_SIZE as i32) as usize;
+    let local_y = world_y.rem_euclid(CHUNK_SIZE as i32) as usize;
+    Some(chunk.get_pixel(local_x, local_y).material_id)
+}
+
+/// Set a single pixel at world coordinates
+///
+/// Edits go to the simulated world when the chunk is loaded (so the next
+/// world tick doesn't overwrite them), otherwise straight to the chunk row.
+/// Returns false if the chunk doesn't exist anywhere.
+pub(super) fn set_world_pixel(
+    storage: &mut impl ServerStorage,
+    world: Option<&mut World>,
+    world_x: i32,
+    world_y: i32,
+    material_id: u16,
+) -> bool {
+    let chunk_x = world_x.div_euclid(CHUNK_SIZE as i32);
+    let chunk_y = world_y.div_euclid(CHUNK_SIZE as i32);
+    let local_x = world_x.rem_euclid(CHUNK_SIZE as i32) as usize;
+    let local_y = world_y.rem_euclid(CHUNK_SIZE as i32) as usize;
+
+    if let Some(world) = world
+        && world.has_chunk(IVec2::new(chunk_x, chunk_y))
+    {
+        world.set_pixel(world_x, world_y, material_id);
+        if let Some(chunk) = world.get_chunk(chunk_x, chunk_y)
+            && let Ok(pixel_data) = encoding::encode_chunk(chunk)
+        {
+            let tick = storage.config().map_or(0, |c| c.tick_count);
+            write_chunk_row(storage, chunk_x, chunk_y, pixel_data, true, tick);
+        }
+        return true;
+    }
+
+    let Some(row) = find_chunk_at(storage, chunk_x, chunk_y) else {
+        return false;
+    };
+    let Ok(mut chunk) = encoding::decode_chunk(&row.pixel_data) else {
+        log::error!("Failed to decode chunk ({}, {})", chunk_x, chunk_y);
+        return false;
+    };
+    chunk.set_pixel(local_x, local_y, Pixel::new(material_id));
+    let Ok(pixel_data) = encoding::encode_chunk(&chunk) else {
+        return false;
+    };
+    storage.update_chunk(ChunkData {
+        pixel_data,
+        dirty: true,
+        ..row
+    });
+    true
+}
+
+/// Place a material at world coordinates
+pub fn place_material(
+    storage: &mut impl ServerStorage,
+    world: Option<&mut World>,
+    world_x: i32,
+    world_y: i32,
+    material_id: u16,
+) -> bool {
+    set_world_pixel(storage, world, world_x, world_y, material_id)
+}
+
+/// Mine a pixel at world coordinates
+pub fn mine(
+    storage: &mut impl ServerStorage,
+    world: Option<&mut World>,
+    world_x: i32,
+    world_y: i32,
+) -> bool {
+    set_world_pixel(storage, world, world_x, world_y, MaterialId::AIR)
+}
+
+/// Place a material for free, ignoring claims (the client's debug placement)
+///
+/// Admins only; players place through `place_from_inventory`.
+pub fn debug_place_material(
+    storage: &mut impl ServerStorage,
+    world: Option<&mut World>,
+    identity: Identity,
+    world_x: i32,
+    world_y: i32,
+    material_id: u16,
+) -> Result<(), String> {
+    session_player(storage, identity)?;
+    require_admin(storage, identity)?;
+    if !place_material(storage, world, world_x, world_y, material_id) {
+        return Err(format!("({}, {}) is not loaded", world_x, world_y));
+    }
+    Ok(())
+}
+
+/// Clear a pixel instantly without collecting it, ignoring claims
+///
+/// Admins only; players mine through `start_mining`.
+pub fn debug_mine(
+    storage: &mut impl ServerStorage,
+    world: Option<&mut World>,
+    identity: Identity,
+    world_x: i32,
+    world_y: i32,
+) -> Result<(), String> {
+    session_player(storage, identity)?;
+    require_admin(storage, identity)?;
+    if !mine(storage, world, world_x, world_y) {
+        return Err(format!("({}, {}) is not loaded", world_x, world_y));
+    }
+    Ok(())
+}
+
+/// Record that the client's edit `seq` was processed
+///
+/// Clients predict edits locally and use `last_edit_seq` / `rejected_edits`
+/// to drop acknowledged predictions or roll back rejected ones. Rejections
+/// stay until `confirm_rejected_edits`. `seq` 0 means the edit isn't tracked.
+pub fn acknowledge_edit(
+    storage: &mut impl ServerStorage,
+    identity: Identity,
+    seq: u64,
+    accepted: bool,
+) {
+    if seq == 0 {
+        return;
+    }
+    let Some(player) = storage.find_player(identity) else {
+        return;
+    };
+
+    let mut rejected_edits = player.rejected_edits.clone();
+    if !accepted {
+        rejected_edits.push(seq);
+        if rejected_edits.len() > MAX_REJECTED_EDITS {
+            rejected_edits.remove(0);
+        }
+    }
+
+    storage.update_player(Player {
+        last_edit_seq: player.last_edit_seq.max(seq),
+        rejected_edits,
+        ..player
+    });
+}
+
+/// Forget rejections up to `seq`, which the client has rolled back
+pub fn confirm_rejected_edits(storage: &mut impl ServerStorage, identity: Identity, seq: u64) {
+    let Some(player) = storage.find_player(identity) else {
+        return;
+    };
+    if !player
+        .rejected_edits
+        .iter()
+        .any(|rejected| *rejected <= seq)
+    {
+        return;
+    }
+
+    let rejected_edits = player
+        .rejected_edits
+        .iter()
+        .copied()
+        .filter(|rejected| *rejected > seq)
+        .collect();
+    storage.update_player(Player {
+        rejected_edits,
+        ..player
+    });
+}
+
+/// Set player name
+pub fn set_player_name(storage: &mut impl ServerStorage, identity: Identity, name: String) {
+    let player = match session_player(storage, identity) {
+        Ok(player) => player,
+        Err(e) => {
+            log::warn!("set_name from {:?} refused: {}", identity, e);
+            return;
+        }
+    };
+
+    storage.update_player(Player {
+        name: Some(name),
+        ..player
+    });
+}
+
+/// Record the chunk radius the player's client keeps loaded
+pub fn set_loaded_radius(storage: &mut impl ServerStorage, identity: Identity, radius: i32) {
+    let player = match session_player(storage, identity) {
+        Ok(player) => player,
+        Err(e) => {
+            log::warn!("set_loaded_radius from {:?} refused: {}", identity, e);
+            return;
+        }
+    };
+
+    storage.update_player(Player {
+        loaded_radius: radius.clamp(1, MAX_LOADED_RADIUS),
+        ..player
+    });
+}
+
+/// Respawn a player at the spawn point with full health and hunger
+pub fn respawn_player(storage: &mut impl ServerStorage, identity: Identity) {
+    let player = match session_player(storage, identity) {
+        Ok(player) => player,
+        Err(e) => {
+            log::error!("Player respawn failed: {}", e);
+            return;
+        }
+    };
+
+    let (spawn_x, spawn_y) = SPAWN_POINT;
+
+    // Get player name before update (to avoid borrow after move)
+    let player_name = player.name.clone();
+
+    // Update player state (reset position, health, hunger)
+    storage.update_player(Player {
+        x: spawn_x,
+        y: spawn_y,
+        vel_x: 0.0,
+        vel_y: 0.0,
+        health: 100.0,
+        hunger: 100.0,
+        mining_data: Vec::new(),
+        ..player
+    });
+
+    log::info!(
+        "Player {} respawned at ({}, {})",
+        player_name.as_deref().unwrap_or("Unknown"),
+        spawn_x,
+        spawn_y
+    );
+}
@@ -0,0 +1,383 @@
+//! World simulation, settlement and metrics logic
+
+use glam::IVec2;
+use std::time::Duration;
+use sunaba_core::world::{DAY_NIGHT_CYCLE_DURATION, World, WorldRng};
+
+use super::inventory::update_player_survival;
+use crate::encoding;
+use crate::helpers::{
+    create_server_world, get_chunks_at_radius, load_or_create_chunk, sync_dirty_chunks_to_db,
+    update_player_physics, upsert_chunk,
+};
+use crate::state::NoOpStats;
+use crate::storage::ServerStorage;
+use crate::tables::{ServerMetrics, WorldConfig};
+
+/// Ticks without world activity before the world tick slows down (1 second at 60fps)
+const IDLE_THRESHOLD_TICKS: u64 = 60;
+
+/// Metric samples kept (10 minutes at 6fps)
+const MAX_METRICS: usize = 3600;
+
+/// Fixed world simulation step
+const WORLD_DELTA_TIME: f32 = 0.016;
+
+/// Initial world config (singleton, id=0)
+pub fn default_world_config() -> WorldConfig {
+    WorldConfig {
+        id: 0,
+        seed: 12345,
+        tick_count: 0,
+        simulation_paused: false,
+        max_creatures: 50,
+        settlement_radius: 3, // Settle 3 chunks from spawn (49 total, ~2.5s)
+        settlement_progress: 0,
+        settlement_complete: false,
+        last_activity_tick: 0,
+        is_idle: false,
+        spawn_protection_radius: 32,
+        time_of_day: DAY_NIGHT_CYCLE_DURATION / 2.0, // Noon, like a fresh World
+    }
+}
+
+/// One world simulation tick
+///
+/// Returns the delay until the next tick (10fps when idle or empty, 60fps
+/// when active), or `None` if ticking should stop (paused or no config).
+pub fn run_world_tick<S: ServerStorage, R: WorldRng>(
+    storage: &mut S,
+    world_slot: &mut Option<World>,
+    rng: &mut R,
+) -> Option<Duration> {
+    let Some(config) = storage.config() else {
+        log::error!("World config not found");
+        return None;
+    };
+
+    if config.simulation_paused {
+        return None;
+    }
+
+    let new_tick_count = config.tick_count + 1;
+
+    // Initialize or get World instance
+    let world = world_slot.get_or_insert_with(|| create_server_world(storage, config.seed));
+
+    // Get online players
+    let online_players = storage.online_players();
+
+    // Skip simulation if no players are online (reduces CPU usage when idle)
+    if online_players.is_empty() {
+        // Update tick count only
+        storage.set_config(WorldConfig {
+            tick_count: new_tick_count,
+            ..config
+        });
+        return Some(Duration::from_millis(100)); // Slower tick when idle (10fps)
+    }
+
+    // Load 7x7 chunks around each player
+    let mut chunks_loaded_this_tick = 0;
+    for player in &online_players {
+        let chunk_x = (player.x as i32).div_euclid(64);
+        let chunk_y = (player.y as i32).div_euclid(64);
+
+        let chunks_before = world.active_chunks().count();
+        for dy in -3..=3 {
+            for dx in -3..=3 {
+                load_or_create_chunk(storage, world, chunk_x + dx, chunk_y + dy);
+            }
+        }
+        let chunks_after = world.active_chunks().count();
+        chunks_loaded_this_tick += chunks_after - chunks_before;
+    }
+
+    // Track if new chunks were loaded (forces simulation to run)
+    let new_chunks_loaded = chunks_loaded_this_tick > 0;
+
+    // Determine if we should run full simulation
+    // Run simulation if:
+    // 1. World is not idle (recent activity)
+    // 2. New chunks were loaded (need to settle them)
+    // 3. We were previously idle and just woke up
+    let should_simulate = !config.is_idle || new_chunks_loaded;
+
+    // The day/night clock runs while anyone is online; the config is the
+    // source of truth so admins can set it and clients can follow it
+    let time_of_day = (config.time_of_day + WORLD_DELTA_TIME) % DAY_NIGHT_CYCLE_DURATION;
+    world.set_time_of_day(time_of_day);
+
+    let mut stats = NoOpStats;
+    let mut dirty_chunks_synced = 0u32;
+
+    if should_simulate {
+        // Run full simulation (World::update uses dirty chunk optimization internally)
+        world.update(WORLD_DELTA_TIME, &mut stats, rng, true);
+
+        // Sync ONLY dirty chunks to database
+        dirty_chunks_synced = sync_dirty_chunks_to_db(storage, world, new_tick_count);
+    }
+
+    // Track world activity for idle detection
+    let had_activity = dirty_chunks_synced > 0 || new_chunks_loaded;
+
+    // Calculate new idle state
+    let (last_activity_tick, is_idle) = if had_activity {
+        // Reset idle timer on any activity
+        if config.is_idle {
+            log::info!("[TICK {}] World waking from idle mode", new_tick_count);
+        }
+        (new_tick_count, false)
+    } else {
+        // Check if we've exceeded idle threshold
+        let frames_idle = new_tick_count.saturating_sub(config.last_activity_tick);
+        let entering_idle = frames_idle > IDLE_THRESHOLD_TICKS && !config.is_idle;
+        if entering_idle {
+            log::info!(
+                "[TICK {}] World entering idle mode (no activity for {} frames)",
+                new_tick_count,
+                frames_idle
+            );
+        }
+        (
+            config.last_activity_tick,
+            frames_idle > IDLE_THRESHOLD_TICKS,
+        )
+    };
+
+    // Update config with new tick count and idle state
+    storage.set_config(WorldConfig {
+        tick_count: new_tick_count,
+        last_activity_tick,
+        is_idle,
+        time_of_day,
+        ..config
+    });
+
+    // Log statistics periodically (every 60 ticks = ~1 second at 60fps)
+    if new_tick_count % 60 == 0 {
+        log::info!(
+            "[TICK {}] {} online players, {} chunks in memory{} [{}]",
+            new_tick_count,
+            online_players.len(),
+            world.active_chunks().count(),
+            if chunks_loaded_this_tick > 0 {
+                format!(" (+{} new)", chunks_loaded_this_tick)
+            } else {
+                String::new()
+            },
+            if is_idle { "IDLE" } else { "ACTIVE" }
+        );
+    }
+
+    // Note: std::time::Instant is not available in WASM, so we skip timing metrics
+    let world_tick_time_ms = 0.0_f32;
+
+    // Update players (only if their chunk is loaded)
+    // Player physics still runs even when idle (collision checks, etc.)
+    for player in online_players {
+        let identity = player.identity;
+        let chunk_x = (player.x as i32).div_euclid(64);
+        let chunk_y = (player.y as i32).div_euclid(64);
+
+        // Check if player's current chunk is loaded
+        let player_chunk_loaded = world.has_chunk(IVec2::new(chunk_x, chunk_y));
+
+        if player_chunk_loaded {
+            // Only run physics if player's chunk exists
+            update_player_physics(storage, player, WORLD_DELTA_TIME);
+        } else {
+            log::debug!(
+                "Skipping physics for player at ({:.0}, {:.0}) - chunk ({}, {}) not loaded yet",
+                player.x,
+                player.y,
+                chunk_x,
+                chunk_y
+            );
+        }
+
+        // Hunger, starvation and mining progress
+        update_player_survival(storage, Some(&mut *world), identity, WORLD_DELTA_TIME);
+    }
+
+    // Collect server metrics every 10th tick (6fps sampling)
+    if new_tick_count % 10 == 0 {
+        storage.insert_metrics(ServerMetrics {
+            id: 0,
+            tick: new_tick_count,
+            timestamp_ms: (new_tick_count * 16), // Approximate timestamp (16ms per tick)
+            world_tick_time_ms,                  // Measured timing from world.update()
+            creature_tick_time_ms: 0.0, // Filled by creature_tick reducer (updates same tick)
+            active_chunks: world.active_chunks().count() as u32,
+            dirty_chunks_synced, // Tracked from sync_dirty_chunks_to_db()
+            online_players: storage.online_players().len() as u32,
+            creatures_alive: storage.alive_creatures().len() as u32,
+        });
+    }
+
+    // Cleanup old metrics every 600 ticks (10 seconds at 60fps)
+    if new_tick_count % 600 == 0 {
+        cleanup_old_metrics(storage);
+    }
+
+    // 10fps when idle, 60fps when active
+    let next_tick_ms = if is_idle { 100 } else { 16 };
+    Some(Duration::from_millis(next_tick_ms))
+}
+
+/// One settlement step: pre-simulates the next ring of chunks around spawn
+///
+/// Returns the delay until the next step, or `None` once settlement is done.
+pub fn run_settle_tick<S: ServerStorage, R: WorldRng>(
+    storage: &mut S,
+    world: Option<&mut World>,
+    rng: &mut R,
+) -> Option<Duration> {
+    let config = storage.config()?;
+
+    if config.settlement_complete {
+        // Settlement is done, don't reschedule
+        return None;
+    }
+
+    // Skip settlement when no players are online (reduces CPU usage when idle)
+    if !storage.has_online_players() {
+        // Reschedule at slower rate but don't do work
+        return Some(Duration::from_millis(1000)); // 1 second when idle
+    }
+
+    let world = world?;
+
+    // Settle chunks in expanding ring around spawn (0, 0)
+    let r = config.settlement_progress;
+    for (chunk_x, chunk_y) in get_chunks_at_radius(0, 0, r) {
+        settle_chunk(storage, world, chunk_x, chunk_y, rng);
+    }
+
+    // Log ring completion
+    log::info!("Settled ring {}", r);
+
+    // Update progress
+    let new_progress = r + 1;
+    let complete = new_progress > config.settlement_radius;
+
+    let settlement_radius = config.settlement_radius;
+    storage.set_config(WorldConfig {
+        settlement_progress: new_progress,
+        settlement_complete: complete,
+        ..config
+    });
+
+    // Log progress periodically (every 5 rings or on completion)
+    if complete {
+        log::info!(
+            "World settlement complete! Settled radius {} ({} expected, {} in database)",
+            settlement_radius,
+            (settlement_radius * 2 + 1).pow(2),
+            storage.chunk_count()
+        );
+    } else if new_progress % 5 == 0 {
+        let progress_pct = (new_progress as f32 / settlement_radius as f32 * 100.0) as u32;
+        log::info!(
+            "Settlement progress: ring {}/{} ({}%)",
+            new_progress,
+            settlement_radius,
+            progress_pct
+        );
+    }
+
+    // 100ms = 10fps
+    Some(Duration::from_millis(100))
+}
+
+/// Settle all spawn chunks immediately in one blocking call
+/// Returns the number of chunks settled
+pub fn settle_spawn_chunks<S: ServerStorage, R: WorldRng>(
+    storage: &mut S,
+    world_slot: &mut Option<World>,
+    radius: i32,
+    rng: &mut R,
+) -> usize {
+    log::info!(
+        "Starting burst settlement of {} rings ({} chunks)...",
+        radius + 1,
+        (radius * 2 + 1).pow(2)
+    );
+
+    let config = storage.config().expect("World config must exist");
+    let world = world_slot.get_or_insert_with(|| create_server_world(storage, config.seed));
+    let mut chunks_settled = 0;
+
+    // Settle ALL chunks in radius at once (no timers, blocking)
+    for r in 0..=radius {
+        for (chunk_x, chunk_y) in get_chunks_at_radius(0, 0, r) {
+            if settle_chunk(storage, world, chunk_x, chunk_y, rng) {
+                chunks_settled += 1;
+            }
+        }
+    }
+
+    // Mark settlement as complete
+    storage.set_config(WorldConfig {
+        settlement_progress: radius + 1,
+        settlement_complete: true,
+        ..config
+    });
+
+    log::info!(
+        "Burst settlement complete! Settled {} chunks in {} rings",
+        chunks_settled,
+        radius + 1
+    );
+
+    chunks_settled
+}
+
+/// Load a chunk, simulate it until sand and liquids settle, and save it
+fn settle_chunk<S: ServerStorage, R: WorldRng>(
+    storage: &mut S,
+    world: &mut World,
+    chunk_x: i32,
+    chunk_y: i32,
+    rng: &mut R,
+) -> bool {
+    // Load chunk if not already loaded
+    load_or_create_chunk(storage, world, chunk_x, chunk_y);
+
+    // Simulate chunk for 10 ticks (sufficient for sand/liquid settling)
+    for _ in 0..10 {
+        world.update_chunk_settle(chunk_x, chunk_y, rng);
+    }
+
+    // Save settled chunk to DB (upsert to avoid duplicate rows)
+    let Some(chunk) = world.get_chunk(chunk_x, chunk_y) else {
+        return false;
+    };
+    let Ok(pixel_data) = encoding::encode_chunk(chunk) else {
+        return false;
+    };
+    upsert_chunk(storage, chunk_x, chunk_y, pixel_data, 0);
+    log::debug!("Settled chunk ({}, {}) saved to DB", chunk_x, chunk_y);
+    true
+}
+
+/// Cleanup old server metrics (keep last MAX_METRICS samples)
+pub fn cleanup_old_metrics(storage: &mut impl ServerStorage) {
+    let mut metrics = storage.metrics();
+
+    if metrics.len() > MAX_METRICS {
+        metrics.sort_by_key(|m| m.tick);
+        let to_remove = metrics.len() - MAX_METRICS;
+
+        for metric in metrics.iter().take(to_remove) {
+            storage.delete_metrics(metric.id);
+        }
+
+        log::info!(
+            "Cleaned up {} old server metrics (kept {})",
+            to_remove,
+            MAX_METRICS
+        );
+    }
+}
@@ -79,3 +79,14 @@ pub fn rebuild_world(ctx: &ReducerContext) {
 
     log::info!("World rebuild complete");
 }
+
+/// Resize the protected spawn zone (pixels from spawn, 0 disables it)
+/// Admin only
+#[spacetimedb::reducer]
+pub fn set_spawn_protection(ctx: &ReducerContext, radius: i32) -> Result<(), String> {
+    crate::logic::set_spawn_protection(
+        &mut crate::storage::SpacetimeStorage::new(ctx),
+        ctx.sender,
+        radius,
+    )
+}
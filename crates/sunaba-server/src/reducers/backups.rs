@@ -0,0 +1,92 @@
+//! Admin reducers for world snapshots, rollback and chunk import
+
+use spacetimedb::ReducerContext;
+
+use crate::helpers::now_ms;
+use crate::logic::{self, ChunkRegion};
+use crate::state::SERVER_WORLD;
+use crate::storage::SpacetimeStorage;
+
+/// Take a labelled snapshot of the whole world (kept until deleted)
+/// Admin only
+#[spacetimedb::reducer]
+pub fn create_snapshot(ctx: &ReducerContext, label: String) -> Result<(), String> {
+    logic::create_snapshot(
+        &mut SpacetimeStorage::new(ctx),
+        ctx.sender,
+        label,
+        now_ms(ctx),
+    )
+    .map(|_| ())
+}
+
+/// Delete a snapshot and its stored chunks and creatures
+/// Admin only
+#[spacetimedb::reducer]
+pub fn delete_snapshot(ctx: &ReducerContext, snapshot_id: u64) -> Result<(), String> {
+    logic::delete_snapshot(&mut SpacetimeStorage::new(ctx), ctx.sender, snapshot_id)
+}
+
+/// Reply in chat with the available snapshots
+/// Admin only
+#[spacetimedb::reducer]
+pub fn list_snapshots(ctx: &ReducerContext) -> Result<(), String> {
+    logic::list_snapshots(&mut SpacetimeStorage::new(ctx), ctx.sender, now_ms(ctx))
+}
+
+/// Roll the whole world back to a snapshot
+/// Admin only
+#[spacetimedb::reducer]
+pub fn rollback_world(ctx: &ReducerContext, snapshot_id: u64) -> Result<(), String> {
+    let mut world_guard = SERVER_WORLD.lock().unwrap();
+    logic::rollback(
+        &mut SpacetimeStorage::new(ctx),
+        world_guard.as_mut(),
+        ctx.sender,
+        snapshot_id,
+        None,
+        now_ms(ctx),
+    )
+}
+
+/// Roll back the chunks touching a world pixel rectangle (inclusive corners)
+/// Admin only
+#[spacetimedb::reducer]
+pub fn rollback_region(
+    ctx: &ReducerContext,
+    snapshot_id: u64,
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+) -> Result<(), String> {
+    let mut world_guard = SERVER_WORLD.lock().unwrap();
+    logic::rollback(
+        &mut SpacetimeStorage::new(ctx),
+        world_guard.as_mut(),
+        ctx.sender,
+        snapshot_id,
+        Some(ChunkRegion::from_pixels(x1, y1, x2, y2)),
+        now_ms(ctx),
+    )
+}
+
+/// Replace a chunk with uploaded data (e.g. from a singleplayer world)
+/// Admin only
+#[spacetimedb::reducer]
+pub fn import_chunk(
+    ctx: &ReducerContext,
+    chunk_x: i32,
+    chunk_y: i32,
+    pixel_data: Vec<u8>,
+) -> Result<(), String> {
+    let mut world_guard = SERVER_WORLD.lock().unwrap();
+    logic::import_chunk(
+        &mut SpacetimeStorage::new(ctx),
+        world_guard.as_mut(),
+        ctx.sender,
+        chunk_x,
+        chunk_y,
+        pixel_data,
+    )
+}
@@ -0,0 +1,35 @@
+//! Chat reducer (messages and slash commands)
+
+use spacetimedb::{ReducerContext, Table};
+use std::time::Duration;
+
+use super::world_ticks::delete_all_world_timers;
+use crate::helpers::now_ms;
+use crate::logic::{self, ChatEffect};
+use crate::state::SERVER_WORLD;
+use crate::storage::SpacetimeStorage;
+use crate::tables::{WorldTickTimer, world_tick_timer};
+
+/// Send a chat message, or run a `/command` (see `/help`)
+#[spacetimedb::reducer]
+pub fn send_chat(ctx: &ReducerContext, text: String) -> Result<(), String> {
+    let mut world_guard = SERVER_WORLD.lock().unwrap();
+
+    let effect = logic::send_chat(
+        &mut SpacetimeStorage::new(ctx),
+        world_guard.as_mut(),
+        ctx.sender,
+        text,
+        now_ms(ctx),
+    )?;
+
+    if effect == ChatEffect::ResumeSimulation {
+        // The paused world tick stopped rescheduling itself
+        delete_all_world_timers(ctx);
+        ctx.db.world_tick_timer().insert(WorldTickTimer {
+            id: 0,
+            scheduled_at: Duration::from_millis(16).into(),
+        });
+    }
+    Ok(())
+}
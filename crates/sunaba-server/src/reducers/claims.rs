@@ -0,0 +1,56 @@
+//! Land claim reducers (claiming, releasing and sharing land)
+
+use spacetimedb::{Identity, ReducerContext};
+
+use crate::logic;
+use crate::storage::SpacetimeStorage;
+
+/// Claim a rectangle of land between two world pixel corners
+#[spacetimedb::reducer]
+pub fn claim_land(
+    ctx: &ReducerContext,
+    name: String,
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+) -> Result<(), String> {
+    logic::claim_land(
+        &mut SpacetimeStorage::new(ctx),
+        ctx.sender,
+        name,
+        x1,
+        y1,
+        x2,
+        y2,
+    )
+}
+
+/// Release a claim (owner or admin)
+#[spacetimedb::reducer]
+pub fn unclaim_land(ctx: &ReducerContext, claim_id: u64) -> Result<(), String> {
+    logic::unclaim_land(&mut SpacetimeStorage::new(ctx), ctx.sender, claim_id)
+}
+
+/// Set another player's build/mine/interact permissions in a claim
+///
+/// All false removes them from the claim.
+#[spacetimedb::reducer]
+pub fn set_claim_permission(
+    ctx: &ReducerContext,
+    claim_id: u64,
+    member: Identity,
+    build: bool,
+    mine: bool,
+    interact: bool,
+) -> Result<(), String> {
+    logic::set_claim_permission(
+        &mut SpacetimeStorage::new(ctx),
+        ctx.sender,
+        claim_id,
+        member,
+        build,
+        mine,
+        interact,
+    )
+}
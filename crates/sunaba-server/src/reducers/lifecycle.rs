@@ -3,10 +3,13 @@
 use spacetimedb::{ReducerContext, Table};
 use std::time::Duration;
 
-use super::world_ticks::settle_spawn_chunks_burst;
+use super::world_ticks::delete_all_settle_timers;
+use crate::logic;
+use crate::state::SERVER_WORLD;
+use crate::storage::{ServerStorage, SpacetimeStorage};
 use crate::tables::{
-    AdminUser, CreatureTickTimer, Player, SettleTickTimer, WorldConfig, WorldTickTimer, admin_user,
-    creature_tick_timer, player, settle_tick_timer, world_config, world_tick_timer,
+    AdminUser, CreatureTickTimer, SettleTickTimer, SnapshotTickTimer, WorldTickTimer, admin_user,
+    creature_tick_timer, settle_tick_timer, snapshot_tick_timer, world_tick_timer,
 };
 
 // ============================================================================
@@ -19,18 +22,7 @@ pub fn init(ctx: &ReducerContext) {
     log::info!("Initializing Sunaba multiplayer server");
 
     // Create world config singleton
-    ctx.db.world_config().insert(WorldConfig {
-        id: 0,
-        seed: 12345,
-        tick_count: 0,
-        simulation_paused: false,
-        max_creatures: 50,
-        settlement_radius: 3, // Settle 3 chunks from spawn (49 total, ~2.5s)
-        settlement_progress: 0,
-        settlement_complete: false,
-        last_activity_tick: 0,
-        is_idle: false,
-    });
+    SpacetimeStorage::new(ctx).set_config(logic::default_world_config());
 
     // Schedule world tick at 60fps (16ms)
     ctx.db.world_tick_timer().insert(WorldTickTimer {
@@ -50,6 +42,12 @@ pub fn init(ctx: &ReducerContext) {
         scheduled_at: Duration::from_millis(100).into(),
     });
 
+    // Schedule periodic snapshot checks (separate from the world tick)
+    ctx.db.snapshot_tick_timer().insert(SnapshotTickTimer {
+        id: 0,
+        scheduled_at: logic::SNAPSHOT_CHECK_INTERVAL.into(),
+    });
+
     // Log admin whitelist from environment
     let admin_emails = std::env::var("SUNABA_ADMIN_EMAILS").unwrap_or_else(|_| String::new());
 
@@ -83,38 +81,21 @@ pub fn client_connected(ctx: &ReducerContext) {
         log::info!("Admin reconnected: {}", email);
     }
 
-    // Check if player already exists
-    if let Some(player) = ctx.db.player().identity().find(ctx.sender) {
-        // Mark existing player as online
-        ctx.db.player().identity().update(Player {
-            online: true,
-            ..player
-        });
-        log::info!("Returning player reconnected");
-    } else {
-        // Create new player at spawn point (0, 100) - matches client spawn chunks
-        ctx.db.player().insert(Player {
-            identity: ctx.sender,
-            name: None,
-            online: true,
-            x: 0.0,
-            y: 100.0,
-            vel_x: 0.0,
-            vel_y: 0.0,
-            selected_material: 2, // Sand
-            health: 100.0,
-            hunger: 100.0,
-        });
-        log::info!("New player created");
-    }
-
-    // Burst settle spawn chunks if not already done
-    // This ensures spawn area is ready IMMEDIATELY when first player connects,
-    // avoiding scheduler starvation where 60fps world_tick blocks settle_tick
-    if let Some(config) = ctx.db.world_config().id().find(0)
-        && !config.settlement_complete
-    {
-        settle_spawn_chunks_burst(ctx, config.settlement_radius);
+    // Mark the player online (created at spawn on first connect). The first
+    // connection burst-settles the spawn chunks so the spawn area is ready
+    // IMMEDIATELY, avoiding scheduler starvation where 60fps world_tick
+    // blocks settle_tick
+    let mut world_guard = SERVER_WORLD.lock().unwrap();
+    let mut rng = ctx.rng();
+    let settled = logic::player_connected(
+        &mut SpacetimeStorage::new(ctx),
+        &mut world_guard,
+        ctx.sender,
+        &mut rng,
+    );
+    if settled {
+        // Cancel any pending settle timers (no longer needed)
+        delete_all_settle_timers(ctx);
     }
 }
 
@@ -123,12 +104,7 @@ pub fn client_connected(ctx: &ReducerContext) {
 pub fn client_disconnected(ctx: &ReducerContext) {
     log::info!("Client disconnected: {:?}", ctx.sender);
 
-    if let Some(player) = ctx.db.player().identity().find(ctx.sender) {
-        ctx.db.player().identity().update(Player {
-            online: false,
-            ..player
-        });
-    }
+    logic::player_disconnected(&mut SpacetimeStorage::new(ctx), ctx.sender);
 }
 
 /// Restart all tick timers (use when timers are missing after deploy)
@@ -170,5 +146,16 @@ pub fn restart_timers(ctx: &ReducerContext) {
         log::info!("settle_tick_timer already exists");
     }
 
+    // Check and create snapshot tick timer if missing
+    if ctx.db.snapshot_tick_timer().id().find(0).is_none() {
+        ctx.db.snapshot_tick_timer().insert(SnapshotTickTimer {
+            id: 0,
+            scheduled_at: logic::SNAPSHOT_CHECK_INTERVAL.into(),
+        });
+        log::info!("Created snapshot_tick_timer");
+    } else {
+        log::info!("snapshot_tick_timer already exists");
+    }
+
     log::info!("Timer restart complete");
 }
@@ -1,6 +1,9 @@
 //! Reducer module re-exports
 
 mod admin;
+mod backups;
+mod chat;
+mod claims;
 mod creatures;
 mod lifecycle;
 mod monitoring;
@@ -9,6 +12,9 @@ mod testing;
 mod world_ticks;
 
 pub use admin::*;
+pub use backups::*;
+pub use chat::*;
+pub use claims::*;
 pub use creatures::*;
 pub use lifecycle::*;
 pub use monitoring::*;
@@ -1,11 +1,10 @@
-//! Player action reducers (movement, placement, mining, name setting)
+//! Player action reducers (movement, placement, mining, crafting, name setting)
 
 use spacetimedb::ReducerContext;
-use sunaba_simulation::{CHUNK_SIZE, Pixel};
 
-use crate::encoding;
-use crate::helpers::find_chunk_at;
-use crate::tables::{ChunkData, Player, chunk_data, player};
+use crate::logic;
+use crate::state::SERVER_WORLD;
+use crate::storage::SpacetimeStorage;
 
 // ============================================================================
 // Player Action Reducers
@@ -14,117 +13,148 @@ use crate::tables::{ChunkData, Player, chunk_data, player};
 /// Update player position directly (client-authoritative for now)
 #[spacetimedb::reducer]
 pub fn player_update_position(ctx: &ReducerContext, x: f32, y: f32, vel_x: f32, vel_y: f32) {
-    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
-        log::warn!("Player not found: {:?}", ctx.sender);
-        return;
-    };
-
-    ctx.db.player().identity().update(Player {
+    logic::update_player_position(
+        &mut SpacetimeStorage::new(ctx),
+        ctx.sender,
         x,
         y,
         vel_x,
         vel_y,
-        ..player
-    });
+    );
 }
 
-/// Place a material at world coordinates
+/// Place a material at world coordinates without spending inventory (admins only)
+///
+/// `seq` is the client's edit sequence number, acknowledged on the player row.
 #[spacetimedb::reducer]
-pub fn player_place_material(ctx: &ReducerContext, world_x: i32, world_y: i32, material_id: u16) {
-    // Get or create chunk
-    let chunk_x = world_x.div_euclid(CHUNK_SIZE as i32);
-    let chunk_y = world_y.div_euclid(CHUNK_SIZE as i32);
-    let local_x = world_x.rem_euclid(CHUNK_SIZE as i32) as usize;
-    let local_y = world_y.rem_euclid(CHUNK_SIZE as i32) as usize;
-
-    if let Some(chunk) = find_chunk_at(ctx, chunk_x, chunk_y)
-        && let Ok(mut pixels) = encoding::decode_chunk_pixels(&chunk.pixel_data)
-    {
-        let idx = local_y * CHUNK_SIZE + local_x;
-        if idx < pixels.len() {
-            pixels[idx] = Pixel::new(material_id);
-
-            if let Ok(pixel_data) = encoding::encode_chunk_pixels(&pixels) {
-                ctx.db.chunk_data().id().update(ChunkData {
-                    pixel_data,
-                    dirty: true,
-                    ..chunk
-                });
-            }
-        }
+pub fn player_place_material(
+    ctx: &ReducerContext,
+    world_x: i32,
+    world_y: i32,
+    material_id: u16,
+    seq: u64,
+) {
+    let mut world_guard = SERVER_WORLD.lock().unwrap();
+    let mut storage = SpacetimeStorage::new(ctx);
+    let result = logic::debug_place_material(
+        &mut storage,
+        world_guard.as_mut(),
+        ctx.sender,
+        world_x,
+        world_y,
+        material_id,
+    );
+    if let Err(e) = &result {
+        log::debug!("Placement rejected: {}", e);
     }
+    logic::acknowledge_edit(&mut storage, ctx.sender, seq, result.is_ok());
 }
 
-/// Mine a pixel at world coordinates
+/// Clear a pixel at world coordinates instantly (admins only)
+///
+/// `seq` is the client's edit sequence number, acknowledged on the player row.
 #[spacetimedb::reducer]
-pub fn player_mine(ctx: &ReducerContext, world_x: i32, world_y: i32) {
-    let chunk_x = world_x.div_euclid(CHUNK_SIZE as i32);
-    let chunk_y = world_y.div_euclid(CHUNK_SIZE as i32);
-    let local_x = world_x.rem_euclid(CHUNK_SIZE as i32) as usize;
-    let local_y = world_y.rem_euclid(CHUNK_SIZE as i32) as usize;
-
-    if let Some(chunk) = find_chunk_at(ctx, chunk_x, chunk_y)
-        && let Ok(mut pixels) = encoding::decode_chunk_pixels(&chunk.pixel_data)
-    {
-        let idx = local_y * CHUNK_SIZE + local_x;
-        if idx < pixels.len() {
-            pixels[idx] = Pixel::new(0); // Air
-
-            if let Ok(pixel_data) = encoding::encode_chunk_pixels(&pixels) {
-                ctx.db.chunk_data().id().update(ChunkData {
-                    pixel_data,
-                    dirty: true,
-                    ..chunk
-                });
-            }
-        }
+pub fn player_mine(ctx: &ReducerContext, world_x: i32, world_y: i32, seq: u64) {
+    let mut world_guard = SERVER_WORLD.lock().unwrap();
+    let mut storage = SpacetimeStorage::new(ctx);
+    let result = logic::debug_mine(
+        &mut storage,
+        world_guard.as_mut(),
+        ctx.sender,
+        world_x,
+        world_y,
+    );
+    if let Err(e) = &result {
+        log::debug!("Mining rejected: {}", e);
     }
+    logic::acknowledge_edit(&mut storage, ctx.sender, seq, result.is_ok());
+}
+
+/// Confirm that the client has rolled back its rejected edits up to `seq`
+#[spacetimedb::reducer]
+pub fn player_confirm_edits(ctx: &ReducerContext, seq: u64) {
+    logic::confirm_rejected_edits(&mut SpacetimeStorage::new(ctx), ctx.sender, seq);
+}
+
+/// Start mining a pixel into the inventory (completes over time in the world tick)
+#[spacetimedb::reducer]
+pub fn player_start_mining(ctx: &ReducerContext, world_x: i32, world_y: i32) -> Result<(), String> {
+    let world_guard = SERVER_WORLD.lock().unwrap();
+    logic::start_mining(
+        &mut SpacetimeStorage::new(ctx),
+        world_guard.as_ref(),
+        ctx.sender,
+        world_x,
+        world_y,
+    )
+}
+
+/// Stop mining without collecting anything
+#[spacetimedb::reducer]
+pub fn player_cancel_mining(ctx: &ReducerContext) {
+    logic::cancel_mining(&mut SpacetimeStorage::new(ctx), ctx.sender);
+}
+
+/// Place one pixel of a material from the player's inventory
+///
+/// Rejections are acknowledged rather than returned as errors, as an error
+/// would also roll back the acknowledgement.
+#[spacetimedb::reducer]
+pub fn player_place_from_inventory(
+    ctx: &ReducerContext,
+    world_x: i32,
+    world_y: i32,
+    material_id: u16,
+    seq: u64,
+) {
+    let mut world_guard = SERVER_WORLD.lock().unwrap();
+    let mut storage = SpacetimeStorage::new(ctx);
+    let result = logic::place_from_inventory(
+        &mut storage,
+        world_guard.as_mut(),
+        ctx.sender,
+        world_x,
+        world_y,
+        material_id,
+    );
+    if let Err(e) = &result {
+        log::debug!("Placement rejected: {}", e);
+    }
+    logic::acknowledge_edit(&mut storage, ctx.sender, seq, result.is_ok());
+}
+
+/// Craft a recipe from the player's inventory
+#[spacetimedb::reducer]
+pub fn player_craft(ctx: &ReducerContext, recipe_id: u16) -> Result<(), String> {
+    logic::craft(&mut SpacetimeStorage::new(ctx), ctx.sender, recipe_id)
+}
+
+/// Eat one unit of food from the player's inventory
+#[spacetimedb::reducer]
+pub fn player_eat(ctx: &ReducerContext, material_id: u16) -> Result<(), String> {
+    logic::eat(&mut SpacetimeStorage::new(ctx), ctx.sender, material_id)
+}
+
+/// Equip a tool from the inventory (`None` unequips)
+#[spacetimedb::reducer]
+pub fn player_equip_tool(ctx: &ReducerContext, tool_id: Option<u16>) -> Result<(), String> {
+    logic::equip_tool(&mut SpacetimeStorage::new(ctx), ctx.sender, tool_id)
 }
 
 /// Set player name
 #[spacetimedb::reducer]
 pub fn set_player_name(ctx: &ReducerContext, name: String) {
-    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
-        log::warn!("Player not found for set_name: {:?}", ctx.sender);
-        return;
-    };
-
-    ctx.db.player().identity().update(Player {
-        name: Some(name),
-        ..player
-    });
+    logic::set_player_name(&mut SpacetimeStorage::new(ctx), ctx.sender, name);
+}
+
+/// Report the chunk radius this client keeps loaded
+#[spacetimedb::reducer]
+pub fn player_set_loaded_radius(ctx: &ReducerContext, radius: i32) {
+    logic::set_loaded_radius(&mut SpacetimeStorage::new(ctx), ctx.sender, radius);
 }
 
 /// Respawn dead player at server-determined spawn point
 #[spacetimedb::reducer]
 pub fn player_respawn(ctx: &ReducerContext) {
-    let Some(player) = ctx.db.player().identity().find(ctx.sender) else {
-        log::error!("Player respawn failed: player not found");
-        return;
-    };
-
-    // Server-determined spawn point (can be customized based on world state)
-    let spawn_x = 0.0;
-    let spawn_y = 100.0;
-
-    // Get player name before update (to avoid borrow after move)
-    let player_name = player.name.clone();
-
-    // Update player state (reset position, health, hunger)
-    ctx.db.player().identity().update(Player {
-        x: spawn_x,
-        y: spawn_y,
-        vel_x: 0.0,
-        vel_y: 0.0,
-        health: 100.0,
-        hunger: 100.0,
-        ..player
-    });
-
-    log::info!(
-        "Player {} respawned at ({}, {})",
-        player_name.as_deref().unwrap_or("Unknown"),
-        spawn_x,
-        spawn_y
-    );
+    logic::respawn_player(&mut SpacetimeStorage::new(ctx), ctx.sender);
 }
@@ -11,6 +11,18 @@ use std::sync::Mutex;
 pub static SERVER_WORLD: Lazy<Mutex<Option<sunaba_core::world::World>>> =
     Lazy::new(|| Mutex::new(None));
 
+/// Material definitions (hardness, nutrition) for server-side gameplay rules
+pub static MATERIALS: Lazy<sunaba_simulation::Materials> =
+    Lazy::new(sunaba_simulation::Materials::new);
+
+/// Crafting recipes, shared with the client
+pub static RECIPES: Lazy<sunaba_core::entity::crafting::RecipeRegistry> =
+    Lazy::new(sunaba_core::entity::crafting::RecipeRegistry::new);
+
+/// Tool definitions, shared with the client
+pub static TOOLS: Lazy<sunaba_core::entity::tools::ToolRegistry> =
+    Lazy::new(sunaba_core::entity::tools::ToolRegistry::new);
+
 // ============================================================================
 // Helper Types
 // ============================================================================
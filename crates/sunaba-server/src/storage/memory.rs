@@ -0,0 +1,247 @@
+//! In-memory `ServerStorage` for unit tests
+
+use spacetimedb::Identity;
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::ServerStorage;
+use crate::tables::{
+    ChatMessage, ChunkData, CreatureData, CreatureInfo, CreaturePose, LandClaim, Player,
+    ServerMetrics, SnapshotChunk, SnapshotCreature, WorldConfig, WorldSnapshot,
+};
+
+/// Plain-map table store (rows iterate in id order)
+#[derive(Default)]
+pub struct InMemoryStorage {
+    config: Option<WorldConfig>,
+    chunks: BTreeMap<u64, ChunkData>,
+    players: BTreeMap<Identity, Player>,
+    admins: BTreeSet<Identity>,
+    claims: BTreeMap<u64, LandClaim>,
+    chat: BTreeMap<u64, ChatMessage>,
+    creatures: BTreeMap<u64, CreatureData>,
+    creature_infos: BTreeMap<u64, CreatureInfo>,
+    creature_poses: BTreeMap<u64, CreaturePose>,
+    snapshots: BTreeMap<u64, WorldSnapshot>,
+    snapshot_chunks: BTreeMap<u64, SnapshotChunk>,
+    snapshot_creatures: BTreeMap<u64, SnapshotCreature>,
+    metrics: BTreeMap<u64, ServerMetrics>,
+    next_id: u64,
+}
+
+impl InMemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Grant admin rights (stands in for the `admin_user` table)
+    pub fn add_admin(&mut self, identity: Identity) {
+        self.admins.insert(identity);
+    }
+
+    /// Id for an `#[auto_inc]` row inserted with `id: 0`
+    fn assign_id(&mut self, id: u64) -> u64 {
+        if id != 0 {
+            return id;
+        }
+        self.next_id += 1;
+        self.next_id
+    }
+}
+
+impl ServerStorage for InMemoryStorage {
+    fn config(&self) -> Option<WorldConfig> {
+        self.config.clone()
+    }
+
+    fn set_config(&mut self, config: WorldConfig) {
+        self.config = Some(config);
+    }
+
+    fn find_chunk(&self, x: i32, y: i32) -> Option<ChunkData> {
+        self.chunks.values().find(|c| c.x == x && c.y == y).cloned()
+    }
+
+    fn chunks(&self) -> Vec<ChunkData> {
+        self.chunks.values().cloned().collect()
+    }
+
+    fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    fn insert_chunk(&mut self, mut chunk: ChunkData) {
+        chunk.id = self.assign_id(chunk.id);
+        self.chunks.insert(chunk.id, chunk);
+    }
+
+    fn update_chunk(&mut self, chunk: ChunkData) {
+        if let Some(row) = self.chunks.get_mut(&chunk.id) {
+            *row = chunk;
+        }
+    }
+
+    fn delete_chunk(&mut self, id: u64) {
+        self.chunks.remove(&id);
+    }
+
+    fn find_player(&self, identity: Identity) -> Option<Player> {
+        self.players.get(&identity).cloned()
+    }
+
+    fn players(&self) -> Vec<Player> {
+        self.players.values().cloned().collect()
+    }
+
+    fn insert_player(&mut self, player: Player) {
+        self.players.insert(player.identity, player);
+    }
+
+    fn update_player(&mut self, player: Player) {
+        if let Some(row) = self.players.get_mut(&player.identity) {
+            *row = player;
+        }
+    }
+
+    fn is_admin(&self, identity: Identity) -> bool {
+        self.admins.contains(&identity)
+    }
+
+    fn claims(&self) -> Vec<LandClaim> {
+        self.claims.values().cloned().collect()
+    }
+
+    fn find_claim(&self, id: u64) -> Option<LandClaim> {
+        self.claims.get(&id).cloned()
+    }
+
+    fn insert_claim(&mut self, mut claim: LandClaim) {
+        claim.id = self.assign_id(claim.id);
+        self.claims.insert(claim.id, claim);
+    }
+
+    fn update_claim(&mut self, claim: LandClaim) {
+        if let Some(row) = self.claims.get_mut(&claim.id) {
+            *row = claim;
+        }
+    }
+
+    fn delete_claim(&mut self, id: u64) {
+        self.claims.remove(&id);
+    }
+
+    fn chat_messages(&self) -> Vec<ChatMessage> {
+        self.chat.values().cloned().collect()
+    }
+
+    fn insert_chat_message(&mut self, mut message: ChatMessage) {
+        message.id = self.assign_id(message.id);
+        self.chat.insert(message.id, message);
+    }
+
+    fn delete_chat_message(&mut self, id: u64) {
+        self.chat.remove(&id);
+    }
+
+    fn creatures(&self) -> Vec<CreatureData> {
+        self.creatures.values().cloned().collect()
+    }
+
+    fn insert_creature(&mut self, mut creature: CreatureData) {
+        creature.id = self.assign_id(creature.id);
+        self.creatures.insert(creature.id, creature);
+    }
+
+    fn update_creature(&mut self, creature: CreatureData) {
+        if let Some(row) = self.creatures.get_mut(&creature.id) {
+            *row = creature;
+        }
+    }
+
+    fn delete_creature(&mut self, id: u64) {
+        self.creatures.remove(&id);
+    }
+
+    fn creature_infos(&self) -> Vec<CreatureInfo> {
+        self.creature_infos.values().cloned().collect()
+    }
+
+    fn insert_creature_info(&mut self, info: CreatureInfo) {
+        self.creature_infos.insert(info.creature_id, info);
+    }
+
+    fn delete_creature_info(&mut self, creature_id: u64) {
+        self.creature_infos.remove(&creature_id);
+    }
+
+    fn creature_poses(&self) -> Vec<CreaturePose> {
+        self.creature_poses.values().cloned().collect()
+    }
+
+    fn set_creature_pose(&mut self, pose: CreaturePose) {
+        self.creature_poses.insert(pose.creature_id, pose);
+    }
+
+    fn delete_creature_pose(&mut self, creature_id: u64) {
+        self.creature_poses.remove(&creature_id);
+    }
+
+    fn snapshots(&self) -> Vec<WorldSnapshot> {
+        self.snapshots.values().cloned().collect()
+    }
+
+    fn find_snapshot(&self, id: u64) -> Option<WorldSnapshot> {
+        self.snapshots.get(&id).cloned()
+    }
+
+    fn insert_snapshot(&mut self, mut snapshot: WorldSnapshot) -> u64 {
+        snapshot.id = self.assign_id(snapshot.id);
+        let id = snapshot.id;
+        self.snapshots.insert(id, snapshot);
+        id
+    }
+
+    fn delete_snapshot(&mut self, id: u64) {
+        self.snapshots.remove(&id);
+        self.snapshot_chunks.retain(|_, c| c.snapshot_id != id);
+        self.snapshot_creatures.retain(|_, c| c.snapshot_id != id);
+    }
+
+    fn snapshot_chunks(&self, snapshot_id: u64) -> Vec<SnapshotChunk> {
+        self.snapshot_chunks
+            .values()
+            .filter(|c| c.snapshot_id == snapshot_id)
+            .cloned()
+            .collect()
+    }
+
+    fn insert_snapshot_chunk(&mut self, mut chunk: SnapshotChunk) {
+        chunk.id = self.assign_id(chunk.id);
+        self.snapshot_chunks.insert(chunk.id, chunk);
+    }
+
+    fn snapshot_creatures(&self, snapshot_id: u64) -> Vec<SnapshotCreature> {
+        self.snapshot_creatures
+            .values()
+            .filter(|c| c.snapshot_id == snapshot_id)
+            .cloned()
+            .collect()
+    }
+
+    fn insert_snapshot_creature(&mut self, mut creature: SnapshotCreature) {
+        creature.id = self.assign_id(creature.id);
+        self.snapshot_creatures.insert(creature.id, creature);
+    }
+
+    fn metrics(&self) -> Vec<ServerMetrics> {
+        self.metrics.values().cloned().collect()
+    }
+
+    fn insert_metrics(&mut self, mut metrics: ServerMetrics) {
+        metrics.id = self.assign_id(metrics.id);
+        self.metrics.insert(metrics.id, metrics);
+    }
+
+    fn delete_metrics(&mut self, id: u64) {
+        self.metrics.remove(&id);
+    }
+}
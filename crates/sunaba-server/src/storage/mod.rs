@@ -0,0 +1,176 @@
+//! Storage abstraction over the server tables
+//!
+//! Gameplay logic in `crate::logic` reads and writes rows through
+//! `ServerStorage` instead of `ReducerContext`, so the same code runs against
+//! SpacetimeDB (`SpacetimeStorage`) and in unit tests (`InMemoryStorage`).
+
+mod memory;
+mod spacetime;
+
+pub use memory::InMemoryStorage;
+pub use spacetime::SpacetimeStorage;
+
+use spacetimedb::Identity;
+
+use crate::tables::{
+    ChatMessage, ChunkData, CreatureData, CreatureInfo, CreaturePose, LandClaim, Player,
+    ServerMetrics, SnapshotChunk, SnapshotCreature, WorldConfig, WorldSnapshot,
+};
+
+/// Row access for chunks, players, claims, chat, creatures, snapshots, world
+/// config and metrics
+///
+/// Rows with an `#[auto_inc]` id are inserted with `id: 0` and get a fresh id
+/// assigned, matching SpacetimeDB semantics.
+pub trait ServerStorage {
+    // ========================================================================
+    // World config (singleton, id=0)
+    // ========================================================================
+
+    /// The world config row, if initialized
+    fn config(&self) -> Option<WorldConfig>;
+
+    /// Insert or replace the world config row
+    fn set_config(&mut self, config: WorldConfig);
+
+    // ========================================================================
+    // Chunks
+    // ========================================================================
+
+    /// Chunk row at chunk coordinates
+    fn find_chunk(&self, x: i32, y: i32) -> Option<ChunkData>;
+
+    /// All chunk rows
+    fn chunks(&self) -> Vec<ChunkData>;
+
+    /// Number of chunk rows
+    fn chunk_count(&self) -> usize;
+
+    fn insert_chunk(&mut self, chunk: ChunkData);
+
+    /// Replace the chunk row with the same id
+    fn update_chunk(&mut self, chunk: ChunkData);
+
+    fn delete_chunk(&mut self, id: u64);
+
+    // ========================================================================
+    // Players
+    // ========================================================================
+
+    fn find_player(&self, identity: Identity) -> Option<Player>;
+
+    /// All players (online and offline)
+    fn players(&self) -> Vec<Player>;
+
+    fn insert_player(&mut self, player: Player);
+
+    /// Replace the player row with the same identity
+    fn update_player(&mut self, player: Player);
+
+    /// Whether the identity is in the admin table
+    fn is_admin(&self, identity: Identity) -> bool;
+
+    // ========================================================================
+    // Land claims
+    // ========================================================================
+
+    fn claims(&self) -> Vec<LandClaim>;
+
+    fn find_claim(&self, id: u64) -> Option<LandClaim>;
+
+    fn insert_claim(&mut self, claim: LandClaim);
+
+    /// Replace the claim row with the same id
+    fn update_claim(&mut self, claim: LandClaim);
+
+    fn delete_claim(&mut self, id: u64);
+
+    // ========================================================================
+    // Chat
+    // ========================================================================
+
+    /// All chat messages (oldest first)
+    fn chat_messages(&self) -> Vec<ChatMessage>;
+
+    fn insert_chat_message(&mut self, message: ChatMessage);
+
+    fn delete_chat_message(&mut self, id: u64);
+
+    // ========================================================================
+    // Creatures
+    // ========================================================================
+
+    /// All creature rows (alive and dead)
+    fn creatures(&self) -> Vec<CreatureData>;
+
+    fn insert_creature(&mut self, creature: CreatureData);
+
+    /// Replace the creature row with the same id
+    fn update_creature(&mut self, creature: CreatureData);
+
+    fn delete_creature(&mut self, id: u64);
+
+    /// All published creature info rows
+    fn creature_infos(&self) -> Vec<CreatureInfo>;
+
+    fn insert_creature_info(&mut self, info: CreatureInfo);
+
+    fn delete_creature_info(&mut self, creature_id: u64);
+
+    /// All published creature poses
+    fn creature_poses(&self) -> Vec<CreaturePose>;
+
+    /// Insert or replace the pose with the same creature id
+    fn set_creature_pose(&mut self, pose: CreaturePose);
+
+    fn delete_creature_pose(&mut self, creature_id: u64);
+
+    // ========================================================================
+    // Snapshots
+    // ========================================================================
+
+    /// All snapshot headers (oldest first)
+    fn snapshots(&self) -> Vec<WorldSnapshot>;
+
+    fn find_snapshot(&self, id: u64) -> Option<WorldSnapshot>;
+
+    /// Insert a snapshot header and return its assigned id
+    fn insert_snapshot(&mut self, snapshot: WorldSnapshot) -> u64;
+
+    /// Delete a snapshot header together with its chunk and creature rows
+    fn delete_snapshot(&mut self, id: u64);
+
+    fn snapshot_chunks(&self, snapshot_id: u64) -> Vec<SnapshotChunk>;
+
+    fn insert_snapshot_chunk(&mut self, chunk: SnapshotChunk);
+
+    fn snapshot_creatures(&self, snapshot_id: u64) -> Vec<SnapshotCreature>;
+
+    fn insert_snapshot_creature(&mut self, creature: SnapshotCreature);
+
+    // ========================================================================
+    // Metrics
+    // ========================================================================
+
+    fn metrics(&self) -> Vec<ServerMetrics>;
+
+    fn insert_metrics(&mut self, metrics: ServerMetrics);
+
+    fn delete_metrics(&mut self, id: u64);
+
+    // ========================================================================
+    // Derived queries
+    // ========================================================================
+
+    fn online_players(&self) -> Vec<Player> {
+        self.players().into_iter().filter(|p| p.online).collect()
+    }
+
+    fn has_online_players(&self) -> bool {
+        self.players().iter().any(|p| p.online)
+    }
+
+    fn alive_creatures(&self) -> Vec<CreatureData> {
+        self.creatures().into_iter().filter(|c| c.alive).collect()
+    }
+}
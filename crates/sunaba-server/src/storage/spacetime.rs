@@ -0,0 +1,239 @@
+//! `ServerStorage` over live SpacetimeDB tables
+
+use spacetimedb::{Identity, ReducerContext, Table};
+
+use super::ServerStorage;
+use crate::tables::{
+    ChatMessage, ChunkData, CreatureData, CreatureInfo, CreaturePose, LandClaim, Player,
+    ServerMetrics, SnapshotChunk, SnapshotCreature, WorldConfig, WorldSnapshot, admin_user,
+    chat_message, chunk_data, creature_data, creature_info, creature_pose, land_claim, player,
+    server_metrics, snapshot_chunk, snapshot_creature, world_config, world_snapshot,
+};
+
+/// Storage backed by the tables of the current reducer call
+pub struct SpacetimeStorage<'a> {
+    ctx: &'a ReducerContext,
+}
+
+impl<'a> SpacetimeStorage<'a> {
+    pub fn new(ctx: &'a ReducerContext) -> Self {
+        Self { ctx }
+    }
+}
+
+impl ServerStorage for SpacetimeStorage<'_> {
+    fn config(&self) -> Option<WorldConfig> {
+        self.ctx.db.world_config().id().find(0)
+    }
+
+    fn set_config(&mut self, config: WorldConfig) {
+        if self.ctx.db.world_config().id().find(config.id).is_some() {
+            self.ctx.db.world_config().id().update(config);
+        } else {
+            self.ctx.db.world_config().insert(config);
+        }
+    }
+
+    fn find_chunk(&self, x: i32, y: i32) -> Option<ChunkData> {
+        self.ctx
+            .db
+            .chunk_data()
+            .iter()
+            .find(|c| c.x == x && c.y == y)
+    }
+
+    fn chunks(&self) -> Vec<ChunkData> {
+        self.ctx.db.chunk_data().iter().collect()
+    }
+
+    fn chunk_count(&self) -> usize {
+        self.ctx.db.chunk_data().count() as usize
+    }
+
+    fn insert_chunk(&mut self, chunk: ChunkData) {
+        self.ctx.db.chunk_data().insert(chunk);
+    }
+
+    fn update_chunk(&mut self, chunk: ChunkData) {
+        self.ctx.db.chunk_data().id().update(chunk);
+    }
+
+    fn delete_chunk(&mut self, id: u64) {
+        self.ctx.db.chunk_data().id().delete(id);
+    }
+
+    fn find_player(&self, identity: Identity) -> Option<Player> {
+        self.ctx.db.player().identity().find(identity)
+    }
+
+    fn players(&self) -> Vec<Player> {
+        self.ctx.db.player().iter().collect()
+    }
+
+    fn insert_player(&mut self, player: Player) {
+        self.ctx.db.player().insert(player);
+    }
+
+    fn update_player(&mut self, player: Player) {
+        self.ctx.db.player().identity().update(player);
+    }
+
+    fn is_admin(&self, identity: Identity) -> bool {
+        self.ctx.db.admin_user().identity().find(identity).is_some()
+    }
+
+    fn claims(&self) -> Vec<LandClaim> {
+        self.ctx.db.land_claim().iter().collect()
+    }
+
+    fn find_claim(&self, id: u64) -> Option<LandClaim> {
+        self.ctx.db.land_claim().id().find(id)
+    }
+
+    fn insert_claim(&mut self, claim: LandClaim) {
+        self.ctx.db.land_claim().insert(claim);
+    }
+
+    fn update_claim(&mut self, claim: LandClaim) {
+        self.ctx.db.land_claim().id().update(claim);
+    }
+
+    fn delete_claim(&mut self, id: u64) {
+        self.ctx.db.land_claim().id().delete(id);
+    }
+
+    fn chat_messages(&self) -> Vec<ChatMessage> {
+        let mut messages: Vec<_> = self.ctx.db.chat_message().iter().collect();
+        messages.sort_by_key(|m| m.id);
+        messages
+    }
+
+    fn insert_chat_message(&mut self, message: ChatMessage) {
+        self.ctx.db.chat_message().insert(message);
+    }
+
+    fn delete_chat_message(&mut self, id: u64) {
+        self.ctx.db.chat_message().id().delete(id);
+    }
+
+    fn creatures(&self) -> Vec<CreatureData> {
+        self.ctx.db.creature_data().iter().collect()
+    }
+
+    fn insert_creature(&mut self, creature: CreatureData) {
+        self.ctx.db.creature_data().insert(creature);
+    }
+
+    fn update_creature(&mut self, creature: CreatureData) {
+        self.ctx.db.creature_data().id().update(creature);
+    }
+
+    fn delete_creature(&mut self, id: u64) {
+        self.ctx.db.creature_data().id().delete(id);
+    }
+
+    fn creature_infos(&self) -> Vec<CreatureInfo> {
+        self.ctx.db.creature_info().iter().collect()
+    }
+
+    fn insert_creature_info(&mut self, info: CreatureInfo) {
+        self.ctx.db.creature_info().insert(info);
+    }
+
+    fn delete_creature_info(&mut self, creature_id: u64) {
+        self.ctx
+            .db
+            .creature_info()
+            .creature_id()
+            .delete(creature_id);
+    }
+
+    fn creature_poses(&self) -> Vec<CreaturePose> {
+        self.ctx.db.creature_pose().iter().collect()
+    }
+
+    fn set_creature_pose(&mut self, pose: CreaturePose) {
+        if self
+            .ctx
+            .db
+            .creature_pose()
+            .creature_id()
+            .find(pose.creature_id)
+            .is_some()
+        {
+            self.ctx.db.creature_pose().creature_id().update(pose);
+        } else {
+            self.ctx.db.creature_pose().insert(pose);
+        }
+    }
+
+    fn delete_creature_pose(&mut self, creature_id: u64) {
+        self.ctx
+            .db
+            .creature_pose()
+            .creature_id()
+            .delete(creature_id);
+    }
+
+    fn snapshots(&self) -> Vec<WorldSnapshot> {
+        let mut snapshots: Vec<_> = self.ctx.db.world_snapshot().iter().collect();
+        snapshots.sort_by_key(|s| s.id);
+        snapshots
+    }
+
+    fn find_snapshot(&self, id: u64) -> Option<WorldSnapshot> {
+        self.ctx.db.world_snapshot().id().find(id)
+    }
+
+    fn insert_snapshot(&mut self, snapshot: WorldSnapshot) -> u64 {
+        self.ctx.db.world_snapshot().insert(snapshot).id
+    }
+
+    fn delete_snapshot(&mut self, id: u64) {
+        for chunk in self.snapshot_chunks(id) {
+            self.ctx.db.snapshot_chunk().id().delete(chunk.id);
+        }
+        for creature in self.snapshot_creatures(id) {
+            self.ctx.db.snapshot_creature().id().delete(creature.id);
+        }
+        self.ctx.db.world_snapshot().id().delete(id);
+    }
+
+    fn snapshot_chunks(&self, snapshot_id: u64) -> Vec<SnapshotChunk> {
+        self.ctx
+            .db
+            .snapshot_chunk()
+            .iter()
+            .filter(|c| c.snapshot_id == snapshot_id)
+            .collect()
+    }
+
+    fn insert_snapshot_chunk(&mut self, chunk: SnapshotChunk) {
+        self.ctx.db.snapshot_chunk().insert(chunk);
+    }
+
+    fn snapshot_creatures(&self, snapshot_id: u64) -> Vec<SnapshotCreature> {
+        self.ctx
+            .db
+            .snapshot_creature()
+            .iter()
+            .filter(|c| c.snapshot_id == snapshot_id)
+            .collect()
+    }
+
+    fn insert_snapshot_creature(&mut self, creature: SnapshotCreature) {
+        self.ctx.db.snapshot_creature().insert(creature);
+    }
+
+    fn metrics(&self) -> Vec<ServerMetrics> {
+        self.ctx.db.server_metrics().iter().collect()
+    }
+
+    fn insert_metrics(&mut self, metrics: ServerMetrics) {
+        self.ctx.db.server_metrics().insert(metrics);
+    }
+
+    fn delete_metrics(&mut self, id: u64) {
+        self.ctx.db.server_metrics().id().delete(id);
+    }
+}
@@ -1,6 +1,6 @@
 //! SpacetimeDB table definitions for Sunaba multiplayer server
 
-use spacetimedb::{Identity, ScheduleAt, Timestamp};
+use spacetimedb::{Identity, ScheduleAt, SpacetimeType, Timestamp};
 
 // Import reducer functions for scheduled tables
 use crate::reducers::{creature_tick, settle_world_tick, world_tick};
@@ -10,6 +10,7 @@ use crate::reducers::{creature_tick, settle_world_tick, world_tick};
 // ============================================================================
 
 /// Global world configuration (singleton, id=0)
+#[derive(Clone, Debug)]
 #[spacetimedb::table(name = world_config, public)]
 pub struct WorldConfig {
     #[primary_key]
@@ -32,9 +33,14 @@ pub struct WorldConfig {
     pub last_activity_tick: u64,
     /// Whether world is currently in idle mode (no simulation needed)
     pub is_idle: bool,
+    /// Half-size of the square around spawn only admins can edit (pixels, 0 = off)
+    pub spawn_protection_radius: i32,
+    /// Seconds into the day/night cycle (clients follow this)
+    pub time_of_day: f32,
 }
 
 /// Chunk pixel data
+#[derive(Clone, Debug)]
 #[spacetimedb::table(name = chunk_data, public)]
 pub struct ChunkData {
     #[primary_key]
@@ -53,6 +59,7 @@ pub struct ChunkData {
 }
 
 /// Player state
+#[derive(Clone, Debug)]
 #[spacetimedb::table(name = player, public)]
 pub struct Player {
     #[primary_key]
@@ -75,10 +82,85 @@ pub struct Player {
     pub health: f32,
     /// Current hunger
     pub hunger: f32,
+    /// Inventory (bincode-encoded `sunaba_core::entity::inventory::Inventory`)
+    pub inventory_data: Vec<u8>,
+    /// Equipped tool ID (must be in the inventory)
+    pub equipped_tool: Option<u16>,
+    /// Mining progress (bincode-encoded `sunaba_core::simulation::mining::MiningProgress`)
+    pub mining_data: Vec<u8>,
+    /// Highest client edit sequence number processed this session
+    pub last_edit_seq: u64,
+    /// Recently rejected edit sequence numbers (oldest first, capped)
+    pub rejected_edits: Vec<u64>,
+    /// Bumped when an admin teleports the player (the client moves to
+    /// `teleport_x`/`teleport_y` when it sees a new value)
+    pub teleport_seq: u32,
+    pub teleport_x: f32,
+    pub teleport_y: f32,
+    /// Chunk radius around the player the client keeps loaded (creature
+    /// poses are only published inside it)
+    pub loaded_radius: i32,
+    /// When the player's chat messages and commands inside the rate limit
+    /// window were sent (milliseconds since the Unix epoch, oldest first)
+    pub chat_sent_ms: Vec<u64>,
+}
+
+/// Chat message or server reply (capped history)
+#[derive(Clone, Debug)]
+#[spacetimedb::table(name = chat_message, public)]
+pub struct ChatMessage {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// Player who sent the message (or whose command produced a reply)
+    pub sender: Identity,
+    /// Sender's display name at the time of sending
+    pub sender_name: String,
+    pub text: String,
+    /// Milliseconds since the Unix epoch
+    pub sent_at_ms: u64,
+    /// Server-generated (command replies and announcements)
+    pub system: bool,
+    /// Only shown to this player (clients filter; `None` = everyone)
+    pub recipient: Option<Identity>,
+}
+
+/// Land claimed by a player (inclusive world pixel bounds)
+#[derive(Clone, Debug)]
+#[spacetimedb::table(name = land_claim, public)]
+pub struct LandClaim {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// Player who owns the claim
+    pub owner: Identity,
+    /// Display name
+    pub name: String,
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+    /// Permissions granted to other players
+    pub members: Vec<ClaimMember>,
+}
+
+/// Permissions of a non-owner inside a land claim
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct ClaimMember {
+    pub identity: Identity,
+    /// May place materials
+    pub build: bool,
+    /// May mine pixels
+    pub mine: bool,
+    /// May craft and eat inside the claim
+    pub interact: bool,
 }
 
 /// Creature state (server-side AI)
-#[spacetimedb::table(name = creature_data, public)]
+///
+/// Private: clients read `creature_info` once and follow `creature_pose`.
+#[derive(Clone, Debug)]
+#[spacetimedb::table(name = creature_data)]
 pub struct CreatureData {
     #[primary_key]
     #[auto_inc]
@@ -123,7 +205,109 @@ pub struct CreatureData {
     pub alive: bool,
 }
 
+/// Creature data that never changes after spawning (clients fetch it once)
+#[derive(Clone, Debug)]
+#[spacetimedb::table(name = creature_info, public)]
+pub struct CreatureInfo {
+    /// `CreatureData::id`
+    #[primary_key]
+    pub creature_id: u64,
+    /// Archetype name
+    pub archetype: String,
+    /// Serialized CreatureGenome (bincode)
+    pub genome_data: Vec<u8>,
+    /// Serialized CreatureMorphology (bincode)
+    pub morphology_data: Vec<u8>,
+    /// Generation number
+    pub generation: u64,
+}
+
+/// Latest creature pose, only kept for creatures inside some online
+/// player's loaded radius
+#[derive(Clone, Debug)]
+#[spacetimedb::table(name = creature_pose, public)]
+pub struct CreaturePose {
+    /// `CreatureData::id`
+    #[primary_key]
+    pub creature_id: u64,
+    /// Bumped every time the creature tick publishes this pose; unlike the
+    /// world tick it keeps counting while the simulation is paused
+    pub seq: u64,
+    /// Position X
+    pub x: f32,
+    /// Position Y
+    pub y: f32,
+    /// Chunk X (for subscription ranges)
+    pub chunk_x: i32,
+    /// Chunk Y (for subscription ranges)
+    pub chunk_y: i32,
+    /// Current health
+    pub health: f32,
+    /// Maximum health
+    pub max_health: f32,
+    /// Body parts in morphology order
+    pub parts: Vec<PartPose>,
+}
+
+/// World transform of one creature body part
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub struct PartPose {
+    pub x: f32,
+    pub y: f32,
+    /// Radians
+    pub rotation: f32,
+}
+
+/// World backup taken at a tick (its rows live in `snapshot_chunk` and
+/// `snapshot_creature`)
+#[derive(Clone, Debug)]
+#[spacetimedb::table(name = world_snapshot, public)]
+pub struct WorldSnapshot {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// World tick when the snapshot was taken
+    pub tick: u64,
+    /// Milliseconds since the Unix epoch
+    pub taken_at_ms: u64,
+    /// Admin-provided label (empty for periodic snapshots)
+    pub label: String,
+    /// Taken by the periodic timer (only these are rotated out)
+    pub automatic: bool,
+    /// World config at the time of the snapshot
+    pub config: WorldConfig,
+    pub chunk_count: u32,
+    pub creature_count: u32,
+}
+
+/// Chunk blob stored in a snapshot (same encoding as `ChunkData::pixel_data`)
+#[derive(Clone, Debug)]
+#[spacetimedb::table(name = snapshot_chunk)]
+pub struct SnapshotChunk {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub snapshot_id: u64,
+    /// Chunk X coordinate (chunk space)
+    pub x: i32,
+    /// Chunk Y coordinate (chunk space)
+    pub y: i32,
+    pub pixel_data: Vec<u8>,
+}
+
+/// Creature row stored in a snapshot
+#[derive(Clone, Debug)]
+#[spacetimedb::table(name = snapshot_creature)]
+pub struct SnapshotCreature {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub snapshot_id: u64,
+    pub creature: CreatureData,
+}
+
 /// Server performance metrics (rolling history)
+#[derive(Clone, Debug)]
 #[spacetimedb::table(name = server_metrics, public)]
 pub struct ServerMetrics {
     #[primary_key]
@@ -174,6 +358,15 @@ pub struct SettleTickTimer {
     pub scheduled_at: ScheduleAt,
 }
 
+/// Timer table for periodic snapshot checks
+/// Note: No #[auto_inc] - we use fixed ID 0 for singleton timer pattern
+#[spacetimedb::table(name = snapshot_tick_timer, scheduled(snapshot_tick))]
+pub struct SnapshotTickTimer {
+    #[primary_key]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
 // ============================================================================
 // Admin Tables
 // ============================================================================
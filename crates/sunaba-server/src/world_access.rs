@@ -1,41 +1,40 @@
-//! WorldAccess trait implementation for SpacetimeDB
+//! WorldAccess trait implementation over server storage
 //!
-//! Provides creature sensing and world interaction over SpacetimeDB tables.
+//! Provides creature sensing and world interaction over the chunk table.
 
 use std::cell::RefCell;
 use std::collections::HashMap;
 
 use glam::Vec2;
-use spacetimedb::{ReducerContext, Table};
+use sunaba_core::world::Chunk;
 use sunaba_creature::{WorldAccess, WorldMutAccess};
-use sunaba_simulation::{CHUNK_SIZE, MaterialId, MaterialType, Materials, Pixel};
+use sunaba_simulation::{CHUNK_SIZE, MaterialType, Materials, Pixel};
 
-use crate::encoding::{decode_chunk_pixels, encode_chunk_pixels};
-use crate::{ChunkData, chunk_data};
+use crate::encoding::decode_chunk;
+use crate::storage::ServerStorage;
 
 /// Cached chunk data
 struct CachedChunk {
-    pixels: Vec<Pixel>,
+    chunk: Chunk,
     dirty: bool,
-    db_id: u64,
 }
 
-/// WorldAccess implementation over SpacetimeDB tables
+/// WorldAccess implementation over the chunk table of a `ServerStorage`
 ///
 /// Uses interior mutability for chunk caching since WorldAccess trait
 /// methods take &self for compatibility with existing creature code.
-pub struct SpacetimeWorldAccess<'a> {
-    ctx: &'a ReducerContext,
+pub struct StorageWorldAccess<'a, S: ServerStorage> {
+    storage: &'a S,
     materials: Materials,
     /// Cached decoded chunks (uses RefCell for interior mutability)
     chunk_cache: RefCell<HashMap<(i32, i32), CachedChunk>>,
 }
 
-impl<'a> SpacetimeWorldAccess<'a> {
+impl<'a, S: ServerStorage> StorageWorldAccess<'a, S> {
     /// Create new world access wrapper
-    pub fn new(ctx: &'a ReducerContext) -> Self {
+    pub fn new(storage: &'a S) -> Self {
         Self {
-            ctx,
+            storage,
             materials: Materials::default(),
             chunk_cache: RefCell::new(HashMap::new()),
         }
@@ -50,42 +49,26 @@ impl<'a> SpacetimeWorldAccess<'a> {
         (chunk_x, chunk_y, local_x, local_y)
     }
 
-    /// Load and cache a chunk from the database
+    /// Load and cache a chunk from storage
     fn load_chunk(&self, chunk_x: i32, chunk_y: i32) -> bool {
         let mut cache = self.chunk_cache.borrow_mut();
         if cache.contains_key(&(chunk_x, chunk_y)) {
             return true;
         }
 
-        // Query chunk from database
-        let chunk_opt = self
-            .ctx
-            .db
-            .chunk_data()
-            .iter()
-            .find(|c| c.x == chunk_x && c.y == chunk_y);
-
-        if let Some(chunk) = chunk_opt
-            && let Ok(pixels) = decode_chunk_pixels(&chunk.pixel_data)
-        {
-            cache.insert(
-                (chunk_x, chunk_y),
-                CachedChunk {
-                    pixels,
-                    dirty: false,
-                    db_id: chunk.id,
-                },
-            );
-            return true;
-        }
+        // Query chunk from storage (rows hold full encoded chunks)
+        let chunk = self
+            .storage
+            .find_chunk(chunk_x, chunk_y)
+            .and_then(|row| decode_chunk(&row.pixel_data).ok())
+            // Create empty chunk if not found
+            .unwrap_or_else(|| Chunk::new(chunk_x, chunk_y));
 
-        // Create empty chunk if not found
         cache.insert(
             (chunk_x, chunk_y),
             CachedChunk {
-                pixels: vec![Pixel::new(MaterialId::AIR); CHUNK_SIZE * CHUNK_SIZE],
+                chunk,
                 dirty: false,
-                db_id: 0, // Will need to insert
             },
         );
 
@@ -102,14 +85,9 @@ impl<'a> SpacetimeWorldAccess<'a> {
     ) -> Option<Pixel> {
         self.load_chunk(chunk_x, chunk_y);
         let cache = self.chunk_cache.borrow();
-        cache.get(&(chunk_x, chunk_y)).map(|chunk| {
-            let idx = local_y * CHUNK_SIZE + local_x;
-            chunk
-                .pixels
-                .get(idx)
-                .copied()
-                .unwrap_or_else(|| Pixel::new(0))
-        })
+        cache
+            .get(&(chunk_x, chunk_y))
+            .map(|cached| cached.chunk.get_pixel(local_x, local_y))
     }
 
     /// Set pixel in cache and mark dirty
@@ -123,52 +101,21 @@ impl<'a> SpacetimeWorldAccess<'a> {
     ) {
         self.load_chunk(chunk_x, chunk_y);
         let mut cache = self.chunk_cache.borrow_mut();
-        if let Some(chunk) = cache.get_mut(&(chunk_x, chunk_y)) {
-            let idx = local_y * CHUNK_SIZE + local_x;
-            if idx < chunk.pixels.len() {
-                chunk.pixels[idx] = pixel;
-                chunk.dirty = true;
-            }
+        if let Some(cached) = cache.get_mut(&(chunk_x, chunk_y)) {
+            cached.chunk.set_pixel(local_x, local_y, pixel);
+            cached.dirty = true;
         }
     }
 
-    /// Commit all dirty chunks back to database
+    /// Chunks modified through `WorldMutAccess`, for the caller to write back
     #[allow(dead_code)]
-    pub fn commit_changes(&self) -> Result<(), String> {
-        let cache = self.chunk_cache.borrow();
-        for ((chunk_x, chunk_y), cached) in cache.iter() {
-            if cached.dirty {
-                let pixel_data = encode_chunk_pixels(&cached.pixels)?;
-
-                if cached.db_id != 0 {
-                    // Update existing chunk
-                    if let Some(existing) = self
-                        .ctx
-                        .db
-                        .chunk_data()
-                        .iter()
-                        .find(|c| c.x == *chunk_x && c.y == *chunk_y)
-                    {
-                        self.ctx.db.chunk_data().id().update(ChunkData {
-                            pixel_data,
-                            dirty: true,
-                            ..existing
-                        });
-                    }
-                } else {
-                    // Insert new chunk
-                    let _ = self.ctx.db.chunk_data().try_insert(ChunkData {
-                        id: 0,
-                        x: *chunk_x,
-                        y: *chunk_y,
-                        pixel_data,
-                        dirty: true,
-                        last_modified_tick: 0,
-                    });
-                }
-            }
-        }
-        Ok(())
+    pub fn into_modified_chunks(self) -> Vec<Chunk> {
+        self.chunk_cache
+            .into_inner()
+            .into_values()
+            .filter(|cached| cached.dirty)
+            .map(|cached| cached.chunk)
+            .collect()
     }
 
     /// Check if material type is solid-like (blocks movement)
@@ -184,7 +131,7 @@ impl<'a> SpacetimeWorldAccess<'a> {
     }
 }
 
-impl<'a> WorldAccess for SpacetimeWorldAccess<'a> {
+impl<S: ServerStorage> WorldAccess for StorageWorldAccess<'_, S> {
     fn get_pixel(&self, x: i32, y: i32) -> Option<Pixel> {
         let (chunk_x, chunk_y, local_x, local_y) = Self::world_to_chunk(x, y);
         self.get_cached_pixel(chunk_x, chunk_y, local_x, local_y)
@@ -283,7 +230,7 @@ impl<'a> WorldAccess for SpacetimeWorldAccess<'a> {
     }
 }
 
-impl<'a> WorldMutAccess for SpacetimeWorldAccess<'a> {
+impl<S: ServerStorage> WorldMutAccess for StorageWorldAccess<'_, S> {
     fn set_pixel(&mut self, x: i32, y: i32, material_id: u16) {
         self.set_pixel_full(x, y, Pixel::new(material_id));
     }
@@ -85,6 +85,32 @@ impl MaterialId {
     pub const SEAWATER: u16 = 56; // Salty water, better conductor
     pub const SOAPY_WATER: u16 = 57; // Creates bubbles
     pub const BUBBLE: u16 = 58; // Gas-filled shell, rises, pops
+
+    // Logic components (driven by the electrical system)
+    pub const DIODE: u16 = 59; // Passes power one way (DIRECTION bits)
+    pub const NOT_GATE: u16 = 60; // Outputs power when input is unpowered
+    pub const AND_GATE: u16 = 61; // Outputs power when both side inputs are powered
+    pub const RELAY: u16 = 62; // Closed relay (solid), opens when powered
+    pub const RELAY_OPEN: u16 = 63; // Open relay (passable), closes when unpowered
+    pub const PRESSURE_PLATE: u16 = 64; // Powers neighbors when weighed down
+    pub const DELAY: u16 = 65; // Repeats its input after a fixed number of ticks
+    pub const WATER_SENSOR: u16 = 66; // Powers neighbors when touching water
+
+    // Workstations (multi-pixel structures placed by the player)
+    pub const FURNACE: u16 = 67; // Smelts ores using fuel
+    pub const ANVIL: u16 = 68; // Forges tools from ingots
+    pub const ALCHEMY_TABLE: u16 = 69; // Brews volatile mixtures
+
+    // Fluid handling (pumps are driven by the electrical system)
+    pub const PIPE: u16 = 70; // Carries pumped fluid to its open ends
+    pub const PUMP: u16 = 71; // Moves fluid from behind it into the pipe in front (DIRECTION bits)
+
+    // Weather
+    pub const SNOW: u16 = 72; // Falls in cold biomes, melts back to water
+
+    // Vegetation (grown by the ecology simulation)
+    pub const GRASS: u16 = 73; // Dirt covered in grass, spreads over lit dirt
+    pub const SAPLING: u16 = 74; // Young tree, grows a trunk and canopy on soil
 }
 
 /// How a material behaves physically
@@ -195,6 +221,9 @@ pub struct MaterialDef {
     pub structural_strength: Option<f32>,
     /// Energy released when burned (for smelting/cooking)
     pub fuel_value: Option<f32>,
+    /// Blast energy released on detonation (None = not an explosive)
+    #[serde(default)]
+    pub explosive_yield: Option<f32>,
     /// Material category tags
     pub tags: Vec<MaterialTag>,
 }
@@ -233,6 +262,7 @@ impl Default for MaterialDef {
             hardness_multiplier: 1.0,
             structural_strength: None,
             fuel_value: None,
+            explosive_yield: None,
             tags: Vec::new(),
         }
     }
@@ -402,6 +432,7 @@ impl Materials {
             density: 1.1,
             hardness: None,
             viscosity: 0.2,
+            toxicity: Some(8.0), // Corrosive on contact
             ..Default::default()
         });
 
@@ -742,6 +773,7 @@ impl Materials {
             burns_to: Some(MaterialId::SMOKE),
             burn_rate: 0.9,         // Burns VERY fast (explosion)
             fuel_value: Some(50.0), // High energy
+            explosive_yield: Some(4.0),
             tags: vec![MaterialTag::Fuel],
             ..Default::default()
         });
@@ -942,6 +974,7 @@ impl Materials {
             burns_to: Some(MaterialId::SMOKE),
             burn_rate: 1.0,          // Instant burn/explosion
             fuel_value: Some(100.0), // High energy
+            explosive_yield: Some(9.0),
             ..Default::default()
         });
 
@@ -1075,8 +1108,9 @@ impl Materials {
             flammable: true,
             ignition_temp: Some(400.0), // High ignition temp - won't detonate easily
             burns_to: Some(MaterialId::SMOKE),
-            burn_rate: 1.0,          // Instant when triggered
-            fuel_value: Some(200.0), // Very high energy
+            burn_rate: 1.0,              // Instant when triggered
+            fuel_value: Some(200.0),     // Very high energy
+            explosive_yield: Some(25.0), // Largest blast
             conducts_electricity: false,
             ..Default::default()
         });
@@ -1095,6 +1129,7 @@ impl Materials {
             burns_to: Some(MaterialId::SMOKE),
             burn_rate: 1.0,          // Instant explosion
             fuel_value: Some(100.0), // Medium-high energy
+            explosive_yield: Some(16.0),
             ..Default::default()
         });
 
@@ -1197,6 +1232,255 @@ impl Materials {
             flammable: false,
             ..Default::default()
         });
+
+        // ===== LOGIC COMPONENTS =====
+        // Non-conductive on purpose: power only crosses them through the logic rules
+        // in LogicSystem, which read inputs/outputs relative to the DIRECTION bits.
+
+        // Diode - forwards power from behind to the pixel it faces
+        self.register(MaterialDef {
+            id: MaterialId::DIODE,
+            name: "diode".to_string(),
+            material_type: MaterialType::Solid,
+            color: [90, 90, 110, 255], // Dark slate
+            density: 2.5,
+            hardness: Some(2),
+            structural: false,
+            tags: vec![MaterialTag::Refined],
+            ..Default::default()
+        });
+
+        // NOT gate - inverts the input behind it
+        self.register(MaterialDef {
+            id: MaterialId::NOT_GATE,
+            name: "not_gate".to_string(),
+            material_type: MaterialType::Solid,
+            color: [170, 60, 60, 255], // Muted red
+            density: 2.5,
+            hardness: Some(2),
+            structural: false,
+            tags: vec![MaterialTag::Refined],
+            ..Default::default()
+        });
+
+        // AND gate - both side inputs must be powered
+        self.register(MaterialDef {
+            id: MaterialId::AND_GATE,
+            name: "and_gate".to_string(),
+            material_type: MaterialType::Solid,
+            color: [60, 140, 170, 255], // Muted cyan
+            density: 2.5,
+            hardness: Some(2),
+            structural: false,
+            tags: vec![MaterialTag::Refined],
+            ..Default::default()
+        });
+
+        // Relay - solid block that opens while powered (doors, gates, valves)
+        self.register(MaterialDef {
+            id: MaterialId::RELAY,
+            name: "relay".to_string(),
+            material_type: MaterialType::Solid,
+            color: [120, 110, 90, 255], // Brass-grey
+            density: 5.0,
+            hardness: Some(4),
+            structural: true,
+            structural_strength: Some(60.0),
+            tags: vec![MaterialTag::Refined],
+            ..Default::default()
+        });
+
+        // Open relay - passable for entities (collision only checks solids) and holds its
+        // position (see World::update_pixel). A powder rather than a gas so fluid systems
+        // and flasks leave it alone; high density so nothing falls or flows through it.
+        self.register(MaterialDef {
+            id: MaterialId::RELAY_OPEN,
+            name: "relay_open".to_string(),
+            material_type: MaterialType::Powder,
+            color: [120, 110, 90, 60], // Faint outline of the closed relay
+            density: 100.0,
+            hardness: Some(4),
+            structural: false,
+            tags: vec![MaterialTag::Refined],
+            ..Default::default()
+        });
+
+        // Pressure plate - powers neighbors when something rests on top
+        self.register(MaterialDef {
+            id: MaterialId::PRESSURE_PLATE,
+            name: "pressure_plate".to_string(),
+            material_type: MaterialType::Solid,
+            color: [150, 150, 140, 255], // Light stone
+            density: 3.0,
+            hardness: Some(3),
+            structural: true,
+            structural_strength: Some(40.0),
+            tags: vec![MaterialTag::Refined],
+            ..Default::default()
+        });
+
+        // Delay - repeats its input after a fixed number of ticks
+        self.register(MaterialDef {
+            id: MaterialId::DELAY,
+            name: "delay".to_string(),
+            material_type: MaterialType::Solid,
+            color: [170, 140, 60, 255], // Amber
+            density: 2.5,
+            hardness: Some(2),
+            structural: false,
+            tags: vec![MaterialTag::Refined],
+            ..Default::default()
+        });
+
+        // Water sensor - powers neighbors while touching water
+        self.register(MaterialDef {
+            id: MaterialId::WATER_SENSOR,
+            name: "water_sensor".to_string(),
+            material_type: MaterialType::Solid,
+            color: [70, 110, 200, 255], // Deep blue
+            density: 2.5,
+            hardness: Some(2),
+            structural: false,
+            tags: vec![MaterialTag::Refined],
+            ..Default::default()
+        });
+
+        // --- Workstations ---
+
+        // Furnace - stone oven, conducts its smelting heat into the surroundings
+        self.register(MaterialDef {
+            id: MaterialId::FURNACE,
+            name: "furnace".to_string(),
+            material_type: MaterialType::Solid,
+            color: [110, 85, 75, 255], // Soot-stained brick
+            density: 2.8,
+            hardness: Some(4),
+            structural: true,
+            melting_point: Some(1800.0),
+            heat_conductivity: 0.9,
+            structural_strength: Some(200.0),
+            tags: vec![MaterialTag::Refined],
+            ..Default::default()
+        });
+
+        // Anvil - solid iron block
+        self.register(MaterialDef {
+            id: MaterialId::ANVIL,
+            name: "anvil".to_string(),
+            material_type: MaterialType::Solid,
+            color: [70, 72, 80, 255], // Dark iron
+            density: 7.8,
+            hardness: Some(6),
+            structural: true,
+            conducts_electricity: true,
+            electrical_conductivity: 0.8,
+            structural_strength: Some(400.0),
+            tags: vec![MaterialTag::Metallic, MaterialTag::Refined],
+            ..Default::default()
+        });
+
+        // Alchemy table - wooden bench with glassware
+        self.register(MaterialDef {
+            id: MaterialId::ALCHEMY_TABLE,
+            name: "alchemy_table".to_string(),
+            material_type: MaterialType::Solid,
+            color: [120, 80, 140, 255], // Stained purple wood
+            density: 0.9,
+            hardness: Some(2),
+            structural: true,
+            flammable: true,
+            ignition_temp: Some(300.0),
+            burns_to: Some(MaterialId::ASH),
+            burn_rate: 0.005,
+            structural_strength: Some(60.0),
+            tags: vec![MaterialTag::Organic, MaterialTag::Refined],
+            ..Default::default()
+        });
+
+        // --- Fluid handling ---
+
+        // Pipe - copper tubing, insulated so it never joins a circuit
+        self.register(MaterialDef {
+            id: MaterialId::PIPE,
+            name: "pipe".to_string(),
+            material_type: MaterialType::Solid,
+            color: [150, 110, 80, 255], // Tarnished copper
+            density: 4.0,
+            hardness: Some(3),
+            structural: true,
+            melting_point: Some(1085.0),
+            heat_conductivity: 0.8,
+            structural_strength: Some(80.0),
+            tags: vec![MaterialTag::Metallic, MaterialTag::Refined],
+            ..Default::default()
+        });
+
+        // Pump - reads the powered conductors around it like a logic component
+        self.register(MaterialDef {
+            id: MaterialId::PUMP,
+            name: "pump".to_string(),
+            material_type: MaterialType::Solid,
+            color: [80, 120, 110, 255], // Painted steel
+            density: 5.0,
+            hardness: Some(4),
+            structural: true,
+            melting_point: Some(1400.0),
+            structural_strength: Some(120.0),
+            tags: vec![MaterialTag::Metallic, MaterialTag::Refined],
+            ..Default::default()
+        });
+
+        // WEATHER MATERIALS
+
+        // Snow - light powder that drifts in the wind and melts like ice
+        self.register(MaterialDef {
+            id: MaterialId::SNOW,
+            name: "snow".to_string(),
+            material_type: MaterialType::Powder,
+            color: [240, 245, 255, 255], // Bright white
+            density: 0.3,
+            hardness: Some(1),
+            friction: 0.3,
+            melting_point: Some(0.0),
+            melts_to: Some(MaterialId::WATER),
+            ..Default::default()
+        });
+
+        // VEGETATION MATERIALS
+
+        // Grass - soil with a living cover; burning it leaves bare dirt
+        self.register(MaterialDef {
+            id: MaterialId::GRASS,
+            name: "grass".to_string(),
+            material_type: MaterialType::Solid,
+            color: [86, 160, 60, 255], // Meadow green
+            density: 1.3,
+            hardness: Some(1),
+            flammable: true,
+            ignition_temp: Some(250.0),
+            burns_to: Some(MaterialId::DIRT),
+            burn_rate: 0.05,
+            hardness_multiplier: 0.5,
+            tags: vec![MaterialTag::Organic],
+            ..Default::default()
+        });
+
+        // Sapling - falls like a seed, then grows into a tree
+        self.register(MaterialDef {
+            id: MaterialId::SAPLING,
+            name: "sapling".to_string(),
+            material_type: MaterialType::Powder,
+            color: [120, 170, 70, 255], // Pale green
+            density: 0.5,
+            hardness: Some(1),
+            flammable: true,
+            ignition_temp: Some(250.0),
+            burns_to: Some(MaterialId::ASH),
+            burn_rate: 0.05,
+            hardness_multiplier: 0.3,
+            tags: vec![MaterialTag::Organic],
+            ..Default::default()
+        });
     }
 
     fn register(&mut self, material: MaterialDef) {
@@ -1734,4 +2018,94 @@ mod tests {
                 || materials.get(MaterialId::BUBBLE).density < 0.05
         );
     }
+
+    #[test]
+    fn test_logic_component_materials() {
+        let materials = Materials::new();
+
+        assert_eq!(MaterialId::DIODE, 59);
+        assert_eq!(MaterialId::WATER_SENSOR, 66);
+
+        // Gates must not conduct, otherwise power would leak around the logic rules
+        for id in [
+            MaterialId::DIODE,
+            MaterialId::NOT_GATE,
+            MaterialId::AND_GATE,
+            MaterialId::RELAY,
+            MaterialId::PRESSURE_PLATE,
+            MaterialId::DELAY,
+            MaterialId::WATER_SENSOR,
+        ] {
+            let mat = materials.get(id);
+            assert_eq!(mat.material_type, MaterialType::Solid, "{}", mat.name);
+            assert!(!mat.conducts_electricity, "{} should not conduct", mat.name);
+        }
+
+        // Open relay is passable, not a fluid, and too dense to be displaced
+        let open = materials.get(MaterialId::RELAY_OPEN);
+        assert_eq!(open.material_type, MaterialType::Powder);
+        assert!(open.density > materials.get(MaterialId::MERCURY).density);
+    }
+
+    #[test]
+    fn test_workstation_materials() {
+        let materials = Materials::new();
+
+        assert_eq!(MaterialId::FURNACE, 67);
+        assert_eq!(MaterialId::ALCHEMY_TABLE, 69);
+
+        for id in [
+            MaterialId::FURNACE,
+            MaterialId::ANVIL,
+            MaterialId::ALCHEMY_TABLE,
+        ] {
+            let mat = materials.get(id);
+            assert_eq!(mat.material_type, MaterialType::Solid, "{}", mat.name);
+            assert!(mat.structural, "{} should support placement", mat.name);
+        }
+    }
+
+    #[test]
+    fn test_fluid_handling_materials() {
+        let materials = Materials::new();
+
+        assert_eq!(MaterialId::PIPE, 70);
+        assert_eq!(MaterialId::PUMP, 71);
+
+        // Neither may conduct, otherwise plumbing would short out the circuit driving the pump
+        for id in [MaterialId::PIPE, MaterialId::PUMP] {
+            let mat = materials.get(id);
+            assert_eq!(mat.material_type, MaterialType::Solid, "{}", mat.name);
+            assert!(!mat.conducts_electricity, "{} should not conduct", mat.name);
+        }
+    }
+
+    #[test]
+    fn test_snow_material() {
+        let materials = Materials::new();
+        let snow = materials.get(MaterialId::SNOW);
+
+        assert_eq!(MaterialId::SNOW, 72);
+        assert_eq!(snow.material_type, MaterialType::Powder);
+        assert_eq!(snow.melts_to, Some(MaterialId::WATER));
+        assert!(snow.density < materials.get(MaterialId::SAND).density);
+    }
+
+    #[test]
+    fn test_vegetation_materials() {
+        let materials = Materials::new();
+
+        assert_eq!(MaterialId::GRASS, 73);
+        assert_eq!(MaterialId::SAPLING, 74);
+
+        // Fire clears grass back to dirt
+        let grass = materials.get(MaterialId::GRASS);
+        assert!(grass.flammable);
+        assert_eq!(grass.burns_to, Some(MaterialId::DIRT));
+
+        // Saplings fall onto soil like seeds
+        let sapling = materials.get(MaterialId::SAPLING);
+        assert_eq!(sapling.material_type, MaterialType::Powder);
+        assert!(sapling.flammable);
+    }
 }
@@ -36,6 +36,25 @@ impl Pixel {
     pub fn is_empty(&self) -> bool {
         self.material_id == MaterialId::AIR
     }
+
+    /// Direction encoded in the DIRECTION bits (0 = up, 1 = right, 2 = down, 3 = left)
+    pub fn direction(&self) -> u8 {
+        let bit0 = (self.flags & pixel_flags::DIRECTION_BIT0 != 0) as u8;
+        let bit1 = (self.flags & pixel_flags::DIRECTION_BIT1 != 0) as u8;
+        bit0 | (bit1 << 1)
+    }
+
+    /// Return a copy with the DIRECTION bits set to `dir` (0-3)
+    pub fn with_direction(mut self, dir: u8) -> Self {
+        self.flags &= !(pixel_flags::DIRECTION_BIT0 | pixel_flags::DIRECTION_BIT1);
+        if dir & 1 != 0 {
+            self.flags |= pixel_flags::DIRECTION_BIT0;
+        }
+        if dir & 2 != 0 {
+            self.flags |= pixel_flags::DIRECTION_BIT1;
+        }
+        self
+    }
 }
 
 /// Flag bits for pixel state
@@ -111,6 +130,21 @@ mod tests {
         assert_eq!(pixel_flags::CLONE_SOURCE, 2048);
     }
 
+    #[test]
+    fn test_pixel_direction_round_trip() {
+        for dir in 0..4u8 {
+            let pixel = Pixel::new(MaterialId::DIODE).with_direction(dir);
+            assert_eq!(pixel.direction(), dir);
+        }
+
+        // Re-orienting clears the previous direction and keeps other flags
+        let mut pixel = Pixel::new(MaterialId::DIODE).with_direction(3);
+        pixel.flags |= pixel_flags::POWERED;
+        let pixel = pixel.with_direction(1);
+        assert_eq!(pixel.direction(), 1);
+        assert!(pixel.flags & pixel_flags::POWERED != 0);
+    }
+
     #[test]
     fn test_pixel_default() {
         let pixel = Pixel::default();
@@ -313,8 +313,8 @@ impl ReactionRegistry {
 
         // === GROWTH/LIFE REACTIONS ===
 
-        // Plant Matter + Water → Plant Matter + Plant Matter (growth, requires light)
-        // NOTE: Light check not implemented yet, will be added in Milestone 4
+        // Plant Matter + Water → Plant Matter + Plant Matter (growth, requires sunlight)
+        // The world only counts sky light here, so this stops at night and underground
         self.register(Reaction {
             name: "grow_plant".to_string(),
             input_a: MaterialId::PLANT_MATTER,
@@ -322,7 +322,7 @@ impl ReactionRegistry {
             min_temp: Some(10.0),
             max_temp: Some(40.0),
             requires_contact: true,
-            requires_light: Some(8), // Requires light >= 8 (not checked yet)
+            requires_light: Some(8), // Requires light >= 8
             min_pressure: None,
             catalyst: None,
             output_a: MaterialId::PLANT_MATTER,
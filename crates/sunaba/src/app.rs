@@ -14,15 +14,17 @@ use winit::{
 #[cfg(not(target_arch = "wasm32"))]
 use crate::config::GameConfig;
 use crate::entity::InputState;
+use crate::entity::crafting::CraftOutcome;
 use crate::levels::LevelManager;
 use crate::render::{ParticleSystem, Renderer};
 use crate::simulation::{MaterialId, MaterialTag, MaterialType};
 use crate::ui::UiState;
-use crate::world::World;
+use crate::world::{ContainerUse, ToolUse, World};
 
 #[cfg(all(feature = "multiplayer", not(target_arch = "wasm32")))]
 use crate::multiplayer::client::{
-    DbContextTrait as _, PlayerTableAccessTrait as _, TableTrait as _,
+    DbContextTrait as _, LandClaimTableAccessTrait as _, PlayerTableAccessTrait as _,
+    TableTrait as _, WorldConfigTableAccessTrait as _,
 };
 
 /// Game mode: persistent world or demo level
@@ -40,6 +42,15 @@ const MAX_ZOOM: f32 = 0.01;
 #[cfg(target_arch = "wasm32")]
 const DEBUG_PLACEMENT: bool = true;
 
+/// Particle color for multiplayer edits the server rejected and we rolled back
+#[cfg(feature = "multiplayer")]
+const REJECTED_EDIT_COLOR: [u8; 4] = [255, 60, 60, 255];
+
+/// Server spawn point in whole pixels (matches `sunaba_server::logic::SPAWN_POINT`),
+/// the center of the protected spawn zone
+#[cfg(feature = "multiplayer")]
+const SERVER_SPAWN_PIXEL: (i32, i32) = (0, 100);
+
 /// Convert screen coordinates to world coordinates
 fn screen_to_world(
     screen_x: f64,
@@ -109,6 +120,37 @@ pub struct RemotePlayerRenderData {
     pub identity: String,
 }
 
+/// Whose land a claim border outlines (picks the border color)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClaimBorderKind {
+    /// Claimed by the local player
+    Own,
+    /// Claimed by someone else
+    Other,
+    /// Protected spawn zone (admins only)
+    Spawn,
+}
+
+/// Land claim outline for rendering (inclusive world pixel bounds)
+#[derive(Clone, Debug)]
+pub struct ClaimBorderRenderData {
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+    pub name: String,
+    pub kind: ClaimBorderKind,
+}
+
+/// Singleplayer chunks sent to the server, waiting for `import_chunk` results
+#[cfg(all(not(target_arch = "wasm32"), feature = "multiplayer"))]
+#[derive(Default)]
+struct WorldUpload {
+    sent: usize,
+    imported: usize,
+    errors: Vec<String>,
+}
+
 pub struct App {
     window: Window,
     renderer: Renderer,
@@ -153,6 +195,18 @@ pub struct App {
     #[cfg(feature = "multiplayer")]
     chunk_loading_started_at: Option<Instant>,
 
+    /// Singleplayer world upload waiting for the server's verdicts
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multiplayer"))]
+    world_upload: Option<WorldUpload>,
+
+    /// Dedicated (plain TCP) server we are playing on
+    #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+    dedicated: Option<crate::dedicated::DedicatedSession>,
+
+    /// Active input recording session (F9 toggles)
+    #[cfg(not(target_arch = "wasm32"))]
+    input_recorder: Option<crate::recording::InputRecorder>,
+
     /// Remote control command receiver (TCP server -> game loop)
     #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
     remote_cmd_rx: Option<std::sync::mpsc::Receiver<crate::remote_control::RemoteCommand>>,
@@ -160,6 +214,10 @@ pub struct App {
     /// Remote control response sender (game loop -> TCP server)
     #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
     remote_resp_tx: Option<std::sync::mpsc::Sender<crate::remote_control::RemoteResponse>>,
+
+    /// Remote clients streaming per-tick query results
+    #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
+    remote_subscriptions: crate::remote_control::Subscriptions,
 }
 
 impl App {
@@ -199,6 +257,10 @@ impl App {
                 return false;
             }
         }
+        #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+        if self.dedicated.is_some() {
+            return false;
+        }
 
         self.paused // Pause in singleplayer when menu open
     }
@@ -251,6 +313,8 @@ impl App {
         let renderer = Renderer::new(&window).await?;
         #[allow(unused_mut)] // mut only needed in singleplayer mode
         let mut world = World::new(false); // Spawn creatures in singleplayer (will be gated when connected to multiplayer)
+        #[cfg(not(target_arch = "wasm32"))]
+        world.set_day_night_settings(config.day_night.settings());
 
         // Initialize level manager (but don't load a level yet)
         let level_manager = LevelManager::new();
@@ -347,10 +411,18 @@ impl App {
             last_chunk_wait_log: None,
             #[cfg(feature = "multiplayer")]
             chunk_loading_started_at: None,
+            #[cfg(all(not(target_arch = "wasm32"), feature = "multiplayer"))]
+            world_upload: None,
+            #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+            dedicated: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            input_recorder: None,
             #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
             remote_cmd_rx: None,
             #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
             remote_resp_tx: None,
+            #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
+            remote_subscriptions: crate::remote_control::Subscriptions::new(),
         };
 
         Ok((app, event_loop))
@@ -532,8 +604,256 @@ impl App {
         Ok(())
     }
 
+    /// Join a dedicated server (`host` or `host:port`) and play in its world
+    ///
+    /// The singleplayer world is saved first and comes back when the
+    /// connection is lost.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+    pub fn connect_to_dedicated(&mut self, addr: &str, name: &str) -> anyhow::Result<()> {
+        let session = crate::dedicated::DedicatedSession::connect(addr, name)?;
+
+        self.world.save_all_dirty_chunks();
+        // The server streams its chunks; nothing local is loaded or saved
+        self.world.disable_persistence();
+        self.world.clear_all_chunks();
+        self.world.player.position = session.spawn();
+        self.world.player.velocity = Vec2::ZERO;
+
+        self.dedicated = Some(session);
+        Ok(())
+    }
+
+    /// Exchange this frame's updates with the dedicated server, falling back
+    /// to the singleplayer world if the connection dropped
+    #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+    fn update_dedicated(&mut self) {
+        let Some(session) = self.dedicated.as_mut() else {
+            return;
+        };
+        if let Err(e) = session.update(&mut self.world) {
+            log::error!("Lost connection to {}: {}", session.addr(), e);
+            self.dedicated = None;
+            self.world.load_persistent_world();
+            self.ui_state
+                .show_toast_error("Disconnected from dedicated server");
+        }
+    }
+
+    /// Pixels around an edit, if a SpacetimeDB server needs to hear about it
+    #[cfg(feature = "multiplayer")]
+    fn multiplayer_snapshot(
+        &self,
+        x: i32,
+        y: i32,
+        radius: i32,
+    ) -> Option<crate::edit_snapshot::EditSnapshot> {
+        self.multiplayer_manager
+            .as_ref()
+            .filter(|manager| manager.state.is_connected())
+            .map(|_| crate::edit_snapshot::EditSnapshot::take(&self.world, x, y, radius))
+    }
+
+    /// Pixels around an edit, if a dedicated server needs to hear about it
+    #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+    fn dedicated_snapshot(
+        &self,
+        x: i32,
+        y: i32,
+        radius: i32,
+    ) -> Option<crate::edit_snapshot::EditSnapshot> {
+        self.dedicated
+            .as_ref()
+            .map(|_| crate::edit_snapshot::EditSnapshot::take(&self.world, x, y, radius))
+    }
+
+    /// Send the pixels an edit changed to the dedicated server
+    #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+    fn send_dedicated_edits(&mut self, before: Option<crate::edit_snapshot::EditSnapshot>) {
+        if let (Some(session), Some(before)) = (self.dedicated.as_mut(), before)
+            && let Err(e) = session.send_edits(&self.world, &before)
+        {
+            log::warn!("Failed to send edits to dedicated server: {}", e);
+        }
+    }
+
+    /// Act on the multiplayer panel's download/upload/snapshot buttons
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multiplayer"))]
+    fn handle_world_transfer_requests(&mut self) {
+        let panel = &self.ui_state.multiplayer_panel;
+        let (download, upload) = (panel.download_world_requested, panel.upload_world_requested);
+        let snapshot_label = panel.create_snapshot_requested.clone();
+        let rollback = panel.rollback_requested;
+
+        let Some(manager) = self
+            .multiplayer_manager
+            .as_ref()
+            .filter(|m| m.state.is_connected())
+        else {
+            self.world_upload = None;
+            return;
+        };
+
+        if download {
+            match self.download_server_world() {
+                Ok(count) => self.ui_state.show_toast(&format!(
+                    "Downloaded {} chunks - disconnect to play offline",
+                    count
+                )),
+                Err(e) => self
+                    .ui_state
+                    .show_toast_error(&format!("Download failed: {}", e)),
+            }
+        }
+
+        if upload {
+            // Results of an earlier, abandoned upload don't count
+            manager.client.take_import_results();
+            match self.upload_singleplayer_world() {
+                Ok(0) => self
+                    .ui_state
+                    .show_toast("No saved singleplayer chunks to upload"),
+                Ok(sent) => {
+                    self.world_upload = Some(WorldUpload {
+                        sent,
+                        ..WorldUpload::default()
+                    });
+                    self.ui_state
+                        .show_toast(&format!("Uploading {} chunks...", sent));
+                }
+                Err(e) => self
+                    .ui_state
+                    .show_toast_error(&format!("Upload failed: {}", e)),
+            }
+        }
+
+        // Report the upload once the server has answered every import
+        if let Some(mut world_upload) = self.world_upload.take() {
+            for result in manager.client.take_import_results() {
+                match result {
+                    Ok(()) => world_upload.imported += 1,
+                    Err(e) => {
+                        log::warn!("Chunk upload rejected: {}", e);
+                        world_upload.errors.push(e);
+                    }
+                }
+            }
+
+            if world_upload.imported + world_upload.errors.len() < world_upload.sent {
+                self.world_upload = Some(world_upload);
+            } else if let Some(first) = world_upload.errors.first() {
+                self.ui_state.show_toast_error(&format!(
+                    "Uploaded {} of {} chunks ({} rejected: {})",
+                    world_upload.imported,
+                    world_upload.sent,
+                    world_upload.errors.len(),
+                    first
+                ));
+            } else {
+                self.ui_state
+                    .show_toast(&format!("Uploaded {} chunks", world_upload.imported));
+            }
+        }
+
+        if let Some(label) = snapshot_label {
+            match manager.client.create_snapshot(label) {
+                Ok(()) => self.ui_state.show_toast("Snapshot requested"),
+                Err(e) => self
+                    .ui_state
+                    .show_toast_error(&format!("Snapshot failed: {}", e)),
+            }
+        }
+
+        if let Some(snapshot_id) = rollback {
+            match manager.client.rollback_world(snapshot_id) {
+                Ok(()) => self
+                    .ui_state
+                    .show_toast(&format!("Rolling back to snapshot #{}", snapshot_id)),
+                Err(e) => self
+                    .ui_state
+                    .show_toast_error(&format!("Rollback failed: {}", e)),
+            }
+        }
+    }
+
+    /// Save the server chunks in the local cache as the singleplayer world
+    ///
+    /// The current singleplayer world is archived first; chunks we never
+    /// received regenerate offline from the server's seed.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multiplayer"))]
+    fn download_server_world(&self) -> anyhow::Result<usize> {
+        use crate::world::{ChunkPersistence, WorldMetadata};
+
+        let manager = self
+            .multiplayer_manager
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Multiplayer manager not initialized"))?;
+        let blobs = manager.client.chunk_blobs();
+        if blobs.is_empty() {
+            anyhow::bail!("No server chunks loaded yet");
+        }
+
+        ChunkPersistence::archive_world("default")?;
+        let persistence = ChunkPersistence::new("default")?;
+        let mut saved = 0;
+        for (x, y, blob) in &blobs {
+            match persistence.import_chunk_blob(blob) {
+                Ok(_) => saved += 1,
+                Err(e) => log::warn!("Skipping server chunk ({}, {}): {}", x, y, e),
+            }
+        }
+
+        let (spawn_x, spawn_y) = SERVER_SPAWN_PIXEL;
+        persistence.save_metadata(&WorldMetadata {
+            seed: manager.client.world_seed().unwrap_or_default(),
+            spawn_point: (spawn_x as f32, spawn_y as f32),
+            ..WorldMetadata::default()
+        })?;
+
+        log::info!(
+            "Downloaded {} server chunks to the singleplayer world",
+            saved
+        );
+        Ok(saved)
+    }
+
+    /// Upload every saved singleplayer chunk to the server (admin only)
+    ///
+    /// Takes a server snapshot first so the upload can be rolled back. Returns
+    /// the number of chunks sent; the server's verdicts arrive later through
+    /// `take_import_results`.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multiplayer"))]
+    fn upload_singleplayer_world(&self) -> anyhow::Result<usize> {
+        use crate::world::ChunkPersistence;
+
+        let manager = self
+            .multiplayer_manager
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Multiplayer manager not initialized"))?;
+        let persistence = ChunkPersistence::new("default")?;
+
+        manager
+            .client
+            .create_snapshot("Before singleplayer upload".to_string())?;
+
+        let mut uploaded = 0;
+        for (x, y) in persistence.saved_chunk_positions()? {
+            if let Some(blob) = persistence.export_chunk_blob(x, y)? {
+                manager.client.import_chunk(x, y, blob)?;
+                uploaded += 1;
+            }
+        }
+
+        log::info!("Sent {} singleplayer chunks to the server", uploaded);
+        Ok(uploaded)
+    }
+
     /// Select a hotbar slot and equip/unequip tools
     fn select_hotbar_slot(&mut self, slot: usize) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(recorder) = self.input_recorder.as_mut() {
+            recorder.record_event(crate::recording::RecordedEvent::SelectSlot { slot });
+        }
+
         // Select the inventory slot
         self.world.player.select_slot(slot);
 
@@ -566,10 +886,107 @@ impl App {
                 log::debug!("Unequipped tool, slot {} has material", slot);
             }
         } else {
-            // Empty slot - unequip any equipped tool
+            // Empty slot or container - unequip any equipped tool
             if self.world.player.equipped_tool.is_some() {
                 self.world.player.unequip_tool();
-                log::debug!("Unequipped tool, slot {} is empty", slot);
+                log::debug!("Unequipped tool, slot {} has no tool or material", slot);
+            }
+        }
+
+        // The server owns the equipped tool in multiplayer
+        #[cfg(feature = "multiplayer")]
+        if let Some(manager) = self.multiplayer_manager.as_ref()
+            && manager.state.is_connected()
+            && let Err(e) = manager.client.equip_tool(self.world.player.equipped_tool)
+        {
+            log::warn!("Failed to send equipped tool to server: {}", e);
+        }
+    }
+
+    /// Eat one unit of the food in the selected hotbar slot (E)
+    fn eat_selected_food(&mut self) {
+        let Some(material_id) = self
+            .world
+            .player
+            .inventory
+            .get_slot(self.world.player.selected_slot)
+            .and_then(|s| s.as_ref())
+            .and_then(|stack| stack.material_id())
+        else {
+            return;
+        };
+        let Some(nutrition) = self.world.materials.get(material_id).nutritional_value else {
+            return;
+        };
+
+        // The server owns inventory and hunger in multiplayer; the result
+        // arrives with the mirrored player row
+        #[cfg(feature = "multiplayer")]
+        if let Some(manager) = self.multiplayer_manager.as_ref()
+            && manager.state.is_connected()
+        {
+            if let Err(e) = manager.client.eat(material_id) {
+                log::warn!("Failed to send eat request to server: {}", e);
+            }
+            return;
+        }
+
+        if self.world.player.eat_food(material_id, nutrition) {
+            log::debug!("Ate {}", self.world.materials.get(material_id).name);
+        }
+    }
+
+    /// Start or stop recording player input (F9)
+    ///
+    /// Stopping writes `recordings/session_<timestamp>.replay` and, with the headless
+    /// feature, a matching `.ron` scenario export.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn toggle_input_recording(&mut self) {
+        use crate::recording::InputRecorder;
+
+        let Some(recorder) = self.input_recorder.take() else {
+            let level = match self.game_mode {
+                GameMode::PersistentWorld => None,
+                GameMode::DemoLevel(id) => Some(id),
+            };
+            self.input_recorder = Some(InputRecorder::start(
+                &self.world,
+                level,
+                self.config.debug.brush_size,
+                self.config.debug.debug_placement,
+            ));
+            self.ui_state.show_toast("Recording input (F9 to stop)");
+            log::info!("Input recording started (seed {})", self.world.seed());
+            return;
+        };
+
+        let recording = recorder.finish();
+        let stamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+        let path = format!("recordings/session_{}.replay", stamp);
+
+        match recording.save(&path) {
+            Ok(()) => {
+                log::info!(
+                    "Saved input recording ({} frames) to {}",
+                    recording.frame_count(),
+                    path
+                );
+                self.ui_state
+                    .show_toast(&format!("Recording saved: {}", path));
+            }
+            Err(e) => {
+                log::error!("Failed to save input recording: {}", e);
+                self.ui_state
+                    .show_toast_error(&format!("Failed to save recording: {}", e));
+            }
+        }
+
+        #[cfg(feature = "headless")]
+        {
+            let ron_path = format!("recordings/session_{}.ron", stamp);
+            let scenario = recording.to_scenario(format!("Recorded session {}", stamp));
+            if let Err(e) = scenario.to_file(&ron_path) {
+                log::warn!("Failed to export recording as scenario: {}", e);
             }
         }
     }
@@ -611,10 +1028,32 @@ impl App {
     #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
     fn execute_remote_command(
         &mut self,
-        action: crate::scenario::ScenarioAction,
+        request: crate::remote_control::RemoteRequest,
     ) -> crate::remote_control::RemoteResponse {
+        use crate::remote_control::{RemoteRequest, RemoteResponse};
         use crate::scenario::ScenarioAction;
 
+        let action = match request {
+            RemoteRequest::Action(action) => action,
+            RemoteRequest::Query(query) => {
+                return match crate::remote_control::run_query(&self.world, &query) {
+                    Ok(data) => RemoteResponse {
+                        data: Some(data),
+                        ..RemoteResponse::ok("Query succeeded")
+                    },
+                    Err(message) => RemoteResponse::error(message),
+                };
+            }
+            RemoteRequest::Subscribe(subscription) => {
+                self.remote_subscriptions.add(subscription);
+                log::info!(
+                    "Remote subscription added ({} active)",
+                    self.remote_subscriptions.len()
+                );
+                return RemoteResponse::ok("Subscribed");
+            }
+        };
+
         let result = match &action {
             ScenarioAction::TeleportPlayer { x, y } => {
                 self.world.player.position = glam::Vec2::new(*x, *y);
@@ -660,17 +1099,11 @@ impl App {
         match result {
             Ok(message) => {
                 log::debug!("Remote command executed: {}", message);
-                crate::remote_control::RemoteResponse {
-                    success: true,
-                    message,
-                }
+                RemoteResponse::ok(message)
             }
             Err(message) => {
                 log::warn!("Remote command failed: {}", message);
-                crate::remote_control::RemoteResponse {
-                    success: false,
-                    message,
-                }
+                RemoteResponse::error(message)
             }
         }
     }
@@ -697,6 +1130,8 @@ impl App {
                     Ok(new_config) => {
                         log::info!("Hot-reloaded config.ron");
                         self.config = new_config;
+                        self.world
+                            .set_day_night_settings(self.config.day_night.settings());
                     }
                     Err(e) => {
                         log::error!("Failed to hot-reload config: {}", e);
@@ -758,6 +1193,10 @@ impl App {
         //     // Note: completion is logged by process_incremental_saves
         // }
 
+        // Apply chunks from the dedicated server and report our position
+        #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+        self.update_dedicated();
+
         // Process SpacetimeDB messages (native multiplayer only)
         #[cfg(all(not(target_arch = "wasm32"), feature = "multiplayer"))]
         {
@@ -799,13 +1238,20 @@ impl App {
                     }
                 }
 
+                // Reconcile predicted edits with the server's acknowledgements
+                // (before chunk sync, so rejected edits aren't re-applied)
+                if manager.state.is_connected() {
+                    reconcile_predicted_edits(manager, &mut self.world, &mut self.particle_system);
+                }
+
                 // THEN: Progressive chunk sync with rate limiting (2-3 chunks per frame)
                 // Now the queue exists on the first frame after subscription data arrives
                 if let Some(ref mut queue) = manager.chunk_load_queue {
-                    if let Ok(synced) = manager
-                        .client
-                        .sync_chunks_progressive(&mut self.world, queue)
-                    {
+                    if let Ok(synced) = manager.client.sync_chunks_progressive(
+                        &mut self.world,
+                        queue,
+                        &manager.predictor,
+                    ) {
                         // Silently load chunks (was spammy in logs)
                         let _ = synced;
                     } else {
@@ -899,6 +1345,64 @@ impl App {
                 }
             }
 
+            // Mirror the server-owned inventory, equipped tool, health and hunger
+            if let Some(manager) = self.multiplayer_manager.as_ref()
+                && manager.state.is_connected()
+            {
+                manager.client.sync_local_player(&mut self.world.player);
+            }
+
+            // Follow admin teleports, the server clock and kicks
+            let mut kicked = false;
+            if let Some(manager) = self.multiplayer_manager.as_mut()
+                && manager.state.is_connected()
+            {
+                if let Some((seq, x, y)) = manager.client.teleport_target() {
+                    // The first value seen is just the current state, not a teleport
+                    if manager.last_teleport_seq.is_some_and(|last| last != seq) {
+                        self.world.player.position = Vec2::new(x, y);
+                        self.world.player.velocity = Vec2::ZERO;
+                    }
+                    manager.last_teleport_seq = Some(seq);
+                }
+
+                if let Some(server_time) = manager.client.time_of_day() {
+                    let cycle = sunaba_core::world::DAY_NIGHT_CYCLE_DURATION;
+                    let drift = (server_time - self.world.time_of_day()).rem_euclid(cycle);
+                    if drift.min(cycle - drift) > 5.0 {
+                        self.world.set_time_of_day(server_time);
+                    }
+                }
+
+                match manager.client.is_player_online() {
+                    Some(true) => manager.seen_online = true,
+                    Some(false) if manager.seen_online => kicked = true,
+                    _ => {}
+                }
+            }
+            if kicked {
+                log::warn!("Kicked from server");
+                if let Err(e) = pollster::block_on(self.disconnect_from_server()) {
+                    log::error!("Failed to disconnect after kick: {}", e);
+                }
+                self.ui_state.show_toast_error("Kicked from server");
+            }
+
+            // Follow server creatures near us (morphology is fetched once each)
+            if let Some(manager) = self.multiplayer_manager.as_mut()
+                && manager.state.is_connected()
+            {
+                let now = manager.creatures.now();
+                manager
+                    .creatures
+                    .update(manager.client.creature_poses(), now);
+                for creature_id in manager.creatures.missing_radii() {
+                    if let Some(radii) = manager.client.creature_part_radii(creature_id) {
+                        manager.creatures.set_radii(creature_id, radii);
+                    }
+                }
+            }
+
             // Check for re-subscription every 60 frames (~1 second at 60fps)
             if let Some(manager) = self.multiplayer_manager.as_mut() {
                 use std::sync::atomic::{AtomicU32, Ordering};
@@ -959,10 +1463,10 @@ impl App {
             }
         }
 
-        // Update metrics collector (WASM multiplayer only)
+        // Update metrics collector and mirror our player row (WASM multiplayer only)
         #[cfg(all(target_arch = "wasm32", feature = "multiplayer"))]
         {
-            if let Some(manager) = self.multiplayer_manager.as_ref() {
+            if let Some(manager) = self.multiplayer_manager.as_mut() {
                 if let Some(ref mut collector) = self.ui_state.metrics_collector {
                     // Record this update
                     collector.record_update();
@@ -977,6 +1481,13 @@ impl App {
                         collector.update_server_metrics(&server_metrics);
                     }
                 }
+
+                // Mirror the server-owned inventory, equipped tool, health and hunger,
+                // then settle predicted edits against the same row
+                if manager.state.is_connected() {
+                    manager.client.sync_local_player(&mut self.world.player);
+                    reconcile_predicted_edits(manager, &mut self.world, &mut self.particle_system);
+                }
             }
         }
 
@@ -999,13 +1510,22 @@ impl App {
             #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
             if let Some(cmd_rx) = &self.remote_cmd_rx {
                 if let Ok(cmd) = cmd_rx.try_recv() {
-                    let response = self.execute_remote_command(cmd.action);
+                    let response = self.execute_remote_command(cmd.request);
                     if let Some(resp_tx) = &self.remote_resp_tx {
                         let _ = resp_tx.send(response);
                     }
                 }
             }
 
+            // Record this tick's input before it is applied
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(recorder) = self.input_recorder.as_mut() {
+                let pointer_blocked = self.egui_ctx.wants_pointer_input()
+                    || self.ui_state.inventory_open
+                    || self.ui_state.crafting_open;
+                recorder.record_frame(&self.input_state, pointer_blocked);
+            }
+
             // Normal game loop - update player from input
             self.world.update_player(&self.input_state, 1.0 / 60.0);
 
@@ -1099,7 +1619,12 @@ impl App {
                 );
             }
 
-            // Update visual particles
+            // Update visual particles (blown by the wind around the player)
+            let player_pos = self.world.player.position;
+            let wind = self
+                .world
+                .get_wind_at(player_pos.x as i32, player_pos.y as i32);
+            self.particle_system.apply_wind(wind, 1.0 / 60.0);
             self.particle_system.update(1.0 / 60.0);
 
             // Update camera zoom
@@ -1155,16 +1680,27 @@ impl App {
                     )
                 };
 
+                #[cfg(feature = "multiplayer")]
+                let multiplayer_before = self.multiplayer_snapshot(center_x, center_y, 16);
+                #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+                let dedicated_before = self.dedicated_snapshot(center_x, center_y, 16);
                 self.world.debug_mine_circle(center_x, center_y, 16);
+                #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+                self.send_dedicated_edits(dedicated_before);
 
-                // Send mining action to server (multiplayer only)
+                // Send every mined pixel to the server as a prediction (multiplayer only)
                 #[cfg(feature = "multiplayer")]
+                if let (Some(manager), Some(before)) =
+                    (self.multiplayer_manager.as_mut(), multiplayer_before)
                 {
-                    if let Some(manager) = self.multiplayer_manager.as_ref() {
-                        if manager.state.is_connected() {
-                            if let Err(e) = manager.client.mine(center_x, center_y) {
-                                log::warn!("Failed to send mining action to server: {}", e);
-                            }
+                    for (x, y, previous, current) in before.changes(&self.world) {
+                        if current != MaterialId::AIR {
+                            continue;
+                        }
+                        let seq = manager.predictor.record(x, y, previous, current);
+                        if let Err(e) = manager.client.mine(x, y, seq) {
+                            log::warn!("Failed to send mining action to server: {}", e);
+                            break;
                         }
                     }
                 }
@@ -1195,9 +1731,91 @@ impl App {
                 self.renderer.add_camera_shake(0.5, 0.05);
             }
 
-            // Placing material from inventory with left mouse button
+            // Using the equipped tool with left mouse button (mine, fell, dig, strike)
+            // Skip if mouse is over UI or overlays are open
+            if self.input_state.left_mouse_pressed
+                && self.world.player.equipped_tool.is_some()
+                && !self.egui_ctx.wants_pointer_input()
+                && !self.ui_state.inventory_open
+                && !self.ui_state.crafting_open
+                && let Some((wx, wy)) = self.input_state.mouse_world_pos
+            {
+                let pos = Vec2::new(wx as f32, wy as f32);
+                #[cfg(feature = "multiplayer")]
+                let mining_target = self.world.player.mining_progress.target_pixel;
+                #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+                let dedicated_before = self.dedicated_snapshot(wx, wy, 1);
+                let tool_use = self.world.use_equipped_tool(wx, wy, 1.0 / 60.0);
+                #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+                self.send_dedicated_edits(dedicated_before);
+
+                // Mirror a newly started mining target on the server, which
+                // completes it into the server-side inventory
+                #[cfg(feature = "multiplayer")]
+                if let Some(manager) = self.multiplayer_manager.as_ref()
+                    && manager.state.is_connected()
+                    && let Some((tx, ty)) = self.world.player.mining_progress.target_pixel
+                    && mining_target != Some((tx, ty))
+                    && let Err(e) = manager.client.start_mining(tx, ty)
+                {
+                    log::warn!("Failed to send mining start to server: {}", e);
+                }
+
+                match tool_use {
+                    ToolUse::Mined(material_id) => {
+                        let color = self.world.materials.get(material_id).color;
+                        self.particle_system.spawn_dust_cloud(pos, color);
+                    }
+                    ToolUse::Felled { collected, debris } => {
+                        let color = self.world.materials.get(MaterialId::WOOD).color;
+                        self.particle_system.spawn_wood_chips(pos, color);
+                        self.renderer.add_camera_shake(1.5, 0.15);
+                        if debris > 0 {
+                            self.ui_state.show_toast(&format!(
+                                "Felled {} pixels ({} dropped - inventory full)",
+                                collected + debris,
+                                debris
+                            ));
+                        }
+                    }
+                    ToolUse::Dug(_) => {
+                        let color = self.world.materials.get(MaterialId::DIRT).color;
+                        self.particle_system.spawn_dust_cloud(pos, color);
+                    }
+                    ToolUse::Struck(hits) if hits > 0 => {
+                        self.particle_system.spawn_sparks(pos);
+                        self.renderer.add_camera_shake(1.0, 0.1);
+                    }
+                    _ => {}
+                }
+            }
+
+            // Scooping or pouring with a bucket/flask in the selected slot
             // Skip if mouse is over UI or overlays are open
             if self.input_state.left_mouse_pressed
+                && self.world.player.equipped_tool.is_none()
+                && self.world.player.get_selected_container().is_some()
+                && !self.egui_ctx.wants_pointer_input()
+                && !self.ui_state.inventory_open
+                && !self.ui_state.crafting_open
+                && let Some((wx, wy)) = self.input_state.mouse_world_pos
+            {
+                match self.world.use_selected_container(wx, wy) {
+                    ContainerUse::Scooped { material_id, .. }
+                    | ContainerUse::Poured { material_id, .. } => {
+                        let color = self.world.materials.get(material_id).color;
+                        self.particle_system
+                            .spawn_liquid_splash(Vec2::new(wx as f32, wy as f32), color);
+                    }
+                    ContainerUse::None => {}
+                }
+            }
+
+            // Placing material from inventory with left mouse button
+            // Skip if a tool or container is selected, mouse is over UI or overlays are open
+            if self.input_state.left_mouse_pressed
+                && self.world.player.equipped_tool.is_none()
+                && self.world.player.get_selected_container().is_none()
                 && !self.egui_ctx.wants_pointer_input()
                 && !self.ui_state.inventory_open
                 && !self.ui_state.crafting_open
@@ -1213,22 +1831,42 @@ impl App {
                 #[cfg(target_arch = "wasm32")]
                 let brush_size = 1; // Default brush size for WASM builds
 
-                if self.debug_placement() {
+                #[cfg(feature = "multiplayer")]
+                let multiplayer_before = self.multiplayer_snapshot(wx, wy, brush_size as i32);
+                let debug_placement = self.debug_placement();
+                #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+                let dedicated_before = self.dedicated_snapshot(wx, wy, brush_size as i32);
+                if debug_placement {
                     self.world
                         .place_material_debug(wx, wy, material_id, brush_size);
                 } else {
                     self.world
                         .place_material_from_inventory(wx, wy, material_id, brush_size);
                 }
+                #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+                self.send_dedicated_edits(dedicated_before);
 
-                // Send material placement to server (multiplayer only)
+                // Send every placed pixel of the brush to the server, each tracked
+                // as a prediction until acknowledged (multiplayer only)
                 #[cfg(feature = "multiplayer")]
+                if let (Some(manager), Some(before)) =
+                    (self.multiplayer_manager.as_mut(), multiplayer_before)
                 {
-                    if let Some(manager) = self.multiplayer_manager.as_ref() {
-                        if manager.state.is_connected() {
-                            if let Err(e) = manager.client.place_material(wx, wy, material_id) {
-                                log::warn!("Failed to send material placement to server: {}", e);
-                            }
+                    for (x, y, previous, current) in before.changes(&self.world) {
+                        if current != material_id {
+                            continue;
+                        }
+                        let seq = manager.predictor.record(x, y, previous, current);
+                        // Debug placement is free, otherwise it comes out of the
+                        // server-side inventory
+                        let result = if debug_placement {
+                            manager.client.place_material(x, y, material_id, seq)
+                        } else {
+                            manager.client.place_from_inventory(x, y, material_id, seq)
+                        };
+                        if let Err(e) = result {
+                            log::warn!("Failed to send material placement to server: {}", e);
+                            break;
                         }
                     }
                 }
@@ -1285,6 +1923,24 @@ impl App {
                 #[cfg(not(feature = "multiplayer"))]
                 let is_multiplayer_connected = false;
 
+                // Use the recording's seeded RNG so replays see the same random stream
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(recorder) = self.input_recorder.as_mut() {
+                    self.world.update(
+                        1.0 / 60.0,
+                        &mut self.ui_state.stats,
+                        recorder.rng(),
+                        is_multiplayer_connected,
+                    );
+                } else {
+                    self.world.update(
+                        1.0 / 60.0,
+                        &mut self.ui_state.stats,
+                        &mut rand::thread_rng(),
+                        is_multiplayer_connected,
+                    );
+                }
+                #[cfg(target_arch = "wasm32")]
                 self.world.update(
                     1.0 / 60.0,
                     &mut self.ui_state.stats,
@@ -1292,6 +1948,12 @@ impl App {
                     is_multiplayer_connected,
                 );
                 self.ui_state.stats.end_sim();
+
+                // Stream this tick's state to remote subscribers
+                #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
+                if !self.remote_subscriptions.is_empty() {
+                    self.remote_subscriptions.publish(&self.world);
+                }
             }
         } // End of game loop - skip when loading chunks
 
@@ -1329,7 +1991,32 @@ impl App {
             None
         };
 
+        // Refresh the chat log and send whatever the panel queued last frame
+        #[cfg(feature = "multiplayer")]
+        if let Some(manager) = self.multiplayer_manager.as_ref()
+            && manager.state.is_connected()
+        {
+            self.ui_state.chat_panel.messages = manager.client.chat_messages();
+            if let Some(text) = self.ui_state.chat_panel.send_requested.take()
+                && let Err(e) = manager.client.send_chat(text)
+            {
+                self.ui_state
+                    .show_toast_error(&format!("Chat failed: {}", e));
+            }
+        }
+
         // Collect multiplayer data before egui closure to avoid borrow checker issues
+        #[cfg(feature = "multiplayer")]
+        let claim_borders = self.collect_claim_borders();
+
+        #[cfg(feature = "multiplayer")]
+        let server_creatures = self
+            .multiplayer_manager
+            .as_ref()
+            .filter(|manager| manager.state.is_connected())
+            .map(|manager| manager.creatures.sample(manager.creatures.now()))
+            .unwrap_or_default();
+
         #[cfg(feature = "multiplayer")]
         let multiplayer_overlay_data = {
             let remote_players = self.collect_remote_players();
@@ -1362,6 +2049,7 @@ impl App {
                 ),
             };
             let in_persistent_world = matches!(self.game_mode, GameMode::PersistentWorld);
+            let workstations = self.world.workstations_in_reach();
 
             #[cfg(not(target_arch = "wasm32"))]
             let menu_action = self.ui_state.render(
@@ -1375,6 +2063,7 @@ impl App {
                 &self.world.player,
                 self.world.tool_registry(),
                 &self.world.recipe_registry,
+                &workstations,
                 &mut self.config,
                 self.world.is_player_dead(),
                 #[cfg(feature = "multiplayer")]
@@ -1393,6 +2082,7 @@ impl App {
                 &self.world.player,
                 self.world.tool_registry(),
                 &self.world.recipe_registry,
+                &workstations,
                 self.world.is_player_dead(),
                 #[cfg(feature = "multiplayer")]
                 self.multiplayer_manager.as_ref(),
@@ -1428,7 +2118,8 @@ impl App {
                 );
             }
 
-            // Draw player nicknames overlay (multiplayer only)
+            // Draw server creatures, land claim borders and player nicknames
+            // (multiplayer only)
             #[cfg(feature = "multiplayer")]
             {
                 let (
@@ -1439,6 +2130,20 @@ impl App {
                     camera_zoom,
                     player_pos,
                 ) = &multiplayer_overlay_data;
+                draw_server_creatures_overlay(
+                    ctx,
+                    *window_size,
+                    *camera_pos,
+                    *camera_zoom,
+                    &server_creatures,
+                );
+                draw_claim_borders_overlay(
+                    ctx,
+                    *window_size,
+                    *camera_pos,
+                    *camera_zoom,
+                    &claim_borders,
+                );
                 draw_player_nicknames_overlay(
                     ctx,
                     *window_size,
@@ -1515,52 +2220,83 @@ impl App {
                 .find(|r| r.name == recipe_name)
                 .cloned()
         {
-            if let Some(output) = self
-                .world
-                .recipe_registry
-                .try_craft(&recipe, &mut self.world.player.inventory)
-            {
-                log::info!("[CRAFTING] Crafted: {}", recipe_name);
-                // Add the crafted output to inventory
-                match &output {
-                    crate::entity::crafting::RecipeOutput::Material { id, count } => {
-                        let remainder = self.world.player.inventory.add_item(*id, *count);
-                        let mat_name = &self.world.materials.get(*id).name;
-                        if remainder == 0 {
-                            self.ui_state
-                                .show_toast(&format!("Crafted {} x{}", mat_name, count));
-                        } else {
-                            self.ui_state.show_toast(&format!(
-                                "Crafted {} x{} ({} dropped - inventory full)",
-                                mat_name, count, remainder
-                            ));
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(recorder) = self.input_recorder.as_mut() {
+                recorder.record_event(crate::recording::RecordedEvent::Craft {
+                    recipe: recipe_name.clone(),
+                });
+            }
+
+            if !self.craft_on_server(&recipe) {
+                match self.world.craft_recipe(&recipe) {
+                    CraftOutcome::Crafted(output) => {
+                        log::info!("[CRAFTING] Crafted: {}", recipe_name);
+                        // Add the crafted output to inventory
+                        match &output {
+                            crate::entity::crafting::RecipeOutput::Material { id, count } => {
+                                let remainder = self.world.player.inventory.add_item(*id, *count);
+                                let mat_name = &self.world.materials.get(*id).name;
+                                if remainder == 0 {
+                                    self.ui_state
+                                        .show_toast(&format!("Crafted {} x{}", mat_name, count));
+                                } else {
+                                    self.ui_state.show_toast(&format!(
+                                        "Crafted {} x{} ({} dropped - inventory full)",
+                                        mat_name, count, remainder
+                                    ));
+                                }
+                            }
+                            crate::entity::crafting::RecipeOutput::Tool {
+                                tool_id,
+                                durability,
+                            } => {
+                                let success =
+                                    self.world.player.inventory.add_tool(*tool_id, *durability);
+                                let tool_name = self.world.tool_registry().name(*tool_id);
+                                if success {
+                                    self.ui_state.show_toast(&format!("Crafted {}", tool_name));
+                                } else {
+                                    self.ui_state.show_toast_error(&format!(
+                                        "Crafted {} but inventory full!",
+                                        tool_name
+                                    ));
+                                }
+                            }
+                            crate::entity::crafting::RecipeOutput::Container { container } => {
+                                if self.world.player.inventory.add_container(*container) {
+                                    self.ui_state
+                                        .show_toast(&format!("Crafted {}", container.name()));
+                                } else {
+                                    self.ui_state.show_toast_error(&format!(
+                                        "Crafted {} but inventory full!",
+                                        container.name()
+                                    ));
+                                }
+                            }
                         }
                     }
-                    crate::entity::crafting::RecipeOutput::Tool {
-                        tool_id,
-                        durability,
-                    } => {
-                        let success = self.world.player.inventory.add_tool(*tool_id, *durability);
-                        let tool_name = match *tool_id {
-                            1000 => "Wood Pickaxe",
-                            1001 => "Stone Pickaxe",
-                            1002 => "Iron Pickaxe",
-                            _ => "Tool",
-                        };
-                        if success {
-                            self.ui_state.show_toast(&format!("Crafted {}", tool_name));
-                        } else {
-                            self.ui_state.show_toast_error(&format!(
-                                "Crafted {} but inventory full!",
-                                tool_name
-                            ));
-                        }
+                    CraftOutcome::Smelting { seconds } => {
+                        log::info!("[CRAFTING] Smelting: {}", recipe_name);
+                        self.ui_state
+                            .show_toast(&format!("Smelting {} ({:.0}s)", recipe_name, seconds));
+                    }
+                    CraftOutcome::MissingWorkstation(workstation) => {
+                        self.ui_state.show_toast_error(&format!(
+                            "{} needs a {} nearby",
+                            recipe_name,
+                            workstation.name()
+                        ));
+                    }
+                    CraftOutcome::MissingFuel => {
+                        self.ui_state
+                            .show_toast_error("The furnace needs fuel (wood, coal, oil...)");
+                    }
+                    CraftOutcome::MissingMaterials => {
+                        log::warn!("[CRAFTING] Failed to craft: {}", recipe_name);
+                        self.ui_state
+                            .show_toast_error(&format!("Failed to craft {}", recipe_name));
                     }
                 }
-            } else {
-                log::warn!("[CRAFTING] Failed to craft: {}", recipe_name);
-                self.ui_state
-                    .show_toast_error(&format!("Failed to craft {}", recipe_name));
             }
         }
 
@@ -1821,6 +2557,10 @@ impl App {
                     }
                 }
 
+                // Handle world transfer and snapshot requests (native only)
+                #[cfg(all(not(target_arch = "wasm32"), feature = "multiplayer"))]
+                self.handle_world_transfer_requests();
+
                 // Reset action flags after processing
                 self.ui_state.multiplayer_panel.reset_flags();
             }
@@ -2011,6 +2751,99 @@ impl App {
         Vec::new()
     }
 
+    /// Collect land claim and spawn zone borders from the subscription (native only)
+    #[cfg(all(feature = "multiplayer", not(target_arch = "wasm32")))]
+    fn collect_claim_borders(&self) -> Vec<ClaimBorderRenderData> {
+        let Some(ref manager) = self.multiplayer_manager else {
+            return Vec::new();
+        };
+
+        let Some(ref conn_arc) = manager.client.get_connection() else {
+            return Vec::new();
+        };
+
+        let Ok(conn) = conn_arc.lock() else {
+            return Vec::new();
+        };
+
+        let local_identity = conn.try_identity();
+        let mut borders: Vec<_> = conn
+            .db
+            .land_claim()
+            .iter()
+            .map(|claim| ClaimBorderRenderData {
+                min_x: claim.min_x,
+                min_y: claim.min_y,
+                max_x: claim.max_x,
+                max_y: claim.max_y,
+                kind: if Some(claim.owner) == local_identity {
+                    ClaimBorderKind::Own
+                } else {
+                    ClaimBorderKind::Other
+                },
+                name: claim.name,
+            })
+            .collect();
+
+        if let Some(config) = conn.db.world_config().id().find(&0)
+            && config.spawn_protection_radius > 0
+        {
+            let radius = config.spawn_protection_radius;
+            let (x, y) = SERVER_SPAWN_PIXEL;
+            borders.push(ClaimBorderRenderData {
+                min_x: x - radius,
+                min_y: y - radius,
+                max_x: x + radius,
+                max_y: y + radius,
+                name: "Spawn".to_string(),
+                kind: ClaimBorderKind::Spawn,
+            });
+        }
+
+        borders
+    }
+
+    /// Collect land claim and spawn zone borders from the JS SDK cache (WASM)
+    #[cfg(all(feature = "multiplayer", target_arch = "wasm32"))]
+    fn collect_claim_borders(&self) -> Vec<ClaimBorderRenderData> {
+        let Some(ref manager) = self.multiplayer_manager else {
+            return Vec::new();
+        };
+
+        let mut borders: Vec<_> = manager
+            .client
+            .land_claims()
+            .into_iter()
+            .map(|claim| ClaimBorderRenderData {
+                min_x: claim.min_x,
+                min_y: claim.min_y,
+                max_x: claim.max_x,
+                max_y: claim.max_y,
+                kind: if claim.own {
+                    ClaimBorderKind::Own
+                } else {
+                    ClaimBorderKind::Other
+                },
+                name: claim.name,
+            })
+            .collect();
+
+        let radius = manager.client.spawn_protection_radius();
+        if radius > 0 {
+            let (x, y) = SERVER_SPAWN_PIXEL;
+            borders.push(ClaimBorderRenderData {
+                min_x: x - radius,
+                min_y: y - radius,
+                max_x: x + radius,
+                max_y: y + radius,
+                name: "Spawn".to_string(),
+                kind: ClaimBorderKind::Spawn,
+            });
+        }
+
+        borders
+    }
+
     /// Get local player name from multiplayer connection (native only)
     #[cfg(all(feature = "multiplayer", not(target_arch = "wasm32")))]
     fn get_local_player_name(&self) -> Option<String> {
@@ -2035,6 +2868,38 @@ impl App {
             .and_then(|p| p.name.clone())
     }
 
+    /// Craft on the server, which owns the inventory while connected
+    ///
+    /// Returns false if not connected and the recipe should be crafted locally.
+    /// The output arrives with the mirrored player row.
+    #[cfg(feature = "multiplayer")]
+    fn craft_on_server(&mut self, recipe: &crate::entity::crafting::Recipe) -> bool {
+        let Some(ref manager) = self.multiplayer_manager else {
+            return false;
+        };
+        if !manager.state.is_connected() {
+            return false;
+        }
+
+        match manager.client.craft(recipe.id) {
+            Ok(()) => self
+                .ui_state
+                .show_toast(&format!("Crafting {}", recipe.name)),
+            Err(e) => {
+                log::warn!("Failed to send craft request to server: {}", e);
+                self.ui_state
+                    .show_toast_error(&format!("Failed to craft {}", recipe.name));
+            }
+        }
+        true
+    }
+
+    /// Crafting is always local without the multiplayer client
+    #[cfg(not(feature = "multiplayer"))]
+    fn craft_on_server(&mut self, _recipe: &crate::entity::crafting::Recipe) -> bool {
+        false
+    }
+
     /// WASM stub for local player name (JS SDK handles differently)
     #[cfg(all(feature = "multiplayer", target_arch = "wasm32"))]
     fn get_local_player_name(&self) -> Option<String> {
@@ -2043,6 +2908,38 @@ impl App {
     }
 }
 
+/// Reconcile predicted edits with the server's acknowledgements, rolling back
+/// rejected ones and telling the server they've been handled
+#[cfg(feature = "multiplayer")]
+fn reconcile_predicted_edits(
+    manager: &mut crate::multiplayer::MultiplayerManager,
+    world: &mut World,
+    particle_system: &mut ParticleSystem,
+) {
+    let Some((last_seq, rejected)) = manager.client.edit_acknowledgements() else {
+        return;
+    };
+
+    for edit in manager.predictor.acknowledge(world, last_seq, &rejected) {
+        // Show the rollback: red burst and flash where the edit was undone
+        log::debug!(
+            "Edit {} at ({}, {}) rejected by server",
+            edit.seq,
+            edit.x,
+            edit.y
+        );
+        particle_system
+            .spawn_impact_burst(Vec2::new(edit.x as f32, edit.y as f32), REJECTED_EDIT_COLOR);
+        world.add_light_flash(edit.x, edit.y, 6, 0.15);
+    }
+
+    if let Some(up_to) = manager.predictor.confirm_rejections(&rejected)
+        && let Err(e) = manager.client.confirm_rejected_edits(up_to)
+    {
+        log::warn!("Failed to confirm rejected edits: {}", e);
+    }
+}
+
 /// Draw active chunks overlay using egui painter (free function to avoid borrow issues)
 fn draw_active_chunks_overlay(
     ctx: &egui::Context,
@@ -2158,6 +3055,105 @@ fn draw_active_chunks_overlay(
     }
 }
 
+/// Draw server-simulated creatures as body part circles (tinted by health)
+#[cfg(feature = "multiplayer")]
+fn draw_server_creatures_overlay(
+    ctx: &egui::Context,
+    window_size: winit::dpi::PhysicalSize<u32>,
+    camera_pos: Vec2,
+    camera_zoom: f32,
+    creatures: &[crate::multiplayer::CreatureRenderData],
+) {
+    let painter = ctx.layer_painter(egui::LayerId::background());
+    let to_screen = |x: f32, y: f32| {
+        world_to_screen(
+            x,
+            y,
+            window_size.width,
+            window_size.height,
+            camera_pos,
+            camera_zoom,
+        )
+    };
+
+    for creature in creatures {
+        let health = creature.health_fraction;
+        let fill = egui::Color32::from_rgba_unmultiplied(
+            (230.0 - 130.0 * health) as u8,
+            (90.0 + 130.0 * health) as u8,
+            90,
+            220,
+        );
+        let outline = egui::Stroke::new(1.0, egui::Color32::from_black_alpha(180));
+
+        for &(pos, radius) in &creature.parts {
+            let (cx, cy) = to_screen(pos.x, pos.y);
+            let (edge_x, _) = to_screen(pos.x + radius, pos.y);
+            let screen_radius = (edge_x - cx).abs().max(1.0);
+            painter.circle(egui::pos2(cx, cy), screen_radius, fill, outline);
+        }
+    }
+}
+
+/// Draw land claim borders overlay using egui painter
+#[cfg(feature = "multiplayer")]
+fn draw_claim_borders_overlay(
+    ctx: &egui::Context,
+    window_size: winit::dpi::PhysicalSize<u32>,
+    camera_pos: Vec2,
+    camera_zoom: f32,
+    claims: &[ClaimBorderRenderData],
+) {
+    // Draw on the background layer (behind UI windows)
+    let painter = ctx.layer_painter(egui::LayerId::background());
+    let font = egui::FontId::proportional(12.0);
+
+    for claim in claims {
+        let color = match claim.kind {
+            ClaimBorderKind::Own => egui::Color32::from_rgba_unmultiplied(80, 220, 80, 200),
+            ClaimBorderKind::Other => egui::Color32::from_rgba_unmultiplied(255, 150, 40, 200),
+            ClaimBorderKind::Spawn => egui::Color32::from_rgba_unmultiplied(80, 160, 255, 200),
+        };
+
+        // Bounds are inclusive pixels, so the outline ends one pixel past max
+        // (Y is flipped in screen space)
+        let min_screen = world_to_screen(
+            claim.min_x as f32,
+            (claim.max_y + 1) as f32,
+            window_size.width,
+            window_size.height,
+            camera_pos,
+            camera_zoom,
+        );
+        let max_screen = world_to_screen(
+            (claim.max_x + 1) as f32,
+            claim.min_y as f32,
+            window_size.width,
+            window_size.height,
+            camera_pos,
+            camera_zoom,
+        );
+
+        let rect = egui::Rect::from_min_max(
+            egui::pos2(min_screen.0, min_screen.1),
+            egui::pos2(max_screen.0, max_screen.1),
+        );
+        painter.rect_stroke(
+            rect,
+            0.0,
+            egui::Stroke::new(2.0, color),
+            egui::StrokeKind::Inside,
+        );
+        painter.text(
+            rect.left_top() + egui::vec2(4.0, 2.0),
+            egui::Align2::LEFT_TOP,
+            &claim.name,
+            font.clone(),
+            color,
+        );
+    }
+}
+
 /// Draw player nicknames overlay using egui painter
 #[cfg(feature = "multiplayer")]
 fn draw_player_nicknames_overlay(
@@ -2500,6 +3496,33 @@ impl ApplicationHandler for App {
                                 self.ui_state.toggle_debug_panels();
                             }
                         }
+                        // Enter / slash - open the chat input while connected
+                        #[cfg(feature = "multiplayer")]
+                        KeyCode::Enter | KeyCode::Slash => {
+                            let connected = self
+                                .multiplayer_manager
+                                .as_ref()
+                                .is_some_and(|m| m.state.is_connected());
+                            if pressed && connected {
+                                let prefix = if code == KeyCode::Slash { "/" } else { "" };
+                                self.ui_state.chat_panel.open_input(prefix);
+                            }
+                        }
+                        KeyCode::KeyE => {
+                            if pressed {
+                                self.eat_selected_food();
+                            }
+                        }
+                        KeyCode::KeyR => {
+                            if pressed {
+                                self.world.player.rotate_placement();
+                                log::info!(
+                                    "Placement direction: {}",
+                                    ["up", "right", "down", "left"]
+                                        [self.world.player.placement_direction as usize]
+                                );
+                            }
+                        }
                         KeyCode::KeyG => {
                             if pressed {
                                 // Check if connected to multiplayer - don't spawn creatures locally
@@ -2547,6 +3570,14 @@ impl ApplicationHandler for App {
                             }
                         }
 
+                        // Input recording (F9)
+                        #[cfg(not(target_arch = "wasm32"))]
+                        KeyCode::F9 => {
+                            if pressed {
+                                self.toggle_input_recording();
+                            }
+                        }
+
                         // Zoom controls
                         KeyCode::Equal | KeyCode::NumpadAdd => {
                             if pressed {
@@ -5,6 +5,7 @@
 //!
 //! Example environment variable: `SUNABA_CAMERA__ZOOM_SPEED=1.5`
 
+use crate::world::DayNightSettings;
 use anyhow::{Context, Result};
 #[cfg(not(target_arch = "wasm32"))]
 use config::{Config, Environment, File};
@@ -22,6 +23,9 @@ pub struct GameConfig {
     #[serde(default)]
     pub world: WorldConfig,
 
+    #[serde(default)]
+    pub day_night: DayNightConfig,
+
     #[serde(default)]
     pub ui: UiConfig,
 
@@ -102,6 +106,63 @@ impl Default for WorldConfig {
     }
 }
 
+/// Day/night cycle effects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DayNightConfig {
+    /// Surface temperature swing in °C (warmer at noon, colder at midnight)
+    pub surface_temperature_swing: f32,
+    /// Glowing mushrooms spread at night
+    pub mushroom_spread: bool,
+    /// Chance per visit that a glowing mushroom spreads (0.0-1.0)
+    pub mushroom_spread_chance: f32,
+    /// Hostile creatures spawn near the player at night
+    pub hostile_spawns: bool,
+    /// Seconds between hostile spawns
+    pub hostile_spawn_interval: f32,
+    /// Maximum hostile creatures alive at once
+    pub max_hostile_creatures: usize,
+    /// Creatures tire at night and rest
+    pub creature_rest: bool,
+    /// Light-dependent reactions (plant growth) only count sunlight
+    pub sunlight_reactions: bool,
+}
+
+impl Default for DayNightConfig {
+    fn default() -> Self {
+        Self::from_settings(&DayNightSettings::default())
+    }
+}
+
+impl DayNightConfig {
+    fn from_settings(settings: &DayNightSettings) -> Self {
+        Self {
+            surface_temperature_swing: settings.surface_temperature_swing,
+            mushroom_spread: settings.mushroom_spread,
+            mushroom_spread_chance: settings.mushroom_spread_chance,
+            hostile_spawns: settings.hostile_spawns,
+            hostile_spawn_interval: settings.hostile_spawn_interval,
+            max_hostile_creatures: settings.max_hostile_creatures,
+            creature_rest: settings.creature_rest,
+            sunlight_reactions: settings.sunlight_reactions,
+        }
+    }
+
+    /// Settings to hand to the world
+    pub fn settings(&self) -> DayNightSettings {
+        DayNightSettings {
+            surface_temperature_swing: self.surface_temperature_swing,
+            mushroom_spread: self.mushroom_spread,
+            mushroom_spread_chance: self.mushroom_spread_chance.clamp(0.0, 1.0),
+            hostile_spawns: self.hostile_spawns,
+            hostile_spawn_interval: self.hostile_spawn_interval.max(1.0),
+            max_hostile_creatures: self.max_hostile_creatures,
+            creature_rest: self.creature_rest,
+            sunlight_reactions: self.sunlight_reactions,
+        }
+    }
+}
+
 /// UI and window settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UiConfig {
@@ -363,6 +424,7 @@ mod tests {
         assert_eq!(config.world.autosave_interval_secs, 180); // 3 minutes
         assert_eq!(config.ui.window_width, 1280);
         assert!(config.debug.debug_placement);
+        assert_eq!(config.day_night.settings(), DayNightSettings::default());
     }
 
     #[test]
@@ -0,0 +1,85 @@
+//! Playing on a `sunaba-dedicated` server
+//!
+//! The local world keeps simulating for smooth movement, like the SpacetimeDB
+//! client does. Chunks and changed regions streamed by the server overwrite
+//! the local copies, so the server's state wins, and local edits are mirrored to it pixel by pixel for
+//! it to accept or undo.
+
+use crate::edit_snapshot::EditSnapshot;
+use crate::simulation::MaterialId;
+use crate::world::World;
+use anyhow::Result;
+use glam::Vec2;
+use sunaba_dedicated::protocol::{DEFAULT_PORT, ServerMessage};
+use sunaba_dedicated::{DedicatedClient, apply_to_world};
+
+/// Joined dedicated server
+pub struct DedicatedSession {
+    client: DedicatedClient,
+    addr: String,
+}
+
+impl DedicatedSession {
+    /// Join the server at `addr` (`host` or `host:port`) under `name`
+    pub fn connect(addr: &str, name: &str) -> Result<Self> {
+        let addr = if addr.contains(':') {
+            addr.to_string()
+        } else {
+            format!("{}:{}", addr, DEFAULT_PORT)
+        };
+        let client = DedicatedClient::connect(addr.as_str(), name)?;
+        log::info!(
+            "Joined dedicated server {} as player {} (seed {})",
+            addr,
+            client.player_id(),
+            client.seed()
+        );
+        Ok(Self { client, addr })
+    }
+
+    /// Address of the server
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    /// Spawn point of the server world
+    pub fn spawn(&self) -> Vec2 {
+        let (x, y) = self.client.spawn();
+        Vec2::new(x, y)
+    }
+
+    /// Apply everything the server sent since the last frame and report
+    /// the local player's movement; fails once the connection is gone
+    pub fn update(&mut self, world: &mut World) -> Result<()> {
+        for message in self.client.poll() {
+            match &message {
+                ServerMessage::ActionRejected { reason } => {
+                    log::debug!("Server rejected edit: {}", reason);
+                }
+                _ => {
+                    apply_to_world(world, &message);
+                }
+            }
+        }
+
+        let player = &world.player;
+        self.client.update_position(
+            player.position.x,
+            player.position.y,
+            player.velocity.x,
+            player.velocity.y,
+        )
+    }
+
+    /// Send every pixel a local edit changed since `before` was taken
+    pub fn send_edits(&mut self, world: &World, before: &EditSnapshot) -> Result<()> {
+        for (x, y, _, current) in before.changes(world) {
+            if current == MaterialId::AIR {
+                self.client.mine(x, y)?;
+            } else {
+                self.client.place_material(x, y, current)?;
+            }
+        }
+        Ok(())
+    }
+}
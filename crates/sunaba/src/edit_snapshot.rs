@@ -0,0 +1,79 @@
+//! Before/after comparison of local world edits
+//!
+//! Brushes and mining circles change many pixels at once. Clients of a
+//! server take a snapshot of the area before applying an edit locally, then
+//! send the server every pixel that actually changed.
+
+use crate::world::World;
+
+/// Materials in the square around an edit, taken before it is applied
+pub struct EditSnapshot {
+    min_x: i32,
+    min_y: i32,
+    size: i32,
+    materials: Vec<Option<u16>>,
+}
+
+impl EditSnapshot {
+    /// Record the square of `radius` around (x, y)
+    pub fn take(world: &World, x: i32, y: i32, radius: i32) -> Self {
+        let (min_x, min_y, size) = (x - radius, y - radius, radius * 2 + 1);
+        let materials = (0..size * size)
+            .map(|i| world.get_pixel_material(min_x + i % size, min_y + i / size))
+            .collect();
+        Self {
+            min_x,
+            min_y,
+            size,
+            materials,
+        }
+    }
+
+    /// Pixels whose material changed since the snapshot, as
+    /// `(x, y, previous, current)` (unloaded pixels are skipped)
+    pub fn changes<'a>(
+        &'a self,
+        world: &'a World,
+    ) -> impl Iterator<Item = (i32, i32, u16, u16)> + 'a {
+        self.materials
+            .iter()
+            .enumerate()
+            .filter_map(move |(i, previous)| {
+                let i = i as i32;
+                let (x, y) = (self.min_x + i % self.size, self.min_y + i / self.size);
+                let previous = (*previous)?;
+                let current = world.get_pixel_material(x, y)?;
+                (current != previous).then_some((x, y, previous, current))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::MaterialId;
+    use crate::world::Chunk;
+    use glam::IVec2;
+
+    #[test]
+    fn test_changes_lists_only_edited_pixels() {
+        let mut world = World::new(true);
+        world.insert_chunk(IVec2::ZERO, Chunk::new(0, 0));
+        world.set_pixel(5, 5, MaterialId::STONE);
+
+        let before = EditSnapshot::take(&world, 5, 5, 2);
+        world.set_pixel(4, 5, MaterialId::SAND);
+        world.set_pixel(5, 5, MaterialId::AIR);
+        // Outside the snapshot
+        world.set_pixel(20, 20, MaterialId::SAND);
+
+        let changes: Vec<_> = before.changes(&world).collect();
+        assert_eq!(
+            changes,
+            [
+                (4, 5, MaterialId::AIR, MaterialId::SAND),
+                (5, 5, MaterialId::STONE, MaterialId::AIR)
+            ]
+        );
+    }
+}
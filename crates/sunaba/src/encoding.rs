@@ -4,13 +4,10 @@
 
 use bincode_next as bincode;
 
-/// Decode full chunk from bytes
+/// Decode full chunk from bytes (older chunk layouts included)
 pub fn decode_chunk(data: &[u8]) -> Result<sunaba_core::world::Chunk, String> {
-    let (chunk, _): (sunaba_core::world::Chunk, _) =
-        bincode::serde::decode_from_slice(data, bincode::config::standard())
-            .map_err(|e| format!("Failed to decode chunk: {}", e))?;
-
-    Ok(chunk)
+    sunaba_core::world::decode_chunk_blob(data)
+        .map_err(|e| format!("Failed to decode chunk: {}", e))
 }
 
 /// Encode full chunk to bytes
@@ -18,3 +15,27 @@ pub fn encode_chunk(chunk: &sunaba_core::world::Chunk) -> Result<Vec<u8>, String
     bincode::serde::encode_to_vec(chunk, bincode::config::standard())
         .map_err(|e| format!("Failed to encode chunk: {}", e))
 }
+
+/// Decode a server-side player inventory from bytes
+pub fn decode_inventory(data: &[u8]) -> Result<sunaba_core::entity::inventory::Inventory, String> {
+    if data.is_empty() {
+        return Ok(sunaba_core::entity::player::Player::starting_inventory());
+    }
+
+    let (inventory, _): (sunaba_core::entity::inventory::Inventory, _) =
+        bincode::serde::decode_from_slice(data, bincode::config::standard())
+            .map_err(|e| format!("Failed to decode inventory: {}", e))?;
+
+    Ok(inventory)
+}
+
+/// Decode a server creature morphology (`creature_info.morphology_data`)
+pub fn decode_morphology(
+    data: &[u8],
+) -> Result<sunaba_core::creature::morphology::CreatureMorphology, String> {
+    let (morphology, _): (sunaba_core::creature::morphology::CreatureMorphology, _) =
+        bincode::serde::decode_from_slice(data, bincode::config::standard())
+            .map_err(|e| format!("Failed to decode morphology: {}", e))?;
+
+    Ok(morphology)
+}
@@ -0,0 +1,296 @@
+//! Animated PNG (APNG) encoding without external tools
+//!
+//! Frames are compressed and written as soon as they are captured, so memory
+//! use stays at one frame regardless of video length. The frame count in the
+//! `acTL` header is patched in when the encoder is finished, which is why the
+//! output must be seekable (a file, or `Cursor<Vec<u8>>` for in-memory use).
+
+use std::fs::File;
+use std::io::{BufWriter, Cursor, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use image::ImageEncoder;
+use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+
+use super::PixelRenderer;
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Streaming APNG encoder for RGB frames
+pub struct ApngEncoder<W: Write + Seek> {
+    writer: W,
+    width: u32,
+    height: u32,
+    /// Frame delay is 1/fps seconds (delay_num = 1)
+    delay_den: u16,
+    frame_count: u32,
+    /// Shared sequence counter for fcTL and fdAT chunks
+    sequence: u32,
+    /// Offset of the acTL chunk, rewritten in `finish`
+    actl_offset: u64,
+}
+
+impl ApngEncoder<BufWriter<File>> {
+    /// Create an encoder that streams to a file
+    pub fn create<P: AsRef<Path>>(path: P, width: u32, height: u32, fps: u32) -> Result<Self> {
+        let path = path.as_ref();
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create APNG file: {:?}", path))?;
+        Self::new(BufWriter::new(file), width, height, fps)
+    }
+}
+
+impl ApngEncoder<Cursor<Vec<u8>>> {
+    /// Create an encoder that writes to an in-memory buffer
+    pub fn in_memory(width: u32, height: u32, fps: u32) -> Result<Self> {
+        Self::new(Cursor::new(Vec::new()), width, height, fps)
+    }
+
+    /// Finish and return the encoded bytes
+    pub fn into_bytes(self) -> Result<Vec<u8>> {
+        Ok(self.finish()?.into_inner())
+    }
+}
+
+impl<W: Write + Seek> ApngEncoder<W> {
+    /// Start a new animation and write the PNG header
+    ///
+    /// # Arguments
+    /// * `writer` - Seekable output
+    /// * `width` - Frame width in pixels
+    /// * `height` - Frame height in pixels
+    /// * `fps` - Playback frames per second (0 falls back to 10)
+    pub fn new(mut writer: W, width: u32, height: u32, fps: u32) -> Result<Self> {
+        if width == 0 || height == 0 {
+            anyhow::bail!("APNG dimensions must be non-zero ({}x{})", width, height);
+        }
+
+        writer.write_all(&PNG_SIGNATURE)?;
+
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend_from_slice(&width.to_be_bytes());
+        ihdr.extend_from_slice(&height.to_be_bytes());
+        ihdr.extend_from_slice(&[8, 2, 0, 0, 0]); // 8-bit RGB, deflate, no interlace
+        write_chunk(&mut writer, b"IHDR", &ihdr)?;
+
+        let actl_offset = writer.stream_position()?;
+        write_chunk(&mut writer, b"acTL", &actl_data(0))?;
+
+        let fps = if fps > 0 { fps } else { 10 };
+
+        Ok(Self {
+            writer,
+            width,
+            height,
+            delay_den: fps.min(u16::MAX as u32) as u16,
+            frame_count: 0,
+            sequence: 0,
+            actl_offset,
+        })
+    }
+
+    /// Append one frame of tightly packed RGB data
+    pub fn write_frame(&mut self, rgb: &[u8]) -> Result<()> {
+        let expected = self.width as usize * self.height as usize * 3;
+        if rgb.len() != expected {
+            anyhow::bail!(
+                "Frame size mismatch: got {} bytes, expected {} ({}x{} RGB)",
+                rgb.len(),
+                expected,
+                self.width,
+                self.height
+            );
+        }
+
+        let image_data = compress_frame(rgb, self.width, self.height)?;
+
+        let mut fctl = Vec::with_capacity(26);
+        fctl.extend_from_slice(&self.next_sequence().to_be_bytes());
+        fctl.extend_from_slice(&self.width.to_be_bytes());
+        fctl.extend_from_slice(&self.height.to_be_bytes());
+        fctl.extend_from_slice(&0u32.to_be_bytes()); // x offset
+        fctl.extend_from_slice(&0u32.to_be_bytes()); // y offset
+        fctl.extend_from_slice(&1u16.to_be_bytes()); // delay numerator
+        fctl.extend_from_slice(&self.delay_den.to_be_bytes());
+        fctl.extend_from_slice(&[0, 0]); // dispose: none, blend: source
+        write_chunk(&mut self.writer, b"fcTL", &fctl)?;
+
+        // First frame doubles as the static image for non-APNG viewers
+        if self.frame_count == 0 {
+            write_chunk(&mut self.writer, b"IDAT", &image_data)?;
+        } else {
+            let mut fdat = Vec::with_capacity(image_data.len() + 4);
+            fdat.extend_from_slice(&self.next_sequence().to_be_bytes());
+            fdat.extend_from_slice(&image_data);
+            write_chunk(&mut self.writer, b"fdAT", &fdat)?;
+        }
+
+        self.frame_count += 1;
+        Ok(())
+    }
+
+    /// Append the current contents of a pixel renderer
+    pub fn capture_frame(&mut self, renderer: &PixelRenderer) -> Result<()> {
+        self.write_frame(&renderer.get_rgb_buffer())
+    }
+
+    /// Get the number of frames written so far
+    pub fn frame_count(&self) -> usize {
+        self.frame_count as usize
+    }
+
+    /// Write the trailer, patch the frame count and return the writer
+    pub fn finish(mut self) -> Result<W> {
+        if self.frame_count == 0 {
+            anyhow::bail!("No frames to encode");
+        }
+
+        write_chunk(&mut self.writer, b"IEND", &[])?;
+
+        let end = self.writer.stream_position()?;
+        self.writer.seek(SeekFrom::Start(self.actl_offset))?;
+        write_chunk(&mut self.writer, b"acTL", &actl_data(self.frame_count))?;
+        self.writer.seek(SeekFrom::Start(end))?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+
+    fn next_sequence(&mut self) -> u32 {
+        let seq = self.sequence;
+        self.sequence += 1;
+        seq
+    }
+}
+
+/// acTL payload: frame count, then play count (0 = loop forever)
+fn actl_data(num_frames: u32) -> [u8; 8] {
+    let mut data = [0u8; 8];
+    data[..4].copy_from_slice(&num_frames.to_be_bytes());
+    data
+}
+
+fn write_chunk<W: Write>(writer: &mut W, kind: &[u8; 4], data: &[u8]) -> Result<()> {
+    let mut crc = crc32fast::Hasher::new();
+    crc.update(kind);
+    crc.update(data);
+
+    writer.write_all(&(data.len() as u32).to_be_bytes())?;
+    writer.write_all(kind)?;
+    writer.write_all(data)?;
+    writer.write_all(&crc.finalize().to_be_bytes())?;
+    Ok(())
+}
+
+/// Filter and deflate one frame by encoding it as a standalone PNG and
+/// lifting out the concatenated IDAT payload
+fn compress_frame(rgb: &[u8], width: u32, height: u32) -> Result<Vec<u8>> {
+    let mut png = Vec::new();
+    PngEncoder::new_with_quality(&mut png, CompressionType::Fast, FilterType::Adaptive)
+        .write_image(rgb, width, height, image::ExtendedColorType::Rgb8)
+        .context("Failed to compress APNG frame")?;
+
+    let mut data = Vec::new();
+    let mut pos = PNG_SIGNATURE.len();
+    while pos + 12 <= png.len() {
+        let len = u32::from_be_bytes(png[pos..pos + 4].try_into()?) as usize;
+        let kind = &png[pos + 4..pos + 8];
+        let body = png
+            .get(pos + 8..pos + 8 + len)
+            .context("Truncated PNG chunk")?;
+        if kind == b"IDAT" {
+            data.extend_from_slice(body);
+        }
+        pos += 12 + len;
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::AnimationDecoder;
+    use image::codecs::png::PngDecoder;
+
+    fn solid_frame(width: u32, height: u32, color: [u8; 3]) -> Vec<u8> {
+        (0..width * height).flat_map(|_| color).collect()
+    }
+
+    #[test]
+    fn test_apng_roundtrip() {
+        let mut encoder = ApngEncoder::in_memory(8, 4, 10).unwrap();
+        encoder
+            .write_frame(&solid_frame(8, 4, [255, 0, 0]))
+            .unwrap();
+        encoder
+            .write_frame(&solid_frame(8, 4, [0, 255, 0]))
+            .unwrap();
+        encoder
+            .write_frame(&solid_frame(8, 4, [0, 0, 255]))
+            .unwrap();
+        assert_eq!(encoder.frame_count(), 3);
+        let bytes = encoder.into_bytes().unwrap();
+
+        let decoder = PngDecoder::new(Cursor::new(bytes)).unwrap();
+        assert!(decoder.is_apng().unwrap());
+        let frames = decoder
+            .apng()
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+        assert_eq!(frames.len(), 3);
+
+        let last = frames[2].buffer();
+        assert_eq!(last.dimensions(), (8, 4));
+        assert_eq!(last.get_pixel(3, 2).0, [0, 0, 255, 255]);
+
+        let (num, den) = frames[0].delay().numer_denom_ms();
+        assert_eq!(num / den, 100);
+    }
+
+    #[test]
+    fn test_first_frame_is_static_image() {
+        let mut encoder = ApngEncoder::in_memory(4, 4, 30).unwrap();
+        encoder
+            .write_frame(&solid_frame(4, 4, [10, 20, 30]))
+            .unwrap();
+        let bytes = encoder.into_bytes().unwrap();
+
+        let img = image::load_from_memory(&bytes).unwrap().to_rgb8();
+        assert_eq!(img.get_pixel(0, 0).0, [10, 20, 30]);
+    }
+
+    #[test]
+    fn test_rejects_bad_frames() {
+        let mut encoder = ApngEncoder::in_memory(4, 4, 10).unwrap();
+        assert!(encoder.write_frame(&[0; 5]).is_err());
+
+        let empty = ApngEncoder::in_memory(4, 4, 10).unwrap();
+        assert!(empty.finish().is_err());
+    }
+
+    #[test]
+    fn test_streams_to_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("out.png");
+        let renderer = PixelRenderer::new(16, 16);
+
+        let mut encoder = ApngEncoder::create(&path, 16, 16, 10).unwrap();
+        for _ in 0..5 {
+            encoder.capture_frame(&renderer).unwrap();
+        }
+        encoder.finish().unwrap();
+
+        let file = std::io::BufReader::new(File::open(&path).unwrap());
+        let frames = PngDecoder::new(file)
+            .unwrap()
+            .apng()
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+        assert_eq!(frames.len(), 5);
+    }
+}
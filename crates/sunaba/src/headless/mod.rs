@@ -1,13 +1,14 @@
 //! Headless training environment for creature evolution
 //!
 //! This module provides infrastructure for evolving creatures offline without GUI:
-//! - Pixel buffer rendering for GIF capture
+//! - Pixel buffer rendering for GIF/APNG capture
 //! - Training scenarios (locomotion, foraging, survival)
 //! - Fitness functions to evaluate creature performance
 //! - MAP-Elites for maintaining diverse populations
 //! - HTML report generation with animated visualizations
 //! - Procedural terrain generation for curriculum learning
 
+mod apng_capture;
 mod curriculum;
 mod env_distribution;
 mod fitness;
@@ -20,6 +21,7 @@ mod scenario;
 mod terrain_config;
 mod training_env;
 
+pub use apng_capture::ApngEncoder;
 pub use curriculum::{AdvancementCriteria, CurriculumConfig, CurriculumStage, CurriculumTracker};
 pub use env_distribution::{DifficultySampling, EnvironmentDistribution};
 pub use fitness::{
@@ -29,7 +31,7 @@ pub use gif_capture::GifCapture;
 pub use map_elites::{DiverseElite, Elite, MapElitesGrid};
 pub use multi_env_eval::{FitnessAggregation, MultiEnvFitness, MultiEnvironmentEvaluator};
 pub use pixel_renderer::PixelRenderer;
-pub use report::ReportGenerator;
+pub use report::{AnimationFormat, ReportGenerator};
 pub use scenario::{Scenario, ScenarioConfig};
 pub use terrain_config::{DifficultyConfig, TrainingTerrainConfig};
 pub use training_env::{TrainingConfig, TrainingEnv, TrainingStats};
@@ -13,6 +13,41 @@ use super::map_elites::MapElitesGrid;
 use super::scenario::ScenarioConfig;
 use super::training_env::TrainingStats;
 
+/// Encoding used for animations embedded in reports
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AnimationFormat {
+    /// Animated GIF (256-color palette, frames buffered until encode)
+    #[default]
+    Gif,
+    /// Animated PNG (full color, frames streamed as captured)
+    Apng,
+}
+
+impl AnimationFormat {
+    /// MIME type for `data:` URIs
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            AnimationFormat::Gif => "image/gif",
+            AnimationFormat::Apng => "image/apng",
+        }
+    }
+}
+
+impl std::str::FromStr for AnimationFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "gif" => Ok(AnimationFormat::Gif),
+            "apng" | "png" => Ok(AnimationFormat::Apng),
+            _ => Err(format!(
+                "Unknown animation format: {} (expected gif or apng)",
+                s
+            )),
+        }
+    }
+}
+
 /// A captured GIF with metadata for the report
 #[derive(Debug, Clone)]
 pub struct CapturedGif {
@@ -22,8 +57,10 @@ pub struct CapturedGif {
     pub fitness: f32,
     /// Behavior descriptor values
     pub behavior: Vec<f32>,
-    /// GIF data as bytes
+    /// Animation data as bytes
     pub data: Vec<u8>,
+    /// Encoding of `data`
+    pub format: AnimationFormat,
 }
 
 /// Generates HTML reports for training runs
@@ -439,7 +476,7 @@ impl ReportGenerator {
         html.push_str(r#"<div class="gif-grid">"#);
 
         for gif in gifs {
-            // Encode GIF as base64
+            // Encode animation as base64
             let base64_data = base64_encode(&gif.data);
 
             // Determine if this is the champion (first GIF)
@@ -452,7 +489,7 @@ impl ReportGenerator {
             let _ = write!(
                 html,
                 r#"<div class="{}">
-                    <img src="data:image/gif;base64,{}" alt="{}">
+                    <img src="data:{};base64,{}" alt="{}">
                     <div class="gif-label">{}</div>
                     <div class="gif-stats">
                         Fitness: {:.1}<br>
@@ -460,6 +497,7 @@ impl ReportGenerator {
                     </div>
                 </div>"#,
                 card_class,
+                gif.format.mime_type(),
                 base64_data,
                 gif.label,
                 gif.label,
@@ -1149,6 +1187,23 @@ mod tests {
         assert_eq!(generator.output_dir, "test_output");
     }
 
+    #[test]
+    fn test_gif_section_uses_animation_mime_type() {
+        let config = ScenarioConfig::default();
+        let generator = ReportGenerator::new("test_output", &config);
+        let gifs = vec![CapturedGif {
+            label: "Champion".to_string(),
+            fitness: 1.0,
+            behavior: vec![0.5, 0.5],
+            data: vec![1, 2, 3],
+            format: AnimationFormat::Apng,
+        }];
+
+        let html = generator.generate_gif_section(&gifs);
+        assert!(html.contains("data:image/apng;base64,"));
+        assert_eq!("APNG".parse(), Ok(AnimationFormat::Apng));
+    }
+
     #[test]
     fn test_base64_encode() {
         assert_eq!(base64_encode(b"Hello"), "SGVsbG8=");
@@ -192,6 +192,8 @@ impl Scenario {
     /// Set up world using procedural generation (NEW)
     fn setup_procedural_world(&self, config: &TrainingTerrainConfig) -> (World, Vec<Vec2>) {
         let mut world = World::new(false);
+        // Rain and wind would make runs of the same scenario diverge
+        world.set_weather_enabled(false);
 
         // Apply difficulty to get WorldGenConfig
         let worldgen_config = config.apply_difficulty();
@@ -219,6 +221,7 @@ impl Scenario {
     /// Set up world using manual terrain (EXISTING)
     fn setup_manual_world(&self) -> (World, Vec<Vec2>) {
         let mut world = World::new(false);
+        world.set_weather_enabled(false);
 
         // Ensure chunks exist for the entire scenario area
         world.ensure_chunks_for_area(
@@ -16,13 +16,14 @@ use crate::simulation::Materials;
 
 use sunaba_core::world::biome::BiomeType;
 
+use super::apng_capture::ApngEncoder;
 use super::curriculum::{CurriculumConfig, CurriculumTracker};
 use super::fitness::BehaviorDescriptor;
 use super::gif_capture::GifCapture;
 use super::map_elites::MapElitesGrid;
 use super::multi_env_eval::MultiEnvironmentEvaluator;
 use super::pixel_renderer::PixelRenderer;
-use super::report::{CapturedGif, ReportGenerator};
+use super::report::{AnimationFormat, CapturedGif, ReportGenerator};
 use super::scenario::Scenario;
 
 /// Configuration for biome specialist training mode
@@ -59,6 +60,8 @@ pub struct TrainingConfig {
     pub gif_size: u16,
     /// GIF frames per second
     pub gif_fps: u16,
+    /// Encoding for report animations (APNG streams frames instead of buffering)
+    pub animation_format: AnimationFormat,
     /// Output directory for reports
     pub output_dir: String,
     /// Use simple morphology (fewer body parts, viability filter)
@@ -89,6 +92,7 @@ impl Default for TrainingConfig {
             gif_capture_interval: 10,
             gif_size: 368,
             gif_fps: 10,
+            animation_format: AnimationFormat::default(),
             output_dir: "training_output".to_string(),
             use_simple_morphology: false,
             min_viability: 0.3,
@@ -1516,11 +1520,20 @@ impl TrainingEnv {
         behavior: &[f32],
     ) -> Result<CapturedGif> {
         let size = self.config.gif_size as usize;
+        let format = self.config.animation_format;
         let mut gif = GifCapture::new(
             self.config.gif_size,
             self.config.gif_size,
             self.config.gif_fps,
         );
+        let mut apng = match format {
+            AnimationFormat::Apng => Some(ApngEncoder::in_memory(
+                self.config.gif_size as u32,
+                self.config.gif_size as u32,
+                self.config.gif_fps as u32,
+            )?),
+            AnimationFormat::Gif => None,
+        };
         let mut renderer = PixelRenderer::new(size, size);
         let materials = Materials::new();
 
@@ -1637,18 +1650,25 @@ impl TrainingEnv {
                 let time_text = format!("T:{:.1}s", elapsed_time);
                 renderer.draw_text(size as i32 - 42, 4, &time_text, [255, 255, 100, 255]);
 
-                gif.capture_frame(&renderer);
+                match apng.as_mut() {
+                    Some(apng) => apng.capture_frame(&renderer)?,
+                    None => gif.capture_frame(&renderer),
+                }
             }
         }
 
-        // Encode GIF to bytes
-        let data = gif.to_bytes().context("Failed to encode GIF")?;
+        // Encode animation to bytes
+        let data = match apng {
+            Some(apng) => apng.into_bytes().context("Failed to encode APNG")?,
+            None => gif.to_bytes().context("Failed to encode GIF")?,
+        };
 
         Ok(CapturedGif {
             label: label.to_string(),
             fitness,
             behavior: behavior.to_vec(),
             data,
+            format,
         })
     }
 
@@ -22,6 +22,10 @@ pub mod screenshot;
 #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
 pub mod scenario;
 
+// Input recording and replay (native only, uses bincode_next)
+#[cfg(not(target_arch = "wasm32"))]
+pub mod recording;
+
 // Remote control module (native only, requires scenario actions)
 #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
 pub mod remote_control;
@@ -30,8 +34,19 @@ pub mod remote_control;
 #[cfg(feature = "multiplayer")]
 pub mod multiplayer;
 
-// Encoding module for chunk synchronization (multiplayer native only - uses bincode_next)
-#[cfg(all(feature = "multiplayer", not(target_arch = "wasm32")))]
+// Before/after pixel comparison for sending local edits to a server
+#[cfg(any(
+    feature = "multiplayer",
+    all(not(target_arch = "wasm32"), feature = "dedicated")
+))]
+pub mod edit_snapshot;
+
+// Dedicated server client (native only, plain TCP)
+#[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+pub mod dedicated;
+
+// Encoding module for multiplayer chunk and player row synchronization (uses bincode_next)
+#[cfg(feature = "multiplayer")]
 pub mod encoding;
 
 // Re-export core modules for convenience
@@ -1,4 +1,7 @@
-#[cfg(feature = "multiplayer")]
+#[cfg(any(
+    feature = "multiplayer",
+    all(not(target_arch = "wasm32"), feature = "dedicated")
+))]
 use anyhow::Context;
 use clap::Parser;
 use std::path::PathBuf;
@@ -39,6 +42,10 @@ struct Args {
     #[arg(long, default_value = "all")]
     archetype: String,
 
+    /// Report animation format: gif (default) or apng
+    #[arg(long, default_value = "gif")]
+    animation_format: String,
+
     /// Server URL to connect to on startup (multiplayer mode)
     #[arg(long)]
     #[cfg(feature = "multiplayer")]
@@ -49,6 +56,16 @@ struct Args {
     #[cfg(feature = "multiplayer")]
     fresh_identity: bool,
 
+    /// Dedicated server to join on startup (host or host:port)
+    #[arg(long)]
+    #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+    dedicated: Option<String>,
+
+    /// Player name shown on a dedicated server
+    #[arg(long, default_value = "Player")]
+    #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+    name: String,
+
     /// Capture a screenshot (level:N, ui:panel, or just N for backward compat)
     #[arg(long)]
     #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
@@ -114,6 +131,11 @@ struct Args {
     #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
     scenario_screenshots: bool,
 
+    /// Overwrite MatchesSnapshot baselines with the current render
+    #[arg(long)]
+    #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
+    update_snapshots: bool,
+
     /// Enable detailed profiling (flamegraph output)
     #[arg(long)]
     #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
@@ -149,6 +171,11 @@ struct Args {
     #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
     video_output_dir: String,
 
+    /// Video encoding: mp4 (requires ffmpeg) or apng (pure Rust)
+    #[arg(long, default_value = "mp4")]
+    #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
+    video_format: String,
+
     /// Enable debug statistics output during video generation
     #[arg(long)]
     #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
@@ -258,6 +285,9 @@ fn main() -> anyhow::Result<()> {
     if args.generate_all_videos {
         use std::path::PathBuf;
 
+        let video_format: sunaba::screenshot::VideoFormat =
+            args.video_format.parse().map_err(anyhow::Error::msg)?;
+
         // Create output directory
         std::fs::create_dir_all(&args.video_output_dir)?;
 
@@ -273,8 +303,11 @@ fn main() -> anyhow::Result<()> {
         for (i, scenario) in scenarios.iter().enumerate() {
             log::info!("=== Video {}/{}: {} ===", i + 1, total, scenario.name);
 
-            let output_path =
-                PathBuf::from(&args.video_output_dir).join(format!("{}.mp4", scenario.id));
+            let output_path = PathBuf::from(&args.video_output_dir).join(format!(
+                "{}.{}",
+                scenario.id,
+                video_format.extension()
+            ));
 
             match sunaba::screenshot::capture_video_scenario(
                 scenario,
@@ -312,9 +345,15 @@ fn main() -> anyhow::Result<()> {
         // Create output directory
         std::fs::create_dir_all(&args.video_output_dir)?;
 
+        let video_format: sunaba::screenshot::VideoFormat =
+            args.video_format.parse().map_err(anyhow::Error::msg)?;
+
         // Determine output path
-        let output_path =
-            PathBuf::from(&args.video_output_dir).join(format!("{}.mp4", scenario.id));
+        let output_path = PathBuf::from(&args.video_output_dir).join(format!(
+            "{}.{}",
+            scenario.id,
+            video_format.extension()
+        ));
 
         log::info!("Generating video scenario: {}", scenario.name);
 
@@ -330,7 +369,9 @@ fn main() -> anyhow::Result<()> {
     if args.test_scenario_stdin {
         use anyhow::Context;
         use std::io::Read;
-        use sunaba::scenario::{ScenarioDefinition, ScenarioExecutor, ScenarioExecutorConfig};
+        use sunaba::scenario::{
+            ScenarioDefinition, ScenarioExecutor, ScenarioExecutorConfig, SnapshotConfig,
+        };
         use sunaba_core::world::World;
 
         // Initialize detailed profiling if requested
@@ -367,6 +408,10 @@ fn main() -> anyhow::Result<()> {
             screenshot_dir: "screenshots".to_string(),
             verbose: false,
             detailed_profiling: args.detailed_profiling,
+            snapshots: SnapshotConfig {
+                update: args.update_snapshots,
+                ..SnapshotConfig::default()
+            },
         };
         let mut executor = ScenarioExecutor::with_config(config);
 
@@ -428,7 +473,9 @@ fn main() -> anyhow::Result<()> {
     // Handle --test-scenario flag
     #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
     if let Some(scenario_path) = args.test_scenario {
-        use sunaba::scenario::{ScenarioDefinition, ScenarioExecutor, ScenarioExecutorConfig};
+        use sunaba::scenario::{
+            ScenarioDefinition, ScenarioExecutor, ScenarioExecutorConfig, SnapshotConfig,
+        };
         use sunaba_core::world::World;
 
         // Initialize detailed profiling if requested
@@ -458,6 +505,10 @@ fn main() -> anyhow::Result<()> {
             screenshot_dir: "screenshots".to_string(),
             verbose: false,
             detailed_profiling: args.detailed_profiling,
+            snapshots: SnapshotConfig {
+                update: args.update_snapshots,
+                ..SnapshotConfig::default()
+            },
         };
         let mut executor = ScenarioExecutor::with_config(config);
 
@@ -566,13 +617,19 @@ fn main() -> anyhow::Result<()> {
     #[cfg(not(all(not(target_arch = "wasm32"), feature = "headless")))]
     let remote_control = false;
 
-    pollster::block_on(run(server_url, remote_control, fresh_identity))
+    // Dedicated server address and player name, if joining one
+    #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+    let dedicated = args.dedicated.map(|addr| (addr, args.name));
+    #[cfg(not(all(not(target_arch = "wasm32"), feature = "dedicated")))]
+    let dedicated: Option<(String, String)> = None;
+
+    pollster::block_on(run(server_url, remote_control, fresh_identity, dedicated))
 }
 
 #[cfg(feature = "headless")]
 fn run_training(args: &Args) -> anyhow::Result<()> {
     use sunaba::creature::morphology::CreatureArchetype;
-    use sunaba::headless::{Scenario, TrainingConfig, TrainingEnv};
+    use sunaba::headless::{AnimationFormat, Scenario, TrainingConfig, TrainingEnv};
 
     // Parse archetype(s)
     let archetypes: Vec<CreatureArchetype> = if args.archetype.to_lowercase() == "all" {
@@ -615,6 +672,11 @@ fn run_training(args: &Args) -> anyhow::Result<()> {
         (s, false)
     };
 
+    let animation_format: AnimationFormat = args.animation_format.parse().unwrap_or_else(|e| {
+        log::warn!("{}, using GIF", e);
+        AnimationFormat::Gif
+    });
+
     let config = TrainingConfig {
         generations: args.generations,
         population_size: args.population,
@@ -622,6 +684,7 @@ fn run_training(args: &Args) -> anyhow::Result<()> {
         use_simple_morphology: use_simple,
         archetypes: archetypes.clone(),
         archetype: archetypes.first().copied().unwrap_or_default(),
+        animation_format,
         ..TrainingConfig::default()
     };
 
@@ -629,11 +692,18 @@ fn run_training(args: &Args) -> anyhow::Result<()> {
     env.run()
 }
 
-#[cfg_attr(not(feature = "multiplayer"), allow(unused_variables, unused_mut))]
+#[cfg_attr(
+    not(any(
+        feature = "multiplayer",
+        all(not(target_arch = "wasm32"), feature = "dedicated")
+    )),
+    allow(unused_variables, unused_mut)
+)]
 async fn run(
     server_url: Option<String>,
     _enable_remote_control: bool,
     fresh_identity: bool,
+    dedicated: Option<(String, String)>,
 ) -> anyhow::Result<()> {
     let (mut app, event_loop) = App::new().await?;
 
@@ -648,6 +718,13 @@ async fn run(
         log::info!("Connected to multiplayer server");
     }
 
+    // Same for a dedicated server (--dedicated specified)
+    #[cfg(all(not(target_arch = "wasm32"), feature = "dedicated"))]
+    if let Some((addr, name)) = dedicated {
+        app.connect_to_dedicated(&addr, &name)
+            .with_context(|| format!("Failed to join dedicated server {}", addr))?;
+    }
+
     // Start TCP remote control server if requested
     #[cfg(all(not(target_arch = "wasm32"), feature = "headless"))]
     if _enable_remote_control {
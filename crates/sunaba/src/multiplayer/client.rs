@@ -7,20 +7,33 @@ use std::sync::{Arc, Mutex};
 
 // Import generated SpacetimeDB client bindings
 use super::generated::{self, DbConnection};
+use generated::admin_user_table::AdminUserTableAccess;
+use generated::chat_message_table::ChatMessageTableAccess;
 use generated::chunk_data_table::ChunkDataTableAccess;
 use generated::claim_admin_reducer::claim_admin;
-use generated::creature_data_table::CreatureDataTableAccess;
+use generated::creature_info_table::CreatureInfoTableAccess;
+use generated::creature_pose_table::CreaturePoseTableAccess;
+use generated::land_claim_table::LandClaimTableAccess;
 use generated::player_respawn_reducer::player_respawn;
 use generated::player_table::PlayerTableAccess;
 use generated::rebuild_world_reducer::rebuild_world;
 use generated::request_ping_reducer::request_ping;
 use generated::server_metrics_table::ServerMetricsTableAccess;
 use generated::set_player_name_reducer::set_player_name;
-use generated::{player_mine, player_place_material, player_update_position};
-use spacetimedb_sdk::{DbContext, Table, TableWithPrimaryKey}; // Trait for connection and table methods
+use generated::world_config_table::WorldConfigTableAccess;
+use generated::world_snapshot_table::WorldSnapshotTableAccess;
+use generated::{
+    create_snapshot, import_chunk, player_cancel_mining, player_confirm_edits, player_craft,
+    player_eat, player_equip_tool, player_mine, player_place_from_inventory, player_place_material,
+    player_set_loaded_radius, player_start_mining, player_update_position, rollback_world,
+    send_chat,
+};
+use spacetimedb_sdk::{DbContext, Status, Table, TableWithPrimaryKey}; // Trait for connection and table methods
 
 // Re-export traits needed by app.rs for player table access
+pub use generated::land_claim_table::LandClaimTableAccess as LandClaimTableAccessTrait;
 pub use generated::player_table::PlayerTableAccess as PlayerTableAccessTrait;
+pub use generated::world_config_table::WorldConfigTableAccess as WorldConfigTableAccessTrait;
 pub use spacetimedb_sdk::{DbContext as DbContextTrait, Table as TableTrait};
 
 // OAuth imports (native only)
@@ -63,6 +76,10 @@ pub struct MultiplayerClient {
     /// Chunks that have been updated on the server and need reloading
     /// Set by on_update callback (background thread), consumed by sync_chunks_progressive
     chunks_needing_reload: Arc<Mutex<HashSet<(i32, i32)>>>,
+
+    /// Outcomes of our `import_chunk` calls (set by the reducer callback,
+    /// consumed by take_import_results)
+    import_results: Arc<Mutex<Vec<Result<(), String>>>>,
 }
 
 /// Generate default nickname from Identity (format: "Player_abc123" using last 6 hex chars)
@@ -89,6 +106,7 @@ impl MultiplayerClient {
             disconnect_detected: Arc::new(AtomicBool::new(false)),
             subscription_data_received: Arc::new(AtomicBool::new(false)),
             chunks_needing_reload: Arc::new(Mutex::new(HashSet::new())),
+            import_results: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -168,6 +186,21 @@ impl MultiplayerClient {
             .build()
             .context("Failed to build SpacetimeDB connection")?;
 
+        // Record how our world uploads went (other admins' imports are ignored)
+        let import_results = Arc::clone(&self.import_results);
+        conn.reducers
+            .on_import_chunk(move |ctx, chunk_x, chunk_y, _pixel_data| {
+                if ctx.event.caller_identity != ctx.identity() {
+                    return;
+                }
+                let result = match &ctx.event.status {
+                    Status::Committed => Ok(()),
+                    Status::Failed(err) => Err(format!("({}, {}): {}", chunk_x, chunk_y, err)),
+                    _ => Err(format!("({}, {}): not applied", chunk_x, chunk_y)),
+                };
+                import_results.lock().unwrap().push(result);
+            });
+
         // Spawn background thread to process WebSocket messages (keeps connection alive)
         // This is required - without it, the connection will timeout and disconnect
         let thread_handle = conn.run_threaded();
@@ -181,7 +214,7 @@ impl MultiplayerClient {
         Ok(())
     }
 
-    /// Subscribe to world state (chunks, players, creatures)
+    /// Subscribe to world state (chunks, players, claims, creatures)
     pub async fn subscribe_world(&mut self) -> anyhow::Result<()> {
         let conn = self
             .connection
@@ -322,16 +355,61 @@ impl MultiplayerClient {
             log::debug!("Identity not yet available, will set nickname later");
         }
 
-        // Subscribe to creatures
+        // Subscribe to land claims (for claim borders)
+        let _claim_sub = conn_guard
+            .subscription_builder()
+            .on_applied(|ctx| {
+                log::debug!(
+                    "Land claim subscription applied - {} claims",
+                    ctx.db.land_claim().iter().count()
+                );
+            })
+            .subscribe("SELECT * FROM land_claim");
+
+        // Subscribe to chat (replies addressed to other players are filtered client-side)
+        let _chat_sub = conn_guard
+            .subscription_builder()
+            .on_applied(|ctx| {
+                log::debug!(
+                    "Chat subscription applied - {} messages",
+                    ctx.db.chat_message().iter().count()
+                );
+            })
+            .subscribe("SELECT * FROM chat_message");
+
+        // Subscribe to admins (the panel only offers admin tools to them)
+        let _admin_sub = conn_guard
+            .subscription_builder()
+            .on_applied(|ctx| {
+                log::debug!(
+                    "Admin subscription applied - {} admins",
+                    ctx.db.admin_user().iter().count()
+                );
+            })
+            .subscribe("SELECT * FROM admin_user");
+
+        // Subscribe to snapshot headers (admins list and roll back to them)
+        let _snapshot_sub = conn_guard
+            .subscription_builder()
+            .on_applied(|ctx| {
+                log::debug!(
+                    "Snapshot subscription applied - {} snapshots",
+                    ctx.db.world_snapshot().iter().count()
+                );
+            })
+            .subscribe("SELECT * FROM world_snapshot");
+
+        // Subscribe to creature genomes/morphologies (written once per
+        // creature); poses follow the chunk subscription area
         let _creature_sub = conn_guard
             .subscription_builder()
             .on_applied(|ctx| {
                 log::debug!(
-                    "Creature subscription applied - {} creatures",
-                    ctx.db.creature_data().iter().count()
+                    "Creature info subscription applied - {} creatures",
+                    ctx.db.creature_info().iter().count()
                 );
             })
-            .subscribe("SELECT * FROM creature_data");
+            .subscribe("SELECT * FROM creature_info");
 
         // Subscribe to server metrics
         let _metrics_sub = conn_guard
@@ -384,7 +462,9 @@ impl MultiplayerClient {
     }
 
     /// Request material placement at position
-    pub fn place_material(&self, x: i32, y: i32, material_id: u16) -> anyhow::Result<()> {
+    ///
+    /// `seq` is the edit's prediction sequence number (0 = untracked).
+    pub fn place_material(&self, x: i32, y: i32, material_id: u16, seq: u64) -> anyhow::Result<()> {
         let conn = self
             .connection
             .as_ref()
@@ -393,14 +473,16 @@ impl MultiplayerClient {
         let conn_guard = conn.lock().unwrap();
         conn_guard
             .reducers
-            .player_place_material(x, y, material_id)
+            .player_place_material(x, y, material_id, seq)
             .context("Failed to call player_place_material reducer")?;
 
         Ok(())
     }
 
     /// Request mining at position
-    pub fn mine(&self, x: i32, y: i32) -> anyhow::Result<()> {
+    ///
+    /// `seq` is the edit's prediction sequence number (0 = untracked).
+    pub fn mine(&self, x: i32, y: i32, seq: u64) -> anyhow::Result<()> {
         let conn = self
             .connection
             .as_ref()
@@ -409,12 +491,392 @@ impl MultiplayerClient {
         let conn_guard = conn.lock().unwrap();
         conn_guard
             .reducers
-            .player_mine(x, y)
+            .player_mine(x, y, seq)
             .context("Failed to call player_mine reducer")?;
 
         Ok(())
     }
 
+    /// Start mining a pixel into the server-side inventory
+    pub fn start_mining(&self, x: i32, y: i32) -> anyhow::Result<()> {
+        let conn = self
+            .connection
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected to server"))?;
+
+        let conn_guard = conn.lock().unwrap();
+        conn_guard
+            .reducers
+            .player_start_mining(x, y)
+            .context("Failed to call player_start_mining reducer")?;
+
+        Ok(())
+    }
+
+    /// Stop mining without collecting anything
+    pub fn cancel_mining(&self) -> anyhow::Result<()> {
+        let conn = self
+            .connection
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected to server"))?;
+
+        let conn_guard = conn.lock().unwrap();
+        conn_guard
+            .reducers
+            .player_cancel_mining()
+            .context("Failed to call player_cancel_mining reducer")?;
+
+        Ok(())
+    }
+
+    /// Place one pixel of a material from the server-side inventory
+    ///
+    /// `seq` is the edit's prediction sequence number (0 = untracked).
+    pub fn place_from_inventory(
+        &self,
+        x: i32,
+        y: i32,
+        material_id: u16,
+        seq: u64,
+    ) -> anyhow::Result<()> {
+        let conn = self
+            .connection
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected to server"))?;
+
+        let conn_guard = conn.lock().unwrap();
+        conn_guard
+            .reducers
+            .player_place_from_inventory(x, y, material_id, seq)
+            .context("Failed to call player_place_from_inventory reducer")?;
+
+        Ok(())
+    }
+
+    /// Craft a recipe from the server-side inventory
+    pub fn craft(&self, recipe_id: u16) -> anyhow::Result<()> {
+        let conn = self
+            .connection
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected to server"))?;
+
+        let conn_guard = conn.lock().unwrap();
+        conn_guard
+            .reducers
+            .player_craft(recipe_id)
+            .context("Failed to call player_craft reducer")?;
+
+        Ok(())
+    }
+
+    /// Eat one unit of food from the server-side inventory
+    pub fn eat(&self, material_id: u16) -> anyhow::Result<()> {
+        let conn = self
+            .connection
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected to server"))?;
+
+        let conn_guard = conn.lock().unwrap();
+        conn_guard
+            .reducers
+            .player_eat(material_id)
+            .context("Failed to call player_eat reducer")?;
+
+        Ok(())
+    }
+
+    /// Tell the server our rejected edits up to `seq` have been rolled back
+    pub fn confirm_rejected_edits(&self, seq: u64) -> anyhow::Result<()> {
+        let conn = self
+            .connection
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected to server"))?;
+
+        let conn_guard = conn.lock().unwrap();
+        conn_guard
+            .reducers
+            .player_confirm_edits(seq)
+            .context("Failed to call player_confirm_edits reducer")?;
+
+        Ok(())
+    }
+
+    /// Equip a tool from the server-side inventory (`None` unequips)
+    pub fn equip_tool(&self, tool_id: Option<u16>) -> anyhow::Result<()> {
+        let conn = self
+            .connection
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected to server"))?;
+
+        let conn_guard = conn.lock().unwrap();
+        conn_guard
+            .reducers
+            .player_equip_tool(tool_id)
+            .context("Failed to call player_equip_tool reducer")?;
+
+        Ok(())
+    }
+
+    /// Send a chat message or `/command`
+    pub fn send_chat(&self, text: String) -> anyhow::Result<()> {
+        let conn = self
+            .connection
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected to server"))?;
+
+        let conn_guard = conn.lock().unwrap();
+        conn_guard
+            .reducers
+            .send_chat(text)
+            .context("Failed to call send_chat reducer")?;
+
+        Ok(())
+    }
+
+    /// Chat messages visible to us (broadcasts and replies to us), oldest first
+    pub fn chat_messages(&self) -> Vec<super::ChatLine> {
+        let Some(conn) = self.connection.as_ref() else {
+            return Vec::new();
+        };
+        let conn_guard = conn.lock().unwrap();
+        let identity = conn_guard.try_identity();
+
+        let mut lines: Vec<_> = conn_guard
+            .db
+            .chat_message()
+            .iter()
+            .filter(|m| m.recipient.is_none() || m.recipient == identity)
+            .map(|m| super::ChatLine {
+                id: m.id,
+                sender_name: m.sender_name,
+                text: m.text,
+                system: m.system,
+            })
+            .collect();
+        lines.sort_by_key(|line| line.id);
+        lines
+    }
+
+    /// World snapshots on the server, newest first
+    pub fn snapshots(&self) -> Vec<super::SnapshotInfo> {
+        let Some(conn) = self.connection.as_ref() else {
+            return Vec::new();
+        };
+        let conn_guard = conn.lock().unwrap();
+
+        let mut snapshots: Vec<_> = conn_guard
+            .db
+            .world_snapshot()
+            .iter()
+            .map(|s| super::SnapshotInfo {
+                id: s.id,
+                tick: s.tick,
+                label: s.label,
+                automatic: s.automatic,
+                chunk_count: s.chunk_count,
+            })
+            .collect();
+        snapshots.sort_by_key(|s| std::cmp::Reverse(s.id));
+        snapshots
+    }
+
+    /// Take a labelled snapshot of the server world (admin only)
+    pub fn create_snapshot(&self, label: String) -> anyhow::Result<()> {
+        let conn = self
+            .connection
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected to server"))?;
+
+        let conn_guard = conn.lock().unwrap();
+        conn_guard
+            .reducers
+            .create_snapshot(label)
+            .context("Failed to call create_snapshot reducer")?;
+
+        Ok(())
+    }
+
+    /// Roll the whole server world back to a snapshot (admin only)
+    pub fn rollback_world(&self, snapshot_id: u64) -> anyhow::Result<()> {
+        let conn = self
+            .connection
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected to server"))?;
+
+        let conn_guard = conn.lock().unwrap();
+        conn_guard
+            .reducers
+            .rollback_world(snapshot_id)
+            .context("Failed to call rollback_world reducer")?;
+
+        Ok(())
+    }
+
+    /// Upload a chunk blob (`chunk_data` encoding) to the server (admin only)
+    pub fn import_chunk(&self, chunk_x: i32, chunk_y: i32, blob: Vec<u8>) -> anyhow::Result<()> {
+        let conn = self
+            .connection
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected to server"))?;
+
+        let conn_guard = conn.lock().unwrap();
+        conn_guard
+            .reducers
+            .import_chunk(chunk_x, chunk_y, blob)
+            .context("Failed to call import_chunk reducer")?;
+
+        Ok(())
+    }
+
+    /// Results of `import_chunk` calls that came back since the last call
+    pub fn take_import_results(&self) -> Vec<Result<(), String>> {
+        std::mem::take(&mut *self.import_results.lock().unwrap())
+    }
+
+    /// Whether the server has granted us admin status
+    pub fn is_admin(&self) -> bool {
+        let Some(conn) = self.connection.as_ref() else {
+            return false;
+        };
+        let conn_guard = conn.lock().unwrap();
+        conn_guard.try_identity().is_some_and(|identity| {
+            conn_guard
+                .db
+                .admin_user()
+                .identity()
+                .find(&identity)
+                .is_some()
+        })
+    }
+
+    /// Every chunk blob in the local cache: `(x, y, pixel_data)`
+    pub fn chunk_blobs(&self) -> Vec<(i32, i32, Vec<u8>)> {
+        let Some(conn) = self.connection.as_ref() else {
+            return Vec::new();
+        };
+        let conn_guard = conn.lock().unwrap();
+        conn_guard
+            .db
+            .chunk_data()
+            .iter()
+            .map(|row| (row.x, row.y, row.pixel_data))
+            .collect()
+    }
+
+    /// Server world seed (chunks missing from a download regenerate from it)
+    pub fn world_seed(&self) -> Option<u64> {
+        let conn = self.connection.as_ref()?;
+        let conn_guard = conn.lock().unwrap();
+        let config = conn_guard.db.world_config().id().find(&0)?;
+        Some(config.seed)
+    }
+
+    /// Creature poses the server currently publishes to us
+    pub fn creature_poses(&self) -> Vec<super::CreaturePoseUpdate> {
+        let Some(conn) = self.connection.as_ref() else {
+            return Vec::new();
+        };
+        let conn_guard = conn.lock().unwrap();
+        conn_guard
+            .db
+            .creature_pose()
+            .iter()
+            .map(|pose| super::CreaturePoseUpdate {
+                creature_id: pose.creature_id,
+                seq: pose.seq,
+                health: pose.health,
+                max_health: pose.max_health,
+                parts: pose
+                    .parts
+                    .iter()
+                    .map(|part| (glam::Vec2::new(part.x, part.y), part.rotation))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Body part radii of a creature, decoded from its `creature_info` row
+    pub fn creature_part_radii(&self, creature_id: u64) -> Option<Vec<f32>> {
+        let conn = self.connection.as_ref()?;
+        let conn_guard = conn.lock().unwrap();
+        let info = conn_guard
+            .db
+            .creature_info()
+            .creature_id()
+            .find(&creature_id)?;
+        match crate::encoding::decode_morphology(&info.morphology_data) {
+            Ok(morphology) => Some(morphology.body_parts.iter().map(|p| p.radius).collect()),
+            Err(e) => {
+                log::warn!("Bad morphology for creature {}: {}", creature_id, e);
+                None
+            }
+        }
+    }
+
+    /// Latest admin teleport of our player: `(teleport_seq, x, y)`
+    pub fn teleport_target(&self) -> Option<(u32, f32, f32)> {
+        let conn = self.connection.as_ref()?;
+        let conn_guard = conn.lock().unwrap();
+        let identity = conn_guard.try_identity()?;
+        let row = conn_guard.db.player().identity().find(&identity)?;
+        Some((row.teleport_seq, row.teleport_x, row.teleport_y))
+    }
+
+    /// Whether the server has our player row marked online (false after a kick)
+    pub fn is_player_online(&self) -> Option<bool> {
+        let conn = self.connection.as_ref()?;
+        let conn_guard = conn.lock().unwrap();
+        let identity = conn_guard.try_identity()?;
+        let row = conn_guard.db.player().identity().find(&identity)?;
+        Some(row.online)
+    }
+
+    /// Server day/night clock (seconds into the cycle)
+    pub fn time_of_day(&self) -> Option<f32> {
+        let conn = self.connection.as_ref()?;
+        let conn_guard = conn.lock().unwrap();
+        let config = conn_guard.db.world_config().id().find(&0)?;
+        Some(config.time_of_day)
+    }
+
+    /// Edit acknowledgements from our player row: the highest processed edit
+    /// sequence number and the rejected ones not yet confirmed
+    pub fn edit_acknowledgements(&self) -> Option<(u64, Vec<u64>)> {
+        let conn = self.connection.as_ref()?;
+        let conn_guard = conn.lock().unwrap();
+        let identity = conn_guard.try_identity()?;
+        let row = conn_guard.db.player().identity().find(&identity)?;
+        Some((row.last_edit_seq, row.rejected_edits))
+    }
+
+    /// Mirror the server's row for this client into the local player
+    ///
+    /// Inventory, equipped tool, health and hunger are server-authoritative;
+    /// position and mining progress stay client-driven (the server runs its
+    /// own copy of the mining timer). Returns false if the row isn't in the
+    /// subscription yet.
+    pub fn sync_local_player(&self, player: &mut sunaba_core::entity::player::Player) -> bool {
+        let Some(conn) = self.connection.as_ref() else {
+            return false;
+        };
+        let conn_guard = conn.lock().unwrap();
+        let Some(identity) = conn_guard.try_identity() else {
+            return false;
+        };
+        let Some(row) = conn_guard.db.player().identity().find(&identity) else {
+            return false;
+        };
+
+        match crate::encoding::decode_inventory(&row.inventory_data) {
+            Ok(inventory) => player.inventory = inventory,
+            Err(e) => log::warn!("[SpacetimeDB] {}", e),
+        }
+        player.equipped_tool = row.equipped_tool;
+        player.health.set(row.health);
+        player.hunger.set(row.hunger);
+        true
+    }
+
     /// Claim admin status on the server (requires OAuth email)
     pub async fn claim_admin(&self, email: String) -> anyhow::Result<()> {
         let conn = self
@@ -493,10 +955,14 @@ impl MultiplayerClient {
     ///
     /// Performance: Uses O(1) coordinate index lookup instead of O(n) linear search
     /// to avoid cloning 29KB ChunkData per iteration (was causing 34+ second load times).
+    ///
+    /// Edits still awaiting acknowledgement are re-applied to every chunk
+    /// loaded from the server, so predictions don't flicker away.
     pub fn sync_chunks_progressive(
         &self,
         world: &mut sunaba_core::world::World,
         load_queue: &mut crate::multiplayer::chunk_loader::ChunkLoadQueue,
+        predictor: &crate::multiplayer::prediction::EditPredictor,
     ) -> anyhow::Result<usize> {
         let conn = self
             .connection
@@ -537,6 +1003,7 @@ impl MultiplayerClient {
                 if let Some(chunk_row) = chunk_row {
                     if let Ok(chunk) = crate::encoding::decode_chunk(&chunk_row.pixel_data) {
                         world.insert_chunk(pos, chunk);
+                        predictor.reapply(world, pos);
                         load_queue.mark_loaded(pos);
                         synced_count += 1;
                         log::info!("Chunk ({}, {}) reloaded from server update", x, y);
@@ -581,6 +1048,7 @@ impl MultiplayerClient {
                 };
 
                 world.insert_chunk(pos, chunk);
+                predictor.reapply(world, pos);
                 load_queue.mark_loaded(pos);
                 synced_count += 1;
             }
@@ -783,6 +1251,7 @@ impl MultiplayerClient {
                 );
             })
             .subscribe(query);
+        Self::subscribe_creature_poses(&conn_guard, center, radius);
 
         log::info!("Expanded chunk subscription successfully");
 
@@ -826,12 +1295,40 @@ impl MultiplayerClient {
                 );
             })
             .subscribe(query);
+        Self::subscribe_creature_poses(&conn_guard, center, radius);
 
         log::info!("Re-subscribed to chunks successfully");
 
         Ok(())
     }
 
+    /// Follow creature poses in the chunk range we keep loaded
+    ///
+    /// The server only publishes poses inside each player's loaded radius,
+    /// so it is told the radius too.
+    fn subscribe_creature_poses(conn: &DbConnection, center: glam::IVec2, radius: i32) {
+        let query = format!(
+            "SELECT * FROM creature_pose WHERE chunk_x BETWEEN {} AND {} AND chunk_y BETWEEN {} AND {}",
+            center.x - radius,
+            center.x + radius,
+            center.y - radius,
+            center.y + radius
+        );
+        let _pose_sub = conn
+            .subscription_builder()
+            .on_applied(|ctx| {
+                log::debug!(
+                    "Creature pose subscription applied - {} creatures",
+                    ctx.db.creature_pose().iter().count()
+                );
+            })
+            .subscribe(query);
+
+        if let Err(e) = conn.reducers.player_set_loaded_radius(radius) {
+            log::warn!("Failed to report loaded radius: {}", e);
+        }
+    }
+
     // ===== OAuth Methods (Native Only) =====
 
     /// Initiate OAuth login flow (native only)
@@ -0,0 +1,239 @@
+//! Smooth rendering of server-simulated creatures
+//!
+//! The server publishes a small pose row per creature (part positions and
+//! rotations) at its AI tick rate, only for creatures near some player.
+//! Genome and morphology come once per creature from `creature_info`; all the
+//! client needs from them is each body part's radius. Between pose updates
+//! parts are blended from where they were drawn toward the newest pose, so
+//! creatures move smoothly despite the low, jittery update rate.
+
+use glam::Vec2;
+use std::collections::HashMap;
+use web_time::Instant;
+
+/// Time to blend from the drawn pose to a newly received one (a few server
+/// creature ticks, which run at ~30fps)
+const BLEND_SECONDS: f64 = 0.1;
+
+/// Pose row as received from the server
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreaturePoseUpdate {
+    pub creature_id: u64,
+    /// Publish counter of the pose row; a different value means a new pose
+    pub seq: u64,
+    pub health: f32,
+    pub max_health: f32,
+    /// `(position, rotation)` per body part, in morphology order
+    pub parts: Vec<(Vec2, f32)>,
+}
+
+/// Creature ready to draw: interpolated parts with their radii
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatureRenderData {
+    pub creature_id: u64,
+    /// `(position, radius)` per body part
+    pub parts: Vec<(Vec2, f32)>,
+    /// Health in 0..=1
+    pub health_fraction: f32,
+}
+
+#[derive(Debug)]
+struct Track {
+    /// Pose drawn when `latest` arrived (blend start)
+    from: Vec<(Vec2, f32)>,
+    latest: CreaturePoseUpdate,
+    received_at: f64,
+}
+
+impl Track {
+    fn sample(&self, now: f64) -> Vec<(Vec2, f32)> {
+        let t = ((now - self.received_at) / BLEND_SECONDS).clamp(0.0, 1.0) as f32;
+        self.latest
+            .parts
+            .iter()
+            .enumerate()
+            .map(|(i, &(to_pos, to_rot))| match self.from.get(i) {
+                Some(&(from_pos, from_rot)) => {
+                    (from_pos.lerp(to_pos, t), lerp_angle(from_rot, to_rot, t))
+                }
+                None => (to_pos, to_rot),
+            })
+            .collect()
+    }
+}
+
+/// Shortest-path interpolation between two angles (radians)
+fn lerp_angle(from: f32, to: f32, t: f32) -> f32 {
+    let delta =
+        (to - from + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU) - std::f32::consts::PI;
+    from + delta * t
+}
+
+/// Interpolated poses of the creatures currently published to us
+#[derive(Debug)]
+pub struct CreatureInterpolator {
+    tracks: HashMap<u64, Track>,
+    /// Body part radii per creature (from `creature_info`, decoded once)
+    radii: HashMap<u64, Vec<f32>>,
+    epoch: Instant,
+}
+
+impl CreatureInterpolator {
+    pub fn new() -> Self {
+        Self {
+            tracks: HashMap::new(),
+            radii: HashMap::new(),
+            epoch: Instant::now(),
+        }
+    }
+
+    /// Seconds since creation (the clock `update` and `sample` expect)
+    pub fn now(&self) -> f64 {
+        self.epoch.elapsed().as_secs_f64()
+    }
+
+    /// Apply the current pose rows (`now` in seconds)
+    ///
+    /// Creatures missing from `poses` left our area or died and are dropped.
+    pub fn update(&mut self, poses: Vec<CreaturePoseUpdate>, now: f64) {
+        self.tracks
+            .retain(|id, _| poses.iter().any(|p| p.creature_id == *id));
+        self.radii.retain(|id, _| self.tracks.contains_key(id));
+
+        for pose in poses {
+            match self.tracks.get_mut(&pose.creature_id) {
+                Some(track) if pose.seq != track.latest.seq => {
+                    track.from = track.sample(now);
+                    track.latest = pose;
+                    track.received_at = now;
+                }
+                Some(_) => {}
+                None => {
+                    self.tracks.insert(
+                        pose.creature_id,
+                        Track {
+                            from: pose.parts.clone(),
+                            latest: pose,
+                            received_at: now,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// Creatures whose part radii haven't been provided yet
+    pub fn missing_radii(&self) -> Vec<u64> {
+        self.tracks
+            .keys()
+            .filter(|id| !self.radii.contains_key(id))
+            .copied()
+            .collect()
+    }
+
+    /// Record a creature's body part radii (morphology order)
+    pub fn set_radii(&mut self, creature_id: u64, radii: Vec<f32>) {
+        self.radii.insert(creature_id, radii);
+    }
+
+    /// Interpolated creatures at `now` (seconds); creatures whose radii
+    /// aren't known yet are skipped
+    pub fn sample(&self, now: f64) -> Vec<CreatureRenderData> {
+        self.tracks
+            .iter()
+            .filter_map(|(&creature_id, track)| {
+                let radii = self.radii.get(&creature_id)?;
+                let parts = track
+                    .sample(now)
+                    .into_iter()
+                    .zip(radii)
+                    .map(|((pos, _), &radius)| (pos, radius))
+                    .collect();
+                let health_fraction = if track.latest.max_health > 0.0 {
+                    (track.latest.health / track.latest.max_health).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                Some(CreatureRenderData {
+                    creature_id,
+                    parts,
+                    health_fraction,
+                })
+            })
+            .collect()
+    }
+
+    /// Forget all creatures (new session)
+    pub fn clear(&mut self) {
+        self.tracks.clear();
+        self.radii.clear();
+    }
+}
+
+impl Default for CreatureInterpolator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pose(seq: u64, x: f32) -> CreaturePoseUpdate {
+        CreaturePoseUpdate {
+            creature_id: 7,
+            seq,
+            health: 50.0,
+            max_health: 100.0,
+            parts: vec![(Vec2::new(x, 0.0), 0.0), (Vec2::new(x + 4.0, 0.0), 0.0)],
+        }
+    }
+
+    #[test]
+    fn test_blends_toward_new_pose() {
+        let mut interpolator = CreatureInterpolator::new();
+        interpolator.update(vec![pose(1, 0.0)], 0.0);
+        assert_eq!(interpolator.missing_radii(), vec![7]);
+        assert!(interpolator.sample(0.0).is_empty());
+
+        interpolator.set_radii(7, vec![3.0, 2.0]);
+        assert!(interpolator.missing_radii().is_empty());
+
+        interpolator.update(vec![pose(2, 10.0)], 1.0);
+        let halfway = interpolator.sample(1.0 + BLEND_SECONDS / 2.0);
+        assert_eq!(halfway.len(), 1);
+        assert!((halfway[0].parts[0].0.x - 5.0).abs() < 1e-4);
+        assert_eq!(halfway[0].parts[0].1, 3.0);
+        assert_eq!(halfway[0].health_fraction, 0.5);
+
+        let done = interpolator.sample(2.0);
+        assert_eq!(done[0].parts[1].0.x, 14.0);
+
+        // Repeated rows don't restart the blend
+        interpolator.update(vec![pose(2, 10.0)], 3.0);
+        assert_eq!(interpolator.sample(3.0)[0].parts[0].0.x, 10.0);
+
+        // A sequence that started over (e.g. after a server restart) is
+        // still a new pose
+        interpolator.update(vec![pose(1, 20.0)], 4.0);
+        assert_eq!(interpolator.sample(5.0)[0].parts[0].0.x, 20.0);
+    }
+
+    #[test]
+    fn test_drops_unpublished_creatures() {
+        let mut interpolator = CreatureInterpolator::new();
+        interpolator.update(vec![pose(1, 0.0)], 0.0);
+        interpolator.set_radii(7, vec![3.0, 2.0]);
+
+        interpolator.update(Vec::new(), 1.0);
+        assert!(interpolator.sample(1.0).is_empty());
+        assert!(interpolator.missing_radii().is_empty());
+    }
+
+    #[test]
+    fn test_lerp_angle_takes_short_way() {
+        let angle = lerp_angle(3.0, -3.0, 0.5);
+        assert!(angle.abs() > 3.0);
+    }
+}
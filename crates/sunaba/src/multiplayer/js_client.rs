@@ -23,11 +23,39 @@ extern "C" {
 
     /// Send material placement
     #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "placeMaterial", catch)]
-    fn js_place_material(x: i32, y: i32, material_id: u16) -> Result<(), JsValue>;
+    fn js_place_material(x: i32, y: i32, material_id: u16, seq: f64) -> Result<(), JsValue>;
 
     /// Send mining action
     #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "mine", catch)]
-    fn js_mine(x: i32, y: i32) -> Result<(), JsValue>;
+    fn js_mine(x: i32, y: i32, seq: f64) -> Result<(), JsValue>;
+
+    /// Send placement from the server-side inventory
+    #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "placeFromInventory", catch)]
+    fn js_place_from_inventory(x: i32, y: i32, material_id: u16, seq: f64) -> Result<(), JsValue>;
+
+    /// Start mining into the server-side inventory
+    #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "startMining", catch)]
+    fn js_start_mining(x: i32, y: i32) -> Result<(), JsValue>;
+
+    /// Craft a recipe from the server-side inventory
+    #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "craft", catch)]
+    fn js_craft(recipe_id: u16) -> Result<(), JsValue>;
+
+    /// Eat food from the server-side inventory
+    #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "eat", catch)]
+    fn js_eat(material_id: u16) -> Result<(), JsValue>;
+
+    /// Confirm rejected edits up to `seq` have been rolled back
+    #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "confirmEdits", catch)]
+    fn js_confirm_edits(seq: f64) -> Result<(), JsValue>;
+
+    /// Equip a tool from the server-side inventory (`None` unequips)
+    #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "equipTool", catch)]
+    fn js_equip_tool(tool_id: Option<u16>) -> Result<(), JsValue>;
+
+    /// Get our player row from JavaScript cache
+    #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "getLocalPlayer")]
+    fn js_get_local_player() -> JsValue;
 
     /// Check if connected to server
     #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "isConnected")]
@@ -68,6 +96,21 @@ extern "C" {
     // Player action bindings
     #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "requestRespawn", catch)]
     async fn js_request_respawn() -> Result<(), JsValue>;
+
+    // Chat bindings
+    #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "sendChat", catch)]
+    async fn js_send_chat(text: &str) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "getChatMessages")]
+    fn js_get_chat_messages() -> JsValue;
+
+    /// Get land claim outlines from JavaScript cache
+    #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "getLandClaims")]
+    fn js_get_land_claims() -> JsValue;
+
+    /// Get the spawn protection radius from JavaScript cache
+    #[wasm_bindgen(js_namespace = ["window", "spacetimeClient"], js_name = "getSpawnProtectionRadius")]
+    fn js_get_spawn_protection_radius() -> i32;
 }
 
 /// Server performance metrics (matches server schema)
@@ -130,6 +173,29 @@ impl ServerMetrics {
     }
 }
 
+/// Server-owned part of our player row, as cached by the JavaScript bridge
+#[derive(serde::Deserialize)]
+struct LocalPlayerRow {
+    inventory_data: Vec<u8>,
+    equipped_tool: Option<u16>,
+    health: f32,
+    hunger: f32,
+    last_edit_seq: u64,
+    rejected_edits: Vec<u64>,
+}
+
+/// Land claim outline, as cached by the JavaScript bridge
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct LandClaimOutline {
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+    pub name: String,
+    /// Claimed by the local player
+    pub own: bool,
+}
+
 /// OAuth email claims parsed from JWT
 #[derive(Debug, Clone)]
 pub struct OAuthClaims {
@@ -241,28 +307,141 @@ impl MultiplayerClient {
     }
 
     /// Request material placement at position
-    pub fn place_material(&self, x: i32, y: i32, material_id: u16) -> anyhow::Result<()> {
+    ///
+    /// `seq` is the edit's prediction sequence number (0 = untracked).
+    pub fn place_material(&self, x: i32, y: i32, material_id: u16, seq: u64) -> anyhow::Result<()> {
         if !self.connected {
             anyhow::bail!("Not connected to server");
         }
 
-        js_place_material(x, y, material_id)
+        js_place_material(x, y, material_id, seq as f64)
             .map_err(|e| anyhow::anyhow!("Failed to place material: {:?}", e))?;
 
         Ok(())
     }
 
     /// Request mining at position
-    pub fn mine(&self, x: i32, y: i32) -> anyhow::Result<()> {
+    ///
+    /// `seq` is the edit's prediction sequence number (0 = untracked).
+    pub fn mine(&self, x: i32, y: i32, seq: u64) -> anyhow::Result<()> {
+        if !self.connected {
+            anyhow::bail!("Not connected to server");
+        }
+
+        js_mine(x, y, seq as f64).map_err(|e| anyhow::anyhow!("Failed to mine: {:?}", e))?;
+
+        Ok(())
+    }
+
+    /// Place one pixel of a material from the server-side inventory
+    ///
+    /// `seq` is the edit's prediction sequence number (0 = untracked).
+    pub fn place_from_inventory(
+        &self,
+        x: i32,
+        y: i32,
+        material_id: u16,
+        seq: u64,
+    ) -> anyhow::Result<()> {
+        if !self.connected {
+            anyhow::bail!("Not connected to server");
+        }
+
+        js_place_from_inventory(x, y, material_id, seq as f64)
+            .map_err(|e| anyhow::anyhow!("Failed to place from inventory: {:?}", e))?;
+
+        Ok(())
+    }
+
+    /// Start mining a pixel into the server-side inventory
+    pub fn start_mining(&self, x: i32, y: i32) -> anyhow::Result<()> {
+        if !self.connected {
+            anyhow::bail!("Not connected to server");
+        }
+
+        js_start_mining(x, y).map_err(|e| anyhow::anyhow!("Failed to start mining: {:?}", e))?;
+
+        Ok(())
+    }
+
+    /// Craft a recipe from the server-side inventory
+    pub fn craft(&self, recipe_id: u16) -> anyhow::Result<()> {
+        if !self.connected {
+            anyhow::bail!("Not connected to server");
+        }
+
+        js_craft(recipe_id).map_err(|e| anyhow::anyhow!("Failed to craft: {:?}", e))?;
+
+        Ok(())
+    }
+
+    /// Eat one unit of food from the server-side inventory
+    pub fn eat(&self, material_id: u16) -> anyhow::Result<()> {
+        if !self.connected {
+            anyhow::bail!("Not connected to server");
+        }
+
+        js_eat(material_id).map_err(|e| anyhow::anyhow!("Failed to eat: {:?}", e))?;
+
+        Ok(())
+    }
+
+    /// Tell the server our rejected edits up to `seq` have been rolled back
+    pub fn confirm_rejected_edits(&self, seq: u64) -> anyhow::Result<()> {
+        if !self.connected {
+            anyhow::bail!("Not connected to server");
+        }
+
+        js_confirm_edits(seq as f64)
+            .map_err(|e| anyhow::anyhow!("Failed to confirm edits: {:?}", e))?;
+
+        Ok(())
+    }
+
+    /// Equip a tool from the server-side inventory (`None` unequips)
+    pub fn equip_tool(&self, tool_id: Option<u16>) -> anyhow::Result<()> {
         if !self.connected {
             anyhow::bail!("Not connected to server");
         }
 
-        js_mine(x, y).map_err(|e| anyhow::anyhow!("Failed to mine: {:?}", e))?;
+        js_equip_tool(tool_id).map_err(|e| anyhow::anyhow!("Failed to equip tool: {:?}", e))?;
 
         Ok(())
     }
 
+    /// Mirror the server's row for this client into the local player
+    ///
+    /// Same as the native client: inventory, equipped tool, health and hunger
+    /// are server-authoritative. Returns false if the row isn't cached yet.
+    pub fn sync_local_player(&self, player: &mut sunaba_core::entity::player::Player) -> bool {
+        if !self.connected {
+            return false;
+        }
+        let Ok(row) = serde_wasm_bindgen::from_value::<LocalPlayerRow>(js_get_local_player())
+        else {
+            return false;
+        };
+
+        match crate::encoding::decode_inventory(&row.inventory_data) {
+            Ok(inventory) => player.inventory = inventory,
+            Err(e) => log::warn!("[SpacetimeDB] {}", e),
+        }
+        player.equipped_tool = row.equipped_tool;
+        player.health.set(row.health);
+        player.hunger.set(row.hunger);
+        true
+    }
+
+    /// Edit acknowledgements from our player row: the highest processed edit
+    /// sequence number and the rejected ones not yet confirmed
+    pub fn edit_acknowledgements(&self) -> Option<(u64, Vec<u64>)> {
+        if !self.connected {
+            return None;
+        }
+        let row = serde_wasm_bindgen::from_value::<LocalPlayerRow>(js_get_local_player()).ok()?;
+        Some((row.last_edit_seq, row.rejected_edits))
+    }
+
     /// Get chunk data from local cache (for rendering)
     ///
     /// Note: Chunk data flows through subscription callbacks in JavaScript
@@ -380,6 +559,48 @@ impl MultiplayerClient {
 
         Ok(())
     }
+
+    /// Send a chat message or slash command
+    pub fn send_chat(&self, text: String) -> anyhow::Result<()> {
+        if !self.connected {
+            anyhow::bail!("Not connected to server");
+        }
+
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(e) = js_send_chat(&text).await {
+                log::error!("Failed to send chat: {:?}", e);
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Chat messages visible to us (oldest first) from the JavaScript cache
+    pub fn chat_messages(&self) -> Vec<super::ChatLine> {
+        if !self.connected {
+            return Vec::new();
+        }
+
+        serde_wasm_bindgen::from_value(js_get_chat_messages()).unwrap_or_default()
+    }
+
+    /// Land claims from the JavaScript cache
+    pub fn land_claims(&self) -> Vec<LandClaimOutline> {
+        if !self.connected {
+            return Vec::new();
+        }
+
+        serde_wasm_bindgen::from_value(js_get_land_claims()).unwrap_or_default()
+    }
+
+    /// Spawn protection radius from the world config (0 = no spawn zone)
+    pub fn spawn_protection_radius(&self) -> i32 {
+        if !self.connected {
+            return 0;
+        }
+
+        js_get_spawn_protection_radius()
+    }
 }
 
 impl Default for MultiplayerClient {
@@ -4,6 +4,8 @@
 
 use super::MultiplayerClient;
 use super::chunk_loader::ChunkLoadQueue;
+use super::creatures::CreatureInterpolator;
+use super::prediction::EditPredictor;
 use crate::config::MultiplayerConfig;
 use glam::IVec2;
 use web_time::Instant;
@@ -76,6 +78,20 @@ pub struct MultiplayerManager {
 
     /// Subscription center in chunk coordinates (for re-subscription)
     pub subscription_center: IVec2,
+
+    /// Locally applied edits awaiting server acknowledgement
+    pub predictor: EditPredictor,
+
+    /// Last admin teleport applied to the local player (`None` until our
+    /// player row is first seen this session)
+    pub last_teleport_seq: Option<u32>,
+
+    /// Our player row has been online this session (so going offline means
+    /// we were kicked)
+    pub seen_online: bool,
+
+    /// Server creatures near us, interpolated between pose updates
+    pub creatures: CreatureInterpolator,
 }
 
 impl MultiplayerManager {
@@ -88,6 +104,10 @@ impl MultiplayerManager {
             saved_singleplayer: false,
             chunk_load_queue: None,
             subscription_center: IVec2::ZERO,
+            predictor: EditPredictor::new(),
+            last_teleport_seq: None,
+            seen_online: false,
+            creatures: CreatureInterpolator::new(),
         }
     }
 
@@ -104,6 +124,10 @@ impl MultiplayerManager {
     pub fn mark_connected(&mut self, server_url: String) {
         log::info!("Successfully connected to {}", server_url);
         self.state = MultiplayerState::Connected { server_url };
+        self.predictor.clear();
+        self.creatures.clear();
+        self.last_teleport_seq = None;
+        self.seen_online = false;
 
         // Update last connected server in config
         self.config.last_server = Some(self.state.server_url().unwrap().to_string());
@@ -151,6 +175,10 @@ impl MultiplayerManager {
         }
         self.state = MultiplayerState::Disconnected;
         self.saved_singleplayer = false;
+        self.predictor.clear();
+        self.creatures.clear();
+        self.last_teleport_seq = None;
+        self.seen_online = false;
     }
 
     /// Set the saved singleplayer flag
@@ -29,6 +29,14 @@ mod manager;
 #[cfg(feature = "multiplayer")]
 pub mod chunk_loader;
 
+// Client-side prediction of world edits (both platforms)
+#[cfg(feature = "multiplayer")]
+pub mod prediction;
+
+// Interpolated server creatures (both platforms)
+#[cfg(feature = "multiplayer")]
+pub mod creatures;
+
 // Metrics available on both platforms when multiplayer enabled
 #[cfg(feature = "multiplayer")]
 pub mod metrics;
@@ -47,6 +55,38 @@ pub use js_client::MultiplayerClient;
 #[cfg(feature = "multiplayer")]
 pub use manager::{MultiplayerManager, MultiplayerState};
 
+#[cfg(feature = "multiplayer")]
+pub use prediction::{EditPredictor, PredictedEdit};
+
+#[cfg(feature = "multiplayer")]
+pub use creatures::{CreatureInterpolator, CreaturePoseUpdate, CreatureRenderData};
+
+// ===== Shared Chat Line (Platform-Agnostic) =====
+
+/// Chat message as shown in the chat panel (works on both native and WASM)
+#[cfg(feature = "multiplayer")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(target_arch = "wasm32", derive(serde::Deserialize))]
+pub struct ChatLine {
+    pub id: u64,
+    pub sender_name: String,
+    pub text: String,
+    /// Server reply or announcement
+    pub system: bool,
+}
+
+/// World snapshot header, as shown in the admin panel (native only)
+#[cfg(all(not(target_arch = "wasm32"), feature = "multiplayer"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotInfo {
+    pub id: u64,
+    pub tick: u64,
+    pub label: String,
+    /// Taken by the periodic timer
+    pub automatic: bool,
+    pub chunk_count: u32,
+}
+
 // ===== Shared OAuth Claims (Platform-Agnostic) =====
 
 /// Shared OAuth claims type used by UI (works on both native and WASM)
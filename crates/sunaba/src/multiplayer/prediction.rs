@@ -0,0 +1,247 @@
+//! Client-side prediction for multiplayer world edits
+//!
+//! Placing and mining apply to the local world immediately. Each edit is
+//! tagged with a sequence number that goes to the server with the reducer
+//! call, and the server acknowledges it on our player row (`last_edit_seq`,
+//! `rejected_edits`). Until then, authoritative chunk data that arrives
+//! without the edit gets it re-applied; rejected edits are rolled back. The
+//! server keeps rejections until we confirm we've seen them, so none are
+//! missed however many pixels a brush stroke changes.
+
+use glam::IVec2;
+use std::collections::VecDeque;
+use sunaba_core::world::{CHUNK_SIZE, World};
+
+/// Unacknowledged edits kept at most (the oldest are dropped, e.g. if the
+/// server never answers); a mining circle alone changes hundreds of pixels
+const MAX_PENDING_EDITS: usize = 4096;
+
+/// An edit applied locally and sent to the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PredictedEdit {
+    pub seq: u64,
+    pub x: i32,
+    pub y: i32,
+    /// Material before the edit (restored on rollback)
+    pub previous: u16,
+    /// Material the edit wrote
+    pub material_id: u16,
+}
+
+impl PredictedEdit {
+    fn chunk_pos(&self) -> IVec2 {
+        IVec2::new(
+            self.x.div_euclid(CHUNK_SIZE as i32),
+            self.y.div_euclid(CHUNK_SIZE as i32),
+        )
+    }
+}
+
+/// Tracks predicted edits until the server acknowledges them
+#[derive(Debug)]
+pub struct EditPredictor {
+    next_seq: u64,
+    pending: VecDeque<PredictedEdit>,
+    /// Highest rejection confirmed to the server
+    confirmed_seq: u64,
+}
+
+impl EditPredictor {
+    pub fn new() -> Self {
+        Self {
+            next_seq: 1,
+            pending: VecDeque::new(),
+            confirmed_seq: 0,
+        }
+    }
+
+    /// Record an edit that was just applied to the local world
+    ///
+    /// Returns the sequence number to send with the reducer call, or 0
+    /// (untracked) if the edit didn't change the pixel locally.
+    pub fn record(&mut self, x: i32, y: i32, previous: u16, material_id: u16) -> u64 {
+        if previous == material_id {
+            return 0;
+        }
+
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        if self.pending.len() >= MAX_PENDING_EDITS {
+            self.pending.pop_front();
+        }
+        self.pending.push_back(PredictedEdit {
+            seq,
+            x,
+            y,
+            previous,
+            material_id,
+        });
+        seq
+    }
+
+    /// Edits the server hasn't acknowledged yet, oldest first
+    pub fn pending(&self) -> impl Iterator<Item = &PredictedEdit> {
+        self.pending.iter()
+    }
+
+    /// Drop edits the server has processed (`seq <= last_seq`)
+    ///
+    /// Rejected edits are rolled back in `world` (unless something else has
+    /// changed the pixel since) and returned so the caller can show them.
+    pub fn acknowledge(
+        &mut self,
+        world: &mut World,
+        last_seq: u64,
+        rejected: &[u64],
+    ) -> Vec<PredictedEdit> {
+        let mut rolled_back = Vec::new();
+        while let Some(edit) = self.pending.front().copied()
+            && edit.seq <= last_seq
+        {
+            self.pending.pop_front();
+            if rejected.contains(&edit.seq) {
+                if world.get_pixel_material(edit.x, edit.y) == Some(edit.material_id) {
+                    world.set_pixel(edit.x, edit.y, edit.previous);
+                }
+                rolled_back.push(edit);
+            }
+        }
+        rolled_back
+    }
+
+    /// Sequence number up to which the server may forget our rejections,
+    /// once `acknowledge` has handled them (`None` if already confirmed)
+    pub fn confirm_rejections(&mut self, rejected: &[u64]) -> Option<u64> {
+        let up_to = rejected.iter().copied().max()?;
+        if up_to <= self.confirmed_seq {
+            return None;
+        }
+        self.confirmed_seq = up_to;
+        Some(up_to)
+    }
+
+    /// Re-apply pending edits to a chunk just replaced with server data
+    ///
+    /// Only pixels still showing the pre-edit material are touched, so
+    /// concurrent changes from the server win. Returns the number re-applied.
+    pub fn reapply(&self, world: &mut World, chunk_pos: IVec2) -> usize {
+        let mut count = 0;
+        for edit in self.pending.iter().filter(|e| e.chunk_pos() == chunk_pos) {
+            if world.get_pixel_material(edit.x, edit.y) == Some(edit.previous) {
+                world.set_pixel(edit.x, edit.y, edit.material_id);
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Forget all edits (new session)
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.next_seq = 1;
+        self.confirmed_seq = 0;
+    }
+}
+
+impl Default for EditPredictor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sunaba_core::simulation::MaterialId;
+    use sunaba_core::world::Chunk;
+
+    fn test_world() -> World {
+        let mut world = World::new(true);
+        world.insert_chunk(IVec2::ZERO, Chunk::new(0, 0));
+        world
+    }
+
+    /// Place locally and record the prediction, like the app does
+    fn predict(predictor: &mut EditPredictor, world: &mut World, x: i32, y: i32, mat: u16) -> u64 {
+        let previous = world.get_pixel_material(x, y).unwrap();
+        world.set_pixel(x, y, mat);
+        predictor.record(x, y, previous, mat)
+    }
+
+    #[test]
+    fn test_sequence_numbers_and_untracked_edits() {
+        let mut predictor = EditPredictor::new();
+        assert_eq!(predictor.record(1, 1, MaterialId::AIR, MaterialId::SAND), 1);
+        assert_eq!(predictor.record(2, 1, MaterialId::AIR, MaterialId::SAND), 2);
+        // No-op edit isn't tracked
+        assert_eq!(
+            predictor.record(3, 1, MaterialId::SAND, MaterialId::SAND),
+            0
+        );
+        assert_eq!(predictor.pending().count(), 2);
+
+        predictor.clear();
+        assert_eq!(predictor.pending().count(), 0);
+        assert_eq!(predictor.record(1, 1, MaterialId::AIR, MaterialId::SAND), 1);
+    }
+
+    #[test]
+    fn test_server_chunk_gets_pending_edits_reapplied() {
+        let mut world = test_world();
+        let mut predictor = EditPredictor::new();
+        predict(&mut predictor, &mut world, 5, 5, MaterialId::STONE);
+
+        // Server chunk arrives before our edit was processed
+        world.insert_chunk(IVec2::ZERO, Chunk::new(0, 0));
+        assert_eq!(world.get_pixel_material(5, 5), Some(MaterialId::AIR));
+        assert_eq!(predictor.reapply(&mut world, IVec2::ZERO), 1);
+        assert_eq!(world.get_pixel_material(5, 5), Some(MaterialId::STONE));
+
+        // Other chunks are untouched
+        assert_eq!(predictor.reapply(&mut world, IVec2::new(1, 0)), 0);
+
+        // Accepted: the prediction is dropped, nothing rolls back
+        assert!(predictor.acknowledge(&mut world, 1, &[]).is_empty());
+        assert_eq!(predictor.pending().count(), 0);
+        assert_eq!(world.get_pixel_material(5, 5), Some(MaterialId::STONE));
+    }
+
+    #[test]
+    fn test_rejected_edit_rolls_back() {
+        let mut world = test_world();
+        let mut predictor = EditPredictor::new();
+        predict(&mut predictor, &mut world, 5, 5, MaterialId::STONE);
+        predict(&mut predictor, &mut world, 6, 5, MaterialId::STONE);
+        predict(&mut predictor, &mut world, 7, 5, MaterialId::STONE);
+
+        // Edit 2 rejected, edit 3 not processed yet
+        let rolled_back = predictor.acknowledge(&mut world, 2, &[2]);
+        assert_eq!(rolled_back.len(), 1);
+        assert_eq!((rolled_back[0].x, rolled_back[0].y), (6, 5));
+        assert_eq!(world.get_pixel_material(5, 5), Some(MaterialId::STONE));
+        assert_eq!(world.get_pixel_material(6, 5), Some(MaterialId::AIR));
+        assert_eq!(world.get_pixel_material(7, 5), Some(MaterialId::STONE));
+        assert_eq!(predictor.pending().map(|e| e.seq).collect::<Vec<_>>(), [3]);
+
+        // Confirmed once, until the server reports a newer rejection
+        assert_eq!(predictor.confirm_rejections(&[2]), Some(2));
+        assert_eq!(predictor.confirm_rejections(&[2]), None);
+        assert_eq!(predictor.confirm_rejections(&[]), None);
+        assert_eq!(predictor.confirm_rejections(&[2, 3]), Some(3));
+    }
+
+    #[test]
+    fn test_server_changes_win_over_predictions() {
+        let mut world = test_world();
+        let mut predictor = EditPredictor::new();
+        predict(&mut predictor, &mut world, 5, 5, MaterialId::STONE);
+
+        // Someone else filled the pixel with sand first
+        world.set_pixel(5, 5, MaterialId::SAND);
+        assert_eq!(predictor.reapply(&mut world, IVec2::ZERO), 0);
+
+        // Rolling back the rejection leaves their sand alone
+        assert_eq!(predictor.acknowledge(&mut world, 1, &[1]).len(), 1);
+        assert_eq!(world.get_pixel_material(5, 5), Some(MaterialId::SAND));
+    }
+}
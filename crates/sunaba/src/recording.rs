@@ -0,0 +1,616 @@
+//! Input session recording and replay
+//!
+//! Records every fixed tick of player input (plus hotbar and crafting actions) together
+//! with the world seed and starting player state. A recording can be saved as a compact
+//! binary replay (run-length encoded input, bincode payload) or exported as a RON
+//! `ScenarioDefinition` for hand-editing.
+//!
+//! While recording, the simulation RNG is seeded from the recording so that a replay
+//! feeds the same random stream into `World::update`.
+
+use anyhow::{Context, Result, bail};
+use glam::Vec2;
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use sunaba_core::entity::InputState;
+use sunaba_core::entity::crafting::{CraftOutcome, RecipeOutput};
+use sunaba_core::entity::inventory::ItemStack;
+use sunaba_core::world::World;
+
+/// File magic for binary replays
+const REPLAY_MAGIC: &[u8; 4] = b"SNRP";
+
+/// Binary replay format version (bump on incompatible changes)
+const REPLAY_VERSION: u16 = 1;
+
+/// Radius of the right-click mining circle (matches the game loop)
+pub const MINE_RADIUS: i32 = 16;
+
+/// Player input for a single tick
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RecordedInput {
+    pub w: bool,
+    pub a: bool,
+    pub s: bool,
+    pub d: bool,
+    pub jump: bool,
+    pub shift: bool,
+    pub a_double_tap: bool,
+    pub d_double_tap: bool,
+    pub left_mouse: bool,
+    pub right_mouse: bool,
+    pub mouse_world_pos: Option<(i32, i32)>,
+    pub selected_material: u16,
+}
+
+impl RecordedInput {
+    /// Capture the parts of `InputState` that affect the simulation
+    ///
+    /// `pointer_blocked` should be true when the mouse is over UI or an overlay is open,
+    /// since clicks are not applied to the world in that case.
+    pub fn capture(input: &InputState, pointer_blocked: bool) -> Self {
+        Self {
+            w: input.w_pressed,
+            a: input.a_pressed,
+            s: input.s_pressed,
+            d: input.d_pressed,
+            jump: input.jump_pressed,
+            shift: input.shift_pressed,
+            a_double_tap: input.a_double_tap,
+            d_double_tap: input.d_double_tap,
+            left_mouse: input.left_mouse_pressed && !pointer_blocked,
+            right_mouse: input.right_mouse_pressed && !pointer_blocked,
+            mouse_world_pos: input.mouse_world_pos,
+            selected_material: input.selected_material,
+        }
+    }
+
+    /// Convert back into an `InputState` for the player update
+    pub fn to_input_state(&self) -> InputState {
+        InputState {
+            w_pressed: self.w,
+            a_pressed: self.a,
+            s_pressed: self.s,
+            d_pressed: self.d,
+            jump_pressed: self.jump,
+            shift_pressed: self.shift,
+            a_double_tap: self.a_double_tap,
+            d_double_tap: self.d_double_tap,
+            left_mouse_pressed: self.left_mouse,
+            right_mouse_pressed: self.right_mouse,
+            mouse_world_pos: self.mouse_world_pos,
+            selected_material: self.selected_material,
+            ..InputState::default()
+        }
+    }
+
+    /// True if no key or mouse button is held
+    pub fn is_idle(&self) -> bool {
+        !(self.w
+            || self.a
+            || self.s
+            || self.d
+            || self.jump
+            || self.shift
+            || self.left_mouse
+            || self.right_mouse)
+    }
+}
+
+/// Run of identical input ticks
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct InputSpan {
+    pub frames: u32,
+    pub input: RecordedInput,
+}
+
+/// Discrete inventory/crafting action
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RecordedEvent {
+    /// Hotbar slot selected (equips tool if the slot holds one)
+    SelectSlot { slot: usize },
+
+    /// Recipe crafted by name
+    Craft { recipe: String },
+}
+
+/// Event applied before the given tick
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimedEvent {
+    pub frame: u64,
+    pub event: RecordedEvent,
+}
+
+/// A complete recorded play session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputRecording {
+    /// World generator seed
+    pub seed: u64,
+
+    /// Seed for the simulation RNG passed to `World::update`
+    pub rng_seed: u64,
+
+    /// Demo level that was active, if any
+    pub level: Option<usize>,
+
+    /// Player state at recording start
+    pub start_position: Vec2,
+    pub start_health: f32,
+    pub start_hunger: f32,
+    pub start_inventory: Vec<Option<ItemStack>>,
+    pub start_slot: usize,
+
+    /// Placement settings in effect while recording
+    pub brush_size: u32,
+    pub debug_placement: bool,
+
+    /// Run-length encoded input ticks
+    pub spans: Vec<InputSpan>,
+
+    /// Inventory/crafting actions, sorted by frame
+    pub events: Vec<TimedEvent>,
+}
+
+impl InputRecording {
+    /// Total number of recorded ticks
+    pub fn frame_count(&self) -> u64 {
+        self.spans.iter().map(|s| s.frames as u64).sum()
+    }
+
+    /// Iterate over the input of every tick
+    pub fn frames(&self) -> impl Iterator<Item = &RecordedInput> {
+        self.spans
+            .iter()
+            .flat_map(|span| std::iter::repeat_n(&span.input, span.frames as usize))
+    }
+
+    /// Events to apply before the given tick
+    pub fn events_at(&self, frame: u64) -> impl Iterator<Item = &RecordedEvent> {
+        self.events
+            .iter()
+            .filter(move |e| e.frame == frame)
+            .map(|e| &e.event)
+    }
+
+    /// Create the simulation RNG for this recording
+    pub fn rng(&self) -> Xoshiro256PlusPlus {
+        Xoshiro256PlusPlus::seed_from_u64(self.rng_seed)
+    }
+
+    /// Restore seed, level and player state to match the recording start
+    pub fn prepare_world(&self, world: &mut World) {
+        world.set_generator(self.seed);
+
+        if let Some(level_id) = self.level {
+            let mut level_manager = sunaba_core::levels::LevelManager::new();
+            level_manager.load_level(level_id, world);
+        }
+
+        let player = &mut world.player;
+        player.position = self.start_position;
+        player.velocity = Vec2::ZERO;
+        player.health.current = self.start_health;
+        player.hunger.current = self.start_hunger;
+        for (idx, slot) in player.inventory.slots.iter_mut().enumerate() {
+            *slot = self.start_inventory.get(idx).cloned().flatten();
+        }
+        apply_event(
+            world,
+            &RecordedEvent::SelectSlot {
+                slot: self.start_slot,
+            },
+        );
+    }
+
+    /// Encode as a binary replay
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let payload = bincode_next::serde::encode_to_vec(self, bincode_next::config::standard())
+            .context("Failed to encode replay")?;
+
+        let mut bytes = Vec::with_capacity(payload.len() + 6);
+        bytes.extend_from_slice(REPLAY_MAGIC);
+        bytes.extend_from_slice(&REPLAY_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&payload);
+        Ok(bytes)
+    }
+
+    /// Decode a binary replay
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 6 || &bytes[0..4] != REPLAY_MAGIC {
+            bail!("Not a replay file (bad magic)");
+        }
+
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != REPLAY_VERSION {
+            bail!(
+                "Unsupported replay version {} (expected {})",
+                version,
+                REPLAY_VERSION
+            );
+        }
+
+        let (recording, _) =
+            bincode_next::serde::decode_from_slice(&bytes[6..], bincode_next::config::standard())
+                .context("Failed to decode replay")?;
+        Ok(recording)
+    }
+
+    /// Save as a binary replay file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_bytes()?)
+            .with_context(|| format!("Failed to write replay: {}", path.display()))
+    }
+
+    /// Load a binary replay file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)
+            .with_context(|| format!("Failed to read replay: {}", path.display()))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Export as a scenario built from `SimulateKey`/`SimulateMouseClick`/`WaitFrames`
+    ///
+    /// This is an approximation meant for reading and editing: simultaneous keys are
+    /// replayed one after another, and crafting/slot events become log lines. Use the
+    /// binary replay (`PlayReplay` action) for a faithful reproduction.
+    #[cfg(feature = "headless")]
+    pub fn to_scenario(&self, name: impl Into<String>) -> crate::scenario::ScenarioDefinition {
+        use crate::scenario::{
+            MouseButton, ScenarioAction, ScenarioDefinition, SimulatedKey, ValidatedHealth,
+            ValidatedHunger, ValidatedSlotIndex,
+        };
+
+        let mut scenario = ScenarioDefinition::new(name).with_description(format!(
+            "Recorded session: {} frames, seed {}",
+            self.frame_count(),
+            self.seed
+        ));
+
+        scenario
+            .setup
+            .push(ScenarioAction::SetWorldSeed { seed: self.seed });
+        if let Some(level_id) = self.level {
+            scenario.setup.push(ScenarioAction::LoadLevel { level_id });
+        }
+        scenario.setup.push(ScenarioAction::TeleportPlayer {
+            x: self.start_position.x,
+            y: self.start_position.y,
+        });
+        if let Ok(health) = ValidatedHealth::new(self.start_health) {
+            scenario
+                .setup
+                .push(ScenarioAction::SetPlayerHealth { health });
+        }
+        if let Ok(hunger) = ValidatedHunger::new(self.start_hunger) {
+            scenario
+                .setup
+                .push(ScenarioAction::SetPlayerHunger { hunger });
+        }
+        for (idx, item) in self.start_inventory.iter().enumerate() {
+            if let (Some(item), Ok(slot)) = (item, ValidatedSlotIndex::new(idx)) {
+                scenario.setup.push(ScenarioAction::GiveItem {
+                    item: item.clone(),
+                    slot: Some(slot),
+                });
+            }
+        }
+
+        let mut frame: u64 = 0;
+        for span in &self.spans {
+            for event in self.events_at(frame) {
+                scenario.actions.push(ScenarioAction::Log {
+                    message: format!("Recorded event at frame {}: {:?}", frame, event),
+                });
+            }
+
+            let frames = span.frames as usize;
+            let input = &span.input;
+
+            if let Some((world_x, world_y)) = input.mouse_world_pos
+                && (input.left_mouse || input.right_mouse)
+            {
+                scenario.actions.push(ScenarioAction::SimulateMouseClick {
+                    world_x,
+                    world_y,
+                    button: if input.left_mouse {
+                        MouseButton::Left
+                    } else {
+                        MouseButton::Right
+                    },
+                    frames,
+                });
+            }
+
+            let keys = [
+                (input.w, SimulatedKey::W),
+                (input.a, SimulatedKey::A),
+                (input.s, SimulatedKey::S),
+                (input.d, SimulatedKey::D),
+                (input.jump, SimulatedKey::Space),
+            ];
+            for (_, key) in keys.into_iter().filter(|(held, _)| *held) {
+                scenario
+                    .actions
+                    .push(ScenarioAction::SimulateKey { key, frames });
+            }
+
+            scenario.actions.push(ScenarioAction::WaitFrames { frames });
+            frame += span.frames as u64;
+        }
+
+        scenario
+    }
+}
+
+/// Records input ticks and events from the running game
+pub struct InputRecorder {
+    recording: InputRecording,
+    rng: Xoshiro256PlusPlus,
+    frame: u64,
+}
+
+impl InputRecorder {
+    /// Start recording from the current world state
+    pub fn start(
+        world: &World,
+        level: Option<usize>,
+        brush_size: u32,
+        debug_placement: bool,
+    ) -> Self {
+        let recording = InputRecording {
+            seed: world.seed(),
+            rng_seed: rand::random(),
+            level,
+            start_position: world.player.position,
+            start_health: world.player.health.current,
+            start_hunger: world.player.hunger.current,
+            start_inventory: world.player.inventory.slots.clone(),
+            start_slot: world.player.selected_slot,
+            brush_size,
+            debug_placement,
+            spans: Vec::new(),
+            events: Vec::new(),
+        };
+        let rng = recording.rng();
+
+        Self {
+            recording,
+            rng,
+            frame: 0,
+        }
+    }
+
+    /// Record the input of one simulation tick
+    pub fn record_frame(&mut self, input: &InputState, pointer_blocked: bool) {
+        let input = RecordedInput::capture(input, pointer_blocked);
+
+        match self.recording.spans.last_mut() {
+            Some(span) if span.input == input && span.frames < u32::MAX => span.frames += 1,
+            _ => self.recording.spans.push(InputSpan { frames: 1, input }),
+        }
+        self.frame += 1;
+    }
+
+    /// Record an inventory/crafting action (applied before the next tick on replay)
+    pub fn record_event(&mut self, event: RecordedEvent) {
+        self.recording.events.push(TimedEvent {
+            frame: self.frame,
+            event,
+        });
+    }
+
+    /// Simulation RNG to pass to `World::update` while recording
+    pub fn rng(&mut self) -> &mut Xoshiro256PlusPlus {
+        &mut self.rng
+    }
+
+    /// Number of ticks recorded so far
+    pub fn frame_count(&self) -> u64 {
+        self.frame
+    }
+
+    /// Stop recording and return the session
+    pub fn finish(self) -> InputRecording {
+        self.recording
+    }
+}
+
+/// Apply a recorded inventory/crafting action to the world
+///
+/// Mirrors the hotbar and crafting handling of the game loop, without UI feedback.
+pub fn apply_event(world: &mut World, event: &RecordedEvent) -> bool {
+    match event {
+        RecordedEvent::SelectSlot { slot } => {
+            world.player.select_slot(*slot);
+            let tool_id = world
+                .player
+                .inventory
+                .get_slot(*slot)
+                .and_then(|s| s.as_ref())
+                .and_then(|stack| stack.tool_id());
+            match tool_id {
+                Some(tool_id) => world.player.equip_tool(tool_id),
+                None => world.player.unequip_tool(),
+            }
+            true
+        }
+        RecordedEvent::Craft { recipe } => {
+            let Some(recipe) = world
+                .recipe_registry
+                .all_recipes()
+                .iter()
+                .find(|r| r.name == *recipe)
+                .cloned()
+            else {
+                return false;
+            };
+
+            match world.craft_recipe(&recipe) {
+                CraftOutcome::Crafted(RecipeOutput::Material { id, count }) => {
+                    world.player.inventory.add_item(id, count);
+                    true
+                }
+                CraftOutcome::Crafted(RecipeOutput::Tool {
+                    tool_id,
+                    durability,
+                }) => world.player.inventory.add_tool(tool_id, durability),
+                CraftOutcome::Crafted(RecipeOutput::Container { container }) => {
+                    world.player.inventory.add_container(container)
+                }
+                CraftOutcome::Smelting { .. } => true,
+                CraftOutcome::MissingMaterials
+                | CraftOutcome::MissingWorkstation(_)
+                | CraftOutcome::MissingFuel => false,
+            }
+        }
+    }
+}
+
+/// Apply one tick of recorded input to the player (movement, dash, mining, placing)
+///
+/// Mirrors the input handling of the game loop, without particles or camera effects.
+/// Does not advance the world simulation.
+pub fn apply_input(
+    world: &mut World,
+    input: &RecordedInput,
+    brush_size: u32,
+    debug_placement: bool,
+) {
+    let input_state = input.to_input_state();
+    world.update_player(&input_state, 1.0 / 60.0);
+
+    // Dash: double-tap A/D or Shift+direction
+    let mut dash_dir = Vec2::ZERO;
+    if input.a {
+        dash_dir.x -= 1.0;
+    }
+    if input.d {
+        dash_dir.x += 1.0;
+    }
+    if input.w {
+        dash_dir.y += 1.0;
+    }
+    if input.s {
+        dash_dir.y -= 1.0;
+    }
+    let dash_triggered = (input.a_double_tap && input.a)
+        || (input.d_double_tap && input.d)
+        || (input.shift && dash_dir.x.abs() > 0.0);
+    if dash_triggered {
+        let grounded = world.player.grounded;
+        world.player.start_dash(dash_dir, grounded);
+    }
+
+    if input.right_mouse {
+        let pos = world.player.position;
+        world.debug_mine_circle(pos.x as i32, pos.y as i32, MINE_RADIUS);
+    }
+
+    if input.left_mouse
+        && let Some((wx, wy)) = input.mouse_world_pos
+    {
+        if world.player.equipped_tool.is_some() {
+            world.use_equipped_tool(wx, wy, 1.0 / 60.0);
+        } else if world.player.get_selected_container().is_some() {
+            world.use_selected_container(wx, wy);
+        } else if debug_placement {
+            world.place_material_debug(wx, wy, input.selected_material, brush_size);
+        } else {
+            world.place_material_from_inventory(wx, wy, input.selected_material, brush_size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn held(d: bool) -> InputState {
+        InputState {
+            d_pressed: d,
+            ..InputState::default()
+        }
+    }
+
+    #[test]
+    fn test_recorder_run_length_encodes() {
+        let world = World::new(true);
+        let mut recorder = InputRecorder::start(&world, None, 1, true);
+
+        for _ in 0..10 {
+            recorder.record_frame(&held(false), false);
+        }
+        for _ in 0..5 {
+            recorder.record_frame(&held(true), false);
+        }
+        recorder.record_event(RecordedEvent::SelectSlot { slot: 2 });
+        recorder.record_frame(&held(false), false);
+
+        let recording = recorder.finish();
+        assert_eq!(recording.spans.len(), 3);
+        assert_eq!(recording.spans[0].frames, 10);
+        assert_eq!(recording.spans[1].frames, 5);
+        assert!(recording.spans[1].input.d);
+        assert_eq!(recording.frame_count(), 16);
+        assert_eq!(recording.frames().count(), 16);
+        assert_eq!(recording.events_at(15).count(), 1);
+    }
+
+    #[test]
+    fn test_pointer_blocked_drops_clicks() {
+        let input = InputState {
+            left_mouse_pressed: true,
+            mouse_world_pos: Some((3, 4)),
+            ..InputState::default()
+        };
+
+        assert!(RecordedInput::capture(&input, false).left_mouse);
+        assert!(!RecordedInput::capture(&input, true).left_mouse);
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let world = World::new(true);
+        let mut recorder = InputRecorder::start(&world, Some(1), 3, false);
+        recorder.record_frame(&held(true), false);
+        recorder.record_event(RecordedEvent::Craft {
+            recipe: "Wood Pickaxe".to_string(),
+        });
+        let recording = recorder.finish();
+
+        let bytes = recording.to_bytes().unwrap();
+        assert_eq!(&bytes[0..4], REPLAY_MAGIC);
+
+        let decoded = InputRecording::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.seed, recording.seed);
+        assert_eq!(decoded.rng_seed, recording.rng_seed);
+        assert_eq!(decoded.level, Some(1));
+        assert_eq!(decoded.spans, recording.spans);
+        assert_eq!(decoded.events, recording.events);
+
+        assert!(InputRecording::from_bytes(b"nope").is_err());
+    }
+
+    #[test]
+    fn test_apply_input_moves_player() {
+        let mut world = World::new(true);
+        let start = world.player.position;
+
+        let input = RecordedInput {
+            d: true,
+            ..RecordedInput::default()
+        };
+        for _ in 0..30 {
+            apply_input(&mut world, &input, 1, true);
+        }
+
+        assert!(world.player.position.x > start.x);
+    }
+}
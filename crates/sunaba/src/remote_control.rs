@@ -13,21 +13,53 @@
 //! ```bash
 //! echo '(type: "MineCircle", center_x: 0, center_y: 50, radius: 10)' | nc localhost 7453
 //! ```
+//!
+//! Read game state (result is in the response's `data` field):
+//! ```bash
+//! echo '(type: "GetPlayer")' | nc localhost 7453
+//! echo '(type: "GetRegion", min_x: -8, min_y: 40, max_x: 8, max_y: 56)' | nc localhost 7453
+//! echo '(type: "Evaluate", condition: (type: "PlayerGrounded", expected: true))' | nc localhost 7453
+//! ```
+//!
+//! Subscribe to per-tick updates (one JSON or RON line per matching tick,
+//! until the client disconnects):
+//! ```bash
+//! echo '(type: "Subscribe", queries: [(type: "GetPlayer")], every_n_ticks: Some(10))' | nc localhost 7453
+//! ```
 
-use crate::scenario::ScenarioAction;
+use crate::scenario::{ScenarioAction, VerificationCondition};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::io::{BufRead, BufReader, Write};
 use std::net::{TcpListener, TcpStream};
 use std::sync::mpsc::{self, Receiver, Sender};
 use std::thread;
+use sunaba_core::entity::inventory::ItemStack;
+use sunaba_core::world::World;
 
 const TCP_PORT: u16 = 7453;
 
+/// Largest area a single `GetRegion` query may cover (in pixels)
+pub const MAX_REGION_PIXELS: i64 = 256 * 256;
+
+/// Material id reported for pixels in chunks that are not loaded
+pub const UNLOADED_MATERIAL: u16 = u16::MAX;
+
 /// Command sent from TCP client to game
 #[derive(Debug, Clone)]
 pub struct RemoteCommand {
-    pub action: ScenarioAction,
+    pub request: RemoteRequest,
+}
+
+/// What the client asked the game to do
+#[derive(Debug, Clone)]
+pub enum RemoteRequest {
+    /// Mutate the world (same actions as scenarios)
+    Action(ScenarioAction),
+    /// Read a piece of game state
+    Query(RemoteQuery),
+    /// Stream query results every N ticks
+    Subscribe(Subscription),
 }
 
 /// Response sent from game to TCP client
@@ -35,6 +67,416 @@ pub struct RemoteCommand {
 pub struct RemoteResponse {
     pub success: bool,
     pub message: String,
+    /// Query result (only present for queries)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<QueryResult>,
+}
+
+impl RemoteResponse {
+    pub fn ok(message: impl Into<String>) -> Self {
+        Self {
+            success: true,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            message: message.into(),
+            data: None,
+        }
+    }
+}
+
+/// Read-only query against live game state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum RemoteQuery {
+    /// Material and flags of a single pixel
+    GetPixel { x: i32, y: i32 },
+
+    /// Material ids of a rectangular region (inclusive bounds)
+    GetRegion {
+        min_x: i32,
+        min_y: i32,
+        max_x: i32,
+        max_y: i32,
+    },
+
+    /// Temperature (°C) at a pixel
+    GetTemperature { x: i32, y: i32 },
+
+    /// Light level (0-15) at a pixel
+    GetLight { x: i32, y: i32 },
+
+    /// Gas pressure at a pixel
+    GetPressure { x: i32, y: i32 },
+
+    /// Player position, velocity and vitals
+    GetPlayer,
+
+    /// Player inventory slots
+    GetInventory,
+
+    /// All creatures with positions and needs
+    GetCreatures,
+
+    /// Evaluate a scenario verification condition against the live world
+    Evaluate { condition: VerificationCondition },
+}
+
+impl RemoteQuery {
+    /// Type tags handled as queries (used to route incoming lines)
+    const TYPES: &'static [&'static str] = &[
+        "GetPixel",
+        "GetRegion",
+        "GetTemperature",
+        "GetLight",
+        "GetPressure",
+        "GetPlayer",
+        "GetInventory",
+        "GetCreatures",
+        "Evaluate",
+    ];
+}
+
+/// Result of a `RemoteQuery`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum QueryResult {
+    Pixel {
+        x: i32,
+        y: i32,
+        /// `None` if the chunk is not loaded
+        material_id: Option<u16>,
+        material: Option<String>,
+        flags: u16,
+    },
+    /// Row-major material ids, bottom row (`min_y`) first
+    Region {
+        min_x: i32,
+        min_y: i32,
+        width: u32,
+        height: u32,
+        materials: Vec<u16>,
+    },
+    Temperature {
+        x: i32,
+        y: i32,
+        celsius: f32,
+    },
+    Light {
+        x: i32,
+        y: i32,
+        level: Option<u8>,
+    },
+    Pressure {
+        x: i32,
+        y: i32,
+        pressure: Option<f32>,
+    },
+    Player {
+        x: f32,
+        y: f32,
+        velocity_x: f32,
+        velocity_y: f32,
+        health: f32,
+        max_health: f32,
+        hunger: f32,
+        max_hunger: f32,
+        grounded: bool,
+        is_dead: bool,
+        selected_slot: usize,
+        equipped_tool: Option<u16>,
+    },
+    Inventory {
+        selected_slot: usize,
+        max_slots: usize,
+        slots: Vec<Option<ItemStack>>,
+    },
+    Creatures {
+        creatures: Vec<CreatureInfo>,
+    },
+    Verification {
+        passed: bool,
+        message: String,
+        actual_value: Option<String>,
+    },
+}
+
+/// Snapshot of one creature for `GetCreatures`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatureInfo {
+    pub id: u64,
+    pub x: f32,
+    pub y: f32,
+    pub health: f32,
+    pub max_health: f32,
+    pub hunger: f32,
+    pub max_hunger: f32,
+    /// Behavior needs (0.0-1.0)
+    pub need_hunger: f32,
+    pub threat_level: f32,
+    pub energy: f32,
+    pub generation: u64,
+    pub action: Option<String>,
+}
+
+/// Evaluate a query against the world
+pub fn run_query(world: &World, query: &RemoteQuery) -> Result<QueryResult, String> {
+    let result = match query {
+        RemoteQuery::GetPixel { x, y } => {
+            let pixel = world.get_pixel(*x, *y);
+            QueryResult::Pixel {
+                x: *x,
+                y: *y,
+                material_id: pixel.map(|p| p.material_id),
+                material: pixel.map(|p| world.materials().get(p.material_id).name.clone()),
+                flags: pixel.map(|p| p.flags).unwrap_or(0),
+            }
+        }
+        RemoteQuery::GetRegion {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        } => {
+            if max_x < min_x || max_y < min_y {
+                return Err("Region max must be >= min".to_string());
+            }
+            let width = (*max_x as i64 - *min_x as i64) + 1;
+            let height = (*max_y as i64 - *min_y as i64) + 1;
+            if width * height > MAX_REGION_PIXELS {
+                return Err(format!(
+                    "Region too large: {}x{} (max {} pixels)",
+                    width, height, MAX_REGION_PIXELS
+                ));
+            }
+            let mut materials = Vec::with_capacity((width * height) as usize);
+            for y in *min_y..=*max_y {
+                for x in *min_x..=*max_x {
+                    materials.push(
+                        world
+                            .get_pixel(x, y)
+                            .map(|p| p.material_id)
+                            .unwrap_or(UNLOADED_MATERIAL),
+                    );
+                }
+            }
+            QueryResult::Region {
+                min_x: *min_x,
+                min_y: *min_y,
+                width: width as u32,
+                height: height as u32,
+                materials,
+            }
+        }
+        RemoteQuery::GetTemperature { x, y } => QueryResult::Temperature {
+            x: *x,
+            y: *y,
+            celsius: world.get_temperature_at_pixel(*x, *y),
+        },
+        RemoteQuery::GetLight { x, y } => QueryResult::Light {
+            x: *x,
+            y: *y,
+            level: world.get_light_at(*x, *y),
+        },
+        RemoteQuery::GetPressure { x, y } => QueryResult::Pressure {
+            x: *x,
+            y: *y,
+            pressure: world.get_pressure_at(*x, *y),
+        },
+        RemoteQuery::GetPlayer => {
+            let player = &world.player;
+            QueryResult::Player {
+                x: player.position.x,
+                y: player.position.y,
+                velocity_x: player.velocity.x,
+                velocity_y: player.velocity.y,
+                health: player.health.current,
+                max_health: player.health.max,
+                hunger: player.hunger.current,
+                max_hunger: player.hunger.max,
+                grounded: player.grounded,
+                is_dead: player.is_dead,
+                selected_slot: player.selected_slot,
+                equipped_tool: player.equipped_tool,
+            }
+        }
+        RemoteQuery::GetInventory => {
+            let player = &world.player;
+            QueryResult::Inventory {
+                selected_slot: player.selected_slot,
+                max_slots: player.inventory.max_slots,
+                slots: player.inventory.slots.clone(),
+            }
+        }
+        RemoteQuery::GetCreatures => {
+            let mut creatures: Vec<CreatureInfo> = world
+                .creature_manager
+                .iter()
+                .map(|c| CreatureInfo {
+                    id: c.id.raw(),
+                    x: c.position.x,
+                    y: c.position.y,
+                    health: c.health.current,
+                    max_health: c.health.max,
+                    hunger: c.hunger.current,
+                    max_hunger: c.hunger.max,
+                    need_hunger: c.needs.hunger,
+                    threat_level: c.needs.threat_level,
+                    energy: c.needs.energy,
+                    generation: c.generation,
+                    action: c.current_action.as_ref().map(|a| format!("{:?}", a)),
+                })
+                .collect();
+            // Stable order for clients diffing successive responses
+            creatures.sort_by_key(|c| c.id);
+            QueryResult::Creatures { creatures }
+        }
+        RemoteQuery::Evaluate { condition } => {
+            let result = condition.evaluate(world);
+            QueryResult::Verification {
+                passed: result.passed,
+                message: result.message,
+                actual_value: result.actual_value,
+            }
+        }
+    };
+    Ok(result)
+}
+
+/// Line encoding for subscription streams
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StreamFormat {
+    #[default]
+    Json,
+    Ron,
+}
+
+/// Subscribe request as sent by the client
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscribeRequest {
+    pub queries: Vec<RemoteQuery>,
+    /// Emit every N ticks (default: every tick)
+    #[serde(default)]
+    pub every_n_ticks: Option<u32>,
+    #[serde(default)]
+    pub format: Option<StreamFormat>,
+}
+
+/// One streamed line: results of all subscribed queries for a tick
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TickEvent {
+    pub tick: u64,
+    pub results: Vec<Result<QueryResult, String>>,
+}
+
+/// Active subscription owned by the game loop
+#[derive(Debug, Clone)]
+pub struct Subscription {
+    pub request: SubscribeRequest,
+    /// Encoded lines are sent here and written to the socket by a writer thread
+    pub sender: Sender<String>,
+}
+
+/// All active subscriptions; published once per simulation tick
+#[derive(Debug, Default)]
+pub struct Subscriptions {
+    active: Vec<Subscription>,
+    tick: u64,
+}
+
+impl Subscriptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, subscription: Subscription) {
+        self.active.push(subscription);
+    }
+
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    /// Advance the tick counter and stream results to due subscribers.
+    /// Subscribers whose connection has closed are dropped.
+    pub fn publish(&mut self, world: &World) {
+        self.tick += 1;
+        let tick = self.tick;
+
+        self.active.retain(|sub| {
+            let every = sub.request.every_n_ticks.unwrap_or(1).max(1) as u64;
+            if !tick.is_multiple_of(every) {
+                return true;
+            }
+
+            let event = TickEvent {
+                tick,
+                results: sub
+                    .request
+                    .queries
+                    .iter()
+                    .map(|q| run_query(world, q))
+                    .collect(),
+            };
+            let line = match sub.request.format.unwrap_or_default() {
+                StreamFormat::Json => serde_json::to_string(&event).map_err(|e| e.to_string()),
+                StreamFormat::Ron => ron::to_string(&event).map_err(|e| e.to_string()),
+            };
+            match line {
+                Ok(line) => sub.sender.send(line).is_ok(),
+                Err(e) => {
+                    log::warn!("Failed to encode subscription event: {}", e);
+                    true
+                }
+            }
+        });
+    }
+}
+
+/// Parsed client line, before it is handed to the game loop
+#[derive(Debug)]
+enum IncomingRequest {
+    Action(ScenarioAction),
+    Query(RemoteQuery),
+    Subscribe(SubscribeRequest),
+}
+
+/// Only the `type` tag of an incoming line (other fields ignored)
+#[derive(Deserialize)]
+struct TypeTag {
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+/// Route a RON line to query, subscribe or scenario action parsing
+fn parse_request(line: &str) -> Result<IncomingRequest, String> {
+    let kind = ron::from_str::<TypeTag>(line).map(|t| t.kind).ok();
+
+    match kind.as_deref() {
+        Some(kind) if RemoteQuery::TYPES.contains(&kind) => ron::from_str(line)
+            .map(IncomingRequest::Query)
+            .map_err(|e| format!("Failed to parse query: {}", e)),
+        Some("Subscribe") => {
+            let request: SubscribeRequest =
+                ron::from_str(line).map_err(|e| format!("Failed to parse subscribe: {}", e))?;
+            if request.queries.is_empty() {
+                return Err("Subscribe requires at least one query".to_string());
+            }
+            Ok(IncomingRequest::Subscribe(request))
+        }
+        _ => ron::from_str(line)
+            .map(IncomingRequest::Action)
+            .map_err(|e| format!("Failed to parse RON: {}", e)),
+    }
 }
 
 /// Start TCP server in background thread
@@ -106,39 +548,209 @@ fn handle_client(
 
     log::debug!("Received command: {}", line);
 
-    // Parse RON command
-    let action: ScenarioAction = match ron::from_str(line) {
-        Ok(action) => action,
-        Err(e) => {
-            let error_resp = RemoteResponse {
-                success: false,
-                message: format!("Failed to parse RON: {}", e),
-            };
-            let json = serde_json::to_string(&error_resp)?;
+    let request = match parse_request(line) {
+        Ok(IncomingRequest::Action(action)) => RemoteRequest::Action(action),
+        Ok(IncomingRequest::Query(query)) => RemoteRequest::Query(query),
+        Ok(IncomingRequest::Subscribe(request)) => {
+            return start_subscription(stream, request, cmd_tx, resp_rx);
+        }
+        Err(message) => {
+            let json = serde_json::to_string(&RemoteResponse::error(message))?;
             writeln!(stream, "{}", json)?;
             return Ok(());
         }
     };
 
     // Send command to game loop
-    cmd_tx.send(RemoteCommand { action })?;
+    cmd_tx.send(RemoteCommand { request })?;
 
-    // Wait for response from game loop (with timeout)
-    match resp_rx.recv_timeout(std::time::Duration::from_secs(5)) {
-        Ok(response) => {
-            let json = serde_json::to_string(&response)?;
-            writeln!(stream, "{}", json)?;
-            log::debug!("Sent response: {}", json);
-        }
-        Err(_) => {
-            let error_resp = RemoteResponse {
-                success: false,
-                message: "Timeout waiting for game response".to_string(),
-            };
-            let json = serde_json::to_string(&error_resp)?;
-            writeln!(stream, "{}", json)?;
-        }
+    let response = await_response(resp_rx);
+    let json = serde_json::to_string(&response)?;
+    writeln!(stream, "{}", json)?;
+    log::debug!("Sent response: {}", json);
+
+    Ok(())
+}
+
+/// Wait for the game loop to answer the last command (with timeout)
+fn await_response(resp_rx: &Receiver<RemoteResponse>) -> RemoteResponse {
+    resp_rx
+        .recv_timeout(std::time::Duration::from_secs(5))
+        .unwrap_or_else(|_| RemoteResponse::error("Timeout waiting for game response"))
+}
+
+/// Register a subscription with the game loop and hand the connection to a
+/// writer thread, so the accept loop can keep serving other clients
+fn start_subscription(
+    mut stream: TcpStream,
+    request: SubscribeRequest,
+    cmd_tx: &Sender<RemoteCommand>,
+    resp_rx: &Receiver<RemoteResponse>,
+) -> Result<()> {
+    let (line_tx, line_rx) = mpsc::channel::<String>();
+    cmd_tx.send(RemoteCommand {
+        request: RemoteRequest::Subscribe(Subscription {
+            request,
+            sender: line_tx,
+        }),
+    })?;
+
+    let response = await_response(resp_rx);
+    writeln!(stream, "{}", serde_json::to_string(&response)?)?;
+    if !response.success {
+        return Ok(());
     }
 
+    thread::spawn(move || {
+        // Ends when the client disconnects (write fails) or the game drops
+        // the subscription; dropping line_rx then unsubscribes on next publish
+        for line in line_rx {
+            if writeln!(stream, "{}", line).is_err() {
+                break;
+            }
+        }
+    });
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sunaba_core::simulation::MaterialId;
+
+    fn test_world() -> World {
+        let mut world = World::new(true);
+        world.ensure_chunks_for_area(-8, -8, 8, 8);
+        world
+    }
+
+    #[test]
+    fn test_parse_routes_queries_actions_and_subscribe() {
+        assert!(matches!(
+            parse_request(r#"(type: "GetPlayer")"#),
+            Ok(IncomingRequest::Query(RemoteQuery::GetPlayer))
+        ));
+        assert!(matches!(
+            parse_request(r#"(type: "TeleportPlayer", x: 0.0, y: 10.0)"#),
+            Ok(IncomingRequest::Action(
+                ScenarioAction::TeleportPlayer { .. }
+            ))
+        ));
+        assert!(matches!(
+            parse_request(
+                r#"(type: "Evaluate", condition: (type: "RegionEmpty", region: (type: "Whole")))"#
+            ),
+            Ok(IncomingRequest::Query(RemoteQuery::Evaluate { .. }))
+        ));
+
+        let Ok(IncomingRequest::Subscribe(sub)) = parse_request(
+            r#"(type: "Subscribe", queries: [(type: "GetPlayer")], every_n_ticks: Some(5), format: Some(Ron))"#,
+        ) else {
+            panic!("expected subscribe");
+        };
+        assert_eq!(sub.every_n_ticks, Some(5));
+        assert_eq!(sub.format, Some(StreamFormat::Ron));
+    }
+
+    #[test]
+    fn test_parse_errors_are_specific() {
+        let err = parse_request(r#"(type: "GetPixel", x: 1)"#).unwrap_err();
+        assert!(err.contains("query"), "{}", err);
+
+        let err = parse_request(r#"(type: "Subscribe", queries: [])"#).unwrap_err();
+        assert!(err.contains("at least one"), "{}", err);
+
+        assert!(parse_request("not ron").is_err());
+    }
+
+    #[test]
+    fn test_pixel_and_region_queries() {
+        let mut world = test_world();
+        world.set_pixel(2, 3, MaterialId::STONE);
+
+        let Ok(QueryResult::Pixel {
+            material_id,
+            material,
+            ..
+        }) = run_query(&world, &RemoteQuery::GetPixel { x: 2, y: 3 })
+        else {
+            panic!("expected pixel result");
+        };
+        assert_eq!(material_id, Some(MaterialId::STONE));
+        assert_eq!(material.as_deref(), Some("stone"));
+
+        let Ok(QueryResult::Region {
+            width,
+            height,
+            materials,
+            ..
+        }) = run_query(
+            &world,
+            &RemoteQuery::GetRegion {
+                min_x: 1,
+                min_y: 3,
+                max_x: 3,
+                max_y: 4,
+            },
+        )
+        else {
+            panic!("expected region result");
+        };
+        assert_eq!((width, height), (3, 2));
+        assert_eq!(materials[1], MaterialId::STONE);
+
+        let too_big = RemoteQuery::GetRegion {
+            min_x: 0,
+            min_y: 0,
+            max_x: 1000,
+            max_y: 1000,
+        };
+        assert!(run_query(&world, &too_big).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_condition() {
+        let mut world = test_world();
+        world.set_pixel(0, 0, MaterialId::SAND);
+
+        let Ok(IncomingRequest::Query(query)) = parse_request(
+            r#"(type: "Evaluate", condition: (type: "MaterialAt", x: 0, y: 0, expected: "sand"))"#,
+        ) else {
+            panic!("expected query");
+        };
+        let Ok(QueryResult::Verification { passed, .. }) = run_query(&world, &query) else {
+            panic!("expected verification result");
+        };
+        assert!(passed);
+    }
+
+    #[test]
+    fn test_subscription_publishes_every_n_ticks_and_drops_closed() {
+        let world = test_world();
+        let mut subs = Subscriptions::new();
+        let (tx, rx) = mpsc::channel();
+        subs.add(Subscription {
+            request: SubscribeRequest {
+                queries: vec![RemoteQuery::GetPlayer],
+                every_n_ticks: Some(2),
+                format: None,
+            },
+            sender: tx,
+        });
+
+        for _ in 0..4 {
+            subs.publish(&world);
+        }
+        let lines: Vec<String> = rx.try_iter().collect();
+        assert_eq!(lines.len(), 2);
+        let event: TickEvent = serde_json::from_str(&lines[0]).unwrap();
+        assert_eq!(event.tick, 2);
+        assert!(matches!(event.results[0], Ok(QueryResult::Player { .. })));
+
+        drop(rx);
+        subs.publish(&world);
+        subs.publish(&world);
+        assert!(subs.is_empty());
+    }
+}
@@ -69,6 +69,17 @@ impl ParticleSystem {
         self.particles.retain(|p| p.is_alive());
     }
 
+    /// Drag particles towards the wind velocity (wind at the camera, in pixels/second)
+    pub fn apply_wind(&mut self, wind: Vec2, dt: f32) {
+        const WIND_DRAG: f32 = 2.0;
+        if wind == Vec2::ZERO {
+            return;
+        }
+        for particle in &mut self.particles {
+            particle.velocity.x += (wind.x - particle.velocity.x) * (WIND_DRAG * dt).min(1.0);
+        }
+    }
+
     /// Spawn a single particle
     pub fn spawn(&mut self, position: Vec2, velocity: Vec2, color: [u8; 4], lifetime: f32) {
         // If at capacity, remove oldest particle
@@ -106,6 +106,9 @@ pub enum ScenarioAction {
     /// Simulate mouse movement to world coordinates
     SimulateMouseMove { world_x: i32, world_y: i32 },
 
+    /// Play back a recorded input session (binary replay file)
+    PlayReplay { path: String },
+
     // === CONTROL FLOW ===
     /// Wait for N simulation frames
     WaitFrames { frames: usize },
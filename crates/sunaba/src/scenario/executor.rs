@@ -6,12 +6,14 @@ use rand::thread_rng;
 use sunaba_core::entity::InputState;
 use sunaba_core::entity::inventory::ItemStack;
 use sunaba_core::simulation::MaterialId;
-use sunaba_core::world::{NoopStats, World};
+use sunaba_core::world::{NoopStats, World, WorldRng};
 
 use super::actions::{MouseButton, ScenarioAction};
 use super::definition::ScenarioDefinition;
 use super::results::ExecutionReport;
+use super::snapshot::SnapshotConfig;
 use super::verification::VerificationCondition;
+use crate::recording::{InputRecording, apply_event, apply_input};
 
 /// Configuration for scenario executor
 #[derive(Debug, Clone)]
@@ -27,6 +29,9 @@ pub struct ScenarioExecutorConfig {
 
     /// Enable detailed profiling (tracing)
     pub detailed_profiling: bool,
+
+    /// Golden-image snapshot settings (baseline dir, update mode)
+    pub snapshots: SnapshotConfig,
 }
 
 impl Default for ScenarioExecutorConfig {
@@ -36,6 +41,7 @@ impl Default for ScenarioExecutorConfig {
             screenshot_dir: "screenshots".to_string(),
             verbose: false,
             detailed_profiling: false,
+            snapshots: SnapshotConfig::default(),
         }
     }
 }
@@ -140,7 +146,7 @@ impl ScenarioExecutor {
         if !scenario.verify.is_empty() {
             self.log(&format!("Running {} verifications", scenario.verify.len()));
             for condition in &scenario.verify {
-                let result = condition.evaluate(world);
+                let result = condition.evaluate_with(world, &self.config.snapshots);
                 self.log(&format!(
                     "  {} {}",
                     if result.passed { "✓" } else { "✗" },
@@ -324,6 +330,12 @@ impl ScenarioExecutor {
                         ItemStack::Tool { .. } => {
                             bail!("Auto-placement of tools not yet implemented - specify a slot");
                         }
+                        ItemStack::Container { container, .. } => {
+                            if !world.player.inventory.add_container(*container) {
+                                bail!("No empty slot for {}", container.name());
+                            }
+                            self.log(&format!("  Gave {} to inventory", container.name()));
+                        }
                     }
                 }
             }
@@ -346,6 +358,11 @@ impl ScenarioExecutor {
                 self.log(&format!("  Set player hunger to {}", hunger.get()));
             }
 
+            ScenarioAction::SetWorldSeed { seed } => {
+                world.set_generator(*seed);
+                self.log(&format!("  Set world seed to {}", seed));
+            }
+
             ScenarioAction::LoadLevel { level_id } => {
                 use crate::levels::LevelManager;
                 let mut level_manager = LevelManager::new();
@@ -372,6 +389,11 @@ impl ScenarioExecutor {
                 self.log(&format!("  Moved mouse to ({}, {})", world_x, world_y));
             }
 
+            ScenarioAction::PlayReplay { path } => {
+                let recording = InputRecording::load(path)?;
+                self.play_recording(&recording, world)?;
+            }
+
             // Control flow
             ScenarioAction::WaitFrames { frames } => {
                 self.simulate_frames(world, *frames)?;
@@ -585,7 +607,7 @@ impl ScenarioExecutor {
         timeout_frames: usize,
     ) -> Result<()> {
         for frame in 0..timeout_frames {
-            let result = condition.evaluate(world);
+            let result = condition.evaluate_with(world, &self.config.snapshots);
 
             if result.passed {
                 self.log(&format!(
@@ -599,7 +621,7 @@ impl ScenarioExecutor {
             self.simulate_frames(world, 1)?;
         }
 
-        let result = condition.evaluate(world);
+        let result = condition.evaluate_with(world, &self.config.snapshots);
         bail!(
             "WaitUntil timed out after {} frames: {}",
             timeout_frames,
@@ -609,32 +631,71 @@ impl ScenarioExecutor {
 
     /// Simulate N frames of physics
     fn simulate_frames(&mut self, world: &mut World, frames: usize) -> Result<()> {
-        use std::time::Instant;
-
         #[cfg(feature = "detailed_profiling")]
         let _span = tracing::info_span!("simulate_frames", frames).entered();
 
-        let mut stats = NoopStats;
         let mut rng = thread_rng();
 
         for _ in 0..frames {
-            let frame_start = Instant::now();
+            self.step_world(world, &mut rng);
+        }
+
+        Ok(())
+    }
 
-            #[cfg(feature = "detailed_profiling")]
-            let _frame_span = tracing::info_span!("world_update").entered();
+    /// Advance the world by one frame and record timing
+    fn step_world<R: WorldRng>(&mut self, world: &mut World, rng: &mut R) {
+        use std::time::Instant;
 
-            world.update(1.0 / 60.0, &mut stats, &mut rng, false);
+        let mut stats = NoopStats;
+        let frame_start = Instant::now();
 
-            #[cfg(feature = "detailed_profiling")]
-            drop(_frame_span);
+        #[cfg(feature = "detailed_profiling")]
+        let _frame_span = tracing::info_span!("world_update").entered();
 
-            let frame_time = frame_start.elapsed().as_secs_f64() * 1000.0;
+        world.update(1.0 / 60.0, &mut stats, rng, false);
 
-            self.frame_times.push(frame_time);
-            self.frame_count += 1;
-            self.update_count += 1;
+        #[cfg(feature = "detailed_profiling")]
+        drop(_frame_span);
+
+        let frame_time = frame_start.elapsed().as_secs_f64() * 1000.0;
+
+        self.frame_times.push(frame_time);
+        self.frame_count += 1;
+        self.update_count += 1;
+    }
+
+    /// Play back a recorded input session frame by frame
+    pub fn play_recording(&mut self, recording: &InputRecording, world: &mut World) -> Result<()> {
+        recording.prepare_world(world);
+        let mut rng = recording.rng();
+        let mut events = recording.events.iter().peekable();
+
+        for (frame, input) in recording.frames().enumerate() {
+            while let Some(timed) = events.next_if(|e| e.frame <= frame as u64) {
+                if !apply_event(world, &timed.event) {
+                    self.log(&format!(
+                        "  Warning: replay event at frame {} had no effect: {:?}",
+                        timed.frame, timed.event
+                    ));
+                }
+            }
+
+            apply_input(
+                world,
+                input,
+                recording.brush_size,
+                recording.debug_placement,
+            );
+            self.step_world(world, &mut rng);
         }
 
+        self.log(&format!(
+            "  Replayed {} frames ({} events, seed {})",
+            recording.frame_count(),
+            recording.events.len(),
+            recording.seed
+        ));
         Ok(())
     }
 
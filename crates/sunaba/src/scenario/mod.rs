@@ -4,7 +4,9 @@
 //! and remote control of the game. It enables:
 //! - High-level commands (MovePlayerTo, MineCircle, PlaceMaterial)
 //! - Low-level input simulation (SimulateKey, SimulateMouseClick)
+//! - Replay of recorded play sessions (PlayReplay, see `crate::recording`)
 //! - State verification (MaterialCount, PlayerPosition, etc.)
+//! - Golden-image snapshots (MatchesSnapshot, re-blessed with `--update-snapshots`)
 //! - Headless execution with JSON results
 //!
 //! ## Example Usage
@@ -33,6 +35,7 @@ pub mod actions;
 pub mod definition;
 pub mod executor;
 pub mod results;
+pub mod snapshot;
 pub mod validated_types;
 pub mod verification;
 
@@ -40,6 +43,7 @@ pub use actions::{MouseButton, ScenarioAction};
 pub use definition::ScenarioDefinition;
 pub use executor::{ScenarioExecutor, ScenarioExecutorConfig};
 pub use results::ExecutionReport;
+pub use snapshot::SnapshotConfig;
 pub use validated_types::{
     CreatureArchetype, SimulatedKey, ValidatedHealth, ValidatedHunger, ValidatedMaterialId,
     ValidatedRadius, ValidatedSlotIndex,
@@ -0,0 +1,432 @@
+//! Golden-image snapshot comparison for scenarios
+//!
+//! Renders a world region with the CPU `PixelRenderer` and compares it against a
+//! stored baseline PNG. Pixel differences are measured in YIQ space (as in
+//! pixelmatch) so that small perceptual shifts from texture variation don't count,
+//! while real changes like missing or moved material do.
+//!
+//! Baselines live under `scenarios/snapshots/` by default. Run a scenario with
+//! `--update-snapshots` to (re-)bless them.
+
+use std::path::{Path, PathBuf};
+
+use glam::Vec2;
+use image::{ImageBuffer, Rgba, RgbaImage};
+use sunaba_core::world::World;
+
+use super::verification::{Region, VerificationResult};
+use crate::headless::PixelRenderer;
+
+/// Maximum possible YIQ delta between two colors
+const MAX_YIQ_DELTA: f32 = 35215.0;
+
+/// Default per-pixel perceptual threshold (0.0-1.0)
+pub const DEFAULT_SNAPSHOT_TOLERANCE: f32 = 0.1;
+
+/// Largest region that can be snapshotted (per axis, in pixels)
+const MAX_SNAPSHOT_SIZE: i32 = 2048;
+
+/// Where baselines are read from and how failures are reported
+#[derive(Debug, Clone)]
+pub struct SnapshotConfig {
+    /// Directory containing baseline PNGs
+    pub snapshot_dir: String,
+
+    /// Directory for actual/diff images of failed comparisons
+    pub diff_dir: String,
+
+    /// Overwrite baselines with the current render instead of comparing
+    pub update: bool,
+}
+
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        Self {
+            snapshot_dir: "scenarios/snapshots".to_string(),
+            diff_dir: "scenario_results/snapshot_diffs".to_string(),
+            update: false,
+        }
+    }
+}
+
+/// Result of comparing two equally sized images
+#[derive(Debug, Clone)]
+pub struct SnapshotDiff {
+    /// Pixels whose perceptual delta exceeds the tolerance
+    pub differing_pixels: usize,
+
+    /// Total pixels compared
+    pub total_pixels: usize,
+
+    /// Largest normalized delta found (0.0-1.0)
+    pub max_delta: f32,
+
+    /// Faded baseline with differing pixels highlighted in red
+    pub diff_image: RgbaImage,
+}
+
+/// Render a world rectangle (inclusive bounds) to an RGBA image, top row first
+pub fn render_region(world: &World, min_x: i32, min_y: i32, max_x: i32, max_y: i32) -> RgbaImage {
+    let width = (max_x - min_x + 1) as usize;
+    let height = (max_y - min_y + 1) as usize;
+
+    let center = Vec2::new(
+        min_x as f32 + width as f32 / 2.0,
+        min_y as f32 + height as f32 / 2.0,
+    );
+
+    let mut renderer = PixelRenderer::new(width, height);
+    renderer.render(world, world.materials(), center, &[], 1.0);
+
+    ImageBuffer::from_raw(width as u32, height as u32, renderer.buffer)
+        .expect("PixelRenderer buffer matches its dimensions")
+}
+
+/// Normalized perceptual difference between two colors (0.0 = identical, 1.0 = maximum)
+pub fn perceptual_delta(a: &Rgba<u8>, b: &Rgba<u8>) -> f32 {
+    let (y1, i1, q1) = rgb_to_yiq(blend_on_white(a));
+    let (y2, i2, q2) = rgb_to_yiq(blend_on_white(b));
+
+    let dy = y1 - y2;
+    let di = i1 - i2;
+    let dq = q1 - q2;
+
+    (0.5053 * dy * dy + 0.299 * di * di + 0.1957 * dq * dq) / MAX_YIQ_DELTA
+}
+
+/// Compare two images, counting pixels whose delta exceeds `tolerance`
+///
+/// Returns `None` if the images have different dimensions.
+pub fn compare_images(
+    actual: &RgbaImage,
+    baseline: &RgbaImage,
+    tolerance: f32,
+) -> Option<SnapshotDiff> {
+    if actual.dimensions() != baseline.dimensions() {
+        return None;
+    }
+
+    // pixelmatch compares the squared delta against the squared threshold
+    let threshold = tolerance * tolerance;
+    let mut differing_pixels = 0;
+    let mut max_delta: f32 = 0.0;
+    let mut diff_image = RgbaImage::new(actual.width(), actual.height());
+
+    for (x, y, expected) in baseline.enumerate_pixels() {
+        let got = actual.get_pixel(x, y);
+        let delta = perceptual_delta(got, expected);
+        max_delta = max_delta.max(delta);
+
+        let out = if delta > threshold {
+            differing_pixels += 1;
+            Rgba([255, 0, 0, 255])
+        } else {
+            // Faded grayscale of the baseline for context
+            let (luma, _, _) = rgb_to_yiq(blend_on_white(expected));
+            let faded = (255.0 + (luma - 255.0) * 0.1) as u8;
+            Rgba([faded, faded, faded, 255])
+        };
+        diff_image.put_pixel(x, y, out);
+    }
+
+    Some(SnapshotDiff {
+        differing_pixels,
+        total_pixels: (actual.width() * actual.height()) as usize,
+        max_delta,
+        diff_image,
+    })
+}
+
+/// Inclusive bounding box of a region, or `None` for unbounded regions
+pub fn region_bounds(region: &Region) -> Option<(i32, i32, i32, i32)> {
+    match region {
+        Region::Rect {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        } => Some((*min_x, *min_y, *max_x, *max_y)),
+        Region::Circle {
+            center_x,
+            center_y,
+            radius,
+        } => {
+            let r = *radius as i32;
+            Some((center_x - r, center_y - r, center_x + r, center_y + r))
+        }
+        Region::Whole | Region::ActiveChunks => None,
+    }
+}
+
+/// Render `region` and compare it against the baseline (or re-bless it in update mode)
+pub fn verify_snapshot(
+    world: &World,
+    baseline: &str,
+    region: &Region,
+    tolerance: Option<f32>,
+    max_diff_pixels: Option<usize>,
+    config: &SnapshotConfig,
+) -> VerificationResult {
+    let Some((min_x, min_y, max_x, max_y)) = region_bounds(region) else {
+        return failed(format!(
+            "Snapshot {}: region {:?} has no bounds, use Rect or Circle",
+            baseline, region
+        ));
+    };
+
+    if max_x < min_x
+        || max_y < min_y
+        || max_x - min_x >= MAX_SNAPSHOT_SIZE
+        || max_y - min_y >= MAX_SNAPSHOT_SIZE
+    {
+        return failed(format!(
+            "Snapshot {}: invalid region size {:?}",
+            baseline, region
+        ));
+    }
+
+    let actual = render_region(world, min_x, min_y, max_x, max_y);
+    let baseline_path = Path::new(&config.snapshot_dir).join(baseline);
+
+    if config.update {
+        return match save_image(&actual, &baseline_path) {
+            Ok(()) => VerificationResult {
+                passed: true,
+                message: format!("Snapshot {}: baseline updated", baseline_path.display()),
+                actual_value: None,
+            },
+            Err(e) => failed(format!(
+                "Snapshot {}: failed to write baseline: {}",
+                baseline_path.display(),
+                e
+            )),
+        };
+    }
+
+    let expected = match image::open(&baseline_path) {
+        Ok(img) => img.to_rgba8(),
+        Err(e) => {
+            let (actual_path, _) = write_failure_images(config, baseline, &actual, None);
+            return failed(format!(
+                "Snapshot {}: cannot load baseline ({}), run with --update-snapshots to create it{}",
+                baseline_path.display(),
+                e,
+                describe_paths(&actual_path, &None)
+            ));
+        }
+    };
+
+    let tol = tolerance.unwrap_or(DEFAULT_SNAPSHOT_TOLERANCE);
+    let max_diff = max_diff_pixels.unwrap_or(0);
+
+    let Some(diff) = compare_images(&actual, &expected, tol) else {
+        let (actual_path, _) = write_failure_images(config, baseline, &actual, None);
+        return failed(format!(
+            "Snapshot {}: size mismatch, expected {}x{}, got {}x{}{}",
+            baseline_path.display(),
+            expected.width(),
+            expected.height(),
+            actual.width(),
+            actual.height(),
+            describe_paths(&actual_path, &None)
+        ));
+    };
+
+    let passed = diff.differing_pixels <= max_diff;
+    let mut message = format!(
+        "Snapshot {}: {} / {} pixels differ (allowed {}, tolerance {}, max delta {:.3})",
+        baseline_path.display(),
+        diff.differing_pixels,
+        diff.total_pixels,
+        max_diff,
+        tol,
+        diff.max_delta
+    );
+
+    if !passed {
+        let (actual_path, diff_path) =
+            write_failure_images(config, baseline, &actual, Some(&diff.diff_image));
+        message.push_str(&describe_paths(&actual_path, &diff_path));
+    }
+
+    VerificationResult {
+        passed,
+        message,
+        actual_value: Some(diff.differing_pixels.to_string()),
+    }
+}
+
+/// Write the actual render (and diff image, if any) for a failed comparison
+fn write_failure_images(
+    config: &SnapshotConfig,
+    baseline: &str,
+    actual: &RgbaImage,
+    diff: Option<&RgbaImage>,
+) -> (Option<PathBuf>, Option<PathBuf>) {
+    let stem = Path::new(baseline)
+        .with_extension("")
+        .to_string_lossy()
+        .replace(['/', '\\'], "_");
+    let dir = Path::new(&config.diff_dir);
+
+    let actual_path = dir.join(format!("{}_actual.png", stem));
+    let actual_path = match save_image(actual, &actual_path) {
+        Ok(()) => Some(actual_path),
+        Err(e) => {
+            log::warn!("Failed to write snapshot image: {}", e);
+            None
+        }
+    };
+
+    let diff_path = diff.and_then(|img| {
+        let path = dir.join(format!("{}_diff.png", stem));
+        match save_image(img, &path) {
+            Ok(()) => Some(path),
+            Err(e) => {
+                log::warn!("Failed to write snapshot diff: {}", e);
+                None
+            }
+        }
+    });
+
+    (actual_path, diff_path)
+}
+
+fn describe_paths(actual: &Option<PathBuf>, diff: &Option<PathBuf>) -> String {
+    let mut out = String::new();
+    if let Some(path) = actual {
+        out.push_str(&format!(", actual: {}", path.display()));
+    }
+    if let Some(path) = diff {
+        out.push_str(&format!(", diff: {}", path.display()));
+    }
+    out
+}
+
+fn save_image(img: &RgbaImage, path: &Path) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    img.save(path)?;
+    Ok(())
+}
+
+fn failed(message: String) -> VerificationResult {
+    VerificationResult {
+        passed: false,
+        message,
+        actual_value: None,
+    }
+}
+
+/// Composite a color over white using its alpha
+fn blend_on_white(c: &Rgba<u8>) -> [f32; 3] {
+    let a = c[3] as f32 / 255.0;
+    [
+        255.0 + (c[0] as f32 - 255.0) * a,
+        255.0 + (c[1] as f32 - 255.0) * a,
+        255.0 + (c[2] as f32 - 255.0) * a,
+    ]
+}
+
+fn rgb_to_yiq([r, g, b]: [f32; 3]) -> (f32, f32, f32) {
+    let y = r * 0.298_895_3 + g * 0.586_622_5 + b * 0.114_482_23;
+    let i = r * 0.595_977_99 - g * 0.274_176_1 - b * 0.321_801_9;
+    let q = r * 0.211_470_17 - g * 0.522_617_1 + b * 0.311_146_94;
+    (y, i, q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid(width: u32, height: u32, color: [u8; 4]) -> RgbaImage {
+        RgbaImage::from_pixel(width, height, Rgba(color))
+    }
+
+    #[test]
+    fn test_perceptual_delta_extremes() {
+        let black = Rgba([0, 0, 0, 255]);
+        let white = Rgba([255, 255, 255, 255]);
+
+        assert_eq!(perceptual_delta(&black, &black), 0.0);
+        let delta = perceptual_delta(&black, &white);
+        assert!(delta > 0.9 && delta <= 1.0, "delta was {}", delta);
+    }
+
+    #[test]
+    fn test_compare_identical_images() {
+        let img = solid(8, 8, [120, 80, 40, 255]);
+        let diff = compare_images(&img, &img, DEFAULT_SNAPSHOT_TOLERANCE).unwrap();
+
+        assert_eq!(diff.differing_pixels, 0);
+        assert_eq!(diff.total_pixels, 64);
+    }
+
+    #[test]
+    fn test_compare_ignores_small_shifts() {
+        let a = solid(4, 4, [120, 80, 40, 255]);
+        let b = solid(4, 4, [124, 82, 41, 255]);
+        let diff = compare_images(&a, &b, DEFAULT_SNAPSHOT_TOLERANCE).unwrap();
+
+        assert_eq!(diff.differing_pixels, 0);
+    }
+
+    #[test]
+    fn test_compare_flags_changed_pixels() {
+        let baseline = solid(4, 4, [0, 0, 0, 255]);
+        let mut actual = baseline.clone();
+        actual.put_pixel(1, 2, Rgba([200, 180, 100, 255]));
+
+        let diff = compare_images(&actual, &baseline, DEFAULT_SNAPSHOT_TOLERANCE).unwrap();
+        assert_eq!(diff.differing_pixels, 1);
+        assert_eq!(*diff.diff_image.get_pixel(1, 2), Rgba([255, 0, 0, 255]));
+        assert_ne!(*diff.diff_image.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn test_compare_size_mismatch() {
+        let a = solid(4, 4, [0, 0, 0, 255]);
+        let b = solid(4, 5, [0, 0, 0, 255]);
+        assert!(compare_images(&a, &b, DEFAULT_SNAPSHOT_TOLERANCE).is_none());
+    }
+
+    #[test]
+    fn test_region_bounds() {
+        let circle = Region::Circle {
+            center_x: 10,
+            center_y: -5,
+            radius: 3,
+        };
+        assert_eq!(region_bounds(&circle), Some((7, -8, 13, -2)));
+        assert_eq!(region_bounds(&Region::Whole), None);
+    }
+
+    #[test]
+    fn test_update_then_verify_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let world = World::new(false);
+        let region = Region::Rect {
+            min_x: -8,
+            min_y: 0,
+            max_x: 7,
+            max_y: 15,
+        };
+
+        let mut config = SnapshotConfig {
+            snapshot_dir: dir.path().join("snapshots").to_string_lossy().to_string(),
+            diff_dir: dir.path().join("diffs").to_string_lossy().to_string(),
+            update: true,
+        };
+
+        let blessed = verify_snapshot(&world, "empty.png", &region, None, None, &config);
+        assert!(blessed.passed, "{}", blessed.message);
+
+        config.update = false;
+        let checked = verify_snapshot(&world, "empty.png", &region, None, None, &config);
+        assert!(checked.passed, "{}", checked.message);
+
+        let missing = verify_snapshot(&world, "missing.png", &region, None, None, &config);
+        assert!(!missing.passed);
+    }
+}
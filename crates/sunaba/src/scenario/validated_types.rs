@@ -361,10 +361,10 @@ mod tests {
 
         // Valid materials by ID
         assert!(ValidatedMaterialId::try_from(0u16).is_ok()); // AIR
-        assert!(ValidatedMaterialId::try_from(58u16).is_ok()); // BUBBLE (max)
+        assert!(ValidatedMaterialId::try_from(74u16).is_ok()); // SAPLING (max)
 
         // Invalid IDs
-        assert!(ValidatedMaterialId::try_from(59u16).is_err()); // Out of range
+        assert!(ValidatedMaterialId::try_from(75u16).is_err()); // Out of range
         assert!(ValidatedMaterialId::try_from(999u16).is_err()); // Way out of range
     }
 
@@ -5,6 +5,7 @@ use sunaba_core::entity::inventory::ItemStack;
 use sunaba_core::simulation::MaterialId;
 use sunaba_core::world::World;
 
+use super::snapshot::{SnapshotConfig, verify_snapshot};
 use super::validated_types::{ValidatedMaterialId, ValidatedSlotIndex};
 
 /// Conditions that can be verified against world state
@@ -93,6 +94,18 @@ pub enum VerificationCondition {
     /// Assert creature exists in region
     CreatureInRegion { region: Region },
 
+    // === VISUAL CHECKS ===
+    /// Render region and compare against a baseline PNG (golden image)
+    MatchesSnapshot {
+        /// Baseline path, relative to the snapshot directory
+        baseline: String,
+        region: Region,
+        /// Per-pixel perceptual threshold (0.0-1.0, default 0.1)
+        tolerance: Option<f32>,
+        /// Number of differing pixels allowed (default 0)
+        max_diff_pixels: Option<usize>,
+    },
+
     // === LOGICAL OPERATORS ===
     /// All conditions must pass
     All {
@@ -147,6 +160,11 @@ pub struct VerificationResult {
 impl VerificationCondition {
     /// Evaluate condition against world state
     pub fn evaluate(&self, world: &World) -> VerificationResult {
+        self.evaluate_with(world, &SnapshotConfig::default())
+    }
+
+    /// Evaluate condition, resolving snapshot baselines with the given config
+    pub fn evaluate_with(&self, world: &World, snapshots: &SnapshotConfig) -> VerificationResult {
         match self {
             VerificationCondition::MaterialCount {
                 material,
@@ -333,7 +351,7 @@ impl VerificationCondition {
                 let mut messages = Vec::new();
 
                 for cond in conditions {
-                    let result = cond.evaluate(world);
+                    let result = cond.evaluate_with(world, snapshots);
                     if !result.passed {
                         all_passed = false;
                     }
@@ -352,7 +370,7 @@ impl VerificationCondition {
                 let mut messages = Vec::new();
 
                 for cond in conditions {
-                    let result = cond.evaluate(world);
+                    let result = cond.evaluate_with(world, snapshots);
                     if result.passed {
                         any_passed = true;
                     }
@@ -367,7 +385,7 @@ impl VerificationCondition {
             }
 
             VerificationCondition::Not { condition } => {
-                let result = condition.evaluate(world);
+                let result = condition.evaluate_with(world, snapshots);
                 VerificationResult {
                     passed: !result.passed,
                     message: format!("NOT ({})", result.message),
@@ -493,6 +511,20 @@ impl VerificationCondition {
                 }
             }
 
+            VerificationCondition::MatchesSnapshot {
+                baseline,
+                region,
+                tolerance,
+                max_diff_pixels,
+            } => verify_snapshot(
+                world,
+                baseline,
+                region,
+                *tolerance,
+                *max_diff_pixels,
+                snapshots,
+            ),
+
             // Stub implementations for less critical verifications
             _ => VerificationResult {
                 passed: false,
@@ -51,7 +51,7 @@ use crate::entity::{
 };
 
 pub use scenario::{ScreenshotScenario, list_all_scenarios};
-pub use video_capture::VideoCapture;
+pub use video_capture::{VideoCapture, VideoFormat};
 pub use video_scenarios::{
     CameraParams, CameraSpec, MaterialFilter, ScenarioAction, VideoScenario,
     get_all_scenarios as get_all_video_scenarios, get_scenario_by_id as get_video_scenario_by_id,
@@ -640,6 +640,21 @@ fn render_inventory_fullscreen(
                         ui.label(format!("[{}] {} ({})", i, tool_def.name, durability));
                     }
                 }
+                ItemStack::Container {
+                    container,
+                    material_id,
+                    amount,
+                } => {
+                    let mat = materials.get(*material_id);
+                    ui.label(format!(
+                        "[{}] {} ({} {}/{})",
+                        i,
+                        container.name(),
+                        mat.name,
+                        amount,
+                        container.capacity()
+                    ));
+                }
             }
         }
     }
@@ -1022,11 +1037,12 @@ fn resolve_camera_spec(
     }
 }
 
-/// Capture a video scenario and encode to MP4
+/// Capture a video scenario and encode to MP4 or APNG
 ///
 /// # Arguments
 /// * `scenario` - The video scenario to capture
-/// * `output_path` - Output MP4 file path (relative to current directory)
+/// * `output_path` - Output file path (relative to current directory); a `.png`
+///   or `.apng` extension selects in-process APNG encoding, anything else MP4 via FFmpeg
 ///
 /// # Returns
 /// Ok(()) on success, or an error if capturing or encoding fails
@@ -1046,7 +1062,15 @@ pub fn capture_video_scenario(
     log::info!("  Actions: {}", scenario.actions.len());
 
     // Initialize video capture
-    let mut video = VideoCapture::new(scenario.width, scenario.height, scenario.fps)?;
+    let mut video = match VideoFormat::from_path(output_path.as_ref()) {
+        VideoFormat::Mp4 => VideoCapture::new(scenario.width, scenario.height, scenario.fps)?,
+        VideoFormat::Apng => VideoCapture::new_apng(
+            output_path.as_ref(),
+            scenario.width,
+            scenario.height,
+            scenario.fps,
+        )?,
+    };
 
     // Initialize world without random generation (skip initial creatures for clean video)
     let mut world = World::new(false);
@@ -1176,8 +1200,15 @@ pub fn capture_video_scenario(
         }
     }
 
-    log::info!("Encoding {} frames to MP4...", video.frame_count());
-    video.encode_to_mp4(&output_path)?;
+    match video.format() {
+        VideoFormat::Mp4 => {
+            log::info!("Encoding {} frames to MP4...", video.frame_count());
+            video.encode_to_mp4(&output_path)?;
+        }
+        VideoFormat::Apng => {
+            video.finish_apng()?;
+        }
+    }
 
     log::info!("Video saved successfully: {:?}", output_path.as_ref());
 
@@ -1,20 +1,82 @@
-//! MP4 video capture for documentation and visualization
+//! Video capture for documentation and visualization
 //!
-//! Captures simulation frames as PNG sequence and encodes to MP4 using FFmpeg.
+//! Two backends:
+//! - MP4: captures frames as PNG sequence and encodes with FFmpeg
+//! - APNG: streams frames into an animated PNG in-process (no external tools)
 
-use std::path::Path;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use anyhow::{Context, Result};
 use image::{ImageBuffer, RgbImage};
 use tempfile::TempDir;
 
-use crate::headless::PixelRenderer;
+use crate::headless::{ApngEncoder, PixelRenderer};
 
-/// Captures frames and encodes them as MP4 video using FFmpeg
+/// Output encoding for `VideoCapture`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VideoFormat {
+    /// H.264 MP4 via the `ffmpeg` binary
+    #[default]
+    Mp4,
+    /// Animated PNG encoded in-process
+    Apng,
+}
+
+impl VideoFormat {
+    /// File extension for this format (APNG uses `.png` so browsers play it in `<img>`)
+    pub fn extension(self) -> &'static str {
+        match self {
+            VideoFormat::Mp4 => "mp4",
+            VideoFormat::Apng => "png",
+        }
+    }
+
+    /// Pick the format from an output path (`.png`/`.apng` → APNG, anything else → MP4)
+    pub fn from_path(path: &Path) -> Self {
+        match path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_lowercase())
+            .as_deref()
+        {
+            Some("png") | Some("apng") => VideoFormat::Apng,
+            _ => VideoFormat::Mp4,
+        }
+    }
+}
+
+impl std::str::FromStr for VideoFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "mp4" => Ok(VideoFormat::Mp4),
+            "apng" | "png" => Ok(VideoFormat::Apng),
+            _ => Err(format!(
+                "Unknown video format: {} (expected mp4 or apng)",
+                s
+            )),
+        }
+    }
+}
+
+/// Where captured frames go
+enum Backend {
+    /// PNG frames in a temp directory, encoded by FFmpeg afterwards
+    Ffmpeg { frames_dir: TempDir },
+    /// Frames streamed straight into the output file (`None` once finished)
+    Apng {
+        encoder: Option<ApngEncoder<BufWriter<File>>>,
+        output: PathBuf,
+    },
+}
+
+/// Captures frames and encodes them as MP4 (FFmpeg) or APNG video
 pub struct VideoCapture {
-    /// Temporary directory for PNG frames
-    frames_dir: TempDir,
+    backend: Backend,
     /// Number of frames captured
     frame_count: usize,
     /// Frame dimensions
@@ -25,7 +87,7 @@ pub struct VideoCapture {
 }
 
 impl VideoCapture {
-    /// Create a new video capture with specified dimensions
+    /// Create a new MP4 video capture with specified dimensions
     ///
     /// # Arguments
     /// * `width` - Frame width in pixels
@@ -35,7 +97,7 @@ impl VideoCapture {
         let frames_dir = TempDir::new().context("Failed to create temp directory for frames")?;
 
         Ok(Self {
-            frames_dir,
+            backend: Backend::Ffmpeg { frames_dir },
             frame_count: 0,
             width,
             height,
@@ -43,19 +105,67 @@ impl VideoCapture {
         })
     }
 
+    /// Create an APNG video capture that streams frames to `output`
+    ///
+    /// Only one compressed frame is held in memory at a time; call
+    /// [`finish_apng`](Self::finish_apng) when done.
+    pub fn new_apng<P: AsRef<Path>>(output: P, width: u32, height: u32, fps: u32) -> Result<Self> {
+        let output = output.as_ref().to_path_buf();
+        if let Some(parent) = output.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create output directory: {:?}", parent))?;
+        }
+
+        let encoder = ApngEncoder::create(&output, width, height, fps)?;
+
+        Ok(Self {
+            backend: Backend::Apng {
+                encoder: Some(encoder),
+                output,
+            },
+            frame_count: 0,
+            width,
+            height,
+            fps,
+        })
+    }
+
+    /// Output format of this capture
+    pub fn format(&self) -> VideoFormat {
+        match self.backend {
+            Backend::Ffmpeg { .. } => VideoFormat::Mp4,
+            Backend::Apng { .. } => VideoFormat::Apng,
+        }
+    }
+
     /// Capture a frame from a pixel renderer
     ///
-    /// Saves the frame as PNG to the temporary directory.
+    /// MP4: saves the frame as PNG to the temporary directory.
+    /// APNG: compresses and appends the frame to the output file.
     pub fn capture_frame(&mut self, renderer: &PixelRenderer) -> Result<()> {
         let rgb = renderer.get_rgb_buffer();
 
+        let frames_dir = match &mut self.backend {
+            Backend::Ffmpeg { frames_dir } => frames_dir,
+            Backend::Apng { encoder, .. } => {
+                encoder
+                    .as_mut()
+                    .context("APNG capture already finished")?
+                    .write_frame(&rgb)
+                    .with_context(|| format!("Failed to encode frame {}", self.frame_count))?;
+                self.frame_count += 1;
+                return Ok(());
+            }
+        };
+
         // Create ImageBuffer from RGB data
         let img: RgbImage = ImageBuffer::from_raw(self.width, self.height, rgb)
             .context("Failed to create image buffer from renderer data")?;
 
         // Save as PNG with frame number
-        let frame_path = self
-            .frames_dir
+        let frame_path = frames_dir
             .path()
             .join(format!("frame_{:05}.png", self.frame_count));
         img.save(&frame_path).with_context(|| {
@@ -74,6 +184,34 @@ impl VideoCapture {
         self.frame_count
     }
 
+    /// Temporary frame directory (MP4 backend only)
+    fn frames_dir(&self) -> Option<&Path> {
+        match &self.backend {
+            Backend::Ffmpeg { frames_dir } => Some(frames_dir.path()),
+            Backend::Apng { .. } => None,
+        }
+    }
+
+    /// Finalize an APNG capture and return the output path
+    pub fn finish_apng(&mut self) -> Result<PathBuf> {
+        let Backend::Apng { encoder, output } = &mut self.backend else {
+            anyhow::bail!("finish_apng called on an MP4 capture (use encode_to_mp4)");
+        };
+
+        encoder
+            .take()
+            .context("APNG capture already finished")?
+            .finish()
+            .with_context(|| format!("Failed to finalize APNG: {:?}", output))?;
+
+        log::info!(
+            "Successfully encoded APNG: {:?} ({} frames)",
+            output,
+            self.frame_count
+        );
+        Ok(output.clone())
+    }
+
     /// Encode captured frames to MP4 using FFmpeg
     ///
     /// # Arguments
@@ -89,6 +227,10 @@ impl VideoCapture {
     /// - `-preset medium`: Encoding speed/compression tradeoff
     /// - `-pix_fmt yuv420p`: Compatibility with most players
     pub fn encode_to_mp4<P: AsRef<Path>>(&self, output: P) -> Result<()> {
+        let Some(frames_dir) = self.frames_dir() else {
+            anyhow::bail!("encode_to_mp4 called on an APNG capture (use finish_apng)");
+        };
+
         if self.frame_count == 0 {
             anyhow::bail!("No frames to encode");
         }
@@ -121,7 +263,7 @@ impl VideoCapture {
 
         // Build FFmpeg command
         let status = Command::new("ffmpeg")
-            .current_dir(self.frames_dir.path())
+            .current_dir(frames_dir)
             .args(&[
                 "-framerate",
                 &self.fps.to_string(),
@@ -190,12 +332,49 @@ mod tests {
         assert_eq!(capture.frame_count(), 2);
 
         // Verify frame files exist
-        let frame0_path = capture.frames_dir.path().join("frame_00000.png");
-        let frame1_path = capture.frames_dir.path().join("frame_00001.png");
+        let frames_dir = capture.frames_dir().expect("MP4 capture has a frames dir");
+        let frame0_path = frames_dir.join("frame_00000.png");
+        let frame1_path = frames_dir.join("frame_00001.png");
         assert!(frame0_path.exists());
         assert!(frame1_path.exists());
     }
 
+    #[test]
+    fn test_apng_capture() {
+        let dir = TempDir::new().unwrap();
+        let output = dir.path().join("videos/test.png");
+        let mut capture =
+            VideoCapture::new_apng(&output, 64, 64, 10).expect("Failed to create APNG capture");
+        let renderer = PixelRenderer::new(64, 64);
+
+        for _ in 0..3 {
+            capture
+                .capture_frame(&renderer)
+                .expect("Failed to capture frame");
+        }
+        assert_eq!(capture.format(), VideoFormat::Apng);
+        assert!(capture.encode_to_mp4(dir.path().join("x.mp4")).is_err());
+
+        let written = capture.finish_apng().expect("Failed to finish APNG");
+        assert_eq!(written, output);
+        assert!(output.exists());
+        assert!(capture.finish_apng().is_err());
+    }
+
+    #[test]
+    fn test_video_format_selection() {
+        assert_eq!(
+            VideoFormat::from_path(Path::new("videos/fire.png")),
+            VideoFormat::Apng
+        );
+        assert_eq!(
+            VideoFormat::from_path(Path::new("videos/fire.mp4")),
+            VideoFormat::Mp4
+        );
+        assert_eq!("apng".parse(), Ok(VideoFormat::Apng));
+        assert!("avi".parse::<VideoFormat>().is_err());
+    }
+
     #[test]
     #[ignore] // Requires FFmpeg installation
     fn test_encode_to_mp4() {
@@ -0,0 +1,113 @@
+//! Multiplayer chat panel (message log and input line)
+
+use crate::multiplayer::ChatLine;
+use crate::ui::theme::GameColors;
+use egui::{Align2, Vec2};
+
+/// Messages shown in the log (the server keeps more)
+const VISIBLE_MESSAGES: usize = 50;
+
+/// Chat panel state
+pub struct ChatPanelState {
+    /// Latest messages visible to us (oldest first), refreshed by App
+    pub messages: Vec<ChatLine>,
+    /// Text being typed
+    pub input: String,
+    /// Input line is open (Enter opens it, Enter sends, Escape closes)
+    pub typing: bool,
+    /// Message to send - set by the panel, consumed by App
+    pub send_requested: Option<String>,
+    /// Focus the input line on the next render
+    focus_input: bool,
+}
+
+impl ChatPanelState {
+    pub fn new() -> Self {
+        Self {
+            messages: Vec::new(),
+            input: String::new(),
+            typing: false,
+            send_requested: None,
+            focus_input: false,
+        }
+    }
+
+    /// Open the input line, optionally starting with some text (e.g. "/")
+    pub fn open_input(&mut self, prefix: &str) {
+        self.typing = true;
+        self.focus_input = true;
+        if self.input.is_empty() {
+            self.input = prefix.to_string();
+        }
+    }
+
+    /// Render the message log and, while typing, the input line
+    pub fn render(&mut self, ctx: &egui::Context, theme_colors: &GameColors) {
+        if self.messages.is_empty() && !self.typing {
+            return;
+        }
+
+        egui::Window::new("chat_panel")
+            .title_bar(false)
+            .resizable(false)
+            .anchor(Align2::LEFT_BOTTOM, Vec2::new(10.0, -10.0))
+            .default_width(380.0)
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical()
+                    .max_height(160.0)
+                    .stick_to_bottom(true)
+                    .show(ui, |ui| {
+                        let start = self.messages.len().saturating_sub(VISIBLE_MESSAGES);
+                        for line in &self.messages[start..] {
+                            if line.system {
+                                ui.label(
+                                    egui::RichText::new(&line.text)
+                                        .italics()
+                                        .color(theme_colors.info),
+                                );
+                            } else {
+                                ui.horizontal_wrapped(|ui| {
+                                    ui.label(
+                                        egui::RichText::new(format!("{}:", line.sender_name))
+                                            .strong()
+                                            .color(theme_colors.text_highlight),
+                                    );
+                                    ui.label(&line.text);
+                                });
+                            }
+                        }
+                    });
+
+                if !self.typing {
+                    return;
+                }
+
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.input)
+                        .hint_text("Say something, or /help")
+                        .desired_width(f32::INFINITY),
+                );
+                if std::mem::take(&mut self.focus_input) {
+                    response.request_focus();
+                }
+
+                if response.lost_focus() {
+                    // Enter sends; Escape or clicking away just closes
+                    if ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        let text = self.input.trim().to_string();
+                        if !text.is_empty() {
+                            self.send_requested = Some(text);
+                        }
+                        self.input.clear();
+                    }
+                    self.typing = false;
+                }
+            });
+    }
+}
+
+impl Default for ChatPanelState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
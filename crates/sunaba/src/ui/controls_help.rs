@@ -88,6 +88,8 @@ impl ControlsHelpState {
                 ui.heading("Actions");
                 ui.label("Left Click - Spawn material");
                 ui.label("G - Spawn creature");
+                ui.label("R - Rotate placed pumps and logic parts");
+                ui.label("E - Eat food in the selected slot");
 
                 ui.add_space(8.0);
                 ui.heading("UI");
@@ -106,6 +108,7 @@ impl ControlsHelpState {
                 ui.label("I - Inventory");
                 ui.label("C - Crafting");
                 ui.label("F5 - Save world");
+                ui.label("F9 - Record input (native)");
 
                 ui.add_space(8.0);
                 ui.separator();
@@ -189,6 +192,8 @@ impl ControlsHelpState {
                 ui.heading("Actions");
                 ui.label("Left Click - Spawn material");
                 ui.label("G - Spawn creature");
+                ui.label("R - Rotate placed pumps and logic parts");
+                ui.label("E - Eat food in the selected slot");
 
                 ui.add_space(8.0);
                 ui.heading("UI");
@@ -207,6 +212,7 @@ impl ControlsHelpState {
                 ui.label("I - Inventory");
                 ui.label("C - Crafting");
                 ui.label("F5 - Save world");
+                ui.label("F9 - Record input (native)");
 
                 ui.add_space(8.0);
                 ui.separator();
@@ -1,7 +1,8 @@
 //! Crafting UI panel
 
-use crate::entity::crafting::{Recipe, RecipeOutput, RecipeRegistry};
+use crate::entity::crafting::{Recipe, RecipeOutput, RecipeRegistry, WorkstationType};
 use crate::entity::inventory::Inventory;
+use crate::entity::tools::ToolRegistry;
 use crate::simulation::Materials;
 use crate::ui::theme::GameColors;
 use egui::{Color32, CornerRadius, RichText};
@@ -12,7 +13,9 @@ pub fn render_crafting_overlay(
     ctx: &egui::Context,
     inventory: &Inventory,
     recipes: &RecipeRegistry,
+    workstations: &[WorkstationType],
     materials: &Materials,
+    tool_registry: &ToolRegistry,
     theme_colors: &GameColors,
     pending_craft: &mut Option<String>,
 ) {
@@ -38,13 +41,14 @@ pub fn render_crafting_overlay(
         .frame(egui::Frame::window(&ctx.style()).fill(ctx.style().visuals.window_fill()))
         .show(ctx, |ui| {
             ui.heading("Available Recipes");
+            render_nearby_workstations(ui, workstations, theme_colors);
             ui.add_space(10.0);
 
-            let craftable = recipes.get_craftable(inventory);
+            let craftable = recipes.get_craftable(inventory, workstations);
 
             if craftable.is_empty() {
                 ui.colored_label(theme_colors.text_disabled, "No craftable recipes");
-                ui.label("Gather more materials to unlock recipes.");
+                ui.label("Gather more materials or build workstations to unlock recipes.");
             } else {
                 egui::ScrollArea::vertical()
                     .max_height(380.0)
@@ -57,6 +61,7 @@ pub fn render_crafting_overlay(
                                 recipe,
                                 inventory,
                                 materials,
+                                tool_registry,
                                 theme_colors,
                             ) {
                                 // User clicked craft - store the recipe name for the App to process
@@ -78,6 +83,41 @@ pub fn render_crafting_overlay(
         });
 }
 
+/// Show which workstations are in reach
+fn render_nearby_workstations(
+    ui: &mut egui::Ui,
+    workstations: &[WorkstationType],
+    theme_colors: &GameColors,
+) {
+    ui.horizontal(|ui| {
+        ui.label(RichText::new("Nearby:").color(theme_colors.text_disabled));
+        if workstations.is_empty() {
+            ui.colored_label(theme_colors.text_disabled, "no workstations");
+        } else {
+            let names: Vec<&str> = workstations.iter().map(|w| w.name()).collect();
+            ui.colored_label(theme_colors.info, names.join(", "));
+        }
+    });
+}
+
+/// Render which workstation a recipe needs (and how long it smelts)
+fn render_recipe_workstation(ui: &mut egui::Ui, recipe: &Recipe, theme_colors: &GameColors) {
+    if let Some(workstation) = recipe.workstation {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("At:").color(theme_colors.text_disabled));
+            if recipe.craft_time > 0.0 {
+                ui.label(format!(
+                    "{} ({:.0}s)",
+                    workstation.name(),
+                    recipe.craft_time
+                ));
+            } else {
+                ui.label(workstation.name());
+            }
+        });
+    }
+}
+
 /// Render a single recipe with a craft button
 /// Returns true if the craft button was clicked
 fn render_recipe_with_button(
@@ -85,6 +125,7 @@ fn render_recipe_with_button(
     recipe: &Recipe,
     inventory: &Inventory,
     materials: &Materials,
+    tool_registry: &ToolRegistry,
     theme_colors: &GameColors,
 ) -> bool {
     let mut craft_clicked = false;
@@ -124,6 +165,8 @@ fn render_recipe_with_button(
         }
     });
 
+    render_recipe_workstation(ui, recipe, theme_colors);
+
     // Show output
     ui.horizontal(|ui| {
         ui.label(RichText::new("Produces:").color(theme_colors.text_disabled));
@@ -137,17 +180,15 @@ fn render_recipe_with_button(
                 tool_id,
                 durability,
             } => {
-                let tool_name = match *tool_id {
-                    1000 => "Wood Pickaxe",
-                    1001 => "Stone Pickaxe",
-                    1002 => "Iron Pickaxe",
-                    _ => "Unknown Tool",
-                };
+                let tool_name = tool_registry.name(*tool_id);
                 ui.colored_label(
                     theme_colors.tool_legendary,
                     format!("{} ({}⚒)", tool_name, durability),
                 );
             }
+            RecipeOutput::Container { container } => {
+                ui.colored_label(theme_colors.info, container.name());
+            }
         }
     });
 
@@ -176,7 +217,9 @@ impl CraftingUI {
         ctx: &egui::Context,
         inventory: &mut Inventory,
         recipes: &RecipeRegistry,
+        workstations: &[WorkstationType],
         materials: &Materials,
+        tool_registry: &ToolRegistry,
         theme_colors: &GameColors,
     ) -> Option<RecipeOutput> {
         if !self.visible {
@@ -191,9 +234,10 @@ impl CraftingUI {
             .frame(egui::Frame::window(&ctx.style()).fill(ctx.style().visuals.window_fill()))
             .show(ctx, |ui| {
                 ui.heading("Available Recipes");
+                render_nearby_workstations(ui, workstations, theme_colors);
                 ui.add_space(10.0);
 
-                let craftable = recipes.get_craftable(inventory);
+                let craftable = recipes.get_craftable(inventory, workstations);
 
                 if craftable.is_empty() {
                     ui.colored_label(theme_colors.text_disabled, "No craftable recipes");
@@ -210,6 +254,7 @@ impl CraftingUI {
                         inventory,
                         recipes,
                         materials,
+                        tool_registry,
                         theme_colors,
                     );
                     if crafted_output.is_some() {
@@ -234,6 +279,7 @@ impl CraftingUI {
         inventory: &mut Inventory,
         recipes: &RecipeRegistry,
         materials: &Materials,
+        tool_registry: &ToolRegistry,
         theme_colors: &GameColors,
     ) -> Option<RecipeOutput> {
         let mut crafted = None;
@@ -278,6 +324,8 @@ impl CraftingUI {
             }
         });
 
+        render_recipe_workstation(ui, recipe, theme_colors);
+
         // Show output
         ui.horizontal(|ui| {
             ui.label(RichText::new("Produces:").color(theme_colors.text_disabled));
@@ -291,17 +339,15 @@ impl CraftingUI {
                     tool_id,
                     durability,
                 } => {
-                    let tool_name = match *tool_id {
-                        1000 => "Wood Pickaxe",
-                        1001 => "Stone Pickaxe",
-                        1002 => "Iron Pickaxe",
-                        _ => "Unknown Tool",
-                    };
+                    let tool_name = tool_registry.name(*tool_id);
                     ui.colored_label(
                         theme_colors.tool_legendary,
                         format!("{} ({}⚒)", tool_name, durability),
                     );
                 }
+                RecipeOutput::Container { container } => {
+                    ui.colored_label(theme_colors.info, container.name());
+                }
             }
         });
 
@@ -1,9 +1,10 @@
 use crate::entity::player::Player;
+use crate::entity::status_effects::StatusEffectKind;
 use crate::entity::tools::ToolRegistry;
 use crate::ui::theme::GameColors;
 use egui::{Color32, Context, CornerRadius, Rect, Stroke, StrokeKind, Vec2};
 
-/// Heads-up display showing player health, hunger, and hotbar
+/// Heads-up display showing player health, hunger, air, status effects, and hotbar
 pub struct Hud {
     show: bool,
 }
@@ -67,6 +68,38 @@ impl Hud {
 
                 ui.add_space(5.0);
 
+                // Air bar (only while holding breath or recovering)
+                if !player.breath.is_full() {
+                    let air_color = if player.breath.is_drowning() {
+                        theme_colors.health_critical
+                    } else {
+                        theme_colors.material_water
+                    };
+
+                    self.render_stat_bar(
+                        ui,
+                        "Air",
+                        player.breath.current,
+                        player.breath.max,
+                        air_color,
+                        theme_colors.hunger_bg,
+                    );
+
+                    ui.add_space(5.0);
+                }
+
+                // Status effects with remaining duration
+                for effect in player.status_effects.iter() {
+                    let color = match effect.kind {
+                        StatusEffectKind::Burning => theme_colors.material_fire,
+                        StatusEffectKind::Poisoned => theme_colors.material_toxic,
+                    };
+                    ui.colored_label(
+                        color,
+                        format!("{} ({:.1}s)", effect.kind.label(), effect.remaining),
+                    );
+                }
+
                 // Inventory summary
                 ui.label(format!(
                     "Inventory: {}/{} slots",
@@ -1,4 +1,5 @@
 use crate::entity::player::Player;
+use crate::entity::tools::ToolRegistry;
 use crate::ui::theme::GameColors;
 use egui::{Color32, Context, CornerRadius, Pos2, Rect, Stroke, StrokeKind, Vec2};
 
@@ -7,6 +8,7 @@ pub fn render_inventory_overlay(
     ctx: &egui::Context,
     player: &Player,
     material_names: &[&str],
+    tool_registry: &ToolRegistry,
     theme_colors: &GameColors,
 ) {
     // Dark semi-transparent backdrop covering entire screen
@@ -76,6 +78,7 @@ pub fn render_inventory_overlay(
                                     player,
                                     slot_index,
                                     material_names,
+                                    tool_registry,
                                     SLOT_SIZE,
                                     theme_colors,
                                 );
@@ -92,12 +95,18 @@ pub fn render_inventory_overlay(
         });
 }
 
+/// Compact tool label for inventory slots ("Iron Pickaxe" -> "Iron Pick")
+fn short_tool_name(tool_registry: &ToolRegistry, tool_id: u16) -> String {
+    tool_registry.name(tool_id).replace("Pickaxe", "Pick")
+}
+
 /// Render a single inventory slot for the overlay
 fn render_overlay_slot(
     ui: &mut egui::Ui,
     player: &Player,
     slot_index: usize,
     material_names: &[&str],
+    tool_registry: &ToolRegistry,
     size: f32,
     theme_colors: &GameColors,
 ) {
@@ -173,13 +182,8 @@ fn render_overlay_slot(
                 let color_bar = Rect::from_min_size(rect.min, Vec2::new(size, 8.0));
                 painter.rect_filled(color_bar, CornerRadius::same(2), tool_color);
 
-                // Tool name (hardcoded for now - registry not accessible from UI)
-                let name = match *tool_id {
-                    1000 => "Wood Pick",
-                    1001 => "Stone Pick",
-                    1002 => "Iron Pick",
-                    _ => "Tool",
-                };
+                // Tool name (compact to fit the slot)
+                let name = short_tool_name(tool_registry, *tool_id);
 
                 let text_pos = Pos2::new(rect.center().x, rect.min.y + 18.0);
 
@@ -203,6 +207,42 @@ fn render_overlay_slot(
                     theme_colors.tool_durability_full,
                 );
             }
+            ItemStack::Container {
+                container,
+                material_id,
+                amount,
+            } => {
+                // Fluid color indicator (dim when empty)
+                let fluid_color = if *amount > 0 {
+                    get_material_color(*material_id)
+                } else {
+                    theme_colors.text_disabled
+                };
+                let color_bar = Rect::from_min_size(rect.min, Vec2::new(size, 8.0));
+                painter.rect_filled(color_bar, CornerRadius::same(2), fluid_color);
+
+                let text_pos = Pos2::new(rect.center().x, rect.min.y + 18.0);
+
+                painter.text(
+                    text_pos,
+                    egui::Align2::CENTER_CENTER,
+                    container.name(),
+                    egui::FontId::proportional(10.0),
+                    Color32::WHITE,
+                );
+
+                // Fill level
+                let fill_text = format!("{}/{}", amount, container.capacity());
+                let fill_pos = Pos2::new(rect.center().x, rect.max.y - 10.0);
+
+                painter.text(
+                    fill_pos,
+                    egui::Align2::CENTER_CENTER,
+                    &fill_text,
+                    egui::FontId::proportional(12.0),
+                    theme_colors.text_secondary,
+                );
+            }
         }
     } else {
         // Empty slot - show slot number for hotbar
@@ -254,18 +294,29 @@ fn render_overlay_slot(
                     tool_id,
                     durability,
                 } => {
-                    let name = match *tool_id {
-                        1000 => "Wood Pickaxe",
-                        1001 => "Stone Pickaxe",
-                        1002 => "Iron Pickaxe",
-                        _ => "Unknown Tool",
-                    };
+                    let name = tool_registry.name(*tool_id);
 
                     response.on_hover_text(format!(
                         "{}\nDurability: {}\nTool ID: {}",
                         name, durability, tool_id
                     ));
                 }
+                ItemStack::Container {
+                    container,
+                    material_id,
+                    amount,
+                } => {
+                    let contents = if *amount > 0 {
+                        let name = material_names
+                            .get(*material_id as usize)
+                            .unwrap_or(&"Unknown");
+                        format!("{} {}/{}", name, amount, container.capacity())
+                    } else {
+                        "Empty".to_string()
+                    };
+
+                    response.on_hover_text(format!("{}\n{}", container.name(), contents));
+                }
             }
         } else if slot_index < 10 {
             response.on_hover_text(format!("Hotbar slot {} (empty)", (slot_index + 1) % 10));
@@ -316,6 +367,7 @@ impl InventoryPanel {
         ctx: &Context,
         player: &Player,
         material_names: &[&str],
+        tool_registry: &ToolRegistry,
         theme_colors: &GameColors,
     ) {
         if !self.open {
@@ -373,6 +425,7 @@ impl InventoryPanel {
                                         player,
                                         slot_index,
                                         material_names,
+                                        tool_registry,
                                         SLOT_SIZE,
                                         theme_colors,
                                     );
@@ -395,6 +448,7 @@ impl InventoryPanel {
         player: &Player,
         slot_index: usize,
         material_names: &[&str],
+        tool_registry: &ToolRegistry,
         size: f32,
         theme_colors: &GameColors,
     ) {
@@ -470,13 +524,8 @@ impl InventoryPanel {
                     let color_bar = Rect::from_min_size(rect.min, Vec2::new(size, 8.0));
                     painter.rect_filled(color_bar, CornerRadius::same(2), tool_color);
 
-                    // Tool name (hardcoded for now - registry not accessible from UI)
-                    let name = match *tool_id {
-                        1000 => "Wood Pick",
-                        1001 => "Stone Pick",
-                        1002 => "Iron Pick",
-                        _ => "Tool",
-                    };
+                    // Tool name (compact to fit the slot)
+                    let name = short_tool_name(tool_registry, *tool_id);
 
                     let text_pos = Pos2::new(rect.center().x, rect.min.y + 18.0);
 
@@ -500,6 +549,42 @@ impl InventoryPanel {
                         theme_colors.tool_durability_full,
                     );
                 }
+                ItemStack::Container {
+                    container,
+                    material_id,
+                    amount,
+                } => {
+                    // Fluid color indicator (dim when empty)
+                    let fluid_color = if *amount > 0 {
+                        self.get_material_color(*material_id)
+                    } else {
+                        theme_colors.text_disabled
+                    };
+                    let color_bar = Rect::from_min_size(rect.min, Vec2::new(size, 8.0));
+                    painter.rect_filled(color_bar, CornerRadius::same(2), fluid_color);
+
+                    let text_pos = Pos2::new(rect.center().x, rect.min.y + 18.0);
+
+                    painter.text(
+                        text_pos,
+                        egui::Align2::CENTER_CENTER,
+                        container.name(),
+                        egui::FontId::proportional(10.0),
+                        Color32::WHITE,
+                    );
+
+                    // Fill level
+                    let fill_text = format!("{}/{}", amount, container.capacity());
+                    let fill_pos = Pos2::new(rect.center().x, rect.max.y - 10.0);
+
+                    painter.text(
+                        fill_pos,
+                        egui::Align2::CENTER_CENTER,
+                        &fill_text,
+                        egui::FontId::proportional(12.0),
+                        theme_colors.text_secondary,
+                    );
+                }
             }
         } else {
             // Empty slot - show slot number for hotbar
@@ -553,18 +638,29 @@ impl InventoryPanel {
                         tool_id,
                         durability,
                     } => {
-                        let name = match *tool_id {
-                            1000 => "Wood Pickaxe",
-                            1001 => "Stone Pickaxe",
-                            1002 => "Iron Pickaxe",
-                            _ => "Unknown Tool",
-                        };
+                        let name = tool_registry.name(*tool_id);
 
                         response.on_hover_text(format!(
                             "{}\nDurability: {}\nTool ID: {}",
                             name, durability, tool_id
                         ));
                     }
+                    ItemStack::Container {
+                        container,
+                        material_id,
+                        amount,
+                    } => {
+                        let contents = if *amount > 0 {
+                            let name = material_names
+                                .get(*material_id as usize)
+                                .unwrap_or(&"Unknown");
+                            format!("{} {}/{}", name, amount, container.capacity())
+                        } else {
+                            "Empty".to_string()
+                        };
+
+                        response.on_hover_text(format!("{}\n{}", container.name(), contents));
+                    }
                 }
             } else if slot_index < 10 {
                 response.on_hover_text(format!("Hotbar slot {} (empty)", (slot_index + 1) % 10));
@@ -1,5 +1,7 @@
 //! UI system - tooltips, overlays, stats, and controls
 
+#[cfg(feature = "multiplayer")]
+pub mod chat_panel;
 pub mod controls_help;
 pub mod crafting_ui;
 pub mod debug_panel;
@@ -20,6 +22,8 @@ pub mod tooltip;
 pub mod ui_state;
 pub mod worldgen_editor;
 
+#[cfg(feature = "multiplayer")]
+pub use chat_panel::ChatPanelState;
 pub use controls_help::ControlsHelpState;
 pub use crafting_ui::CraftingUI;
 pub use debug_panel::DebugPanelManager;
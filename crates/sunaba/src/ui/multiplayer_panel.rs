@@ -56,6 +56,18 @@ pub struct MultiplayerPanelState {
     // Admin actions
     /// Flag: rebuild world requested
     pub rebuild_world_requested: bool,
+    /// Label for the next manual snapshot
+    pub snapshot_label: String,
+    /// Flag: snapshot requested (with label)
+    pub create_snapshot_requested: Option<String>,
+    /// Flag: whole-world rollback to this snapshot requested
+    pub rollback_requested: Option<u64>,
+    /// Flag: upload the singleplayer world to the server requested
+    pub upload_world_requested: bool,
+
+    // World transfer
+    /// Flag: save the server world as the singleplayer world requested
+    pub download_world_requested: bool,
 
     // Nickname editing
     /// Nickname being edited (empty = use default)
@@ -78,6 +90,10 @@ impl MultiplayerPanelState {
         self.oauth_login_requested = false;
         self.oauth_logout_requested = false;
         self.rebuild_world_requested = false;
+        self.create_snapshot_requested = None;
+        self.rollback_requested = None;
+        self.upload_world_requested = false;
+        self.download_world_requested = false;
         self.set_nickname_requested = None;
     }
 }
@@ -108,6 +124,8 @@ pub fn render_multiplayer_panel(
                 online_player_count,
                 panel_state,
             );
+            #[cfg(not(target_arch = "wasm32"))]
+            render_world_transfer_ui(ui, &manager.client, panel_state);
         }
         MultiplayerState::Reconnecting {
             server_url,
@@ -237,6 +255,66 @@ fn render_disconnected_ui(
     }
 }
 
+/// Download/upload and snapshot controls for the connected server (native only)
+#[cfg(all(feature = "multiplayer", not(target_arch = "wasm32")))]
+fn render_world_transfer_ui(
+    ui: &mut Ui,
+    client: &MultiplayerClient,
+    state: &mut MultiplayerPanelState,
+) {
+    ui.separator();
+    ui.heading("World Transfer");
+
+    if ui.button("⬇ Download to Singleplayer").clicked() {
+        state.download_world_requested = true;
+    }
+    ui.label(
+        "Saves the loaded server chunks as your singleplayer world (the old one is backed up)",
+    );
+
+    // Upload and snapshots need server-side admin status, not just an OAuth login
+    if !client.is_admin() {
+        return;
+    }
+
+    if ui.button("⬆ Upload Singleplayer World").clicked() {
+        state.upload_world_requested = true;
+    }
+    ui.label("Replaces server chunks with your saved ones (a snapshot is taken first)");
+
+    ui.add_space(5.0);
+    ui.heading("Snapshots");
+    ui.horizontal(|ui| {
+        ui.text_edit_singleline(&mut state.snapshot_label);
+        if ui.button("📸 Snapshot Now").clicked() {
+            state.create_snapshot_requested = Some(state.snapshot_label.trim().to_string());
+            state.snapshot_label.clear();
+        }
+    });
+
+    egui::ScrollArea::vertical()
+        .id_salt("snapshot_list")
+        .max_height(150.0)
+        .show(ui, |ui| {
+            for snapshot in client.snapshots() {
+                ui.horizontal(|ui| {
+                    let label = if snapshot.automatic {
+                        "periodic"
+                    } else {
+                        snapshot.label.as_str()
+                    };
+                    ui.label(format!(
+                        "#{} tick {} ({}, {} chunks)",
+                        snapshot.id, snapshot.tick, label, snapshot.chunk_count
+                    ));
+                    if ui.button("Roll back").clicked() {
+                        state.rollback_requested = Some(snapshot.id);
+                    }
+                });
+            }
+        });
+}
+
 /// UI for connecting state - loading indicator and cancel button
 #[cfg(feature = "multiplayer")]
 fn render_connecting_ui(ui: &mut Ui, server_url: &str, state: &mut MultiplayerPanelState) {
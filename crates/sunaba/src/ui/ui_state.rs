@@ -60,6 +60,10 @@ pub struct UiState {
     #[cfg(feature = "multiplayer")]
     pub multiplayer_panel: super::multiplayer_panel::MultiplayerPanelState,
 
+    /// Multiplayer chat (log and input line)
+    #[cfg(feature = "multiplayer")]
+    pub chat_panel: super::chat_panel::ChatPanelState,
+
     /// Game over panel state (death screen)
     pub game_over_panel: super::game_over_panel::GameOverPanelState,
 
@@ -115,6 +119,8 @@ impl UiState {
             metrics_collector: None,
             #[cfg(feature = "multiplayer")]
             multiplayer_panel: super::multiplayer_panel::MultiplayerPanelState::new(),
+            #[cfg(feature = "multiplayer")]
+            chat_panel: super::chat_panel::ChatPanelState::new(),
             game_over_panel: super::game_over_panel::GameOverPanelState::new(),
             worldgen_editor: WorldGenEditor::new(),
             theme,
@@ -142,6 +148,8 @@ impl UiState {
             metrics_collector: None,
             #[cfg(feature = "multiplayer")]
             multiplayer_panel: super::multiplayer_panel::MultiplayerPanelState::new(),
+            #[cfg(feature = "multiplayer")]
+            chat_panel: super::chat_panel::ChatPanelState::new(),
             game_over_panel: super::game_over_panel::GameOverPanelState::new(),
             worldgen_editor: WorldGenEditor::new(),
             theme: SunabaTheme::default(), // Cozy Alchemist theme
@@ -252,6 +260,7 @@ impl UiState {
         player: &crate::entity::player::Player,
         tool_registry: &crate::entity::tools::ToolRegistry,
         recipe_registry: &crate::entity::crafting::RecipeRegistry,
+        workstations: &[crate::entity::crafting::WorkstationType],
         config: &mut crate::config::GameConfig,
         show_game_over: bool,
         #[cfg(feature = "multiplayer")] multiplayer_manager: Option<
@@ -308,6 +317,12 @@ impl UiState {
             self.tooltip.render(ctx, cursor_screen_pos);
         }
 
+        // Render chat (multiplayer only)
+        #[cfg(feature = "multiplayer")]
+        if multiplayer_manager.is_some_and(|m| m.state.is_connected()) {
+            self.chat_panel.render(ctx, &self.theme.game);
+        }
+
         // Render worldgen editor (F7)
         self.worldgen_editor.render(ctx, materials);
 
@@ -322,6 +337,7 @@ impl UiState {
                 ctx,
                 player,
                 &material_names,
+                tool_registry,
                 &self.theme.game,
             );
         }
@@ -333,7 +349,9 @@ impl UiState {
                 ctx,
                 &player.inventory,
                 recipe_registry,
+                workstations,
                 materials,
+                tool_registry,
                 &self.theme.game,
                 &mut self.pending_craft,
             );
@@ -358,6 +376,7 @@ impl UiState {
         player: &crate::entity::player::Player,
         tool_registry: &crate::entity::tools::ToolRegistry,
         recipe_registry: &crate::entity::crafting::RecipeRegistry,
+        workstations: &[crate::entity::crafting::WorkstationType],
         show_game_over: bool,
         #[cfg(feature = "multiplayer")] multiplayer_manager: Option<
             &crate::multiplayer::MultiplayerManager,
@@ -411,6 +430,12 @@ impl UiState {
             self.tooltip.render(ctx, cursor_screen_pos);
         }
 
+        // Render chat (multiplayer only)
+        #[cfg(feature = "multiplayer")]
+        if multiplayer_manager.is_some_and(|m| m.state.is_connected()) {
+            self.chat_panel.render(ctx, &self.theme.game);
+        }
+
         // Render worldgen editor (F7)
         self.worldgen_editor.render(ctx, materials);
 
@@ -425,6 +450,7 @@ impl UiState {
                 ctx,
                 player,
                 &material_names,
+                tool_registry,
                 &self.theme.game,
             );
         }
@@ -434,7 +460,9 @@ impl UiState {
                 ctx,
                 &player.inventory,
                 recipe_registry,
+                workstations,
                 materials,
+                tool_registry,
                 &self.theme.game,
                 &mut self.pending_craft,
             );
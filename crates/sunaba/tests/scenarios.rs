@@ -18,12 +18,17 @@
 //!
 //! # Run all scenario tests
 //! cargo test --test scenarios --features headless -- --include-ignored
+//!
+//! # Re-bless MatchesSnapshot baselines (scenarios/snapshots/)
+//! UPDATE_SNAPSHOTS=1 cargo test --test scenarios --features headless -- --include-ignored
 //! ```
 
 #![cfg(feature = "headless")]
 
 use anyhow::Result;
-use sunaba::scenario::{ScenarioDefinition, ScenarioExecutor};
+use sunaba::scenario::{
+    ScenarioDefinition, ScenarioExecutor, ScenarioExecutorConfig, SnapshotConfig,
+};
 use sunaba_core::world::World;
 
 /// Helper function to run a scenario test from a .ron file
@@ -45,7 +50,20 @@ fn run_scenario_test(path: &str) -> Result<()> {
     let full_path = workspace_root.join(path);
 
     let scenario = ScenarioDefinition::from_file(&full_path)?;
-    let mut executor = ScenarioExecutor::new();
+    let mut executor = ScenarioExecutor::with_config(ScenarioExecutorConfig {
+        snapshots: SnapshotConfig {
+            snapshot_dir: workspace_root
+                .join("scenarios/snapshots")
+                .to_string_lossy()
+                .to_string(),
+            diff_dir: workspace_root
+                .join("scenario_results/snapshot_diffs")
+                .to_string_lossy()
+                .to_string(),
+            update: std::env::var_os("UPDATE_SNAPSHOTS").is_some(),
+        },
+        ..ScenarioExecutorConfig::default()
+    });
     let mut world = World::new(false);
     let report = executor.execute_scenario(&scenario, &mut world)?;
 
@@ -80,6 +98,13 @@ fn test_mining_mechanics() -> Result<()> {
     run_scenario_test("scenarios/test_mining.ron")
 }
 
+/// Golden-image check: solids in a sealed cavity render unchanged after settling
+#[test]
+#[ignore]
+fn test_solid_cavity_snapshot() -> Result<()> {
+    run_scenario_test("scenarios/tier3_physics/test_solid_cavity_snapshot.ron")
+}
+
 /// Test all scenarios in the scenarios/ directory
 /// This ensures all example scenarios remain valid
 #[test]
@@ -10,6 +10,10 @@ let spacetimeClient = null;
 let isConnectedFlag = false;
 let latestServerMetrics = null;
 let chunkCache = new Map(); // Key: "x,y", Value: Uint8Array chunk data
+let chatMessages = new Map(); // Key: message id, Value: chat line visible to us
+let localPlayer = null; // Server-owned state of our own player row
+let landClaims = new Map(); // Key: claim id, Value: claim outline
+let spawnProtectionRadius = 0; // From world_config, 0 = no spawn zone
 
 /**
  * Connect to SpacetimeDB server
@@ -79,13 +83,37 @@ export async function subscribeWorld() {
             'SELECT * FROM world_config',
             'SELECT * FROM chunk_data',
             'SELECT * FROM player',
-            'SELECT * FROM creature_data',
-            'SELECT * FROM server_metrics'
+            'SELECT * FROM creature_info',
+            'SELECT * FROM creature_pose',
+            'SELECT * FROM server_metrics',
+            'SELECT * FROM chat_message',
+            'SELECT * FROM land_claim'
         ]);
 
         // Set up table update handlers
         spacetimeClient.on('world_config', (table, operation, row) => {
             console.log('[SpacetimeDB] World config update:', row);
+            if (operation !== 'delete') {
+                spawnProtectionRadius = row.spawn_protection_radius;
+            }
+        });
+
+        spacetimeClient.on('land_claim', (table, operation, row) => {
+            const id = Number(row.id);
+            if (operation === 'delete') {
+                landClaims.delete(id);
+                return;
+            }
+
+            const identity = spacetimeClient.identity;
+            landClaims.set(id, {
+                min_x: row.min_x,
+                min_y: row.min_y,
+                max_x: row.max_x,
+                max_y: row.max_y,
+                name: row.name,
+                own: !!identity && row.owner.toHexString() === identity.toHexString()
+            });
         });
 
         spacetimeClient.on('chunk_data', (table, operation, row) => {
@@ -102,11 +130,30 @@ export async function subscribeWorld() {
         });
 
         spacetimeClient.on('player', (table, operation, row) => {
-            console.log('[SpacetimeDB] Player update:', row);
+            // Only our own row is mirrored (inventory, tool, health, hunger)
+            const identity = spacetimeClient.identity;
+            if (!identity || row.identity.toHexString() !== identity.toHexString()) {
+                return;
+            }
+            if (operation === 'delete') {
+                localPlayer = null;
+                return;
+            }
+
+            localPlayer = {
+                inventory_data: Array.from(row.inventory_data),
+                equipped_tool: row.equipped_tool ?? null,
+                health: row.health,
+                hunger: row.hunger,
+                last_edit_seq: Number(row.last_edit_seq),
+                rejected_edits: Array.from(row.rejected_edits, Number)
+            };
         });
 
-        spacetimeClient.on('creature_data', (table, operation, row) => {
-            // console.log('[SpacetimeDB] Creature update:', row.id);
+        // Creatures aren't drawn on the web client yet (the server only
+        // publishes poses near players, so these stay small)
+        spacetimeClient.on('creature_pose', (table, operation, row) => {
+            // console.log('[SpacetimeDB] Creature pose:', row.creature_id);
         });
 
         spacetimeClient.on('server_metrics', (table, operation, row) => {
@@ -126,6 +173,28 @@ export async function subscribeWorld() {
             }
         });
 
+        spacetimeClient.on('chat_message', (table, operation, row) => {
+            const id = Number(row.id);
+            if (operation === 'delete') {
+                chatMessages.delete(id);
+                return;
+            }
+
+            // Private command replies are only shown to their recipient
+            const identity = spacetimeClient.identity;
+            if (row.recipient != null &&
+                (!identity || row.recipient.toHexString() !== identity.toHexString())) {
+                return;
+            }
+
+            chatMessages.set(id, {
+                id,
+                sender_name: row.sender_name,
+                text: row.text,
+                system: row.system
+            });
+        });
+
         console.log('[SpacetimeDB] Subscribed to world state');
 
     } catch (error) {
@@ -159,32 +228,148 @@ export function updatePlayerPosition(x, y, velX, velY) {
  * @param {number} x - Pixel X coordinate
  * @param {number} y - Pixel Y coordinate
  * @param {number} materialId - Material ID
+ * @param {number} seq - Edit sequence number for prediction (0 = untracked)
  */
-export function placeMaterial(x, y, materialId) {
+export function placeMaterial(x, y, materialId, seq) {
     if (!spacetimeClient) {
         throw new Error('Not connected to SpacetimeDB');
     }
 
     try {
-        spacetimeClient.call('player_place_material', x, y, materialId);
+        spacetimeClient.call('player_place_material', x, y, materialId, seq);
     } catch (error) {
         console.error('[SpacetimeDB] Failed to place material:', error);
         throw error;
     }
 }
 
+/**
+ * Send placement from the server-side inventory
+ * @param {number} x - Pixel X coordinate
+ * @param {number} y - Pixel Y coordinate
+ * @param {number} materialId - Material ID
+ * @param {number} seq - Edit sequence number for prediction (0 = untracked)
+ */
+export function placeFromInventory(x, y, materialId, seq) {
+    if (!spacetimeClient) {
+        throw new Error('Not connected to SpacetimeDB');
+    }
+
+    try {
+        spacetimeClient.call('player_place_from_inventory', x, y, materialId, seq);
+    } catch (error) {
+        console.error('[SpacetimeDB] Failed to place from inventory:', error);
+        throw error;
+    }
+}
+
+/**
+ * Start mining a pixel into the server-side inventory
+ * @param {number} x - Pixel X coordinate
+ * @param {number} y - Pixel Y coordinate
+ */
+export function startMining(x, y) {
+    if (!spacetimeClient) {
+        throw new Error('Not connected to SpacetimeDB');
+    }
+
+    try {
+        spacetimeClient.call('player_start_mining', x, y);
+    } catch (error) {
+        console.error('[SpacetimeDB] Failed to start mining:', error);
+        throw error;
+    }
+}
+
+/**
+ * Craft a recipe from the server-side inventory
+ * @param {number} recipeId - Recipe ID
+ */
+export function craft(recipeId) {
+    if (!spacetimeClient) {
+        throw new Error('Not connected to SpacetimeDB');
+    }
+
+    try {
+        spacetimeClient.call('player_craft', recipeId);
+    } catch (error) {
+        console.error('[SpacetimeDB] Failed to craft:', error);
+        throw error;
+    }
+}
+
+/**
+ * Eat one unit of food from the server-side inventory
+ * @param {number} materialId - Food material ID
+ */
+export function eat(materialId) {
+    if (!spacetimeClient) {
+        throw new Error('Not connected to SpacetimeDB');
+    }
+
+    try {
+        spacetimeClient.call('player_eat', materialId);
+    } catch (error) {
+        console.error('[SpacetimeDB] Failed to eat:', error);
+        throw error;
+    }
+}
+
+/**
+ * Tell the server our rejected edits up to seq have been rolled back
+ * @param {number} seq - Highest rolled back edit sequence number
+ */
+export function confirmEdits(seq) {
+    if (!spacetimeClient) {
+        throw new Error('Not connected to SpacetimeDB');
+    }
+
+    try {
+        spacetimeClient.call('player_confirm_edits', seq);
+    } catch (error) {
+        console.error('[SpacetimeDB] Failed to confirm edits:', error);
+        throw error;
+    }
+}
+
+/**
+ * Equip a tool from the server-side inventory
+ * @param {number|null} toolId - Tool ID, or null to unequip
+ */
+export function equipTool(toolId) {
+    if (!spacetimeClient) {
+        throw new Error('Not connected to SpacetimeDB');
+    }
+
+    try {
+        spacetimeClient.call('player_equip_tool', toolId ?? null);
+    } catch (error) {
+        console.error('[SpacetimeDB] Failed to equip tool:', error);
+        throw error;
+    }
+}
+
+/**
+ * Get the server-owned state of our player row
+ * @returns {Object|null} ({ inventory_data, equipped_tool, health, hunger }) or null before it arrives
+ */
+export function getLocalPlayer() {
+    return localPlayer;
+}
+
 /**
  * Send mining request to server
  * @param {number} x - Pixel X coordinate
  * @param {number} y - Pixel Y coordinate
+ * @param {number} seq - Edit sequence number for prediction (0 = untracked)
  */
-export function mineMaterial(x, y) {
+export function mineMaterial(x, y, seq) {
     if (!spacetimeClient) {
         throw new Error('Not connected to SpacetimeDB');
     }
 
     try {
-        spacetimeClient.call('player_mine', x, y);
+        spacetimeClient.call('player_mine', x, y, seq);
     } catch (error) {
         console.error('[SpacetimeDB] Failed to mine:', error);
         throw error;
@@ -290,6 +475,48 @@ export async function requestRespawn() {
     }
 }
 
+/**
+ * Send a chat message or slash command
+ * @param {string} text - Message text
+ * @returns {Promise<void>}
+ */
+export async function sendChat(text) {
+    if (!spacetimeClient) {
+        throw new Error('Not connected to SpacetimeDB');
+    }
+
+    try {
+        await spacetimeClient.call('send_chat', text);
+    } catch (error) {
+        console.error('[SpacetimeDB] Failed to send chat:', error);
+        throw error;
+    }
+}
+
+/**
+ * Get chat messages visible to this client, oldest first
+ * @returns {Array<Object>} Chat lines ({ id, sender_name, text, system })
+ */
+export function getChatMessages() {
+    return Array.from(chatMessages.values()).sort((a, b) => a.id - b.id);
+}
+
+/**
+ * Get land claim outlines from the local cache
+ * @returns {Array<Object>} Claims ({ min_x, min_y, max_x, max_y, name, own })
+ */
+export function getLandClaims() {
+    return Array.from(landClaims.values());
+}
+
+/**
+ * Get the spawn protection radius from the world config
+ * @returns {number} Radius in pixels (0 = no spawn zone)
+ */
+export function getSpawnProtectionRadius() {
+    return spawnProtectionRadius;
+}
+
 /**
  * Get chunk data from local cache for rendering
  * @param {number} x - Chunk X coordinate